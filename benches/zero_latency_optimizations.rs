@@ -5,11 +5,19 @@
 //! - Zero-copy string slicing vs String allocation
 //! - Discriminator LUT lookup
 //! - Hot-path fast routing
+//! - Cached base58 signature encoding
 //!
 //! Run with: cargo bench --bench zero_latency_optimizations
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId};
 use sol_parser_sdk::logs::utils::{read_string, read_string_ref, text_parser::{extract_text_field, extract_text_field_ref}};
+use sol_parser_sdk::core::cache::signature_base58;
+use sol_parser_sdk::core::events::EventMetadata;
+use sol_parser_sdk::logs::optimized_matcher::InvokeCursor;
+use sol_parser_sdk::logs::{parse_log, parse_log_with_context, LogDecodeContext};
+use solana_sdk::signature::Signature;
+use memchr::memmem;
+use once_cell::sync::Lazy;
 use smallvec::SmallVec;
 
 // ========================================================================
@@ -335,6 +343,166 @@ fn bench_realistic_event_parsing(c: &mut Criterion) {
     group.finish();
 }
 
+// ========================================================================
+// Signature Base58 Cache Benchmarks
+// ========================================================================
+
+fn bench_signature_base58(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Signature Base58");
+
+    // Typical export: ~3 events per transaction share one signature
+    let signatures: Vec<Signature> = (0..64u8).map(|i| Signature::from([i; 64])).collect();
+
+    group.bench_function("to_string", |b| {
+        b.iter(|| {
+            for sig in &signatures {
+                for _ in 0..3 {
+                    std::hint::black_box(sig.to_string());
+                }
+            }
+        });
+    });
+
+    group.bench_function("cached", |b| {
+        b.iter(|| {
+            for sig in &signatures {
+                for _ in 0..3 {
+                    std::hint::black_box(signature_base58(sig));
+                }
+            }
+        });
+    });
+
+    group.finish();
+}
+
+// ========================================================================
+// Log Decode Context Benchmarks
+// ========================================================================
+
+fn bench_log_decode_context(c: &mut Criterion) {
+    use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+    let mut group = c.benchmark_group("Log Decode Context");
+
+    // Multi-event transaction: invoke/log lines interleaved with PumpSwap BuyEvent data
+    let mut event = vec![103u8, 244, 82, 31, 44, 245, 119, 119];
+    event.resize(8 + 400, 0);
+    let logs: Vec<String> = (0..24u8)
+        .map(|i| {
+            if i % 3 == 0 {
+                format!("Program data: {}", STANDARD.encode(&event))
+            } else {
+                "Program log: Instruction: Swap".to_string()
+            }
+        })
+        .collect();
+    let signature = Signature::default();
+
+    group.bench_function("per_line_stack_buffer", |b| {
+        b.iter(|| {
+            for log in &logs {
+                std::hint::black_box(parse_log(log, signature, 1, 0, Some(0), 0, None, false));
+            }
+        });
+    });
+
+    let mut ctx = LogDecodeContext::new();
+    group.bench_function("per_transaction_context", |b| {
+        b.iter(|| {
+            ctx.clear();
+            let metadata = EventMetadata { signature, slot: 1, ..Default::default() };
+            for (i, log) in logs.iter().enumerate() {
+                std::hint::black_box(parse_log_with_context(
+                    &mut ctx, i, log, metadata, None, None, false,
+                ));
+            }
+        });
+    });
+
+    group.finish();
+}
+
+// ========================================================================
+// Protocol Detection Benchmarks
+// ========================================================================
+
+/// Substring-based detection removed from `optimized_matcher`, kept here as the baseline
+static SUBSTRING_FINDERS: Lazy<Vec<memmem::Finder<'static>>> = Lazy::new(|| {
+    [
+        "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8",
+        "CAMMCzo5YL8w4VFF8KVHrK22GGUQpMdRBFSzKNT3t4ivN6",
+        "CPMDWBwJDtYax9qKcQP3CtKz7tHjJsN3H8hGrYVD9mZD",
+        "LanMV9sAd7wArD4vJFi2qDdfnVhFxYSUg6eADduJ3uj",
+        "whirL",
+        "meteora",
+        "pumpswap",
+        "PumpSwap",
+        "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P",
+    ]
+    .iter()
+    .map(|p| memmem::Finder::new(p.as_bytes()))
+    .collect()
+});
+static PROGRAM_DATA: Lazy<memmem::Finder<'static>> =
+    Lazy::new(|| memmem::Finder::new(b"Program data: "));
+
+fn substring_detect(log: &str) -> u8 {
+    let bytes = log.as_bytes();
+    if bytes.len() < 20 || PROGRAM_DATA.find(bytes).is_none() {
+        return 0;
+    }
+    for (i, finder) in SUBSTRING_FINDERS.iter().enumerate() {
+        if finder.find(bytes).is_some() {
+            return i as u8 + 1;
+        }
+    }
+    u8::from(log.len() > 30) * 10
+}
+
+fn bench_protocol_detection(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Protocol Detection");
+
+    // Typical aggregator swap: compute budget + router CPI into PumpSwap, token transfers, one event
+    let logs: Vec<String> = vec![
+        "Program ComputeBudget111111111111111111111111111111 invoke [1]".into(),
+        "Program ComputeBudget111111111111111111111111111111 success".into(),
+        "Program JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 invoke [1]".into(),
+        "Program log: Instruction: Route".into(),
+        "Program pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA invoke [2]".into(),
+        "Program log: Instruction: Buy".into(),
+        "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [3]".into(),
+        "Program log: Instruction: TransferChecked".into(),
+        "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA consumed 6238 of 180000 compute units".into(),
+        "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success".into(),
+        "Program data: Z/RSHyz1d3cAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA".into(),
+        "Program pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA consumed 40213 of 170000 compute units".into(),
+        "Program pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA success".into(),
+        "Program JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 consumed 80213 of 200000 compute units".into(),
+        "Program JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 success".into(),
+    ];
+
+    group.bench_function("substring_scan", |b| {
+        b.iter(|| {
+            for log in &logs {
+                std::hint::black_box(substring_detect(log));
+            }
+        });
+    });
+
+    group.bench_function("invoke_attribution", |b| {
+        b.iter(|| {
+            let mut cursor = InvokeCursor::new();
+            for log in &logs {
+                cursor.observe(log);
+                std::hint::black_box(cursor.program());
+            }
+        });
+    });
+
+    group.finish();
+}
+
 // ========================================================================
 // Criterion Configuration
 // ========================================================================
@@ -346,7 +514,10 @@ criterion_group!(
     bench_text_field_extraction,
     bench_discriminator_lookup,
     bench_branch_prediction,
-    bench_realistic_event_parsing
+    bench_realistic_event_parsing,
+    bench_signature_base58,
+    bench_log_decode_context,
+    bench_protocol_detection
 );
 
 criterion_main!(benches);
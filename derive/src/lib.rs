@@ -0,0 +1,195 @@
+//! sol-parser-sdk 事件类型的派生宏
+//!
+//! `#[derive(DexEventFields)]` 用于两类类型：
+//! - 事件结构体：实现 `sol_parser_sdk::core::events::DexEventFields`，提供元数据访问、事件类型名和字段列表
+//! - `DexEvent` 枚举：生成按变体分发的 `metadata()` / `metadata_mut()` / `event_type_name()` / `field_names()`
+//!
+//! 属性：
+//! - 结构体 `#[dex_event(event_type = "...")]`：覆盖事件类型名，默认为去掉 `Event` 后缀的结构体名
+//! - 结构体字段 `#[dex_event(metadata)]`：指定元数据字段，默认为名为 `metadata` 的字段
+//! - 枚举 `#[dex_event(default_metadata = PATH)]`：无元数据变体的 `metadata()` 返回值
+//! - 枚举变体 `#[dex_event(skip)]`：该变体没有元数据（如 `Error(String)`）
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, LitStr, Path};
+
+#[proc_macro_derive(DexEventFields, attributes(dex_event))]
+pub fn derive_dex_event_fields(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let expanded = match &input.data {
+        Data::Struct(_) => derive_struct(&input),
+        Data::Enum(_) => derive_enum(&input),
+        Data::Union(_) => Err(syn::Error::new_spanned(&input.ident, "DexEventFields 不支持 union")),
+    };
+    expanded.unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+fn derive_struct(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else { unreachable!() };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(&input.ident, "DexEventFields 只支持具名字段的结构体"));
+    };
+
+    let mut event_type = None;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("dex_event")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("event_type") {
+                event_type = Some(meta.value()?.parse::<LitStr>()?.value());
+                Ok(())
+            } else {
+                Err(meta.error("未知的 dex_event 属性"))
+            }
+        })?;
+    }
+    let name = &input.ident;
+    let event_type = event_type.unwrap_or_else(|| {
+        let name = name.to_string();
+        name.strip_suffix("Event").unwrap_or(&name).to_string()
+    });
+
+    let mut metadata = None;
+    for field in &fields.named {
+        let mut marked = false;
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("dex_event")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("metadata") {
+                    marked = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("未知的 dex_event 字段属性"))
+                }
+            })?;
+        }
+        if marked || (metadata.is_none() && field.ident.as_ref().is_some_and(|i| i == "metadata")) {
+            metadata = field.ident.clone();
+        }
+    }
+    let Some(metadata) = metadata else {
+        return Err(syn::Error::new_spanned(
+            name,
+            "缺少 metadata 字段，或用 #[dex_event(metadata)] 标注",
+        ));
+    };
+    let field_names = fields.named.iter().map(|f| f.ident.as_ref().unwrap().to_string());
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::sol_parser_sdk::core::events::DexEventFields for #name #ty_generics #where_clause {
+            const EVENT_TYPE: &'static str = #event_type;
+            const FIELD_NAMES: &'static [&'static str] = &[#(#field_names),*];
+
+            #[inline]
+            fn metadata(&self) -> &::sol_parser_sdk::core::events::EventMetadata {
+                &self.#metadata
+            }
+
+            #[inline]
+            fn metadata_mut(&mut self) -> &mut ::sol_parser_sdk::core::events::EventMetadata {
+                &mut self.#metadata
+            }
+        }
+    })
+}
+
+fn derive_enum(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Enum(data) = &input.data else { unreachable!() };
+
+    let mut default_metadata: Option<Path> = None;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("dex_event")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("default_metadata") {
+                default_metadata = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("未知的 dex_event 属性"))
+            }
+        })?;
+    }
+
+    let mut metadata_arms = Vec::new();
+    let mut metadata_mut_arms = Vec::new();
+    let mut name_arms = Vec::new();
+    let mut fields_arms = Vec::new();
+    for variant in &data.variants {
+        let ident = &variant.ident;
+        let variant_name = ident.to_string();
+        let Fields::Unnamed(fields) = &variant.fields else {
+            return Err(syn::Error::new_spanned(ident, "DexEventFields 变体必须是单字段元组变体"));
+        };
+        if fields.unnamed.len() != 1 {
+            return Err(syn::Error::new_spanned(ident, "DexEventFields 变体必须是单字段元组变体"));
+        }
+
+        let mut skip = false;
+        for attr in variant.attrs.iter().filter(|a| a.path().is_ident("dex_event")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    skip = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("未知的 dex_event 变体属性"))
+                }
+            })?;
+        }
+
+        name_arms.push(quote! { Self::#ident(_) => #variant_name, });
+        if skip {
+            let Some(default_metadata) = &default_metadata else {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    "跳过的变体需要在枚举上指定 #[dex_event(default_metadata = PATH)]",
+                ));
+            };
+            metadata_arms.push(quote! { Self::#ident(_) => &#default_metadata, });
+            metadata_mut_arms.push(quote! { Self::#ident(_) => None, });
+            fields_arms.push(quote! { Self::#ident(_) => &[], });
+        } else {
+            let ty = &fields.unnamed[0].ty;
+            metadata_arms.push(quote! {
+                Self::#ident(e) => ::sol_parser_sdk::core::events::DexEventFields::metadata(e),
+            });
+            metadata_mut_arms.push(quote! {
+                Self::#ident(e) => Some(::sol_parser_sdk::core::events::DexEventFields::metadata_mut(e)),
+            });
+            fields_arms.push(quote! {
+                Self::#ident(_) => <#ty as ::sol_parser_sdk::core::events::DexEventFields>::FIELD_NAMES,
+            });
+        }
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// 获取事件的元数据
+            pub fn metadata(&self) -> &::sol_parser_sdk::core::events::EventMetadata {
+                match self {
+                    #(#metadata_arms)*
+                }
+            }
+
+            /// 获取事件元数据的可变引用（没有元数据的变体返回 None）
+            pub fn metadata_mut(&mut self) -> Option<&mut ::sol_parser_sdk::core::events::EventMetadata> {
+                match self {
+                    #(#metadata_mut_arms)*
+                }
+            }
+
+            /// 事件类型名称（与枚举变体名一致）
+            pub fn event_type_name(&self) -> &'static str {
+                match self {
+                    #(#name_arms)*
+                }
+            }
+
+            /// 事件结构体的字段名（按声明顺序），没有元数据的变体返回空列表
+            pub fn field_names(&self) -> &'static [&'static str] {
+                match self {
+                    #(#fields_arms)*
+                }
+            }
+        }
+    })
+}
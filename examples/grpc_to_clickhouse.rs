@@ -0,0 +1,134 @@
+//! gRPC → ClickHouse
+//!
+//! 订阅 PumpFun / PumpSwap / Meteora DAMM V2 的 swap，统一为 `NormalizedSwap` 后按 slot 微批次
+//! 通过 ClickHouse HTTP 接口（`INSERT ... FORMAT JSONEachRow`）写入，启动时自动建表。
+//!
+//! ```bash
+//! GRPC_ENDPOINT=https://... GRPC_TOKEN=... CLICKHOUSE_ADDR=127.0.0.1:8123 \
+//!     cargo run --release --example grpc_to_clickhouse
+//! ```
+
+use sol_parser_sdk::analytics::{normalize_swap, NormalizedSwap, PoolRegistry};
+use sol_parser_sdk::grpc::{
+    AccountFilter, ClientConfig, EventType, EventTypeFilter, Protocol, TransactionFilter,
+    YellowstoneGrpc,
+};
+use sol_parser_sdk::DexEvent;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+const TABLE: &str = "dex_swaps";
+
+const CREATE_TABLE: &str = "CREATE TABLE IF NOT EXISTS dex_swaps (
+    slot UInt64,
+    signature String,
+    source LowCardinality(String),
+    pool String,
+    trader String,
+    base_mint String,
+    quote_mint String,
+    direction LowCardinality(String),
+    base_amount UInt64,
+    quote_amount UInt64
+) ENGINE = MergeTree ORDER BY (slot, signature)";
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+
+    let endpoint = std::env::var("GRPC_ENDPOINT")
+        .unwrap_or_else(|_| "https://solana-yellowstone-grpc.publicnode.com:443".to_string());
+    let token = std::env::var("GRPC_TOKEN").ok();
+    let clickhouse = ClickHouse::new(
+        &std::env::var("CLICKHOUSE_ADDR").unwrap_or_else(|_| "127.0.0.1:8123".into()),
+    )?;
+    clickhouse.execute(CREATE_TABLE, String::new()).await?;
+
+    let protocols = vec![Protocol::PumpFun, Protocol::PumpSwap, Protocol::MeteoraDammV2];
+    let event_filter = EventTypeFilter::include_only(vec![
+        EventType::PumpFunTrade,
+        EventType::PumpSwapBuy,
+        EventType::PumpSwapSell,
+        EventType::MeteoraDammV2Swap,
+    ]);
+
+    // 批次回调在流任务上同步执行，写库交给单独的任务
+    let (tx, mut rx) = mpsc::channel::<String>(64);
+    tokio::spawn(async move {
+        let query = format!("INSERT INTO {} FORMAT JSONEachRow", TABLE);
+        while let Some(rows) = rx.recv().await {
+            if let Err(e) = clickhouse.execute(&query, rows).await {
+                eprintln!("ClickHouse insert failed: {}", e);
+            }
+        }
+    });
+
+    let grpc = YellowstoneGrpc::new_with_config(endpoint, token, ClientConfig::default())?;
+    let mut registry = PoolRegistry::new();
+    grpc.subscribe_dex_events_batched(
+        vec![TransactionFilter::for_protocols(&protocols)],
+        vec![AccountFilter::for_protocols(&protocols)],
+        Some(event_filter),
+        Duration::from_millis(200),
+        move |events: Vec<DexEvent>| {
+            let rows: String = events
+                .iter()
+                .filter_map(|event| {
+                    registry.observe(event);
+                    Some(row(event, &normalize_swap(event, &registry)?))
+                })
+                .collect();
+            if !rows.is_empty() && tx.try_send(rows).is_err() {
+                eprintln!("ClickHouse writer is behind, dropping batch");
+            }
+        },
+    )
+    .await?;
+
+    println!("Writing swaps to ClickHouse table {}, press Ctrl+C to stop", TABLE);
+    tokio::signal::ctrl_c().await?;
+    grpc.stop().await;
+    Ok(())
+}
+
+/// 一行 JSONEachRow
+fn row(event: &DexEvent, swap: &NormalizedSwap) -> String {
+    let value = serde_json::json!({
+        "slot": swap.slot,
+        "signature": event.metadata().signature.to_string(),
+        "source": swap.source,
+        "pool": swap.pool.to_string(),
+        "trader": swap.trader.map(|t| t.to_string()).unwrap_or_default(),
+        "base_mint": swap.base_mint.to_string(),
+        "quote_mint": swap.quote_mint().to_string(),
+        "direction": format!("{:?}", swap.direction),
+        "base_amount": swap.base_amount(),
+        "quote_amount": swap.quote_amount(),
+    });
+    format!("{}\n", value)
+}
+
+/// ClickHouse HTTP 接口：`query` 放在 URL 参数中，数据放在请求体
+struct ClickHouse {
+    http: reqwest::Client,
+    url: reqwest::Url,
+}
+
+impl ClickHouse {
+    fn new(addr: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let http = reqwest::Client::builder().timeout(Duration::from_secs(30)).build()?;
+        Ok(Self { http, url: format!("http://{}/", addr).parse()? })
+    }
+
+    async fn execute(&self, query: &str, body: String) -> Result<(), Box<dyn std::error::Error>> {
+        let response =
+            self.http.post(self.url.clone()).query(&[("query", query)]).body(body).send().await?;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            let status = response.status();
+            let detail = response.text().await.unwrap_or_default();
+            Err(format!("{}: {}", status, detail.trim()).into())
+        }
+    }
+}
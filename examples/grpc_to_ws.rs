@@ -0,0 +1,49 @@
+//! gRPC → WebSocket 广播（feature `ws-server`）
+//!
+//! 订阅 PumpFun / PumpSwap 事件，经脱敏转换器后由 `ws_server::serve_events` 广播给所有连接。
+//! 连接可发送订阅消息按事件类型和 mint 过滤：
+//!
+//! ```bash
+//! cargo run --release --example grpc_to_ws --features ws-server
+//! websocat ws://127.0.0.1:9000
+//! {"event_types": ["PumpFunCreate", "PumpSwapBuy"]}
+//! ```
+
+use sol_parser_sdk::core::{RedactAction, Redactor, ALL_EVENTS};
+use sol_parser_sdk::grpc::ws_server::serve_events;
+use sol_parser_sdk::grpc::{
+    AccountFilter, ClientConfig, Protocol, TransactionFilter, YellowstoneGrpc,
+};
+use std::net::SocketAddr;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+
+    let endpoint = std::env::var("GRPC_ENDPOINT")
+        .unwrap_or_else(|_| "https://solana-yellowstone-grpc.publicnode.com:443".to_string());
+    let token = std::env::var("GRPC_TOKEN").ok();
+    let addr: SocketAddr = std::env::var("WS_ADDR").unwrap_or("127.0.0.1:9000".into()).parse()?;
+
+    // 对外广播前隐藏交易钱包，同一钱包仍映射到同一个哈希
+    let salt = std::env::var("REDACT_SALT").unwrap_or_default();
+    let redactor = Redactor::new().with_salt(salt).redact(ALL_EVENTS, "user", RedactAction::Hash);
+
+    let grpc = YellowstoneGrpc::new_with_config(endpoint, token, ClientConfig::default())?
+        .with_transformer(redactor);
+    let protocols = vec![Protocol::PumpFun, Protocol::PumpSwap];
+    let queue = grpc
+        .subscribe_dex_events(
+            vec![TransactionFilter::for_protocols(&protocols)],
+            vec![AccountFilter::for_protocols(&protocols)],
+            None,
+        )
+        .await?;
+
+    let server = serve_events(addr, queue).await?;
+    println!("Broadcasting events on ws://{}, press Ctrl+C to stop", addr);
+    tokio::signal::ctrl_c().await?;
+    server.abort();
+    grpc.stop().await;
+    Ok(())
+}
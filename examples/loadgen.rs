@@ -0,0 +1,48 @@
+//! 合成事件流压测
+//!
+//! 无需 gRPC 端点，按协议合成交易日志驱动 解析 → 队列 → sink 管线，输出吞吐与延迟分位数。
+//!
+//! ```bash
+//! cargo run --release --example loadgen -- --rate 100000 --secs 30 --protocols pumpfun,pumpswap,clmm
+//! cargo run --release --example loadgen -- --rate 0 --file /tmp/events.jsonl
+//! ```
+//!
+//! 参数：
+//! - `--rate N`：目标速率（交易/秒），0 表示不限速，默认 50000
+//! - `--secs N`：持续时间（秒），默认 10
+//! - `--protocols a,b`：`pumpfun` / `pumpswap` / `clmm`，默认全部
+//! - `--file PATH`：写入 JSON Lines 文件 sink；`--stdout` 写入标准输出
+
+use sol_parser_sdk::grpc::sink::SinkConfig;
+use sol_parser_sdk::loadgen::{run, LoadGenConfig, SyntheticProtocol};
+use std::time::Duration;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut config = LoadGenConfig::default();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("missing value for {}", arg));
+        match arg.as_str() {
+            "--rate" => config.rate = value()?.parse()?,
+            "--secs" => config.duration = Duration::from_secs(value()?.parse()?),
+            "--protocols" => {
+                config.protocols = value()?
+                    .split(',')
+                    .map(|name| {
+                        SyntheticProtocol::from_name(name)
+                            .ok_or_else(|| format!("unknown protocol: {}", name))
+                    })
+                    .collect::<Result<_, _>>()?;
+            }
+            "--file" => config.sinks.push(SinkConfig::File { path: value()?.into() }),
+            "--stdout" => config.sinks.push(SinkConfig::Stdout),
+            _ => return Err(format!("unknown argument: {}", arg).into()),
+        }
+    }
+
+    sol_parser_sdk::warmup_parser();
+    eprintln!("Running load generator: {:?}", config);
+    let report = run(&config)?;
+    eprintln!("{}", report);
+    Ok(())
+}
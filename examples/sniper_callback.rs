@@ -0,0 +1,104 @@
+//! 低延迟回调狙击骨架
+//!
+//! 用 `subscribe_dex_events_direct` 在流任务上直接处理事件（不经过队列）：
+//! 1. `PumpFunCreate` 登记新发行的 mint
+//! 2. 该 mint 的第一笔成交到达时，用 `PoolStateTracker` 按 bonding curve 储备报价
+//! 3. 以自己的钱包重建 buy 指令（`PumpFunTradeEvent::instruction_for`），附带滑点上限
+//!
+//! 签名与发送交易不在本示例范围内：指令通过 channel 交给发送线程，这里只打印。
+//! 回调内不做 IO，保持在微秒级。
+//!
+//! ```bash
+//! SNIPER_WALLET=<pubkey> cargo run --release --example sniper_callback
+//! ```
+
+use sol_parser_sdk::analytics::{PoolStateTracker, SwapDirection};
+use sol_parser_sdk::core::now_micros;
+use sol_parser_sdk::grpc::{
+    AccountFilter, ClientConfig, EventType, EventTypeFilter, OrderMode, Protocol,
+    TransactionFilter, YellowstoneGrpc,
+};
+use sol_parser_sdk::DexEvent;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashSet;
+use std::sync::mpsc;
+
+/// 每个新币投入的 SOL（lamports）
+const BUY_LAMPORTS: u64 = 10_000_000;
+/// 滑点上限（基点）
+const MAX_SLIPPAGE_BPS: u64 = 500;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+
+    let endpoint = std::env::var("GRPC_ENDPOINT")
+        .unwrap_or_else(|_| "https://solana-yellowstone-grpc.publicnode.com:443".to_string());
+    let token = std::env::var("GRPC_TOKEN").ok();
+    let wallet: Pubkey = match std::env::var("SNIPER_WALLET") {
+        Ok(key) => key.parse()?,
+        Err(_) => Pubkey::new_unique(),
+    };
+
+    // 无序模式：事件解析完立即回调
+    let config = ClientConfig { order_mode: OrderMode::Unordered, ..Default::default() };
+    let grpc = YellowstoneGrpc::new_with_config(endpoint, token, config)?;
+
+    let (tx, rx) = mpsc::channel::<(Pubkey, Instruction, u64)>();
+    std::thread::spawn(move || {
+        for (mint, ix, decided_us) in rx {
+            // 在这里签名并发送交易（如通过 Jito / 多个 RPC 并发）
+            println!(
+                "buy {} with {} accounts, decided in {}μs after receive",
+                mint,
+                ix.accounts.len(),
+                decided_us
+            );
+        }
+    });
+
+    let protocols = vec![Protocol::PumpFun];
+    let event_filter =
+        EventTypeFilter::include_only(vec![EventType::PumpFunCreate, EventType::PumpFunTrade]);
+    let mut pools = PoolStateTracker::new();
+    let mut launched: HashSet<Pubkey> = HashSet::new();
+
+    grpc.subscribe_dex_events_direct(
+        vec![TransactionFilter::for_protocols(&protocols)],
+        vec![AccountFilter::for_protocols(&protocols)],
+        Some(event_filter),
+        move |event: DexEvent| {
+            pools.observe(&event);
+            let trade = match &event {
+                DexEvent::PumpFunCreate(e) => {
+                    launched.insert(e.mint);
+                    return;
+                }
+                DexEvent::PumpFunTrade(e) | DexEvent::PumpFunBuy(e) => e,
+                _ => return,
+            };
+            // 只在新币的第一笔成交后出手
+            if !launched.remove(&trade.mint) {
+                return;
+            }
+
+            let pool = event.pool().unwrap_or(trade.mint);
+            // bonding curve 储备为 (token, sol)，SOL → token 为 BToA
+            let Some(quote) = pools.quote(&pool, BUY_LAMPORTS, SwapDirection::BToA) else {
+                return;
+            };
+            let min_tokens = quote.amount_out * (10_000 - MAX_SLIPPAGE_BPS) / 10_000;
+            if let Some(ix) = trade.instruction_for(wallet, min_tokens, BUY_LAMPORTS) {
+                let decided_us = now_micros() - trade.metadata.grpc_recv_us;
+                let _ = tx.send((trade.mint, ix, decided_us as u64));
+            }
+        },
+    )
+    .await?;
+
+    println!("Waiting for new PumpFun launches, press Ctrl+C to stop");
+    tokio::signal::ctrl_c().await?;
+    grpc.stop().await;
+    Ok(())
+}
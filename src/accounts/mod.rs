@@ -1,9 +1,14 @@
+//! 账户解析器
+
+#![cfg_attr(feature = "forbid-unsafe", forbid(unsafe_code))]
+
 pub mod nonce;
 pub mod program_ids;
 pub mod pumpswap;
+pub mod raydium_amm;
 pub mod token;
 pub mod utils;
-use crate::core::events::EventMetadata;
+use crate::core::events::{AccountClosedEvent, EventMetadata};
 use crate::grpc::EventTypeFilter;
 use crate::DexEvent;
 pub use nonce::parse_nonce_account;
@@ -11,6 +16,10 @@ use program_ids::*;
 pub use pumpswap::{
     parse_global_config as parse_pumpswap_global_config, parse_pool as parse_pumpswap_pool,
 };
+pub use raydium_amm::{
+    parse_amm_info as parse_raydium_amm_v4_amm_info, parse_open_orders as parse_serum_open_orders,
+    parse_target_orders as parse_raydium_amm_v4_target_orders,
+};
 pub use token::parse_token_account;
 pub use token::AccountData;
 pub use utils::*;
@@ -20,10 +29,6 @@ pub fn parse_account_unified(
     metadata: EventMetadata,
     event_type_filter: Option<&EventTypeFilter>,
 ) -> Option<DexEvent> {
-    if account.data.is_empty() {
-        return None;
-    }
-
     // Early filtering based on event type filter
     if let Some(filter) = event_type_filter {
         if let Some(ref include_only) = filter.include_only {
@@ -36,6 +41,10 @@ pub fn parse_account_unified(
                         | EventType::NonceAccount
                         | EventType::AccountPumpSwapGlobalConfig
                         | EventType::AccountPumpSwapPool
+                        | EventType::AccountClosed
+                        | EventType::AccountRaydiumAmmV4AmmInfo
+                        | EventType::AccountRaydiumAmmV4TargetOrders
+                        | EventType::AccountSerumOpenOrders
                 )
             });
             if !should_parse {
@@ -44,18 +53,46 @@ pub fn parse_account_unified(
         }
     }
 
+    // lamports 为 0 表示账户已被关闭，数据通常也已清空
+    if account.lamports == 0 {
+        if let Some(filter) = event_type_filter {
+            if !filter.should_include(crate::grpc::EventType::AccountClosed) {
+                return None;
+            }
+        }
+        return Some(DexEvent::AccountClosed(AccountClosedEvent {
+            metadata,
+            pubkey: account.pubkey,
+            owner: account.owner,
+        }));
+    }
+    if account.data.is_empty() {
+        return None;
+    }
+
     if account.owner == PUMPSWAP_PROGRAM_ID {
         if let Some(filter) = event_type_filter {
             if filter.should_include(crate::grpc::EventType::AccountPumpSwapGlobalConfig)
                 || filter.should_include(crate::grpc::EventType::AccountPumpSwapPool)
             {
-                let event = parse_pumpswap_account(account, metadata.clone());
+                let event = parse_pumpswap_account(account, metadata);
                 if event.is_some() {
                     return event;
                 }
             }
         }
     }
+    if account.owner == RAYDIUM_AMM_V4_PROGRAM_ID {
+        return parse_raydium_amm_v4_account(account, metadata, event_type_filter);
+    }
+    if account.owner == OPENBOOK_PROGRAM_ID || account.owner == SERUM_DEX_V3_PROGRAM_ID {
+        if event_type_filter
+            .is_some_and(|f| !f.should_include(crate::grpc::EventType::AccountSerumOpenOrders))
+        {
+            return None;
+        }
+        return raydium_amm::parse_open_orders(account, metadata);
+    }
     if nonce::is_nonce_account(&account.data) {
         // Check filter for NonceAccount specifically
         if let Some(filter) = event_type_filter {
@@ -85,3 +122,57 @@ fn parse_pumpswap_account(account: &AccountData, metadata: EventMetadata) -> Opt
     }
     None
 }
+
+fn parse_raydium_amm_v4_account(
+    account: &AccountData,
+    metadata: EventMetadata,
+    event_type_filter: Option<&EventTypeFilter>,
+) -> Option<DexEvent> {
+    use crate::grpc::EventType;
+    // 按账户大小区分类型
+    let (event_type, parse): (_, fn(&AccountData, EventMetadata) -> Option<DexEvent>) =
+        match account.data.len() {
+            raydium_amm::AMM_INFO_SIZE => {
+                (EventType::AccountRaydiumAmmV4AmmInfo, raydium_amm::parse_amm_info)
+            }
+            raydium_amm::TARGET_ORDERS_SIZE => {
+                (EventType::AccountRaydiumAmmV4TargetOrders, raydium_amm::parse_target_orders)
+            }
+            _ => return None,
+        };
+    if event_type_filter.is_some_and(|f| !f.should_include(event_type)) {
+        return None;
+    }
+    parse(account, metadata)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grpc::EventType;
+    use solana_sdk::pubkey::Pubkey;
+
+    #[test]
+    fn test_closed_account_emits_account_closed() {
+        let account = AccountData {
+            pubkey: Pubkey::new_unique(),
+            executable: false,
+            lamports: 0,
+            owner: Pubkey::default(),
+            rent_epoch: 0,
+            data: Vec::new(),
+        };
+        let event = parse_account_unified(&account, EventMetadata::default(), None);
+        let Some(DexEvent::AccountClosed(closed)) = event else { panic!("expected AccountClosed") };
+        assert_eq!((closed.pubkey, closed.owner), (account.pubkey, account.owner));
+
+        let only_tokens = EventTypeFilter::include_only(vec![EventType::TokenAccount]);
+        assert!(
+            parse_account_unified(&account, EventMetadata::default(), Some(&only_tokens)).is_none()
+        );
+
+        // 有余额但无数据的普通账户不视为关闭
+        let wallet = AccountData { lamports: 1_000_000, ..account };
+        assert!(parse_account_unified(&wallet, EventMetadata::default(), None).is_none());
+    }
+}
@@ -10,6 +10,16 @@ use solana_sdk::pubkey::Pubkey;
 /// PumpSwap 程序 ID
 pub const PUMPSWAP_PROGRAM_ID: Pubkey = pubkey!("pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA");
 
+/// Raydium AMM V4 程序 ID
+pub const RAYDIUM_AMM_V4_PROGRAM_ID: Pubkey =
+    pubkey!("675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8");
+
+/// OpenBook 程序 ID（AMM V4 池子关联的订单簿市场）
+pub const OPENBOOK_PROGRAM_ID: Pubkey = pubkey!("srmqPvymJeFKQ4zGQed1GFppgkRHL9kaELCbyksJtPX");
+
+/// Serum DEX V3 程序 ID（早期 AMM V4 池子关联的订单簿市场）
+pub const SERUM_DEX_V3_PROGRAM_ID: Pubkey = pubkey!("9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin");
+
 // ==================== 系统程序 ID ====================
 
 /// SPL Token 程序 ID
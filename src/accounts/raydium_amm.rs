@@ -0,0 +1,216 @@
+//! Raydium AMM V4 账户解析
+//!
+//! 提供 AmmInfo、TargetOrders 以及池子关联的 Serum / OpenBook OpenOrders 账户的解析。
+//! AMM V4 池子的实际储备 = 金库余额 + OpenOrders 总额 − need_take_pnl，
+//! 见 [`crate::analytics::TvlTracker`]。
+
+use crate::core::events::{
+    EventMetadata, RaydiumAmmAmmInfoAccountEvent, RaydiumAmmInfo, RaydiumAmmTargetOrders,
+    RaydiumAmmTargetOrdersAccountEvent, SerumOpenOrders, SerumOpenOrdersAccountEvent,
+};
+use crate::DexEvent;
+
+use super::token::AccountData;
+use super::utils::*;
+
+/// AmmInfo 账户大小（无 discriminator）
+pub const AMM_INFO_SIZE: usize = 752;
+
+/// TargetOrders 账户大小（无 discriminator）
+pub const TARGET_ORDERS_SIZE: usize = 2208;
+
+/// OpenOrders 账户大小（含 5 字节 `serum` 头与 7 字节 `padding` 尾）
+pub const OPEN_ORDERS_SIZE: usize = 3228;
+
+/// Serum 账户头
+const SERUM_HEAD: &[u8] = b"serum";
+
+/// `AccountFlag::Initialized | AccountFlag::OpenOrders`
+const OPEN_ORDERS_FLAGS: u64 = 1 | 4;
+
+/// 解析 Raydium AMM V4 AmmInfo 账户
+///
+/// # Returns
+/// 返回 `Some(DexEvent::RaydiumAmmV4AmmInfoAccount)` 如果解析成功，否则返回 `None`
+pub fn parse_amm_info(account: &AccountData, metadata: EventMetadata) -> Option<DexEvent> {
+    if account.data.len() != AMM_INFO_SIZE {
+        return None;
+    }
+    let data = &account.data[..];
+
+    let amm_info = RaydiumAmmInfo {
+        status: read_u64_le(data, 0)?,
+        nonce: read_u64_le(data, 8)?,
+        order_num: read_u64_le(data, 16)?,
+        depth: read_u64_le(data, 24)?,
+        coin_decimals: read_u64_le(data, 32)?,
+        pc_decimals: read_u64_le(data, 40)?,
+        state: read_u64_le(data, 48)?,
+        reset_flag: read_u64_le(data, 56)?,
+        min_size: read_u64_le(data, 64)?,
+        vol_max_cut_ratio: read_u64_le(data, 72)?,
+        amount_wave_ratio: read_u64_le(data, 80)?,
+        coin_lot_size: read_u64_le(data, 88)?,
+        pc_lot_size: read_u64_le(data, 96)?,
+        min_price_multiplier: read_u64_le(data, 104)?,
+        max_price_multiplier: read_u64_le(data, 112)?,
+        sys_decimal_value: read_u64_le(data, 120)?,
+        // Fees: min_separate(2), trade_fee(2), pnl(2), swap_fee(2)
+        trade_fee_numerator: read_u64_le(data, 144)?,
+        trade_fee_denominator: read_u64_le(data, 152)?,
+        swap_fee_numerator: read_u64_le(data, 176)?,
+        swap_fee_denominator: read_u64_le(data, 184)?,
+        // StateData
+        need_take_pnl_coin: read_u64_le(data, 192)?,
+        need_take_pnl_pc: read_u64_le(data, 200)?,
+        pool_open_time: read_u64_le(data, 224)?,
+        coin_vault: read_pubkey(data, 336)?,
+        pc_vault: read_pubkey(data, 368)?,
+        coin_vault_mint: read_pubkey(data, 400)?,
+        pc_vault_mint: read_pubkey(data, 432)?,
+        lp_mint: read_pubkey(data, 464)?,
+        open_orders: read_pubkey(data, 496)?,
+        market: read_pubkey(data, 528)?,
+        market_program: read_pubkey(data, 560)?,
+        target_orders: read_pubkey(data, 592)?,
+        amm_owner: read_pubkey(data, 688)?,
+        lp_amount: read_u64_le(data, 720)?,
+    };
+
+    Some(DexEvent::RaydiumAmmV4AmmInfoAccount(RaydiumAmmAmmInfoAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        amm_info,
+    }))
+}
+
+/// 解析 Raydium AMM V4 TargetOrders 账户
+///
+/// # Returns
+/// 返回 `Some(DexEvent::RaydiumAmmV4TargetOrdersAccount)` 如果解析成功，否则返回 `None`
+pub fn parse_target_orders(account: &AccountData, metadata: EventMetadata) -> Option<DexEvent> {
+    if account.data.len() != TARGET_ORDERS_SIZE {
+        return None;
+    }
+    let data = &account.data[..];
+
+    // owner(32) + buy_orders(50 * 16) + padding(64) 之后为 10 个 u128
+    let target_orders = RaydiumAmmTargetOrders {
+        amm: read_pubkey(data, 0)?,
+        target_x: read_u128_le(data, 896)?,
+        target_y: read_u128_le(data, 912)?,
+        placed_x: read_u128_le(data, 992)?,
+        placed_y: read_u128_le(data, 1008)?,
+        calc_pnl_x: read_u128_le(data, 1024)?,
+        calc_pnl_y: read_u128_le(data, 1040)?,
+        valid_buy_order_num: read_u64_le(data, 2096)?,
+        valid_sell_order_num: read_u64_le(data, 2104)?,
+    };
+
+    Some(DexEvent::RaydiumAmmV4TargetOrdersAccount(RaydiumAmmTargetOrdersAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        target_orders,
+    }))
+}
+
+/// 解析 Serum / OpenBook OpenOrders 账户
+///
+/// # Returns
+/// 返回 `Some(DexEvent::SerumOpenOrdersAccount)` 如果解析成功，否则返回 `None`
+pub fn parse_open_orders(account: &AccountData, metadata: EventMetadata) -> Option<DexEvent> {
+    if !is_open_orders_account(&account.data) {
+        return None;
+    }
+    let data = &account.data[..];
+
+    let open_orders = SerumOpenOrders {
+        market: read_pubkey(data, 13)?,
+        open_orders_owner: read_pubkey(data, 45)?,
+        native_coin_free: read_u64_le(data, 77)?,
+        native_coin_total: read_u64_le(data, 85)?,
+        native_pc_free: read_u64_le(data, 93)?,
+        native_pc_total: read_u64_le(data, 101)?,
+        referrer_rebates_accrued: read_u64_le(data, 3213)?,
+    };
+
+    Some(DexEvent::SerumOpenOrdersAccount(SerumOpenOrdersAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        open_orders,
+    }))
+}
+
+/// 检查账户是否是 OpenOrders 账户（Market、EventQueue 等同程序账户返回 false）
+pub fn is_open_orders_account(data: &[u8]) -> bool {
+    data.len() == OPEN_ORDERS_SIZE
+        && has_discriminator(data, SERUM_HEAD)
+        && read_u64_le(data, 5).is_some_and(|flags| flags & OPEN_ORDERS_FLAGS == OPEN_ORDERS_FLAGS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts::program_ids::{OPENBOOK_PROGRAM_ID, RAYDIUM_AMM_V4_PROGRAM_ID};
+    use solana_sdk::pubkey::Pubkey;
+
+    fn account(owner: Pubkey, data: Vec<u8>) -> AccountData {
+        AccountData {
+            pubkey: Pubkey::new_unique(),
+            executable: false,
+            lamports: 1_000_000,
+            owner,
+            rent_epoch: 0,
+            data,
+        }
+    }
+
+    #[test]
+    fn test_parse_amm_v4_accounts() {
+        let (coin_vault, open_orders) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut data = vec![0u8; AMM_INFO_SIZE];
+        data[32..40].copy_from_slice(&9u64.to_le_bytes());
+        data[192..200].copy_from_slice(&120u64.to_le_bytes());
+        data[336..368].copy_from_slice(coin_vault.as_ref());
+        data[496..528].copy_from_slice(open_orders.as_ref());
+        let amm = account(RAYDIUM_AMM_V4_PROGRAM_ID, data);
+        let Some(DexEvent::RaydiumAmmV4AmmInfoAccount(e)) =
+            parse_amm_info(&amm, EventMetadata::default())
+        else {
+            panic!("expected AmmInfo")
+        };
+        assert_eq!((e.amm_info.coin_decimals, e.amm_info.need_take_pnl_coin), (9, 120));
+        assert_eq!((e.amm_info.coin_vault, e.amm_info.open_orders), (coin_vault, open_orders));
+
+        let mut data = vec![0u8; OPEN_ORDERS_SIZE];
+        data[..5].copy_from_slice(SERUM_HEAD);
+        data[5..13].copy_from_slice(&5u64.to_le_bytes());
+        data[85..93].copy_from_slice(&700u64.to_le_bytes());
+        data[101..109].copy_from_slice(&300u64.to_le_bytes());
+        let oo = account(OPENBOOK_PROGRAM_ID, data);
+        let Some(DexEvent::SerumOpenOrdersAccount(e)) =
+            parse_open_orders(&oo, EventMetadata::default())
+        else {
+            panic!("expected OpenOrders")
+        };
+        assert_eq!((e.open_orders.native_coin_total, e.open_orders.native_pc_total), (700, 300));
+
+        // Market 账户（flags 不含 OpenOrders）不解析
+        let mut market = oo.clone();
+        market.data[5..13].copy_from_slice(&3u64.to_le_bytes());
+        assert!(parse_open_orders(&market, EventMetadata::default()).is_none());
+        assert!(parse_target_orders(&amm, EventMetadata::default()).is_none());
+    }
+}
@@ -42,12 +42,12 @@ pub struct AccountData {
 pub fn parse_token_account(account: &AccountData, metadata: EventMetadata) -> Option<DexEvent> {
     // 快速路径：尝试零拷贝解析
     if account.data.len() <= 100 {
-        if let Some(event) = parse_mint_fast(account, metadata.clone()) {
+        if let Some(event) = parse_mint_fast(account, metadata) {
             return Some(event);
         }
     }
 
-    if let Some(event) = parse_token_fast(account, metadata.clone()) {
+    if let Some(event) = parse_token_fast(account, metadata) {
         return Some(event);
     }
 
@@ -92,6 +92,7 @@ fn parse_mint_fast(account: &AccountData, metadata: EventMetadata) -> Option<Dex
 #[inline]
 fn parse_token_fast(account: &AccountData, metadata: EventMetadata) -> Option<DexEvent> {
     const TOKEN_ACCOUNT_SIZE: usize = 165;
+    const OWNER_OFFSET: usize = 32;
     const AMOUNT_OFFSET: usize = 64;
 
     if account.data.len() < AMOUNT_OFFSET + 8 {
@@ -105,6 +106,10 @@ fn parse_token_fast(account: &AccountData, metadata: EventMetadata) -> Option<De
 
     let amount_bytes: [u8; 8] = account.data[AMOUNT_OFFSET..AMOUNT_OFFSET + 8].try_into().ok()?;
     let amount = u64::from_le_bytes(amount_bytes);
+    let mint = Pubkey::new_from_array(account.data[..32].try_into().ok()?);
+    // 持有人在账户数据中；account.owner 是 token program
+    let token_owner =
+        Pubkey::new_from_array(account.data[OWNER_OFFSET..AMOUNT_OFFSET].try_into().ok()?);
 
     let event = TokenAccountEvent {
         metadata,
@@ -114,7 +119,8 @@ fn parse_token_fast(account: &AccountData, metadata: EventMetadata) -> Option<De
         owner: account.owner,
         rent_epoch: account.rent_epoch,
         amount: Some(amount),
-        token_owner: account.owner,
+        token_owner,
+        mint,
     };
 
     Some(DexEvent::TokenAccount(event))
@@ -172,6 +178,7 @@ fn parse_token_with_extensions(account: &AccountData, metadata: EventMetadata) -
                 rent_epoch: account.rent_epoch,
                 amount: Some(account_state.base.amount),
                 token_owner,
+                mint: Pubkey::new_from_array(account_state.base.mint.to_bytes()),
             };
             return Some(DexEvent::TokenAccount(event));
         }
@@ -190,6 +197,7 @@ fn parse_token_with_extensions(account: &AccountData, metadata: EventMetadata) -
             rent_epoch: account.rent_epoch,
             amount: Some(token_account.amount),
             token_owner,
+            mint: Pubkey::new_from_array(token_account.mint.to_bytes()),
         };
         return Some(DexEvent::TokenAccount(event));
     }
@@ -253,4 +261,34 @@ mod tests {
             assert_eq!(token_account.amount, Some(5000));
         }
     }
+
+    #[test]
+    fn test_parse_packed_spl_token_account() {
+        let (mint, holder) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let state = Account {
+            mint: mint.to_bytes().into(),
+            owner: holder.to_bytes().into(),
+            amount: 42,
+            state: spl_token::state::AccountState::Initialized,
+            ..Default::default()
+        };
+        let mut data = vec![0u8; Account::LEN];
+        Account::pack(state, &mut data).unwrap();
+        let account = AccountData {
+            pubkey: Pubkey::new_unique(),
+            executable: false,
+            lamports: 2039280,
+            owner: Pubkey::new_from_array(spl_token::ID.to_bytes()),
+            rent_epoch: 0,
+            data,
+        };
+
+        let Some(DexEvent::TokenAccount(e)) =
+            parse_token_account(&account, EventMetadata::default())
+        else {
+            panic!("not a token account");
+        };
+        assert_eq!((e.mint, e.token_owner, e.amount), (mint, holder, Some(42)));
+        assert_eq!(e.owner, account.owner);
+    }
 }
@@ -25,6 +25,17 @@ pub fn read_u64_le(data: &[u8], offset: usize) -> Option<u64> {
     ))
 }
 
+/// 从字节数组中读取 u128（小端序）
+#[inline]
+pub fn read_u128_le(data: &[u8], offset: usize) -> Option<u128> {
+    if data.len() < offset + 16 {
+        return None;
+    }
+    Some(u128::from_le_bytes(
+        data[offset..offset + 16].try_into().ok()?,
+    ))
+}
+
 /// 从字节数组中读取 u16（小端序）
 #[inline]
 pub fn read_u16_le(data: &[u8], offset: usize) -> Option<u16> {
@@ -0,0 +1,155 @@
+//! 跨池套利机会检测
+//!
+//! 按代币对维护各池子最近一次成交价，同一代币对的最低价池与最高价池价差超过阈值时
+//! 输出 [`ArbOpportunity`]。价格来自实际成交（含手续费影响），只作为机会提示，
+//! 执行前仍需按池子状态重新报价。
+
+use super::pool_registry::PoolRegistry;
+use super::swap::normalize_swap;
+use crate::core::events::DexEvent;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+
+/// 默认报价有效期（slot），约 1 分钟
+const DEFAULT_MAX_QUOTE_AGE_SLOTS: u64 = 150;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArbOpportunity {
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    /// 最低价池子（买入 base）
+    pub buy_pool: Pubkey,
+    pub buy_price: f64,
+    /// 最高价池子（卖出 base）
+    pub sell_pool: Pubkey,
+    pub sell_price: f64,
+    pub spread_bps: u64,
+    /// 触发检测的成交所在 slot
+    pub slot: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PoolQuote {
+    /// base 以 quote 计价的成交价（原始单位）
+    price: f64,
+    slot: u64,
+}
+
+#[derive(Debug)]
+pub struct ArbDetector {
+    threshold_bps: u64,
+    max_quote_age_slots: u64,
+    registry: PoolRegistry,
+    /// (base_mint, quote_mint) → pool → 最近成交价；base 为字节序较小的 mint
+    quotes: HashMap<(Pubkey, Pubkey), HashMap<Pubkey, PoolQuote>>,
+}
+
+impl ArbDetector {
+    pub fn new(threshold_bps: u64) -> Self {
+        Self {
+            threshold_bps,
+            max_quote_age_slots: DEFAULT_MAX_QUOTE_AGE_SLOTS,
+            registry: PoolRegistry::new(),
+            quotes: HashMap::new(),
+        }
+    }
+
+    /// 超过该 slot 数未更新的报价不参与比较
+    pub fn with_max_quote_age(mut self, slots: u64) -> Self {
+        self.max_quote_age_slots = slots;
+        self
+    }
+
+    /// 用于预先注册事件中不含 mint 的池子
+    pub fn registry_mut(&mut self) -> &mut PoolRegistry {
+        &mut self.registry
+    }
+
+    /// 处理一个事件，价差超过阈值时返回套利机会
+    pub fn observe(&mut self, event: &DexEvent) -> Option<ArbOpportunity> {
+        self.registry.observe(event);
+        let swap = normalize_swap(event, &self.registry)?;
+
+        let (base_mint, quote_mint) = if swap.input_mint < swap.output_mint {
+            (swap.input_mint, swap.output_mint)
+        } else {
+            (swap.output_mint, swap.input_mint)
+        };
+        let price = swap.price_of(&base_mint)?;
+
+        let max_age = self.max_quote_age_slots;
+        let pools = self.quotes.entry((base_mint, quote_mint)).or_default();
+        pools.insert(swap.pool, PoolQuote { price, slot: swap.slot });
+        pools.retain(|_, q| swap.slot.saturating_sub(q.slot) <= max_age);
+
+        let (buy_pool, buy) = pools.iter().min_by(|a, b| a.1.price.total_cmp(&b.1.price))?;
+        let (sell_pool, sell) = pools.iter().max_by(|a, b| a.1.price.total_cmp(&b.1.price))?;
+        if buy_pool == sell_pool {
+            return None;
+        }
+
+        let spread_bps = ((sell.price - buy.price) / buy.price * 10_000.0) as u64;
+        (spread_bps >= self.threshold_bps).then_some(ArbOpportunity {
+            base_mint,
+            quote_mint,
+            buy_pool: *buy_pool,
+            buy_price: buy.price,
+            sell_pool: *sell_pool,
+            sell_price: sell.price,
+            spread_bps,
+            slot: swap.slot,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::*;
+
+    fn pumpswap_buy(
+        pool: Pubkey,
+        base: Pubkey,
+        quote: Pubkey,
+        quote_in: u64,
+        slot: u64,
+    ) -> DexEvent {
+        DexEvent::PumpSwapBuy(PumpSwapBuyEvent {
+            metadata: EventMetadata { slot, ..Default::default() },
+            pool,
+            base_mint: base,
+            quote_mint: quote,
+            base_amount_out: 1_000,
+            user_quote_amount_in: quote_in,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_detects_spread_across_pools() {
+        let (base, quote) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (pool_a, pool_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut detector = ArbDetector::new(100);
+
+        assert!(detector.observe(&pumpswap_buy(pool_a, base, quote, 1_000, 1)).is_none());
+        // 0.5% 价差低于阈值
+        assert!(detector.observe(&pumpswap_buy(pool_b, base, quote, 1_005, 2)).is_none());
+
+        let opp = detector.observe(&pumpswap_buy(pool_b, base, quote, 1_020, 3)).unwrap();
+        assert_eq!(opp.buy_pool, pool_a);
+        assert_eq!(opp.sell_pool, pool_b);
+        assert_eq!(opp.slot, 3);
+        assert!((195..=205).contains(&opp.spread_bps), "{}", opp.spread_bps);
+    }
+
+    #[test]
+    fn test_ignores_stale_quotes() {
+        let (base, quote) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut detector = ArbDetector::new(100).with_max_quote_age(10);
+
+        detector.observe(&pumpswap_buy(Pubkey::new_unique(), base, quote, 1_000, 1));
+        assert!(detector
+            .observe(&pumpswap_buy(Pubkey::new_unique(), base, quote, 2_000, 100))
+            .is_none());
+    }
+}
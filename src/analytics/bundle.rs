@@ -0,0 +1,175 @@
+//! 同区块捆绑交易检测
+//!
+//! 同一 slot 内 `tx_index` 相邻、操作同一池子、来自多个钱包的一组交易，很可能是
+//! 一个 Jito bundle。检测器按 slot 缓存 swap，slot 结束（出现更新的 slot）后输出
+//! [`BundleSuspected`]，用于区分自然成交和捆绑成交。
+
+use super::pool_registry::PoolRegistry;
+use super::swap::normalize_swap;
+use crate::core::events::DexEvent;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// 疑似捆绑交易组
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BundleSuspected {
+    pub slot: u64,
+    pub pool: Pubkey,
+    /// 按 tx_index 升序
+    pub tx_indices: Vec<u64>,
+    pub signatures: Vec<Signature>,
+    /// 去重后的钱包
+    pub traders: Vec<Pubkey>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SlotSwap {
+    tx_index: u64,
+    signature: Signature,
+    trader: Pubkey,
+}
+
+#[derive(Debug)]
+pub struct BundleDetector {
+    registry: PoolRegistry,
+    min_txs: usize,
+    min_wallets: usize,
+    max_index_gap: u64,
+    /// slot → 池子 → swap
+    slots: BTreeMap<u64, HashMap<Pubkey, Vec<SlotSwap>>>,
+}
+
+impl Default for BundleDetector {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+impl BundleDetector {
+    /// `min_txs`：一组至少包含的交易数
+    pub fn new(min_txs: usize) -> Self {
+        Self {
+            registry: PoolRegistry::new(),
+            min_txs,
+            min_wallets: 2,
+            max_index_gap: 1,
+            slots: BTreeMap::new(),
+        }
+    }
+
+    /// 一组至少来自多少个不同钱包（默认 2）
+    pub fn with_min_wallets(mut self, min_wallets: usize) -> Self {
+        self.min_wallets = min_wallets;
+        self
+    }
+
+    /// 视为相邻的最大 tx_index 间隔（默认 1，即严格连续）
+    pub fn with_max_index_gap(mut self, gap: u64) -> Self {
+        self.max_index_gap = gap.max(1);
+        self
+    }
+
+    pub fn registry_mut(&mut self) -> &mut PoolRegistry {
+        &mut self.registry
+    }
+
+    /// 处理一个事件；出现更新的 slot 时输出之前各 slot 的检测结果
+    pub fn observe(&mut self, event: &DexEvent) -> Vec<BundleSuspected> {
+        self.registry.observe(event);
+        let Some(swap) = normalize_swap(event, &self.registry) else { return Vec::new() };
+        let Some(trader) = swap.trader else { return Vec::new() };
+        let metadata = event.metadata();
+
+        let swaps = self.slots.entry(metadata.slot).or_default().entry(swap.pool).or_default();
+        // 同一交易内的多个 swap 事件只记一次
+        if !swaps.iter().any(|s| s.tx_index == metadata.tx_index) {
+            swaps.push(SlotSwap {
+                tx_index: metadata.tx_index,
+                signature: metadata.signature,
+                trader,
+            });
+        }
+
+        let settled = self.slots.split_off(&metadata.slot);
+        let finished = std::mem::replace(&mut self.slots, settled);
+        finished.into_iter().flat_map(|(slot, pools)| self.detect(slot, pools)).collect()
+    }
+
+    /// 输出所有缓存 slot 的检测结果（流结束时调用）
+    pub fn flush(&mut self) -> Vec<BundleSuspected> {
+        std::mem::take(&mut self.slots)
+            .into_iter()
+            .flat_map(|(slot, pools)| self.detect(slot, pools))
+            .collect()
+    }
+
+    fn detect(&self, slot: u64, pools: HashMap<Pubkey, Vec<SlotSwap>>) -> Vec<BundleSuspected> {
+        let mut found = Vec::new();
+        for (pool, mut swaps) in pools {
+            swaps.sort_unstable_by_key(|s| s.tx_index);
+            for run in swaps.chunk_by(|a, b| b.tx_index - a.tx_index <= self.max_index_gap) {
+                let traders: HashSet<Pubkey> = run.iter().map(|s| s.trader).collect();
+                if run.len() < self.min_txs || traders.len() < self.min_wallets {
+                    continue;
+                }
+                let mut traders: Vec<Pubkey> = traders.into_iter().collect();
+                traders.sort_unstable();
+                found.push(BundleSuspected {
+                    slot,
+                    pool,
+                    tx_indices: run.iter().map(|s| s.tx_index).collect(),
+                    signatures: run.iter().map(|s| s.signature).collect(),
+                    traders,
+                });
+            }
+        }
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::{EventMetadata, PumpFunTradeEvent};
+
+    fn buy(curve: Pubkey, user: Pubkey, slot: u64, tx_index: u64) -> DexEvent {
+        DexEvent::PumpFunBuy(PumpFunTradeEvent {
+            metadata: EventMetadata { slot, tx_index, ..Default::default() },
+            mint: Pubkey::new_unique(),
+            bonding_curve: curve,
+            user,
+            is_buy: true,
+            sol_amount: 1,
+            token_amount: 1,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_detect_adjacent_multi_wallet_group() {
+        let (curve, other) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let wallets: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let mut detector = BundleDetector::new(3);
+
+        for (i, w) in wallets.iter().enumerate() {
+            assert!(detector.observe(&buy(curve, *w, 10, 5 + i as u64)).is_empty());
+        }
+        // 不相邻的交易和其他池子的交易不计入
+        detector.observe(&buy(curve, wallets[0], 10, 20));
+        detector.observe(&buy(other, wallets[1], 10, 8));
+        // 同一钱包的连续交易不视为捆绑
+        for i in 30..33 {
+            detector.observe(&buy(other, wallets[2], 10, i));
+        }
+
+        let found = detector.observe(&buy(curve, wallets[0], 11, 0));
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].slot, 10);
+        assert_eq!(found[0].pool, curve);
+        assert_eq!(found[0].tx_indices, vec![5, 6, 7]);
+        assert_eq!(found[0].traders.len(), 3);
+
+        assert!(detector.flush().is_empty());
+    }
+}
@@ -0,0 +1,203 @@
+//! 亚秒级事件时间估计
+//!
+//! `block_time` 只有秒级精度且滞后于实际出块。[`EventClock`] 用带 `block_time` 的事件
+//! 拟合 slot → 时间的线性时间线（slot 时长 + 截距），再按端点校准 gRPC 接收时间相对
+//! slot 时间线的平均延迟，得到 [`EventClock::estimated_event_time_us`]：
+//!
+//! - 实时事件：接收时间减去该端点的平均延迟，限制在所属 slot 的时间范围内
+//! - 重放 / 无接收时间的事件：所属 slot 的中点
+//!
+//! 估计值与 `block_time` 使用同一时间基准（继承其整体滞后），适合需要优于秒级、
+//! 且同一 slot 内保持接收顺序的研究数据集；多个端点的数据可共用一个实例。
+
+use crate::core::events::{DexEvent, EventMetadata};
+use std::collections::HashMap;
+
+/// [`EventClock`] 配置
+#[derive(Debug, Clone, Copy)]
+pub struct EventClockConfig {
+    /// 初始 slot 时长（微秒），样本跨度不足时使用
+    pub slot_duration_us: i64,
+    /// 拟合 slot 时长所需的最小 slot 跨度，越大受秒级截断的影响越小
+    pub min_calibration_slots: u64,
+    /// 截距与端点延迟的指数平滑系数，取值 (0, 1]
+    pub smoothing: f64,
+}
+
+impl Default for EventClockConfig {
+    fn default() -> Self {
+        Self { slot_duration_us: 400_000, min_calibration_slots: 150, smoothing: 0.05 }
+    }
+}
+
+/// slot → 时间的线性模型
+#[derive(Debug, Clone, Copy)]
+struct Timeline {
+    anchor_slot: u64,
+    anchor_block_time_us: i64,
+    /// anchor_slot 的起始时间
+    intercept_us: f64,
+    latest_slot: u64,
+}
+
+/// 事件时间估计模型，按 slot 顺序调用 [`Self::observe`] 校准
+#[derive(Debug)]
+pub struct EventClock {
+    config: EventClockConfig,
+    slot_duration_us: f64,
+    timeline: Option<Timeline>,
+    /// 端点 -> 接收时间相对 slot 中点的平均延迟
+    endpoint_offsets: HashMap<String, f64>,
+}
+
+impl Default for EventClock {
+    fn default() -> Self {
+        Self::new(EventClockConfig::default())
+    }
+}
+
+impl EventClock {
+    pub fn new(config: EventClockConfig) -> Self {
+        Self {
+            config,
+            slot_duration_us: config.slot_duration_us as f64,
+            timeline: None,
+            endpoint_offsets: HashMap::new(),
+        }
+    }
+
+    /// 当前估计的 slot 时长（微秒）
+    pub fn slot_duration_us(&self) -> i64 {
+        self.slot_duration_us.round() as i64
+    }
+
+    /// 端点的平均接收延迟（微秒），尚未校准时为 None
+    pub fn endpoint_offset_us(&self, endpoint: &str) -> Option<i64> {
+        self.endpoint_offsets.get(endpoint).map(|offset| offset.round() as i64)
+    }
+
+    /// 用 `endpoint` 推送的事件校准时间线与该端点的延迟
+    pub fn observe(&mut self, endpoint: &str, event: &DexEvent) {
+        let metadata = event.metadata();
+        if metadata.block_time_us > 0 {
+            self.observe_block_time(metadata.slot, metadata.block_time_us);
+        }
+        if metadata.grpc_recv_us <= 0 || metadata.replayed {
+            return;
+        }
+        let Some(slot_mid) =
+            self.slot_start_us(metadata.slot).map(|start| start + self.slot_duration_us / 2.0)
+        else {
+            return;
+        };
+        let delay = metadata.grpc_recv_us as f64 - slot_mid;
+        let alpha = self.config.smoothing;
+        self.endpoint_offsets
+            .entry(endpoint.to_string())
+            .and_modify(|offset| *offset += alpha * (delay - *offset))
+            .or_insert(delay);
+    }
+
+    /// 估计事件发生时间（微秒），时间线尚未建立时为 None
+    pub fn estimated_event_time_us(&self, endpoint: &str, metadata: &EventMetadata) -> Option<i64> {
+        let start = self.slot_start_us(metadata.slot)?;
+        let mid = start + self.slot_duration_us / 2.0;
+        let estimate = match self.endpoint_offsets.get(endpoint) {
+            Some(offset) if metadata.grpc_recv_us > 0 && !metadata.replayed => {
+                (metadata.grpc_recv_us as f64 - offset).clamp(start, start + self.slot_duration_us)
+            }
+            _ => mid,
+        };
+        Some(estimate.round() as i64)
+    }
+
+    /// 每个 slot 只取一次样本，避免繁忙 slot 占据过高权重
+    fn observe_block_time(&mut self, slot: u64, block_time_us: i64) {
+        // block_time 向下取整到秒，平均低估半秒
+        let sample = block_time_us as f64 + 500_000.0;
+        let Some(timeline) = self.timeline.as_mut() else {
+            self.timeline = Some(Timeline {
+                anchor_slot: slot,
+                anchor_block_time_us: block_time_us,
+                intercept_us: sample,
+                latest_slot: slot,
+            });
+            return;
+        };
+        if slot <= timeline.latest_slot || slot < timeline.anchor_slot {
+            return;
+        }
+        timeline.latest_slot = slot;
+
+        let span = slot - timeline.anchor_slot;
+        if span >= self.config.min_calibration_slots {
+            self.slot_duration_us =
+                (block_time_us - timeline.anchor_block_time_us) as f64 / span as f64;
+        }
+        let projected = sample - self.slot_duration_us * span as f64;
+        timeline.intercept_us += self.config.smoothing * (projected - timeline.intercept_us);
+    }
+
+    fn slot_start_us(&self, slot: u64) -> Option<f64> {
+        let timeline = self.timeline?;
+        let span = slot as f64 - timeline.anchor_slot as f64;
+        Some(timeline.intercept_us + span * self.slot_duration_us)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::BlockMetaEvent;
+
+    const SLOT_US: i64 = 400_000;
+    const T0: i64 = 1_700_000_000_000_000;
+
+    /// 实际 slot 起始时间
+    fn slot_start(slot: u64) -> i64 {
+        T0 + (slot - 1_000) as i64 * SLOT_US
+    }
+
+    fn event(slot: u64, grpc_recv_us: i64) -> DexEvent {
+        DexEvent::BlockMeta(BlockMetaEvent {
+            metadata: EventMetadata {
+                slot,
+                block_time_us: slot_start(slot) / 1_000_000 * 1_000_000,
+                grpc_recv_us,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_estimated_event_time_per_endpoint() {
+        let mut clock = EventClock::default();
+        assert!(clock.estimated_event_time_us("a", &EventMetadata::default()).is_none());
+
+        // 端点 a 延迟 150ms，端点 b 延迟 900ms
+        for slot in 1_000..1_400 {
+            let happened = slot_start(slot) + 100_000;
+            clock.observe("a", &event(slot, happened + 150_000));
+            clock.observe("b", &event(slot, happened + 900_000));
+        }
+        assert!((clock.slot_duration_us() - SLOT_US).abs() < 5_000);
+        let offset_gap =
+            clock.endpoint_offset_us("b").unwrap() - clock.endpoint_offset_us("a").unwrap();
+        assert!((offset_gap - 750_000).abs() < 10_000, "{offset_gap}");
+
+        // 同一事件经两个端点的估计一致，且与实际时间相差不超过一个 slot
+        let happened = slot_start(1_400) + 300_000;
+        let from_a = event(1_400, happened + 150_000);
+        let from_b = event(1_400, happened + 900_000);
+        let a = clock.estimated_event_time_us("a", from_a.metadata()).unwrap();
+        let b = clock.estimated_event_time_us("b", from_b.metadata()).unwrap();
+        assert!((a - b).abs() < 10_000, "{a} {b}");
+        assert!((a - happened).abs() < SLOT_US, "{}", a - happened);
+
+        // 重放事件使用 slot 中点
+        let replayed = EventMetadata { replayed: true, ..*from_a.metadata() };
+        let mid = clock.estimated_event_time_us("a", &replayed).unwrap();
+        assert!((mid - (slot_start(1_400) + SLOT_US / 2)).abs() < SLOT_US);
+    }
+}
@@ -0,0 +1,319 @@
+//! 费率档位变更告警
+//!
+//! 订阅配置账户（PumpSwap GlobalConfig、Raydium CLMM / CPMM AmmConfig）的更新，
+//! 在交易费率或协议费率变化时输出 [`FeeTierChanged`]。每个配置账户首次出现时只记录基线，不告警。
+//!
+//! 各协议费率单位不同，统一换算为相对成交额的百万分之一（ppm）：
+//! - PumpSwap：基点 × 100，交易费率 = LP + 协议 + 创建者费率
+//! - Raydium：`trade_fee_rate` 已是 ppm；`protocol_fee_rate` 是交易手续费中的占比，换算为相对成交额
+//!
+//! [`SwapFeeBreakdown`] 按 Raydium 合约的取整方式计算单笔 swap 的交易 / 协议 / 基金 / 创建者费用。
+
+use crate::core::events::{
+    DexEvent, PumpSwapGlobalConfig, RaydiumClmmAmmConfig, RaydiumClmmAmmConfigAccountEvent,
+    RaydiumClmmSwapEvent, RaydiumCpmmAmmConfig, RaydiumCpmmAmmConfigAccountEvent,
+    RaydiumCpmmSwapEvent,
+};
+use crate::instr::program_ids::{
+    PUMPSWAP_PROGRAM_ID, RAYDIUM_CLMM_PROGRAM_ID, RAYDIUM_CPMM_PROGRAM_ID,
+};
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+
+/// Raydium 费率分母
+const RAYDIUM_FEE_RATE_DENOMINATOR: u64 = 1_000_000;
+
+/// 相对成交额的费率（ppm）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FeeTier {
+    pub trade_fee_ppm: u64,
+    pub protocol_fee_ppm: u64,
+}
+
+impl FeeTier {
+    pub fn from_pumpswap(config: &PumpSwapGlobalConfig) -> Self {
+        let trade_bps = config
+            .lp_fee_basis_points
+            .saturating_add(config.protocol_fee_basis_points)
+            .saturating_add(config.coin_creator_fee_basis_points);
+        Self {
+            trade_fee_ppm: trade_bps.saturating_mul(100),
+            protocol_fee_ppm: config.protocol_fee_basis_points.saturating_mul(100),
+        }
+    }
+
+    pub fn from_raydium_clmm(config: &RaydiumClmmAmmConfig) -> Self {
+        Self::from_raydium(config.trade_fee_rate as u64, config.protocol_fee_rate as u64)
+    }
+
+    pub fn from_raydium_cpmm(config: &RaydiumCpmmAmmConfig) -> Self {
+        Self::from_raydium(config.trade_fee_rate, config.protocol_fee_rate)
+    }
+
+    fn from_raydium(trade_fee_rate: u64, protocol_fee_rate: u64) -> Self {
+        let protocol = trade_fee_rate as u128 * protocol_fee_rate as u128
+            / RAYDIUM_FEE_RATE_DENOMINATOR as u128;
+        Self { trade_fee_ppm: trade_fee_rate, protocol_fee_ppm: protocol as u64 }
+    }
+}
+
+/// 单笔 swap 的手续费拆分，单位为输入代币
+///
+/// `trade_fee` 含协议费和基金费，其余部分归 LP；创建者费用（CPMM）在交易手续费之外另收。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SwapFeeBreakdown {
+    pub trade_fee: u64,
+    pub protocol_fee: u64,
+    pub fund_fee: u64,
+    pub creator_fee: u64,
+}
+
+impl SwapFeeBreakdown {
+    /// Raydium CPMM：`amount_in` 为扣除 Token-2022 转账费后的输入量
+    ///
+    /// 交易 / 创建者费用对输入量向上取整，协议 / 基金费用对交易手续费向下取整，与合约一致。
+    /// 创建者费用从输出代币收取时（`creator_fee_on_input = false`）不计入。
+    pub fn raydium_cpmm(
+        config: &RaydiumCpmmAmmConfig,
+        amount_in: u64,
+        creator_fee_on_input: bool,
+    ) -> Self {
+        let creator_fee =
+            if creator_fee_on_input { fee_ceil(amount_in, config.creator_fee_rate) } else { 0 };
+        Self {
+            creator_fee,
+            ..Self::from_trade_fee(
+                fee_ceil(amount_in, config.trade_fee_rate),
+                config.protocol_fee_rate,
+                config.fund_fee_rate,
+            )
+        }
+    }
+
+    /// Raydium CLMM：`amount_in` 为含手续费、扣除转账费后的输入量
+    ///
+    /// 合约按每个 tick 区间分别取整，跨越 tick 的 swap 与此结果可能相差几个最小单位。
+    pub fn raydium_clmm(config: &RaydiumClmmAmmConfig, amount_in: u64) -> Self {
+        Self::from_trade_fee(
+            fee_ceil(amount_in, config.trade_fee_rate as u64),
+            config.protocol_fee_rate as u64,
+            config.fund_fee_rate as u64,
+        )
+    }
+
+    /// 已解析的 CPMM swap 事件的手续费
+    pub fn from_cpmm_swap(
+        config: &RaydiumCpmmAmmConfig,
+        event: &RaydiumCpmmSwapEvent,
+        creator_fee_on_input: bool,
+    ) -> Self {
+        Self::raydium_cpmm(config, event.input_amount, creator_fee_on_input)
+    }
+
+    /// 已解析的 CLMM swap 事件的手续费
+    pub fn from_clmm_swap(config: &RaydiumClmmAmmConfig, event: &RaydiumClmmSwapEvent) -> Self {
+        let amount_in = if event.zero_for_one { event.amount_0 } else { event.amount_1 };
+        Self::raydium_clmm(config, amount_in)
+    }
+
+    /// 归 LP 的部分
+    pub fn lp_fee(&self) -> u64 {
+        self.trade_fee.saturating_sub(self.protocol_fee).saturating_sub(self.fund_fee)
+    }
+
+    fn from_trade_fee(trade_fee: u64, protocol_fee_rate: u64, fund_fee_rate: u64) -> Self {
+        Self {
+            trade_fee,
+            protocol_fee: fee_floor(trade_fee, protocol_fee_rate),
+            fund_fee: fee_floor(trade_fee, fund_fee_rate),
+            creator_fee: 0,
+        }
+    }
+}
+
+fn fee_ceil(amount: u64, rate: u64) -> u64 {
+    let denominator = RAYDIUM_FEE_RATE_DENOMINATOR as u128;
+    ((amount as u128 * rate as u128).div_ceil(denominator)) as u64
+}
+
+fn fee_floor(amount: u64, rate: u64) -> u64 {
+    (amount as u128 * rate as u128 / RAYDIUM_FEE_RATE_DENOMINATOR as u128) as u64
+}
+
+/// 费率变更告警
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeeTierChanged {
+    pub program: Pubkey,
+    /// 配置账户地址
+    pub config: Pubkey,
+    pub slot: u64,
+    pub old: FeeTier,
+    pub new: FeeTier,
+}
+
+/// 按配置账户跟踪费率，变化时告警
+#[derive(Debug, Default)]
+pub struct FeeTierMonitor {
+    tiers: HashMap<Pubkey, FeeTier>,
+}
+
+impl FeeTierMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 配置账户当前记录的费率
+    pub fn current(&self, config: &Pubkey) -> Option<FeeTier> {
+        self.tiers.get(config).copied()
+    }
+
+    /// 处理一个事件，目前只有 `PumpSwapGlobalConfigAccount` 以 `DexEvent` 形式输出，其余事件忽略
+    pub fn observe(&mut self, event: &DexEvent) -> Option<FeeTierChanged> {
+        let DexEvent::PumpSwapGlobalConfigAccount(e) = event else { return None };
+        self.update(
+            PUMPSWAP_PROGRAM_ID,
+            e.pubkey,
+            e.metadata.slot,
+            FeeTier::from_pumpswap(&e.global_config),
+        )
+    }
+
+    pub fn observe_clmm_config(
+        &mut self,
+        event: &RaydiumClmmAmmConfigAccountEvent,
+    ) -> Option<FeeTierChanged> {
+        self.update(
+            RAYDIUM_CLMM_PROGRAM_ID,
+            event.pubkey,
+            event.metadata.slot,
+            FeeTier::from_raydium_clmm(&event.amm_config),
+        )
+    }
+
+    pub fn observe_cpmm_config(
+        &mut self,
+        event: &RaydiumCpmmAmmConfigAccountEvent,
+    ) -> Option<FeeTierChanged> {
+        self.update(
+            RAYDIUM_CPMM_PROGRAM_ID,
+            event.pubkey,
+            event.metadata.slot,
+            FeeTier::from_raydium_cpmm(&event.amm_config),
+        )
+    }
+
+    fn update(
+        &mut self,
+        program: Pubkey,
+        config: Pubkey,
+        slot: u64,
+        new: FeeTier,
+    ) -> Option<FeeTierChanged> {
+        let old = self.tiers.insert(config, new)?;
+        (old != new).then_some(FeeTierChanged { program, config, slot, old, new })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::{EventMetadata, PumpSwapGlobalConfigAccountEvent};
+
+    #[test]
+    fn test_fee_tier_change_alerts() {
+        let mut monitor = FeeTierMonitor::new();
+        let config = Pubkey::new_unique();
+        let pumpswap = |slot, protocol_fee_basis_points| {
+            DexEvent::PumpSwapGlobalConfigAccount(PumpSwapGlobalConfigAccountEvent {
+                metadata: EventMetadata { slot, ..Default::default() },
+                pubkey: config,
+                global_config: PumpSwapGlobalConfig {
+                    lp_fee_basis_points: 20,
+                    protocol_fee_basis_points,
+                    coin_creator_fee_basis_points: 5,
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+        };
+
+        // 首次只记录基线，费率不变不告警
+        assert_eq!(monitor.observe(&pumpswap(1, 5)), None);
+        assert_eq!(monitor.observe(&pumpswap(2, 5)), None);
+        let alert = monitor.observe(&pumpswap(3, 10)).unwrap();
+        assert_eq!((alert.program, alert.config, alert.slot), (PUMPSWAP_PROGRAM_ID, config, 3));
+        assert_eq!(alert.old, FeeTier { trade_fee_ppm: 3_000, protocol_fee_ppm: 500 });
+        assert_eq!(alert.new, FeeTier { trade_fee_ppm: 3_500, protocol_fee_ppm: 1_000 });
+
+        // CLMM：协议费率按交易手续费占比换算
+        let clmm = |trade_fee_rate, protocol_fee_rate| RaydiumClmmAmmConfigAccountEvent {
+            metadata: EventMetadata::default(),
+            pubkey: Pubkey::new_from_array([1; 32]),
+            amm_config: RaydiumClmmAmmConfig {
+                bump: 0,
+                index: 0,
+                owner: Pubkey::default(),
+                protocol_fee_rate,
+                trade_fee_rate,
+                tick_spacing: 60,
+                fund_fee_rate: 0,
+                fund_owner: Pubkey::default(),
+            },
+        };
+        assert_eq!(monitor.observe_clmm_config(&clmm(2_500, 120_000)), None);
+        let alert = monitor.observe_clmm_config(&clmm(2_500, 200_000)).unwrap();
+        assert_eq!(alert.program, RAYDIUM_CLMM_PROGRAM_ID);
+        assert_eq!(alert.old.protocol_fee_ppm, 300);
+        assert_eq!(alert.new, FeeTier { trade_fee_ppm: 2_500, protocol_fee_ppm: 500 });
+    }
+
+    #[test]
+    fn test_raydium_swap_fee_breakdown() {
+        let cpmm = RaydiumCpmmAmmConfig {
+            bump: 0,
+            disable_create_pool: false,
+            index: 0,
+            trade_fee_rate: 2_500,
+            protocol_fee_rate: 120_000,
+            fund_fee_rate: 40_000,
+            create_pool_fee: 0,
+            protocol_owner: Pubkey::default(),
+            fund_owner: Pubkey::default(),
+            creator_fee_rate: 1_000,
+            padding: [0; 15],
+        };
+        let fees = SwapFeeBreakdown::raydium_cpmm(&cpmm, 1_000_000, true);
+        assert_eq!(
+            fees,
+            SwapFeeBreakdown {
+                trade_fee: 2_500,
+                protocol_fee: 300,
+                fund_fee: 100,
+                creator_fee: 1_000
+            }
+        );
+        assert_eq!(fees.lp_fee(), 2_100);
+        // 交易手续费向上取整，协议 / 基金费用向下取整
+        let fees = SwapFeeBreakdown::raydium_cpmm(&cpmm, 999, false);
+        assert_eq!(
+            fees,
+            SwapFeeBreakdown { trade_fee: 3, protocol_fee: 0, fund_fee: 0, creator_fee: 0 }
+        );
+
+        let clmm = RaydiumClmmAmmConfig {
+            bump: 0,
+            index: 0,
+            owner: Pubkey::default(),
+            protocol_fee_rate: 120_000,
+            trade_fee_rate: 500,
+            tick_spacing: 10,
+            fund_fee_rate: 40_000,
+            fund_owner: Pubkey::default(),
+        };
+        let fees = SwapFeeBreakdown::raydium_clmm(&clmm, 2_000_000);
+        assert_eq!(
+            fees,
+            SwapFeeBreakdown { trade_fee: 1_000, protocol_fee: 120, fund_fee: 40, creator_fee: 0 }
+        );
+    }
+}
@@ -0,0 +1,231 @@
+//! 持仓集中度估计
+//!
+//! 订阅某个 mint 的 Token 账户后，根据 `TokenAccount` 事件维护近似的持仓分布
+//! （按持有人聚合的 top-N 余额、持有人数量），并在前 N 名持仓占比变化较大时发出告警。
+//! 只能看到订阅期间更新过的账户，因此结果是近似值。
+
+use crate::core::bounded::{BoundedMap, BoundedMapConfig, EvictionStats};
+use crate::core::events::DexEvent;
+use solana_sdk::pubkey::Pubkey;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+/// 持仓分布快照
+#[derive(Debug, Clone, PartialEq)]
+pub struct HolderSnapshot {
+    pub mint: Pubkey,
+    /// 余额大于 0 的持有人数量
+    pub holder_count: usize,
+    /// 已观察到的总持仓
+    pub total_amount: u128,
+    /// 前 N 名持有人及余额，按余额降序
+    pub top_holders: Vec<(Pubkey, u64)>,
+    /// 前 N 名占总持仓的比例（基点）
+    pub top_share_bps: u64,
+}
+
+/// 集中度变化告警
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConcentrationAlert {
+    pub mint: Pubkey,
+    pub slot: u64,
+    pub previous_share_bps: u64,
+    pub snapshot: HolderSnapshot,
+}
+
+#[derive(Debug, Default)]
+struct MintHolders {
+    /// token 账户 → (持有人, 余额)
+    accounts: HashMap<Pubkey, (Pubkey, u64)>,
+    /// 持有人 → 各账户余额之和，随账户更新增量维护
+    by_owner: HashMap<Pubkey, u64>,
+    total_amount: u128,
+    /// 上次告警（或首次观察）时的前 N 名占比
+    baseline_share_bps: Option<u64>,
+}
+
+impl MintHolders {
+    /// 更新一个 token 账户的余额，余额为 0 时移除
+    fn update(&mut self, account: Pubkey, owner: Pubkey, amount: u64) {
+        let previous = if amount == 0 {
+            self.accounts.remove(&account)
+        } else {
+            self.accounts.insert(account, (owner, amount))
+        };
+        if let Some((previous_owner, previous_amount)) = previous {
+            self.total_amount -= previous_amount as u128;
+            if let Some(total) = self.by_owner.get_mut(&previous_owner) {
+                *total -= previous_amount;
+                if *total == 0 {
+                    self.by_owner.remove(&previous_owner);
+                }
+            }
+        }
+        if amount > 0 {
+            self.total_amount += amount as u128;
+            *self.by_owner.entry(owner).or_default() += amount;
+        }
+    }
+
+    fn snapshot(&self, mint: Pubkey, top_n: usize) -> HolderSnapshot {
+        let total_amount = self.total_amount;
+        let holder_count = self.by_owner.len();
+        let mut holders: Vec<(Pubkey, u64)> = self.by_owner.iter().map(|(&o, &a)| (o, a)).collect();
+        // 只对前 N 名排序
+        if top_n > 0 && holders.len() > top_n {
+            holders.select_nth_unstable_by_key(top_n - 1, |&(_, amount)| Reverse(amount));
+        }
+        holders.truncate(top_n);
+        holders.sort_unstable_by_key(|&(_, amount)| Reverse(amount));
+
+        let top_amount: u128 = holders.iter().map(|&(_, a)| a as u128).sum();
+        let top_share_bps = (top_amount * 10_000).checked_div(total_amount).unwrap_or(0) as u64;
+
+        HolderSnapshot { mint, holder_count, total_amount, top_holders: holders, top_share_bps }
+    }
+}
+
+#[derive(Debug)]
+pub struct HolderTracker {
+    top_n: usize,
+    alert_threshold_bps: u64,
+    mints: BoundedMap<Pubkey, MintHolders>,
+}
+
+impl HolderTracker {
+    /// `top_n`：统计集中度的前 N 名；`alert_threshold_bps`：占比变化达到该值时告警
+    pub fn new(top_n: usize, alert_threshold_bps: u64) -> Self {
+        Self { top_n, alert_threshold_bps, mints: BoundedMap::default() }
+    }
+
+    /// 限制跟踪的 mint 数，超出容量或过期时丢弃最久未更新的 mint 的持仓（默认不限制）
+    pub fn with_limits(mut self, limits: BoundedMapConfig) -> Self {
+        self.mints.set_config(limits);
+        self
+    }
+
+    pub fn eviction_stats(&self) -> EvictionStats {
+        self.mints.eviction_stats()
+    }
+
+    /// 处理一个事件，非 `TokenAccount` 事件直接忽略
+    pub fn observe(&mut self, event: &DexEvent) -> Option<ConcentrationAlert> {
+        let DexEvent::TokenAccount(e) = event else { return None };
+        let amount = e.amount?;
+        if e.mint == Pubkey::default() {
+            return None;
+        }
+
+        let holders = self.mints.get_or_insert_with(e.mint, MintHolders::default);
+        holders.update(e.pubkey, e.token_owner, amount);
+
+        let snapshot = holders.snapshot(e.mint, self.top_n);
+        let Some(previous_share_bps) = holders.baseline_share_bps else {
+            holders.baseline_share_bps = Some(snapshot.top_share_bps);
+            return None;
+        };
+        if snapshot.top_share_bps.abs_diff(previous_share_bps) < self.alert_threshold_bps {
+            return None;
+        }
+        holders.baseline_share_bps = Some(snapshot.top_share_bps);
+        Some(ConcentrationAlert {
+            mint: e.mint,
+            slot: e.metadata.slot,
+            previous_share_bps,
+            snapshot,
+        })
+    }
+
+    /// 查询 mint 当前的持仓分布
+    pub fn snapshot(&self, mint: &Pubkey) -> Option<HolderSnapshot> {
+        self.mints.get(mint).map(|h| h.snapshot(*mint, self.top_n))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::TokenAccountEvent;
+
+    fn account(mint: Pubkey, pubkey: Pubkey, owner: Pubkey, amount: u64) -> DexEvent {
+        DexEvent::TokenAccount(TokenAccountEvent {
+            pubkey,
+            amount: Some(amount),
+            token_owner: owner,
+            mint,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_holder_distribution_and_alert() {
+        let mint = Pubkey::new_unique();
+        let whale = Pubkey::new_unique();
+        let (whale_a, whale_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut tracker = HolderTracker::new(1, 1_000);
+
+        assert!(tracker.observe(&account(mint, whale_a, whale, 100)).is_none());
+        let add_holder = |tracker: &mut HolderTracker| {
+            tracker.observe(&account(mint, Pubkey::new_unique(), Pubkey::new_unique(), 100))
+        };
+        // 前 1 名占比 10000 → 5000 → 3333 → 2500，最后一次变化低于阈值
+        assert_eq!(add_holder(&mut tracker).unwrap().previous_share_bps, 10_000);
+        assert_eq!(add_holder(&mut tracker).unwrap().previous_share_bps, 5_000);
+        assert!(add_holder(&mut tracker).is_none());
+
+        let snapshot = tracker.snapshot(&mint).unwrap();
+        assert_eq!(snapshot.holder_count, 4);
+        assert_eq!(snapshot.total_amount, 400);
+        assert_eq!(snapshot.top_share_bps, 2_500);
+
+        // 同一持有人的第二个账户按持有人聚合
+        let alert = tracker.observe(&account(mint, whale_b, whale, 400)).unwrap();
+        assert_eq!(alert.snapshot.holder_count, 4);
+        assert_eq!(alert.snapshot.top_holders, vec![(whale, 500)]);
+        assert_eq!(alert.snapshot.top_share_bps, 6_250);
+
+        // 余额清零后移除
+        tracker.observe(&account(mint, whale_b, whale, 0));
+        assert_eq!(tracker.snapshot(&mint).unwrap().total_amount, 400);
+    }
+
+    #[test]
+    fn test_holders_from_packed_token_accounts() {
+        use crate::accounts::{parse_token_account, AccountData};
+        use crate::core::events::EventMetadata;
+        use spl_token::solana_program::program_pack::Pack;
+        use spl_token::state::{Account, AccountState};
+
+        let mint = Pubkey::new_unique();
+        let token_account = |holder: Pubkey, amount| {
+            let mut data = vec![0u8; Account::LEN];
+            let state = Account {
+                mint: mint.to_bytes().into(),
+                owner: holder.to_bytes().into(),
+                amount,
+                state: AccountState::Initialized,
+                ..Default::default()
+            };
+            Account::pack(state, &mut data).unwrap();
+            let account = AccountData {
+                pubkey: Pubkey::new_unique(),
+                executable: false,
+                lamports: 2_039_280,
+                owner: Pubkey::new_from_array(spl_token::ID.to_bytes()),
+                rent_epoch: 0,
+                data,
+            };
+            parse_token_account(&account, EventMetadata::default()).unwrap()
+        };
+
+        // 不同持有人的账户不会合并为 token program 一个持有人
+        let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut tracker = HolderTracker::new(2, 10_000);
+        tracker.observe(&token_account(alice, 300));
+        tracker.observe(&token_account(bob, 100));
+        tracker.observe(&token_account(alice, 200));
+        let snapshot = tracker.snapshot(&mint).unwrap();
+        assert_eq!(snapshot.holder_count, 2);
+        assert_eq!(snapshot.top_holders, vec![(alice, 500), (bob, 100)]);
+    }
+}
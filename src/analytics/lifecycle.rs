@@ -0,0 +1,305 @@
+//! 新币发行生命周期
+//!
+//! 收到 `PumpFunCreate` / `BonkPoolCreate` 后开始跟踪该发行，记录最早的 N 笔交易
+//! （交易者、数量、相对创建的 slot / 时间差），凑满后输出 [`LaunchSnipersReport`]，
+//! 用于识别狙击和对敲。超过最大跟踪时长仍未凑满的发行可用 [`LaunchTracker::expire`] 输出部分报告。
+//!
+//! Bonk 事件中不含 mint，发行以 `pool_state` 标识。
+//! 配置 [`CreatorReputation`] 后，报告附带创建者此前的发行数和跑路次数。
+
+use super::reputation::CreatorReputation;
+use crate::core::events::{DexEvent, EventMetadata, TradeDirection};
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// 默认最大跟踪时长（slot），约 5 分钟
+const DEFAULT_MAX_AGE_SLOTS: u64 = 750;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LaunchProtocol {
+    PumpFun,
+    Bonk,
+}
+
+/// 发行后的单笔交易
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaunchTrade {
+    pub trader: Pubkey,
+    pub is_buy: bool,
+    /// 计价代币数量（PumpFun 为 lamports）
+    pub quote_amount: u64,
+    pub token_amount: u64,
+    /// 交易者是否为创建者
+    pub is_creator: bool,
+    pub slot: u64,
+    pub tx_index: u64,
+    /// 相对创建的 slot 差，0 表示与创建同 slot
+    pub slots_after_create: u64,
+    /// 相对创建的时间差（微秒）
+    pub us_after_create: i64,
+}
+
+/// 发行最早 N 笔交易的汇总
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaunchSnipersReport {
+    pub protocol: LaunchProtocol,
+    /// PumpFun 为 mint，Bonk 为 pool_state
+    pub launch: Pubkey,
+    pub creator: Pubkey,
+    pub create_slot: u64,
+    pub trades: Vec<LaunchTrade>,
+    /// 与创建同 slot 的交易数（不含创建者）
+    pub same_slot_trades: usize,
+    /// 是否已凑满 N 笔
+    pub complete: bool,
+    /// 创建时查询到的创建者此前发行数，未配置 [`CreatorReputation`] 时为 0
+    pub creator_prior_launches: u32,
+    /// 创建时查询到的创建者此前跑路次数
+    pub creator_rug_count: u32,
+}
+
+#[derive(Debug)]
+struct PendingLaunch {
+    protocol: LaunchProtocol,
+    creator: Pubkey,
+    create_slot: u64,
+    create_time_us: i64,
+    trades: Vec<LaunchTrade>,
+    prior_launches: u32,
+    rug_count: u32,
+}
+
+impl PendingLaunch {
+    fn into_report(self, launch: Pubkey, complete: bool) -> LaunchSnipersReport {
+        let same_slot_trades =
+            self.trades.iter().filter(|t| t.slots_after_create == 0 && !t.is_creator).count();
+        LaunchSnipersReport {
+            protocol: self.protocol,
+            launch,
+            creator: self.creator,
+            create_slot: self.create_slot,
+            trades: self.trades,
+            same_slot_trades,
+            complete,
+            creator_prior_launches: self.prior_launches,
+            creator_rug_count: self.rug_count,
+        }
+    }
+}
+
+pub struct LaunchTracker {
+    first_n: usize,
+    max_age_slots: u64,
+    pending: HashMap<Pubkey, PendingLaunch>,
+    reputation: Option<Arc<dyn CreatorReputation>>,
+}
+
+impl std::fmt::Debug for LaunchTracker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LaunchTracker")
+            .field("first_n", &self.first_n)
+            .field("max_age_slots", &self.max_age_slots)
+            .field("pending", &self.pending)
+            .field("reputation", &self.reputation.is_some())
+            .finish()
+    }
+}
+
+impl LaunchTracker {
+    pub fn new(first_n: usize) -> Self {
+        Self {
+            first_n,
+            max_age_slots: DEFAULT_MAX_AGE_SLOTS,
+            pending: HashMap::new(),
+            reputation: None,
+        }
+    }
+
+    /// 创建发行时查询创建者的历史记录，填入报告的 `creator_prior_launches` / `creator_rug_count`
+    pub fn with_reputation(mut self, reputation: Arc<dyn CreatorReputation>) -> Self {
+        self.reputation = Some(reputation);
+        self
+    }
+
+    /// 设置最大跟踪时长（slot）
+    pub fn with_max_age_slots(mut self, slots: u64) -> Self {
+        self.max_age_slots = slots;
+        self
+    }
+
+    /// 正在跟踪的发行数量
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// 处理一个事件，某个发行凑满 N 笔交易时返回报告
+    pub fn observe(&mut self, event: &DexEvent) -> Option<LaunchSnipersReport> {
+        match event {
+            DexEvent::PumpFunCreate(e) => {
+                self.start(LaunchProtocol::PumpFun, e.mint, e.creator, &e.metadata);
+                None
+            }
+            DexEvent::BonkPoolCreate(e) => {
+                self.start(LaunchProtocol::Bonk, e.pool_state, e.creator, &e.metadata);
+                None
+            }
+            DexEvent::PumpFunTrade(e)
+            | DexEvent::PumpFunBuy(e)
+            | DexEvent::PumpFunSell(e)
+            | DexEvent::PumpFunBuyExactSolIn(e) => {
+                self.record(e.mint, e.user, e.is_buy, e.sol_amount, e.token_amount, &e.metadata)
+            }
+            DexEvent::BonkTrade(e) => {
+                let is_buy = e.trade_direction == TradeDirection::Buy;
+                let (quote_amount, token_amount) =
+                    if is_buy { (e.amount_in, e.amount_out) } else { (e.amount_out, e.amount_in) };
+                self.record(e.pool_state, e.user, is_buy, quote_amount, token_amount, &e.metadata)
+            }
+            _ => None,
+        }
+    }
+
+    /// 输出并移除超过最大跟踪时长的发行（未凑满 N 笔）
+    pub fn expire(&mut self, current_slot: u64) -> Vec<LaunchSnipersReport> {
+        let expired: Vec<Pubkey> = self
+            .pending
+            .iter()
+            .filter(|(_, p)| current_slot.saturating_sub(p.create_slot) > self.max_age_slots)
+            .map(|(launch, _)| *launch)
+            .collect();
+        expired
+            .into_iter()
+            .filter_map(|launch| self.pending.remove(&launch).map(|p| p.into_report(launch, false)))
+            .collect()
+    }
+
+    fn start(
+        &mut self,
+        protocol: LaunchProtocol,
+        launch: Pubkey,
+        creator: Pubkey,
+        metadata: &EventMetadata,
+    ) {
+        let (first_n, reputation) = (self.first_n, &self.reputation);
+        self.pending.entry(launch).or_insert_with(|| {
+            let history = reputation.as_ref().map(|r| r.history(&creator)).unwrap_or_default();
+            PendingLaunch {
+                protocol,
+                creator,
+                create_slot: metadata.slot,
+                create_time_us: event_time_us(metadata),
+                trades: Vec::with_capacity(first_n),
+                prior_launches: history.prior_launches,
+                rug_count: history.rug_count,
+            }
+        });
+    }
+
+    fn record(
+        &mut self,
+        launch: Pubkey,
+        trader: Pubkey,
+        is_buy: bool,
+        quote_amount: u64,
+        token_amount: u64,
+        metadata: &EventMetadata,
+    ) -> Option<LaunchSnipersReport> {
+        let pending = self.pending.get_mut(&launch)?;
+        pending.trades.push(LaunchTrade {
+            trader,
+            is_buy,
+            quote_amount,
+            token_amount,
+            is_creator: trader == pending.creator,
+            slot: metadata.slot,
+            tx_index: metadata.tx_index,
+            slots_after_create: metadata.slot.saturating_sub(pending.create_slot),
+            us_after_create: event_time_us(metadata) - pending.create_time_us,
+        });
+        if pending.trades.len() < self.first_n {
+            return None;
+        }
+        self.pending.remove(&launch).map(|p| p.into_report(launch, true))
+    }
+}
+
+/// 事件时间：优先使用区块时间，缺失时使用接收时间
+fn event_time_us(metadata: &EventMetadata) -> i64 {
+    if metadata.block_time_us > 0 {
+        metadata.block_time_us
+    } else {
+        metadata.grpc_recv_us
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::{PumpFunCreateTokenEvent, PumpFunTradeEvent};
+
+    fn metadata(slot: u64) -> EventMetadata {
+        EventMetadata { slot, block_time_us: slot as i64 * 400_000, ..Default::default() }
+    }
+
+    fn buy(mint: Pubkey, user: Pubkey, slot: u64) -> DexEvent {
+        DexEvent::PumpFunBuy(PumpFunTradeEvent {
+            metadata: metadata(slot),
+            mint,
+            user,
+            is_buy: true,
+            sol_amount: 1_000,
+            token_amount: 10_000,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_first_n_buyers_report() {
+        let (mint, creator, sniper) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let mut tracker = LaunchTracker::new(3);
+
+        // 创建前的交易不跟踪
+        assert!(tracker.observe(&buy(mint, sniper, 99)).is_none());
+        tracker.observe(&DexEvent::PumpFunCreate(PumpFunCreateTokenEvent {
+            metadata: metadata(100),
+            mint,
+            creator,
+            ..Default::default()
+        }));
+        assert_eq!(tracker.pending_len(), 1);
+
+        assert!(tracker.observe(&buy(mint, creator, 100)).is_none());
+        assert!(tracker.observe(&buy(mint, sniper, 100)).is_none());
+        let report = tracker.observe(&buy(mint, Pubkey::new_unique(), 102)).unwrap();
+
+        assert!(report.complete);
+        assert_eq!(report.protocol, LaunchProtocol::PumpFun);
+        assert_eq!(report.launch, mint);
+        assert_eq!(report.trades.len(), 3);
+        assert!(report.trades[0].is_creator);
+        assert_eq!(report.same_slot_trades, 1);
+        assert_eq!(report.trades[2].slots_after_create, 2);
+        assert_eq!(report.trades[2].us_after_create, 800_000);
+        assert_eq!(tracker.pending_len(), 0);
+    }
+
+    #[test]
+    fn test_expire_partial_report() {
+        let mint = Pubkey::new_unique();
+        let mut tracker = LaunchTracker::new(10).with_max_age_slots(5);
+        tracker.observe(&DexEvent::PumpFunCreate(PumpFunCreateTokenEvent {
+            metadata: metadata(100),
+            mint,
+            ..Default::default()
+        }));
+        tracker.observe(&buy(mint, Pubkey::new_unique(), 101));
+
+        assert!(tracker.expire(105).is_empty());
+        let reports = tracker.expire(106);
+        assert_eq!(reports.len(), 1);
+        assert!(!reports[0].complete);
+        assert_eq!(reports[0].trades.len(), 1);
+    }
+}
@@ -0,0 +1,214 @@
+//! 流动性迁移关联
+//!
+//! PumpFun 毕业迁移到 PumpSwap、Bonk (LaunchLab) 迁移到 Raydium AMM V4 / CPMM 时，
+//! 迁移事件与目标 DEX 的建池事件是两个独立事件。[`MigrationLinker`] 把两者关联起来，
+//! 在较后的一个之后插入 `DexEvent::MigrationCompleted`，机器人可以立即切换到新池子。
+//!
+//! - 同一交易内：建池事件可能在迁移事件之前（CPI 先于迁移日志），按交易整体匹配
+//! - 跨交易：迁移后 `max_slot_gap` 个 slot 内出现的建池事件，超时未匹配的迁移丢弃
+//!
+//! 迁移事件带目标池子时按池子匹配（PumpFun `pool`、Bonk `new_pool`），
+//! PumpFun 迁移缺少 `pool` 时按 mint 匹配包含该 mint 的建池事件。
+
+use super::pool_created::normalize_pool_created;
+use crate::core::events::{DexEvent, MigrationCompletedEvent, NormalizedPoolCreatedEvent};
+use solana_sdk::pubkey::Pubkey;
+
+/// 默认最大 slot 间隔，约 10 秒
+const DEFAULT_MAX_SLOT_GAP: u64 = 25;
+
+#[derive(Debug)]
+struct PendingMigration {
+    source: &'static str,
+    source_pool: Pubkey,
+    source_slot: u64,
+    destination_pool: Option<Pubkey>,
+    mint: Option<Pubkey>,
+    /// 在当前批次中的下标，跨批次时为 None
+    index: Option<usize>,
+}
+
+impl PendingMigration {
+    fn from_event(event: &DexEvent, index: usize) -> Option<Self> {
+        let known = |key: Pubkey| (key != Pubkey::default()).then_some(key);
+        let (source_pool, destination_pool, mint) = match event {
+            DexEvent::PumpFunMigrate(e) => (e.bonding_curve, known(e.pool), known(e.mint)),
+            DexEvent::BonkMigrateAmm(e) => (e.old_pool, known(e.new_pool), None),
+            _ => return None,
+        };
+        if destination_pool.is_none() && mint.is_none() {
+            return None;
+        }
+        Some(Self {
+            source: event.event_type_name(),
+            source_pool,
+            source_slot: event.metadata().slot,
+            destination_pool,
+            mint,
+            index: Some(index),
+        })
+    }
+
+    fn matches(&self, created: &NormalizedPoolCreatedEvent) -> bool {
+        match (self.destination_pool, self.mint) {
+            (Some(pool), _) => pool == created.pool,
+            (None, Some(mint)) => mint == created.mint_a || mint == created.mint_b,
+            (None, None) => false,
+        }
+    }
+}
+
+/// 迁移事件与目标 DEX 建池事件的关联器，按交易（或按 slot 顺序的事件批次）调用 [`Self::process`]
+#[derive(Debug)]
+pub struct MigrationLinker {
+    max_slot_gap: u64,
+    pending: Vec<PendingMigration>,
+    latest_slot: u64,
+}
+
+impl Default for MigrationLinker {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_SLOT_GAP)
+    }
+}
+
+impl MigrationLinker {
+    /// `max_slot_gap`：迁移后最多等待多少个 slot 的建池事件，0 表示只在同一 slot 内关联
+    pub fn new(max_slot_gap: u64) -> Self {
+        Self { max_slot_gap, pending: Vec::new(), latest_slot: 0 }
+    }
+
+    /// 等待建池事件的迁移数
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// 关联 `events` 中的迁移与建池事件，插入 `DexEvent::MigrationCompleted`，返回插入数
+    pub fn process(&mut self, events: &mut Vec<DexEvent>) -> usize {
+        for (i, event) in events.iter().enumerate() {
+            self.latest_slot = self.latest_slot.max(event.metadata().slot);
+            self.pending.extend(PendingMigration::from_event(event, i));
+        }
+        if self.pending.is_empty() {
+            return 0;
+        }
+
+        let mut completed = Vec::new();
+        for (i, event) in events.iter().enumerate() {
+            let Some(created) = normalize_pool_created(event) else { continue };
+            let slot = created.metadata.slot;
+            let Some(pos) = self.pending.iter().position(|m| {
+                m.matches(&created)
+                    && slot >= m.source_slot
+                    && slot - m.source_slot <= self.max_slot_gap
+            }) else {
+                continue;
+            };
+            let migration = self.pending.swap_remove(pos);
+            let at = migration.index.map_or(i, |index| index.max(i)) + 1;
+            completed.push((
+                at,
+                MigrationCompletedEvent {
+                    metadata: created.metadata,
+                    source: migration.source.to_string(),
+                    source_pool: migration.source_pool,
+                    source_slot: migration.source_slot,
+                    destination: created.source,
+                    destination_pool: created.pool,
+                    mint_a: created.mint_a,
+                    mint_b: created.mint_b,
+                },
+            ));
+        }
+
+        let (latest_slot, max_slot_gap) = (self.latest_slot, self.max_slot_gap);
+        self.pending.retain(|m| m.source_slot + max_slot_gap >= latest_slot);
+        self.pending.iter_mut().for_each(|m| m.index = None);
+
+        // 从后往前插入，同一位置保持匹配顺序
+        completed.sort_by_key(|&(at, _)| at);
+        let count = completed.len();
+        for (at, event) in completed.into_iter().rev() {
+            events.insert(at, DexEvent::MigrationCompleted(event));
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytics::WSOL_MINT;
+    use crate::core::events::{
+        BonkMigrateAmmEvent, EventMetadata, PumpFunMigrateEvent, PumpSwapCreatePoolEvent,
+        RaydiumCpmmInitializeEvent,
+    };
+
+    fn metadata(slot: u64) -> EventMetadata {
+        EventMetadata { slot, ..Default::default() }
+    }
+
+    #[test]
+    fn test_link_migrations() {
+        let (mint, curve, pool) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let mut linker = MigrationLinker::new(5);
+
+        // 同一交易：建池 CPI 在迁移事件之前
+        let mut events = vec![
+            DexEvent::PumpSwapCreatePool(PumpSwapCreatePoolEvent {
+                metadata: metadata(100),
+                pool,
+                base_mint: mint,
+                quote_mint: WSOL_MINT,
+                ..Default::default()
+            }),
+            DexEvent::PumpFunMigrate(PumpFunMigrateEvent {
+                metadata: metadata(100),
+                mint,
+                bonding_curve: curve,
+                pool,
+                ..Default::default()
+            }),
+        ];
+        assert_eq!(linker.process(&mut events), 1);
+        let DexEvent::MigrationCompleted(e) = &events[2] else { panic!("{:?}", events) };
+        assert_eq!(
+            (e.source.as_str(), e.destination.as_str()),
+            ("PumpFunMigrate", "PumpSwapCreatePool")
+        );
+        assert_eq!((e.source_pool, e.destination_pool, e.mint_a), (curve, pool, mint));
+        assert_eq!(linker.pending(), 0);
+
+        // 跨交易：Bonk 迁移后 3 个 slot 建池
+        let (old_pool, new_pool) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut migrate = vec![DexEvent::BonkMigrateAmm(BonkMigrateAmmEvent {
+            metadata: metadata(200),
+            old_pool,
+            new_pool,
+            user: Pubkey::new_unique(),
+            liquidity_amount: 0,
+        })];
+        assert_eq!(linker.process(&mut migrate), 0);
+        assert_eq!(linker.pending(), 1);
+        let initialize = |slot| {
+            DexEvent::RaydiumCpmmInitialize(RaydiumCpmmInitializeEvent {
+                metadata: metadata(slot),
+                pool: new_pool,
+                token_0_mint: WSOL_MINT,
+                token_1_mint: mint,
+                ..Default::default()
+            })
+        };
+        let mut events = vec![initialize(203)];
+        assert_eq!(linker.process(&mut events), 1);
+        let DexEvent::MigrationCompleted(e) = &events[1] else { panic!("{:?}", events) };
+        assert_eq!((e.source_pool, e.source_slot, e.destination_pool), (old_pool, 200, new_pool));
+
+        // 超时的迁移被丢弃
+        assert_eq!(linker.process(&mut migrate), 0);
+        let mut events = vec![initialize(206)];
+        assert_eq!(linker.process(&mut events), 0);
+        assert_eq!(linker.pending(), 0);
+    }
+}
@@ -0,0 +1,402 @@
+//! 按 mint 的滚动成交统计
+//!
+//! 维护每个代币最近 1 小时的成交记录，按 1m / 5m / 1h 窗口查询成交量、成交笔数、
+//! 独立交易者数和买卖比，也可按固定间隔批量输出 [`MintStatsEvent`]。
+//! 只统计一侧为计价代币（WSOL / USDC / USDT）的 swap，SOL 成交量只累计 WSOL 计价的成交。
+//!
+//! 配置 [`VelocityThresholds`] 后，新币（`PumpFunCreate` / `BonkPoolCreate`）创建后一段时间内
+//! SOL 成交量或独立买家数达到阈值时，[`MintStatsTracker::observe`] 返回 [`LaunchVelocityAlert`]，
+//! 每个 mint 最多告警一次。
+
+use super::pool_registry::PoolRegistry;
+use super::swap::{normalize_swap, WSOL_MINT};
+use crate::core::events::DexEvent;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::Duration;
+
+/// 统计窗口
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatsWindow {
+    OneMinute,
+    FiveMinutes,
+    OneHour,
+}
+
+impl StatsWindow {
+    pub const ALL: [StatsWindow; 3] =
+        [StatsWindow::OneMinute, StatsWindow::FiveMinutes, StatsWindow::OneHour];
+
+    pub fn duration_us(self) -> i64 {
+        match self {
+            StatsWindow::OneMinute => 60_000_000,
+            StatsWindow::FiveMinutes => 300_000_000,
+            StatsWindow::OneHour => 3_600_000_000,
+        }
+    }
+}
+
+/// 单个窗口内的统计结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MintStats {
+    pub trades: u64,
+    pub buys: u64,
+    pub sells: u64,
+    /// 代币本身的成交量（原始单位）
+    pub token_volume: u128,
+    /// WSOL 计价成交的 SOL 成交量（lamports）
+    pub sol_volume: u64,
+    pub unique_traders: u64,
+}
+
+impl MintStats {
+    /// 买入笔数 / 卖出笔数，窗口内没有卖出时为 None
+    pub fn buy_sell_ratio(&self) -> Option<f64> {
+        (self.sells > 0).then(|| self.buys as f64 / self.sells as f64)
+    }
+}
+
+/// 周期性输出的统计快照
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MintStatsEvent {
+    pub mint: Pubkey,
+    pub timestamp_us: i64,
+    pub one_minute: MintStats,
+    pub five_minutes: MintStats,
+    pub one_hour: MintStats,
+}
+
+/// 新币速度告警阈值，创建后 `within` 内任一阈值达到即告警
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VelocityThresholds {
+    pub within: Duration,
+    /// 买卖双向的 SOL 成交量（lamports），None 表示不检查
+    pub min_sol_volume: Option<u64>,
+    /// 独立买家数，None 表示不检查
+    pub min_unique_buyers: Option<u64>,
+}
+
+/// 触发告警的阈值
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VelocityTrigger {
+    SolVolume,
+    UniqueBuyers,
+}
+
+/// 新币速度告警
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaunchVelocityAlert {
+    pub mint: Pubkey,
+    pub trigger: VelocityTrigger,
+    pub created_at_us: i64,
+    /// 触发告警的成交相对创建的时间差（微秒）
+    pub us_after_create: i64,
+    pub trades: u64,
+    pub sol_volume: u64,
+    pub unique_buyers: u64,
+}
+
+/// 创建后、告警前的累计量
+#[derive(Debug)]
+struct LaunchVelocity {
+    created_at_us: i64,
+    trades: u64,
+    sol_volume: u64,
+    buyers: HashSet<Pubkey>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TradeRecord {
+    timestamp_us: i64,
+    is_buy: bool,
+    token_amount: u64,
+    sol_amount: u64,
+    trader: Option<Pubkey>,
+}
+
+#[derive(Debug, Default)]
+pub struct MintStatsTracker {
+    registry: PoolRegistry,
+    trades: HashMap<Pubkey, VecDeque<TradeRecord>>,
+    emit_interval_us: Option<i64>,
+    last_emit_us: i64,
+    velocity: Option<VelocityThresholds>,
+    launches: HashMap<Pubkey, LaunchVelocity>,
+}
+
+impl MintStatsTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 启用周期输出，配合 [`MintStatsTracker::poll`] 使用
+    pub fn with_emit_interval(mut self, interval: std::time::Duration) -> Self {
+        self.emit_interval_us = Some(interval.as_micros() as i64);
+        self
+    }
+
+    /// 启用新币速度告警
+    pub fn with_velocity_alert(mut self, thresholds: VelocityThresholds) -> Self {
+        self.velocity = Some(thresholds);
+        self
+    }
+
+    /// 用于预先注册事件中不含 mint 的池子
+    pub fn registry_mut(&mut self) -> &mut PoolRegistry {
+        &mut self.registry
+    }
+
+    /// 记录一个事件（非 swap 事件只用于学习池子信息），新币达到速度阈值时返回告警
+    pub fn observe(&mut self, event: &DexEvent) -> Option<LaunchVelocityAlert> {
+        self.registry.observe(event);
+        if self.velocity.is_some() {
+            let created = match event {
+                DexEvent::PumpFunCreate(e) => Some(e.mint),
+                DexEvent::BonkPoolCreate(e) => Some(e.base_mint),
+                _ => None,
+            };
+            if let Some(mint) = created.filter(|mint| *mint != Pubkey::default()) {
+                let launch = LaunchVelocity {
+                    created_at_us: event_time_us(event),
+                    trades: 0,
+                    sol_volume: 0,
+                    buyers: HashSet::new(),
+                };
+                self.launches.insert(mint, launch);
+                return None;
+            }
+        }
+        let swap = normalize_swap(event, &self.registry)?;
+        let (mint, is_buy) = swap.token_side()?;
+
+        let (token_amount, quote_amount, quote_mint) = if is_buy {
+            (swap.output_amount, swap.input_amount, swap.input_mint)
+        } else {
+            (swap.input_amount, swap.output_amount, swap.output_mint)
+        };
+        let timestamp_us = event_time_us(event);
+
+        let record = TradeRecord {
+            timestamp_us,
+            is_buy,
+            token_amount,
+            sol_amount: if quote_mint == WSOL_MINT { quote_amount } else { 0 },
+            trader: swap.trader,
+        };
+        let records = self.trades.entry(mint).or_default();
+        records.push_back(record);
+        prune(records, timestamp_us);
+        self.check_velocity(mint, &record)
+    }
+
+    fn check_velocity(
+        &mut self,
+        mint: Pubkey,
+        record: &TradeRecord,
+    ) -> Option<LaunchVelocityAlert> {
+        let thresholds = self.velocity?;
+        let launch = self.launches.get_mut(&mint)?;
+        let us_after_create = record.timestamp_us - launch.created_at_us;
+        if us_after_create > thresholds.within.as_micros() as i64 {
+            self.launches.remove(&mint);
+            return None;
+        }
+
+        launch.trades += 1;
+        launch.sol_volume += record.sol_amount;
+        if let Some(trader) = record.trader.filter(|_| record.is_buy) {
+            launch.buyers.insert(trader);
+        }
+        let unique_buyers = launch.buyers.len() as u64;
+        let trigger = if thresholds.min_sol_volume.is_some_and(|min| launch.sol_volume >= min) {
+            VelocityTrigger::SolVolume
+        } else if thresholds.min_unique_buyers.is_some_and(|min| unique_buyers >= min) {
+            VelocityTrigger::UniqueBuyers
+        } else {
+            return None;
+        };
+        let launch = self.launches.remove(&mint)?;
+        Some(LaunchVelocityAlert {
+            mint,
+            trigger,
+            created_at_us: launch.created_at_us,
+            us_after_create,
+            trades: launch.trades,
+            sol_volume: launch.sol_volume,
+            unique_buyers,
+        })
+    }
+
+    /// 查询 `mint` 在 `now_us` 之前一个窗口内的统计，没有任何记录时返回 None
+    pub fn stats(&self, mint: &Pubkey, window: StatsWindow, now_us: i64) -> Option<MintStats> {
+        let records = self.trades.get(mint)?;
+        let since = now_us - window.duration_us();
+
+        let mut stats = MintStats::default();
+        let mut traders = HashSet::new();
+        for r in records.iter().rev().take_while(|r| r.timestamp_us > since) {
+            stats.trades += 1;
+            if r.is_buy {
+                stats.buys += 1;
+            } else {
+                stats.sells += 1;
+            }
+            stats.token_volume += r.token_amount as u128;
+            stats.sol_volume += r.sol_amount;
+            if let Some(trader) = r.trader {
+                traders.insert(trader);
+            }
+        }
+        stats.unique_traders = traders.len() as u64;
+        Some(stats)
+    }
+
+    /// 到达输出间隔时，为最近 1 小时内有成交的每个 mint 生成快照
+    ///
+    /// 同时清理过期记录和超出速度告警时限的新币；未启用周期输出时只做清理。
+    pub fn poll(&mut self, now_us: i64) -> Vec<MintStatsEvent> {
+        self.trades.retain(|_, records| {
+            prune(records, now_us);
+            !records.is_empty()
+        });
+        if let Some(thresholds) = self.velocity {
+            let since = now_us - thresholds.within.as_micros() as i64;
+            self.launches.retain(|_, launch| launch.created_at_us >= since);
+        }
+
+        let Some(interval) = self.emit_interval_us else { return Vec::new() };
+        if now_us - self.last_emit_us < interval {
+            return Vec::new();
+        }
+        self.last_emit_us = now_us;
+
+        self.trades
+            .keys()
+            .map(|mint| {
+                let [one_minute, five_minutes, one_hour] =
+                    StatsWindow::ALL.map(|w| self.stats(mint, w, now_us).unwrap_or_default());
+                MintStatsEvent {
+                    mint: *mint,
+                    timestamp_us: now_us,
+                    one_minute,
+                    five_minutes,
+                    one_hour,
+                }
+            })
+            .collect()
+    }
+}
+
+/// 丢弃超出最大窗口的记录
+fn prune(records: &mut VecDeque<TradeRecord>, now_us: i64) {
+    let since = now_us - StatsWindow::OneHour.duration_us();
+    while records.front().is_some_and(|r| r.timestamp_us <= since) {
+        records.pop_front();
+    }
+}
+
+/// 事件时间：优先使用区块时间，缺失时使用接收时间
+fn event_time_us(event: &DexEvent) -> i64 {
+    let metadata = event.metadata();
+    if metadata.block_time_us > 0 {
+        metadata.block_time_us
+    } else {
+        metadata.grpc_recv_us
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::*;
+    use crate::core::test_fixtures::{trade, MINUTE_US};
+
+    #[test]
+    fn test_rolling_windows() {
+        let mint = Pubkey::new_unique();
+        let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let now = 10 * MINUTE_US;
+
+        let mut tracker = MintStatsTracker::new();
+        tracker.observe(&trade(mint, alice, true, 100, now - 4 * MINUTE_US));
+        tracker.observe(&trade(mint, bob, true, 200, now - 30_000_000));
+        tracker.observe(&trade(mint, alice, false, 50, now - 10_000_000));
+
+        let m1 = tracker.stats(&mint, StatsWindow::OneMinute, now).unwrap();
+        assert_eq!((m1.trades, m1.buys, m1.sells), (2, 1, 1));
+        assert_eq!(m1.sol_volume, 250);
+        assert_eq!(m1.token_volume, 2_500);
+        assert_eq!(m1.unique_traders, 2);
+        assert_eq!(m1.buy_sell_ratio(), Some(1.0));
+
+        let m5 = tracker.stats(&mint, StatsWindow::FiveMinutes, now).unwrap();
+        assert_eq!((m5.trades, m5.unique_traders), (3, 2));
+        assert_eq!(m5.buy_sell_ratio(), Some(2.0));
+
+        assert!(tracker.stats(&Pubkey::new_unique(), StatsWindow::OneHour, now).is_none());
+    }
+
+    #[test]
+    fn test_poll_emits_and_prunes() {
+        let mint = Pubkey::new_unique();
+        let mut tracker =
+            MintStatsTracker::new().with_emit_interval(std::time::Duration::from_secs(60));
+        tracker.observe(&trade(mint, Pubkey::new_unique(), true, 100, MINUTE_US));
+
+        let events = tracker.poll(2 * MINUTE_US);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].mint, mint);
+        assert_eq!(events[0].one_minute.trades, 0);
+        assert_eq!(events[0].five_minutes.trades, 1);
+
+        // 未到输出间隔
+        assert!(tracker.poll(2 * MINUTE_US + 1).is_empty());
+
+        // 超过 1 小时后记录被清理
+        assert!(tracker.poll(2 * MINUTE_US + 3_600_000_000).is_empty());
+        assert!(tracker.stats(&mint, StatsWindow::OneHour, 2 * MINUTE_US).is_none());
+    }
+
+    #[test]
+    fn test_launch_velocity_alert() {
+        let create = |mint, at_us| {
+            DexEvent::PumpFunCreate(PumpFunCreateTokenEvent {
+                metadata: EventMetadata { block_time_us: at_us, ..Default::default() },
+                mint,
+                ..Default::default()
+            })
+        };
+        let thresholds = VelocityThresholds {
+            within: std::time::Duration::from_secs(60),
+            min_sol_volume: Some(1_000),
+            min_unique_buyers: Some(3),
+        };
+        let mut tracker = MintStatsTracker::new().with_velocity_alert(thresholds);
+        let (fast, slow, old) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let alice = Pubkey::new_unique();
+        for mint in [fast, slow, old] {
+            assert_eq!(tracker.observe(&create(mint, 0)), None);
+        }
+
+        // 同一买家重复买入只算一个独立买家，三个买家时触发
+        assert_eq!(tracker.observe(&trade(fast, alice, true, 10, 1_000_000)), None);
+        assert_eq!(tracker.observe(&trade(fast, alice, true, 10, 2_000_000)), None);
+        assert_eq!(tracker.observe(&trade(fast, Pubkey::new_unique(), false, 10, 3_000_000)), None);
+        assert_eq!(tracker.observe(&trade(fast, Pubkey::new_unique(), true, 10, 4_000_000)), None);
+        let alert = tracker.observe(&trade(fast, Pubkey::new_unique(), true, 10, 5_000_000));
+        let alert = alert.unwrap();
+        assert_eq!(alert.trigger, VelocityTrigger::UniqueBuyers);
+        assert_eq!((alert.us_after_create, alert.trades, alert.sol_volume), (5_000_000, 5, 50));
+        // 每个 mint 只告警一次
+        assert_eq!(
+            tracker.observe(&trade(fast, Pubkey::new_unique(), true, 5_000, 6_000_000)),
+            None
+        );
+
+        let alert = tracker.observe(&trade(slow, alice, true, 1_000, 30_000_000)).unwrap();
+        assert_eq!((alert.trigger, alert.unique_buyers), (VelocityTrigger::SolVolume, 1));
+
+        // 超出时限后不再告警
+        assert_eq!(tracker.observe(&trade(old, alice, true, 5_000, 61_000_000)), None);
+    }
+}
@@ -0,0 +1,58 @@
+//! 事件分析模块
+//!
+//! 基于解析出的 `DexEvent` 流做轻量的在线分析：
+//! - `pool_registry`：从事件中学习池子 → 代币对映射
+//! - `pool_created`：把各协议的建池事件统一为 `NormalizedPoolCreated`
+//! - `migration`：关联迁移事件与目标 DEX 的建池事件（`MigrationCompleted`）
+//! - `swap`：把各协议的 swap 事件统一为输入/输出代币与数量
+//! - `arb`：跨池价差检测
+//! - `bundle`：同区块相邻交易的捆绑检测
+//! - `event_clock`：按 slot 时间线与端点延迟估计亚秒级事件时间
+//! - `fees`：配置账户的费率档位变更告警，Raydium swap 手续费拆分
+//! - `mint_stats`：按 mint 的滚动成交统计，新币速度告警
+//! - `holders`：基于 Token 账户流的持仓集中度估计
+//! - `propagation`：多区域实例交换接收时间，按 leader 比较传播延迟
+//! - `lifecycle`：新币发行后最早 N 笔交易（狙击检测）
+//! - `reputation`：创建者历史发行与跑路记录（可插拔数据源）
+//! - `quote`：按池子状态本地报价（恒定乘积 / CLMM / DLMM）
+//! - `tvl`：按池子的流动性净流入与金库储备变化，按窗口输出
+//! - `wash`：按 mint 的刷量交易评分（往返、固定数量、自筹资金）
+
+pub mod arb;
+pub mod bundle;
+pub mod event_clock;
+pub mod fees;
+pub mod holders;
+pub mod lifecycle;
+pub mod migration;
+pub mod mint_stats;
+pub mod pool_created;
+pub mod pool_registry;
+pub mod propagation;
+pub mod quote;
+pub mod reputation;
+pub mod swap;
+pub mod tvl;
+pub mod wash;
+
+pub use arb::{ArbDetector, ArbOpportunity};
+pub use bundle::{BundleDetector, BundleSuspected};
+pub use event_clock::{EventClock, EventClockConfig};
+pub use fees::{FeeTier, FeeTierChanged, FeeTierMonitor, SwapFeeBreakdown};
+pub use holders::{ConcentrationAlert, HolderSnapshot, HolderTracker};
+pub use lifecycle::{LaunchProtocol, LaunchSnipersReport, LaunchTracker, LaunchTrade};
+pub use migration::MigrationLinker;
+pub use mint_stats::{
+    LaunchVelocityAlert, MintStats, MintStatsEvent, MintStatsTracker, StatsWindow,
+    VelocityThresholds, VelocityTrigger,
+};
+pub use pool_created::{insert_pool_created, normalize_pool_created};
+pub use pool_registry::{PoolInfo, PoolRegistry};
+pub use propagation::{PropagationDelta, PropagationTracker, RecvReport, RecvSample};
+pub use quote::{PoolState, PoolStateTracker, Quote, SwapDirection};
+pub use reputation::{CreatorHistory, CreatorReputation, InMemoryCreatorReputation};
+pub use swap::{
+    normalize_swap, NormalizedSwap, SwapSide, QUOTE_MINTS, USDC_MINT, USDT_MINT, WSOL_MINT,
+};
+pub use tvl::{PoolTvlDelta, TvlTracker};
+pub use wash::{WashTradeDetector, WashTradeScore};
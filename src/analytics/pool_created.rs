@@ -0,0 +1,115 @@
+//! 统一的建池事件
+//!
+//! 各协议的建池事件（PumpSwap / Bonk / Raydium CLMM / CPMM / AMM V4 / Orca Whirlpool /
+//! Meteora Pools / Meteora DLMM）字段名各不相同，这里统一为 [`NormalizedPoolCreatedEvent`]，
+//! 新池监控只需订阅 `DexEvent::NormalizedPoolCreated` 一个变体。
+//!
+//! 代币对来自事件数据或指令账户，账户未填充（mint 为默认值）时不输出。
+
+use crate::core::events::{DexEvent, NormalizedPoolCreatedEvent};
+use solana_sdk::pubkey::Pubkey;
+
+/// 把建池事件统一为 [`NormalizedPoolCreatedEvent`]，非建池事件或代币对未知时返回 None
+pub fn normalize_pool_created(event: &DexEvent) -> Option<NormalizedPoolCreatedEvent> {
+    let (pool, mint_a, mint_b, creator) = match event {
+        DexEvent::PumpSwapCreatePool(e) => (e.pool, e.base_mint, e.quote_mint, e.creator),
+        DexEvent::BonkPoolCreate(e) => (e.pool_state, e.base_mint, e.quote_mint, e.creator),
+        DexEvent::RaydiumClmmCreatePool(e) => (e.pool, e.token_0_mint, e.token_1_mint, e.creator),
+        DexEvent::RaydiumCpmmInitialize(e) => (e.pool, e.token_0_mint, e.token_1_mint, e.creator),
+        DexEvent::RaydiumAmmV4Initialize2(e) => (e.amm, e.coin_mint, e.pc_mint, e.user_wallet),
+        DexEvent::OrcaWhirlpoolPoolInitialized(e) => {
+            (e.whirlpool, e.token_mint_a, e.token_mint_b, Pubkey::default())
+        }
+        DexEvent::MeteoraPoolsPoolCreated(e) => {
+            (e.pool, e.token_a_mint, e.token_b_mint, Pubkey::default())
+        }
+        DexEvent::MeteoraDlmmInitializePool(e) => {
+            (e.pool, e.token_x_mint, e.token_y_mint, e.creator)
+        }
+        _ => return None,
+    };
+    if mint_a == Pubkey::default() || mint_b == Pubkey::default() {
+        return None;
+    }
+    Some(NormalizedPoolCreatedEvent {
+        metadata: *event.metadata(),
+        source: event.event_type_name().to_string(),
+        pool,
+        mint_a,
+        mint_b,
+        creator,
+    })
+}
+
+/// 在每个建池事件之后插入 `DexEvent::NormalizedPoolCreated`，返回插入数
+pub fn insert_pool_created(events: &mut Vec<DexEvent>) -> usize {
+    let created: Vec<_> = events
+        .iter()
+        .enumerate()
+        .filter_map(|(i, event)| Some((i, normalize_pool_created(event)?)))
+        .collect();
+    // 从后往前插入，前面的下标不受影响
+    for (i, event) in created.iter().rev() {
+        events.insert(i + 1, DexEvent::NormalizedPoolCreated(event.clone()));
+    }
+    created.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytics::WSOL_MINT;
+    use crate::core::events::{
+        EventMetadata, MeteoraDlmmInitializePoolEvent, PumpSwapBuyEvent, PumpSwapCreatePoolEvent,
+        RaydiumCpmmInitializeEvent,
+    };
+
+    #[test]
+    fn test_insert_pool_created() {
+        let (pool, mint, creator) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let mut events = vec![
+            DexEvent::PumpSwapCreatePool(PumpSwapCreatePoolEvent {
+                pool,
+                base_mint: mint,
+                quote_mint: WSOL_MINT,
+                creator,
+                ..Default::default()
+            }),
+            DexEvent::PumpSwapBuy(PumpSwapBuyEvent { pool, ..Default::default() }),
+            DexEvent::MeteoraDlmmInitializePool(MeteoraDlmmInitializePoolEvent {
+                metadata: EventMetadata::default(),
+                pool,
+                creator,
+                active_bin_id: 0,
+                bin_step: 25,
+                token_x_mint: mint,
+                token_y_mint: WSOL_MINT,
+            }),
+            // 账户未填充，代币对未知
+            DexEvent::RaydiumCpmmInitialize(RaydiumCpmmInitializeEvent {
+                pool,
+                ..Default::default()
+            }),
+        ];
+
+        assert_eq!(insert_pool_created(&mut events), 2);
+        let names: Vec<_> = events.iter().map(|e| e.event_type_name()).collect();
+        assert_eq!(
+            names,
+            [
+                "PumpSwapCreatePool",
+                "NormalizedPoolCreated",
+                "PumpSwapBuy",
+                "MeteoraDlmmInitializePool",
+                "NormalizedPoolCreated",
+                "RaydiumCpmmInitialize",
+            ]
+        );
+        let DexEvent::NormalizedPoolCreated(e) = &events[1] else { unreachable!() };
+        assert_eq!((e.pool, e.mint_a, e.mint_b, e.creator), (pool, mint, WSOL_MINT, creator));
+        assert_eq!(e.source, "PumpSwapCreatePool");
+        let DexEvent::NormalizedPoolCreated(e) = &events[4] else { unreachable!() };
+        assert_eq!(e.source, "MeteoraDlmmInitializePool");
+    }
+}
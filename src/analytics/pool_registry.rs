@@ -0,0 +1,91 @@
+//! 池子注册表：pool → (mint_a, mint_b)
+//!
+//! 部分协议的 swap 事件不包含代币 mint（Orca / Raydium CLMM / Meteora DLMM），
+//! 需要从建池事件中学习映射，或由调用方预先注册。
+
+use crate::core::bounded::{BoundedMap, BoundedMapConfig, EvictionStats};
+use super::pool_created::normalize_pool_created;
+use crate::core::events::DexEvent;
+use solana_sdk::pubkey::Pubkey;
+
+/// 池子的代币对，顺序与协议内部一致（a/0/x 在前）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolInfo {
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+}
+
+#[derive(Debug, Default)]
+pub struct PoolRegistry {
+    pools: BoundedMap<Pubkey, PoolInfo>,
+}
+
+impl PoolRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 限制注册表大小，超出容量或过期时淘汰最久未更新的池子（默认不限制）
+    pub fn with_limits(mut self, limits: BoundedMapConfig) -> Self {
+        self.pools.set_config(limits);
+        self
+    }
+
+    pub fn eviction_stats(&self) -> EvictionStats {
+        self.pools.eviction_stats()
+    }
+
+    /// 手动注册池子（例如启动时从 RPC 加载的池子列表）
+    pub fn register(&mut self, pool: Pubkey, mint_a: Pubkey, mint_b: Pubkey) {
+        self.pools.insert(pool, PoolInfo { mint_a, mint_b });
+    }
+
+    pub fn get(&self, pool: &Pubkey) -> Option<&PoolInfo> {
+        self.pools.get(pool)
+    }
+
+    /// 包含该代币的池子
+    pub fn pools_for_mint(&self, mint: &Pubkey) -> Vec<Pubkey> {
+        self.pools
+            .iter()
+            .filter(|(_, info)| info.mint_a == *mint || info.mint_b == *mint)
+            .map(|(pool, _)| *pool)
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.pools.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pools.is_empty()
+    }
+
+    /// 从携带池子代币信息的事件中学习映射
+    pub fn observe(&mut self, event: &DexEvent) {
+        let (pool, mint_a, mint_b) = match event {
+            DexEvent::PumpSwapCreatePool(e) => (e.pool, e.base_mint, e.quote_mint),
+            DexEvent::PumpSwapBuy(e) => (e.pool, e.base_mint, e.quote_mint),
+            DexEvent::PumpSwapSell(e) => (e.pool, e.base_mint, e.quote_mint),
+            DexEvent::OrcaWhirlpoolPoolInitialized(e) => {
+                (e.whirlpool, e.token_mint_a, e.token_mint_b)
+            }
+            DexEvent::RaydiumClmmCreatePool(e) => (e.pool, e.token_0_mint, e.token_1_mint),
+            DexEvent::RaydiumCpmmInitialize(e) => (e.pool, e.token_0_mint, e.token_1_mint),
+            DexEvent::MeteoraPoolsPoolCreated(e) => (e.pool, e.token_a_mint, e.token_b_mint),
+            DexEvent::MeteoraDammV2Swap(e) => (e.pool, e.token_a_mint, e.token_b_mint),
+            DexEvent::NormalizedPoolCreated(e) => (e.pool, e.mint_a, e.mint_b),
+            DexEvent::RaydiumAmmV4AmmInfoAccount(e) => {
+                (e.pubkey, e.amm_info.coin_vault_mint, e.amm_info.pc_vault_mint)
+            }
+            _ => match normalize_pool_created(event) {
+                Some(created) => (created.pool, created.mint_a, created.mint_b),
+                None => return,
+            },
+        };
+        // 账户未填充时 mint 为默认值，不记录
+        if mint_a != Pubkey::default() && mint_b != Pubkey::default() {
+            self.register(pool, mint_a, mint_b);
+        }
+    }
+}
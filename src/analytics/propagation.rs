@@ -0,0 +1,277 @@
+//! 多区域接收时间对比
+//!
+//! 在多个区域运行解析器时，各实例用 [`PropagationTracker`] 记录每笔交易的首次接收时间，
+//! 定期交换 [`RecvReport`]（`(signature, slot, recv_us)` 样本），按签名（交易级去重键）
+//! 配对后得到同一笔交易在各区域之间的到达时间差，再按出块 leader 汇总，用于端点选择：
+//! 某个区域对某些 leader 的交易稳定更快时，可以按 leader schedule 切换订阅端点。
+//!
+//! 时间差 = 远端接收时间 - 本地接收时间，正数表示本地更早收到。
+//! 差值包含各实例之间的时钟偏差，需要 NTP / PTP 同步；跨 leader 的相对比较不受恒定偏差影响。
+//!
+//! ```ignore
+//! let mut tracker = PropagationTracker::new("fra");
+//! tracker.observe(&event);
+//! transport.send(tracker.take_report().to_bytes()?);
+//! tracker.merge(&RecvReport::from_bytes(&received)?);
+//! for delta in tracker.deltas() {
+//!     println!("{} {:?}: {}us ({} samples)", delta.region, delta.leader, delta.mean_us, delta.samples);
+//! }
+//! ```
+
+use crate::core::events::DexEvent;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use std::collections::{BTreeMap, HashMap};
+
+/// 单笔交易的接收时间样本
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecvSample {
+    pub signature: Signature,
+    pub slot: u64,
+    pub recv_us: i64,
+}
+
+/// 实例之间交换的样本批次
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecvReport {
+    /// 发送方区域名
+    pub region: String,
+    pub samples: Vec<RecvSample>,
+}
+
+impl RecvReport {
+    /// bincode 编码，传输方式（UDP、Redis、HTTP 等）由调用方决定
+    pub fn to_bytes(&self) -> Result<Vec<u8>, bincode::Error> {
+        bincode::serialize(self)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, bincode::Error> {
+        bincode::deserialize(bytes)
+    }
+}
+
+/// 某个远端区域相对本地、在某个 leader 出块的交易上的到达时间差
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropagationDelta {
+    pub region: String,
+    /// 出块 leader，未知时为 None
+    pub leader: Option<Pubkey>,
+    pub samples: u64,
+    /// 平均时间差（远端 - 本地，微秒）
+    pub mean_us: i64,
+    pub min_us: i64,
+    pub max_us: i64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct DeltaAcc {
+    count: u64,
+    sum: i128,
+    min: i64,
+    max: i64,
+}
+
+impl DeltaAcc {
+    fn new(delta: i64) -> Self {
+        Self { count: 1, sum: delta as i128, min: delta, max: delta }
+    }
+
+    fn add(&mut self, delta: i64) {
+        self.count += 1;
+        self.sum += delta as i128;
+        self.min = self.min.min(delta);
+        self.max = self.max.max(delta);
+    }
+
+    fn merge(&mut self, other: &DeltaAcc) {
+        self.count += other.count;
+        self.sum += other.sum;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+}
+
+/// 本地接收样本与远端报告的配对器，按 slot 调用 [`Self::prune_before`] 控制内存
+#[derive(Debug)]
+pub struct PropagationTracker {
+    region: String,
+    local: HashMap<Signature, RecvSample>,
+    /// 尚未发送的本地样本
+    outgoing: Vec<RecvSample>,
+    /// 远端区域 -> 本地尚未收到的样本
+    remote: HashMap<String, HashMap<Signature, RecvSample>>,
+    leaders: HashMap<u64, Pubkey>,
+    /// (远端区域, slot) -> 时间差；leader 往往在 slot 的交易之后才确定，汇总时再归属
+    slot_deltas: HashMap<(String, u64), DeltaAcc>,
+}
+
+impl PropagationTracker {
+    pub fn new(region: impl Into<String>) -> Self {
+        Self {
+            region: region.into(),
+            local: HashMap::new(),
+            outgoing: Vec::new(),
+            remote: HashMap::new(),
+            leaders: HashMap::new(),
+            slot_deltas: HashMap::new(),
+        }
+    }
+
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+
+    /// 记录本地事件：block meta 提供 slot leader，其余实时事件按签名取首次接收时间
+    pub fn observe(&mut self, event: &DexEvent) {
+        if let DexEvent::BlockMeta(meta) = event {
+            if meta.leader != Pubkey::default() {
+                self.set_leader(meta.metadata.slot, meta.leader);
+            }
+            return;
+        }
+        let metadata = event.metadata();
+        if metadata.grpc_recv_us <= 0
+            || metadata.replayed
+            || self.local.contains_key(&metadata.signature)
+        {
+            return;
+        }
+        let sample = RecvSample {
+            signature: metadata.signature,
+            slot: metadata.slot,
+            recv_us: metadata.grpc_recv_us,
+        };
+        self.local.insert(sample.signature, sample);
+        self.outgoing.push(sample);
+        for (region, pending) in &mut self.remote {
+            if let Some(remote) = pending.remove(&sample.signature) {
+                record_delta(&mut self.slot_deltas, region, &sample, remote.recv_us);
+            }
+        }
+    }
+
+    /// 设置 slot leader（如 `SlotEvents::leader` 或 [`LeaderSchedule`](crate::grpc::LeaderSchedule)）
+    pub fn set_leader(&mut self, slot: u64, leader: Pubkey) {
+        self.leaders.insert(slot, leader);
+    }
+
+    /// 取出自上次调用以来的本地样本，发送给其他实例
+    pub fn take_report(&mut self) -> RecvReport {
+        RecvReport { region: self.region.clone(), samples: std::mem::take(&mut self.outgoing) }
+    }
+
+    /// 合并其他实例的报告；本区域自己的报告被忽略
+    pub fn merge(&mut self, report: &RecvReport) {
+        if report.region == self.region {
+            return;
+        }
+        let pending = self.remote.entry(report.region.clone()).or_default();
+        for sample in &report.samples {
+            match self.local.get(&sample.signature) {
+                Some(local) => {
+                    record_delta(&mut self.slot_deltas, &report.region, local, sample.recv_us)
+                }
+                None => {
+                    pending.entry(sample.signature).or_insert(*sample);
+                }
+            }
+        }
+    }
+
+    /// 按 (远端区域, leader) 汇总的时间差，按区域名排序
+    pub fn deltas(&self) -> Vec<PropagationDelta> {
+        let mut grouped: BTreeMap<(&str, Option<Pubkey>), DeltaAcc> = BTreeMap::new();
+        for ((region, slot), acc) in &self.slot_deltas {
+            let key = (region.as_str(), self.leaders.get(slot).copied());
+            grouped.entry(key).and_modify(|total| total.merge(acc)).or_insert(*acc);
+        }
+        grouped
+            .into_iter()
+            .map(|((region, leader), acc)| PropagationDelta {
+                region: region.to_string(),
+                leader,
+                samples: acc.count,
+                mean_us: (acc.sum / acc.count as i128) as i64,
+                min_us: acc.min,
+                max_us: acc.max,
+            })
+            .collect()
+    }
+
+    /// 移除早于 `slot` 的样本、leader 与时间差
+    pub fn prune_before(&mut self, slot: u64) {
+        self.local.retain(|_, s| s.slot >= slot);
+        self.outgoing.retain(|s| s.slot >= slot);
+        for pending in self.remote.values_mut() {
+            pending.retain(|_, s| s.slot >= slot);
+        }
+        self.remote.retain(|_, pending| !pending.is_empty());
+        self.leaders.retain(|&s, _| s >= slot);
+        self.slot_deltas.retain(|(_, s), _| *s >= slot);
+    }
+}
+
+fn record_delta(
+    slot_deltas: &mut HashMap<(String, u64), DeltaAcc>,
+    region: &str,
+    local: &RecvSample,
+    remote_recv_us: i64,
+) {
+    let delta = remote_recv_us - local.recv_us;
+    slot_deltas
+        .entry((region.to_string(), local.slot))
+        .and_modify(|acc| acc.add(delta))
+        .or_insert_with(|| DeltaAcc::new(delta));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::{BlockMetaEvent, EventMetadata, PumpFunTradeEvent};
+
+    fn trade(signature: Signature, slot: u64, recv_us: i64) -> DexEvent {
+        let metadata =
+            EventMetadata { signature, slot, grpc_recv_us: recv_us, ..Default::default() };
+        DexEvent::PumpFunTrade(PumpFunTradeEvent { metadata, ..Default::default() })
+    }
+
+    #[test]
+    fn test_propagation_deltas() {
+        let (leader_a, leader_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let sigs: Vec<Signature> = (1..=3u8).map(|i| Signature::from([i; 64])).collect();
+        let mut fra = PropagationTracker::new("fra");
+        let mut nyc = PropagationTracker::new("nyc");
+
+        // fra 先收到 slot 100（leader_a）的交易，nyc 先收到 slot 101（leader_b）的交易
+        fra.observe(&trade(sigs[0], 100, 1_000));
+        fra.observe(&trade(sigs[0], 100, 1_050)); // 同一交易的第二个事件不计入
+        fra.observe(&trade(sigs[1], 100, 2_000));
+        nyc.observe(&trade(sigs[0], 100, 1_300));
+        nyc.observe(&trade(sigs[1], 100, 2_100));
+        nyc.observe(&trade(sigs[2], 101, 3_000));
+
+        // 报告可以早于本地事件到达
+        let report = RecvReport::from_bytes(&nyc.take_report().to_bytes().unwrap()).unwrap();
+        assert_eq!(report.samples.len(), 3);
+        fra.merge(&report);
+        let own = fra.take_report();
+        fra.merge(&own); // 自己的报告被忽略
+        fra.observe(&trade(sigs[2], 101, 3_500));
+        fra.observe(&DexEvent::BlockMeta(BlockMetaEvent {
+            metadata: EventMetadata { slot: 100, ..Default::default() },
+            leader: leader_a,
+        }));
+        fra.set_leader(101, leader_b);
+
+        let deltas = fra.deltas();
+        let of = |leader| deltas.iter().find(|d| d.leader == Some(leader)).unwrap();
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(of(leader_a).region, "nyc");
+        assert_eq!((of(leader_a).samples, of(leader_a).mean_us), (2, 200));
+        assert_eq!((of(leader_a).min_us, of(leader_a).max_us), (100, 300));
+        assert_eq!((of(leader_b).samples, of(leader_b).mean_us), (1, -500));
+
+        fra.prune_before(101);
+        assert_eq!(fra.deltas().len(), 1);
+    }
+}
@@ -0,0 +1,458 @@
+//! 基于池子状态的本地报价
+//!
+//! [`PoolStateTracker`] 从 swap 事件中维护池子状态，[`PoolState::quote`] 按协议族计算预期输出：
+//! - 恒定乘积（PumpFun bonding curve / PumpSwap）：储备完全来自事件，整数精确计算
+//! - CLMM（Raydium CLMM / Orca Whirlpool）：按已初始化 tick 逐段计算并跨越 tick
+//! - DLMM（Meteora DLMM）：从 active bin 起逐 bin 消耗流动性
+//!
+//! CLMM 的费率与 tick 流动性、DLMM 的 bin 储备不在事件中，需要调用方用账户数据通过
+//! [`PoolStateTracker::insert`] / [`PoolStateTracker::get_mut`] 写入，之后事件只更新价格与区间。
+//! CLMM / DLMM 用 f64 计算，结果为估计值；DLMM 只计基础费率，不含波动费。
+
+use crate::core::events::DexEvent;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::{BTreeMap, HashMap};
+
+/// Q64.64 定点数的 1.0
+const Q64: f64 = 18_446_744_073_709_551_616.0;
+/// CLMM 费率的分母（百万分之一）
+pub const FEE_RATE_DENOMINATOR: u32 = 1_000_000;
+
+/// 交换方向，a 为协议内部的 base / token 0 / token A / x
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwapDirection {
+    AToB,
+    BToA,
+}
+
+/// 报价结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Quote {
+    /// 实际可成交的输入数量（流动性不足时小于请求数量）
+    pub amount_in: u64,
+    pub amount_out: u64,
+    /// 输入侧收取的手续费
+    pub fee: u64,
+    /// 扣除手续费后成交价相对现价的偏离（基点）
+    pub price_impact_bps: u64,
+}
+
+/// 恒定乘积池：`reserve_a * reserve_b = k`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ConstantProductState {
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+    /// 输入侧收取的总费率（基点）
+    pub fee_bps: u64,
+}
+
+/// 集中流动性池
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClmmState {
+    pub sqrt_price_x64: u128,
+    /// 当前区间的流动性
+    pub liquidity: u128,
+    pub tick_current: i32,
+    /// 费率（百万分之一）
+    pub fee_rate: u32,
+    /// 已初始化 tick → liquidity_net
+    pub ticks: BTreeMap<i32, i128>,
+}
+
+/// DLMM 单个 bin 的储备
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Bin {
+    pub amount_x: u64,
+    pub amount_y: u64,
+}
+
+/// 离散流动性池
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DlmmState {
+    pub active_id: i32,
+    pub bin_step: u16,
+    /// 基础费率（基点）
+    pub base_fee_bps: u64,
+    pub bins: BTreeMap<i32, Bin>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PoolState {
+    ConstantProduct(ConstantProductState),
+    Clmm(ClmmState),
+    Dlmm(DlmmState),
+}
+
+impl PoolState {
+    /// 计算输入 `amount_in` 的预期输出，池子为空或无法成交时返回 None
+    pub fn quote(&self, amount_in: u64, direction: SwapDirection) -> Option<Quote> {
+        if amount_in == 0 {
+            return None;
+        }
+        match self {
+            PoolState::ConstantProduct(s) => s.quote(amount_in, direction),
+            PoolState::Clmm(s) => s.quote(amount_in, direction),
+            PoolState::Dlmm(s) => s.quote(amount_in, direction),
+        }
+    }
+}
+
+impl ConstantProductState {
+    fn quote(&self, amount_in: u64, direction: SwapDirection) -> Option<Quote> {
+        let (reserve_in, reserve_out) = match direction {
+            SwapDirection::AToB => (self.reserve_a as u128, self.reserve_b as u128),
+            SwapDirection::BToA => (self.reserve_b as u128, self.reserve_a as u128),
+        };
+        if reserve_in == 0 || reserve_out == 0 {
+            return None;
+        }
+        let fee = (amount_in as u128 * self.fee_bps as u128).div_ceil(10_000);
+        let net_in = (amount_in as u128).saturating_sub(fee);
+        let amount_out = reserve_out * net_in / (reserve_in + net_in);
+        // 现价输出 net_in * reserve_out / reserve_in，偏离即为 net_in / (reserve_in + net_in)
+        let price_impact_bps = (net_in * 10_000 / (reserve_in + net_in)) as u64;
+        Some(Quote { amount_in, amount_out: amount_out as u64, fee: fee as u64, price_impact_bps })
+    }
+}
+
+/// tick 对应的 sqrt 价格（非 Q64）
+fn sqrt_price_at_tick(tick: i32) -> f64 {
+    1.0001f64.powf(tick as f64 / 2.0)
+}
+
+/// sqrt 价格（Q64.64）所在的 tick
+fn tick_at_sqrt_price(sqrt_price_x64: u128) -> i32 {
+    let price = (sqrt_price_x64 as f64 / Q64).powi(2);
+    (price.ln() / 1.0001f64.ln()).floor() as i32
+}
+
+impl ClmmState {
+    fn quote(&self, amount_in: u64, direction: SwapDirection) -> Option<Quote> {
+        let fee_rate = self.fee_rate as f64 / FEE_RATE_DENOMINATOR as f64;
+        let mut sqrt_price = self.sqrt_price_x64 as f64 / Q64;
+        let mut liquidity = self.liquidity as f64;
+        let mut tick = self.tick_current;
+        if sqrt_price <= 0.0 {
+            return None;
+        }
+        let spot_price = sqrt_price * sqrt_price;
+        let net_in = amount_in as f64 * (1.0 - fee_rate);
+        let mut remaining = net_in;
+        let mut amount_out = 0.0;
+
+        while remaining > 0.0 {
+            // 下一个已初始化 tick：a→b 价格下降，向下找；b→a 向上找
+            let next = match direction {
+                SwapDirection::AToB => self.ticks.range(..=tick).next_back(),
+                SwapDirection::BToA => self.ticks.range(tick + 1..).next(),
+            };
+            let target = next.map(|(&t, _)| sqrt_price_at_tick(t));
+            if liquidity > 0.0 {
+                // 未到达下一个 tick 时本段即完成成交，否则价格移动到该 tick
+                let (step_in, step_out) = match direction {
+                    SwapDirection::AToB => {
+                        let max_in = target
+                            .map_or(f64::INFINITY, |p| liquidity * (1.0 / p - 1.0 / sqrt_price));
+                        if remaining < max_in {
+                            let p = liquidity / (liquidity / sqrt_price + remaining);
+                            (remaining, liquidity * (sqrt_price - p))
+                        } else {
+                            let p = target.unwrap_or_default();
+                            (max_in, liquidity * (sqrt_price - p))
+                        }
+                    }
+                    SwapDirection::BToA => {
+                        let max_in = target.map_or(f64::INFINITY, |p| liquidity * (p - sqrt_price));
+                        if remaining < max_in {
+                            let p = sqrt_price + remaining / liquidity;
+                            (remaining, liquidity * (1.0 / sqrt_price - 1.0 / p))
+                        } else {
+                            let p = target.unwrap_or_default();
+                            (max_in, liquidity * (1.0 / sqrt_price - 1.0 / p))
+                        }
+                    }
+                };
+                remaining -= step_in;
+                amount_out += step_out;
+                if remaining <= 0.0 {
+                    break;
+                }
+            }
+            // 跨越 tick 更新流动性；没有更多 tick 时剩余输入无法成交
+            let Some((&t, &net)) = next else { break };
+            sqrt_price = sqrt_price_at_tick(t);
+            match direction {
+                SwapDirection::AToB => {
+                    liquidity -= net as f64;
+                    tick = t - 1;
+                }
+                SwapDirection::BToA => {
+                    liquidity += net as f64;
+                    tick = t;
+                }
+            }
+            liquidity = liquidity.max(0.0);
+        }
+
+        let filled_net = net_in - remaining.max(0.0);
+        if filled_net <= 0.0 || amount_out < 1.0 {
+            return None;
+        }
+        let filled = (filled_net / (1.0 - fee_rate)).round().min(amount_in as f64) as u64;
+        let spot_out = match direction {
+            SwapDirection::AToB => filled_net * spot_price,
+            SwapDirection::BToA => filled_net / spot_price,
+        };
+        Some(Quote {
+            amount_in: filled,
+            amount_out: amount_out as u64,
+            fee: filled - filled_net.round() as u64,
+            price_impact_bps: impact_bps(amount_out, spot_out),
+        })
+    }
+
+    /// 价格移动到 `sqrt_price_x64` 所在 tick，沿途跨越的 tick 更新流动性
+    fn move_to(&mut self, sqrt_price_x64: u128) {
+        let tick = tick_at_sqrt_price(sqrt_price_x64);
+        if tick < self.tick_current {
+            for (_, net) in self.ticks.range(tick + 1..=self.tick_current) {
+                self.liquidity = (self.liquidity as i128 - net).max(0) as u128;
+            }
+        } else {
+            for (_, net) in self.ticks.range(self.tick_current + 1..=tick) {
+                self.liquidity = (self.liquidity as i128 + net).max(0) as u128;
+            }
+        }
+        self.sqrt_price_x64 = sqrt_price_x64;
+        self.tick_current = tick;
+    }
+}
+
+impl DlmmState {
+    /// bin 的价格（每单位 x 兑换的 y）
+    fn price(&self, id: i32) -> f64 {
+        (1.0 + self.bin_step as f64 / 10_000.0).powi(id)
+    }
+
+    fn quote(&self, amount_in: u64, direction: SwapDirection) -> Option<Quote> {
+        let fee = (amount_in as u128 * self.base_fee_bps as u128).div_ceil(10_000) as u64;
+        let net_in = amount_in.saturating_sub(fee) as f64;
+        let mut remaining = net_in;
+        let mut amount_out = 0.0;
+
+        // x→y 从 active bin 向下消耗 y，y→x 向上消耗 x
+        let bins: Box<dyn Iterator<Item = (&i32, &Bin)>> = match direction {
+            SwapDirection::AToB => Box::new(self.bins.range(..=self.active_id).rev()),
+            SwapDirection::BToA => Box::new(self.bins.range(self.active_id..)),
+        };
+        for (&id, bin) in bins {
+            let price = self.price(id);
+            let (available, max_in) = match direction {
+                SwapDirection::AToB => (bin.amount_y as f64, bin.amount_y as f64 / price),
+                SwapDirection::BToA => (bin.amount_x as f64, bin.amount_x as f64 * price),
+            };
+            if remaining <= max_in {
+                amount_out += match direction {
+                    SwapDirection::AToB => remaining * price,
+                    SwapDirection::BToA => remaining / price,
+                };
+                remaining = 0.0;
+                break;
+            }
+            amount_out += available;
+            remaining -= max_in;
+        }
+
+        let filled_net = net_in - remaining;
+        if filled_net <= 0.0 || amount_out < 1.0 {
+            return None;
+        }
+        let spot = self.price(self.active_id);
+        let spot_out = match direction {
+            SwapDirection::AToB => filled_net * spot,
+            SwapDirection::BToA => filled_net / spot,
+        };
+        let filled_fee = if remaining > 0.0 {
+            (filled_net * self.base_fee_bps as f64 / (10_000 - self.base_fee_bps.min(9_999)) as f64)
+                .ceil() as u64
+        } else {
+            fee
+        };
+        Some(Quote {
+            amount_in: filled_net as u64 + filled_fee,
+            amount_out: amount_out as u64,
+            fee: filled_fee,
+            price_impact_bps: impact_bps(amount_out, spot_out),
+        })
+    }
+}
+
+fn impact_bps(amount_out: f64, spot_out: f64) -> u64 {
+    if spot_out <= 0.0 {
+        return 0;
+    }
+    ((1.0 - amount_out / spot_out).max(0.0) * 10_000.0) as u64
+}
+
+/// 池子状态表：pool → 最新状态
+#[derive(Debug, Default)]
+pub struct PoolStateTracker {
+    pools: HashMap<Pubkey, PoolState>,
+}
+
+impl PoolStateTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 写入池子状态（例如从账户数据加载的 CLMM tick / DLMM bin）
+    pub fn insert(&mut self, pool: Pubkey, state: PoolState) {
+        self.pools.insert(pool, state);
+    }
+
+    pub fn get(&self, pool: &Pubkey) -> Option<&PoolState> {
+        self.pools.get(pool)
+    }
+
+    pub fn get_mut(&mut self, pool: &Pubkey) -> Option<&mut PoolState> {
+        self.pools.get_mut(pool)
+    }
+
+    pub fn len(&self) -> usize {
+        self.pools.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pools.is_empty()
+    }
+
+    /// 按已跟踪的状态报价，池子未知时返回 None
+    pub fn quote(&self, pool: &Pubkey, amount_in: u64, direction: SwapDirection) -> Option<Quote> {
+        self.pools.get(pool)?.quote(amount_in, direction)
+    }
+
+    /// 用 swap 事件更新池子状态
+    ///
+    /// 恒定乘积池直接写入成交后的储备；CLMM / DLMM 只更新已注册池子的价格与区间。
+    pub fn observe(&mut self, event: &DexEvent) {
+        match event {
+            DexEvent::PumpFunTrade(e)
+            | DexEvent::PumpFunBuy(e)
+            | DexEvent::PumpFunSell(e)
+            | DexEvent::PumpFunBuyExactSolIn(e) => {
+                // bonding curve 未填充时以 mint 作为池子标识
+                let pool = event.pool().unwrap_or(e.mint);
+                self.set_reserves(
+                    pool,
+                    e.virtual_token_reserves,
+                    e.virtual_sol_reserves,
+                    e.fee_basis_points + e.creator_fee_basis_points,
+                );
+            }
+            // 事件中的池子储备为成交前的值，LP 费留在池内
+            DexEvent::PumpSwapBuy(e) => self.set_reserves(
+                e.pool,
+                e.pool_base_token_reserves.saturating_sub(e.base_amount_out),
+                e.pool_quote_token_reserves + e.quote_amount_in_with_lp_fee,
+                e.lp_fee_basis_points
+                    + e.protocol_fee_basis_points
+                    + e.coin_creator_fee_basis_points,
+            ),
+            DexEvent::PumpSwapSell(e) => self.set_reserves(
+                e.pool,
+                e.pool_base_token_reserves + e.base_amount_in,
+                e.pool_quote_token_reserves.saturating_sub(e.quote_amount_out_without_lp_fee),
+                e.lp_fee_basis_points
+                    + e.protocol_fee_basis_points
+                    + e.coin_creator_fee_basis_points,
+            ),
+            DexEvent::RaydiumClmmSwap(e) => {
+                if let Some(PoolState::Clmm(s)) = self.pools.get_mut(&e.pool_state) {
+                    s.sqrt_price_x64 = e.sqrt_price_x64;
+                    s.liquidity = e.liquidity;
+                    s.tick_current = e.tick;
+                }
+            }
+            DexEvent::OrcaWhirlpoolSwap(e) => {
+                if let Some(PoolState::Clmm(s)) = self.pools.get_mut(&e.whirlpool) {
+                    if e.post_sqrt_price != 0 {
+                        s.move_to(e.post_sqrt_price);
+                    }
+                }
+            }
+            DexEvent::MeteoraDlmmSwap(e) => {
+                if let Some(PoolState::Dlmm(s)) = self.pools.get_mut(&e.pool) {
+                    s.active_id = e.end_bin_id;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn set_reserves(&mut self, pool: Pubkey, reserve_a: u64, reserve_b: u64, fee_bps: u64) {
+        if pool == Pubkey::default() {
+            return;
+        }
+        self.pools.insert(
+            pool,
+            PoolState::ConstantProduct(ConstantProductState { reserve_a, reserve_b, fee_bps }),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::PumpSwapBuyEvent;
+
+    #[test]
+    fn test_quote_per_protocol_family() {
+        // 恒定乘积：事件储备为成交前的值
+        let pool = Pubkey::new_unique();
+        let mut tracker = PoolStateTracker::new();
+        tracker.observe(&DexEvent::PumpSwapBuy(PumpSwapBuyEvent {
+            pool,
+            pool_base_token_reserves: 1_000_100,
+            pool_quote_token_reserves: 999_900,
+            base_amount_out: 100,
+            quote_amount_in_with_lp_fee: 100,
+            lp_fee_basis_points: 20,
+            protocol_fee_basis_points: 5,
+            ..Default::default()
+        }));
+        let q = tracker.quote(&pool, 10_000, SwapDirection::BToA).unwrap();
+        assert_eq!((q.amount_in, q.fee, q.amount_out, q.price_impact_bps), (10_000, 25, 9_876, 98));
+
+        // CLMM：输入足以跨越下方 tick，跨越后流动性减少
+        let ticks = BTreeMap::from([(-100, 500_000_000i128)]);
+        let clmm = PoolState::Clmm(ClmmState {
+            sqrt_price_x64: Q64 as u128,
+            liquidity: 1_000_000_000,
+            tick_current: 0,
+            fee_rate: 3_000,
+            ticks,
+        });
+        let small = clmm.quote(1_000, SwapDirection::AToB).unwrap();
+        assert_eq!((small.fee, small.amount_out), (3, 996));
+        let large = clmm.quote(10_000_000, SwapDirection::AToB).unwrap();
+        assert_eq!(large.amount_in, 10_000_000);
+        assert!(large.amount_out < 9_970_000 && large.price_impact_bps > 100);
+
+        // DLMM：逐 bin 消耗，流动性不足时部分成交
+        let dlmm = PoolState::Dlmm(DlmmState {
+            active_id: 0,
+            bin_step: 100,
+            base_fee_bps: 0,
+            bins: BTreeMap::from([
+                (0, Bin { amount_x: 0, amount_y: 1_000 }),
+                (-1, Bin { amount_x: 0, amount_y: 1_000 }),
+            ]),
+        });
+        let q = dlmm.quote(500, SwapDirection::AToB).unwrap();
+        assert_eq!((q.amount_out, q.price_impact_bps), (500, 0));
+        let q = dlmm.quote(5_000, SwapDirection::AToB).unwrap();
+        assert_eq!(q.amount_out, 2_000);
+        assert!(q.amount_in < 5_000);
+        assert!(dlmm.quote(500, SwapDirection::BToA).is_none());
+    }
+}
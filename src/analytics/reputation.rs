@@ -0,0 +1,176 @@
+//! 创建者信誉
+//!
+//! [`CreatorReputation`] 按创建者查询历史发行数和跑路次数，可接入外部数据源；
+//! [`InMemoryCreatorReputation`] 由 [`LaunchTracker`](super::LaunchTracker) 输出的历史报告累计，
+//! 配合 `LaunchTracker::with_reputation` 为新发行附带创建者的历史记录。
+
+use super::lifecycle::LaunchSnipersReport;
+use crate::core::bounded::{BoundedMap, BoundedMapConfig, EvictionStats};
+use parking_lot::RwLock;
+use solana_sdk::pubkey::Pubkey;
+
+/// 默认跑路判定：创建者卖出其买入数量的 90% 以上
+const DEFAULT_RUG_SELL_RATIO: f64 = 0.9;
+
+/// 创建者的历史发行记录
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CreatorHistory {
+    pub prior_launches: u32,
+    pub rug_count: u32,
+}
+
+/// 创建者信誉数据源
+pub trait CreatorReputation: Send + Sync {
+    /// 查询创建者的历史记录，未知创建者返回默认值
+    fn history(&self, creator: &Pubkey) -> CreatorHistory;
+}
+
+#[derive(Debug, Default)]
+struct ReputationState {
+    creators: BoundedMap<Pubkey, CreatorHistory>,
+    /// 已计入的发行，同一发行只计一次
+    launches: BoundedMap<Pubkey, ()>,
+}
+
+/// 基于历史发行报告的内存实现，可通过 `Arc` 在跟踪器和调用方之间共享
+#[derive(Debug)]
+pub struct InMemoryCreatorReputation {
+    state: RwLock<ReputationState>,
+    rug_sell_ratio: f64,
+}
+
+impl Default for InMemoryCreatorReputation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryCreatorReputation {
+    pub fn new() -> Self {
+        Self { state: RwLock::default(), rug_sell_ratio: DEFAULT_RUG_SELL_RATIO }
+    }
+
+    /// 创建者在报告的交易中卖出其买入代币数量的多少比例视为跑路（默认 0.9）
+    pub fn with_rug_sell_ratio(mut self, ratio: f64) -> Self {
+        self.rug_sell_ratio = ratio;
+        self
+    }
+
+    /// 限制记录的创建者数和去重用的发行数，超出容量或过期时淘汰最久未更新的记录（默认不限制）
+    pub fn with_limits(self, limits: BoundedMapConfig) -> Self {
+        {
+            let mut state = self.state.write();
+            state.creators.set_config(limits);
+            state.launches.set_config(limits);
+        }
+        self
+    }
+
+    /// 创建者记录的淘汰计数
+    pub fn eviction_stats(&self) -> EvictionStats {
+        self.state.read().creators.eviction_stats()
+    }
+
+    /// 计入一个发行报告；报告交易中创建者卖出达到阈值时记为跑路
+    ///
+    /// 只能看到报告内的最早 N 笔交易，之后的抛售需通过 [`Self::record_rug`] 补充。
+    pub fn record_report(&self, report: &LaunchSnipersReport) {
+        let mut state = self.state.write();
+        if state.launches.insert(report.launch, ()).is_some() {
+            return;
+        }
+        let history = state.creators.get_or_insert_with(report.creator, CreatorHistory::default);
+        history.prior_launches += 1;
+        if self.is_rug(report) {
+            history.rug_count += 1;
+        }
+    }
+
+    /// 由外部信号（如流动性撤出、价格归零）记录一次跑路
+    pub fn record_rug(&self, creator: Pubkey) {
+        self.state
+            .write()
+            .creators
+            .get_or_insert_with(creator, CreatorHistory::default)
+            .rug_count += 1;
+    }
+
+    /// 已记录的创建者数量
+    pub fn len(&self) -> usize {
+        self.state.read().creators.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.state.read().creators.is_empty()
+    }
+
+    fn is_rug(&self, report: &LaunchSnipersReport) -> bool {
+        let (mut bought, mut sold) = (0u128, 0u128);
+        for trade in report.trades.iter().filter(|t| t.is_creator) {
+            if trade.is_buy {
+                bought += trade.token_amount as u128;
+            } else {
+                sold += trade.token_amount as u128;
+            }
+        }
+        bought > 0 && sold as f64 >= bought as f64 * self.rug_sell_ratio
+    }
+}
+
+impl CreatorReputation for InMemoryCreatorReputation {
+    fn history(&self, creator: &Pubkey) -> CreatorHistory {
+        self.state.read().creators.get(creator).copied().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytics::LaunchTracker;
+    use crate::core::events::*;
+    use std::sync::Arc;
+
+    fn create(mint: Pubkey, creator: Pubkey) -> DexEvent {
+        DexEvent::PumpFunCreate(PumpFunCreateTokenEvent { mint, creator, ..Default::default() })
+    }
+
+    fn trade(mint: Pubkey, user: Pubkey, is_buy: bool) -> DexEvent {
+        DexEvent::PumpFunTrade(PumpFunTradeEvent {
+            mint,
+            user,
+            is_buy,
+            token_amount: 1_000,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_creator_history_annotates_launches() {
+        let creator = Pubkey::new_unique();
+        let reputation = Arc::new(InMemoryCreatorReputation::new());
+        let mut tracker = LaunchTracker::new(2).with_reputation(reputation.clone());
+
+        // 第一次发行：创建者买入后全部卖出
+        let first = Pubkey::new_unique();
+        tracker.observe(&create(first, creator));
+        tracker.observe(&trade(first, creator, true));
+        let report = tracker.observe(&trade(first, creator, false)).unwrap();
+        assert_eq!((report.creator_prior_launches, report.creator_rug_count), (0, 0));
+        reputation.record_report(&report);
+        reputation.record_report(&report);
+
+        // 第二次发行附带之前的记录
+        let second = Pubkey::new_unique();
+        tracker.observe(&create(second, creator));
+        tracker.observe(&trade(second, creator, true));
+        let report = tracker.observe(&trade(second, Pubkey::new_unique(), true)).unwrap();
+        assert_eq!((report.creator_prior_launches, report.creator_rug_count), (1, 1));
+        reputation.record_report(&report);
+
+        assert_eq!(
+            reputation.history(&creator),
+            CreatorHistory { prior_launches: 2, rug_count: 1 }
+        );
+        assert_eq!(reputation.history(&Pubkey::new_unique()), CreatorHistory::default());
+    }
+}
@@ -0,0 +1,289 @@
+//! 统一的 swap 视图
+//!
+//! 各协议 swap 事件的字段和方向语义不同（`amount_in/out`、`amount_0/1`、`base/quote`），
+//! 这里统一为「池子 + 输入/输出代币与数量」，并在归一化时确定 base 代币及交易方向，
+//! 消费方无需再按协议判断 `zero_for_one` / `is_buy` 等字段的含义。
+//! 价格以原始单位（未按 decimals 换算）表示，同一代币对的不同池子之间可直接比较。
+
+use super::pool_registry::PoolRegistry;
+use crate::core::events::{DexEvent, TradeDirection};
+use solana_sdk::pubkey;
+use solana_sdk::pubkey::Pubkey;
+
+/// Wrapped SOL mint（PumpFun bonding curve 的计价代币）
+pub const WSOL_MINT: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
+pub const USDC_MINT: Pubkey = pubkey!("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+pub const USDT_MINT: Pubkey = pubkey!("Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB");
+
+/// 常见计价代币
+pub const QUOTE_MINTS: [Pubkey; 3] = [WSOL_MINT, USDC_MINT, USDT_MINT];
+
+/// 代币在一次 swap 中所处的一侧
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwapSide {
+    Input,
+    Output,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NormalizedSwap {
+    pub pool: Pubkey,
+    /// 来源事件类型（`DexEvent::event_type_name`）
+    pub source: &'static str,
+    pub slot: u64,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub input_amount: u64,
+    pub output_amount: u64,
+    /// 发起交易的钱包，事件中没有该信息时为 None
+    pub trader: Option<Pubkey>,
+    /// base 代币：PumpFun 为代币 mint，PumpSwap 为池子的 `base_mint`；
+    /// 其他协议为非计价代币（[`QUOTE_MINTS`]）一侧，无法区分时为池子的第一个代币（mint_a / mint_0 / x）
+    pub base_mint: Pubkey,
+    /// base 代币在本次 swap 中是输入还是输出
+    pub base_mint_side: SwapSide,
+    /// 相对 base 代币的方向：得到 base 为 Buy，付出 base 为 Sell
+    pub direction: TradeDirection,
+}
+
+impl NormalizedSwap {
+    /// 计价代币（base 的另一侧）
+    pub fn quote_mint(&self) -> Pubkey {
+        match self.base_mint_side {
+            SwapSide::Input => self.output_mint,
+            SwapSide::Output => self.input_mint,
+        }
+    }
+
+    /// base 代币的数量
+    pub fn base_amount(&self) -> u64 {
+        match self.base_mint_side {
+            SwapSide::Input => self.input_amount,
+            SwapSide::Output => self.output_amount,
+        }
+    }
+
+    /// 计价代币的数量
+    pub fn quote_amount(&self) -> u64 {
+        match self.base_mint_side {
+            SwapSide::Input => self.output_amount,
+            SwapSide::Output => self.input_amount,
+        }
+    }
+
+    /// `base` 以另一侧代币计价的成交价（原始单位）
+    ///
+    /// `base` 不属于本次 swap 或数量为 0 时返回 None。
+    pub fn price_of(&self, base: &Pubkey) -> Option<f64> {
+        let (base_amount, quote_amount) = if *base == self.input_mint {
+            (self.input_amount, self.output_amount)
+        } else if *base == self.output_mint {
+            (self.output_amount, self.input_amount)
+        } else {
+            return None;
+        };
+        (base_amount > 0 && quote_amount > 0).then(|| quote_amount as f64 / base_amount as f64)
+    }
+
+    /// 非计价代币一侧：(token_mint, 是否买入 token)
+    ///
+    /// 恰好一侧属于 [`QUOTE_MINTS`] 时才有意义，否则返回 None。
+    pub fn token_side(&self) -> Option<(Pubkey, bool)> {
+        match (QUOTE_MINTS.contains(&self.input_mint), QUOTE_MINTS.contains(&self.output_mint)) {
+            (true, false) => Some((self.output_mint, true)),
+            (false, true) => Some((self.input_mint, false)),
+            _ => None,
+        }
+    }
+}
+
+/// 把 swap 事件统一为 [`NormalizedSwap`]
+///
+/// 事件本身不含 mint 的协议（Orca / Raydium CLMM / Meteora DLMM）需要池子已在 `registry` 中。
+pub fn normalize_swap(event: &DexEvent, registry: &PoolRegistry) -> Option<NormalizedSwap> {
+    let swap = |pool, input_mint, output_mint, input_amount, output_amount| NormalizedSwap {
+        pool,
+        source: event.event_type_name(),
+        slot: event.metadata().slot,
+        input_mint,
+        output_mint,
+        input_amount,
+        output_amount,
+        trader: None,
+        base_mint: Pubkey::default(),
+        base_mint_side: SwapSide::Output,
+        direction: TradeDirection::Buy,
+    };
+    // 按池子代币对顺序定向：a_to_b 为 true 时输入为 mint_a
+    let by_registry = |pool: Pubkey, a_to_b: bool, amount_in: u64, amount_out: u64| {
+        let info = registry.get(&pool)?;
+        let (input, output) =
+            if a_to_b { (info.mint_a, info.mint_b) } else { (info.mint_b, info.mint_a) };
+        Some((
+            swap(pool, input, output, amount_in, amount_out),
+            pool_base(info.mint_a, info.mint_b),
+        ))
+    };
+
+    let (mut normalized, base_mint) = match event {
+        DexEvent::PumpFunTrade(e)
+        | DexEvent::PumpFunBuy(e)
+        | DexEvent::PumpFunSell(e)
+        | DexEvent::PumpFunBuyExactSolIn(e) => {
+            let normalized = if e.is_buy {
+                swap(e.bonding_curve, WSOL_MINT, e.mint, e.sol_amount, e.token_amount)
+            } else {
+                swap(e.bonding_curve, e.mint, WSOL_MINT, e.token_amount, e.sol_amount)
+            };
+            (normalized, e.mint)
+        }
+        DexEvent::PumpSwapBuy(e) => (
+            swap(e.pool, e.quote_mint, e.base_mint, e.user_quote_amount_in, e.base_amount_out),
+            e.base_mint,
+        ),
+        DexEvent::PumpSwapSell(e) => (
+            swap(e.pool, e.base_mint, e.quote_mint, e.base_amount_in, e.user_quote_amount_out),
+            e.base_mint,
+        ),
+        DexEvent::MeteoraDammV2Swap(e) => {
+            // trade_direction: 0 = AtoB, 1 = BtoA
+            let (input, output) = if e.trade_direction == 0 {
+                (e.token_a_mint, e.token_b_mint)
+            } else {
+                (e.token_b_mint, e.token_a_mint)
+            };
+            (
+                swap(e.pool, input, output, e.actual_amount_in, e.output_amount),
+                pool_base(e.token_a_mint, e.token_b_mint),
+            )
+        }
+        DexEvent::OrcaWhirlpoolSwap(e) => {
+            by_registry(e.whirlpool, e.a_to_b, e.input_amount, e.output_amount)?
+        }
+        DexEvent::RaydiumClmmSwap(e) => {
+            let (amount_in, amount_out) =
+                if e.zero_for_one { (e.amount_0, e.amount_1) } else { (e.amount_1, e.amount_0) };
+            by_registry(e.pool_state, e.zero_for_one, amount_in, amount_out)?
+        }
+        DexEvent::MeteoraDlmmSwap(e) => {
+            by_registry(e.pool, e.swap_for_y, e.amount_in, e.amount_out)?
+        }
+        _ => return None,
+    };
+
+    // 账户未填充（mint 为默认值）时无法定价
+    if normalized.input_mint == Pubkey::default() || normalized.output_mint == Pubkey::default() {
+        return None;
+    }
+
+    normalized.trader = event.user();
+    normalized.base_mint = base_mint;
+    (normalized.base_mint_side, normalized.direction) = if base_mint == normalized.input_mint {
+        (SwapSide::Input, TradeDirection::Sell)
+    } else {
+        (SwapSide::Output, TradeDirection::Buy)
+    };
+    Some(normalized)
+}
+
+/// 没有声明 base 的池子：非计价代币一侧为 base，两侧都是或都不是计价代币时取 mint_a
+fn pool_base(mint_a: Pubkey, mint_b: Pubkey) -> Pubkey {
+    if QUOTE_MINTS.contains(&mint_a) && !QUOTE_MINTS.contains(&mint_b) {
+        mint_b
+    } else {
+        mint_a
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::*;
+
+    #[test]
+    fn test_normalize_pumpfun_sell() {
+        let mint = Pubkey::new_unique();
+        let event = DexEvent::PumpFunSell(PumpFunTradeEvent {
+            mint,
+            bonding_curve: Pubkey::new_unique(),
+            sol_amount: 2_000,
+            token_amount: 1_000,
+            is_buy: false,
+            ..Default::default()
+        });
+
+        let swap = normalize_swap(&event, &PoolRegistry::new()).unwrap();
+        assert_eq!((swap.input_mint, swap.output_mint), (mint, WSOL_MINT));
+        assert_eq!(swap.price_of(&mint), Some(2.0));
+        assert_eq!(swap.price_of(&WSOL_MINT), Some(0.5));
+        assert_eq!(swap.source, "PumpFunSell");
+        assert_eq!((swap.base_mint, swap.base_mint_side), (mint, SwapSide::Input));
+        assert_eq!(swap.direction, TradeDirection::Sell);
+        assert_eq!(
+            (swap.quote_mint(), swap.base_amount(), swap.quote_amount()),
+            (WSOL_MINT, 1_000, 2_000)
+        );
+    }
+
+    #[test]
+    fn test_base_side_from_pool_order() {
+        let (pool, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let clmm = |zero_for_one| {
+            DexEvent::RaydiumClmmSwap(RaydiumClmmSwapEvent {
+                metadata: EventMetadata::default(),
+                pool_state: pool,
+                token_account_0: Pubkey::new_unique(),
+                token_account_1: Pubkey::new_unique(),
+                amount_0: 5_000,
+                amount_1: 700,
+                zero_for_one,
+                sqrt_price_x64: 0,
+                liquidity: 0,
+                sender: Pubkey::new_unique(),
+                transfer_fee_0: 0,
+                transfer_fee_1: 0,
+                tick: 0,
+            })
+        };
+        // WSOL 为 mint_0 时 base 仍是另一侧的代币：zero_for_one 付出 SOL、买入代币
+        let mut registry = PoolRegistry::new();
+        registry.register(pool, WSOL_MINT, mint);
+        let buy = normalize_swap(&clmm(true), &registry).unwrap();
+        assert_eq!(
+            (buy.base_mint, buy.base_mint_side, buy.direction),
+            (mint, SwapSide::Output, TradeDirection::Buy)
+        );
+        assert_eq!((buy.base_amount(), buy.quote_amount()), (700, 5_000));
+        let sell = normalize_swap(&clmm(false), &registry).unwrap();
+        assert_eq!((sell.base_mint_side, sell.direction), (SwapSide::Input, TradeDirection::Sell));
+        assert_eq!((sell.base_amount(), sell.quote_amount()), (700, 5_000));
+    }
+
+    #[test]
+    fn test_normalize_dlmm_requires_registry() {
+        let (pool, mint_x, mint_y) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let event = DexEvent::MeteoraDlmmSwap(MeteoraDlmmSwapEvent {
+            metadata: EventMetadata::default(),
+            pool,
+            from: Pubkey::new_unique(),
+            start_bin_id: 0,
+            end_bin_id: 0,
+            amount_in: 300,
+            amount_out: 100,
+            swap_for_y: false,
+            fee: 0,
+            protocol_fee: 0,
+            fee_bps: 0,
+            host_fee: 0,
+        });
+
+        let mut registry = PoolRegistry::new();
+        assert!(normalize_swap(&event, &registry).is_none());
+
+        registry.register(pool, mint_x, mint_y);
+        let swap = normalize_swap(&event, &registry).unwrap();
+        assert_eq!((swap.input_mint, swap.output_mint), (mint_y, mint_x));
+        assert_eq!(swap.price_of(&mint_x), Some(3.0));
+    }
+}
@@ -0,0 +1,360 @@
+//! 按池子的 TVL 变化
+//!
+//! 从加 / 撤流动性事件累计每个池子的净流入，从已登记金库的 Token 账户更新跟踪储备变化，
+//! 按固定窗口批量输出 [`PoolTvlDelta`]，下游无需保留全部事件即可绘制 TVL 曲线。
+//! 数量均为原始单位；Raydium CLMM / AMM V4 的流动性事件只有数量上下限，不计入净流入。
+//!
+//! Raydium AMM V4 池子的部分资金在 OpenBook 挂单中，且金库含待提取的协议收益。
+//! 观察到 AmmInfo 账户更新后自动登记其金库，储备按 金库 + OpenOrders 总额 − need_take_pnl 计算；
+//! OpenOrders 更新需在对应 AmmInfo 之后到达才会计入。
+
+use super::pool_registry::{PoolInfo, PoolRegistry};
+use crate::core::events::DexEvent;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+
+/// 一个窗口内单个池子的 TVL 变化
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolTvlDelta {
+    pub pool: Pubkey,
+    /// 池子代币对，未在注册表中时为 None
+    pub mints: Option<PoolInfo>,
+    pub window_start_us: i64,
+    pub window_end_us: i64,
+    /// 流动性事件的净流入（加流动性为正，撤流动性为负）
+    pub liquidity_net_a: i128,
+    pub liquidity_net_b: i128,
+    pub adds: u64,
+    pub removes: u64,
+    /// 已登记金库的余额变化，包含 swap 与手续费
+    pub reserve_delta_a: i128,
+    pub reserve_delta_b: i128,
+    /// 窗口结束时已知的储备（AMM V4 已计入 OpenOrders 与 need_take_pnl）
+    pub reserve_a: Option<u64>,
+    pub reserve_b: Option<u64>,
+}
+
+/// 金库所属的池子与代币侧
+#[derive(Debug, Clone, Copy)]
+struct Vault {
+    pool: Pubkey,
+    is_a: bool,
+}
+
+/// AMM V4 池子在金库余额之外的储备修正项
+#[derive(Debug, Default, Clone, Copy)]
+struct AmmV4Adjustment {
+    open_orders_total: [u64; 2],
+    need_take_pnl: [u64; 2],
+}
+
+#[derive(Debug, Default)]
+struct PoolFlows {
+    liquidity_net: [i128; 2],
+    adds: u64,
+    removes: u64,
+    reserve_delta: [i128; 2],
+    reserve: [Option<u64>; 2],
+    /// 金库余额（非 AMM V4 池子即为储备）
+    vault: [Option<u64>; 2],
+    /// 当前窗口内是否有变化
+    active: bool,
+}
+
+#[derive(Debug)]
+pub struct TvlTracker {
+    registry: PoolRegistry,
+    vaults: HashMap<Pubkey, Vault>,
+    pools: HashMap<Pubkey, PoolFlows>,
+    amm_v4: HashMap<Pubkey, AmmV4Adjustment>,
+    /// OpenOrders 账户 -> AMM V4 池子
+    open_orders: HashMap<Pubkey, Pubkey>,
+    window_us: i64,
+    window_start_us: Option<i64>,
+}
+
+impl TvlTracker {
+    /// 按 `window` 输出，配合 [`TvlTracker::poll`] 使用
+    pub fn new(window: std::time::Duration) -> Self {
+        Self {
+            registry: PoolRegistry::new(),
+            vaults: HashMap::new(),
+            pools: HashMap::new(),
+            amm_v4: HashMap::new(),
+            open_orders: HashMap::new(),
+            window_us: window.as_micros() as i64,
+            window_start_us: None,
+        }
+    }
+
+    /// 用于预先注册事件中不含 mint 的池子
+    pub fn registry_mut(&mut self) -> &mut PoolRegistry {
+        &mut self.registry
+    }
+
+    /// 登记池子的金库账户，之后该账户的 `DexEvent::TokenAccount` 更新计入储备变化
+    ///
+    /// `is_a` 表示金库属于池子的 a / 0 / base 一侧。
+    pub fn register_vault(&mut self, pool: Pubkey, vault: Pubkey, is_a: bool) {
+        self.vaults.insert(vault, Vault { pool, is_a });
+    }
+
+    /// 记录一个事件（其他事件只用于学习池子信息）
+    pub fn observe(&mut self, event: &DexEvent) {
+        self.registry.observe(event);
+        if self.window_start_us.is_none() {
+            self.window_start_us = Some(event_time_us(event));
+        }
+
+        match event {
+            DexEvent::TokenAccount(e) => {
+                let Some(vault) = self.vaults.get(&e.pubkey).copied() else { return };
+                let Some(amount) = e.amount else { return };
+                let side = if vault.is_a { 0 } else { 1 };
+                self.pools.entry(vault.pool).or_default().vault[side] = Some(amount);
+                self.update_reserve(vault.pool, side);
+                return;
+            }
+            DexEvent::RaydiumAmmV4AmmInfoAccount(e) => {
+                let (pool, info) = (e.pubkey, &e.amm_info);
+                self.vaults.entry(info.coin_vault).or_insert(Vault { pool, is_a: true });
+                self.vaults.entry(info.pc_vault).or_insert(Vault { pool, is_a: false });
+                self.open_orders.insert(info.open_orders, pool);
+                self.amm_v4.entry(pool).or_default().need_take_pnl =
+                    [info.need_take_pnl_coin, info.need_take_pnl_pc];
+                self.update_reserve(pool, 0);
+                self.update_reserve(pool, 1);
+                return;
+            }
+            DexEvent::SerumOpenOrdersAccount(e) => {
+                let Some(&pool) = self.open_orders.get(&e.pubkey) else { return };
+                let open_orders = &e.open_orders;
+                self.amm_v4.entry(pool).or_default().open_orders_total =
+                    [open_orders.native_coin_total, open_orders.native_pc_total];
+                self.update_reserve(pool, 0);
+                self.update_reserve(pool, 1);
+                return;
+            }
+            _ => {}
+        }
+
+        let Some((pool, is_add, amount_a, amount_b)) = liquidity_flow(event) else { return };
+        let flows = self.pools.entry(pool).or_default();
+        let sign = if is_add { 1 } else { -1 };
+        flows.liquidity_net[0] += sign * amount_a as i128;
+        flows.liquidity_net[1] += sign * amount_b as i128;
+        if is_add {
+            flows.adds += 1;
+        } else {
+            flows.removes += 1;
+        }
+        flows.active = true;
+    }
+
+    /// 按金库余额与 AMM V4 修正项重新计算储备，金库余额未知时不更新
+    fn update_reserve(&mut self, pool: Pubkey, side: usize) {
+        let flows = self.pools.entry(pool).or_default();
+        let Some(vault) = flows.vault[side] else { return };
+        let reserve = match self.amm_v4.get(&pool) {
+            Some(adjust) => vault
+                .saturating_add(adjust.open_orders_total[side])
+                .saturating_sub(adjust.need_take_pnl[side]),
+            None => vault,
+        };
+        if let Some(previous) = flows.reserve[side] {
+            flows.reserve_delta[side] += reserve as i128 - previous as i128;
+        }
+        flows.reserve[side] = Some(reserve);
+        flows.active = true;
+    }
+
+    /// 到达窗口结束时，为窗口内有变化的每个池子生成 [`PoolTvlDelta`] 并开始新窗口
+    pub fn poll(&mut self, now_us: i64) -> Vec<PoolTvlDelta> {
+        let start = *self.window_start_us.get_or_insert(now_us);
+        if now_us - start < self.window_us {
+            return Vec::new();
+        }
+        self.window_start_us = Some(now_us);
+
+        let mut deltas = Vec::new();
+        for (pool, flows) in self.pools.iter_mut().filter(|(_, f)| f.active) {
+            deltas.push(PoolTvlDelta {
+                pool: *pool,
+                mints: self.registry.get(pool).copied(),
+                window_start_us: start,
+                window_end_us: now_us,
+                liquidity_net_a: flows.liquidity_net[0],
+                liquidity_net_b: flows.liquidity_net[1],
+                adds: flows.adds,
+                removes: flows.removes,
+                reserve_delta_a: flows.reserve_delta[0],
+                reserve_delta_b: flows.reserve_delta[1],
+                reserve_a: flows.reserve[0],
+                reserve_b: flows.reserve[1],
+            });
+            // 只保留最近的储备，其余计数随窗口重置
+            *flows = PoolFlows { reserve: flows.reserve, vault: flows.vault, ..Default::default() };
+        }
+        deltas
+    }
+}
+
+/// 流动性事件：(pool, 是否加流动性, a 侧数量, b 侧数量)
+fn liquidity_flow(event: &DexEvent) -> Option<(Pubkey, bool, u64, u64)> {
+    let flow = match event {
+        DexEvent::PumpSwapLiquidityAdded(e) => (e.pool, true, e.base_amount_in, e.quote_amount_in),
+        DexEvent::PumpSwapLiquidityRemoved(e) => {
+            (e.pool, false, e.base_amount_out, e.quote_amount_out)
+        }
+        DexEvent::RaydiumCpmmDeposit(e) => (e.pool, true, e.token0_amount, e.token1_amount),
+        DexEvent::RaydiumCpmmWithdraw(e) => (e.pool, false, e.token0_amount, e.token1_amount),
+        DexEvent::OrcaWhirlpoolLiquidityIncreased(e) => {
+            (e.whirlpool, true, e.token_a_amount, e.token_b_amount)
+        }
+        DexEvent::OrcaWhirlpoolLiquidityDecreased(e) => {
+            (e.whirlpool, false, e.token_a_amount, e.token_b_amount)
+        }
+        DexEvent::MeteoraPoolsAddLiquidity(e) => (e.pool, true, e.token_a_amount, e.token_b_amount),
+        DexEvent::MeteoraPoolsRemoveLiquidity(e) => {
+            (e.pool, false, e.token_a_out_amount, e.token_b_out_amount)
+        }
+        DexEvent::MeteoraDammV2AddLiquidity(e) => {
+            (e.pool, true, e.token_a_amount, e.token_b_amount)
+        }
+        DexEvent::MeteoraDammV2RemoveLiquidity(e) => {
+            (e.pool, false, e.token_a_amount, e.token_b_amount)
+        }
+        DexEvent::MeteoraDlmmAddLiquidity(e) => (e.pool, true, e.amounts[0], e.amounts[1]),
+        DexEvent::MeteoraDlmmRemoveLiquidity(e) => (e.pool, false, e.amounts[0], e.amounts[1]),
+        _ => return None,
+    };
+    Some(flow)
+}
+
+/// 事件时间：优先使用区块时间，缺失时使用接收时间
+fn event_time_us(event: &DexEvent) -> i64 {
+    let metadata = event.metadata();
+    if metadata.block_time_us > 0 {
+        metadata.block_time_us
+    } else {
+        metadata.grpc_recv_us
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::*;
+
+    const MINUTE_US: i64 = 60_000_000;
+
+    #[test]
+    fn test_tvl_deltas_per_window() {
+        let (pool, vault_a) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let metadata = EventMetadata { block_time_us: MINUTE_US, ..Default::default() };
+        let vault_update = |amount| {
+            DexEvent::TokenAccount(TokenAccountEvent {
+                metadata,
+                pubkey: vault_a,
+                amount: Some(amount),
+                ..Default::default()
+            })
+        };
+
+        let mut tracker = TvlTracker::new(std::time::Duration::from_secs(60));
+        tracker.register_vault(pool, vault_a, true);
+        tracker.observe(&DexEvent::RaydiumCpmmDeposit(RaydiumCpmmDepositEvent {
+            metadata,
+            pool,
+            token0_amount: 1_000,
+            token1_amount: 500,
+            lp_token_amount: 10,
+            user: Pubkey::new_unique(),
+        }));
+        tracker.observe(&DexEvent::RaydiumCpmmWithdraw(RaydiumCpmmWithdrawEvent {
+            metadata,
+            pool,
+            lp_token_amount: 4,
+            token0_amount: 300,
+            token1_amount: 200,
+            user: Pubkey::new_unique(),
+        }));
+        tracker.observe(&vault_update(10_000));
+        tracker.observe(&vault_update(10_700));
+
+        assert!(tracker.poll(MINUTE_US + 1).is_empty());
+        let deltas = tracker.poll(2 * MINUTE_US);
+        assert_eq!(deltas.len(), 1);
+        let delta = deltas[0];
+        assert_eq!(
+            (delta.pool, delta.window_start_us, delta.window_end_us),
+            (pool, MINUTE_US, 2 * MINUTE_US)
+        );
+        assert_eq!((delta.liquidity_net_a, delta.liquidity_net_b), (700, 300));
+        assert_eq!((delta.adds, delta.removes), (1, 1));
+        assert_eq!(
+            (delta.reserve_delta_a, delta.reserve_a, delta.reserve_b),
+            (700, Some(10_700), None)
+        );
+
+        // 新窗口只计新的变化，储备沿用
+        tracker.observe(&vault_update(10_500));
+        let deltas = tracker.poll(3 * MINUTE_US);
+        assert_eq!((deltas[0].reserve_delta_a, deltas[0].liquidity_net_a), (-200, 0));
+        assert!(tracker.poll(4 * MINUTE_US).is_empty());
+    }
+
+    #[test]
+    fn test_amm_v4_reserve_includes_open_orders() {
+        let (pool, open_orders) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (coin_vault, pc_vault) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let metadata = EventMetadata { block_time_us: MINUTE_US, ..Default::default() };
+        let vault_update = |pubkey, amount| {
+            DexEvent::TokenAccount(TokenAccountEvent {
+                metadata,
+                pubkey,
+                amount: Some(amount),
+                ..Default::default()
+            })
+        };
+        let amm_info = |need_take_pnl_coin| {
+            DexEvent::RaydiumAmmV4AmmInfoAccount(RaydiumAmmAmmInfoAccountEvent {
+                metadata,
+                pubkey: pool,
+                amm_info: RaydiumAmmInfo {
+                    need_take_pnl_coin,
+                    need_take_pnl_pc: 50,
+                    coin_vault,
+                    pc_vault,
+                    open_orders,
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+        };
+
+        let mut tracker = TvlTracker::new(std::time::Duration::from_secs(60));
+        tracker.observe(&amm_info(100));
+        tracker.observe(&vault_update(coin_vault, 10_000));
+        tracker.observe(&vault_update(pc_vault, 2_000));
+        tracker.observe(&DexEvent::SerumOpenOrdersAccount(SerumOpenOrdersAccountEvent {
+            metadata,
+            pubkey: open_orders,
+            open_orders: SerumOpenOrders {
+                native_coin_total: 500,
+                native_pc_total: 30,
+                ..Default::default()
+            },
+            ..Default::default()
+        }));
+        let deltas = tracker.poll(2 * MINUTE_US);
+        assert_eq!((deltas[0].reserve_a, deltas[0].reserve_b), (Some(10_400), Some(1_980)));
+        assert_eq!(deltas[0].reserve_delta_a, 500);
+
+        // 协议收益增加只影响 coin 侧储备
+        tracker.observe(&amm_info(300));
+        let deltas = tracker.poll(3 * MINUTE_US);
+        assert_eq!((deltas[0].reserve_a, deltas[0].reserve_delta_a), (Some(10_200), -200));
+        assert_eq!(deltas[0].reserve_delta_b, 0);
+    }
+}
@@ -0,0 +1,231 @@
+//! 刷量交易评分
+//!
+//! 基于统一后的 swap 流，在滑动窗口内为每个 mint 计算刷量嫌疑分数 [`WashTradeScore`]：
+//! - 往返：同一钱包先买后卖
+//! - 固定数量：相同计价数量的成交反复出现（机器人来回刷单）
+//! - 自筹资金：买入资金不超过该钱包窗口内此前卖出所得（卖出所得再买回）
+//!
+//! 只统计一侧为计价代币的 swap；缺少钱包信息的成交只参与固定数量判断。
+
+use super::pool_registry::PoolRegistry;
+use super::swap::normalize_swap;
+use crate::core::events::DexEvent;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::{HashMap, VecDeque};
+
+/// 单个 mint 在一个窗口内的刷量评分
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WashTradeScore {
+    pub mint: Pubkey,
+    pub timestamp_us: i64,
+    pub trades: u64,
+    /// 属于同一钱包先买后卖的卖出占比
+    pub round_trip_ratio: f64,
+    /// 计价数量重复出现（不少于 `min_repeats` 次）的成交占比
+    pub fixed_size_ratio: f64,
+    /// 由同一钱包此前卖出所得覆盖的买入占比
+    pub self_funded_ratio: f64,
+    /// 综合分数，0.0 ~ 1.0
+    pub score: f64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct WashTrade {
+    timestamp_us: i64,
+    is_buy: bool,
+    quote_amount: u64,
+    trader: Option<Pubkey>,
+}
+
+#[derive(Debug)]
+pub struct WashTradeDetector {
+    registry: PoolRegistry,
+    trades: HashMap<Pubkey, VecDeque<WashTrade>>,
+    window_us: i64,
+    emit_interval_us: i64,
+    last_emit_us: i64,
+    min_trades: u64,
+    min_repeats: usize,
+}
+
+impl WashTradeDetector {
+    /// `window`：滑动窗口长度，同时作为默认输出间隔
+    pub fn new(window: std::time::Duration) -> Self {
+        let window_us = window.as_micros() as i64;
+        Self {
+            registry: PoolRegistry::new(),
+            trades: HashMap::new(),
+            window_us,
+            emit_interval_us: window_us,
+            last_emit_us: 0,
+            min_trades: 10,
+            min_repeats: 3,
+        }
+    }
+
+    /// 输出间隔（默认等于窗口长度）
+    pub fn with_emit_interval(mut self, interval: std::time::Duration) -> Self {
+        self.emit_interval_us = interval.as_micros() as i64;
+        self
+    }
+
+    /// 窗口内至少多少笔成交才评分（默认 10）
+    pub fn with_min_trades(mut self, min_trades: u64) -> Self {
+        self.min_trades = min_trades;
+        self
+    }
+
+    /// 同一计价数量至少出现多少次视为固定数量成交（默认 3）
+    pub fn with_min_repeats(mut self, min_repeats: usize) -> Self {
+        self.min_repeats = min_repeats.max(2);
+        self
+    }
+
+    /// 用于预先注册事件中不含 mint 的池子
+    pub fn registry_mut(&mut self) -> &mut PoolRegistry {
+        &mut self.registry
+    }
+
+    /// 记录一个事件（非 swap 事件只用于学习池子信息）
+    pub fn observe(&mut self, event: &DexEvent) {
+        self.registry.observe(event);
+        let Some(swap) = normalize_swap(event, &self.registry) else { return };
+        let Some((mint, is_buy)) = swap.token_side() else { return };
+
+        let timestamp_us = event_time_us(event);
+        let records = self.trades.entry(mint).or_default();
+        records.push_back(WashTrade {
+            timestamp_us,
+            is_buy,
+            quote_amount: if is_buy { swap.input_amount } else { swap.output_amount },
+            trader: swap.trader,
+        });
+        prune(records, timestamp_us - self.window_us);
+    }
+
+    /// 计算 `mint` 在 `now_us` 之前一个窗口内的评分，成交数不足时返回 None
+    pub fn score(&self, mint: &Pubkey, now_us: i64) -> Option<WashTradeScore> {
+        let records = self.trades.get(mint)?;
+        let since = now_us - self.window_us;
+        let window: Vec<&WashTrade> = records.iter().filter(|r| r.timestamp_us > since).collect();
+        let trades = window.len() as u64;
+        if trades == 0 || trades < self.min_trades {
+            return None;
+        }
+
+        let mut size_counts: HashMap<u64, usize> = HashMap::new();
+        for r in &window {
+            *size_counts.entry(r.quote_amount).or_default() += 1;
+        }
+        let fixed_size =
+            window.iter().filter(|r| size_counts[&r.quote_amount] >= self.min_repeats).count();
+
+        // 钱包 → (未卖出的买入笔数, 卖出所得尚未用于买入的计价数量)
+        let mut wallets: HashMap<Pubkey, (u64, u64)> = HashMap::new();
+        let (mut buys, mut sells, mut round_trips, mut self_funded) = (0u64, 0u64, 0u64, 0u64);
+        for r in &window {
+            if r.is_buy {
+                buys += 1;
+            } else {
+                sells += 1;
+            }
+            let Some(trader) = r.trader else { continue };
+            let (open_buys, proceeds) = wallets.entry(trader).or_default();
+            if r.is_buy {
+                if *proceeds > 0 && r.quote_amount <= *proceeds {
+                    self_funded += 1;
+                    *proceeds -= r.quote_amount;
+                }
+                *open_buys += 1;
+            } else {
+                if *open_buys > 0 {
+                    round_trips += 1;
+                    *open_buys -= 1;
+                }
+                *proceeds += r.quote_amount;
+            }
+        }
+
+        let ratio = |n: u64, d: u64| if d == 0 { 0.0 } else { n as f64 / d as f64 };
+        let round_trip_ratio = ratio(round_trips, sells);
+        let fixed_size_ratio = ratio(fixed_size as u64, trades);
+        let self_funded_ratio = ratio(self_funded, buys);
+        Some(WashTradeScore {
+            mint: *mint,
+            timestamp_us: now_us,
+            trades,
+            round_trip_ratio,
+            fixed_size_ratio,
+            self_funded_ratio,
+            score: 0.4 * round_trip_ratio + 0.3 * fixed_size_ratio + 0.3 * self_funded_ratio,
+        })
+    }
+
+    /// 到达输出间隔时，为窗口内成交数足够的每个 mint 生成评分，并清理过期记录
+    pub fn poll(&mut self, now_us: i64) -> Vec<WashTradeScore> {
+        let since = now_us - self.window_us;
+        self.trades.retain(|_, records| {
+            prune(records, since);
+            !records.is_empty()
+        });
+
+        if now_us - self.last_emit_us < self.emit_interval_us {
+            return Vec::new();
+        }
+        self.last_emit_us = now_us;
+        self.trades.keys().filter_map(|mint| self.score(mint, now_us)).collect()
+    }
+}
+
+/// 丢弃 `since` 及之前的记录
+fn prune(records: &mut VecDeque<WashTrade>, since: i64) {
+    while records.front().is_some_and(|r| r.timestamp_us <= since) {
+        records.pop_front();
+    }
+}
+
+/// 事件时间：优先使用区块时间，缺失时使用接收时间
+fn event_time_us(event: &DexEvent) -> i64 {
+    let metadata = event.metadata();
+    if metadata.block_time_us > 0 {
+        metadata.block_time_us
+    } else {
+        metadata.grpc_recv_us
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_fixtures::{trade, MINUTE_US};
+
+    #[test]
+    fn test_wash_trade_score() {
+        let (wash_mint, organic_mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let bot = Pubkey::new_unique();
+        let mut detector =
+            WashTradeDetector::new(std::time::Duration::from_secs(60)).with_min_trades(4);
+
+        // 同一钱包固定数量来回买卖
+        for i in 0..4 {
+            let at = MINUTE_US + i * 1_000_000;
+            detector.observe(&trade(wash_mint, bot, i % 2 == 0, 1_000, at));
+            detector.observe(&trade(organic_mint, Pubkey::new_unique(), true, 100 + i as u64, at));
+        }
+
+        let now = MINUTE_US + 10_000_000;
+        let wash = detector.score(&wash_mint, now).unwrap();
+        assert_eq!(wash.trades, 4);
+        assert_eq!((wash.round_trip_ratio, wash.fixed_size_ratio), (1.0, 1.0));
+        // 第二次买入由第一次卖出所得覆盖
+        assert_eq!(wash.self_funded_ratio, 0.5);
+        assert!((wash.score - 0.85).abs() < 1e-9);
+
+        let organic = detector.score(&organic_mint, now).unwrap();
+        assert_eq!(organic.score, 0.0);
+
+        let scores = detector.poll(now + MINUTE_US);
+        assert!(scores.is_empty());
+        assert!(detector.score(&wash_mint, now).is_none());
+    }
+}
@@ -0,0 +1,197 @@
+//! Block Event Parser
+//!
+//! 将整个区块中的所有交易解析为一个按 slot 分组的事件批次（`SlotEvents`）
+//! 支持 RPC `getBlock` 结果（`UiConfirmedBlock`）和 gRPC 区块订阅（`SubscribeUpdateBlock`）
+
+use crate::core::events::DexEvent;
+use crate::grpc::types::EventTypeFilter;
+use crate::rpc_parser::{parse_encoded_transaction, ParseError};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use solana_transaction_status::UiConfirmedBlock;
+use yellowstone_grpc_proto::prelude::SubscribeUpdateBlock;
+
+/// 单个 slot 的事件批次
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlotEvents {
+    pub slot: u64,
+    /// 区块时间（Unix 秒），节点未提供时为 None
+    pub block_time: Option<i64>,
+    /// 出块 leader（区块手续费奖励的接收者），节点未返回奖励时为 None
+    #[serde(default)]
+    pub leader: Option<Pubkey>,
+    /// 按 tx_index 排序，同一交易内保持解析顺序
+    pub events: Vec<DexEvent>,
+}
+
+/// 区块输入：RPC 区块不包含 slot，需要调用方提供
+pub enum BlockRef<'a> {
+    Rpc { slot: u64, block: &'a UiConfirmedBlock },
+    Grpc(&'a SubscribeUpdateBlock),
+}
+
+impl<'a> From<(u64, &'a UiConfirmedBlock)> for BlockRef<'a> {
+    fn from((slot, block): (u64, &'a UiConfirmedBlock)) -> Self {
+        BlockRef::Rpc { slot, block }
+    }
+}
+
+impl<'a> From<&'a SubscribeUpdateBlock> for BlockRef<'a> {
+    fn from(block: &'a SubscribeUpdateBlock) -> Self {
+        BlockRef::Grpc(block)
+    }
+}
+
+/// 解析区块中的所有交易，返回该 slot 的事件批次
+///
+/// 失败的交易会被跳过（与交易订阅的 `failed: false` 行为一致）。
+/// RPC 区块需要以 `UiTransactionEncoding::Base64` 获取。
+///
+/// # Example
+/// ```no_run
+/// use solana_client::rpc_client::RpcClient;
+/// use solana_client::rpc_config::RpcBlockConfig;
+/// use solana_transaction_status::UiTransactionEncoding;
+/// use sol_parser_sdk::parse_block_events;
+///
+/// let client = RpcClient::new("https://api.mainnet-beta.solana.com".to_string());
+/// let slot = 300_000_000;
+/// let config = RpcBlockConfig {
+///     encoding: Some(UiTransactionEncoding::Base64),
+///     max_supported_transaction_version: Some(0),
+///     ..Default::default()
+/// };
+/// let block = client.get_block_with_config(slot, config).unwrap();
+/// let slot_events = parse_block_events((slot, &block), None).unwrap();
+/// println!("{} events in slot {}", slot_events.events.len(), slot_events.slot);
+/// ```
+pub fn parse_block_events<'a>(
+    block: impl Into<BlockRef<'a>>,
+    filter: Option<&EventTypeFilter>,
+) -> Result<SlotEvents, ParseError> {
+    match block.into() {
+        BlockRef::Rpc { slot, block } => parse_rpc_block(slot, block, filter),
+        BlockRef::Grpc(block) => Ok(parse_grpc_block(block, filter)),
+    }
+}
+
+fn parse_rpc_block(
+    slot: u64,
+    block: &UiConfirmedBlock,
+    filter: Option<&EventTypeFilter>,
+) -> Result<SlotEvents, ParseError> {
+    let mut events = Vec::new();
+
+    for (tx_index, tx) in block.transactions.iter().flatten().enumerate() {
+        if tx.meta.as_ref().is_some_and(|m| m.err.is_some()) {
+            continue;
+        }
+        events.extend(parse_encoded_transaction(
+            tx,
+            slot,
+            block.block_time,
+            tx_index as u64,
+            filter,
+        )?);
+    }
+
+    let leader = block.rewards.as_deref().and_then(crate::grpc::leader::leader_from_rpc_rewards);
+    Ok(SlotEvents { slot, block_time: block.block_time, leader, events })
+}
+
+fn parse_grpc_block(block: &SubscribeUpdateBlock, filter: Option<&EventTypeFilter>) -> SlotEvents {
+    let block_time = block.block_time.map(|t| t.timestamp);
+    let block_us = block_time.map(|t| t * 1_000_000);
+    let grpc_us = crate::core::now_micros();
+
+    let mut events = Vec::new();
+    for info in &block.transactions {
+        if info.is_vote || info.meta.as_ref().is_some_and(|m| m.err.is_some()) {
+            continue;
+        }
+        let (parsed, _) = crate::grpc::client::parse_transaction_info(
+            info,
+            block.slot,
+            grpc_us,
+            block_us,
+            filter,
+            false,
+            crate::grpc::types::ParseLimits::default(),
+        );
+        events.extend(parsed);
+    }
+    // 区块中的交易不保证按 index 排列；稳定排序保留同一交易内的事件顺序
+    events.sort_by_key(|e| e.metadata().tx_index);
+
+    let leader = block.rewards.as_ref().and_then(crate::grpc::leader::leader_from_rewards);
+    SlotEvents { slot: block.slot, block_time, leader, events }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::{engine::general_purpose::STANDARD, Engine as _};
+    use yellowstone_grpc_proto::prelude::{
+        SubscribeUpdateTransactionInfo, TransactionError, TransactionStatusMeta, UnixTimestamp,
+    };
+
+    /// PumpSwap DisableEvent 日志（admin + 全部 disable 标志）
+    fn disable_log() -> String {
+        let mut data = vec![107, 253, 193, 76, 228, 202, 27, 104];
+        data.extend_from_slice(&0i64.to_le_bytes());
+        data.extend_from_slice(&[7u8; 32]);
+        data.extend_from_slice(&[1, 1, 1, 1, 1]);
+        format!("Program data: {}", STANDARD.encode(data))
+    }
+
+    fn tx_info(index: u64, failed: bool) -> SubscribeUpdateTransactionInfo {
+        SubscribeUpdateTransactionInfo {
+            signature: vec![index as u8; 64],
+            is_vote: false,
+            transaction: None,
+            meta: Some(TransactionStatusMeta {
+                err: failed.then(TransactionError::default),
+                log_messages: vec![disable_log()],
+                ..Default::default()
+            }),
+            index,
+        }
+    }
+
+    #[test]
+    fn test_parse_grpc_block_sorts_by_tx_index() {
+        let block = SubscribeUpdateBlock {
+            slot: 42,
+            block_time: Some(UnixTimestamp { timestamp: 1_700_000_000 }),
+            transactions: vec![tx_info(5, false), tx_info(2, false), tx_info(3, true)],
+            ..Default::default()
+        };
+
+        let slot_events = parse_block_events(&block, None).unwrap();
+        assert_eq!(slot_events.slot, 42);
+        assert_eq!(slot_events.block_time, Some(1_700_000_000));
+
+        let indices: Vec<u64> = slot_events.events.iter().map(|e| e.metadata().tx_index).collect();
+        assert_eq!(indices, vec![2, 5]);
+        assert!(slot_events.events.iter().all(|e| e.metadata().block_time_us == 1_700_000_000_000_000));
+    }
+
+    #[test]
+    fn test_parse_empty_rpc_block() {
+        let block = UiConfirmedBlock {
+            previous_blockhash: String::new(),
+            blockhash: String::new(),
+            parent_slot: 9,
+            transactions: None,
+            signatures: None,
+            rewards: None,
+            num_reward_partitions: None,
+            block_time: None,
+            block_height: None,
+        };
+
+        let slot_events = parse_block_events((10, &block), None).unwrap();
+        assert_eq!(slot_events.slot, 10);
+        assert!(slot_events.events.is_empty());
+    }
+}
@@ -0,0 +1,231 @@
+//! 协议能力矩阵
+//!
+//! [`PROTOCOL_REGISTRY`] 登记本构建能解析的协议：程序 ID（与解析路由使用的常量一致）、
+//! 产出的事件（`DexEvent` 变体名，与 `event_type_name()` 一致）以及事件来源。
+//! [`supported_protocols`] 由注册表生成，供集成方和文档工具查询。
+
+use crate::instr::program_ids::*;
+use serde::Serialize;
+use solana_sdk::pubkey::Pubkey;
+
+/// 事件来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum ParseSource {
+    /// `Program data:` 日志（Anchor 事件）
+    Logs,
+    /// 外层指令
+    Instruction,
+    /// Inner instruction（CPI 事件）
+    InnerInstruction,
+    /// 账户更新
+    Account,
+}
+
+/// 注册表条目
+#[derive(Debug, Clone, Copy)]
+pub struct ProtocolEntry {
+    pub name: &'static str,
+    pub program_id: Pubkey,
+    pub events: &'static [&'static str],
+    pub parse_sources: &'static [ParseSource],
+}
+
+/// 协议能力信息
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProtocolInfo {
+    pub name: &'static str,
+    pub program_id: Pubkey,
+    /// 可能产出的事件（`DexEvent` 变体名）
+    pub events: Vec<&'static str>,
+    pub parse_sources: Vec<ParseSource>,
+}
+
+impl From<&ProtocolEntry> for ProtocolInfo {
+    fn from(entry: &ProtocolEntry) -> Self {
+        Self {
+            name: entry.name,
+            program_id: entry.program_id,
+            events: entry.events.to_vec(),
+            parse_sources: entry.parse_sources.to_vec(),
+        }
+    }
+}
+
+use ParseSource::{Account, InnerInstruction, Instruction, Logs};
+
+/// 已支持的协议，新增协议解析时在此登记
+pub const PROTOCOL_REGISTRY: &[ProtocolEntry] = &[
+    ProtocolEntry {
+        name: "PumpFun",
+        program_id: PUMPFUN_PROGRAM_ID,
+        events: &[
+            "PumpFunCreate",
+            "PumpFunTrade",
+            "PumpFunBuy",
+            "PumpFunSell",
+            "PumpFunBuyExactSolIn",
+            "PumpFunMigrate",
+        ],
+        parse_sources: &[Logs, Instruction, InnerInstruction],
+    },
+    ProtocolEntry {
+        name: "PumpSwap",
+        program_id: PUMPSWAP_PROGRAM_ID,
+        events: &[
+            "PumpSwapTrade",
+            "PumpSwapBuy",
+            "PumpSwapSell",
+            "PumpSwapCreatePool",
+            "PumpSwapLiquidityAdded",
+            "PumpSwapLiquidityRemoved",
+            "PumpSwapPoolUpdated",
+            "PumpSwapConfigUpdated",
+            "PumpSwapCreatorFeeClaimed",
+            "PumpSwapGlobalConfigAccount",
+            "PumpSwapPoolAccount",
+        ],
+        parse_sources: &[Logs, Instruction, InnerInstruction, Account],
+    },
+    ProtocolEntry {
+        name: "Raydium Launchpad",
+        program_id: BONK_PROGRAM_ID,
+        events: &["BonkTrade", "BonkPoolCreate", "BonkMigrateAmm"],
+        parse_sources: &[Logs, Instruction, InnerInstruction],
+    },
+    ProtocolEntry {
+        name: "Raydium CLMM",
+        program_id: RAYDIUM_CLMM_PROGRAM_ID,
+        events: &[
+            "RaydiumClmmSwap",
+            "RaydiumClmmCreatePool",
+            "RaydiumClmmOpenPosition",
+            "RaydiumClmmOpenPositionWithTokenExtNft",
+            "RaydiumClmmClosePosition",
+            "RaydiumClmmIncreaseLiquidity",
+            "RaydiumClmmDecreaseLiquidity",
+            "RaydiumClmmCollectFee",
+            "RaydiumClmmInitializeReward",
+            "RaydiumClmmCollectReward",
+            "RaydiumClmmSetRewardParams",
+        ],
+        parse_sources: &[Logs, Instruction, InnerInstruction],
+    },
+    ProtocolEntry {
+        name: "Raydium CPMM",
+        program_id: RAYDIUM_CPMM_PROGRAM_ID,
+        events: &[
+            "RaydiumCpmmSwap",
+            "RaydiumCpmmDeposit",
+            "RaydiumCpmmWithdraw",
+            "RaydiumCpmmInitialize",
+        ],
+        parse_sources: &[Logs, Instruction, InnerInstruction],
+    },
+    ProtocolEntry {
+        name: "Raydium AMM V4",
+        program_id: RAYDIUM_AMM_V4_PROGRAM_ID,
+        events: &[
+            "RaydiumAmmV4Swap",
+            "RaydiumAmmV4Deposit",
+            "RaydiumAmmV4Initialize2",
+            "RaydiumAmmV4Withdraw",
+            "RaydiumAmmV4WithdrawPnl",
+            "RaydiumAmmV4AmmInfoAccount",
+            "RaydiumAmmV4TargetOrdersAccount",
+            "SerumOpenOrdersAccount",
+        ],
+        parse_sources: &[Logs, InnerInstruction, Account],
+    },
+    ProtocolEntry {
+        name: "Orca Whirlpool",
+        program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+        events: &[
+            "OrcaWhirlpoolSwap",
+            "OrcaWhirlpoolLiquidityIncreased",
+            "OrcaWhirlpoolLiquidityDecreased",
+            "OrcaWhirlpoolPoolInitialized",
+        ],
+        parse_sources: &[Logs, InnerInstruction],
+    },
+    ProtocolEntry {
+        name: "Meteora Pools",
+        program_id: METEORA_POOLS_PROGRAM_ID,
+        events: &[
+            "MeteoraPoolsSwap",
+            "MeteoraPoolsAddLiquidity",
+            "MeteoraPoolsRemoveLiquidity",
+            "MeteoraPoolsBootstrapLiquidity",
+            "MeteoraPoolsPoolCreated",
+            "MeteoraPoolsSetPoolFees",
+        ],
+        parse_sources: &[Logs, InnerInstruction],
+    },
+    ProtocolEntry {
+        name: "Meteora DAMM V2",
+        program_id: METEORA_DAMM_V2_PROGRAM_ID,
+        events: &[
+            "MeteoraDammV2Swap",
+            "MeteoraDammV2CreatePosition",
+            "MeteoraDammV2ClosePosition",
+            "MeteoraDammV2AddLiquidity",
+            "MeteoraDammV2RemoveLiquidity",
+        ],
+        parse_sources: &[Logs, Instruction, InnerInstruction],
+    },
+    ProtocolEntry {
+        name: "Meteora DLMM",
+        program_id: METEORA_DLMM_PROGRAM_ID,
+        events: &[
+            "MeteoraDlmmSwap",
+            "MeteoraDlmmAddLiquidity",
+            "MeteoraDlmmRemoveLiquidity",
+            "MeteoraDlmmInitializePool",
+            "MeteoraDlmmInitializeBinArray",
+            "MeteoraDlmmCreatePosition",
+            "MeteoraDlmmClosePosition",
+            "MeteoraDlmmClaimFee",
+            "MeteoraDlmmClaimReward",
+            "MeteoraDlmmFundReward",
+            "MeteoraDlmmUpdatePositionOperator",
+            "MeteoraDlmmInitializeBinArrayBitmapExtension",
+        ],
+        parse_sources: &[Logs, Instruction, InnerInstruction],
+    },
+];
+
+/// 本构建支持的协议及其事件、事件来源
+pub fn supported_protocols() -> Vec<ProtocolInfo> {
+    PROTOCOL_REGISTRY.iter().map(ProtocolInfo::from).collect()
+}
+
+/// 按程序 ID 查询协议
+pub fn protocol_by_program_id(program_id: &Pubkey) -> Option<ProtocolInfo> {
+    PROTOCOL_REGISTRY.iter().find(|e| e.program_id == *program_id).map(ProtocolInfo::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::PumpFunTradeEvent;
+    use crate::DexEvent;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_supported_protocols_from_registry() {
+        let protocols = supported_protocols();
+        assert_eq!(protocols.len(), PROTOCOL_REGISTRY.len());
+
+        let ids: HashSet<_> = protocols.iter().map(|p| p.program_id).collect();
+        assert_eq!(ids.len(), protocols.len(), "duplicate program id");
+        let events: Vec<_> = protocols.iter().flat_map(|p| p.events.iter()).collect();
+        assert_eq!(events.iter().collect::<HashSet<_>>().len(), events.len(), "duplicate event");
+
+        // 事件名与 DexEvent::event_type_name 一致
+        let buy = DexEvent::PumpFunBuy(PumpFunTradeEvent::default());
+        let pumpfun = protocol_by_program_id(&PUMPFUN_PROGRAM_ID).unwrap();
+        assert_eq!(pumpfun.name, "PumpFun");
+        assert!(pumpfun.events.contains(&buy.event_type_name()));
+        assert!(pumpfun.parse_sources.contains(&ParseSource::Logs));
+        assert!(protocol_by_program_id(&Pubkey::new_unique()).is_none());
+    }
+}
@@ -6,8 +6,8 @@
 //! 各协议的具体填充逻辑在 account_fillers/ 子模块中实现。
 
 use crate::core::account_fillers::{self, AccountGetter};
+use crate::core::cache::TransactionKeys;
 use crate::core::events::*;
-use crate::instr::utils::get_instruction_account_getter;
 use solana_sdk::pubkey::Pubkey;
 use std::collections::HashMap;
 use yellowstone_grpc_proto::prelude::{Transaction, TransactionStatusMeta};
@@ -43,19 +43,10 @@ fn find_instruction_invoke<'a>(
 
 /// 通用填充辅助宏
 macro_rules! fill_event_accounts {
-    ($event:expr, $meta:expr, $tx:expr, $invokes:expr, $program_id:expr, $filler:expr) => {
+    ($event:expr, $meta:expr, $tx:expr, $keys:expr, $invokes:expr, $program_id:expr, $filler:expr) => {
         if let Some(invokes) = $invokes.get($program_id) {
             if let Some(invoke) = find_instruction_invoke(invokes, $meta, $tx) {
-                let account_keys =
-                    $tx.as_ref().and_then(|tx| tx.message.as_ref()).map(|msg| &msg.account_keys);
-                if let Some(get_account) = get_instruction_account_getter(
-                    $meta,
-                    $tx,
-                    account_keys,
-                    &$meta.loaded_writable_addresses,
-                    &$meta.loaded_readonly_addresses,
-                    invoke,
-                ) {
+                if let Some(get_account) = $keys.account_getter($meta, $tx, invoke) {
                     $filler(&get_account);
                 }
             }
@@ -72,6 +63,7 @@ pub fn fill_accounts_with_owned_keys(
     event: &mut DexEvent,
     meta: &TransactionStatusMeta,
     transaction: &Option<Transaction>,
+    keys: &TransactionKeys,
     program_invokes: &HashMap<Pubkey, Vec<(i32, i32)>>,
 ) {
     use crate::grpc::program_ids::*;
@@ -86,6 +78,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &PUMPFUN_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -98,6 +91,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &PUMPFUN_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -110,6 +104,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &PUMPFUN_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -124,6 +119,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &PUMPSWAP_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -136,6 +132,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &PUMPSWAP_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -148,6 +145,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &PUMPSWAP_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -160,6 +158,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &PUMPSWAP_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -168,15 +167,8 @@ pub fn fill_accounts_with_owned_keys(
             );
 
             // Detect Pump.fun migration
-            if let Some(tx) = transaction.as_ref() {
-                if let Some(msg) = tx.message.as_ref() {
-                    if let Some(signer_key_bytes) = msg.account_keys.first() {
-                        let signer = crate::instr::utils::read_pubkey_fast(signer_key_bytes);
-                        if signer == PUMPFUN_MIGRATION_PROGRAM {
-                            e.is_pumpfun_migrated_pool = true;
-                        }
-                    }
-                }
+            if keys.signer() == Some(&PUMPFUN_MIGRATION_PROGRAM) {
+                e.is_pumpfun_migrated_pool = true;
             }
         }
         DexEvent::PumpSwapLiquidityAdded(e) => {
@@ -184,6 +176,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &PUMPSWAP_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -196,6 +189,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &PUMPSWAP_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -210,6 +204,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &RAYDIUM_CLMM_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -222,6 +217,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &RAYDIUM_CLMM_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -234,6 +230,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &RAYDIUM_CLMM_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -246,6 +243,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &RAYDIUM_CLMM_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -258,6 +256,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &RAYDIUM_CLMM_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -270,6 +269,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &RAYDIUM_CLMM_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -284,6 +284,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &RAYDIUM_CPMM_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -296,6 +297,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &RAYDIUM_CPMM_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -308,6 +310,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &RAYDIUM_CPMM_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -320,6 +323,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &RAYDIUM_CPMM_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -334,6 +338,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &RAYDIUM_AMM_V4_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -346,6 +351,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &RAYDIUM_AMM_V4_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -358,6 +364,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &RAYDIUM_AMM_V4_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -372,6 +379,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &ORCA_WHIRLPOOL_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -384,6 +392,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &ORCA_WHIRLPOOL_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -396,6 +405,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &ORCA_WHIRLPOOL_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -410,6 +420,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &METEORA_DAMM_V2_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -422,6 +433,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &METEORA_DAMM_V2_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -434,6 +446,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &METEORA_DAMM_V2_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -446,6 +459,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &METEORA_DAMM_V2_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -458,6 +472,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &METEORA_DAMM_V2_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -472,6 +487,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &METEORA_POOLS_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -484,6 +500,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &METEORA_POOLS_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -496,6 +513,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &METEORA_POOLS_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -510,6 +528,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &METEORA_DLMM_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -522,6 +541,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &METEORA_DLMM_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -534,6 +554,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &METEORA_DLMM_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -541,6 +562,58 @@ pub fn fill_accounts_with_owned_keys(
                 }
             );
         }
+        DexEvent::MeteoraDlmmClaimReward(e) => {
+            fill_event_accounts!(
+                e,
+                meta,
+                transaction,
+                keys,
+                program_invokes,
+                &METEORA_DLMM_PROGRAM,
+                |get: &AccountGetter<'_>| {
+                    account_fillers::meteora::fill_dlmm_claim_reward_accounts(e, get);
+                }
+            );
+        }
+        DexEvent::MeteoraDlmmFundReward(e) => {
+            fill_event_accounts!(
+                e,
+                meta,
+                transaction,
+                keys,
+                program_invokes,
+                &METEORA_DLMM_PROGRAM,
+                |get: &AccountGetter<'_>| {
+                    account_fillers::meteora::fill_dlmm_fund_reward_accounts(e, get);
+                }
+            );
+        }
+        DexEvent::MeteoraDlmmUpdatePositionOperator(e) => {
+            fill_event_accounts!(
+                e,
+                meta,
+                transaction,
+                keys,
+                program_invokes,
+                &METEORA_DLMM_PROGRAM,
+                |get: &AccountGetter<'_>| {
+                    account_fillers::meteora::fill_dlmm_update_position_operator_accounts(e, get);
+                }
+            );
+        }
+        DexEvent::MeteoraDlmmInitializePool(e) => {
+            fill_event_accounts!(
+                e,
+                meta,
+                transaction,
+                keys,
+                program_invokes,
+                &METEORA_DLMM_PROGRAM,
+                |get: &AccountGetter<'_>| {
+                    account_fillers::meteora::fill_dlmm_initialize_pool_accounts(e, get);
+                }
+            );
+        }
 
         // Bonk
         DexEvent::BonkTrade(e) => {
@@ -548,6 +621,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &BONK_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -560,6 +634,7 @@ pub fn fill_accounts_with_owned_keys(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 &BONK_PROGRAM,
                 |get: &AccountGetter<'_>| {
@@ -577,6 +652,7 @@ pub fn fill_accounts_from_transaction_data(
     event: &mut DexEvent,
     meta: &TransactionStatusMeta,
     transaction: &Option<Transaction>,
+    keys: &TransactionKeys,
     program_invokes: &HashMap<&str, Vec<(i32, i32)>>,
 ) {
     use crate::grpc::program_ids::*;
@@ -591,6 +667,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 PUMPFUN_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -603,6 +680,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 PUMPFUN_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -615,6 +693,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 PUMPFUN_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -629,6 +708,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 PUMPSWAP_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -641,6 +721,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 PUMPSWAP_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -653,6 +734,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 PUMPSWAP_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -665,6 +747,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 PUMPSWAP_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -673,15 +756,8 @@ pub fn fill_accounts_from_transaction_data(
             );
 
             // Detect Pump.fun migration
-            if let Some(tx) = transaction.as_ref() {
-                if let Some(msg) = tx.message.as_ref() {
-                    if let Some(signer_key_bytes) = msg.account_keys.first() {
-                        let signer = crate::instr::utils::read_pubkey_fast(signer_key_bytes);
-                        if signer == PUMPFUN_MIGRATION_PROGRAM {
-                            e.is_pumpfun_migrated_pool = true;
-                        }
-                    }
-                }
+            if keys.signer() == Some(&PUMPFUN_MIGRATION_PROGRAM) {
+                e.is_pumpfun_migrated_pool = true;
             }
         }
         DexEvent::PumpSwapLiquidityAdded(e) => {
@@ -689,6 +765,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 PUMPSWAP_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -701,6 +778,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 PUMPSWAP_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -715,6 +793,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 RAYDIUM_CLMM_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -727,6 +806,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 RAYDIUM_CLMM_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -739,6 +819,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 RAYDIUM_CLMM_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -751,6 +832,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 RAYDIUM_CLMM_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -763,6 +845,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 RAYDIUM_CLMM_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -775,6 +858,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 RAYDIUM_CLMM_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -789,6 +873,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 RAYDIUM_CPMM_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -801,6 +886,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 RAYDIUM_CPMM_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -813,6 +899,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 RAYDIUM_CPMM_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -825,6 +912,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 RAYDIUM_CPMM_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -839,6 +927,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 RAYDIUM_AMM_V4_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -851,6 +940,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 RAYDIUM_AMM_V4_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -863,6 +953,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 RAYDIUM_AMM_V4_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -877,6 +968,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 ORCA_WHIRLPOOL_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -889,6 +981,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 ORCA_WHIRLPOOL_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -901,6 +994,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 ORCA_WHIRLPOOL_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -915,6 +1009,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 METEORA_DAMM_V2_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -927,6 +1022,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 METEORA_DAMM_V2_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -939,6 +1035,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 METEORA_DAMM_V2_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -951,6 +1048,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 METEORA_DAMM_V2_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -963,6 +1061,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 METEORA_DAMM_V2_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -977,6 +1076,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 METEORA_POOLS_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -989,6 +1089,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 METEORA_POOLS_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -1001,6 +1102,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 METEORA_POOLS_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -1015,6 +1117,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 METEORA_DLMM_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -1027,6 +1130,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 METEORA_DLMM_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -1039,6 +1143,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 METEORA_DLMM_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -1046,6 +1151,58 @@ pub fn fill_accounts_from_transaction_data(
                 }
             );
         }
+        DexEvent::MeteoraDlmmClaimReward(e) => {
+            fill_event_accounts!(
+                e,
+                meta,
+                transaction,
+                keys,
+                program_invokes,
+                METEORA_DLMM_PROGRAM_ID,
+                |get: &AccountGetter<'_>| {
+                    account_fillers::meteora::fill_dlmm_claim_reward_accounts(e, get);
+                }
+            );
+        }
+        DexEvent::MeteoraDlmmFundReward(e) => {
+            fill_event_accounts!(
+                e,
+                meta,
+                transaction,
+                keys,
+                program_invokes,
+                METEORA_DLMM_PROGRAM_ID,
+                |get: &AccountGetter<'_>| {
+                    account_fillers::meteora::fill_dlmm_fund_reward_accounts(e, get);
+                }
+            );
+        }
+        DexEvent::MeteoraDlmmUpdatePositionOperator(e) => {
+            fill_event_accounts!(
+                e,
+                meta,
+                transaction,
+                keys,
+                program_invokes,
+                METEORA_DLMM_PROGRAM_ID,
+                |get: &AccountGetter<'_>| {
+                    account_fillers::meteora::fill_dlmm_update_position_operator_accounts(e, get);
+                }
+            );
+        }
+        DexEvent::MeteoraDlmmInitializePool(e) => {
+            fill_event_accounts!(
+                e,
+                meta,
+                transaction,
+                keys,
+                program_invokes,
+                METEORA_DLMM_PROGRAM_ID,
+                |get: &AccountGetter<'_>| {
+                    account_fillers::meteora::fill_dlmm_initialize_pool_accounts(e, get);
+                }
+            );
+        }
 
         // Bonk
         DexEvent::BonkTrade(e) => {
@@ -1053,6 +1210,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 BONK_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -1065,6 +1223,7 @@ pub fn fill_accounts_from_transaction_data(
                 e,
                 meta,
                 transaction,
+                keys,
                 program_invokes,
                 BONK_PROGRAM_ID,
                 |get: &AccountGetter<'_>| {
@@ -1076,3 +1235,137 @@ pub fn fill_accounts_from_transaction_data(
         _ => {}
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_fixtures::zeroed_event;
+    use serde::Deserialize;
+    use std::collections::BTreeMap;
+    use std::str::FromStr;
+    use yellowstone_grpc_proto::prelude::{
+        CompiledInstruction, InnerInstruction, InnerInstructions, Message,
+    };
+
+    /// 账户填充夹具（`tests/fixtures/account_fillers.json`）
+    ///
+    /// `accounts` 按协议 IDL 顺序列出指令账户名，`expect` 为事件字段 -> 应填入的账户名。
+    /// 协议调整账户顺序时更新夹具，对应填充器未同步修改就会失败。
+    #[derive(Deserialize)]
+    struct FillerFixture {
+        event: String,
+        program: String,
+        /// 覆盖事件中的非账户字段（如 `is_buy`）
+        #[serde(default)]
+        fields: serde_json::Map<String, serde_json::Value>,
+        accounts: Vec<String>,
+        expect: BTreeMap<String, String>,
+    }
+
+    type ProgramInvokes = HashMap<Pubkey, Vec<(i32, i32)>>;
+
+    /// 账户名对应的确定地址
+    fn account_key(name: &str) -> Pubkey {
+        let digest = ring::digest::digest(&ring::digest::SHA256, name.as_bytes());
+        Pubkey::new_from_array(digest.as_ref().try_into().unwrap())
+    }
+
+    /// 日志解析出的事件：账户字段全部为默认值
+    fn event_for(fixture: &FillerFixture) -> DexEvent {
+        let event = zeroed_event(&fixture.event)
+            .unwrap_or_else(|| panic!("{}: 不是 DexEvent 变体", fixture.event));
+        let mut value = serde_json::to_value(event).unwrap();
+        let fields = value[&fixture.event].as_object_mut().unwrap();
+        for (name, field) in &fixture.fields {
+            assert!(fields.contains_key(name), "{}: 没有字段 {name}", fixture.event);
+            fields.insert(name.clone(), field.clone());
+        }
+        serde_json::from_value(value).unwrap()
+    }
+
+    /// 构造只包含夹具指令的交易，`as_inner` 为 true 时作为外层指令 0 的 CPI 出现
+    fn transaction_for(
+        fixture: &FillerFixture,
+        program: Pubkey,
+        as_inner: bool,
+    ) -> (TransactionStatusMeta, Option<Transaction>, ProgramInvokes) {
+        let mut keys = vec![account_key("fee_payer")];
+        keys.extend(fixture.accounts.iter().map(|name| account_key(name)));
+        keys.push(program);
+        keys.push(account_key("router"));
+        let accounts: Vec<u8> = (1..=fixture.accounts.len() as u8).collect();
+        let program_index = keys.len() as u32 - 2;
+
+        let (outer, inner_instructions, invoke) = if as_inner {
+            let inner = InnerInstructions {
+                index: 0,
+                instructions: vec![InnerInstruction {
+                    program_id_index: program_index,
+                    accounts,
+                    ..Default::default()
+                }],
+            };
+            let router = CompiledInstruction {
+                program_id_index: program_index + 1,
+                accounts: vec![],
+                data: vec![],
+            };
+            (router, vec![inner], (0, 0))
+        } else {
+            let outer =
+                CompiledInstruction { program_id_index: program_index, accounts, data: vec![] };
+            (outer, vec![], (0, -1))
+        };
+
+        let transaction = Some(Transaction {
+            message: Some(Message {
+                account_keys: keys.iter().map(|k| k.to_bytes().to_vec()).collect(),
+                instructions: vec![outer],
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        let meta = TransactionStatusMeta { inner_instructions, ..Default::default() };
+        (meta, transaction, HashMap::from([(program, vec![invoke])]))
+    }
+
+    #[test]
+    fn test_account_fillers_against_fixtures() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/account_fillers.json");
+        let fixtures: Vec<FillerFixture> =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+
+        let mut failures = Vec::new();
+        for fixture in &fixtures {
+            let program = Pubkey::from_str(&fixture.program).unwrap();
+            for as_inner in [false, true] {
+                let (meta, transaction, invokes) = transaction_for(fixture, program, as_inner);
+                let keys = TransactionKeys::from_grpc(&meta, &transaction);
+                let mut event = event_for(fixture);
+                fill_accounts_with_owned_keys(&mut event, &meta, &transaction, &keys, &invokes);
+
+                let value = serde_json::to_value(&event).unwrap();
+                for (field, account) in &fixture.expect {
+                    let location = if as_inner { "inner" } else { "outer" };
+                    let Some(actual) = value[&fixture.event].get(field) else {
+                        failures.push(format!("{}.{field}: 事件没有该字段", fixture.event));
+                        continue;
+                    };
+                    let actual: Pubkey = serde_json::from_value(actual.clone()).unwrap();
+                    if actual == Pubkey::default() {
+                        failures.push(format!(
+                            "{}.{field} ({location}): 仍为 Pubkey::default()",
+                            fixture.event
+                        ));
+                    } else if actual != account_key(account) {
+                        failures.push(format!(
+                            "{}.{field} ({location}): 未填入账户 {account}",
+                            fixture.event
+                        ));
+                    }
+                }
+            }
+        }
+        assert!(failures.is_empty(), "账户填充与夹具不一致:\n{}", failures.join("\n"));
+    }
+}
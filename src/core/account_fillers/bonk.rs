@@ -7,43 +7,49 @@ pub type AccountGetter<'a> = dyn Fn(usize) -> Pubkey + 'a;
 
 /// 填充 Bonk Trade 事件账户
 ///
-/// Trade instruction account mapping (based on IDL):
-/// 0: user
-/// 1: poolState
-/// 2: userTokenAccount
-/// 3: poolTokenAccount
+/// buy/sell exact in/out instruction account mapping (based on raydium_launchpad IDL):
+/// 0: payer
+/// 1: authority
+/// 2: global_config
+/// 3: platform_config
+/// 4: pool_state
+/// 5: user_base_token
+/// 6: user_quote_token
 pub fn fill_trade_accounts(e: &mut BonkTradeEvent, get: &AccountGetter<'_>) {
     if e.user == Pubkey::default() {
         e.user = get(0);
     }
     if e.pool_state == Pubkey::default() {
-        e.pool_state = get(1);
+        e.pool_state = get(4);
     }
 }
 
 /// Bonk Pool Create 账户填充
 ///
-/// createPool instruction account mapping (based on IDL):
-/// 0: state
-/// 1: pool
-/// 2: tokenX
-/// 3: tokenY
-/// 4: poolXAccount
-/// 5: poolYAccount
-/// 6: adminXAccount
-/// 7: adminYAccount
-/// 8: admin
-/// 9: projectOwner
-/// 10: programAuthority
-/// 11: systemProgram
-/// 12: tokenProgram
-/// 13: rent
+/// initialize / initialize_v2 instruction account mapping (based on raydium_launchpad IDL):
+/// 0: payer
+/// 1: creator
+/// 2: global_config
+/// 3: platform_config
+/// 4: authority
+/// 5: pool_state
+/// 6: base_mint
+/// 7: quote_mint
 pub fn fill_pool_create_accounts(e: &mut BonkPoolCreateEvent, get: &AccountGetter<'_>) {
     if e.pool_state == Pubkey::default() {
-        e.pool_state = get(1); // pool
+        e.pool_state = get(5);
     }
     if e.creator == Pubkey::default() {
-        e.creator = get(8); // admin
+        e.creator = get(1);
     }
-    // base_mint_param 已从事件数据或其他来源解析
+    if e.config == Pubkey::default() {
+        e.config = get(2);
+    }
+    if e.base_mint == Pubkey::default() {
+        e.base_mint = get(6);
+    }
+    if e.quote_mint == Pubkey::default() {
+        e.quote_mint = get(7);
+    }
+    // base_mint_param 已从事件数据解析
 }
@@ -74,8 +74,10 @@ pub fn fill_damm_v2_remove_liquidity_accounts(_e: &mut MeteoraDammV2RemoveLiquid
 /// 12: user
 /// 13: vaultProgram
 /// 14: tokenProgram
-pub fn fill_pools_swap_accounts(_e: &mut MeteoraPoolsSwapEvent, _get: &AccountGetter<'_>) {
-    // 事件数据已包含主要信息
+pub fn fill_pools_swap_accounts(e: &mut MeteoraPoolsSwapEvent, get: &AccountGetter<'_>) {
+    if e.pool == Pubkey::default() {
+        e.pool = get(0);
+    }
 }
 
 /// Meteora Pools Add Liquidity 账户填充
@@ -96,8 +98,10 @@ pub fn fill_pools_swap_accounts(_e: &mut MeteoraPoolsSwapEvent, _get: &AccountGe
 /// 12: userBToken
 /// 13: user
 /// ...
-pub fn fill_pools_add_liquidity_accounts(_e: &mut MeteoraPoolsAddLiquidityEvent, _get: &AccountGetter<'_>) {
-    // 事件数据已包含主要信息
+pub fn fill_pools_add_liquidity_accounts(e: &mut MeteoraPoolsAddLiquidityEvent, get: &AccountGetter<'_>) {
+    if e.pool == Pubkey::default() {
+        e.pool = get(0);
+    }
 }
 
 /// Meteora Pools Remove Liquidity 账户填充
@@ -111,8 +115,10 @@ pub fn fill_pools_add_liquidity_accounts(_e: &mut MeteoraPoolsAddLiquidityEvent,
 /// 5: aVault
 /// 6: bVault
 /// ...
-pub fn fill_pools_remove_liquidity_accounts(_e: &mut MeteoraPoolsRemoveLiquidityEvent, _get: &AccountGetter<'_>) {
-    // 事件数据已包含主要信息
+pub fn fill_pools_remove_liquidity_accounts(e: &mut MeteoraPoolsRemoveLiquidityEvent, get: &AccountGetter<'_>) {
+    if e.pool == Pubkey::default() {
+        e.pool = get(0);
+    }
 }
 
 // ============================================================================
@@ -180,3 +186,87 @@ pub fn fill_dlmm_add_liquidity_accounts(_e: &mut MeteoraDlmmAddLiquidityEvent, _
 pub fn fill_dlmm_remove_liquidity_accounts(_e: &mut MeteoraDlmmRemoveLiquidityEvent, _get: &AccountGetter<'_>) {
     // 事件数据已包含主要信息
 }
+
+/// Meteora DLMM Claim Reward 账户填充
+///
+/// claimReward instruction account mapping (based on IDL):
+/// 0: lbPair
+/// 1: position
+/// 2: binArrayLower
+/// 3: binArrayUpper
+/// 4: sender
+/// 5: rewardVault
+/// 6: rewardMint
+/// 7: userTokenAccount
+/// ...
+pub fn fill_dlmm_claim_reward_accounts(e: &mut MeteoraDlmmClaimRewardEvent, get: &AccountGetter<'_>) {
+    if e.reward_mint == Pubkey::default() {
+        e.reward_mint = get(6);
+    }
+}
+
+/// Meteora DLMM Fund Reward 账户填充
+///
+/// fundReward instruction account mapping (based on IDL):
+/// 0: lbPair
+/// 1: rewardVault
+/// 2: rewardMint
+/// 3: funderTokenAccount
+/// 4: funder
+/// ...
+pub fn fill_dlmm_fund_reward_accounts(e: &mut MeteoraDlmmFundRewardEvent, get: &AccountGetter<'_>) {
+    if e.reward_mint == Pubkey::default() {
+        e.reward_mint = get(2);
+    }
+}
+
+/// Meteora DLMM Update Position Operator 账户填充
+///
+/// updatePositionOperator instruction account mapping (based on IDL):
+/// 0: position
+/// 1: owner
+/// ...
+pub fn fill_dlmm_update_position_operator_accounts(
+    e: &mut MeteoraDlmmUpdatePositionOperatorEvent,
+    get: &AccountGetter<'_>,
+) {
+    if e.owner == Pubkey::default() {
+        e.owner = get(1);
+    }
+}
+
+/// Meteora DLMM Initialize Pool 账户填充
+///
+/// initializeLbPair / initializeCustomizablePermissionlessLbPair instruction account mapping (based on IDL):
+/// 0: lbPair
+/// 1: binArrayBitmapExtension
+/// 2: tokenMintX
+/// 3: tokenMintY
+/// ...
+/// 8: funder
+///
+/// initializePermissionLbPair 多一个前置的 base 账户：
+/// 0: base
+/// 1: lbPair
+/// 2: binArrayBitmapExtension
+/// 3: tokenMintX
+/// 4: tokenMintY
+/// ...
+/// 8: admin
+///
+/// 日志事件已带 lbPair 时按其位置区分两种布局；未知时按 initializeLbPair 处理
+pub fn fill_dlmm_initialize_pool_accounts(e: &mut MeteoraDlmmInitializePoolEvent, get: &AccountGetter<'_>) {
+    let base = if e.pool != Pubkey::default() && get(0) != e.pool && get(1) == e.pool { 1 } else { 0 };
+    if e.pool == Pubkey::default() {
+        e.pool = get(0);
+    }
+    if e.creator == Pubkey::default() {
+        e.creator = get(8);
+    }
+    if e.token_x_mint == Pubkey::default() {
+        e.token_x_mint = get(base + 2);
+    }
+    if e.token_y_mint == Pubkey::default() {
+        e.token_y_mint = get(base + 3);
+    }
+}
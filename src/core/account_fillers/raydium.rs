@@ -20,6 +20,8 @@ pub type AccountGetter<'a> = dyn Fn(usize) -> Pubkey + 'a;
 /// 5: inputVault
 /// 6: outputVault
 /// 7: observationState
+///
+/// swap_v2 前 8 个账户顺序相同（之后为 Token-2022 程序、memo 程序和两侧 vault mint）
 pub fn fill_clmm_swap_accounts(e: &mut RaydiumClmmSwapEvent, get: &AccountGetter<'_>) {
     if e.pool_state == Pubkey::default() {
         e.pool_state = get(2);
@@ -207,14 +209,37 @@ pub fn fill_cpmm_withdraw_accounts(e: &mut RaydiumCpmmWithdrawEvent, get: &Accou
 /// 1: ammConfig
 /// 2: authority
 /// 3: poolState
+/// 4: token0Mint
+/// 5: token1Mint
+/// 6: lpMint
+/// 10: token0Vault
+/// 11: token1Vault
 /// ...
 pub fn fill_cpmm_initialize_accounts(e: &mut RaydiumCpmmInitializeEvent, get: &AccountGetter<'_>) {
     if e.creator == Pubkey::default() {
         e.creator = get(0);
     }
+    if e.amm_config == Pubkey::default() {
+        e.amm_config = get(1);
+    }
     if e.pool == Pubkey::default() {
         e.pool = get(3);
     }
+    if e.token_0_mint == Pubkey::default() {
+        e.token_0_mint = get(4);
+    }
+    if e.token_1_mint == Pubkey::default() {
+        e.token_1_mint = get(5);
+    }
+    if e.lp_mint == Pubkey::default() {
+        e.lp_mint = get(6);
+    }
+    if e.token_0_vault == Pubkey::default() {
+        e.token_0_vault = get(10);
+    }
+    if e.token_1_vault == Pubkey::default() {
+        e.token_1_vault = get(11);
+    }
 }
 
 // ============================================================================
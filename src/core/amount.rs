@@ -0,0 +1,225 @@
+//! 带精度的代币数量
+//!
+//! 事件中的数量都是原始单位（`u64`），换算成 UI 数量、格式化和跨精度比较时容易出错
+//! （`as f64` 丢精度、`10u64.pow` 溢出、不同 decimals 直接相加等）。[`Amount`] 把原始数量和
+//! decimals 放在一起：
+//! - 精确的十进制字符串格式化 / 解析，不经过浮点
+//! - 转换为 `f64`，开启 `decimal` feature 时可无损转换为 `rust_decimal::Decimal`
+//! - 检查溢出和精度不一致的算术，失败时返回 None
+//!
+//! 价格换算（Q64.64、tick、DLMM bin）见 [`crate::core::price`]。
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+pub use super::price::sqrt_price_x64_to_price;
+
+#[inline]
+fn pow10(decimals: u8) -> Option<u64> {
+    10u64.checked_pow(decimals as u32)
+}
+
+/// 原始数量 + 精度
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Amount {
+    pub raw: u64,
+    pub decimals: u8,
+}
+
+impl Amount {
+    pub const fn new(raw: u64, decimals: u8) -> Self {
+        Self { raw, decimals }
+    }
+
+    pub const fn zero(decimals: u8) -> Self {
+        Self { raw: 0, decimals }
+    }
+
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    /// UI 数量（可能丢失超过 53 位有效数字的精度）
+    pub fn to_f64(self) -> f64 {
+        self.raw as f64 / 10f64.powi(self.decimals as i32)
+    }
+
+    /// 由 UI 数量构造，四舍五入到 `decimals` 位；负数、NaN、溢出时返回 None
+    pub fn from_f64(value: f64, decimals: u8) -> Option<Self> {
+        let raw = (value * 10f64.powi(decimals as i32)).round();
+        // u64::MAX as f64 向上取整为 2^64，需用 < 判断
+        (raw.is_finite() && raw >= 0.0 && raw < u64::MAX as f64)
+            .then(|| Self::new(raw as u64, decimals))
+    }
+
+    /// 精确解析十进制字符串（如 `"1.5"`），小数位超过 `decimals` 或溢出时返回 None
+    pub fn parse(s: &str, decimals: u8) -> Option<Self> {
+        let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+        if (int.is_empty() && frac.is_empty()) || frac.len() > decimals as usize {
+            return None;
+        }
+        let digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        if !digits(int) || !digits(frac) {
+            return None;
+        }
+        let int: u64 = if int.is_empty() { 0 } else { int.parse().ok()? };
+        let frac_raw: u64 = if frac.is_empty() { 0 } else { frac.parse().ok()? };
+        let frac_raw = frac_raw.checked_mul(pow10(decimals - frac.len() as u8)?)?;
+        let raw = int.checked_mul(pow10(decimals)?)?.checked_add(frac_raw)?;
+        Some(Self::new(raw, decimals))
+    }
+
+    /// 保留 `places` 位小数的字符串，多余位截断（不四舍五入），不足补 0
+    pub fn format_fixed(&self, places: u8) -> String {
+        let s = self.to_string();
+        let Some((int, frac)) = s.split_once('.') else {
+            return if places == 0 { s } else { format!("{}.{}", s, "0".repeat(places as usize)) };
+        };
+        if places == 0 {
+            return int.to_string();
+        }
+        let frac: String =
+            frac.chars().chain(std::iter::repeat('0')).take(places as usize).collect();
+        format!("{}.{}", int, frac)
+    }
+
+    /// 换算到另一精度；降低精度时截断，溢出时返回 None
+    pub fn rescale(self, decimals: u8) -> Option<Self> {
+        let raw = if decimals >= self.decimals {
+            self.raw.checked_mul(pow10(decimals - self.decimals)?)?
+        } else {
+            pow10(self.decimals - decimals).map_or(0, |unit| self.raw / unit)
+        };
+        Some(Self::new(raw, decimals))
+    }
+
+    /// 精度不同或溢出时返回 None
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        (self.decimals == other.decimals)
+            .then(|| self.raw.checked_add(other.raw))
+            .flatten()
+            .map(|raw| Self::new(raw, self.decimals))
+    }
+
+    /// 精度不同或结果为负时返回 None
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        (self.decimals == other.decimals)
+            .then(|| self.raw.checked_sub(other.raw))
+            .flatten()
+            .map(|raw| Self::new(raw, self.decimals))
+    }
+
+    /// 按比例缩放 `raw * numerator / denominator`（中间结果用 u128，向下取整）
+    ///
+    /// 用于按基点计算手续费等，`denominator` 为 0 或结果溢出时返回 None。
+    pub fn checked_mul_div(self, numerator: u64, denominator: u64) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let raw = self.raw as u128 * numerator as u128 / denominator as u128;
+        u64::try_from(raw).ok().map(|raw| Self::new(raw, self.decimals))
+    }
+
+    /// UI 数量之比 `self / other`（如 quote / base 即成交价），`other` 为 0 时返回 None
+    pub fn ratio(self, other: Self) -> Option<f64> {
+        (!other.is_zero()).then(|| self.to_f64() / other.to_f64())
+    }
+
+    /// 无损转换为 `Decimal`，`decimals` 超过 28 时返回 None
+    #[cfg(feature = "decimal")]
+    pub fn to_decimal(self) -> Option<rust_decimal::Decimal> {
+        rust_decimal::Decimal::try_from_i128_with_scale(self.raw as i128, self.decimals as u32).ok()
+    }
+
+    /// 由 `Decimal` 构造，小数位超过 `decimals`、为负或溢出时返回 None
+    #[cfg(feature = "decimal")]
+    pub fn from_decimal(value: rust_decimal::Decimal, decimals: u8) -> Option<Self> {
+        if value.is_sign_negative() && !value.is_zero() {
+            return None;
+        }
+        let mut scaled = value;
+        scaled.rescale(decimals as u32);
+        if scaled != value || scaled.scale() != decimals as u32 {
+            return None;
+        }
+        u64::try_from(scaled.mantissa()).ok().map(|raw| Self::new(raw, decimals))
+    }
+}
+
+/// 精确的十进制表示，去掉末尾的 0（如 `1.5`、`0.000001`、`12`）
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.decimals == 0 {
+            return write!(f, "{}", self.raw);
+        }
+        let Some(unit) = pow10(self.decimals) else {
+            // 超过 u64 精度：整数部分必为 0
+            let digits = format!("{:0>width$}", self.raw, width = self.decimals as usize);
+            let frac = digits.trim_end_matches('0');
+            return if frac.is_empty() { write!(f, "0") } else { write!(f, "0.{}", frac) };
+        };
+        let (int, frac) = (self.raw / unit, self.raw % unit);
+        if frac == 0 {
+            return write!(f, "{}", int);
+        }
+        let frac = format!("{:0>width$}", frac, width = self.decimals as usize);
+        write!(f, "{}.{}", int, frac.trim_end_matches('0'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_amount_conversions() {
+        let sol = Amount::new(1_500_000_000, 9);
+        assert_eq!(sol.to_string(), "1.5");
+        assert_eq!(Amount::new(1, 6).to_string(), "0.000001");
+        assert_eq!(Amount::new(u64::MAX, 19).to_string(), "1.8446744073709551615");
+        assert_eq!(Amount::new(42, 0).to_string(), "42");
+        assert_eq!(sol.format_fixed(4), "1.5000");
+        assert_eq!(Amount::new(1_234_567, 6).format_fixed(2), "1.23");
+        assert_eq!(sol.to_f64(), 1.5);
+
+        assert_eq!(Amount::parse("1.5", 9), Some(sol));
+        assert_eq!(Amount::parse(".25", 2), Some(Amount::new(25, 2)));
+        assert_eq!(Amount::parse("1.0000001", 6), None);
+        assert_eq!(Amount::parse("18446744073709551616", 0), None);
+        assert_eq!(Amount::parse("-1", 6), None);
+        assert_eq!(Amount::from_f64(0.1, 9), Some(Amount::new(100_000_000, 9)));
+        assert_eq!(Amount::from_f64(-1.0, 9), None);
+        assert_eq!(Amount::from_f64(1e30, 9), None);
+
+        // 算术：精度不一致、溢出、下溢
+        let usdc = Amount::new(2_000_000, 6);
+        assert_eq!(sol.checked_add(usdc), None);
+        assert_eq!(sol.checked_add(Amount::new(u64::MAX, 9)), None);
+        assert_eq!(usdc.checked_sub(Amount::new(3_000_000, 6)), None);
+        assert_eq!(
+            usdc.rescale(9).and_then(|a| a.checked_sub(sol)),
+            Some(Amount::new(500_000_000, 9))
+        );
+        assert_eq!(Amount::new(u64::MAX, 0).rescale(1), None);
+        assert_eq!(
+            Amount::new(u64::MAX, 9).checked_mul_div(25, 10_000),
+            Some(Amount::new(u64::MAX / 400, 9))
+        );
+        assert_eq!(usdc.ratio(sol), Some(2.0 / 1.5));
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_amount_decimal() {
+        let amount = Amount::new(1_500_000_001, 9);
+        let decimal = amount.to_decimal().unwrap();
+        assert_eq!(decimal.to_string(), "1.500000001");
+        assert_eq!(Amount::from_decimal(decimal, 9), Some(amount));
+        assert_eq!(Amount::from_decimal(decimal, 6), None);
+        assert_eq!(
+            Amount::from_decimal(rust_decimal::Decimal::new(15, 1), 9),
+            Some(Amount::new(1_500_000_000, 9))
+        );
+    }
+}
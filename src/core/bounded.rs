@@ -0,0 +1,256 @@
+//! 有界状态表
+//!
+//! 长时间运行时，池子注册表、交易账户缓存、钱包 / 持仓跟踪等内部映射会随新池子和新钱包持续增长。
+//! [`BoundedMap`] 为这些映射提供统一的增长控制：
+//! - 容量上限：超出时淘汰最久未写入的条目（LRU，按写入时间）
+//! - TTL：条目在最后一次写入后超过 `ttl` 即视为过期，读取时不可见，写入时顺带清理
+//!
+//! 读取（[`BoundedMap::get`]）不更新条目的新旧顺序，因此可在 `&self` 下查询；
+//! 淘汰计数见 [`EvictionStats`]。
+
+use super::clock::now_micros;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::time::Duration;
+
+/// 容量与过期配置，默认不限制
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BoundedMapConfig {
+    /// 最大条目数，0 表示不限制
+    pub capacity: usize,
+    /// 条目在最后一次写入后的存活时间，None 表示不过期
+    pub ttl: Option<Duration>,
+}
+
+impl BoundedMapConfig {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { capacity, ttl: None }
+    }
+
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+}
+
+/// 累计淘汰计数
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EvictionStats {
+    /// 超出容量被淘汰的条目数
+    pub capacity: u64,
+    /// 过期被清理的条目数
+    pub expired: u64,
+}
+
+impl EvictionStats {
+    #[inline]
+    pub fn total(&self) -> u64 {
+        self.capacity + self.expired
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Slot<V> {
+    value: V,
+    /// 写入序号，对应 `order` 中的键
+    tick: u64,
+    touched_us: i64,
+}
+
+/// 带 LRU / TTL 淘汰的映射，非线程安全，共享时由持有者加锁
+#[derive(Debug, Clone)]
+pub struct BoundedMap<K, V> {
+    config: BoundedMapConfig,
+    entries: HashMap<K, Slot<V>>,
+    /// 写入序号 → 键，最早写入的在前
+    order: BTreeMap<u64, K>,
+    next_tick: u64,
+    stats: EvictionStats,
+}
+
+impl<K, V> Default for BoundedMap<K, V> {
+    fn default() -> Self {
+        Self {
+            config: BoundedMapConfig::default(),
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+            next_tick: 0,
+            stats: EvictionStats::default(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> BoundedMap<K, V> {
+    pub fn new(config: BoundedMapConfig) -> Self {
+        Self { config, ..Default::default() }
+    }
+
+    pub fn config(&self) -> BoundedMapConfig {
+        self.config
+    }
+
+    /// 修改配置，超出新容量的条目立即淘汰
+    pub fn set_config(&mut self, config: BoundedMapConfig) {
+        self.config = config;
+        self.evict(now_micros());
+    }
+
+    /// 插入或更新条目并刷新其新旧顺序，返回旧值
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let now_us = now_micros();
+        let previous = self.remove(&key);
+        self.push(key, value, now_us);
+        self.evict(now_us);
+        previous
+    }
+
+    /// 取得条目的可变引用，不存在（或已过期）时用 `default` 创建；刷新其新旧顺序
+    pub fn get_or_insert_with(&mut self, key: K, default: impl FnOnce() -> V) -> &mut V {
+        let now_us = now_micros();
+        let value = match self.remove_expired_entry(&key, now_us) {
+            Some(value) => value,
+            None => default(),
+        };
+        self.push(key.clone(), value, now_us);
+        self.evict(now_us);
+        // 刚写入的条目最新，不会被本次淘汰
+        &mut self.entries.get_mut(&key).expect("entry just inserted").value
+    }
+
+    /// 查询条目，不刷新其新旧顺序；已过期的条目返回 None
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let slot = self.entries.get(key)?;
+        (!self.is_expired(slot, now_micros())).then_some(&slot.value)
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let slot = self.entries.remove(key)?;
+        self.order.remove(&slot.tick);
+        Some(slot.value)
+    }
+
+    /// 只保留满足条件的条目（不计入淘汰统计）
+    pub fn retain(&mut self, mut keep: impl FnMut(&K, &mut V) -> bool) {
+        let order = &mut self.order;
+        self.entries.retain(|key, slot| {
+            let kept = keep(key, &mut slot.value);
+            if !kept {
+                order.remove(&slot.tick);
+            }
+            kept
+        });
+    }
+
+    /// 遍历未过期的条目，顺序不定
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        let now_us = now_micros();
+        self.entries
+            .iter()
+            .filter(move |(_, slot)| !self.is_expired(slot, now_us))
+            .map(|(key, slot)| (key, &slot.value))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// 当前保存的条目数（可能包含尚未清理的过期条目）
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+
+    /// 立即清理过期条目，返回清理的数量
+    pub fn purge_expired(&mut self) -> usize {
+        let before = self.stats.expired;
+        self.evict(now_micros());
+        (self.stats.expired - before) as usize
+    }
+
+    pub fn eviction_stats(&self) -> EvictionStats {
+        self.stats
+    }
+
+    fn push(&mut self, key: K, value: V, now_us: i64) {
+        let tick = self.next_tick;
+        self.next_tick += 1;
+        self.order.insert(tick, key.clone());
+        self.entries.insert(key, Slot { value, tick, touched_us: now_us });
+    }
+
+    /// 移除条目并返回仍未过期的值，过期时计入淘汰统计
+    fn remove_expired_entry(&mut self, key: &K, now_us: i64) -> Option<V> {
+        let slot = self.entries.remove(key)?;
+        self.order.remove(&slot.tick);
+        if self.is_expired(&slot, now_us) {
+            self.stats.expired += 1;
+            None
+        } else {
+            Some(slot.value)
+        }
+    }
+
+    #[inline]
+    fn is_expired(&self, slot: &Slot<V>, now_us: i64) -> bool {
+        self.config.ttl.is_some_and(|ttl| now_us - slot.touched_us > ttl.as_micros() as i64)
+    }
+
+    /// 按写入顺序清理过期条目，再淘汰超出容量的最旧条目
+    fn evict(&mut self, now_us: i64) {
+        while let Some((&tick, key)) = self.order.first_key_value() {
+            let over_capacity =
+                self.config.capacity > 0 && self.entries.len() > self.config.capacity;
+            let expired = self.entries.get(key).is_some_and(|slot| self.is_expired(slot, now_us));
+            if !over_capacity && !expired {
+                break;
+            }
+            let key = self.order.remove(&tick).expect("first key exists");
+            self.entries.remove(&key);
+            if expired {
+                self.stats.expired += 1;
+            } else {
+                self.stats.capacity += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounded_map_evicts_lru_and_expired() {
+        let mut map = BoundedMap::new(BoundedMapConfig::with_capacity(2));
+        map.insert(1, "a");
+        map.insert(2, "b");
+        // 写入刷新顺序，容量淘汰最久未写入的 2
+        *map.get_or_insert_with(1, || "x") = "a2";
+        map.insert(3, "c");
+        assert_eq!((map.get(&1), map.get(&2), map.get(&3)), (Some(&"a2"), None, Some(&"c")));
+        assert_eq!(map.eviction_stats(), EvictionStats { capacity: 1, expired: 0 });
+
+        map.set_config(BoundedMapConfig::default().with_ttl(Duration::from_millis(1)));
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(map.get(&1).is_none() && map.iter().next().is_none());
+        assert_eq!(map.purge_expired(), 2);
+        assert!(map.is_empty());
+        assert_eq!(map.eviction_stats().total(), 3);
+    }
+}
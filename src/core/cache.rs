@@ -3,6 +3,10 @@
 //! 提供高性能缓存机制，减少内存分配和重复计算：
 //! - 程序ID缓存：避免重复查找和分配
 //! - 账户公钥缓存：线程局部缓存，零锁竞争
+//! - 签名 base58 缓存：同一交易的多个事件只编码一次签名
+//! - 交易账户表：每笔交易只把 gRPC 字节数组转换为 `Pubkey` 一次，
+//!   指令解析、账户填充和 invoke 映射共用
+//! - 交易账户缓存：按签名短期保存完整账户表（含可写 / 签名标记），供跟单等场景按需查询
 //!
 //! ## 性能优势
 //!
@@ -23,8 +27,14 @@
 //! let account_pubkeys = build_account_pubkeys_with_cache(&instruction_accounts, &all_accounts);
 //! ```
 
+use super::bounded::{BoundedMap, BoundedMapConfig, EvictionStats};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
 use std::cell::RefCell;
+use std::sync::Arc;
+use yellowstone_grpc_proto::prelude::{Transaction, TransactionStatusMeta};
 
 // ============================================================================
 // 账户公钥缓存工具（Account Pubkey Cache）
@@ -134,6 +144,269 @@ pub fn build_account_pubkeys_with_cache(
     })
 }
 
+// ============================================================================
+// 交易账户表（Transaction-scope Pubkey Interner）
+// ============================================================================
+
+/// 单笔交易的完整账户表
+///
+/// 按交易中的账户索引顺序（静态账户 → ALT 可写 → ALT 只读）保存已转换的 `Pubkey`。
+/// 每笔交易构建一次，之后所有按索引取账户的地方都直接读切片，不再重复解析 32 字节数组。
+#[derive(Debug, Clone, Default)]
+pub struct TransactionKeys {
+    keys: Vec<Pubkey>,
+}
+
+impl TransactionKeys {
+    /// 从 gRPC 交易构建账户表
+    pub fn from_grpc(meta: &TransactionStatusMeta, transaction: &Option<Transaction>) -> Self {
+        let static_keys = transaction
+            .as_ref()
+            .and_then(|tx| tx.message.as_ref())
+            .map(|msg| msg.account_keys.as_slice())
+            .unwrap_or_default();
+        let keys = static_keys
+            .iter()
+            .chain(&meta.loaded_writable_addresses)
+            .chain(&meta.loaded_readonly_addresses)
+            .map(|bytes| crate::instr::utils::read_pubkey_fast(bytes))
+            .collect();
+        Self { keys }
+    }
+
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&Pubkey> {
+        self.keys.get(index)
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[Pubkey] {
+        &self.keys
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// 交易的第一个签名者（fee payer）
+    #[inline]
+    pub fn signer(&self) -> Option<&Pubkey> {
+        self.keys.first()
+    }
+
+    /// 按指令账户索引取账户列表（超出范围的索引跳过）
+    #[inline]
+    pub fn instruction_accounts(&self, indices: &[u8]) -> Vec<Pubkey> {
+        build_account_pubkeys_with_cache(indices, &self.keys)
+    }
+
+    /// 指令账户访问器：`(outer_index, inner_index)` 定位指令，返回按指令内账户序号取 `Pubkey` 的闭包
+    ///
+    /// 与 `instr::utils::get_instruction_account_getter` 语义一致，账户不存在时返回默认值。
+    pub fn account_getter<'a>(
+        &'a self,
+        meta: &'a TransactionStatusMeta,
+        transaction: &'a Option<Transaction>,
+        index: &(i32, i32),
+    ) -> Option<impl Fn(usize) -> Pubkey + 'a> {
+        let accounts = if index.1 >= 0 {
+            let outer_idx = index.0 as u32;
+            meta.inner_instructions
+                .binary_search_by_key(&outer_idx, |i| i.index)
+                .ok()
+                .and_then(|pos| meta.inner_instructions.get(pos))
+                .or_else(|| meta.inner_instructions.iter().find(|i| i.index == outer_idx))?
+                .instructions
+                .get(index.1 as usize)?
+                .accounts
+                .as_slice()
+        } else {
+            transaction
+                .as_ref()?
+                .message
+                .as_ref()?
+                .instructions
+                .get(index.0 as usize)?
+                .accounts
+                .as_slice()
+        };
+
+        Some(move |acc_index: usize| -> Pubkey {
+            accounts
+                .get(acc_index)
+                .and_then(|&idx| self.keys.get(idx as usize))
+                .copied()
+                .unwrap_or_default()
+        })
+    }
+
+    /// 附带可写 / 签名标记的完整账户表，标记按消息头与 ALT 分组计算
+    pub fn to_accounts(
+        &self,
+        meta: &TransactionStatusMeta,
+        transaction: &Option<Transaction>,
+    ) -> TransactionAccounts {
+        let message = transaction.as_ref().and_then(|tx| tx.message.as_ref());
+        let (signers, readonly_signed, readonly_unsigned) = message
+            .and_then(|msg| msg.header.as_ref())
+            .map(|h| {
+                (
+                    h.num_required_signatures as usize,
+                    h.num_readonly_signed_accounts as usize,
+                    h.num_readonly_unsigned_accounts as usize,
+                )
+            })
+            .unwrap_or_default();
+        let static_len = message.map_or(0, |msg| msg.account_keys.len());
+        let loaded_writable_end = static_len + meta.loaded_writable_addresses.len();
+
+        let writable = (0..self.keys.len())
+            .map(|i| {
+                if i < signers {
+                    i < signers.saturating_sub(readonly_signed)
+                } else if i < static_len {
+                    i < static_len.saturating_sub(readonly_unsigned)
+                } else {
+                    i < loaded_writable_end
+                }
+            })
+            .collect();
+        let signer = (0..self.keys.len()).map(|i| i < signers).collect();
+        TransactionAccounts { keys: self.keys.clone(), writable, signer }
+    }
+}
+
+// ============================================================================
+// 交易账户缓存（Transaction Accounts Cache）
+// ============================================================================
+
+/// 交易的完整账户表及每个账户的可写 / 签名标记
+///
+/// 顺序与 [`TransactionKeys`] 相同（静态账户 → ALT 可写 → ALT 只读），ALT 账户已解析为实际地址，
+/// 可直接用于重建交易。标记按消息声明计算，不含运行时的降级（如被调用程序不可写）。
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionAccounts {
+    pub keys: Vec<Pubkey>,
+    pub writable: Vec<bool>,
+    pub signer: Vec<bool>,
+}
+
+impl TransactionAccounts {
+    #[inline]
+    pub fn is_writable(&self, index: usize) -> bool {
+        self.writable.get(index).copied().unwrap_or(false)
+    }
+
+    #[inline]
+    pub fn is_signer(&self, index: usize) -> bool {
+        self.signer.get(index).copied().unwrap_or(false)
+    }
+}
+
+/// 按签名索引的短期 [`TransactionAccounts`] 缓存
+///
+/// 容量固定，满后淘汰最早写入的交易，可另设 TTL；容量为 0 时不保存任何内容。
+#[derive(Debug, Default)]
+pub struct TransactionAccountsCache {
+    enabled: bool,
+    entries: Mutex<BoundedMap<Signature, Arc<TransactionAccounts>>>,
+}
+
+impl TransactionAccountsCache {
+    pub fn new(capacity: usize) -> Self {
+        Self::with_config(BoundedMapConfig::with_capacity(capacity))
+    }
+
+    /// 按容量 / TTL 配置创建，`capacity` 为 0 时关闭
+    pub fn with_config(config: BoundedMapConfig) -> Self {
+        Self { enabled: config.capacity > 0, entries: Mutex::new(BoundedMap::new(config)) }
+    }
+
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// 写入交易账户表，返回本次淘汰的条目数
+    pub fn insert(&self, signature: Signature, accounts: Arc<TransactionAccounts>) -> u64 {
+        if !self.enabled {
+            return 0;
+        }
+        let mut entries = self.entries.lock();
+        if entries.contains_key(&signature) {
+            return 0;
+        }
+        let before = entries.eviction_stats().total();
+        entries.insert(signature, accounts);
+        entries.eviction_stats().total() - before
+    }
+
+    pub fn get(&self, signature: &Signature) -> Option<Arc<TransactionAccounts>> {
+        self.entries.lock().get(signature).cloned()
+    }
+
+    pub fn eviction_stats(&self) -> EvictionStats {
+        self.entries.lock().eviction_stats()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+// ============================================================================
+// 签名 Base58 缓存（Signature Base58 Cache）
+// ============================================================================
+
+/// 直接映射缓存的槽数
+const SIGNATURE_CACHE_SLOTS: usize = 256;
+
+type SignatureCache = [Option<(Signature, Arc<str>)>; SIGNATURE_CACHE_SLOTS];
+
+thread_local! {
+    static THREAD_LOCAL_SIGNATURE_CACHE: RefCell<SignatureCache> =
+        const { RefCell::new([const { None }; SIGNATURE_CACHE_SLOTS]) };
+}
+
+/// 签名的 base58 字符串（线程局部缓存）
+///
+/// 导出 JSON 等场景中同一交易的多个事件共享签名，`Signature::to_string()` 每次都要做一次
+/// base58 编码（约 1μs）。这里按签名首字节直接映射缓存最近的编码结果，命中时只需一次引用计数。
+///
+/// # 示例
+/// ```rust
+/// use sol_parser_sdk::core::cache::signature_base58;
+/// use solana_sdk::signature::Signature;
+///
+/// let sig = Signature::from([7u8; 64]);
+/// assert_eq!(&*signature_base58(&sig), sig.to_string());
+/// ```
+#[inline]
+pub fn signature_base58(signature: &Signature) -> Arc<str> {
+    THREAD_LOCAL_SIGNATURE_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        let slot = &mut cache[signature.as_ref()[0] as usize % SIGNATURE_CACHE_SLOTS];
+        match slot {
+            Some((cached, encoded)) if cached == signature => Arc::clone(encoded),
+            _ => {
+                let encoded: Arc<str> = signature.to_string().into();
+                *slot = Some((*signature, Arc::clone(&encoded)));
+                encoded
+            }
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -202,4 +475,81 @@ mod tests {
         let result2 = build_account_pubkeys_with_cache(&[5u8, 6, 7], &all_accounts);
         assert_eq!(result2.len(), 3);
     }
+
+    #[test]
+    fn test_signature_base58_cache() {
+        let a = Signature::from([1u8; 64]);
+        let mut bytes = [1u8; 64];
+        bytes[63] = 2;
+        // 与 a 映射到同一个槽
+        let b = Signature::from(bytes);
+
+        let first = signature_base58(&a);
+        assert_eq!(&*first, a.to_string());
+        assert!(Arc::ptr_eq(&first, &signature_base58(&a)));
+
+        assert_eq!(&*signature_base58(&b), b.to_string());
+        assert_eq!(&*signature_base58(&a), a.to_string());
+    }
+
+    #[test]
+    fn test_transaction_keys() {
+        use yellowstone_grpc_proto::prelude::{
+            CompiledInstruction, InnerInstruction, InnerInstructions, Message,
+        };
+
+        let keys: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let transaction = Some(Transaction {
+            message: Some(Message {
+                account_keys: vec![keys[0].to_bytes().to_vec(), keys[1].to_bytes().to_vec()],
+                instructions: vec![CompiledInstruction {
+                    program_id_index: 1,
+                    accounts: vec![0, 3],
+                    data: vec![],
+                }],
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        let meta = TransactionStatusMeta {
+            loaded_writable_addresses: vec![keys[2].to_bytes().to_vec()],
+            loaded_readonly_addresses: vec![keys[3].to_bytes().to_vec()],
+            inner_instructions: vec![InnerInstructions {
+                index: 0,
+                instructions: vec![InnerInstruction {
+                    program_id_index: 2,
+                    accounts: vec![2, 9],
+                    ..Default::default()
+                }],
+            }],
+            ..Default::default()
+        };
+
+        let tx_keys = TransactionKeys::from_grpc(&meta, &transaction);
+        assert_eq!(tx_keys.as_slice(), keys.as_slice());
+        assert_eq!(tx_keys.signer(), Some(&keys[0]));
+        assert_eq!(tx_keys.instruction_accounts(&[3, 0, 7]), vec![keys[3], keys[0]]);
+
+        let outer = tx_keys.account_getter(&meta, &transaction, &(0, -1)).unwrap();
+        assert_eq!((outer(0), outer(1), outer(2)), (keys[0], keys[3], Pubkey::default()));
+        let inner = tx_keys.account_getter(&meta, &transaction, &(0, 0)).unwrap();
+        assert_eq!((inner(0), inner(1)), (keys[2], Pubkey::default()));
+        assert!(tx_keys.account_getter(&meta, &transaction, &(1, -1)).is_none());
+    }
+
+    #[test]
+    fn test_transaction_accounts_cache_eviction() {
+        let cache = TransactionAccountsCache::new(2);
+        let sigs: Vec<Signature> = (1..=3).map(|i| Signature::from([i; 64])).collect();
+        for sig in &sigs {
+            cache.insert(*sig, Arc::new(TransactionAccounts::default()));
+        }
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&sigs[0]).is_none());
+        assert!(cache.get(&sigs[2]).is_some());
+
+        let disabled = TransactionAccountsCache::new(0);
+        disabled.insert(sigs[0], Arc::new(TransactionAccounts::default()));
+        assert!(disabled.is_empty());
+    }
 }
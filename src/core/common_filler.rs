@@ -22,6 +22,10 @@ pub fn fill_data(
                     event.is_pump_pool = is_pump_pool;
                 }
             }
+            if event.instruction_args.is_none() {
+                event.instruction_args =
+                    pumpswap_trade_args(&event.metadata, meta, transaction, program_invokes);
+            }
         }
         DexEvent::PumpSwapSell(ref mut event) => {
             if let Some(invoke) = program_invokes
@@ -35,11 +39,67 @@ pub fn fill_data(
                     event.is_pump_pool = is_pump_pool;
                 }
             }
+            if event.instruction_args.is_none() {
+                event.instruction_args =
+                    pumpswap_trade_args(&event.metadata, meta, transaction, program_invokes);
+            }
+        }
+        DexEvent::PumpFunTrade(ref mut event)
+        | DexEvent::PumpFunBuy(ref mut event)
+        | DexEvent::PumpFunSell(ref mut event)
+        | DexEvent::PumpFunBuyExactSolIn(ref mut event)
+            if event.slippage_tolerance_bps.is_none() =>
+        {
+            if let Some(data) = event_instruction_data(
+                &event.metadata,
+                meta,
+                transaction,
+                program_invokes,
+                crate::grpc::program_ids::PUMPFUN_PROGRAM_ID,
+            ) {
+                crate::instr::pump::apply_trade_args(event, data);
+            }
         }
         _ => {} // 其他事件类型TODO
     }
 }
 
+/// 日志事件所在的 PumpSwap 指令参数
+fn pumpswap_trade_args(
+    metadata: &EventMetadata,
+    meta: &TransactionStatusMeta,
+    transaction: &Option<Transaction>,
+    program_invokes: &HashMap<&str, Vec<(i32, i32)>>,
+) -> Option<PumpSwapInstructionArgs> {
+    let data = event_instruction_data(
+        metadata,
+        meta,
+        transaction,
+        program_invokes,
+        crate::grpc::program_ids::PUMPSWAP_PROGRAM_ID,
+    )?;
+    crate::instr::pump_amm::parse_trade_args(data)
+}
+
+/// 日志事件所在的 `program_id` 指令数据
+///
+/// 按事件的 (outer_index, inner_index) 定位指令；未记录位置且交易只调用一次该程序时取该指令
+fn event_instruction_data<'a>(
+    metadata: &EventMetadata,
+    meta: &'a TransactionStatusMeta,
+    transaction: &'a Option<Transaction>,
+    program_invokes: &HashMap<&str, Vec<(i32, i32)>>,
+    program_id: &str,
+) -> Option<&'a [u8]> {
+    let invokes = program_invokes.get(program_id)?;
+    let position = (metadata.outer_index as i32, metadata.inner_index.map_or(-1, |i| i as i32));
+    let invoke = invokes
+        .iter()
+        .find(|invoke| **invoke == position)
+        .or_else(|| if invokes.len() == 1 { invokes.first() } else { None })?;
+    get_instruction_data(meta, transaction, invoke)
+}
+
 pub fn get_instruction_data<'a>(
     meta: &'a TransactionStatusMeta,
     transaction: &'a Option<Transaction>,
@@ -5,31 +5,253 @@
 // use prost_types::Timestamp;
 use borsh::BorshDeserialize;
 use serde::{Deserialize, Serialize};
+use crate::core::cache::TransactionAccounts;
 use solana_sdk::{pubkey::Pubkey, signature::Signature};
 
+/// 派生宏与同名 trait 一起导出，`use ...::DexEventFields` 即可同时使用两者
+pub use sol_parser_sdk_derive::DexEventFields;
+
 /// 基础元数据 - 所有事件共享的字段
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+///
+/// 所有字段（`Signature` + 整数）均为 `Copy`，因此元数据按值传递，
+/// 热路径上无需 `clone()`。
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
 pub struct EventMetadata {
     pub signature: Signature,
     pub slot: u64,
     pub tx_index: u64, // 交易在slot中的索引，参考solana-streamer
     pub block_time_us: i64,
     pub grpc_recv_us: i64,
+    /// 事件所属的外层指令索引
+    #[serde(default)]
+    pub outer_index: u32,
+    /// 事件所属的内层指令索引（在该外层指令的 inner instructions 中），外层指令本身为 None
+    #[serde(default)]
+    pub inner_index: Option<u32>,
+    /// 事件来自 `from_slot` 历史重放阶段，而非实时推送
+    #[serde(default)]
+    pub replayed: bool,
+}
+
+impl EventMetadata {
+    /// 签名的 base58 字符串，同一线程内重复调用复用缓存的编码结果
+    #[inline]
+    pub fn signature_base58(&self) -> std::sync::Arc<str> {
+        crate::core::cache::signature_base58(&self.signature)
+    }
+
+    /// 稳定的事件 ID：SHA-256(signature + outer_index + inner_index + event_type) 的前 16 字节
+    ///
+    /// 同一事件无论经由 gRPC、RPC 还是区块重放解析都得到相同的 ID，
+    /// 可作为下游入库的幂等键（文本键可使用 `format!("{:032x}", id)`）。
+    pub fn event_id(&self, event_type: &str) -> u128 {
+        let mut ctx = ring::digest::Context::new(&ring::digest::SHA256);
+        ctx.update(self.signature.as_ref());
+        ctx.update(&self.outer_index.to_le_bytes());
+        match self.inner_index {
+            Some(inner) => {
+                ctx.update(&[1]);
+                ctx.update(&inner.to_le_bytes());
+            }
+            None => ctx.update(&[0]),
+        }
+        ctx.update(event_type.as_bytes());
+
+        let digest = ctx.finish();
+        let mut id = [0u8; 16];
+        id.copy_from_slice(&digest.as_ref()[..16]);
+        u128::from_be_bytes(id)
+    }
+}
+
+/// 事件结构体的公共访问接口，由 `#[derive(DexEventFields)]` 生成
+///
+/// 同一结构体可能对应多个 `DexEvent` 变体（如 `PumpFunTradeEvent`），
+/// 因此 `EVENT_TYPE` 是结构体级别的类型名，变体名请用 [`DexEvent::event_type_name`]。
+pub trait DexEventFields {
+    /// 事件类型名，默认为去掉 `Event` 后缀的结构体名
+    const EVENT_TYPE: &'static str;
+    /// 字段名（按声明顺序）
+    const FIELD_NAMES: &'static [&'static str];
+
+    fn metadata(&self) -> &EventMetadata;
+    fn metadata_mut(&mut self) -> &mut EventMetadata;
+
+    fn event_type(&self) -> &'static str {
+        Self::EVENT_TYPE
+    }
 }
 
 /// Block Meta Event
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, DexEventFields)]
 pub struct BlockMetaEvent {
     pub metadata: EventMetadata,
+    /// 出块 leader（来自区块手续费奖励或注入的 leader schedule），未知时为默认值
+    #[serde(default)]
+    pub leader: Pubkey,
 }
 
-/// Bonk Pool Create Event
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// 单个 slot 的优先费统计（compute unit 价格，micro-lamports）
+#[derive(Debug, Clone, Serialize, Deserialize, Default, DexEventFields)]
+pub struct PriorityFeeStatsEvent {
+    pub metadata: EventMetadata,
+    /// 参与统计的交易数（未设置价格的交易按 0 计入）
+    pub count: u32,
+    pub p50: u64,
+    pub p90: u64,
+    pub max: u64,
+}
+
+/// 事件的原始数据，开启 `ClientConfig::retain_raw_data` 时紧跟在对应事件之后输出
+///
+/// `metadata` 与对应事件相同，`metadata.event_id(&event_type)` 即对应事件的 `event_id()`。
+/// 可与事件一起存储，解析器升级后用于核对或重新解析。
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
+pub struct RawDataEvent {
+    pub metadata: EventMetadata,
+    /// 对应事件的类型名（`DexEvent::event_type_name`）
+    pub event_type: String,
+    pub source: RawSource,
+}
+
+/// 事件的来源数据
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RawSource {
+    /// 日志行（`Program data: ...`）
+    Log(Box<str>),
+    /// 指令数据（含 discriminator）
+    Instruction(Box<[u8]>),
+}
+
+/// 交易的完整账户表，开启 `ClientConfig::emit_transaction_accounts` 时在该交易的事件之前输出
+///
+/// 跟单等场景可直接用它重建交易，无需再通过 RPC 获取。
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
+pub struct TransactionAccountsEvent {
+    pub metadata: EventMetadata,
+    pub accounts: TransactionAccounts,
+}
+
+/// 交易解析被截断的标记，位于该交易已解析的事件之后，见 `ClientConfig::parse_limits`
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
+pub struct ParseTruncatedEvent {
+    pub metadata: EventMetadata,
+    pub reason: TruncateReason,
+}
+
+/// 截断原因，同一交易触发多个原因时取最后一个变体（时间预算优先）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum TruncateReason {
+    /// 内层指令超出最大 CPI 深度
+    MaxDepth,
+    /// 指令数超出上限
+    MaxInstructions,
+    /// 超出解析时间预算
+    TimeBudget,
+}
+
+/// 被跟踪池子在新 slot 中的第一笔 swap，紧挨在该 swap 事件之前输出，供报价引擎立即重新定价
+///
+/// 见 `YellowstoneGrpc::with_first_swap_pools`，`metadata` 与对应 swap 事件相同。
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
+pub struct FirstSwapInSlotEvent {
+    pub metadata: EventMetadata,
+    pub pool: Pubkey,
+    /// 该池子上一次出现 swap 的 slot，首次出现时为 0
+    pub previous_slot: u64,
+}
+
+/// 发行事件的 URI 元数据（IPFS / Arweave JSON），由 `metadata::uri::UriFetcher` 异步抓取后输出
+///
+/// `metadata` 与对应的 `PumpFunCreate` / `BonkPoolCreate` 事件相同；Bonk 发行事件不含 mint，此时 `mint` 为默认值。
+#[derive(Debug, Clone, Default, Serialize, Deserialize, DexEventFields)]
+pub struct TokenMetadataResolvedEvent {
+    pub metadata: EventMetadata,
+    pub mint: Pubkey,
+    pub uri: String,
+    pub name: String,
+    pub symbol: String,
+    pub description: Option<String>,
+    pub image: Option<String>,
+    pub twitter: Option<String>,
+    pub telegram: Option<String>,
+    pub website: Option<String>,
+}
+
+impl TokenMetadataResolvedEvent {
+    /// 是否带有任一社交链接（推特 / 电报 / 网站）
+    pub fn has_socials(&self) -> bool {
+        self.twitter.is_some() || self.telegram.is_some() || self.website.is_some()
+    }
+}
+
+/// swap 数量与交易 token 余额变化不一致，位于对应 swap 事件之后（事件被丢弃时单独输出）
+///
+/// 见 `ClientConfig::amount_validation`，`metadata` 与对应 swap 事件相同。
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
+pub struct ValidationWarningEvent {
+    pub metadata: EventMetadata,
+    /// 被校验事件的类型名
+    pub event_type: String,
+    pub pool: Pubkey,
+    /// 偏差最大一侧的代币
+    pub mint: Pubkey,
+    /// 事件中的数量
+    pub expected: u64,
+    /// 该 mint 的 token 账户余额变化中最接近事件数量的值
+    pub observed: u64,
+    pub deviation_bps: u64,
+    /// 原事件是否已被丢弃
+    pub dropped: bool,
+}
+
+/// 各协议建池事件的统一形式，位于对应建池事件之后
+///
+/// 见 `ClientConfig::emit_pool_created` 与 [`crate::analytics::normalize_pool_created`]，
+/// `metadata` 与建池事件相同。
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
+pub struct NormalizedPoolCreatedEvent {
+    pub metadata: EventMetadata,
+    /// 建池事件的类型名（如 `PumpSwapCreatePool`）
+    pub source: String,
+    pub pool: Pubkey,
+    /// 代币对，顺序与协议内部一致（base/0/a/x 在前）
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    /// 建池者，协议事件不含时为默认值
+    pub creator: Pubkey,
+}
+
+/// 迁移事件（PumpFun → PumpSwap、Bonk → Raydium）与目标 DEX 建池事件的关联结果
+///
+/// 见 [`crate::analytics::MigrationLinker`]，`metadata` 与建池事件相同。
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
+pub struct MigrationCompletedEvent {
+    pub metadata: EventMetadata,
+    /// 迁移事件的类型名（`PumpFunMigrate` / `BonkMigrateAmm`）
+    pub source: String,
+    /// 迁移前的池子（PumpFun bonding curve / LaunchLab pool_state）
+    pub source_pool: Pubkey,
+    /// 迁移事件所在 slot
+    pub source_slot: u64,
+    /// 建池事件的类型名（如 `PumpSwapCreatePool`）
+    pub destination: String,
+    pub destination_pool: Pubkey,
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+}
+
+/// Bonk Pool Create Event - 基于 Raydium LaunchLab IDL `PoolCreateEvent`
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct BonkPoolCreateEvent {
     pub metadata: EventMetadata,
     pub base_mint_param: BaseMintParam,
     pub pool_state: Pubkey,
     pub creator: Pubkey,
+    pub config: Pubkey,
+    /// 以下来自指令账户，事件中不含
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -40,36 +262,92 @@ pub struct BaseMintParam {
     pub decimals: u8,
 }
 
-/// Bonk Trade Event
+/// Bonk Trade Event - 基于 Raydium LaunchLab IDL `TradeEvent`
+///
+/// 注意：该事件与 PumpFun `TradeEvent` 共享同一个 discriminator，
+/// 只能通过程序 ID 或数据长度（`TRADE_EVENT_SIZE`）区分。
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, DexEventFields)]
 pub struct BonkTradeEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
 
-    // === Borsh 序列化字段（从 inner instruction data 读取）===
-    pub pool_state: Pubkey, // 32 bytes
-    pub user: Pubkey,       // 32 bytes
-    pub amount_in: u64,     // 8 bytes
-    pub amount_out: u64,    // 8 bytes
-    pub is_buy: bool,       // 1 byte
+    // === IDL TradeEvent 事件字段（Borsh 序列化字段，按顺序）===
+    pub pool_state: Pubkey,
+    pub total_base_sell: u64,
+    pub virtual_base: u64,
+    pub virtual_quote: u64,
+    pub real_base_before: u64,
+    pub real_quote_before: u64,
+    pub real_base_after: u64,
+    pub real_quote_after: u64,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub protocol_fee: u64,
+    pub platform_fee: u64,
+    pub creator_fee: u64,
+    pub share_fee: u64,
+    pub trade_direction: TradeDirection,
+    pub pool_status: PoolStatus,
+    pub exact_in: bool,
 
-    // === 非 Borsh 字段（派生字段）===
+    // === 非 Borsh 字段（派生字段 / 指令账户填充）===
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
-    pub trade_direction: TradeDirection,
+    pub is_buy: bool,
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
-    pub exact_in: bool,
+    pub user: Pubkey,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+impl BonkTradeEvent {
+    /// IDL TradeEvent 序列化长度：pool_state(32) + 13 * u64 + trade_direction(1) + pool_status(1) + exact_in(1)
+    pub const TRADE_EVENT_SIZE: usize = 32 + 13 * 8 + 1 + 1 + 1;
+}
+
+#[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum TradeDirection {
     #[default]
     Buy,
     Sell,
 }
 
+impl TradeDirection {
+    #[inline]
+    pub fn from_u8(v: u8) -> Option<Self> {
+        match v {
+            0 => Some(Self::Buy),
+            1 => Some(Self::Sell),
+            _ => None,
+        }
+    }
+}
+
+/// Bonk (Raydium Launchpad) 池状态
+#[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum PoolStatus {
+    #[default]
+    Fund,
+    Migrate,
+    Trade,
+}
+
+impl PoolStatus {
+    #[inline]
+    pub fn from_u8(v: u8) -> Option<Self> {
+        match v {
+            0 => Some(Self::Fund),
+            1 => Some(Self::Migrate),
+            2 => Some(Self::Trade),
+            _ => None,
+        }
+    }
+}
+
 /// Bonk Migrate AMM Event
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// LaunchLab 迁移不产生日志事件，由 `migrate_to_amm` / `migrate_to_cpswap` 指令解析得到。
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct BonkMigrateAmmEvent {
     pub metadata: EventMetadata,
     pub old_pool: Pubkey,
@@ -83,7 +361,7 @@ pub struct BonkMigrateAmmEvent {
 /// 字段来源标记:
 /// - [EVENT]: 来自原始IDL事件定义，由程序日志直接解析获得
 /// - [INSTRUCTION]: 来自指令解析，用于补充事件缺失的上下文信息
-#[derive(Debug, Clone, Serialize, Deserialize, Default, BorshDeserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, BorshDeserialize, DexEventFields)]
 pub struct PumpFunTradeEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
@@ -117,10 +395,16 @@ pub struct PumpFunTradeEvent {
     /// Mayhem mode flag (new field from IDL update)
     pub mayhem_mode: bool,
 
-    // === Instruction parameter fields (reserved for future use, DO NOT delete) ===
-    // pub amount: u64,                     // buy/sell.args.amount
-    // pub max_sol_cost: u64,               // buy.args.maxSolCost
-    // pub min_sol_output: u64,             // sell.args.minSolOutput
+    // === 指令参数字段 (从外层指令数据填充，不在 Borsh 数据中) ===
+    #[borsh(skip)]
+    pub amount: u64, // buy/sell.args.amount，buy_exact_sol_in.args.minTokensOut
+    #[borsh(skip)]
+    pub max_sol_cost: u64, // buy.args.maxSolCost，buy_exact_sol_in.args.spendableSolIn
+    #[borsh(skip)]
+    pub min_sol_output: u64, // sell.args.minSolOutput
+    /// 指令允许的滑点（相对成交数量的基点），未取得指令参数时为 None
+    #[borsh(skip)]
+    pub slippage_tolerance_bps: Option<u64>,
 
     // === 指令账户字段 (从指令账户填充，不在 Borsh 数据中) ===
     // pub global: Pubkey,                  // 0
@@ -136,7 +420,7 @@ pub struct PumpFunTradeEvent {
 }
 
 /// PumpFun Migrate Event
-#[derive(Debug, Clone, Serialize, Deserialize, Default, BorshDeserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, BorshDeserialize, DexEventFields)]
 pub struct PumpFunMigrateEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
@@ -166,7 +450,7 @@ pub struct PumpFunMigrateEvent {
 }
 
 /// PumpFun Create Token Event - Based on IDL CreateEvent definition
-#[derive(Debug, Clone, Serialize, Deserialize, Default, BorshDeserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, BorshDeserialize, DexEventFields)]
 pub struct PumpFunCreateTokenEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
@@ -192,7 +476,7 @@ pub struct PumpFunCreateTokenEvent {
 
 /// PumpSwap Trade Event - Unified trade event from IDL TradeEvent
 /// Produced by: buy, sell, buy_exact_sol_in instructions
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, DexEventFields)]
 pub struct PumpSwapTradeEvent {
     pub metadata: EventMetadata,
     // === IDL TradeEvent fields ===
@@ -220,8 +504,19 @@ pub struct PumpSwapTradeEvent {
     pub ix_name: String, // "buy" | "sell" | "buy_exact_sol_in"
 }
 
+/// PumpSwap buy / sell 指令参数
+///
+/// 事件中的 `base_amount_out` / `max_quote_amount_in` 等字段对 buy_exact_quote_in 含义不同，
+/// 跟单需要按原始指令区分
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PumpSwapInstructionArgs {
+    Buy { base_amount_out: u64, max_quote_amount_in: u64 },
+    BuyExactQuoteIn { spendable_quote_in: u64, min_base_amount_out: u64 },
+    Sell { base_amount_in: u64, min_quote_amount_out: u64 },
+}
+
 /// PumpSwap Buy Event
-#[derive(Debug, Clone, Serialize, Deserialize, Default, BorshDeserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, BorshDeserialize, DexEventFields)]
 pub struct PumpSwapBuyEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
@@ -261,6 +556,9 @@ pub struct PumpSwapBuyEvent {
     // === 额外的信息 ===
     #[borsh(skip)]
     pub is_pump_pool: bool,
+    /// 外层指令参数（从指令数据解析，日志中不含）
+    #[borsh(skip)]
+    pub instruction_args: Option<PumpSwapInstructionArgs>,
 
     // === 额外账户信息 (from instruction accounts, not event data) ===
     #[borsh(skip)]
@@ -282,7 +580,7 @@ pub struct PumpSwapBuyEvent {
 }
 
 /// PumpSwap Sell Event
-#[derive(Debug, Clone, Serialize, Deserialize, Default, BorshDeserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, BorshDeserialize, DexEventFields)]
 pub struct PumpSwapSellEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
@@ -313,6 +611,9 @@ pub struct PumpSwapSellEvent {
     // === 额外的信息 ===
     #[borsh(skip)]
     pub is_pump_pool: bool,
+    /// 外层指令参数（从指令数据解析，日志中不含）
+    #[borsh(skip)]
+    pub instruction_args: Option<PumpSwapInstructionArgs>,
 
     // === 额外账户信息 (from instruction accounts, not event data) ===
     #[borsh(skip)]
@@ -334,7 +635,7 @@ pub struct PumpSwapSellEvent {
 }
 
 /// PumpSwap Create Pool Event
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, DexEventFields)]
 pub struct PumpSwapCreatePoolEvent {
     pub metadata: EventMetadata,
     pub timestamp: i64,
@@ -394,7 +695,7 @@ pub struct PumpSwapPoolCreated {
 // }
 
 /// PumpSwap Liquidity Added Event - Instruction parsing version
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, DexEventFields)]
 pub struct PumpSwapLiquidityAdded {
     pub metadata: EventMetadata,
     pub timestamp: i64,
@@ -416,7 +717,7 @@ pub struct PumpSwapLiquidityAdded {
 }
 
 /// PumpSwap Liquidity Removed Event - Instruction parsing version
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, DexEventFields)]
 pub struct PumpSwapLiquidityRemoved {
     pub metadata: EventMetadata,
     pub timestamp: i64,
@@ -437,14 +738,64 @@ pub struct PumpSwapLiquidityRemoved {
     pub user_pool_token_account: Pubkey,
 }
 
-/// PumpSwap Pool Updated Event - 指令解析版本
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// PumpSwap Pool Updated Event - 池级别的 coin creator 变更
+/// Produced by: set_coin_creator (SetBondingCurveCoinCreatorEvent / SetMetaplexCoinCreatorEvent),
+/// admin_set_coin_creator (AdminSetCoinCreatorEvent)
+#[derive(Debug, Clone, Serialize, Deserialize, Default, DexEventFields)]
 pub struct PumpSwapPoolUpdated {
     pub metadata: EventMetadata,
-    pub pool_account: Pubkey,
+    pub timestamp: i64,
+    pub pool: Pubkey,
+    pub base_mint: Pubkey,
+    /// 仅 admin_set_coin_creator 事件包含旧值，其余为 default
+    pub old_coin_creator: Pubkey,
+    pub new_coin_creator: Pubkey,
+    /// admin_set_coin_creator: admin_set_coin_creator_authority;
+    /// set_coin_creator: bonding_curve 或 metadata 账户（coin creator 的来源）
     pub authority: Pubkey,
+    pub ix_name: String, // "set_coin_creator" | "admin_set_coin_creator"
+}
+
+/// PumpSwap Config Updated Event - GlobalConfig 管理变更
+/// Produced by: update_fee_config (UpdateFeeConfigEvent), disable (DisableEvent)
+#[derive(Debug, Clone, Serialize, Deserialize, Default, DexEventFields)]
+pub struct PumpSwapConfigUpdated {
+    pub metadata: EventMetadata,
+    pub timestamp: i64,
     pub admin: Pubkey,
-    pub new_fee_rate: u64,
+
+    // === update_fee_config 字段（disable 事件中为 0 / default）===
+    pub lp_fee_basis_points: u64,
+    pub protocol_fee_basis_points: u64,
+    pub protocol_fee_recipients: [Pubkey; 8],
+    pub coin_creator_fee_basis_points: u64,
+    pub admin_set_coin_creator_authority: Pubkey,
+
+    // === disable 字段（update_fee_config 事件中为 false）===
+    pub disable_create_pool: bool,
+    pub disable_deposit: bool,
+    pub disable_withdraw: bool,
+    pub disable_buy: bool,
+    pub disable_sell: bool,
+
+    pub ix_name: String, // "update_fee_config" | "disable"
+}
+
+/// PumpSwap Creator Fee Claimed Event - coin creator 领取累计的创作者手续费
+/// Produced by: collect_coin_creator_fee (CollectCoinCreatorFeeEvent)
+///
+/// creator vault 由该 creator 的所有池子共享，指令和事件都不涉及具体池子，因此没有 pool 字段。
+#[derive(Debug, Clone, Serialize, Deserialize, Default, DexEventFields)]
+pub struct PumpSwapCreatorFeeClaimed {
+    pub metadata: EventMetadata,
+    pub timestamp: i64,
+    pub creator: Pubkey,
+    /// 领取的 quote 数量
+    pub amount: u64,
+    pub creator_vault_ata: Pubkey,
+    pub creator_token_account: Pubkey,
+    /// 来自指令账户 0，仅外层指令可得
+    pub quote_mint: Pubkey,
 }
 
 /// PumpSwap Fees Claimed Event - 指令解析版本
@@ -479,7 +830,7 @@ pub struct PumpSwapWithdrawEvent {
 
 /// Raydium CPMM Swap Event (基于IDL SwapEvent + swapBaseInput指令定义)
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct RaydiumCpmmSwapEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -519,7 +870,7 @@ pub struct RaydiumCpmmSwapEvent {
 
 /// Raydium CPMM Deposit Event
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct RaydiumCpmmDepositEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -536,18 +887,29 @@ pub struct RaydiumCpmmDepositEvent {
 }
 
 /// Raydium CPMM Initialize Event
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// CPMM 建池不发出日志事件，由 `initialize` / `initialize_with_permission` 指令解析
+#[derive(Debug, Clone, Serialize, Deserialize, Default, DexEventFields)]
 pub struct RaydiumCpmmInitializeEvent {
     pub metadata: EventMetadata,
     pub pool: Pubkey,
     pub creator: Pubkey,
     pub init_amount0: u64,
     pub init_amount1: u64,
+    pub open_time: u64,
+
+    // === 指令账户字段 ===
+    pub amm_config: Pubkey,
+    pub token_0_mint: Pubkey,
+    pub token_1_mint: Pubkey,
+    pub lp_mint: Pubkey,
+    pub token_0_vault: Pubkey,
+    pub token_1_vault: Pubkey,
 }
 
 /// Raydium CPMM Withdraw Event
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct RaydiumCpmmWithdrawEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -565,7 +927,7 @@ pub struct RaydiumCpmmWithdrawEvent {
 
 /// Raydium CLMM Swap Event (基于IDL SwapEvent + swap指令定义)
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct RaydiumClmmSwapEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -600,7 +962,7 @@ pub struct RaydiumClmmSwapEvent {
 }
 
 /// Raydium CLMM Close Position Event
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct RaydiumClmmClosePositionEvent {
     pub metadata: EventMetadata,
     pub pool: Pubkey,
@@ -610,7 +972,7 @@ pub struct RaydiumClmmClosePositionEvent {
 
 /// Raydium CLMM Decrease Liquidity Event
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct RaydiumClmmDecreaseLiquidityEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -629,7 +991,7 @@ pub struct RaydiumClmmDecreaseLiquidityEvent {
 
 /// Raydium CLMM Collect Fee Event
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct RaydiumClmmCollectFeeEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -641,9 +1003,50 @@ pub struct RaydiumClmmCollectFeeEvent {
     pub amount_1: u64,
 }
 
+/// Raydium CLMM Initialize Reward Event（`initialize_reward` 指令，为池子开启一档奖励）
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
+pub struct RaydiumClmmInitializeRewardEvent {
+    pub metadata: EventMetadata,
+    pub pool_state: Pubkey,
+    pub reward_funder: Pubkey,
+    pub reward_token_mint: Pubkey,
+    pub reward_token_vault: Pubkey,
+    pub open_time: u64,
+    pub end_time: u64,
+    /// 每秒奖励数量（Q64.64 定点数）
+    pub emissions_per_second_x64: u128,
+}
+
+/// Raydium CLMM Collect Reward Event（`collect_remaining_rewards` 指令，奖励结束后取回剩余奖励）
+///
+/// 取回数量不在指令数据中，需从随后的 token 转账获取。
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
+pub struct RaydiumClmmCollectRewardEvent {
+    pub metadata: EventMetadata,
+    pub pool_state: Pubkey,
+    pub reward_funder: Pubkey,
+    pub reward_token_vault: Pubkey,
+    pub reward_token_mint: Pubkey,
+    pub reward_index: u8,
+}
+
+/// Raydium CLMM Set Reward Params Event（`set_reward_params` 指令，调整奖励速率或延长周期）
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
+pub struct RaydiumClmmSetRewardParamsEvent {
+    pub metadata: EventMetadata,
+    pub pool_state: Pubkey,
+    pub authority: Pubkey,
+    pub amm_config: Pubkey,
+    pub reward_index: u8,
+    /// 每秒奖励数量（Q64.64 定点数）
+    pub emissions_per_second_x64: u128,
+    pub open_time: u64,
+    pub end_time: u64,
+}
+
 /// Raydium CLMM Create Pool Event
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct RaydiumClmmCreatePoolEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -665,7 +1068,7 @@ pub struct RaydiumClmmCreatePoolEvent {
 
 /// Raydium CLMM Increase Liquidity Event
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct RaydiumClmmIncreaseLiquidityEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -683,7 +1086,7 @@ pub struct RaydiumClmmIncreaseLiquidityEvent {
 }
 
 /// Raydium CLMM Open Position with Token Extension NFT Event
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct RaydiumClmmOpenPositionWithTokenExtNftEvent {
     pub metadata: EventMetadata,
     pub pool: Pubkey,
@@ -695,7 +1098,7 @@ pub struct RaydiumClmmOpenPositionWithTokenExtNftEvent {
 }
 
 /// Raydium CLMM Open Position Event
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct RaydiumClmmOpenPositionEvent {
     pub metadata: EventMetadata,
     pub pool: Pubkey,
@@ -747,7 +1150,7 @@ pub struct RaydiumAmmWithdrawPnlEvent {
 
 /// Raydium AMM V4 Swap Event
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct RaydiumAmmV4SwapEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -800,7 +1203,7 @@ pub struct RaydiumAmmV4SwapEvent {
 
 /// Raydium AMM V4 Deposit Event
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct RaydiumAmmV4DepositEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -842,7 +1245,7 @@ pub struct RaydiumAmmV4DepositEvent {
 }
 
 /// Raydium AMM V4 Initialize2 Event
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct RaydiumAmmV4Initialize2Event {
     pub metadata: EventMetadata,
     pub nonce: u8,
@@ -875,7 +1278,7 @@ pub struct RaydiumAmmV4Initialize2Event {
 
 /// Raydium AMM V4 Withdraw Event
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct RaydiumAmmV4WithdrawEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -930,7 +1333,7 @@ pub struct RaydiumAmmV4WithdrawEvent {
 }
 
 /// Raydium AMM V4 Withdraw PnL Event
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct RaydiumAmmV4WithdrawPnlEvent {
     pub metadata: EventMetadata,
 
@@ -1085,7 +1488,7 @@ pub struct BonkPlatformConfig {
 }
 
 /// PumpSwap Global Config Account Event
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, DexEventFields)]
 pub struct PumpSwapGlobalConfigAccountEvent {
     pub metadata: EventMetadata,
     pub pubkey: Pubkey,
@@ -1112,7 +1515,7 @@ pub struct PumpSwapGlobalConfig {
 }
 
 /// PumpSwap Pool Account Event
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, DexEventFields)]
 pub struct PumpSwapPoolAccountEvent {
     pub metadata: EventMetadata,
     pub pubkey: Pubkey,
@@ -1188,14 +1591,18 @@ pub struct PumpFunGlobal {
 }
 
 /// Raydium AMM V4 Info Account Event
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, DexEventFields)]
 pub struct RaydiumAmmAmmInfoAccountEvent {
     pub metadata: EventMetadata,
     pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
     pub amm_info: RaydiumAmmInfo,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct RaydiumAmmInfo {
     pub status: u64,
     pub nonce: u64,
@@ -1213,6 +1620,77 @@ pub struct RaydiumAmmInfo {
     pub min_price_multiplier: u64,
     pub max_price_multiplier: u64,
     pub sys_decimal_value: u64,
+    pub trade_fee_numerator: u64,
+    pub trade_fee_denominator: u64,
+    pub swap_fee_numerator: u64,
+    pub swap_fee_denominator: u64,
+    /// 待提取的协议收益，计算储备时需从金库余额中扣除
+    pub need_take_pnl_coin: u64,
+    pub need_take_pnl_pc: u64,
+    pub pool_open_time: u64,
+    pub coin_vault: Pubkey,
+    pub pc_vault: Pubkey,
+    pub coin_vault_mint: Pubkey,
+    pub pc_vault_mint: Pubkey,
+    pub lp_mint: Pubkey,
+    pub open_orders: Pubkey,
+    pub market: Pubkey,
+    pub market_program: Pubkey,
+    pub target_orders: Pubkey,
+    pub amm_owner: Pubkey,
+    pub lp_amount: u64,
+}
+
+/// Raydium AMM V4 Target Orders Account Event
+#[derive(Debug, Clone, Serialize, Deserialize, Default, DexEventFields)]
+pub struct RaydiumAmmTargetOrdersAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub target_orders: RaydiumAmmTargetOrders,
+}
+
+/// AMM V4 做市计划的汇总字段（挂单明细未解码）
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct RaydiumAmmTargetOrders {
+    /// 所属 AMM
+    pub amm: Pubkey,
+    pub target_x: u128,
+    pub target_y: u128,
+    pub placed_x: u128,
+    pub placed_y: u128,
+    pub calc_pnl_x: u128,
+    pub calc_pnl_y: u128,
+    pub valid_buy_order_num: u64,
+    pub valid_sell_order_num: u64,
+}
+
+/// Serum / OpenBook OpenOrders Account Event（AMM V4 池子挂单占用的资金）
+#[derive(Debug, Clone, Serialize, Deserialize, Default, DexEventFields)]
+pub struct SerumOpenOrdersAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub open_orders: SerumOpenOrders,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SerumOpenOrders {
+    pub market: Pubkey,
+    /// OpenOrders 所有者，AMM V4 池子为池子的 authority
+    pub open_orders_owner: Pubkey,
+    pub native_coin_free: u64,
+    /// 含挂单锁定的 coin 总额
+    pub native_coin_total: u64,
+    pub native_pc_free: u64,
+    pub native_pc_total: u64,
+    pub referrer_rebates_accrued: u64,
 }
 
 /// Raydium CLMM AMM Config Account Event
@@ -1352,7 +1830,7 @@ pub struct RaydiumCpmmPoolState {
 }
 
 /// Token Info Event
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, DexEventFields)]
 pub struct TokenInfoEvent {
     pub metadata: EventMetadata,
     pub pubkey: Pubkey,
@@ -1365,7 +1843,7 @@ pub struct TokenInfoEvent {
 }
 
 /// Token Account Event
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, DexEventFields)]
 pub struct TokenAccountEvent {
     pub metadata: EventMetadata,
     pub pubkey: Pubkey,
@@ -1375,10 +1853,22 @@ pub struct TokenAccountEvent {
     pub rent_epoch: u64,
     pub amount: Option<u64>,
     pub token_owner: Pubkey,
+    #[serde(default)]
+    pub mint: Pubkey,
+}
+
+/// 账户关闭事件：账户更新的 lamports 为 0（账户已被关闭回收），供状态跟踪器移除对应条目
+///
+/// `owner` 为更新中携带的所有者，关闭后通常已被重置为 System Program。
+#[derive(Debug, Clone, Serialize, Deserialize, Default, DexEventFields)]
+pub struct AccountClosedEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub owner: Pubkey,
 }
 
 /// Nonce Account Event
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, DexEventFields)]
 pub struct NonceAccountEvent {
     pub metadata: EventMetadata,
     pub pubkey: Pubkey,
@@ -1393,7 +1883,7 @@ pub struct NonceAccountEvent {
 // ====================== Orca Whirlpool Events ======================
 
 /// Orca Whirlpool Swap Event (基于 TradedEvent，不是 SwapEvent)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
 pub struct OrcaWhirlpoolSwapEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
@@ -1437,7 +1927,7 @@ pub struct OrcaWhirlpoolSwapEvent {
 
 /// Orca Whirlpool Liquidity Increased Event
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct OrcaWhirlpoolLiquidityIncreasedEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -1463,7 +1953,7 @@ pub struct OrcaWhirlpoolLiquidityIncreasedEvent {
 
 /// Orca Whirlpool Liquidity Decreased Event
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct OrcaWhirlpoolLiquidityDecreasedEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -1488,7 +1978,7 @@ pub struct OrcaWhirlpoolLiquidityDecreasedEvent {
 }
 
 /// Orca Whirlpool Pool Initialized Event
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct OrcaWhirlpoolPoolInitializedEvent {
     pub metadata: EventMetadata,
     pub whirlpool: Pubkey,
@@ -1506,7 +1996,7 @@ pub struct OrcaWhirlpoolPoolInitializedEvent {
 // ====================== Meteora Pools Events ======================
 
 /// Meteora Pools Swap Event
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct MeteoraPoolsSwapEvent {
     pub metadata: EventMetadata,
     pub in_amount: u64,
@@ -1514,28 +2004,31 @@ pub struct MeteoraPoolsSwapEvent {
     pub trade_fee: u64,
     pub admin_fee: u64, // IDL字段名: adminFee
     pub host_fee: u64,
+    pub pool: Pubkey, // 来自指令账户 0
 }
 
 /// Meteora Pools Add Liquidity Event
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct MeteoraPoolsAddLiquidityEvent {
     pub metadata: EventMetadata,
     pub lp_mint_amount: u64,
     pub token_a_amount: u64,
     pub token_b_amount: u64,
+    pub pool: Pubkey, // 来自指令账户 0
 }
 
 /// Meteora Pools Remove Liquidity Event
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct MeteoraPoolsRemoveLiquidityEvent {
     pub metadata: EventMetadata,
     pub lp_unmint_amount: u64,
     pub token_a_out_amount: u64,
     pub token_b_out_amount: u64,
+    pub pool: Pubkey, // 来自指令账户 0
 }
 
 /// Meteora Pools Bootstrap Liquidity Event
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct MeteoraPoolsBootstrapLiquidityEvent {
     pub metadata: EventMetadata,
     pub lp_mint_amount: u64,
@@ -1545,7 +2038,7 @@ pub struct MeteoraPoolsBootstrapLiquidityEvent {
 }
 
 /// Meteora Pools Pool Created Event
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct MeteoraPoolsPoolCreatedEvent {
     pub metadata: EventMetadata,
     pub lp_mint: Pubkey,
@@ -1556,7 +2049,7 @@ pub struct MeteoraPoolsPoolCreatedEvent {
 }
 
 /// Meteora Pools Set Pool Fees Event
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct MeteoraPoolsSetPoolFeesEvent {
     pub metadata: EventMetadata,
     pub trade_fee_numerator: u64,
@@ -1570,7 +2063,7 @@ pub struct MeteoraPoolsSetPoolFeesEvent {
 
 /// Meteora DAMM V2 Swap Event
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, DexEventFields)]
 pub struct MeteoraDammV2SwapEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -1618,7 +2111,7 @@ pub struct MeteoraDammV2SwapEvent {
 
 /// Meteora DAMM V2 Add Liquidity Event
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct MeteoraDammV2AddLiquidityEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -1645,7 +2138,7 @@ pub struct MeteoraDammV2AddLiquidityEvent {
 
 /// Meteora DAMM V2 Remove Liquidity Event
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct MeteoraDammV2RemoveLiquidityEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -1668,7 +2161,7 @@ pub struct MeteoraDammV2RemoveLiquidityEvent {
 
 /// Meteora DAMM V2 Create Position Event
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct MeteoraDammV2CreatePositionEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -1682,7 +2175,7 @@ pub struct MeteoraDammV2CreatePositionEvent {
 
 /// Meteora DAMM V2 Close Position Event
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct MeteoraDammV2ClosePositionEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -1696,7 +2189,7 @@ pub struct MeteoraDammV2ClosePositionEvent {
 
 /// Meteora DLMM Swap Event
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct MeteoraDlmmSwapEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -1717,7 +2210,7 @@ pub struct MeteoraDlmmSwapEvent {
 
 /// Meteora DLMM Add Liquidity Event
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct MeteoraDlmmAddLiquidityEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -1732,7 +2225,7 @@ pub struct MeteoraDlmmAddLiquidityEvent {
 
 /// Meteora DLMM Remove Liquidity Event
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct MeteoraDlmmRemoveLiquidityEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -1747,7 +2240,7 @@ pub struct MeteoraDlmmRemoveLiquidityEvent {
 
 /// Meteora DLMM Initialize Pool Event
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct MeteoraDlmmInitializePoolEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -1757,11 +2250,17 @@ pub struct MeteoraDlmmInitializePoolEvent {
     pub creator: Pubkey,    // 32 bytes
     pub active_bin_id: i32, // 4 bytes
     pub bin_step: u16,      // 2 bytes
+
+    // === 指令账户字段 ===
+    #[cfg_attr(feature = "parse-borsh", borsh(skip))]
+    pub token_x_mint: Pubkey,
+    #[cfg_attr(feature = "parse-borsh", borsh(skip))]
+    pub token_y_mint: Pubkey,
 }
 
 /// Meteora DLMM Initialize Bin Array Event
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct MeteoraDlmmInitializeBinArrayEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -1774,7 +2273,7 @@ pub struct MeteoraDlmmInitializeBinArrayEvent {
 
 /// Meteora DLMM Create Position Event
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct MeteoraDlmmCreatePositionEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -1789,7 +2288,7 @@ pub struct MeteoraDlmmCreatePositionEvent {
 
 /// Meteora DLMM Close Position Event
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct MeteoraDlmmClosePositionEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -1802,7 +2301,7 @@ pub struct MeteoraDlmmClosePositionEvent {
 
 /// Meteora DLMM Claim Fee Event
 #[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
 pub struct MeteoraDlmmClaimFeeEvent {
     #[cfg_attr(feature = "parse-borsh", borsh(skip))]
     pub metadata: EventMetadata,
@@ -1815,10 +2314,76 @@ pub struct MeteoraDlmmClaimFeeEvent {
     pub fee_y: u64,       // 8 bytes
 }
 
+/// Meteora DLMM Claim Reward Event
+#[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
+pub struct MeteoraDlmmClaimRewardEvent {
+    #[cfg_attr(feature = "parse-borsh", borsh(skip))]
+    pub metadata: EventMetadata,
+
+    // === Borsh 序列化字段（从 inner instruction data 读取）===
+    pub pool: Pubkey,       // 32 bytes
+    pub position: Pubkey,   // 32 bytes
+    pub owner: Pubkey,      // 32 bytes
+    pub reward_index: u64,  // 8 bytes
+    pub total_reward: u64,  // 8 bytes
+
+    // === 指令账户字段（从 instruction accounts 获取）===
+    #[cfg_attr(feature = "parse-borsh", borsh(skip))]
+    pub reward_mint: Pubkey, // 来自指令账户 6
+}
+
+/// Meteora DLMM Fund Reward Event
+#[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
+pub struct MeteoraDlmmFundRewardEvent {
+    #[cfg_attr(feature = "parse-borsh", borsh(skip))]
+    pub metadata: EventMetadata,
+
+    // === Borsh 序列化字段（从 inner instruction data 读取）===
+    pub pool: Pubkey,      // 32 bytes
+    pub funder: Pubkey,    // 32 bytes
+    pub reward_index: u64, // 8 bytes
+    pub amount: u64,       // 8 bytes
+
+    // === 指令账户字段（从 instruction accounts 获取）===
+    #[cfg_attr(feature = "parse-borsh", borsh(skip))]
+    pub reward_mint: Pubkey, // 来自指令账户 2
+}
+
+/// Meteora DLMM Update Position Operator Event
+#[cfg_attr(feature = "parse-borsh", derive(BorshDeserialize))]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
+pub struct MeteoraDlmmUpdatePositionOperatorEvent {
+    #[cfg_attr(feature = "parse-borsh", borsh(skip))]
+    pub metadata: EventMetadata,
+
+    // === Borsh 序列化字段（从 inner instruction data 读取）===
+    pub position: Pubkey,     // 32 bytes
+    pub old_operator: Pubkey, // 32 bytes
+    pub new_operator: Pubkey, // 32 bytes
+
+    // === 指令账户字段（从 instruction accounts 获取）===
+    #[cfg_attr(feature = "parse-borsh", borsh(skip))]
+    pub owner: Pubkey, // 来自指令账户 1
+}
+
+/// Meteora DLMM Initialize Bin Array Bitmap Extension Event
+///
+/// 程序不为该操作发出日志事件，由 `initialize_bin_array_bitmap_extension` 指令解析得到
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
+pub struct MeteoraDlmmInitializeBinArrayBitmapExtensionEvent {
+    pub metadata: EventMetadata,
+    pub pool: Pubkey,
+    pub bin_array_bitmap_extension: Pubkey,
+    pub funder: Pubkey,
+}
+
 // ====================== 统一的 DEX 事件枚举 ======================
 
 /// 统一的 DEX 事件枚举 - 参考 sol-dex-shreds 的做法
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, DexEventFields)]
+#[dex_event(default_metadata = DEFAULT_METADATA)]
 pub enum DexEvent {
     // PumpFun 事件
     PumpFunCreate(PumpFunCreateTokenEvent),  // - 已对接
@@ -1835,6 +2400,9 @@ pub enum DexEvent {
     PumpSwapCreatePool(PumpSwapCreatePoolEvent), // - 已对接
     PumpSwapLiquidityAdded(PumpSwapLiquidityAdded), // - 已对接
     PumpSwapLiquidityRemoved(PumpSwapLiquidityRemoved), // - 已对接
+    PumpSwapPoolUpdated(PumpSwapPoolUpdated),           // - 已对接 (coin creator 变更)
+    PumpSwapConfigUpdated(PumpSwapConfigUpdated),       // - 已对接 (费率配置 / disable)
+    PumpSwapCreatorFeeClaimed(PumpSwapCreatorFeeClaimed), // - 已对接 (创作者手续费领取)
 
     // Meteora DAMM V2 事件
     MeteoraDammV2Swap(MeteoraDammV2SwapEvent), // - 已对接
@@ -1857,6 +2425,9 @@ pub enum DexEvent {
     RaydiumClmmIncreaseLiquidity(RaydiumClmmIncreaseLiquidityEvent),
     RaydiumClmmDecreaseLiquidity(RaydiumClmmDecreaseLiquidityEvent),
     RaydiumClmmCollectFee(RaydiumClmmCollectFeeEvent),
+    RaydiumClmmInitializeReward(RaydiumClmmInitializeRewardEvent),
+    RaydiumClmmCollectReward(RaydiumClmmCollectRewardEvent),
+    RaydiumClmmSetRewardParams(RaydiumClmmSetRewardParamsEvent),
 
     // Raydium CPMM 事件
     RaydiumCpmmSwap(RaydiumCpmmSwapEvent),
@@ -1894,6 +2465,10 @@ pub enum DexEvent {
     MeteoraDlmmCreatePosition(MeteoraDlmmCreatePositionEvent),
     MeteoraDlmmClosePosition(MeteoraDlmmClosePositionEvent),
     MeteoraDlmmClaimFee(MeteoraDlmmClaimFeeEvent),
+    MeteoraDlmmClaimReward(MeteoraDlmmClaimRewardEvent),
+    MeteoraDlmmFundReward(MeteoraDlmmFundRewardEvent),
+    MeteoraDlmmUpdatePositionOperator(MeteoraDlmmUpdatePositionOperatorEvent),
+    MeteoraDlmmInitializeBinArrayBitmapExtension(MeteoraDlmmInitializeBinArrayBitmapExtensionEvent),
 
     // 账户事件
     TokenInfo(TokenInfoEvent),       // - 已对接
@@ -1901,11 +2476,27 @@ pub enum DexEvent {
     NonceAccount(NonceAccountEvent), // - 已对接
     PumpSwapGlobalConfigAccount(PumpSwapGlobalConfigAccountEvent), // - 已对接
     PumpSwapPoolAccount(PumpSwapPoolAccountEvent), // - 已对接
+    AccountClosed(AccountClosedEvent),
+    RaydiumAmmV4AmmInfoAccount(RaydiumAmmAmmInfoAccountEvent),
+    RaydiumAmmV4TargetOrdersAccount(RaydiumAmmTargetOrdersAccountEvent),
+    SerumOpenOrdersAccount(SerumOpenOrdersAccountEvent),
 
     // 区块元数据事件
     BlockMeta(BlockMetaEvent),
+    PriorityFeeStats(PriorityFeeStatsEvent),
+
+    // 原始数据
+    RawData(RawDataEvent),
+    TransactionAccounts(TransactionAccountsEvent),
+    ParseTruncated(ParseTruncatedEvent),
+    FirstSwapInSlot(FirstSwapInSlotEvent),
+    ValidationWarning(ValidationWarningEvent),
+    NormalizedPoolCreated(NormalizedPoolCreatedEvent),
+    MigrationCompleted(MigrationCompletedEvent),
+    TokenMetadataResolved(TokenMetadataResolvedEvent),
 
     // 错误事件
+    #[dex_event(skip)]
     Error(String),
 }
 
@@ -1917,99 +2508,323 @@ static DEFAULT_METADATA: Lazy<EventMetadata> = Lazy::new(|| EventMetadata {
     tx_index: 0,
     block_time_us: 0,
     grpc_recv_us: 0,
+    ..Default::default()
 });
 
 impl DexEvent {
-    /// 获取事件的元数据
-    pub fn metadata(&self) -> &EventMetadata {
-        match self {
-            // PumpFun 事件
-            DexEvent::PumpFunCreate(e) => &e.metadata,
-            DexEvent::PumpFunTrade(e) => &e.metadata,
-            DexEvent::PumpFunBuy(e) => &e.metadata,
-            DexEvent::PumpFunSell(e) => &e.metadata,
-            DexEvent::PumpFunBuyExactSolIn(e) => &e.metadata,
-            DexEvent::PumpFunMigrate(e) => &e.metadata,
-
-            // PumpSwap 事件
-            DexEvent::PumpSwapTrade(e) => &e.metadata,
-            DexEvent::PumpSwapBuy(e) => &e.metadata,
-            DexEvent::PumpSwapSell(e) => &e.metadata,
-            DexEvent::PumpSwapCreatePool(e) => &e.metadata,
-            DexEvent::PumpSwapLiquidityAdded(e) => &e.metadata,
-            DexEvent::PumpSwapLiquidityRemoved(e) => &e.metadata,
-
-            // Meteora DAMM V2 事件
-            DexEvent::MeteoraDammV2Swap(e) => &e.metadata,
-            DexEvent::MeteoraDammV2CreatePosition(e) => &e.metadata,
-            DexEvent::MeteoraDammV2ClosePosition(e) => &e.metadata,
-            DexEvent::MeteoraDammV2AddLiquidity(e) => &e.metadata,
-            DexEvent::MeteoraDammV2RemoveLiquidity(e) => &e.metadata,
-
-            // Bonk 事件
-            DexEvent::BonkTrade(e) => &e.metadata,
-            DexEvent::BonkPoolCreate(e) => &e.metadata,
-            DexEvent::BonkMigrateAmm(e) => &e.metadata,
-
-            // Raydium CLMM 事件
-            DexEvent::RaydiumClmmSwap(e) => &e.metadata,
-            DexEvent::RaydiumClmmCreatePool(e) => &e.metadata,
-            DexEvent::RaydiumClmmOpenPosition(e) => &e.metadata,
-            DexEvent::RaydiumClmmOpenPositionWithTokenExtNft(e) => &e.metadata,
-            DexEvent::RaydiumClmmClosePosition(e) => &e.metadata,
-            DexEvent::RaydiumClmmIncreaseLiquidity(e) => &e.metadata,
-            DexEvent::RaydiumClmmDecreaseLiquidity(e) => &e.metadata,
-            DexEvent::RaydiumClmmCollectFee(e) => &e.metadata,
-
-            // Raydium CPMM 事件
-            DexEvent::RaydiumCpmmSwap(e) => &e.metadata,
-            DexEvent::RaydiumCpmmDeposit(e) => &e.metadata,
-            DexEvent::RaydiumCpmmWithdraw(e) => &e.metadata,
-            DexEvent::RaydiumCpmmInitialize(e) => &e.metadata,
-
-            // Raydium AMM V4 事件
-            DexEvent::RaydiumAmmV4Swap(e) => &e.metadata,
-            DexEvent::RaydiumAmmV4Deposit(e) => &e.metadata,
-            DexEvent::RaydiumAmmV4Initialize2(e) => &e.metadata,
-            DexEvent::RaydiumAmmV4Withdraw(e) => &e.metadata,
-            DexEvent::RaydiumAmmV4WithdrawPnl(e) => &e.metadata,
-
-            // Orca Whirlpool 事件
-            DexEvent::OrcaWhirlpoolSwap(e) => &e.metadata,
-            DexEvent::OrcaWhirlpoolLiquidityIncreased(e) => &e.metadata,
-            DexEvent::OrcaWhirlpoolLiquidityDecreased(e) => &e.metadata,
-            DexEvent::OrcaWhirlpoolPoolInitialized(e) => &e.metadata,
-
-            // Meteora Pools 事件
-            DexEvent::MeteoraPoolsSwap(e) => &e.metadata,
-            DexEvent::MeteoraPoolsAddLiquidity(e) => &e.metadata,
-            DexEvent::MeteoraPoolsRemoveLiquidity(e) => &e.metadata,
-            DexEvent::MeteoraPoolsBootstrapLiquidity(e) => &e.metadata,
-            DexEvent::MeteoraPoolsPoolCreated(e) => &e.metadata,
-            DexEvent::MeteoraPoolsSetPoolFees(e) => &e.metadata,
-
-            // Meteora DLMM 事件
-            DexEvent::MeteoraDlmmSwap(e) => &e.metadata,
-            DexEvent::MeteoraDlmmAddLiquidity(e) => &e.metadata,
-            DexEvent::MeteoraDlmmRemoveLiquidity(e) => &e.metadata,
-            DexEvent::MeteoraDlmmInitializePool(e) => &e.metadata,
-            DexEvent::MeteoraDlmmInitializeBinArray(e) => &e.metadata,
-            DexEvent::MeteoraDlmmCreatePosition(e) => &e.metadata,
-            DexEvent::MeteoraDlmmClosePosition(e) => &e.metadata,
-            DexEvent::MeteoraDlmmClaimFee(e) => &e.metadata,
-
-            // 账户事件
-            DexEvent::TokenInfo(e) => &e.metadata,
-            DexEvent::TokenAccount(e) => &e.metadata,
-            DexEvent::NonceAccount(e) => &e.metadata,
-            DexEvent::PumpSwapGlobalConfigAccount(e) => &e.metadata,
-            DexEvent::PumpSwapPoolAccount(e) => &e.metadata,
-
-            // 区块元数据事件
-            DexEvent::BlockMeta(e) => &e.metadata,
-
-            // 错误事件 - 返回默认元数据
-            DexEvent::Error(_) => &DEFAULT_METADATA,
+    /// 稳定的事件 ID，见 [`EventMetadata::event_id`]
+    pub fn event_id(&self) -> u128 {
+        self.metadata().event_id(self.event_type_name())
+    }
+
+    /// 是否为 swap（成交）事件
+    #[inline]
+    pub fn is_swap(&self) -> bool {
+        matches!(
+            self,
+            DexEvent::PumpFunTrade(_)
+                | DexEvent::PumpFunBuy(_)
+                | DexEvent::PumpFunSell(_)
+                | DexEvent::PumpFunBuyExactSolIn(_)
+                | DexEvent::PumpSwapBuy(_)
+                | DexEvent::PumpSwapSell(_)
+                | DexEvent::BonkTrade(_)
+                | DexEvent::RaydiumClmmSwap(_)
+                | DexEvent::RaydiumCpmmSwap(_)
+                | DexEvent::RaydiumAmmV4Swap(_)
+                | DexEvent::OrcaWhirlpoolSwap(_)
+                | DexEvent::MeteoraPoolsSwap(_)
+                | DexEvent::MeteoraDammV2Swap(_)
+                | DexEvent::MeteoraDlmmSwap(_)
+        )
+    }
+}
+
+/// 为 `DexEvent` 生成按变体取账户字段的访问器，字段为默认值（未填充）时返回 None
+macro_rules! event_key_accessors {
+    ($($(#[$doc:meta])* $name:ident { $($variant:ident => $($field:ident).+),* $(,)? })*) => {
+        impl DexEvent {
+            $(
+                $(#[$doc])*
+                pub fn $name(&self) -> Option<Pubkey> {
+                    let key = match self {
+                        $(DexEvent::$variant(e) => e.$($field).+,)*
+                        _ => return None,
+                    };
+                    (key != Pubkey::default()).then_some(key)
+                }
+            )*
         }
+    };
+}
+
+event_key_accessors! {
+    /// 事件所属的池子（PumpFun 为 bonding curve，账户事件为账户地址本身）
+    pool {
+        PumpFunCreate => bonding_curve,
+        PumpFunTrade => bonding_curve,
+        PumpFunBuy => bonding_curve,
+        PumpFunSell => bonding_curve,
+        PumpFunBuyExactSolIn => bonding_curve,
+        PumpFunMigrate => bonding_curve,
+        PumpSwapBuy => pool,
+        PumpSwapSell => pool,
+        PumpSwapCreatePool => pool,
+        PumpSwapLiquidityAdded => pool,
+        PumpSwapLiquidityRemoved => pool,
+        PumpSwapPoolUpdated => pool,
+        MeteoraDammV2Swap => pool,
+        MeteoraDammV2CreatePosition => pool,
+        MeteoraDammV2ClosePosition => pool,
+        MeteoraDammV2AddLiquidity => pool,
+        MeteoraDammV2RemoveLiquidity => pool,
+        BonkTrade => pool_state,
+        BonkPoolCreate => pool_state,
+        BonkMigrateAmm => old_pool,
+        RaydiumClmmSwap => pool_state,
+        RaydiumClmmCreatePool => pool,
+        RaydiumClmmOpenPosition => pool,
+        RaydiumClmmOpenPositionWithTokenExtNft => pool,
+        RaydiumClmmClosePosition => pool,
+        RaydiumClmmIncreaseLiquidity => pool,
+        RaydiumClmmDecreaseLiquidity => pool,
+        RaydiumClmmCollectFee => pool_state,
+        RaydiumClmmInitializeReward => pool_state,
+        RaydiumClmmCollectReward => pool_state,
+        RaydiumClmmSetRewardParams => pool_state,
+        RaydiumCpmmSwap => pool_id,
+        RaydiumCpmmDeposit => pool,
+        RaydiumCpmmWithdraw => pool,
+        RaydiumCpmmInitialize => pool,
+        RaydiumAmmV4Swap => amm,
+        RaydiumAmmV4Deposit => amm,
+        RaydiumAmmV4Initialize2 => amm,
+        RaydiumAmmV4Withdraw => amm,
+        RaydiumAmmV4WithdrawPnl => amm,
+        OrcaWhirlpoolSwap => whirlpool,
+        OrcaWhirlpoolLiquidityIncreased => whirlpool,
+        OrcaWhirlpoolLiquidityDecreased => whirlpool,
+        OrcaWhirlpoolPoolInitialized => whirlpool,
+        MeteoraPoolsSwap => pool,
+        MeteoraPoolsAddLiquidity => pool,
+        MeteoraPoolsRemoveLiquidity => pool,
+        MeteoraPoolsBootstrapLiquidity => pool,
+        MeteoraPoolsPoolCreated => pool,
+        MeteoraPoolsSetPoolFees => pool,
+        MeteoraDlmmSwap => pool,
+        MeteoraDlmmAddLiquidity => pool,
+        MeteoraDlmmRemoveLiquidity => pool,
+        MeteoraDlmmInitializePool => pool,
+        MeteoraDlmmInitializeBinArray => pool,
+        MeteoraDlmmCreatePosition => pool,
+        MeteoraDlmmClosePosition => pool,
+        MeteoraDlmmClaimFee => pool,
+        MeteoraDlmmClaimReward => pool,
+        MeteoraDlmmFundReward => pool,
+        MeteoraDlmmInitializeBinArrayBitmapExtension => pool,
+        PumpSwapPoolAccount => pubkey,
+        RaydiumAmmV4AmmInfoAccount => pubkey,
+        RaydiumAmmV4TargetOrdersAccount => target_orders.amm,
+        FirstSwapInSlot => pool,
+        ValidationWarning => pool,
+        NormalizedPoolCreated => pool,
+        MigrationCompleted => destination_pool,
+    }
+
+    /// 事件的主要代币（发射台为代币本身，双边池为 token A / token 0）
+    ///
+    /// 不含 mint 的事件（如 Orca / Raydium CLMM swap）返回 None，可用
+    /// [`crate::analytics::PoolRegistry`] 按池子补全。
+    mint {
+        PumpFunCreate => mint,
+        PumpFunTrade => mint,
+        PumpFunBuy => mint,
+        PumpFunSell => mint,
+        PumpFunBuyExactSolIn => mint,
+        PumpFunMigrate => mint,
+        PumpSwapTrade => mint,
+        PumpSwapBuy => base_mint,
+        PumpSwapSell => base_mint,
+        PumpSwapCreatePool => base_mint,
+        PumpSwapPoolUpdated => base_mint,
+        MeteoraDammV2Swap => token_a_mint,
+        RaydiumClmmCreatePool => token_0_mint,
+        RaydiumCpmmInitialize => token_0_mint,
+        RaydiumAmmV4Initialize2 => coin_mint,
+        BonkPoolCreate => base_mint,
+        MeteoraDlmmInitializePool => token_x_mint,
+        OrcaWhirlpoolPoolInitialized => token_mint_a,
+        MeteoraPoolsPoolCreated => token_a_mint,
+        TokenInfo => pubkey,
+        TokenAccount => mint,
+        PumpSwapPoolAccount => pool.base_mint,
+        RaydiumAmmV4AmmInfoAccount => amm_info.coin_vault_mint,
+        TokenMetadataResolved => mint,
+        ValidationWarning => mint,
+        NormalizedPoolCreated => mint_a,
+        MigrationCompleted => mint_a,
+    }
+
+    /// 发起操作的钱包（交易者、创建者或仓位所有者）
+    user {
+        PumpFunCreate => user,
+        PumpFunTrade => user,
+        PumpFunBuy => user,
+        PumpFunSell => user,
+        PumpFunBuyExactSolIn => user,
+        PumpFunMigrate => user,
+        PumpSwapTrade => user,
+        PumpSwapBuy => user,
+        PumpSwapSell => user,
+        PumpSwapCreatePool => creator,
+        PumpSwapLiquidityAdded => user,
+        PumpSwapLiquidityRemoved => user,
+        PumpSwapCreatorFeeClaimed => creator,
+        MeteoraDammV2CreatePosition => owner,
+        MeteoraDammV2ClosePosition => owner,
+        MeteoraDammV2AddLiquidity => owner,
+        MeteoraDammV2RemoveLiquidity => owner,
+        BonkTrade => user,
+        BonkPoolCreate => creator,
+        BonkMigrateAmm => user,
+        RaydiumClmmSwap => sender,
+        RaydiumClmmCreatePool => creator,
+        RaydiumClmmOpenPosition => user,
+        RaydiumClmmOpenPositionWithTokenExtNft => user,
+        RaydiumClmmClosePosition => user,
+        RaydiumClmmIncreaseLiquidity => user,
+        RaydiumClmmDecreaseLiquidity => user,
+        RaydiumClmmInitializeReward => reward_funder,
+        RaydiumClmmCollectReward => reward_funder,
+        RaydiumClmmSetRewardParams => authority,
+        RaydiumCpmmDeposit => user,
+        RaydiumCpmmWithdraw => user,
+        RaydiumCpmmInitialize => creator,
+        RaydiumAmmV4Swap => user_source_owner,
+        RaydiumAmmV4Deposit => user_owner,
+        RaydiumAmmV4Initialize2 => user_wallet,
+        RaydiumAmmV4Withdraw => user_owner,
+        MeteoraDlmmSwap => from,
+        MeteoraDlmmAddLiquidity => from,
+        MeteoraDlmmRemoveLiquidity => from,
+        MeteoraDlmmInitializePool => creator,
+        MeteoraDlmmCreatePosition => owner,
+        MeteoraDlmmClosePosition => owner,
+        MeteoraDlmmClaimFee => owner,
+        MeteoraDlmmClaimReward => owner,
+        MeteoraDlmmFundReward => funder,
+        MeteoraDlmmUpdatePositionOperator => owner,
+        MeteoraDlmmInitializeBinArrayBitmapExtension => funder,
+        TokenAccount => token_owner,
+        NormalizedPoolCreated => creator,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trade(metadata: EventMetadata) -> DexEvent {
+        DexEvent::PumpFunTrade(PumpFunTradeEvent { metadata, ..Default::default() })
+    }
+
+    #[test]
+    fn test_event_id_is_deterministic() {
+        let metadata = EventMetadata {
+            signature: Signature::from([7u8; 64]),
+            slot: 100,
+            outer_index: 2,
+            inner_index: Some(3),
+            ..Default::default()
+        };
+        let event = trade(metadata);
+
+        // 接收时间等非身份字段不影响 ID
+        let replayed = trade(EventMetadata { slot: 100, grpc_recv_us: 42, ..metadata });
+        assert_eq!(event.event_id(), replayed.event_id());
+        assert_eq!(event.event_id(), metadata.event_id("PumpFunTrade"));
+    }
+
+    #[test]
+    fn test_event_id_distinguishes_position_and_type() {
+        let metadata = EventMetadata {
+            signature: Signature::from([7u8; 64]),
+            outer_index: 1,
+            ..Default::default()
+        };
+        let id = trade(metadata).event_id();
+
+        assert_ne!(id, trade(EventMetadata { inner_index: Some(0), ..metadata }).event_id());
+        assert_ne!(id, trade(EventMetadata { outer_index: 0, ..metadata }).event_id());
+        assert_ne!(
+            id,
+            trade(EventMetadata { signature: Signature::from([8u8; 64]), ..metadata }).event_id()
+        );
+        assert_ne!(
+            id,
+            DexEvent::PumpFunBuy(PumpFunTradeEvent { metadata, ..Default::default() }).event_id()
+        );
+    }
+
+    #[test]
+    fn test_key_accessors() {
+        let (curve, mint, user) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let event = DexEvent::PumpFunBuy(PumpFunTradeEvent {
+            bonding_curve: curve,
+            mint,
+            user,
+            ..Default::default()
+        });
+        assert_eq!(
+            (event.pool(), event.mint(), event.user()),
+            (Some(curve), Some(mint), Some(user))
+        );
+
+        let pool = Pubkey::new_unique();
+        let event = DexEvent::PumpSwapPoolAccount(PumpSwapPoolAccountEvent {
+            pubkey: pool,
+            pool: PumpSwapPool { base_mint: mint, ..Default::default() },
+            ..Default::default()
+        });
+        assert_eq!((event.pool(), event.mint(), event.user()), (Some(pool), Some(mint), None));
+
+        // 未填充的账户字段视为缺失
+        assert_eq!(trade(EventMetadata::default()).pool(), None);
+        assert_eq!(DexEvent::Error("x".into()).user(), None);
+    }
+
+    #[test]
+    fn test_is_swap() {
+        assert!(trade(EventMetadata::default()).is_swap());
+        assert!(DexEvent::PumpSwapBuy(Default::default()).is_swap());
+        assert!(!DexEvent::PumpFunCreate(Default::default()).is_swap());
+        assert!(!DexEvent::Error("x".into()).is_swap());
+    }
+
+    #[test]
+    fn test_derived_event_fields() {
+        let mut event = RawDataEvent {
+            metadata: EventMetadata::default(),
+            event_type: "PumpFunTrade".into(),
+            source: RawSource::Log("Program data: AA==".into()),
+        };
+        event.metadata_mut().slot = 9;
+        assert_eq!(DexEventFields::metadata(&event).slot, 9);
+        assert_eq!(event.event_type(), "RawData");
+        assert_eq!(RawDataEvent::FIELD_NAMES, ["metadata", "event_type", "source"]);
+
+        // 同一结构体的不同变体保留各自的变体名
+        let mut buy = DexEvent::PumpFunBuy(PumpFunTradeEvent::default());
+        buy.metadata_mut().unwrap().slot = 5;
+        assert_eq!(buy.metadata().slot, 5);
+        assert_eq!(buy.event_type_name(), "PumpFunBuy");
+        assert_eq!(PumpFunTradeEvent::EVENT_TYPE, "PumpFunTrade");
+        assert_eq!(buy.field_names(), PumpFunTradeEvent::FIELD_NAMES);
+
+        let mut error = DexEvent::Error("x".into());
+        assert!(error.metadata_mut().is_none());
+        assert_eq!(error.metadata().slot, 0);
+        assert!(error.field_names().is_empty());
     }
 }
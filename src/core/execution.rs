@@ -0,0 +1,185 @@
+//! 跟单执行钩子
+//!
+//! 对少量配置好的事件模式（如「跟踪的钱包买入某个 mint」），在事件解析完成（账户已填充）后、
+//! 进入转换器和输出队列之前同步调用 [`ExecutionHook`]，为延迟敏感的跟单提供最早的触发点。
+//! 有序模式下钩子在事件进入 slot 缓冲时即触发，不等待 slot 结束。
+//!
+//! 断点恢复（`from_slot`）重放的历史事件（`metadata.replayed`）默认不触发钩子，避免按过期数据重复下单，
+//! 需要时用 [`ExecutionPattern::with_replayed`] 显式开启。
+//!
+//! 钩子运行在解析线程上，耗时会直接阻塞后续事件，应只做下单请求的投递（如写入 channel）。
+//! 闭包 `Fn(&DexEvent)` 可直接作为钩子。
+
+use super::events::{DexEvent, TradeDirection};
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+pub trait ExecutionHook: Send + Sync {
+    fn on_match(&self, event: &DexEvent);
+}
+
+impl<F> ExecutionHook for F
+where
+    F: Fn(&DexEvent) + Send + Sync,
+{
+    #[inline]
+    fn on_match(&self, event: &DexEvent) {
+        self(event)
+    }
+}
+
+/// 触发条件，各条件同时满足才匹配；集合为空 / None 表示不限制
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExecutionPattern {
+    /// 发起操作的钱包（[`DexEvent::user`]）
+    pub users: HashSet<Pubkey>,
+    /// 事件的主要代币（[`DexEvent::mint`]）
+    pub mints: HashSet<Pubkey>,
+    /// 交易方向，设置后只匹配自带方向的交易事件（PumpFun、PumpSwap、Bonk）
+    pub direction: Option<TradeDirection>,
+    /// 是否匹配重放的历史事件，默认 false
+    pub include_replayed: bool,
+}
+
+impl ExecutionPattern {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_users(mut self, users: impl IntoIterator<Item = Pubkey>) -> Self {
+        self.users.extend(users);
+        self
+    }
+
+    pub fn with_mints(mut self, mints: impl IntoIterator<Item = Pubkey>) -> Self {
+        self.mints.extend(mints);
+        self
+    }
+
+    pub fn with_direction(mut self, direction: TradeDirection) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// 同时匹配重放的历史事件
+    pub fn with_replayed(mut self) -> Self {
+        self.include_replayed = true;
+        self
+    }
+
+    pub fn matches(&self, event: &DexEvent) -> bool {
+        if event.metadata().replayed && !self.include_replayed {
+            return false;
+        }
+        let in_set = |set: &HashSet<Pubkey>, key: Option<Pubkey>| {
+            set.is_empty() || key.is_some_and(|key| set.contains(&key))
+        };
+        in_set(&self.users, event.user())
+            && in_set(&self.mints, event.mint())
+            && self.direction.is_none_or(|d| trade_direction(event) == Some(d))
+    }
+}
+
+/// 按注册顺序检查的 (模式, 钩子) 列表
+#[derive(Clone, Default)]
+pub struct ExecutionHooks {
+    hooks: Vec<(ExecutionPattern, Arc<dyn ExecutionHook>)>,
+}
+
+impl ExecutionHooks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, pattern: ExecutionPattern, hook: impl ExecutionHook + 'static) {
+        self.hooks.push((pattern, Arc::new(hook)));
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.hooks.is_empty()
+    }
+
+    /// 调用所有模式匹配的钩子，返回调用次数
+    #[inline]
+    pub fn dispatch(&self, event: &DexEvent) -> usize {
+        let mut matched = 0;
+        for (pattern, hook) in &self.hooks {
+            if pattern.matches(event) {
+                hook.on_match(event);
+                matched += 1;
+            }
+        }
+        matched
+    }
+}
+
+impl std::fmt::Debug for ExecutionHooks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExecutionHooks").field("len", &self.hooks.len()).finish()
+    }
+}
+
+/// 自带方向的交易事件的方向，其他协议需要池子代币对才能判断（见 `analytics::normalize_swap`）
+fn trade_direction(event: &DexEvent) -> Option<TradeDirection> {
+    let from_is_buy = |is_buy| if is_buy { TradeDirection::Buy } else { TradeDirection::Sell };
+    match event {
+        DexEvent::PumpFunTrade(e) => Some(from_is_buy(e.is_buy)),
+        DexEvent::PumpFunBuy(_) | DexEvent::PumpFunBuyExactSolIn(_) | DexEvent::PumpSwapBuy(_) => {
+            Some(TradeDirection::Buy)
+        }
+        DexEvent::PumpFunSell(_) | DexEvent::PumpSwapSell(_) => Some(TradeDirection::Sell),
+        DexEvent::PumpSwapTrade(e) => Some(from_is_buy(e.is_buy)),
+        DexEvent::BonkTrade(e) => Some(e.trade_direction),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::PumpFunTradeEvent;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_hooks_fire_on_matching_patterns() {
+        let (wallet, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let trade = |user, mint, is_buy| {
+            DexEvent::PumpFunTrade(PumpFunTradeEvent { user, mint, is_buy, ..Default::default() })
+        };
+
+        let buys = Arc::new(AtomicUsize::new(0));
+        let mut hooks = ExecutionHooks::new();
+        let counter = buys.clone();
+        hooks.push(
+            ExecutionPattern::new()
+                .with_users([wallet])
+                .with_mints([mint])
+                .with_direction(TradeDirection::Buy),
+            move |_: &DexEvent| {
+                counter.fetch_add(1, Ordering::Relaxed);
+            },
+        );
+        hooks.push(ExecutionPattern::new().with_users([wallet]), |_: &DexEvent| {});
+
+        assert_eq!(hooks.dispatch(&trade(wallet, mint, true)), 2);
+        assert_eq!(hooks.dispatch(&trade(wallet, mint, false)), 1);
+        assert_eq!(hooks.dispatch(&trade(wallet, Pubkey::new_unique(), true)), 1);
+        assert_eq!(hooks.dispatch(&trade(Pubkey::new_unique(), mint, true)), 0);
+        // 卖出只触发不限方向的模式
+        let sell =
+            DexEvent::PumpFunSell(PumpFunTradeEvent { user: wallet, mint, ..Default::default() });
+        assert_eq!(hooks.dispatch(&sell), 1);
+        assert_eq!(buys.load(Ordering::Relaxed), 1);
+
+        // 重放的历史事件只触发显式开启的模式
+        let mut replayed = trade(wallet, mint, true);
+        if let DexEvent::PumpFunTrade(e) = &mut replayed {
+            e.metadata.replayed = true;
+        }
+        assert_eq!(hooks.dispatch(&replayed), 0);
+        hooks.push(ExecutionPattern::new().with_mints([mint]).with_replayed(), |_: &DexEvent| {});
+        assert_eq!(hooks.dispatch(&replayed), 1);
+    }
+}
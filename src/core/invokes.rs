@@ -0,0 +1,131 @@
+//! 交易的程序调用分析
+//!
+//! 按执行顺序列出交易中的每次程序调用（外层指令与 CPI），附带调用栈深度和父调用，
+//! 可用于判断交易经过了哪些程序、由谁发起。
+
+use super::cache::TransactionKeys;
+use serde::Serialize;
+use solana_sdk::pubkey::Pubkey;
+use yellowstone_grpc_proto::prelude::{Transaction, TransactionStatusMeta};
+
+/// 一次程序调用（外层指令或内层 CPI）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct InvokeRecord {
+    pub program_id: Pubkey,
+    /// 所属外层指令序号
+    pub outer_index: u32,
+    /// 内层指令序号，外层指令为 None
+    pub inner_index: Option<u32>,
+    /// 调用栈深度，外层指令为 1；节点未提供 stack_height 时内层指令按 2 处理
+    pub stack_height: u32,
+    /// 父调用在结果中的下标，外层指令为 None
+    pub parent: Option<usize>,
+}
+
+/// 按执行顺序分析交易中的所有程序调用
+///
+/// 账户表包含 ALT 解析出的地址，程序账户不存在时 `program_id` 为默认值。
+pub fn analyze_program_invokes(
+    meta: &TransactionStatusMeta,
+    transaction: &Option<Transaction>,
+) -> Vec<InvokeRecord> {
+    let Some(msg) = transaction.as_ref().and_then(|tx| tx.message.as_ref()) else {
+        return Vec::new();
+    };
+    let keys = TransactionKeys::from_grpc(meta, transaction);
+    let program = |index: u32| keys.get(index as usize).copied().unwrap_or_default();
+
+    let mut records = Vec::with_capacity(msg.instructions.len() + meta.inner_instructions.len());
+    // 当前调用链上各层调用在 records 中的下标
+    let mut stack: Vec<usize> = Vec::with_capacity(4);
+    for (i, ix) in msg.instructions.iter().enumerate() {
+        let outer_index = i as u32;
+        stack.clear();
+        stack.push(records.len());
+        records.push(InvokeRecord {
+            program_id: program(ix.program_id_index),
+            outer_index,
+            inner_index: None,
+            stack_height: 1,
+            parent: None,
+        });
+
+        let Some(inner) = meta.inner_instructions.iter().find(|g| g.index == outer_index) else {
+            continue;
+        };
+        for (j, inner_ix) in inner.instructions.iter().enumerate() {
+            let stack_height = inner_ix.stack_height.unwrap_or(2).max(2);
+            stack.truncate(stack_height as usize - 1);
+            let parent = stack.last().copied();
+            stack.push(records.len());
+            records.push(InvokeRecord {
+                program_id: program(inner_ix.program_id_index),
+                outer_index,
+                inner_index: Some(j as u32),
+                stack_height,
+                parent,
+            });
+        }
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yellowstone_grpc_proto::prelude::{
+        CompiledInstruction, InnerInstruction, InnerInstructions, Message,
+    };
+
+    #[test]
+    fn test_analyze_program_invokes() {
+        let keys: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let alt_program = Pubkey::new_unique();
+        let outer =
+            |program_id_index| CompiledInstruction { program_id_index, ..Default::default() };
+        let inner = |program_id_index, stack_height| InnerInstruction {
+            program_id_index,
+            stack_height,
+            ..Default::default()
+        };
+        let transaction = Some(Transaction {
+            message: Some(Message {
+                account_keys: keys.iter().map(|k| k.to_bytes().to_vec()).collect(),
+                instructions: vec![outer(0), outer(1)],
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        let meta = TransactionStatusMeta {
+            loaded_readonly_addresses: vec![alt_program.to_bytes().to_vec()],
+            inner_instructions: vec![InnerInstructions {
+                index: 1,
+                instructions: vec![
+                    inner(2, Some(2)),
+                    inner(3, Some(3)),
+                    inner(4, Some(2)),
+                    inner(3, None),
+                ],
+            }],
+            ..Default::default()
+        };
+
+        let records = analyze_program_invokes(&meta, &transaction);
+        let summary: Vec<_> = records
+            .iter()
+            .map(|r| (r.program_id, r.outer_index, r.inner_index, r.stack_height, r.parent))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (keys[0], 0, None, 1, None),
+                (keys[1], 1, None, 1, None),
+                (keys[2], 1, Some(0), 2, Some(1)),
+                (keys[3], 1, Some(1), 3, Some(2)),
+                (alt_program, 1, Some(2), 2, Some(1)),
+                (keys[3], 1, Some(3), 2, Some(1)),
+            ]
+        );
+        assert!(analyze_program_invokes(&meta, &None).is_empty());
+    }
+}
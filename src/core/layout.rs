@@ -0,0 +1,105 @@
+//! 事件数据的历史布局版本
+//!
+//! 程序升级时 Anchor 事件通常只在末尾追加字段（如 PumpFun TradeEvent 先后追加了
+//! 实际储备、手续费、创建者费用、交易量跟踪、`ix_name`），旧 slot 中的事件长度更短。
+//! 解析器按当前布局检查长度，回填旧 slot 时这些事件会被静默丢弃。
+//!
+//! [`VersionedLayout`] 登记每个事件的历史固定长度：数据满足当前布局时直接使用；
+//! 长度恰好等于某个历史版本时补零到当前布局（新增字段取默认值、字符串为空）后解析；
+//! 其他长度原样交给解析器判断，不会把截断的数据误当作旧版本。
+
+use std::borrow::Cow;
+
+/// 一个事件在各程序版本中的数据布局（不含 discriminator）
+#[derive(Debug, Clone, Copy)]
+pub struct VersionedLayout {
+    pub name: &'static str,
+    /// 历史版本的固定长度，从旧到新
+    pub legacy_sizes: &'static [usize],
+    /// 当前版本的最小长度（变长字段取空值）
+    pub current_size: usize,
+}
+
+impl VersionedLayout {
+    /// 数据对应的版本号：0 为最旧的历史版本，`legacy_sizes.len()` 为当前版本，无法识别时为 None
+    pub fn version_of(&self, len: usize) -> Option<usize> {
+        if len >= self.current_size {
+            return Some(self.legacy_sizes.len());
+        }
+        self.legacy_sizes.iter().position(|&size| size == len)
+    }
+
+    /// 把历史版本的数据补零到当前布局，其他数据原样返回
+    #[inline]
+    pub fn upgrade<'a>(&self, data: &'a [u8]) -> Cow<'a, [u8]> {
+        if data.len() >= self.current_size || !self.legacy_sizes.contains(&data.len()) {
+            return Cow::Borrowed(data);
+        }
+        let mut padded = data.to_vec();
+        padded.resize(self.current_size, 0);
+        Cow::Owned(padded)
+    }
+}
+
+/// PumpFun TradeEvent
+///
+/// 105：初版；121：+ 实际储备；217：+ 手续费与创建者费用；250：+ 交易量跟踪；
+/// 当前：+ `ix_name`、`mayhem_mode`
+pub const PUMPFUN_TRADE_EVENT: VersionedLayout = VersionedLayout {
+    name: "PumpFunTradeEvent",
+    legacy_sizes: &[105, 121, 217, 250],
+    current_size: 250 + 4 + 1,
+};
+
+/// PumpSwap BuyEvent
+///
+/// 304：初版；352：+ 创建者费用；385：+ 交易量跟踪；当前：+ `min_base_amount_out`、`ix_name`
+pub const PUMPSWAP_BUY_EVENT: VersionedLayout = VersionedLayout {
+    name: "PumpSwapBuyEvent",
+    legacy_sizes: &[304, 352, 385],
+    current_size: 385 + 8 + 4,
+};
+
+/// PumpSwap SellEvent
+///
+/// 304：初版；当前：+ 创建者费用
+pub const PUMPSWAP_SELL_EVENT: VersionedLayout =
+    VersionedLayout { name: "PumpSwapSellEvent", legacy_sizes: &[304], current_size: 352 };
+
+/// 已登记历史布局的事件
+pub const VERSIONED_LAYOUTS: &[VersionedLayout] =
+    &[PUMPFUN_TRADE_EVENT, PUMPSWAP_BUY_EVENT, PUMPSWAP_SELL_EVENT];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::{DexEvent, EventMetadata};
+    use solana_sdk::pubkey::Pubkey;
+
+    #[test]
+    fn test_legacy_pumpfun_trade_is_upgraded() {
+        let mint = Pubkey::new_unique();
+        // 初版 TradeEvent：mint, sol_amount, token_amount, is_buy, user, timestamp, 虚拟储备
+        let mut data = Vec::new();
+        data.extend_from_slice(mint.as_ref());
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&5_000u64.to_le_bytes());
+        data.push(1);
+        data.extend_from_slice(Pubkey::new_unique().as_ref());
+        data.extend_from_slice(&1_600_000_000i64.to_le_bytes());
+        data.extend_from_slice(&30_000u64.to_le_bytes());
+        data.extend_from_slice(&1_073_000_000u64.to_le_bytes());
+        assert_eq!(PUMPFUN_TRADE_EVENT.version_of(data.len()), Some(0));
+        assert_eq!(PUMPFUN_TRADE_EVENT.version_of(PUMPFUN_TRADE_EVENT.current_size), Some(4));
+        assert!(PUMPFUN_TRADE_EVENT.version_of(data.len() - 1).is_none());
+
+        let event =
+            crate::logs::pump::parse_trade_from_data(&data, EventMetadata::default(), false);
+        let Some(DexEvent::PumpFunTrade(e)) = event else { panic!("{:?}", event) };
+        assert_eq!((e.mint, e.sol_amount, e.token_amount, e.is_buy), (mint, 1_000, 5_000, true));
+        assert_eq!((e.virtual_token_reserves, e.creator_fee), (1_073_000_000, 0));
+
+        // 截断的数据不当作旧版本
+        assert!(matches!(PUMPFUN_TRADE_EVENT.upgrade(&data[..100]), Cow::Borrowed(_)));
+    }
+}
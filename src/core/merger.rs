@@ -7,6 +7,8 @@
 //! - 内联优化，最小化开销
 
 use crate::core::events::*;
+use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
 
 /// 合并 instruction 事件和 inner instruction 事件
 ///
@@ -36,11 +38,26 @@ pub fn merge_events(base: &mut DexEvent, inner: DexEvent) {
         (PumpFunMigrate(b), PumpFunMigrate(i)) => merge_pumpfun_migrate(b, i),
 
         // ========== PumpSwap 系列 ==========
-        (PumpSwapBuy(b), PumpSwapBuy(i)) => merge_generic(b, i),
-        (PumpSwapSell(b), PumpSwapSell(i)) => merge_generic(b, i),
+        (PumpSwapBuy(b), PumpSwapBuy(i)) => {
+            // 事件不含指令参数，保留外层指令解析的值
+            let args = b.instruction_args.take();
+            merge_generic(b, i);
+            b.instruction_args = b.instruction_args.or(args);
+        }
+        (PumpSwapSell(b), PumpSwapSell(i)) => {
+            let args = b.instruction_args.take();
+            merge_generic(b, i);
+            b.instruction_args = b.instruction_args.or(args);
+        }
         (PumpSwapCreatePool(b), PumpSwapCreatePool(i)) => merge_generic(b, i),
         (PumpSwapLiquidityAdded(b), PumpSwapLiquidityAdded(i)) => merge_generic(b, i),
         (PumpSwapLiquidityRemoved(b), PumpSwapLiquidityRemoved(i)) => merge_generic(b, i),
+        (PumpSwapCreatorFeeClaimed(b), PumpSwapCreatorFeeClaimed(i)) => {
+            // 事件不含 quote_mint，保留指令账户中的值
+            let quote_mint = b.quote_mint;
+            merge_generic(b, i);
+            b.quote_mint = quote_mint;
+        }
 
         // ========== Raydium CLMM 系列 ==========
         (RaydiumClmmSwap(b), RaydiumClmmSwap(i)) => merge_generic(b, i),
@@ -65,9 +82,12 @@ pub fn merge_events(base: &mut DexEvent, inner: DexEvent) {
         (OrcaWhirlpoolLiquidityDecreased(b), OrcaWhirlpoolLiquidityDecreased(i)) => merge_generic(b, i),
 
         // ========== Meteora Pools (AMM) 系列 ==========
-        (MeteoraPoolsSwap(b), MeteoraPoolsSwap(i)) => merge_generic(b, i),
-        (MeteoraPoolsAddLiquidity(b), MeteoraPoolsAddLiquidity(i)) => merge_generic(b, i),
-        (MeteoraPoolsRemoveLiquidity(b), MeteoraPoolsRemoveLiquidity(i)) => merge_generic(b, i),
+        (MeteoraPoolsSwap(b), MeteoraPoolsSwap(i)) => merge_meteora_pools_swap(b, i),
+        (MeteoraPoolsAddLiquidity(b), MeteoraPoolsAddLiquidity(i)) => merge_meteora_pools_add_liquidity(b, i),
+        (MeteoraPoolsRemoveLiquidity(b), MeteoraPoolsRemoveLiquidity(i)) => merge_meteora_pools_remove_liquidity(b, i),
+        (MeteoraPoolsBootstrapLiquidity(b), MeteoraPoolsBootstrapLiquidity(i)) => merge_generic(b, i),
+        (MeteoraPoolsPoolCreated(b), MeteoraPoolsPoolCreated(i)) => merge_generic(b, i),
+        (MeteoraPoolsSetPoolFees(b), MeteoraPoolsSetPoolFees(i)) => merge_generic(b, i),
 
         // ========== Meteora DAMM V2 系列 ==========
         (MeteoraDammV2Swap(b), MeteoraDammV2Swap(i)) => merge_generic(b, i),
@@ -171,6 +191,46 @@ fn merge_pumpfun_migrate(base: &mut PumpFunMigrateEvent, inner: PumpFunMigrateEv
     base.pool = inner.pool;
 }
 
+// ============================================================================
+// Meteora Pools 事件合并实现
+// ============================================================================
+
+/// 合并 Meteora Pools Swap 事件（保留 instruction 提供的 pool 账户）
+#[inline(always)]
+fn merge_meteora_pools_swap(base: &mut MeteoraPoolsSwapEvent, inner: MeteoraPoolsSwapEvent) {
+    let pool = base.pool;
+    *base = inner;
+    if base.pool == solana_sdk::pubkey::Pubkey::default() {
+        base.pool = pool;
+    }
+}
+
+/// 合并 Meteora Pools AddLiquidity 事件（保留 instruction 提供的 pool 账户）
+#[inline(always)]
+fn merge_meteora_pools_add_liquidity(
+    base: &mut MeteoraPoolsAddLiquidityEvent,
+    inner: MeteoraPoolsAddLiquidityEvent,
+) {
+    let pool = base.pool;
+    *base = inner;
+    if base.pool == solana_sdk::pubkey::Pubkey::default() {
+        base.pool = pool;
+    }
+}
+
+/// 合并 Meteora Pools RemoveLiquidity 事件（保留 instruction 提供的 pool 账户）
+#[inline(always)]
+fn merge_meteora_pools_remove_liquidity(
+    base: &mut MeteoraPoolsRemoveLiquidityEvent,
+    inner: MeteoraPoolsRemoveLiquidityEvent,
+) {
+    let pool = base.pool;
+    *base = inner;
+    if base.pool == solana_sdk::pubkey::Pubkey::default() {
+        base.pool = pool;
+    }
+}
+
 // ============================================================================
 // 工具函数
 // ============================================================================
@@ -213,6 +273,58 @@ pub fn can_merge(base: &DexEvent, inner: &DexEvent) -> bool {
     }
 }
 
+/// 同一交易内重复事件的处理策略，见 [`dedupe_events`]
+///
+/// 类型相同且来自同一条指令（`outer_index` + `inner_index` 相同）的事件视为重复，
+/// 例如同一笔成交既从日志又从内层指令解析出来。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DedupPolicy {
+    /// 不去重，按解析顺序输出全部事件
+    Disabled,
+    /// 保留先出现的事件
+    KeepFirst,
+    /// 保留后出现的事件，位置不变
+    KeepLast,
+    /// 以先出现的事件为基础，用 [`merge_events`] 合并后出现的事件（默认）
+    ///
+    /// 多数协议由后出现的事件整体覆盖；PumpFun 交易 / 创建 / 迁移只覆盖交易数据字段，保留先出现事件的账户上下文。
+    #[default]
+    Merge,
+}
+
+/// 按 `policy` 去重同一交易内的重复事件，保持首次出现的顺序
+///
+/// `DexEvent::Error` 不参与去重。
+pub fn dedupe_events<A: smallvec::Array<Item = DexEvent>>(
+    events: &mut SmallVec<A>,
+    policy: DedupPolicy,
+) {
+    if policy == DedupPolicy::Disabled || events.len() < 2 {
+        return;
+    }
+    let key = |e: &DexEvent| {
+        let m = e.metadata();
+        (e.event_type_name(), m.outer_index, m.inner_index)
+    };
+
+    let mut deduped: SmallVec<A> = SmallVec::with_capacity(events.len());
+    for event in events.drain(..) {
+        let existing = if matches!(event, DexEvent::Error(_)) {
+            None
+        } else {
+            let k = key(&event);
+            deduped.iter_mut().find(|e| !matches!(e, DexEvent::Error(_)) && key(e) == k)
+        };
+        match (existing, policy) {
+            (None, _) => deduped.push(event),
+            (Some(existing), DedupPolicy::KeepLast) => *existing = event,
+            (Some(existing), DedupPolicy::Merge) => merge_events(existing, event),
+            (Some(_), _) => {}
+        }
+    }
+    *events = deduped;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -226,11 +338,12 @@ mod tests {
             tx_index: 1,
             block_time_us: 1000,
             grpc_recv_us: 2000,
+            ..Default::default()
         };
 
         // Base event 来自 instruction（包含账户上下文）
         let mut base = DexEvent::PumpFunTrade(PumpFunTradeEvent {
-            metadata: metadata.clone(),
+            metadata,
             bonding_curve: Pubkey::new_unique(),
             associated_bonding_curve: Pubkey::new_unique(),
             ..Default::default()
@@ -238,7 +351,7 @@ mod tests {
 
         // Inner event 来自 inner instruction（包含交易数据）
         let inner = DexEvent::PumpFunTrade(PumpFunTradeEvent {
-            metadata: metadata.clone(),
+            metadata,
             mint: Pubkey::new_unique(),
             sol_amount: 1000,
             token_amount: 2000,
@@ -271,15 +384,16 @@ mod tests {
             tx_index: 1,
             block_time_us: 1000,
             grpc_recv_us: 2000,
+            ..Default::default()
         };
 
         let base = DexEvent::PumpFunTrade(PumpFunTradeEvent {
-            metadata: metadata.clone(),
+            metadata,
             ..Default::default()
         });
 
         let inner = DexEvent::PumpFunBuy(PumpFunTradeEvent {
-            metadata: metadata.clone(),
+            metadata,
             ..Default::default()
         });
 
@@ -297,4 +411,39 @@ mod tests {
 
         assert!(!can_merge(&base, &different_sig));
     }
+
+    #[test]
+    fn test_dedupe_events() {
+        let metadata = EventMetadata { outer_index: 1, ..Default::default() };
+        let trade = |metadata, sol_amount| {
+            DexEvent::PumpFunTrade(PumpFunTradeEvent { metadata, sol_amount, ..Default::default() })
+        };
+        let events = || -> SmallVec<[DexEvent; 4]> {
+            smallvec::smallvec![
+                trade(metadata, 1),
+                trade(EventMetadata { inner_index: Some(0), ..metadata }, 2),
+                trade(metadata, 3),
+            ]
+        };
+        let amounts = |events: &SmallVec<[DexEvent; 4]>| -> Vec<u64> {
+            events
+                .iter()
+                .map(|e| match e {
+                    DexEvent::PumpFunTrade(t) => t.sol_amount,
+                    _ => unreachable!(),
+                })
+                .collect()
+        };
+
+        for (policy, expected) in [
+            (DedupPolicy::Disabled, vec![1, 2, 3]),
+            (DedupPolicy::KeepFirst, vec![1, 2]),
+            (DedupPolicy::KeepLast, vec![3, 2]),
+            (DedupPolicy::Merge, vec![3, 2]),
+        ] {
+            let mut events = events();
+            dedupe_events(&mut events, policy);
+            assert_eq!(amounts(&events), expected, "{:?}", policy);
+        }
+    }
 }
@@ -15,15 +15,44 @@ pub mod common_filler;
 pub mod merger;             // 事件合并器 - instruction + inner instruction
 pub mod clock;              // 高性能时钟 - 微秒级时间戳获取
 pub mod cache;              // 解析器缓存 - 减少内存分配
+pub mod bounded;            // 有界状态表 - LRU / TTL 淘汰
+pub mod transform;          // 事件转换钩子 - 解析后、投递前
+pub mod execution;          // 跟单执行钩子 - 匹配的事件入队前同步回调
+pub mod redact;             // 字段脱敏 - 按事件类型清零或哈希指定字段
+pub mod invokes;            // 程序调用分析 - 调用顺序、栈深度与父调用
+pub mod router;             // 事件路由 - 按类型 / mint 声明处理函数
+pub mod validation;         // 数量校验 - 按 token 余额变化交叉检查 swap 数量
+pub mod amount;             // 带精度的代币数量 - 格式化、换算与溢出检查
+pub mod price;              // 价格换算 - Q64.64、tick 与 DLMM bin
+pub mod layout;             // 历史布局版本 - 旧 slot 的短事件补零后解析
+pub mod profile;            // 热路径分支计数 - feature `profile-hooks`
+#[cfg(test)]
+pub(crate) mod test_fixtures; // 测试夹具：填充事件生成、golden 文件测试
 
 // 主要导出 - 核心事件处理功能
 pub use events::*;
 pub use unified_parser::{
-    parse_transaction_events, parse_logs_only, parse_transaction_with_listener, EventListener,
+    parse_transaction_events, parse_transaction_events_with_dedup, parse_logs_only, parse_transaction_with_listener, EventListener, AsyncEventListener,
     parse_transaction_events_streaming, parse_logs_streaming, parse_transaction_with_streaming_listener, StreamingEventListener
 };
 pub use clock::{now_micros, elapsed_micros_since, now_nanos};
-pub use cache::{build_account_pubkeys_with_cache, AccountPubkeyCache};
+pub use cache::{
+    build_account_pubkeys_with_cache, signature_base58, AccountPubkeyCache, TransactionAccounts,
+    TransactionAccountsCache, TransactionKeys,
+};
+pub use bounded::{BoundedMap, BoundedMapConfig, EvictionStats};
+pub use transform::{Transformer, TransformerChain};
+pub use execution::{ExecutionHook, ExecutionHooks, ExecutionPattern};
+pub use redact::{RedactAction, Redactor, ALL_EVENTS};
+pub use invokes::{analyze_program_invokes, InvokeRecord};
+pub use merger::{dedupe_events, DedupPolicy};
+pub use router::drain_queue;
+pub use validation::{validate_amounts, AmountValidation};
+pub use amount::Amount;
+pub use price::{
+    bin_id_to_price, price_to_bin_id, price_to_sqrt_price_x64, price_to_tick, q64_to_price,
+    sqrt_price_x64_to_price, tick_to_price,
+};
 
 pub use crate::accounts::{
     parse_token_account, parse_nonce_account, AccountData,
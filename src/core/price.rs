@@ -0,0 +1,101 @@
+//! 集中流动性价格换算
+//!
+//! 链上价格都是原始单位之比（每个最小单位的 token 0 / X 值多少最小单位的 token 1 / Y），
+//! 这里统一换算为 UI 价格（每个 token 0 值多少 token 1），乘以 `10^(decimals_0 - decimals_1)`：
+//! - Raydium CLMM / Orca Whirlpool：Q64.64 平方根价格 `sqrt_price_x64`，或 tick（`1.0001^tick`）
+//! - Meteora DLMM：bin id 与 bin step（基点），`(1 + bin_step / 10000)^bin_id`；
+//!   账户中的 Q64.64 价格用 [`q64_to_price`]
+//!
+//! 计算使用 `f64`，适合展示和分析，不用于复现链上的精确取整。
+
+/// Q64.64 定点数的 1.0
+const Q64: f64 = 18_446_744_073_709_551_616.0; // 2^64
+
+/// bin step 的分母（基点）
+const BASIS_POINT_MAX: f64 = 10_000.0;
+
+#[inline]
+fn decimals_scale(decimals_0: u8, decimals_1: u8) -> f64 {
+    10f64.powi(decimals_0 as i32 - decimals_1 as i32)
+}
+
+/// Q64.64 平方根价格换算为 UI 价格（每个 token 0 值多少 token 1）
+pub fn sqrt_price_x64_to_price(sqrt_price_x64: u128, decimals_0: u8, decimals_1: u8) -> f64 {
+    let sqrt_price = sqrt_price_x64 as f64 / Q64;
+    sqrt_price * sqrt_price * decimals_scale(decimals_0, decimals_1)
+}
+
+/// UI 价格换算为 Q64.64 平方根价格，非正数或超出范围时返回 None
+pub fn price_to_sqrt_price_x64(price: f64, decimals_0: u8, decimals_1: u8) -> Option<u128> {
+    let raw = price / decimals_scale(decimals_0, decimals_1);
+    let sqrt_price_x64 = raw.sqrt() * Q64;
+    (raw > 0.0 && sqrt_price_x64 < u128::MAX as f64).then_some(sqrt_price_x64 as u128)
+}
+
+/// tick 对应的 UI 价格
+pub fn tick_to_price(tick: i32, decimals_0: u8, decimals_1: u8) -> f64 {
+    1.0001f64.powi(tick) * decimals_scale(decimals_0, decimals_1)
+}
+
+/// UI 价格所在的 tick（向下取整），非正数时返回 None
+pub fn price_to_tick(price: f64, decimals_0: u8, decimals_1: u8) -> Option<i32> {
+    let raw = price / decimals_scale(decimals_0, decimals_1);
+    (raw > 0.0).then(|| (raw.ln() / 1.0001f64.ln()).floor() as i32)
+}
+
+/// DLMM bin 的 UI 价格（每个 token X 值多少 token Y）
+pub fn bin_id_to_price(bin_id: i32, bin_step: u16, decimals_x: u8, decimals_y: u8) -> f64 {
+    let base = 1.0 + bin_step as f64 / BASIS_POINT_MAX;
+    base.powi(bin_id) * decimals_scale(decimals_x, decimals_y)
+}
+
+/// UI 价格所在的 DLMM bin（向下取整），非正数或 `bin_step` 为 0 时返回 None
+pub fn price_to_bin_id(price: f64, bin_step: u16, decimals_x: u8, decimals_y: u8) -> Option<i32> {
+    let raw = price / decimals_scale(decimals_x, decimals_y);
+    if raw <= 0.0 || bin_step == 0 {
+        return None;
+    }
+    let base = 1.0 + bin_step as f64 / BASIS_POINT_MAX;
+    // 加一个极小量，避免恰好落在 bin 边界的价格因浮点误差落入前一个 bin
+    Some((raw.ln() / base.ln() + 1e-9).floor() as i32)
+}
+
+/// Q64.64 价格（非平方根，如 DLMM 的 bin 价格）换算为 UI 价格
+pub fn q64_to_price(price_x64: u128, decimals_0: u8, decimals_1: u8) -> f64 {
+    price_x64 as f64 / Q64 * decimals_scale(decimals_0, decimals_1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual / expected - 1.0).abs() < 1e-9, "{} != {}", actual, expected);
+    }
+
+    #[test]
+    fn test_price_conversions() {
+        // SOL (9) / USDC (6)：原始价格 1 即 1000 USDC/SOL
+        assert_close(sqrt_price_x64_to_price(1u128 << 64, 9, 6), 1_000.0);
+        assert_eq!(price_to_sqrt_price_x64(1_000.0, 9, 6), Some(1u128 << 64));
+        assert_eq!(price_to_sqrt_price_x64(0.0, 9, 6), None);
+        let sqrt_price_x64 = price_to_sqrt_price_x64(150.25, 9, 6).unwrap();
+        assert_close(sqrt_price_x64_to_price(sqrt_price_x64, 9, 6), 150.25);
+
+        assert_close(tick_to_price(0, 9, 6), 1_000.0);
+        assert_close(tick_to_price(-23_028, 9, 6), 1_000.0 * 1.0001f64.powi(-23_028));
+        assert_eq!(price_to_tick(tick_to_price(-23_028, 9, 6) * 1.00001, 9, 6), Some(-23_028));
+        assert_eq!(price_to_tick(-1.0, 9, 6), None);
+
+        // bin step 25 基点，bin 100 = 1.0025^100
+        assert_close(bin_id_to_price(100, 25, 6, 6), 1.0025f64.powi(100));
+        assert_close(bin_id_to_price(-100, 25, 9, 6), 1.0025f64.powi(-100) * 1_000.0);
+        for bin_id in [-4_000, -1, 0, 1, 4_000] {
+            let price = bin_id_to_price(bin_id, 25, 9, 6);
+            assert_eq!(price_to_bin_id(price, 25, 9, 6), Some(bin_id));
+        }
+        assert_eq!(price_to_bin_id(1.0, 0, 9, 6), None);
+
+        assert_close(q64_to_price(3u128 << 63, 6, 6), 1.5);
+    }
+}
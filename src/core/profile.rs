@@ -0,0 +1,138 @@
+//! 热路径分支计数（feature `profile-hooks`）
+//!
+//! 在日志解析的主要路由分支上计数：日志类型、discriminator 热路径 / 冷路径命中与未命中、
+//! 程序归属跳过和事件类型过滤器的提前拒绝。不需要完整的 profiler 就能看出当前负载的解析
+//! 时间花在哪条路径上，例如过滤器拒绝占比高说明交易订阅可以收窄。
+//!
+//! 计数器为全局 Relaxed 原子操作；未开启 feature 时 [`profile_hit!`] 展开为空，没有任何开销。
+//!
+//! ```ignore
+//! let before = sol_parser_sdk::core::profile::snapshot();
+//! // ... 运行一段时间 ...
+//! for (branch, count) in sol_parser_sdk::core::profile::snapshot().since(&before).iter() {
+//!     println!("{:?}: {}", branch, count);
+//! }
+//! ```
+
+/// 记录一次分支命中，未开启 `profile-hooks` 时展开为空
+macro_rules! profile_hit {
+    ($branch:ident) => {
+        #[cfg(feature = "profile-hooks")]
+        $crate::core::profile::hit($crate::core::profile::Branch::$branch);
+    };
+}
+pub(crate) use profile_hit;
+
+#[cfg(feature = "profile-hooks")]
+pub use hooks::*;
+
+#[cfg(feature = "profile-hooks")]
+mod hooks {
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    /// 计数的路由分支
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Branch {
+        /// 不是有效 Program data 的日志（invoke / success / `Program log:` 等）
+        OtherLog,
+        /// 解码出 Program data 的日志
+        ProgramData,
+        /// 调用栈归属的程序不输出该 discriminator，跳过
+        ProgramMismatch,
+        /// 解析字段前被事件类型过滤器拒绝
+        FilterRejected,
+        /// 归属明确，直接交给协议解析器（Meteora DAMM V2 / DLMM）
+        AttributedRoute,
+        /// discriminator 命中热路径
+        HotPathHit,
+        /// 进入冷路径 match（含未命中）
+        ColdPath,
+        /// 未知 discriminator
+        DiscriminatorMiss,
+    }
+
+    impl Branch {
+        pub const ALL: [Branch; 8] = [
+            Branch::OtherLog,
+            Branch::ProgramData,
+            Branch::ProgramMismatch,
+            Branch::FilterRejected,
+            Branch::AttributedRoute,
+            Branch::HotPathHit,
+            Branch::ColdPath,
+            Branch::DiscriminatorMiss,
+        ];
+    }
+
+    static COUNTERS: [AtomicU64; Branch::ALL.len()] =
+        [const { AtomicU64::new(0) }; Branch::ALL.len()];
+
+    #[inline(always)]
+    pub fn hit(branch: Branch) {
+        COUNTERS[branch as usize].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 各分支的累计命中数
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct ProfileSnapshot {
+        counts: [u64; Branch::ALL.len()],
+    }
+
+    impl ProfileSnapshot {
+        pub fn get(&self, branch: Branch) -> u64 {
+            self.counts[branch as usize]
+        }
+
+        /// 相对更早快照的增量
+        pub fn since(&self, earlier: &ProfileSnapshot) -> ProfileSnapshot {
+            let mut counts = self.counts;
+            for (count, earlier) in counts.iter_mut().zip(earlier.counts) {
+                *count = count.saturating_sub(earlier);
+            }
+            ProfileSnapshot { counts }
+        }
+
+        pub fn iter(&self) -> impl Iterator<Item = (Branch, u64)> + '_ {
+            Branch::ALL.iter().map(|&branch| (branch, self.get(branch)))
+        }
+    }
+
+    pub fn snapshot() -> ProfileSnapshot {
+        ProfileSnapshot { counts: COUNTERS.each_ref().map(|c| c.load(Ordering::Relaxed)) }
+    }
+
+    pub fn reset() {
+        for counter in &COUNTERS {
+            counter.store(0, Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "profile-hooks"))]
+mod tests {
+    use super::*;
+    use crate::core::events::EventMetadata;
+    use crate::logs::{parse_log_with_context, with_decode_context};
+
+    #[test]
+    fn test_branch_counters() {
+        let before = snapshot();
+        // PumpFun Trade discriminator（热路径），数据不足时解析失败
+        let logs = [
+            "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P invoke [1]",
+            "Program data: vdt/007mYe4AAAAAAAAAAA==",
+        ];
+        with_decode_context(|ctx| {
+            for (i, log) in logs.iter().enumerate() {
+                parse_log_with_context(ctx, i, log, EventMetadata::default(), None, None, false);
+            }
+        });
+
+        // 计数器是全局的，其他并行测试也会计数，只检查下限
+        let delta = snapshot().since(&before);
+        assert!(delta.get(Branch::OtherLog) >= 1);
+        assert!(delta.get(Branch::ProgramData) >= 1);
+        assert!(delta.get(Branch::HotPathHit) >= 1);
+        assert_eq!(delta.iter().count(), Branch::ALL.len());
+    }
+}
@@ -0,0 +1,193 @@
+//! 事件字段脱敏
+//!
+//! 对外转发事件流时，按事件类型把指定字段（如用户钱包）清零或替换为加盐哈希，
+//! 在事件离开进程之前完成。[`Redactor`] 实现了 [`Transformer`]，通过
+//! `YellowstoneGrpc::with_transformer` 接入客户端后，回调、sink 和 WebSocket 广播
+//! 拿到的都是脱敏后的事件；也可以直接调用 [`Redactor::apply`]。
+//!
+//! 字段按序列化后的名称匹配，`a.b` 表示嵌套字段（如 `metadata.signature`）。
+//! 登记在 [`ALL_EVENTS`] 下的策略对所有事件类型生效，事件中不存在的字段忽略。
+
+use super::events::DexEvent;
+use super::transform::Transformer;
+use serde_json::Value;
+use solana_program::hash::hashv;
+use std::collections::HashMap;
+
+/// 对所有事件类型生效的策略键
+pub const ALL_EVENTS: &str = "*";
+
+/// 字段脱敏方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RedactAction {
+    /// 清零：数字为 0、布尔为 false、字符串为空，数组与对象逐项清零（Pubkey 变为全零）
+    Zero,
+    /// 加盐 SHA-256：字节数组（Pubkey、签名）替换为同长度的哈希字节，字符串替换为哈希的 base58，
+    /// 其他类型按 [`Self::Zero`] 处理。同一个盐下相同的值结果相同，仍可关联同一钱包的事件
+    Hash,
+}
+
+/// 按事件类型配置的字段脱敏转换器
+///
+/// ```ignore
+/// let redactor = Redactor::new()
+///     .with_salt(secret)
+///     .redact(ALL_EVENTS, "user", RedactAction::Hash)
+///     .redact("PumpFunTrade", "creator", RedactAction::Zero);
+/// let client = YellowstoneGrpc::new(endpoint, token)?.with_transformer(redactor);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Redactor {
+    salt: Vec<u8>,
+    /// 事件类型名 -> (字段路径, 脱敏方式)
+    policies: HashMap<String, Vec<(Vec<String>, RedactAction)>>,
+}
+
+impl Redactor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 哈希使用的盐，对外分发时应使用保密的随机值，否则钱包可被枚举还原
+    pub fn with_salt(mut self, salt: impl Into<Vec<u8>>) -> Self {
+        self.salt = salt.into();
+        self
+    }
+
+    /// 登记策略：`event_type` 为 `DexEvent::event_type_name()`（如 `PumpFunTrade`）或 [`ALL_EVENTS`]
+    pub fn redact(mut self, event_type: &str, field: &str, action: RedactAction) -> Self {
+        let path = field.split('.').map(str::to_string).collect();
+        self.policies.entry(event_type.to_string()).or_default().push((path, action));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.policies.is_empty()
+    }
+
+    /// 按事件类型的策略脱敏，没有匹配策略时原样返回
+    ///
+    /// 序列化往返失败时丢弃事件并返回 None，不会把未脱敏的事件发出去。
+    pub fn apply(&self, event: DexEvent) -> Option<DexEvent> {
+        let specific = self.policies.get(event.event_type_name());
+        let all = self.policies.get(ALL_EVENTS);
+        if specific.is_none() && all.is_none() {
+            return Some(event);
+        }
+
+        let redacted = serde_json::to_value(&event).and_then(|mut value| {
+            // DexEvent 序列化为 {"<类型>": {...}}
+            if let Some(fields) = value.as_object_mut().and_then(|map| map.values_mut().next()) {
+                for (path, action) in all.into_iter().chain(specific).flatten() {
+                    if let Some(target) = field_mut(fields, path) {
+                        self.redact_value(target, *action);
+                    }
+                }
+            }
+            serde_json::from_value(value)
+        });
+        match redacted {
+            Ok(event) => Some(event),
+            Err(e) => {
+                log::warn!(
+                    "Dropping {} event that failed redaction: {}",
+                    event.event_type_name(),
+                    e
+                );
+                None
+            }
+        }
+    }
+
+    fn redact_value(&self, value: &mut Value, action: RedactAction) {
+        match (action, &mut *value) {
+            (RedactAction::Hash, Value::String(s)) => {
+                *s = hashv(&[&self.salt, s.as_bytes()]).to_string();
+            }
+            (RedactAction::Hash, Value::Array(items)) if !items.is_empty() => {
+                let bytes: Option<Vec<u8>> =
+                    items.iter().map(|v| v.as_u64().and_then(|b| u8::try_from(b).ok())).collect();
+                match bytes {
+                    Some(bytes) => {
+                        *items = self.hash_bytes(&bytes).into_iter().map(Value::from).collect()
+                    }
+                    None => items.iter_mut().for_each(|v| self.redact_value(v, action)),
+                }
+            }
+            (_, Value::Number(n)) => *n = 0.into(),
+            (_, Value::Bool(b)) => *b = false,
+            (_, Value::String(s)) => s.clear(),
+            (_, Value::Array(items)) => items.iter_mut().for_each(|v| self.redact_value(v, action)),
+            (_, Value::Object(map)) => map.values_mut().for_each(|v| self.redact_value(v, action)),
+            (_, Value::Null) => {}
+        }
+    }
+
+    /// 与输入同长度的哈希字节，超过 32 字节时按块序号分别哈希
+    fn hash_bytes(&self, bytes: &[u8]) -> Vec<u8> {
+        let mut out = vec![0u8; bytes.len()];
+        for (i, chunk) in out.chunks_mut(32).enumerate() {
+            let hash = hashv(&[&self.salt, bytes, &(i as u32).to_le_bytes()]);
+            chunk.copy_from_slice(&hash.to_bytes()[..chunk.len()]);
+        }
+        out
+    }
+}
+
+impl Transformer for Redactor {
+    #[inline]
+    fn transform(&self, event: DexEvent) -> Option<DexEvent> {
+        self.apply(event)
+    }
+}
+
+fn field_mut<'a>(value: &'a mut Value, path: &[String]) -> Option<&'a mut Value> {
+    path.iter().try_fold(value, |value, key| value.as_object_mut()?.get_mut(key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::{EventMetadata, PumpFunTradeEvent, PumpSwapBuyEvent};
+    use solana_sdk::{pubkey::Pubkey, signature::Signature};
+
+    #[test]
+    fn test_redact_per_event_type() {
+        let (user, creator) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let metadata =
+            EventMetadata { signature: Signature::from([7u8; 64]), ..Default::default() };
+        let trade = DexEvent::PumpFunTrade(PumpFunTradeEvent {
+            metadata,
+            user,
+            creator,
+            sol_amount: 1_000,
+            ..Default::default()
+        });
+        let buy = DexEvent::PumpSwapBuy(PumpSwapBuyEvent {
+            user,
+            coin_creator: creator,
+            ..Default::default()
+        });
+
+        let redactor = Redactor::new()
+            .with_salt(*b"secret")
+            .redact(ALL_EVENTS, "user", RedactAction::Hash)
+            .redact("PumpFunTrade", "creator", RedactAction::Zero)
+            .redact("PumpFunTrade", "metadata.signature", RedactAction::Hash);
+
+        let Some(DexEvent::PumpFunTrade(t)) = redactor.apply(trade.clone()) else { panic!() };
+        let Some(DexEvent::PumpSwapBuy(b)) = redactor.apply(buy) else { panic!() };
+        // 同一钱包在不同事件中映射到同一个哈希
+        assert_ne!(t.user, user);
+        assert_eq!(t.user, b.user);
+        assert_eq!((t.creator, b.coin_creator), (Pubkey::default(), creator));
+        assert_ne!(t.metadata.signature, metadata.signature);
+        assert_eq!(t.sol_amount, 1_000);
+
+        // 换盐后哈希不同
+        let other =
+            Redactor::new().with_salt(*b"other").redact(ALL_EVENTS, "user", RedactAction::Hash);
+        let Some(DexEvent::PumpFunTrade(o)) = other.apply(trade) else { panic!() };
+        assert_ne!(o.user, t.user);
+    }
+}
@@ -0,0 +1,101 @@
+//! 事件路由
+//!
+//! [`route_events!`](crate::route_events) 按事件类型（可附加 mint 和任意条件）声明处理函数，
+//! 展开为一个对 `DexEvent` 的 `match`，没有动态分发和查表开销：
+//!
+//! ```ignore
+//! let mut route = route_events! {
+//!     PumpFunBuy(e) | PumpFunSell(e) where mint == target => on_trade(e),
+//!     PumpSwapBuy(e) if e.quote_amount_in > 1_000_000_000 => on_whale(e),
+//!     PumpFunCreate(e) => println!("new token {}", e.mint),
+//!     _ => {}
+//! };
+//! drain_queue(&queue, &mut route);
+//! ```
+//!
+//! 每个分支依次检查 mint（`DexEvent::mint()`）和 `if` 条件，都满足时执行处理函数；
+//! 生成的闭包返回事件是否命中了某个具名分支（命中 `_` 分支或无分支命中时为 false）。
+//! 闭包按引用捕获处理函数用到的状态，需要移入其他线程时在外层用 `move` 闭包包装。
+
+use crate::DexEvent;
+use crossbeam_queue::ArrayQueue;
+
+/// 按事件类型 / mint 声明处理函数，生成 `FnMut(&DexEvent) -> bool` 闭包，见 [模块文档](crate::core::router)
+#[macro_export]
+macro_rules! route_events {
+    (
+        $(
+            $($variant:ident ( $bind:pat ))|+
+            $(where mint == $mint:tt)?
+            $(if $guard:expr)?
+            => $body:expr
+        ),+
+        $(, _ => $default:expr)?
+        $(,)?
+    ) => {
+        |event: &$crate::DexEvent| -> bool {
+            match event {
+                $(
+                    $($crate::DexEvent::$variant($bind))|+
+                        if true
+                            $(&& $crate::DexEvent::mint(event) == Some($mint))?
+                            $(&& $guard)? =>
+                    {
+                        $body;
+                        true
+                    }
+                )+
+                _ => {
+                    $($default;)?
+                    false
+                }
+            }
+        }
+    };
+}
+
+/// 取出队列中当前所有事件并路由，返回命中具名分支的事件数
+pub fn drain_queue(
+    queue: &ArrayQueue<DexEvent>,
+    mut route: impl FnMut(&DexEvent) -> bool,
+) -> usize {
+    let mut routed = 0;
+    while let Some(event) = queue.pop() {
+        routed += route(&event) as usize;
+    }
+    routed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::{PumpFunCreateTokenEvent, PumpFunTradeEvent};
+    use solana_sdk::pubkey::Pubkey;
+
+    #[test]
+    fn test_route_events_dispatch() {
+        let target = Pubkey::new_unique();
+        let (mut trades, mut large, mut creates, mut other) = (0, 0, 0, 0);
+        let trade = |mint, sol_amount| PumpFunTradeEvent { mint, sol_amount, ..Default::default() };
+        let queue = ArrayQueue::new(8);
+        for event in [
+            DexEvent::PumpFunBuy(trade(target, 5)),
+            DexEvent::PumpFunSell(trade(target, 7)),
+            DexEvent::PumpFunBuy(trade(Pubkey::new_unique(), 100)),
+            DexEvent::PumpFunBuy(trade(Pubkey::new_unique(), 1)),
+            DexEvent::PumpFunCreate(PumpFunCreateTokenEvent::default()),
+            DexEvent::Error("x".into()),
+        ] {
+            queue.push(event).unwrap();
+        }
+        let route = route_events! {
+            PumpFunBuy(e) | PumpFunSell(e) where mint == target => trades += e.sol_amount,
+            PumpFunBuy(e) if e.sol_amount >= 100 => large += 1,
+            PumpFunCreate(_) => creates += 1,
+            _ => other += 1,
+        };
+        assert_eq!(drain_queue(&queue, route), 4);
+        assert!(queue.is_empty());
+        assert_eq!((trades, large, creates, other), (12, 1, 1, 2));
+    }
+}
@@ -0,0 +1,369 @@
+//! 测试夹具：按变体生成 `DexEvent` 实例，以及 JSON 序列化的 golden 文件测试
+//!
+//! golden 文件测试：每个变体生成一个所有字段都有值的实例，序列化结果与 `tests/golden/events/<变体名>.json` 比较，
+//! 字段改名、顺序变化或增删都会导致测试失败，避免悄悄破坏下游 JSON 消费方。
+//! 有意修改事件结构后，用 `UPDATE_GOLDEN=1 cargo test --lib golden` 重新生成 golden 文件。
+//!
+//! 实例由 [`Populate`] 生成：它是一个 serde `Deserializer`，按请求的类型依次给出确定的非零值，
+//! 因此不要求事件结构体实现 `Default`，新增变体也会自动覆盖。
+
+use super::events::{DexEvent, EventMetadata, PumpFunTradeEvent};
+use serde::de::value::{Error, StrDeserializer};
+use serde::de::{
+    self, DeserializeSeed, Deserializer, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+use serde::Deserialize;
+use solana_sdk::pubkey::Pubkey;
+use std::cell::Cell;
+use std::path::PathBuf;
+
+/// 确定性填充器：数值依次递增，Option 取 Some，序列取两个元素，枚举取 `variant` 指定的变体
+struct Populate<'a> {
+    counter: &'a Cell<u64>,
+    /// 下一个枚举使用的变体索引，内层枚举固定取第一个变体
+    variant: Option<usize>,
+    /// 记录最外层枚举的变体名列表
+    variants: &'a Cell<&'static [&'static str]>,
+}
+
+impl<'a> Populate<'a> {
+    fn next(&self) -> u64 {
+        let value = self.counter.get() + 1;
+        self.counter.set(value);
+        value
+    }
+
+    fn inner(&self) -> Populate<'a> {
+        Populate { counter: self.counter, variant: None, variants: self.variants }
+    }
+}
+
+macro_rules! populate_number {
+    ($($method:ident => $visit:ident as $ty:ty),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                visitor.$visit(self.next() as $ty)
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for Populate<'_> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Error> {
+        Err(de::Error::custom("Populate 无法推断自描述类型"))
+    }
+
+    populate_number! {
+        deserialize_i8 => visit_i8 as i8,
+        deserialize_i16 => visit_i16 as i16,
+        deserialize_i32 => visit_i32 as i32,
+        deserialize_i64 => visit_i64 as i64,
+        deserialize_i128 => visit_i128 as i128,
+        deserialize_u8 => visit_u8 as u8,
+        deserialize_u16 => visit_u16 as u16,
+        deserialize_u32 => visit_u32 as u32,
+        deserialize_u64 => visit_u64 as u64,
+        deserialize_u128 => visit_u128 as u128,
+        deserialize_f32 => visit_f32 as f32,
+        deserialize_f64 => visit_f64 as f64,
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_bool(self.next() % 2 == 1)
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_char((b'a' + (self.next() % 26) as u8) as char)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_string(format!("s{}", self.next()))
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_some(self.inner())
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self.inner())
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_seq(Elements { populate: self.inner(), remaining: 2 })
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_seq(Elements { populate: self.inner(), remaining: len })
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Error> {
+        Err(de::Error::custom("Populate 不支持 map 字段"))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_map(Fields { populate: self.inner(), fields })
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let index = match self.variant {
+            Some(index) => {
+                self.variants.set(variants);
+                index
+            }
+            None => 0,
+        };
+        let name = variants.get(index).ok_or_else(|| de::Error::custom("变体索引越界"))?;
+        visitor.visit_enum(Variant { populate: self.inner(), name })
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+}
+
+struct Elements<'a> {
+    populate: Populate<'a>,
+    remaining: usize,
+}
+
+impl<'de> SeqAccess<'de> for Elements<'_> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        seed.deserialize(self.populate.inner()).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+struct Fields<'a> {
+    populate: Populate<'a>,
+    fields: &'static [&'static str],
+}
+
+impl<'de> MapAccess<'de> for Fields<'_> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Error> {
+        let Some((field, rest)) = self.fields.split_first() else {
+            return Ok(None);
+        };
+        self.fields = rest;
+        let key: StrDeserializer<Error> = field.into_deserializer();
+        seed.deserialize(key).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        seed.deserialize(self.populate.inner())
+    }
+}
+
+struct Variant<'a> {
+    populate: Populate<'a>,
+    name: &'static str,
+}
+
+impl<'de, 'a> EnumAccess<'de> for Variant<'a> {
+    type Error = Error;
+    type Variant = Populate<'a>;
+
+    fn variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<(T::Value, Populate<'a>), Error> {
+        let key: StrDeserializer<Error> = self.name.into_deserializer();
+        Ok((seed.deserialize(key)?, self.populate))
+    }
+}
+
+impl<'de> VariantAccess<'de> for Populate<'_> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        seed.deserialize(self.inner())
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_struct("", fields, visitor)
+    }
+}
+
+/// 生成所有 `DexEvent` 变体的填充实例，返回 (变体名, 事件)
+pub(crate) fn populated_events() -> Vec<(&'static str, DexEvent)> {
+    let variants = Cell::new(&[] as &'static [&'static str]);
+    let mut events = Vec::new();
+    let mut index = 0;
+    loop {
+        // 每个变体从相同的计数开始，新增变体不会改变其它变体的 golden 文件
+        let counter = Cell::new(0);
+        let populate = Populate { counter: &counter, variant: Some(index), variants: &variants };
+        let event = DexEvent::deserialize(populate)
+            .unwrap_or_else(|e| panic!("填充变体 #{index} 失败: {e}"));
+        events.push((variants.get()[index], event));
+        index += 1;
+        if index == variants.get().len() {
+            return events;
+        }
+    }
+}
+
+/// 指定变体的"空"实例：所有数值为 0、布尔为 false、账户为 `Pubkey::default()`，
+/// 相当于日志未提供任何账户时解析出的事件（字符串保持填充值，单元枚举序列化为变体名）
+pub(crate) fn zeroed_event(variant: &str) -> Option<DexEvent> {
+    fn zero(value: &mut serde_json::Value) {
+        use serde_json::Value;
+        match value {
+            Value::Number(_) => *value = Value::from(0),
+            Value::Bool(b) => *b = false,
+            Value::Array(items) => items.iter_mut().for_each(zero),
+            // 外层 {变体名: 事件} 的键需要保留
+            Value::Object(fields) => fields.values_mut().for_each(zero),
+            Value::String(_) | Value::Null => {}
+        }
+    }
+
+    let (_, event) = populated_events().into_iter().find(|(name, _)| *name == variant)?;
+    let mut value = serde_json::to_value(event).ok()?;
+    zero(&mut value);
+    serde_json::from_value(value).ok()
+}
+
+/// 一分钟对应的微秒数
+pub(crate) const MINUTE_US: i64 = 60_000_000;
+
+/// `at_us` 时刻的 PumpFun 成交，代币数量固定为 SOL 数量的 10 倍
+pub(crate) fn trade(mint: Pubkey, user: Pubkey, is_buy: bool, sol: u64, at_us: i64) -> DexEvent {
+    DexEvent::PumpFunTrade(PumpFunTradeEvent {
+        metadata: EventMetadata { block_time_us: at_us, ..Default::default() },
+        mint,
+        user,
+        is_buy,
+        sol_amount: sol,
+        token_amount: sol * 10,
+        bonding_curve: Pubkey::new_unique(),
+        ..Default::default()
+    })
+}
+
+fn golden_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/golden/events")
+}
+
+#[test]
+fn test_dex_event_json_golden() {
+    let update = std::env::var_os("UPDATE_GOLDEN").is_some();
+    let dir = golden_dir();
+    if update {
+        std::fs::create_dir_all(&dir).unwrap();
+    }
+
+    let events = populated_events();
+    let mut mismatched = Vec::new();
+    for (name, event) in &events {
+        assert_eq!(event.event_type_name(), *name);
+        let json = serde_json::to_string_pretty(event).unwrap() + "\n";
+        let path = dir.join(format!("{name}.json"));
+        if update {
+            std::fs::write(&path, &json).unwrap();
+            continue;
+        }
+        match std::fs::read_to_string(&path) {
+            Ok(golden) if golden == json => {
+                // golden 文件也必须能反序列化回同一个事件
+                let parsed: DexEvent = serde_json::from_str(&golden).unwrap();
+                assert_eq!(serde_json::to_string_pretty(&parsed).unwrap() + "\n", golden);
+            }
+            Ok(_) => mismatched.push(format!("{name}: 序列化结果与 golden 文件不一致")),
+            Err(_) => mismatched.push(format!("{name}: 缺少 golden 文件 {}", path.display())),
+        }
+    }
+
+    // 删除的变体不应留下过期的 golden 文件
+    if !update {
+        for entry in std::fs::read_dir(&dir).unwrap() {
+            let file = entry.unwrap().file_name().into_string().unwrap();
+            let name = file.trim_end_matches(".json");
+            if !events.iter().any(|(variant, _)| *variant == name) {
+                mismatched.push(format!("{name}: golden 文件没有对应的变体"));
+            }
+        }
+    }
+
+    assert!(
+        mismatched.is_empty(),
+        "DexEvent JSON 格式发生变化（确认为有意修改后运行 UPDATE_GOLDEN=1 cargo test --lib golden 更新）:\n{}",
+        mismatched.join("\n")
+    );
+}
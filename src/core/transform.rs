@@ -0,0 +1,88 @@
+//! 事件转换钩子
+//!
+//! 在解析完成、投递之前对事件做轻量处理（脱敏字段、换算数量、丢弃不需要的事件等），
+//! 按部署定制时无需修改解析代码。闭包 `Fn(DexEvent) -> Option<DexEvent>` 可直接作为转换器。
+
+use super::events::DexEvent;
+use std::sync::Arc;
+
+pub trait Transformer: Send + Sync {
+    /// 返回 None 表示丢弃该事件
+    fn transform(&self, event: DexEvent) -> Option<DexEvent>;
+}
+
+impl<F> Transformer for F
+where
+    F: Fn(DexEvent) -> Option<DexEvent> + Send + Sync,
+{
+    #[inline]
+    fn transform(&self, event: DexEvent) -> Option<DexEvent> {
+        self(event)
+    }
+}
+
+/// 按注册顺序依次应用的转换器链
+#[derive(Clone, Default)]
+pub struct TransformerChain {
+    transformers: Vec<Arc<dyn Transformer>>,
+}
+
+impl TransformerChain {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, transformer: impl Transformer + 'static) {
+        self.transformers.push(Arc::new(transformer));
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.transformers.is_empty()
+    }
+
+    /// 依次应用，任一转换器丢弃事件即停止
+    #[inline]
+    pub fn apply(&self, event: DexEvent) -> Option<DexEvent> {
+        self.transformers.iter().try_fold(event, |event, t| t.transform(event))
+    }
+}
+
+impl std::fmt::Debug for TransformerChain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TransformerChain").field("len", &self.transformers.len()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::PumpFunTradeEvent;
+    use solana_sdk::pubkey::Pubkey;
+
+    #[test]
+    fn test_chain_applies_in_order_and_drops() {
+        let mut chain = TransformerChain::new();
+        chain.push(|mut event: DexEvent| {
+            if let DexEvent::PumpFunTrade(e) = &mut event {
+                e.user = Pubkey::default();
+            }
+            Some(event)
+        });
+        chain.push(|event: DexEvent| match &event {
+            DexEvent::PumpFunTrade(e) if e.sol_amount < 10 => None,
+            _ => Some(event),
+        });
+
+        let trade = |sol_amount| {
+            DexEvent::PumpFunTrade(PumpFunTradeEvent {
+                user: Pubkey::new_unique(),
+                sol_amount,
+                ..Default::default()
+            })
+        };
+        assert!(chain.apply(trade(1)).is_none());
+        let Some(DexEvent::PumpFunTrade(e)) = chain.apply(trade(100)) else { panic!() };
+        assert_eq!(e.user, Pubkey::default());
+    }
+}
@@ -8,7 +8,11 @@
 //! - 流式处理，立即回调
 //! - 分支预测提示
 
+use crate::core::clock::now_us;
 use crate::core::events::*;
+use crate::core::merger::{dedupe_events, DedupPolicy};
+use crate::logs::optimized_matcher::InvokeCursor;
+use crate::logs::LogDecodeContext;
 use solana_sdk::{pubkey::Pubkey, signature::Signature};
 use smallvec::{SmallVec, smallvec};
 
@@ -23,30 +27,55 @@ use smallvec::{SmallVec, smallvec};
 /// - block_time_us: 区块时间
 /// - program_id: 程序 ID
 ///
+/// 输出前按 [`DedupPolicy::Merge`] 去重，见 [`parse_transaction_events_with_dedup`]。
+///
 /// ## 零延迟优化
 /// - 使用 SmallVec<[DexEvent; 4]> 栈分配，大多数交易 ≤ 4 个事件
 /// - 预分配容量，避免动态扩容
 #[inline]  // 零延迟优化：内联
 pub fn parse_transaction_events(
+    instruction_data: &[u8],
+    accounts: &[Pubkey],
+    logs: &[String],
+    signature: Signature,
+    slot: u64,
+    tx_index: u64,
+    block_time_us: Option<i64>,
+    program_id: &Pubkey,
+) -> SmallVec<[DexEvent; 4]> {  // 零延迟优化：SmallVec 栈分配
+    parse_transaction_events_with_dedup(
+        instruction_data, accounts, logs, signature, slot, tx_index, block_time_us, program_id,
+        DedupPolicy::default(),
+    )
+}
+
+/// 同 [`parse_transaction_events`]，按 `policy` 处理重复事件
+///
+/// 事件按日志中的 invoke 行定位到所属指令（`outer_index` / `inner_index`），类型相同且位于同一指令的
+/// 事件视为重复，由 `policy` 决定保留哪一个：默认 `Merge` 以先出现的事件为基础合并后出现的事件。
+/// 日志中没有 invoke 行时无法定位指令，不做去重。
+#[allow(clippy::too_many_arguments)]
+pub fn parse_transaction_events_with_dedup(
     _instruction_data: &[u8],
     _accounts: &[Pubkey],
     logs: &[String],
     signature: Signature,
     slot: u64,
-    _tx_index: u64,
+    tx_index: u64,
     block_time_us: Option<i64>,
     _program_id: &Pubkey,
-) -> SmallVec<[DexEvent; 4]> {  // 零延迟优化：SmallVec 栈分配
+    policy: DedupPolicy,
+) -> SmallVec<[DexEvent; 4]> {
     let mut events = smallvec![];  // 栈分配，容量 4
 
     // 2. 解析日志事件 - 大多数日志会成功解析
-    for log in logs {
-        if let Some(log_event) = crate::logs::parse_log_unified(log, signature, slot, block_time_us) {
-            events.push(log_event);  // 热路径：成功解析
-        }
-        // 冷路径：解析失败，继续下一个
-    }
+    let located = crate::logs::with_decode_context(|ctx| {
+        for_each_log_event(ctx, logs, signature, slot, tx_index, block_time_us, |e| events.push(e))
+    });
 
+    if located {
+        dedupe_events(&mut events, policy);
+    }
     events
 }
 
@@ -60,20 +89,64 @@ pub fn parse_logs_only(
 ) -> SmallVec<[DexEvent; 4]> {  // 零延迟优化：SmallVec 栈分配
     let mut events = SmallVec::with_capacity(logs.len().min(4));  // 预分配容量
 
-    for log in logs {
-        if let Some(event) = crate::logs::parse_log_unified(log, signature, slot, block_time_us) {
-            events.push(event);
-        }
-    }
+    crate::logs::with_decode_context(|ctx| {
+        for_each_log_event(ctx, logs, signature, slot, 0, block_time_us, |e| events.push(e))
+    });
 
     events
 }
 
+/// 逐条解析日志事件：按调用栈归属输出程序（跳过其他程序的日志），并带上所属指令位置
+///
+/// 返回日志中是否出现 invoke 行，即事件位置是否可信。
+fn for_each_log_event(
+    ctx: &mut LogDecodeContext,
+    logs: &[String],
+    signature: Signature,
+    slot: u64,
+    tx_index: u64,
+    block_time_us: Option<i64>,
+    mut callback: impl FnMut(DexEvent),
+) -> bool {
+    let metadata = EventMetadata {
+        signature,
+        slot,
+        tx_index,
+        block_time_us: block_time_us.unwrap_or(0),
+        grpc_recv_us: now_us(),
+        ..Default::default()
+    };
+    let mut cursor = InvokeCursor::new();
+    let mut located = false;
+
+    for (i, log) in logs.iter().enumerate() {
+        located |= cursor.observe(log).is_some();
+        let program = cursor.program();
+        if let Some(mut event) =
+            crate::logs::parse_log_with_context(ctx, i, log, metadata, program, None, false)
+        {
+            if let Some(m) = event.metadata_mut() {
+                (m.outer_index, m.inner_index) = cursor.position();
+            }
+            callback(event);
+        }
+    }
+    located
+}
+
 /// 事件监听器 trait - 用户可以实现此 trait 来处理解析出的事件
 pub trait EventListener {
     fn on_dex_event(&self, event: &DexEvent);
 }
 
+/// 异步事件监听器 - 回调中可以写数据库、发网络请求，不阻塞解析线程
+///
+/// 通过 `YellowstoneGrpc::subscribe_dex_events_with_listener` 使用。
+#[async_trait::async_trait]
+pub trait AsyncEventListener: Send + Sync {
+    async fn on_event(&self, event: DexEvent);
+}
+
 /// 使用监听器解析交易的便捷函数
 pub fn parse_transaction_with_listener<T: EventListener>(
     instruction_data: &[u8],
@@ -105,10 +178,10 @@ pub fn parse_transaction_events_streaming<F>(
     logs: &[String],
     signature: Signature,
     slot: u64,
-    _tx_index: u64,
+    tx_index: u64,
     block_time_us: Option<i64>,
     _program_id: &Pubkey,
-    mut callback: F,
+    callback: F,
 ) where
     F: FnMut(DexEvent)
 {
@@ -119,12 +192,10 @@ pub fn parse_transaction_events_streaming<F>(
     //     callback(instr_event);  // 立即回调指令事件
     // }
 
-    // 2. 逐个解析日志事件 - 每个事件立即回调
-    for log in logs {
-        if let Some(log_event) = crate::logs::parse_log_unified(log, signature, slot, block_time_us) {
-            callback(log_event);  // 立即回调日志事件，不等待其他日志
-        }
-    }
+    // 2. 逐个解析日志事件 - 每个事件立即回调，不等待其他日志
+    // 回调中可能再次解析，不使用线程共享的解码上下文
+    let mut ctx = LogDecodeContext::new();
+    for_each_log_event(&mut ctx, logs, signature, slot, tx_index, block_time_us, callback);
 
     // 注意：这里完全不做事件合并和缓存，确保每个事件都是立即回调
     // 回调顺序：先指令事件，然后按日志顺序回调日志事件
@@ -136,15 +207,12 @@ pub fn parse_logs_streaming<F>(
     signature: Signature,
     slot: u64,
     block_time_us: Option<i64>,
-    mut callback: F,
+    callback: F,
 ) where
     F: FnMut(DexEvent)
 {
-    for log in logs {
-        if let Some(event) = crate::logs::parse_log_unified(log, signature, slot, block_time_us) {
-            callback(event);
-        }
-    }
+    let mut ctx = LogDecodeContext::new();
+    for_each_log_event(&mut ctx, logs, signature, slot, 0, block_time_us, callback);
 }
 
 /// 流式事件监听器 trait - 适用于流式处理
@@ -175,4 +243,52 @@ pub fn parse_transaction_with_streaming_listener<T: StreamingEventListener>(
         program_id,
         |event| listener.on_dex_event_streaming(event)
     );
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+    #[test]
+    fn test_log_events_are_attributed_to_invoking_program() {
+        let mut trade = vec![189, 219, 127, 211, 78, 230, 97, 238];
+        trade.resize(8 + 256, 0);
+        let data = format!("Program data: {}", STANDARD.encode(&trade));
+        let logs_of = |program: &str| {
+            vec![
+                format!("Program {} invoke [1]", program),
+                data.clone(),
+                format!("Program {} success", program),
+            ]
+        };
+        let program_id = Pubkey::default();
+        let parse = |logs: &[String]| {
+            parse_transaction_events(&[], &[], logs, Signature::default(), 1, 0, None, &program_id)
+        };
+
+        let events = parse(&logs_of("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P"));
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].metadata().outer_index, 0);
+        // 其他程序输出的同 discriminator 日志被跳过
+        assert!(parse(&logs_of("11111111111111111111111111111111")).is_empty());
+    }
+
+    #[test]
+    fn test_clmm_logs_attributed_under_mainnet_program_id() {
+        use crate::logs::optimized_matcher::discriminators::RAYDIUM_CLMM_SWAP;
+
+        let mut swap = RAYDIUM_CLMM_SWAP.to_le_bytes().to_vec();
+        swap.resize(8 + 197, 0);
+        let program = "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK";
+        let logs = vec![
+            format!("Program {} invoke [1]", program),
+            format!("Program data: {}", STANDARD.encode(&swap)),
+            format!("Program {} success", program),
+        ];
+        let program_id = Pubkey::default();
+        let sig = Signature::default();
+        let events = parse_transaction_events(&[], &[], &logs, sig, 1, 0, None, &program_id);
+        assert!(matches!(events.as_slice(), [DexEvent::RaydiumClmmSwap(_)]), "{:?}", events);
+    }
+}
@@ -0,0 +1,202 @@
+//! swap 数量校验
+//!
+//! 用交易 meta 中的 token 余额变化交叉检查解析出的 swap 数量，发现布局漂移等导致的异常数量。
+//! 对 swap 每一侧的代币，在该 mint 所有 token 账户的余额变化中取与事件数量最接近的值，
+//! 偏差超过 [`AmountValidation::tolerance_bps`] 时在事件之后输出 `DexEvent::ValidationWarning`，
+//! 或按 [`AmountValidation::drop_invalid`] 丢弃该事件。
+//!
+//! 只校验能确定 mint 的 swap（见 [`normalize_swap`]）；没有 token 余额记录的一侧（如 PumpFun 的原生 SOL）跳过。
+//! 同一交易中同一池子的多笔 swap 会合并余额变化，可能产生误报，容差需按手续费和路由情况设置。
+
+use super::events::{DexEvent, ValidationWarningEvent};
+use crate::analytics::{normalize_swap, PoolRegistry};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::str::FromStr;
+use yellowstone_grpc_proto::prelude::{TokenBalance, TransactionStatusMeta};
+
+/// 数量校验配置
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AmountValidation {
+    /// 允许的偏差（基点），需覆盖协议手续费
+    pub tolerance_bps: u64,
+    /// 不一致时丢弃事件（只输出警告），否则事件照常输出并附带警告
+    pub drop_invalid: bool,
+}
+
+impl Default for AmountValidation {
+    fn default() -> Self {
+        Self { tolerance_bps: 100, drop_invalid: false }
+    }
+}
+
+/// 只校验事件自带 mint 的 swap，共用一个空注册表
+static EMPTY_REGISTRY: Lazy<PoolRegistry> = Lazy::new(PoolRegistry::new);
+
+/// 各 mint 的 token 账户余额变化（绝对值）
+fn balance_deltas(meta: &TransactionStatusMeta) -> HashMap<Pubkey, Vec<u64>> {
+    fn amounts(balances: &[TokenBalance]) -> HashMap<u32, (&str, u64)> {
+        balances
+            .iter()
+            .filter_map(|b| {
+                let amount = b.ui_token_amount.as_ref()?.amount.parse().ok()?;
+                Some((b.account_index, (b.mint.as_str(), amount)))
+            })
+            .collect()
+    }
+    let pre = amounts(&meta.pre_token_balances);
+    let post = amounts(&meta.post_token_balances);
+
+    let mut deltas: HashMap<Pubkey, Vec<u64>> = HashMap::new();
+    for index in pre.keys().chain(post.keys().filter(|i| !pre.contains_key(i))) {
+        // 交易中关闭的账户（如 swap 后关闭的 WSOL ATA）只出现在 pre 中
+        let (mint, before) = match pre.get(index) {
+            Some(&entry) => entry,
+            None => (post[index].0, 0),
+        };
+        let after = post.get(index).map_or(0, |&(_, amount)| amount);
+        let Ok(mint) = Pubkey::from_str(mint) else { continue };
+        deltas.entry(mint).or_default().push(before.abs_diff(after));
+    }
+    deltas
+}
+
+fn deviation_bps(expected: u64, observed: u64) -> u64 {
+    (expected.abs_diff(observed) as u128 * 10_000 / expected.max(1) as u128) as u64
+}
+
+/// 校验 `events` 中的 swap 数量，按配置插入警告或丢弃事件，返回警告数
+///
+/// `meta` 中没有 token 余额记录时不做任何检查。
+pub fn validate_amounts(
+    events: &mut Vec<DexEvent>,
+    meta: &TransactionStatusMeta,
+    config: &AmountValidation,
+) -> usize {
+    let deltas = balance_deltas(meta);
+    if deltas.is_empty() {
+        return 0;
+    }
+    let mut warnings = 0;
+    let mut i = 0;
+    while i < events.len() {
+        let event = &events[i];
+        i += 1;
+        let Some(swap) = normalize_swap(event, &EMPTY_REGISTRY) else { continue };
+        // 取偏差最大的一侧
+        let worst = [(swap.input_mint, swap.input_amount), (swap.output_mint, swap.output_amount)]
+            .into_iter()
+            .filter(|&(_, amount)| amount > 0)
+            .filter_map(|(mint, expected)| {
+                let observed =
+                    *deltas.get(&mint)?.iter().min_by_key(|&&delta| delta.abs_diff(expected))?;
+                Some((mint, expected, observed, deviation_bps(expected, observed)))
+            })
+            .max_by_key(|&(.., deviation)| deviation);
+
+        let Some((mint, expected, observed, deviation_bps)) = worst else { continue };
+        if deviation_bps <= config.tolerance_bps {
+            continue;
+        }
+        warnings += 1;
+        let warning = DexEvent::ValidationWarning(ValidationWarningEvent {
+            metadata: *event.metadata(),
+            event_type: event.event_type_name().to_string(),
+            pool: swap.pool,
+            mint,
+            expected,
+            observed,
+            deviation_bps,
+            dropped: config.drop_invalid,
+        });
+        // 警告紧跟在事件之后，或替换被丢弃的事件；警告很少，原地插入即可
+        if config.drop_invalid {
+            events[i - 1] = warning;
+        } else {
+            events.insert(i, warning);
+            i += 1;
+        }
+    }
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytics::WSOL_MINT;
+    use crate::core::events::PumpSwapBuyEvent;
+    use yellowstone_grpc_proto::prelude::UiTokenAmount;
+
+    fn balance(account_index: u32, mint: &Pubkey, amount: u64) -> TokenBalance {
+        TokenBalance {
+            account_index,
+            mint: mint.to_string(),
+            ui_token_amount: Some(UiTokenAmount {
+                amount: amount.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_amounts_against_balances() {
+        let (pool, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let buy = |base_amount_out| {
+            DexEvent::PumpSwapBuy(PumpSwapBuyEvent {
+                pool,
+                base_mint: mint,
+                quote_mint: WSOL_MINT,
+                base_amount_out,
+                user_quote_amount_in: 1_000,
+                ..Default::default()
+            })
+        };
+        // 用户 WSOL 账户 -1000，代币账户 +5000（新建），池子代币金库 -5000
+        let meta = TransactionStatusMeta {
+            pre_token_balances: vec![balance(1, &WSOL_MINT, 3_000), balance(3, &mint, 9_000)],
+            post_token_balances: vec![
+                balance(1, &WSOL_MINT, 2_000),
+                balance(2, &mint, 5_000),
+                balance(3, &mint, 4_000),
+            ],
+            ..Default::default()
+        };
+
+        let mut events = vec![buy(5_000), buy(5_030), buy(9_000)];
+        let config = AmountValidation { tolerance_bps: 100, drop_invalid: false };
+        assert_eq!(validate_amounts(&mut events, &meta, &config), 1);
+        assert_eq!(events.len(), 4);
+        let DexEvent::ValidationWarning(w) = &events[3] else { panic!("{:?}", events[3]) };
+        assert_eq!((w.pool, w.mint, w.expected, w.observed), (pool, mint, 9_000, 5_000));
+        assert_eq!(w.deviation_bps, 4_444);
+        assert_eq!(w.event_type, "PumpSwapBuy");
+
+        let mut events = vec![buy(9_000), buy(5_000)];
+        let config = AmountValidation { tolerance_bps: 0, drop_invalid: true };
+        assert_eq!(validate_amounts(&mut events, &meta, &config), 1);
+        assert!(
+            matches!(&events[..], [DexEvent::ValidationWarning(w), DexEvent::PumpSwapBuy(_)] if w.dropped)
+        );
+
+        // 交易中关闭的账户只出现在 pre 中：WSOL ATA 3000 -> 关闭
+        let closed = TransactionStatusMeta {
+            pre_token_balances: vec![balance(1, &WSOL_MINT, 3_000), balance(3, &mint, 9_000)],
+            post_token_balances: vec![balance(3, &mint, 4_000)],
+            ..Default::default()
+        };
+        let mut events = vec![buy(5_000)];
+        let config = AmountValidation { tolerance_bps: 100, drop_invalid: false };
+        assert_eq!(validate_amounts(&mut events, &closed, &config), 1);
+        let DexEvent::ValidationWarning(w) = &events[1] else { panic!("{:?}", events[1]) };
+        assert_eq!((w.mint, w.expected, w.observed), (WSOL_MINT, 1_000, 3_000));
+
+        // 没有余额记录时不检查
+        let mut events = vec![buy(9_000)];
+        assert_eq!(validate_amounts(&mut events, &TransactionStatusMeta::default(), &config), 0);
+        assert_eq!(events.len(), 1);
+    }
+}
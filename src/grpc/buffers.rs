@@ -83,6 +83,16 @@ impl SlotBuffer {
         result
     }
 
+    /// 输出指定 slot 的全部事件（按 tx_index 稳定排序，同一交易内保持解析顺序）
+    ///
+    /// 用于 block meta 确认 slot 完整后的按 slot 批次输出
+    pub fn flush_slot(&mut self, slot: u64) -> Vec<DexEvent> {
+        let Some(mut events) = self.slots.remove(&slot) else { return Vec::new() };
+        events.sort_by_key(|(idx, _)| *idx);
+        self.last_flush_time = Some(Instant::now());
+        events.into_iter().map(|(_, e)| e).collect()
+    }
+
     /// 检查是否超时
     #[inline]
     pub fn should_timeout(&self, timeout_ms: u64) -> bool {
@@ -0,0 +1,201 @@
+//! 事件流断点与恢复
+//!
+//! 客户端记录最后一个完整投递的 slot（[`Checkpoint`]），通过可替换的 [`CheckpointStore`] 持久化。
+//! 重启或重连后从断点 slot 重新订阅（gRPC `from_slot`，该 slot 会被重放一次）；
+//! 服务端不支持时发出 `ClientEvent::Resume { replayed: false, .. }`，由调用方用
+//! [`crate::rpc_parser::backfill_since_checkpoint`] 通过 RPC 补齐缺口。
+//!
+//! 投递指回调返回：直接回调、微批次和按 slot 回调的订阅在回调返回后推进断点，此时断点之前的事件
+//! 至少投递一次。队列输出入队后何时被消费（或被 `DropOldest` 挤出）无从得知，由消费者处理完事件后
+//! 调用 `YellowstoneGrpc::ack` 推进断点。
+//!
+//! 逐事件投递时，某个 slot 在投递了更新 slot 的事件后才视为完整；无序模式下乱序到达的旧 slot 事件
+//! 不会回退断点。
+
+use crate::core::events::EventMetadata;
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+use solana_sdk::signature::Signature;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// 最后一个完整投递的 slot 及其中最后投递的交易签名（按 slot 批次投递时为默认值）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub slot: u64,
+    pub signature: Signature,
+}
+
+/// 断点持久化接口，`save` 在事件流任务上同步调用，实现应尽量轻量
+pub trait CheckpointStore: Send + Sync {
+    fn load(&self) -> Option<Checkpoint>;
+    fn save(&self, checkpoint: &Checkpoint) -> std::io::Result<()>;
+}
+
+/// 进程内断点，只在重连之间保持
+#[derive(Debug, Default)]
+pub struct MemoryCheckpointStore {
+    checkpoint: RwLock<Option<Checkpoint>>,
+}
+
+impl MemoryCheckpointStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CheckpointStore for MemoryCheckpointStore {
+    fn load(&self) -> Option<Checkpoint> {
+        *self.checkpoint.read()
+    }
+
+    fn save(&self, checkpoint: &Checkpoint) -> std::io::Result<()> {
+        *self.checkpoint.write() = Some(*checkpoint);
+        Ok(())
+    }
+}
+
+/// 以 JSON 文件保存断点，先写临时文件再重命名，避免进程中断留下半个文件
+#[derive(Debug, Clone)]
+pub struct FileCheckpointStore {
+    path: PathBuf,
+}
+
+impl FileCheckpointStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl CheckpointStore for FileCheckpointStore {
+    fn load(&self) -> Option<Checkpoint> {
+        let data = std::fs::read(&self.path).ok()?;
+        serde_json::from_slice(&data)
+            .map_err(|e| log::warn!("invalid checkpoint file {}: {}", self.path.display(), e))
+            .ok()
+    }
+
+    fn save(&self, checkpoint: &Checkpoint) -> std::io::Result<()> {
+        let tmp = self.path.with_extension("tmp");
+        std::fs::write(&tmp, serde_json::to_vec(checkpoint)?)?;
+        std::fs::rename(&tmp, &self.path)
+    }
+}
+
+#[derive(Debug, Default)]
+struct TrackerState {
+    /// 正在投递的 slot 及其最后投递的签名
+    current: Option<Checkpoint>,
+    /// 已完整投递的断点
+    committed: Option<Checkpoint>,
+}
+
+/// 跟踪投递进度并在 slot 完整时写入存储
+pub(crate) struct CheckpointTracker {
+    store: Arc<dyn CheckpointStore>,
+    state: Mutex<TrackerState>,
+    /// 服务端拒绝 `from_slot` 后不再使用
+    from_slot_unsupported: AtomicBool,
+}
+
+impl CheckpointTracker {
+    pub(crate) fn new(store: Arc<dyn CheckpointStore>) -> Self {
+        let committed = store.load();
+        Self {
+            store,
+            state: Mutex::new(TrackerState { current: None, committed }),
+            from_slot_unsupported: AtomicBool::new(false),
+        }
+    }
+
+    /// 当前断点（已完整投递的 slot）
+    pub(crate) fn checkpoint(&self) -> Option<Checkpoint> {
+        self.state.lock().committed
+    }
+
+    /// 记录一个已投递的事件；不带 slot 的事件（如部分账户更新）忽略
+    pub(crate) fn on_delivered(&self, metadata: &EventMetadata) {
+        if metadata.slot == 0 {
+            return;
+        }
+        let completed = {
+            let mut state = self.state.lock();
+            let next = Checkpoint { slot: metadata.slot, signature: metadata.signature };
+            match state.current {
+                Some(current) if metadata.slot < current.slot => return,
+                Some(current) if metadata.slot == current.slot => {
+                    if metadata.signature != Signature::default() {
+                        state.current = Some(next);
+                    }
+                    return;
+                }
+                previous => {
+                    state.current = Some(next);
+                    previous
+                }
+            }
+        };
+        if let Some(completed) = completed {
+            self.commit(completed);
+        }
+    }
+
+    /// 整个 slot 已投递（按 slot 批次回调之后）
+    pub(crate) fn commit(&self, checkpoint: Checkpoint) {
+        {
+            let mut state = self.state.lock();
+            if state.committed.is_some_and(|c| c.slot >= checkpoint.slot) {
+                return;
+            }
+            state.committed = Some(checkpoint);
+        }
+        if let Err(e) = self.store.save(&checkpoint) {
+            log::warn!("failed to save checkpoint at slot {}: {}", checkpoint.slot, e);
+        }
+    }
+
+    /// 重新订阅时使用的 `from_slot`
+    pub(crate) fn resume_slot(&self) -> Option<u64> {
+        if self.from_slot_unsupported.load(Ordering::Relaxed) {
+            return None;
+        }
+        self.checkpoint().map(|c| c.slot)
+    }
+
+    pub(crate) fn disable_from_slot(&self) {
+        self.from_slot_unsupported.store(true, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn delivered(tracker: &CheckpointTracker, slot: u64, sig: u8) {
+        let signature = Signature::from([sig; 64]);
+        tracker.on_delivered(&EventMetadata { slot, signature, ..Default::default() });
+    }
+
+    #[test]
+    fn test_checkpoint_commits_completed_slots() {
+        let store = Arc::new(MemoryCheckpointStore::new());
+        let tracker = CheckpointTracker::new(store.clone());
+        delivered(&tracker, 100, 1);
+        delivered(&tracker, 100, 2);
+        assert_eq!(tracker.checkpoint(), None);
+
+        // 出现更新的 slot 后，100 视为完整；乱序的旧 slot 不回退断点
+        delivered(&tracker, 101, 3);
+        delivered(&tracker, 99, 4);
+        let expected = Checkpoint { slot: 100, signature: Signature::from([2; 64]) };
+        assert_eq!((tracker.checkpoint(), store.load()), (Some(expected), Some(expected)));
+        assert_eq!(tracker.resume_slot(), Some(100));
+
+        // 重启后从存储恢复
+        let restarted = CheckpointTracker::new(store);
+        assert_eq!(restarted.checkpoint(), Some(expected));
+        restarted.disable_from_slot();
+        assert_eq!(restarted.resume_slot(), None);
+    }
+}
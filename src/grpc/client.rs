@@ -5,28 +5,240 @@
 //! - MicroBatch: 50-200μs 微批次有序
 //! - StreamingOrdered: 0.1-5ms 流式有序
 //! - Ordered: 1-50ms 完全有序
+//!
+//! 另外提供按 slot 批次回调（`subscribe_slot_events`），在 block meta 确认 slot 完整后触发；
+//! 直接回调模式（`subscribe_dex_events_direct`）在流任务上同步回调，不经过输出队列。
 
 use super::buffers::{MicroBatchBuffer, SlotBuffer};
+use super::checkpoint::{Checkpoint, CheckpointStore, CheckpointTracker};
+use super::instruction_parser::TxContext;
+use super::leader::{leader_from_rewards, LeaderSchedule};
+use super::priority_fee::{compute_unit_price, PriorityFeeAggregator};
+use super::status::{ClientStats, ClientStatus, SubscriptionState, TransactionOutcome};
+use super::thread_tuning::tune_current_thread;
 use super::types::*;
-use crate::core::{now_micros, EventMetadata}; // 导入高性能时钟
+use crate::analytics::{insert_pool_created, MigrationLinker};
+use crate::block_parser::SlotEvents;
+use crate::capabilities::PROTOCOL_REGISTRY;
+use crate::core::events::{
+    BlockMetaEvent, FirstSwapInSlotEvent, ParseTruncatedEvent, RawDataEvent, RawSource,
+    TransactionAccountsEvent, TruncateReason,
+};
+use crate::core::{
+    now_micros, validate_amounts, AsyncEventListener, EventMetadata, ExecutionHook, ExecutionHooks,
+    ExecutionPattern, TransactionAccounts, TransactionAccountsCache, TransactionKeys, Transformer,
+    TransformerChain,
+}; // 导入高性能时钟
 use crate::instr::read_pubkey_fast;
 use crate::logs::timestamp_to_microseconds;
 use crate::DexEvent;
 use crossbeam_queue::ArrayQueue;
-use futures::{SinkExt, StreamExt};
-use log::error;
-use memchr::memmem;
-use once_cell::sync::Lazy;
+use futures::{FutureExt, SinkExt, StreamExt};
+use log::{error, warn};
+use solana_sdk::pubkey::Pubkey;
 use std::collections::HashMap;
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
-use tokio::sync::{mpsc, Mutex};
+use tokio::sync::{broadcast, mpsc, Mutex, Semaphore};
+use tokio::task::{JoinHandle, JoinSet};
 use tokio::time::{Duration, Instant};
 use tonic::transport::ClientTlsConfig;
-use yellowstone_grpc_client::GeyserGrpcClient;
+use yellowstone_grpc_client::{GeyserGrpcClient, Interceptor};
 use yellowstone_grpc_proto::prelude::*;
 
-static PROGRAM_DATA_FINDER: Lazy<memmem::Finder> =
-    Lazy::new(|| memmem::Finder::new(b"Program data: "));
+/// 按 slot 批次模式下最多保留的未确认 slot 数，更早的 slot（分叉/跳过）直接丢弃
+const MAX_PENDING_SLOTS: u64 = 64;
+
+/// 外部接收时间戳来源（微秒），返回 None 时退回内部时钟，见 [`YellowstoneGrpc::with_recv_clock`]
+pub type RecvClock = Arc<dyn Fn(&SubscribeUpdate) -> Option<i64> + Send + Sync>;
+
+// ==================== 输出队列 ====================
+
+/// 按 pool/mint 分片的输出队列，单分片时等价于一个 `ArrayQueue`
+struct OutputQueues {
+    shards: Vec<Arc<ArrayQueue<DexEvent>>>,
+}
+
+impl OutputQueues {
+    /// 创建 `shards` 个分片，每个分片容量为 `capacity`
+    fn new(capacity: usize, shards: usize) -> Self {
+        let shards =
+            (0..shards.max(1)).map(|_| Arc::new(ArrayQueue::new(capacity.max(1)))).collect();
+        Self { shards }
+    }
+
+    /// 选择事件所属分片
+    #[inline]
+    fn select(&self, event: &DexEvent) -> &ArrayQueue<DexEvent> {
+        &self.shards[shard_index(event, self.shards.len())]
+    }
+}
+
+/// 直接回调，流任务独占调用；用锁满足 `&self` 访问，无竞争时开销可忽略
+type DirectCallback = parking_lot::Mutex<Box<dyn FnMut(DexEvent) + Send>>;
+
+/// 微批次回调，流任务独占调用
+type BatchCallback = Box<dyn FnMut(Vec<DexEvent>) + Send>;
+
+/// 按 slot 边界和时间窗口攒批的输出
+struct BatchedOutput {
+    max_delay: Duration,
+    state: parking_lot::Mutex<BatchState>,
+}
+
+struct BatchState {
+    events: Vec<DexEvent>,
+    /// 当前批次的 slot
+    slot: u64,
+    /// 当前批次第一个事件到达时间 + `max_delay`，批次为空时为 None
+    deadline: Option<Instant>,
+    on_batch: BatchCallback,
+}
+
+impl BatchedOutput {
+    fn new(max_delay: Duration, on_batch: BatchCallback) -> Self {
+        Self {
+            max_delay,
+            state: parking_lot::Mutex::new(BatchState {
+                events: Vec::new(),
+                slot: 0,
+                deadline: None,
+                on_batch,
+            }),
+        }
+    }
+}
+
+/// 事件流的输出目标
+enum EventOutput {
+    Queues(OutputQueues),
+    Direct(DirectCallback),
+    Batched(BatchedOutput),
+}
+
+impl EventOutput {
+    /// 微批次输出下当前批次的交付时间
+    fn batch_deadline(&self) -> Option<Instant> {
+        match self {
+            EventOutput::Batched(batched) => batched.state.lock().deadline,
+            _ => None,
+        }
+    }
+}
+
+/// 计算事件的分片下标：优先按 pool，其次按 mint，都没有时为 0
+///
+/// Pubkey 本身均匀分布，直接取前 8 字节作为哈希值。
+#[inline]
+fn shard_index(event: &DexEvent, shards: usize) -> usize {
+    if shards <= 1 {
+        return 0;
+    }
+    let Some(key) = event.pool().or_else(|| event.mint()) else { return 0 };
+    let bytes = key.to_bytes();
+    let hash = u64::from_le_bytes(bytes[..8].try_into().unwrap());
+    (hash % shards as u64) as usize
+}
+
+/// 被跟踪池子最近一次出现 swap 的 slot，用于检测 slot 边界并插入 `DexEvent::FirstSwapInSlot`
+#[derive(Default)]
+struct FirstSwapTracker {
+    last_slots: parking_lot::Mutex<HashMap<Pubkey, u64>>,
+    enabled: bool,
+}
+
+impl FirstSwapTracker {
+    fn new(pools: impl IntoIterator<Item = Pubkey>) -> Self {
+        let last_slots: HashMap<Pubkey, u64> = pools.into_iter().map(|pool| (pool, 0)).collect();
+        let enabled = !last_slots.is_empty();
+        Self { last_slots: parking_lot::Mutex::new(last_slots), enabled }
+    }
+
+    /// 在被跟踪池子进入新 slot 后的第一笔 swap 之前插入标记；早于已见 slot 的乱序交易不标记
+    fn mark(&self, events: &mut Vec<DexEvent>) {
+        if !self.enabled {
+            return;
+        }
+        let mut last_slots = self.last_slots.lock();
+        let mut i = 0;
+        while i < events.len() {
+            let event = &events[i];
+            let marker = event
+                .pool()
+                .filter(|_| event.is_swap())
+                .and_then(|pool| Some((pool, last_slots.get_mut(&pool)?)))
+                .filter(|(_, last)| event.metadata().slot > **last)
+                .map(|(pool, last)| {
+                    let previous_slot = std::mem::replace(last, event.metadata().slot);
+                    FirstSwapInSlotEvent { metadata: *event.metadata(), pool, previous_slot }
+                });
+            if let Some(marker) = marker {
+                events.insert(i, DexEvent::FirstSwapInSlot(marker));
+                i += 1;
+            }
+            i += 1;
+        }
+    }
+}
+
+/// `from_slot` 历史重放阶段：订阅时实时 slot 之前的事件标记为 `replayed`
+#[derive(Default)]
+struct ReplayState {
+    /// 订阅时的实时 slot，0 表示不在重放阶段
+    live_slot: AtomicU64,
+    /// `ClientConfig::from_slot` 只用于首次订阅
+    initial_used: AtomicBool,
+}
+
+impl ReplayState {
+    fn mark(&self, slot: u64, events: &mut [DexEvent]) {
+        if slot >= self.live_slot.load(Ordering::Relaxed) {
+            return;
+        }
+        for event in events {
+            if let Some(metadata) = event.metadata_mut() {
+                metadata.replayed = true;
+            }
+        }
+    }
+
+    /// 收到不早于实时 slot 的更新时结束重放，返回结束时的实时 slot
+    fn observe(&self, slot: Option<u64>) -> Option<u64> {
+        let live_slot = self.live_slot.load(Ordering::Relaxed);
+        let slot = slot?;
+        if live_slot == 0 || slot < live_slot {
+            return None;
+        }
+        self.live_slot.compare_exchange(live_slot, 0, Ordering::Relaxed, Ordering::Relaxed).ok()
+    }
+}
+
+/// 交易的过滤分类，见 `ClientConfig::transaction_stats`
+///
+/// 被调用的程序只能位于静态账户表中，因此只检查 `account_keys`。
+fn transaction_outcome(tx: &SubscribeUpdateTransaction, events: &[DexEvent]) -> TransactionOutcome {
+    let Some(info) = &tx.transaction else { return TransactionOutcome::NoEvents };
+    let known_program = || {
+        let keys = info.transaction.as_ref().and_then(|t| t.message.as_ref());
+        keys.is_some_and(|message| {
+            message.account_keys.iter().any(|key| {
+                PROTOCOL_REGISTRY.iter().any(|p| p.program_id.as_ref() == key.as_slice())
+            })
+        })
+    };
+    if !events.is_empty() {
+        TransactionOutcome::WithEvents
+    } else if info.is_vote {
+        TransactionOutcome::Vote
+    } else if info.meta.as_ref().is_some_and(|meta| meta.err.is_some()) {
+        TransactionOutcome::Failed
+    } else if !known_program() {
+        TransactionOutcome::NoKnownProgram
+    } else {
+        TransactionOutcome::NoEvents
+    }
+}
 
 // ==================== YellowstoneGrpc 客户端 ====================
 
@@ -36,6 +248,17 @@ pub struct YellowstoneGrpc {
     token: Option<String>,
     config: ClientConfig,
     control_tx: Arc<Mutex<Option<mpsc::Sender<SubscribeRequest>>>>,
+    client_events: broadcast::Sender<ClientEvent>,
+    status: Arc<ClientStatus>,
+    transformers: TransformerChain,
+    execution_hooks: ExecutionHooks,
+    leader_schedule: Option<Arc<LeaderSchedule>>,
+    tx_accounts: Arc<TransactionAccountsCache>,
+    first_swap: Arc<FirstSwapTracker>,
+    migrations: Option<Arc<parking_lot::Mutex<MigrationLinker>>>,
+    checkpoint: Option<Arc<CheckpointTracker>>,
+    replay: Arc<ReplayState>,
+    recv_clock: Option<RecvClock>,
 }
 
 impl YellowstoneGrpc {
@@ -43,13 +266,7 @@ impl YellowstoneGrpc {
         endpoint: String,
         token: Option<String>,
     ) -> Result<Self, Box<dyn std::error::Error>> {
-        crate::warmup::warmup_parser();
-        Ok(Self {
-            endpoint,
-            token,
-            config: ClientConfig::default(),
-            control_tx: Arc::new(Mutex::new(None)),
-        })
+        Self::new_with_config(endpoint, token, ClientConfig::default())
     }
 
     pub fn new_with_config(
@@ -58,21 +275,223 @@ impl YellowstoneGrpc {
         config: ClientConfig,
     ) -> Result<Self, Box<dyn std::error::Error>> {
         crate::warmup::warmup_parser();
-        Ok(Self { endpoint, token, config, control_tx: Arc::new(Mutex::new(None)) })
+        let (client_events, _) = broadcast::channel(64);
+        Ok(Self {
+            endpoint,
+            token,
+            control_tx: Arc::new(Mutex::new(None)),
+            client_events,
+            status: Arc::new(ClientStatus::default()),
+            transformers: TransformerChain::new(),
+            execution_hooks: ExecutionHooks::new(),
+            leader_schedule: None,
+            tx_accounts: Arc::new(TransactionAccountsCache::with_config(
+                config.transaction_accounts_limits(),
+            )),
+            first_swap: Arc::new(FirstSwapTracker::default()),
+            migrations: None,
+            checkpoint: None,
+            replay: Arc::new(ReplayState::default()),
+            recv_clock: None,
+            config,
+        })
+    }
+
+    /// 追加事件转换器，按添加顺序在解析后、投递前依次应用
+    pub fn with_transformer(mut self, transformer: impl Transformer + 'static) -> Self {
+        self.transformers.push(transformer);
+        self
+    }
+
+    /// 注册跟单执行钩子：匹配 `pattern` 的事件在解析完成后、转换器和输出队列之前同步回调，
+    /// 断点恢复重放的事件默认不触发，见 [`crate::core::execution`]
+    pub fn with_execution_hook(
+        mut self,
+        pattern: ExecutionPattern,
+        hook: impl ExecutionHook + 'static,
+    ) -> Self {
+        self.execution_hooks.push(pattern, hook);
+        self
+    }
+
+    /// 注入 leader schedule，block meta 中没有手续费奖励时用它确定 slot leader
+    pub fn with_leader_schedule(mut self, schedule: Arc<LeaderSchedule>) -> Self {
+        self.leader_schedule = Some(schedule);
+        self
+    }
+
+    /// 跟踪这些池子的 slot 边界：池子在新 slot 中的第一笔 swap 之前输出 `DexEvent::FirstSwapInSlot`
+    ///
+    /// 标记在解析时立即生成，不等待 slot 结束，可用于触发重新报价。
+    pub fn with_first_swap_pools(mut self, pools: impl IntoIterator<Item = Pubkey>) -> Self {
+        self.first_swap = Arc::new(FirstSwapTracker::new(pools));
+        self
+    }
+
+    /// 关联迁移事件（PumpFun → PumpSwap、Bonk → Raydium）与目标 DEX 的建池事件，
+    /// 输出 `DexEvent::MigrationCompleted`，见 [`MigrationLinker`]
+    ///
+    /// `max_slot_gap`：迁移后最多等待多少个 slot 的建池事件。
+    pub fn with_migration_linking(mut self, max_slot_gap: u64) -> Self {
+        let linker = MigrationLinker::new(max_slot_gap);
+        self.migrations = Some(Arc::new(parking_lot::Mutex::new(linker)));
+        self
+    }
+
+    /// 记录投递进度并在重连 / 重启时从断点恢复，见 [`super::checkpoint`]
+    ///
+    /// 存储中已有断点时，首次订阅即从该断点恢复。直接回调、微批次和按 slot 回调的订阅在回调返回后
+    /// 推进断点；队列输出（如 `subscribe_dex_events`）无法得知事件何时被消费，需由消费者调用
+    /// [`ack`](Self::ack) 确认。
+    pub fn with_checkpoint_store(mut self, store: Arc<dyn CheckpointStore>) -> Self {
+        self.checkpoint = Some(Arc::new(CheckpointTracker::new(store)));
+        self
+    }
+
+    /// 使用外部测得的接收时间（如内核旁路抓包或 relayer 时间戳）作为事件的 `grpc_recv_us`
+    ///
+    /// 每条更新在解析前调用一次，返回 None 时使用内部时钟。时间基准须与 `now_micros` 一致（Unix 微秒），
+    /// 否则延迟统计无意义。
+    pub fn with_recv_clock(
+        mut self,
+        clock: impl Fn(&SubscribeUpdate) -> Option<i64> + Send + Sync + 'static,
+    ) -> Self {
+        self.recv_clock = Some(Arc::new(clock));
+        self
+    }
+
+    /// 确认队列输出中的事件已处理完毕，推进断点
+    ///
+    /// 消费者按出队顺序对每个处理完的事件调用；未配置断点存储时不做任何事。
+    pub fn ack(&self, metadata: &EventMetadata) {
+        if let Some(tracker) = &self.checkpoint {
+            tracker.on_delivered(metadata);
+        }
+    }
+
+    /// 当前断点：最后一个完整投递的 slot，未配置断点存储时为 None
+    pub fn checkpoint(&self) -> Option<Checkpoint> {
+        self.checkpoint.as_ref()?.checkpoint()
+    }
+
+    pub fn config(&self) -> &ClientConfig {
+        &self.config
+    }
+
+    /// 客户端运行状态（连接、计数器、当前过滤器）
+    pub fn status(&self) -> Arc<ClientStatus> {
+        Arc::clone(&self.status)
+    }
+
+    /// 当前统计快照：事件计数、订阅过滤器、运行时间、重连次数与最新 slot
+    pub fn stats(&self) -> ClientStats {
+        self.status.stats()
+    }
+
+    /// 按签名查询最近交易的完整账户表（含 ALT 解析出的账户及可写 / 签名标记）
+    ///
+    /// 需设置 `ClientConfig::transaction_accounts_cache`，只包含产出过事件且仍在缓存中的交易。
+    pub fn transaction_accounts(
+        &self,
+        signature: &solana_sdk::signature::Signature,
+    ) -> Option<Arc<TransactionAccounts>> {
+        self.tx_accounts.get(signature)
+    }
+
+    /// 订阅客户端状态通知（如 [`ClientEvent::StaleStream`]）
+    pub fn subscribe_client_events(&self) -> broadcast::Receiver<ClientEvent> {
+        self.client_events.subscribe()
     }
 
     /// 订阅 DEX 事件（自动重连）
+    ///
+    /// 配置了断点存储时，处理完出队的事件后调用 [`ack`](Self::ack) 推进断点。
     pub async fn subscribe_dex_events(
         &self,
         transaction_filters: Vec<TransactionFilter>,
         account_filters: Vec<AccountFilter>,
         event_type_filter: Option<EventTypeFilter>,
     ) -> Result<Arc<ArrayQueue<DexEvent>>, Box<dyn std::error::Error>> {
-        let queue = Arc::new(ArrayQueue::new(100_000));
-        let queue_clone = Arc::clone(&queue);
+        let queues = OutputQueues::new(self.config.queue_capacity, 1);
+        let queue = Arc::clone(&queues.shards[0]);
+        let output = EventOutput::Queues(queues);
+        self.spawn_stream(transaction_filters, account_filters, event_type_filter, output);
+        Ok(queue)
+    }
+
+    /// 订阅 DEX 事件并按 pool（无 pool 时按 mint）分片输出（自动重连）
+    ///
+    /// 分片数由 `output_shards` 决定，事件按 `hash(pool) % N` 写入对应队列，
+    /// 同一市场的事件始终落在同一分片并保持输出顺序；不带 pool/mint 的事件写入分片 0。
+    /// 每个分片可由独立线程消费，分片之间不保证顺序。
+    pub async fn subscribe_dex_events_sharded(
+        &self,
+        transaction_filters: Vec<TransactionFilter>,
+        account_filters: Vec<AccountFilter>,
+        event_type_filter: Option<EventTypeFilter>,
+    ) -> Result<Vec<Arc<ArrayQueue<DexEvent>>>, Box<dyn std::error::Error>> {
+        let queues = OutputQueues::new(self.config.queue_capacity, self.config.output_shards);
+        let shards = queues.shards.clone();
+        let output = EventOutput::Queues(queues);
+        self.spawn_stream(transaction_filters, account_filters, event_type_filter, output);
+        Ok(shards)
+    }
+
+    /// 订阅 DEX 事件并在流任务上直接回调（自动重连）
+    ///
+    /// 事件解析后立即调用 `on_event`，省去 ArrayQueue 入队/出队的一跳，适合单消费者的超低延迟场景。
+    /// 代价：
+    /// - 回调同步执行，耗时直接阻塞接收与解析；积压过多时服务端可能断开连接，回调内不要做 IO 或阻塞等待
+    /// - 没有队列，`queue_capacity`、`output_shards`、`backpressure` 不生效，也不会丢弃事件
+    /// - 开启 `catch_panics` 时回调 panic 只记录日志，事件流继续
+    pub async fn subscribe_dex_events_direct<F>(
+        &self,
+        transaction_filters: Vec<TransactionFilter>,
+        account_filters: Vec<AccountFilter>,
+        event_type_filter: Option<EventTypeFilter>,
+        on_event: F,
+    ) -> Result<(), Box<dyn std::error::Error>>
+    where
+        F: FnMut(DexEvent) + Send + 'static,
+    {
+        let output = EventOutput::Direct(parking_lot::Mutex::new(Box::new(on_event)));
+        self.spawn_stream(transaction_filters, account_filters, event_type_filter, output);
+        Ok(())
+    }
+
+    /// 订阅 DEX 事件并按微批次回调（自动重连）
+    ///
+    /// 事件按 slot 攒批，slot 变化或批次中第一个事件等待满 `max_delay` 时以 `Vec<DexEvent>`
+    /// 调用一次 `on_batch`；每批只含同一 slot 的事件，批内保持输出顺序。下游按批写入数据库
+    /// 时吞吐远高于逐事件回调。与 [`Self::subscribe_dex_events_direct`] 相同，回调在流任务上
+    /// 同步执行，队列相关配置不生效；断线时先交付未满的批次。
+    pub async fn subscribe_dex_events_batched<F>(
+        &self,
+        transaction_filters: Vec<TransactionFilter>,
+        account_filters: Vec<AccountFilter>,
+        event_type_filter: Option<EventTypeFilter>,
+        max_delay: Duration,
+        on_batch: F,
+    ) -> Result<(), Box<dyn std::error::Error>>
+    where
+        F: FnMut(Vec<DexEvent>) + Send + 'static,
+    {
+        let output = EventOutput::Batched(BatchedOutput::new(max_delay, Box::new(on_batch)));
+        self.spawn_stream(transaction_filters, account_filters, event_type_filter, output);
+        Ok(())
+    }
+
+    /// 启动后台事件流任务，断线后按指数退避重连
+    fn spawn_stream(
+        &self,
+        transaction_filters: Vec<TransactionFilter>,
+        account_filters: Vec<AccountFilter>,
+        event_type_filter: Option<EventTypeFilter>,
+        output: EventOutput,
+    ) {
         let self_clone = self.clone();
 
-        tokio::spawn(async move {
+        self.spawn_stream_task(async move {
             let mut delay = 1u64;
             loop {
                 match self_clone
@@ -80,19 +499,116 @@ impl YellowstoneGrpc {
                         &transaction_filters,
                         &account_filters,
                         &event_type_filter,
-                        &queue_clone,
+                        &output,
                     )
                     .await
                 {
                     Ok(_) => delay = 1,
                     Err(e) => println!("❌ gRPC error: {} - retry in {}s", e, delay),
                 }
+                self_clone.status.on_disconnected();
                 tokio::time::sleep(Duration::from_secs(delay)).await;
                 delay = (delay * 2).min(60);
             }
         });
+    }
 
-        Ok(queue)
+    /// 运行事件流任务
+    ///
+    /// 配置了 `stream_cpu`、`stream_thread_priority` 或非 `Standard` 的 `latency_profile` 时
+    /// 在独立线程的单线程 runtime 上运行，先按配置绑核、调整优先级；否则交给当前 tokio runtime。
+    fn spawn_stream_task<F>(&self, task: F)
+    where
+        F: std::future::Future<Output = ()> + Send + 'static,
+    {
+        let (cpu, priority) = (self.config.stream_cpu, self.config.stream_thread_priority);
+        let profile = self.config.latency_profile;
+        if cpu.is_none() && priority.is_none() && profile == LatencyProfile::Standard {
+            tokio::spawn(task);
+            return;
+        }
+        let spawned =
+            std::thread::Builder::new().name("sol-parser-stream".to_string()).spawn(move || {
+                tune_current_thread(cpu, priority);
+                if let Err(e) = run_stream_runtime(profile, task) {
+                    error!("failed to build stream runtime: {}", e);
+                }
+            });
+        if let Err(e) = spawned {
+            error!("failed to spawn stream thread: {}", e);
+        }
+    }
+
+    /// 按 slot 批次订阅 DEX 事件（自动重连）
+    ///
+    /// 同一 slot 的交易事件先缓冲，收到该 slot 的 block meta（slot 已完整）后按 tx_index
+    /// 排序，以 [`SlotEvents`] 调用一次 `on_slot`。没有匹配事件的 slot 也会回调（events 为空），
+    /// 可作为 slot 心跳使用。账户更新不包含在批次中。
+    pub async fn subscribe_slot_events<F>(
+        &self,
+        transaction_filters: Vec<TransactionFilter>,
+        event_type_filter: Option<EventTypeFilter>,
+        on_slot: F,
+    ) -> Result<(), Box<dyn std::error::Error>>
+    where
+        F: Fn(SlotEvents) + Send + Sync + 'static,
+    {
+        let on_slot = Arc::new(on_slot);
+        let self_clone = self.clone();
+
+        self.spawn_stream_task(async move {
+            let mut delay = 1u64;
+            loop {
+                match self_clone
+                    .stream_slot_events(&transaction_filters, &event_type_filter, &on_slot)
+                    .await
+                {
+                    Ok(_) => delay = 1,
+                    Err(e) => println!("❌ gRPC error: {} - retry in {}s", e, delay),
+                }
+                self_clone.status.on_disconnected();
+                tokio::time::sleep(Duration::from_secs(delay)).await;
+                delay = (delay * 2).min(60);
+            }
+        });
+
+        Ok(())
+    }
+
+    /// 订阅 DEX 事件并交给异步监听器处理（自动重连）
+    ///
+    /// 解析任务只写入输出队列，监听器在独立任务中回调，慢速 IO 不会阻塞解析。
+    /// `listener_concurrency` 为 1 时按队列顺序串行回调，大于 1 时最多并发这么多个回调（不保证顺序）。
+    /// `output_shards` 大于 1 时每个分片各有一个分发任务，同一 pool 的事件仍按顺序回调。
+    /// 返回分发任务句柄，abort 后停止回调。
+    pub async fn subscribe_dex_events_with_listener<L>(
+        &self,
+        transaction_filters: Vec<TransactionFilter>,
+        account_filters: Vec<AccountFilter>,
+        event_type_filter: Option<EventTypeFilter>,
+        listener: Arc<L>,
+    ) -> Result<JoinHandle<()>, Box<dyn std::error::Error>>
+    where
+        L: AsyncEventListener + 'static,
+    {
+        let shards = self
+            .subscribe_dex_events_sharded(transaction_filters, account_filters, event_type_filter)
+            .await?;
+        let concurrency = self.config.listener_concurrency.max(1);
+        let status = self.config.catch_panics.then(|| self.status());
+        Ok(tokio::spawn(async move {
+            // JoinSet 随外层任务一起 drop，abort 外层句柄即可停止所有分片的分发
+            let mut dispatchers = JoinSet::new();
+            for queue in shards {
+                dispatchers.spawn(dispatch_to_listener(
+                    queue,
+                    Arc::clone(&listener),
+                    concurrency,
+                    status.clone(),
+                ));
+            }
+            while dispatchers.join_next().await.is_some() {}
+        }))
     }
 
     /// 动态更新订阅过滤器
@@ -105,6 +621,7 @@ impl YellowstoneGrpc {
 
         let request = build_subscribe_request(&transaction_filters, &account_filters);
         sender.send(request).await.map_err(|e| e.to_string())?;
+        self.status.update_filters(transaction_filters, account_filters);
         Ok(())
     }
 
@@ -119,44 +636,47 @@ impl YellowstoneGrpc {
         tx_filters: &[TransactionFilter],
         acc_filters: &[AccountFilter],
         event_filter: &Option<EventTypeFilter>,
-        queue: &Arc<ArrayQueue<DexEvent>>,
+        queue: &EventOutput,
     ) -> Result<(), String> {
-        let _ = rustls::crypto::ring::default_provider().install_default();
-
-        // 构建客户端
-        let mut builder = GeyserGrpcClient::build_from_shared(self.endpoint.clone())
-            .map_err(|e| e.to_string())?
-            .x_token(self.token.clone())
-            .map_err(|e| e.to_string())?
-            .max_decoding_message_size(1024 * 1024 * 1024);
-
-        if self.config.connection_timeout_ms > 0 {
-            builder =
-                builder.connect_timeout(Duration::from_millis(self.config.connection_timeout_ms));
-        }
-        if self.config.keep_alive_interval_ms > 0 {
-            builder = builder.http2_keep_alive_interval(Duration::from_millis(
-                self.config.keep_alive_interval_ms,
-            ));
+        if let Some(derived) = event_filter.as_ref().and_then(TransactionFilter::for_event_types) {
+            if tx_filters.iter().any(TransactionFilter::is_unconstrained) {
+                warn!(
+                    "transaction filter without accounts streams every transaction, \
+                     `TransactionFilter::for_event_types` derives {:?} from the event filter",
+                    derived.account_include
+                );
+            }
         }
-        if self.config.keep_alive_timeout_ms > 0 {
-            builder = builder
-                .keep_alive_timeout(Duration::from_millis(self.config.keep_alive_timeout_ms));
+        let mut client = self.connect().await?;
+        let mut watchdog = StreamWatchdog::new(self.config.stale_stream_timeout_ms);
+        let mut request = build_subscribe_request(tx_filters, acc_filters);
+        if watchdog.is_some() {
+            // 订阅 slot 更新，保证过滤条件稀疏时也能观察到 slot 推进
+            request.slots = slot_progress_filter();
         }
-
-        if self.config.enable_tls {
-            builder = builder
-                .tls_config(ClientTlsConfig::new().with_native_roots())
-                .map_err(|e| e.to_string())?;
+        if self.config.emit_block_meta {
+            request.blocks_meta = block_meta_filter();
         }
-
-        let mut client = builder.connect().await.map_err(|e| e.to_string())?;
-        let request = build_subscribe_request(tx_filters, acc_filters);
+        let resume = self.prepare_replay(&mut client, &mut request).await;
+        let from_slot = request.from_slot;
 
         let (subscribe_tx, mut stream) =
-            client.subscribe_with_request(Some(request)).await.map_err(|e| e.to_string())?;
+            client.subscribe_with_request(Some(request)).await.map_err(|e| {
+                self.on_subscribe_rejected(from_slot);
+                e.to_string()
+            })?;
 
         self.print_mode_info();
+        self.status.on_connected();
+        if let Some(event) = resume {
+            let _ = self.client_events.send(event);
+        }
+        self.status.set_subscription(SubscriptionState {
+            order_mode: self.config.order_mode,
+            transactions: tx_filters.to_vec(),
+            accounts: acc_filters.to_vec(),
+            event_types: event_filter.clone(),
+        });
 
         // 设置控制通道
         let (control_tx, mut control_rx) = mpsc::channel::<SubscribeRequest>(100);
@@ -164,32 +684,18 @@ impl YellowstoneGrpc {
         let subscribe_tx = Arc::new(Mutex::new(subscribe_tx));
 
         // 初始化缓冲区
-        let mut slot_buffer = SlotBuffer::new();
-        let mut micro_batch = MicroBatchBuffer::new();
-        let mut last_slot = 0u64;
-
-        let order_mode = self.config.order_mode;
-        let timeout_ms = self.config.order_timeout_ms;
-        let batch_us = self.config.micro_batch_us;
-        let check_interval = Duration::from_millis(timeout_ms / 2);
-        let mut next_check = Instant::now() + check_interval;
+        let mut ctx = StreamContext::new(&self.config, event_filter);
 
         // Ping intervals
-        let ping_interval = Duration::from_millis(self.config.keep_alive_interval_ms.max(10000));
+        let ping_interval = ping_interval(&self.config);
         let mut next_ping = Instant::now() + ping_interval;
+        // 订阅后是否收到过消息，用于判断 `from_slot` 是否被服务端拒绝
+        let mut received = false;
 
         loop {
+            let batch_deadline = queue.batch_deadline();
             // Periodic timeout check for ordered modes and MicroBatch
-            self.check_timeout(
-                order_mode,
-                &mut slot_buffer,
-                &mut micro_batch,
-                queue,
-                timeout_ms,
-                batch_us,
-                &mut next_check,
-                check_interval,
-            );
+            self.check_timeout(&mut ctx, queue);
 
             tokio::select! {
                 // Periodic Ping
@@ -204,9 +710,30 @@ impl YellowstoneGrpc {
                     }
                 }
 
+                _ = tokio::time::sleep_until(batch_deadline.unwrap_or(next_ping)), if batch_deadline.is_some() => {
+                    self.flush_batch(queue);
+                }
+
+                _ = tokio::time::sleep_until(watchdog.as_ref().map_or(next_ping, |w| w.deadline())), if watchdog.is_some() => {
+                    if let Some(w) = &watchdog {
+                        if let Some(event) = w.check(Instant::now()) {
+                            self.flush_on_disconnect(&mut ctx, queue);
+                            return Err(self.report_stale(event));
+                        }
+                    }
+                }
+
                 msg = stream.next() => {
                     match msg {
                         Some(Ok(update)) => {
+                            received = true;
+                            let slot = update_slot(&update);
+                            self.status.on_message(slot);
+                            self.observe_replay(slot);
+                            if let Some(w) = &mut watchdog {
+                                w.on_update(slot);
+                            }
+
                             // Check if it's a pong
                             if let Some(subscribe_update::UpdateOneof::Ping(_)) = update.update_oneof {
                                 // Pong received (it's actually called Ping in the response too sometimes, or handled as update)
@@ -214,23 +741,29 @@ impl YellowstoneGrpc {
                                 continue;
                             }
 
-                            self.handle_update(
-                                update, order_mode, event_filter, queue,
-                                &mut slot_buffer, &mut micro_batch, &mut last_slot, batch_us
-                            );
+                            self.handle_update(update, queue, &mut ctx);
                         }
                         Some(Err(e)) => {
                             error!("Stream error: {:?}", e);
-                            self.flush_on_disconnect(order_mode, &mut slot_buffer, queue);
+                            if !received {
+                                self.on_subscribe_rejected(from_slot);
+                            }
+                            self.flush_on_disconnect(&mut ctx, queue);
                             return Err(e.to_string());
                         }
                         None => {
-                            self.flush_on_disconnect(order_mode, &mut slot_buffer, queue);
+                            self.flush_on_disconnect(&mut ctx, queue);
                             return Ok(());
                         }
                     }
                 }
-                Some(req) = control_rx.recv() => {
+                Some(mut req) = control_rx.recv() => {
+                    if watchdog.is_some() {
+                        req.slots = slot_progress_filter();
+                    }
+                    if self.config.emit_block_meta {
+                        req.blocks_meta = block_meta_filter();
+                    }
                     if let Err(e) = subscribe_tx.lock().await.send(req).await {
                         return Err(e.to_string());
                     }
@@ -239,118 +772,482 @@ impl YellowstoneGrpc {
         }
     }
 
-    fn print_mode_info(&self) {
-        match self.config.order_mode {
-            OrderMode::Unordered => println!("✅ Unordered Mode (10-20μs)"),
-            OrderMode::Ordered => {
-                println!("✅ Ordered Mode (timeout={}ms)", self.config.order_timeout_ms)
-            }
-            OrderMode::StreamingOrdered => {
-                println!("✅ StreamingOrdered Mode (timeout={}ms)", self.config.order_timeout_ms)
-            }
-            OrderMode::MicroBatch => {
-                println!("✅ MicroBatch Mode (window={}μs)", self.config.micro_batch_us)
-            }
-        }
-    }
-
-    #[inline]
-    fn check_timeout(
+    /// 按 slot 批次的事件流处理
+    async fn stream_slot_events<F>(
         &self,
-        mode: OrderMode,
-        slot_buf: &mut SlotBuffer,
-        micro_buf: &mut MicroBatchBuffer,
-        queue: &Arc<ArrayQueue<DexEvent>>,
-        timeout_ms: u64,
-        batch_us: u64,
-        next_check: &mut Instant,
-        interval: Duration,
-    ) {
-        if Instant::now() < *next_check {
-            return;
-        }
-        *next_check = Instant::now() + interval;
+        tx_filters: &[TransactionFilter],
+        event_filter: &Option<EventTypeFilter>,
+        on_slot: &Arc<F>,
+    ) -> Result<(), String>
+    where
+        F: Fn(SlotEvents) + Send + Sync + 'static,
+    {
+        let mut client = self.connect().await?;
+        let mut request = build_subscribe_request(tx_filters, &[]);
+        request.blocks_meta = block_meta_filter();
+        let resume = self.prepare_replay(&mut client, &mut request).await;
+        let from_slot = request.from_slot;
 
-        match mode {
-            OrderMode::Ordered => {
-                if slot_buf.should_timeout(timeout_ms) {
-                    for e in slot_buf.flush_all() {
-                        let _ = queue.push(e);
-                    }
-                }
-            }
-            OrderMode::StreamingOrdered => {
-                if slot_buf.should_timeout(timeout_ms) {
-                    for e in slot_buf.flush_streaming_timeout() {
-                        let _ = queue.push(e);
-                    }
-                }
-            }
-            OrderMode::MicroBatch => {
-                // Periodic flush for MicroBatch mode
-                let now_us = get_timestamp_us();
-                if micro_buf.should_flush(now_us, batch_us) {
-                    for e in micro_buf.flush() {
-                        let _ = queue.push(e);
-                    }
-                }
-            }
-            OrderMode::Unordered => {}
-        }
-    }
+        let (subscribe_tx, mut stream) =
+            client.subscribe_with_request(Some(request)).await.map_err(|e| {
+                self.on_subscribe_rejected(from_slot);
+                e.to_string()
+            })?;
 
-    fn flush_on_disconnect(
-        &self,
-        mode: OrderMode,
-        buffer: &mut SlotBuffer,
-        queue: &Arc<ArrayQueue<DexEvent>>,
-    ) {
-        if matches!(mode, OrderMode::Ordered | OrderMode::StreamingOrdered) {
-            let events = match mode {
-                OrderMode::StreamingOrdered => buffer.flush_streaming_timeout(),
-                _ => buffer.flush_all(),
-            };
-            for e in events {
-                let _ = queue.push(e);
-            }
+        println!("✅ Slot Batch Mode (flush on block meta)");
+        self.status.on_connected();
+        if let Some(event) = resume {
+            let _ = self.client_events.send(event);
         }
-    }
+        self.status.set_subscription(SubscriptionState {
+            order_mode: self.config.order_mode,
+            transactions: tx_filters.to_vec(),
+            accounts: Vec::new(),
+            event_types: event_filter.clone(),
+        });
 
-    #[inline]
+        // 设置控制通道
+        let (control_tx, mut control_rx) = mpsc::channel::<SubscribeRequest>(100);
+        *self.control_tx.lock().await = Some(control_tx);
+        let subscribe_tx = Arc::new(Mutex::new(subscribe_tx));
+
+        let mut slot_buffer = SlotBuffer::new();
+        // block meta 每个 slot 都会到达，无需额外订阅 slot 更新
+        let mut watchdog = StreamWatchdog::new(self.config.stale_stream_timeout_ms);
+
+        // Ping intervals
+        let ping_interval = ping_interval(&self.config);
+        let mut next_ping = Instant::now() + ping_interval;
+        // 订阅后是否收到过消息，用于判断 `from_slot` 是否被服务端拒绝
+        let mut received = false;
+
+        loop {
+            tokio::select! {
+                // Periodic Ping
+                _ = tokio::time::sleep_until(next_ping), if Instant::now() >= next_ping => {
+                    next_ping = Instant::now() + ping_interval;
+                    let ping_request = SubscribeRequest {
+                        ping: Some(SubscribeRequestPing { id: 1 }),
+                        ..Default::default()
+                    };
+                    if let Err(e) = subscribe_tx.lock().await.send(ping_request).await {
+                        error!("Failed to send ping: {}", e);
+                    }
+                }
+
+                _ = tokio::time::sleep_until(watchdog.as_ref().map_or(next_ping, |w| w.deadline())), if watchdog.is_some() => {
+                    if let Some(w) = &watchdog {
+                        if let Some(event) = w.check(Instant::now()) {
+                            return Err(self.report_stale(event));
+                        }
+                    }
+                }
+
+                msg = stream.next() => {
+                    match msg {
+                        Some(Ok(update)) => {
+                            received = true;
+                            let slot = update_slot(&update);
+                            self.status.on_message(slot);
+                            self.observe_replay(slot);
+                            if let Some(w) = &mut watchdog {
+                                w.on_update(slot);
+                            }
+                            let block_time_us =
+                                timestamp_to_microseconds(&update.created_at.unwrap_or_default()) as i64;
+                            let grpc_recv_us = self.recv_us(&update);
+
+                            match update.update_oneof {
+                                Some(subscribe_update::UpdateOneof::Transaction(tx)) => {
+                                    let idx =
+                                        tx.transaction.as_ref().map(|t| t.index).unwrap_or(0);
+                                    for e in self.parse_guarded(
+                                        &tx,
+                                        grpc_recv_us,
+                                        block_time_us,
+                                        event_filter,
+                                    ) {
+                                        self.dispatch_execution_hooks(&e);
+                                        if let Some(e) = self.transformers.apply(e) {
+                                            slot_buffer.push(tx.slot, idx, e);
+                                        }
+                                    }
+                                }
+                                Some(subscribe_update::UpdateOneof::BlockMeta(meta)) => {
+                                    let events = slot_buffer.flush_slot(meta.slot);
+                                    let last_signature = events
+                                        .last()
+                                        .map_or_else(Default::default, |e| e.metadata().signature);
+                                    // 丢弃长时间未确认的 slot（分叉或被跳过）
+                                    let _ = slot_buffer
+                                        .flush_before(meta.slot.saturating_sub(MAX_PENDING_SLOTS));
+                                    self.status.on_emitted(events.len() as u64);
+                                    let batch = SlotEvents {
+                                        slot: meta.slot,
+                                        block_time: meta.block_time.map(|t| t.timestamp),
+                                        leader: self.slot_leader(&meta),
+                                        events,
+                                    };
+                                    if let Err(msg) =
+                                        catch_panic(self.config.catch_panics, &self.status, || {
+                                            on_slot(batch)
+                                        })
+                                    {
+                                        error!("on_slot callback panicked at slot {}: {}", meta.slot, msg);
+                                    } else if let Some(tracker) = &self.checkpoint {
+                                        tracker.commit(Checkpoint {
+                                            slot: meta.slot,
+                                            signature: last_signature,
+                                        });
+                                    }
+                                }
+                                _ => {}
+                            }
+                        }
+                        Some(Err(e)) => {
+                            error!("Stream error: {:?}", e);
+                            if !received {
+                                self.on_subscribe_rejected(from_slot);
+                            }
+                            return Err(e.to_string());
+                        }
+                        None => return Ok(()),
+                    }
+                }
+                Some(mut req) = control_rx.recv() => {
+                    // update_subscription 构建的请求不含 blocks_meta，这里补上以保持批次输出
+                    req.blocks_meta = block_meta_filter();
+                    if let Err(e) = subscribe_tx.lock().await.send(req).await {
+                        return Err(e.to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    /// 调用匹配的跟单执行钩子
+    #[inline]
+    fn dispatch_execution_hooks(&self, event: &DexEvent) {
+        if self.execution_hooks.is_empty() {
+            return;
+        }
+        if let Err(msg) = catch_panic(self.config.catch_panics, &self.status, || {
+            self.execution_hooks.dispatch(event)
+        }) {
+            error!("execution hook panicked: {}", msg);
+        }
+    }
+
+    /// 按背压策略写入输出队列，直接回调模式下同步回调
+    #[inline]
+    fn push_event(&self, output: &EventOutput, event: DexEvent) {
+        self.dispatch_execution_hooks(&event);
+        let Some(event) = self.transformers.apply(event) else { return };
+        let queues = match output {
+            EventOutput::Queues(queues) => queues,
+            EventOutput::Direct(on_event) => {
+                let metadata = self.checkpoint.as_ref().map(|_| *event.metadata());
+                let mut on_event = on_event.lock();
+                match catch_panic(self.config.catch_panics, &self.status, || on_event(event)) {
+                    Ok(()) => self.on_delivered(metadata),
+                    Err(msg) => error!("direct callback panicked: {}", msg),
+                }
+                return;
+            }
+            EventOutput::Batched(batched) => {
+                let slot = event.metadata().slot;
+                let flush = {
+                    let state = batched.state.lock();
+                    !state.events.is_empty() && state.slot != slot
+                };
+                if flush {
+                    self.flush_batch(output);
+                }
+                let mut state = batched.state.lock();
+                if state.events.is_empty() {
+                    state.slot = slot;
+                    state.deadline = Some(Instant::now() + batched.max_delay);
+                }
+                state.events.push(event);
+                return;
+            }
+        };
+        let queue = queues.select(&event);
+        match self.config.backpressure {
+            BackpressurePolicy::DropNewest => {
+                if queue.push(event).is_err() {
+                    self.status.on_dropped();
+                    return;
+                }
+            }
+            BackpressurePolicy::DropOldest => {
+                if queue.force_push(event).is_some() {
+                    self.status.on_dropped();
+                }
+            }
+        }
+        // 入队不等于已被消费，队列输出不推进断点
+        self.status.on_emitted(1);
+    }
+
+    /// 交付微批次输出中攒下的事件
+    fn flush_batch(&self, output: &EventOutput) {
+        let EventOutput::Batched(batched) = output else { return };
+        let mut state = batched.state.lock();
+        state.deadline = None;
+        if state.events.is_empty() {
+            return;
+        }
+        let events = std::mem::take(&mut state.events);
+        let metadata: Vec<EventMetadata> = match &self.checkpoint {
+            Some(_) => events.iter().map(|e| *e.metadata()).collect(),
+            None => Vec::new(),
+        };
+        let count = events.len() as u64;
+        let on_batch = &mut state.on_batch;
+        match catch_panic(self.config.catch_panics, &self.status, || on_batch(events)) {
+            Ok(()) => {
+                self.status.on_emitted(count);
+                if let Some(tracker) = &self.checkpoint {
+                    metadata.iter().for_each(|m| tracker.on_delivered(m));
+                }
+            }
+            Err(msg) => error!("batch callback panicked: {}", msg),
+        }
+    }
+
+    #[inline]
+    fn on_delivered(&self, metadata: Option<EventMetadata>) {
+        self.status.on_emitted(1);
+        if let (Some(tracker), Some(metadata)) = (&self.checkpoint, metadata) {
+            tracker.on_delivered(&metadata);
+        }
+    }
+
+    /// 按断点设置 `from_slot`，返回本次订阅要发出的 `ClientEvent::Resume`
+    fn apply_checkpoint(&self, request: &mut SubscribeRequest) -> Option<ClientEvent> {
+        let tracker = self.checkpoint.as_ref()?;
+        let checkpoint = tracker.checkpoint()?;
+        if self.config.resume_from_slot {
+            request.from_slot = tracker.resume_slot();
+        }
+        Some(ClientEvent::Resume { checkpoint, replayed: request.from_slot.is_some() })
+    }
+
+    /// 设置本次订阅的 `from_slot`（断点优先，其次为首次订阅的 `ClientConfig::from_slot`），
+    /// 并查询实时 slot 作为重放阶段的终点
+    async fn prepare_replay<I: Interceptor>(
+        &self,
+        client: &mut GeyserGrpcClient<I>,
+        request: &mut SubscribeRequest,
+    ) -> Option<ClientEvent> {
+        let initial = self
+            .config
+            .from_slot
+            .filter(|_| !self.replay.initial_used.swap(true, Ordering::Relaxed));
+        let resume = self.apply_checkpoint(request);
+        if request.from_slot.is_none() {
+            request.from_slot = initial;
+        }
+        let live_slot = match request.from_slot {
+            Some(from_slot) => match client.get_slot(Some(CommitmentLevel::Processed)).await {
+                Ok(response) if response.slot > from_slot => response.slot,
+                Ok(_) => 0,
+                Err(e) => {
+                    warn!("failed to query live slot, replayed events are not marked: {}", e);
+                    0
+                }
+            },
+            None => 0,
+        };
+        self.replay.live_slot.store(live_slot, Ordering::Relaxed);
+        resume
+    }
+
+    /// 更新的接收时间：优先使用外部时钟
+    #[inline]
+    fn recv_us(&self, update: &SubscribeUpdate) -> i64 {
+        self.recv_clock.as_ref().and_then(|clock| clock(update)).unwrap_or_else(get_timestamp_us)
+    }
+
+    /// 重放追上实时 slot 时发出 `ClientEvent::ReplayCompleted`
+    #[inline]
+    fn observe_replay(&self, slot: Option<u64>) {
+        if let Some(live_slot) = self.replay.observe(slot) {
+            let _ = self.client_events.send(ClientEvent::ReplayCompleted { live_slot });
+        }
+    }
+
+    /// 带 `from_slot` 的订阅失败时，之后的重连不再使用 `from_slot`
+    fn on_subscribe_rejected(&self, from_slot: Option<u64>) {
+        if let (Some(tracker), Some(slot)) = (&self.checkpoint, from_slot) {
+            warn!("subscription from slot {} failed, falling back to RPC backfill", slot);
+            tracker.disable_from_slot();
+        }
+    }
+
+    /// 确定 slot leader：优先取区块手续费奖励的接收者，其次查询注入的 leader schedule
+    fn slot_leader(&self, meta: &SubscribeUpdateBlockMeta) -> Option<Pubkey> {
+        meta.rewards
+            .as_ref()
+            .and_then(leader_from_rewards)
+            .or_else(|| self.leader_schedule.as_ref()?.leader(meta.slot))
+    }
+
+    /// block meta 转为 `DexEvent::BlockMeta`，不经过排序缓冲直接输出
+    fn block_meta_event(&self, meta: &SubscribeUpdateBlockMeta, grpc_us: i64) -> DexEvent {
+        DexEvent::BlockMeta(BlockMetaEvent {
+            metadata: EventMetadata {
+                slot: meta.slot,
+                block_time_us: meta.block_time.map_or(0, |t| t.timestamp * 1_000_000),
+                grpc_recv_us: grpc_us,
+                ..Default::default()
+            },
+            leader: self.slot_leader(meta).unwrap_or_default(),
+        })
+    }
+
+    /// 发出僵死流通知，返回用于触发重连的错误信息
+    fn report_stale(&self, event: ClientEvent) -> String {
+        self.status.on_stale();
+        let _ = self.client_events.send(event.clone());
+        let ClientEvent::StaleStream { reason, last_slot, idle_ms } = event else {
+            return "stale stream".to_string();
+        };
+        format!("stale stream ({:?}, last slot {}, idle {}ms)", reason, last_slot, idle_ms)
+    }
+
+    /// 构建并连接 gRPC 客户端
+    async fn connect(&self) -> Result<GeyserGrpcClient<impl Interceptor>, String> {
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let mut builder = GeyserGrpcClient::build_from_shared(self.endpoint.clone())
+            .map_err(|e| e.to_string())?
+            .x_token(self.token.clone())
+            .map_err(|e| e.to_string())?
+            .max_decoding_message_size(1024 * 1024 * 1024);
+
+        if self.config.connection_timeout_ms > 0 {
+            builder =
+                builder.connect_timeout(Duration::from_millis(self.config.connection_timeout_ms));
+        }
+        if self.config.keep_alive_interval_ms > 0 {
+            builder = builder.http2_keep_alive_interval(Duration::from_millis(
+                self.config.keep_alive_interval_ms,
+            ));
+        }
+        if self.config.keep_alive_timeout_ms > 0 {
+            builder = builder
+                .keep_alive_timeout(Duration::from_millis(self.config.keep_alive_timeout_ms));
+        }
+
+        if self.config.enable_tls {
+            builder = builder
+                .tls_config(ClientTlsConfig::new().with_native_roots())
+                .map_err(|e| e.to_string())?;
+        }
+
+        builder.connect().await.map_err(|e| e.to_string())
+    }
+
+    fn print_mode_info(&self) {
+        match self.config.order_mode {
+            OrderMode::Unordered => println!("✅ Unordered Mode (10-20μs)"),
+            OrderMode::Ordered => {
+                println!("✅ Ordered Mode (timeout={}ms)", self.config.order_timeout_ms)
+            }
+            OrderMode::StreamingOrdered => {
+                println!("✅ StreamingOrdered Mode (timeout={}ms)", self.config.order_timeout_ms)
+            }
+            OrderMode::MicroBatch => {
+                println!("✅ MicroBatch Mode (window={}μs)", self.config.micro_batch_us)
+            }
+        }
+    }
+
+    #[inline]
+    fn check_timeout(&self, ctx: &mut StreamContext<'_>, queue: &EventOutput) {
+        if Instant::now() < ctx.next_check {
+            return;
+        }
+        ctx.next_check = Instant::now() + ctx.check_interval;
+
+        match ctx.mode {
+            OrderMode::Ordered => {
+                if ctx.slot_buf.should_timeout(ctx.timeout_ms) {
+                    for e in ctx.slot_buf.flush_all() {
+                        self.push_event(queue, e);
+                    }
+                }
+            }
+            OrderMode::StreamingOrdered => {
+                if ctx.slot_buf.should_timeout(ctx.timeout_ms) {
+                    for e in ctx.slot_buf.flush_streaming_timeout() {
+                        self.push_event(queue, e);
+                    }
+                }
+            }
+            OrderMode::MicroBatch => {
+                // Periodic flush for MicroBatch mode
+                let now_us = get_timestamp_us();
+                if ctx.micro_buf.should_flush(now_us, ctx.batch_us) {
+                    for e in ctx.micro_buf.flush() {
+                        self.push_event(queue, e);
+                    }
+                }
+            }
+            OrderMode::Unordered => {}
+        }
+    }
+
+    fn flush_on_disconnect(&self, ctx: &mut StreamContext<'_>, queue: &EventOutput) {
+        if matches!(ctx.mode, OrderMode::Ordered | OrderMode::StreamingOrdered) {
+            let events = match ctx.mode {
+                OrderMode::StreamingOrdered => ctx.slot_buf.flush_streaming_timeout(),
+                _ => ctx.slot_buf.flush_all(),
+            };
+            for e in events {
+                self.push_event(queue, e);
+            }
+        }
+        self.flush_batch(queue);
+    }
+
+    #[inline]
     fn handle_update(
         &self,
         update_msg: SubscribeUpdate,
-        mode: OrderMode,
-        filter: &Option<EventTypeFilter>,
-        queue: &Arc<ArrayQueue<DexEvent>>,
-        slot_buf: &mut SlotBuffer,
-        micro_buf: &mut MicroBatchBuffer,
-        last_slot: &mut u64,
-        batch_us: u64,
+        queue: &EventOutput,
+        ctx: &mut StreamContext<'_>,
     ) {
         let block_time_us =
             timestamp_to_microseconds(&update_msg.created_at.unwrap_or_default()) as i64;
-        let grpc_recv_us = get_timestamp_us();
+        let grpc_recv_us = self.recv_us(&update_msg);
 
         let Some(update) = update_msg.update_oneof else { return };
 
         match update {
             subscribe_update::UpdateOneof::Transaction(tx) => {
-                self.handle_transaction(
-                    tx,
-                    mode,
-                    filter,
-                    queue,
-                    slot_buf,
-                    micro_buf,
-                    last_slot,
-                    batch_us,
-                    grpc_recv_us,
-                    block_time_us,
-                );
+                if let Some(fees) = &mut ctx.fees {
+                    let price = tx
+                        .transaction
+                        .as_ref()
+                        .and_then(|t| t.transaction.as_ref())
+                        .and_then(compute_unit_price);
+                    if let Some(mut stats) = fees.observe(tx.slot, price) {
+                        stats.metadata.grpc_recv_us = grpc_recv_us;
+                        self.push_event(queue, DexEvent::PriorityFeeStats(stats));
+                    }
+                }
+                self.handle_transaction(tx, queue, ctx, grpc_recv_us, block_time_us);
             }
             subscribe_update::UpdateOneof::Account(acc) => {
-                Self::handle_account(acc, filter, queue, grpc_recv_us, block_time_us);
+                self.handle_account(acc, ctx.filter, queue, grpc_recv_us, block_time_us);
+            }
+            subscribe_update::UpdateOneof::BlockMeta(meta)
+                if ctx.filter.as_ref().is_none_or(|f| f.should_include(EventType::BlockMeta)) =>
+            {
+                self.push_event(queue, self.block_meta_event(&meta, grpc_recv_us));
             }
             _ => {}
         }
@@ -360,53 +1257,44 @@ impl YellowstoneGrpc {
     fn handle_transaction(
         &self,
         tx: SubscribeUpdateTransaction,
-        mode: OrderMode,
-        filter: &Option<EventTypeFilter>,
-        queue: &Arc<ArrayQueue<DexEvent>>,
-        slot_buf: &mut SlotBuffer,
-        micro_buf: &mut MicroBatchBuffer,
-        last_slot: &mut u64,
-        batch_us: u64,
+        queue: &EventOutput,
+        ctx: &mut StreamContext<'_>,
         grpc_us: i64,
         block_us: i64,
     ) {
         let slot = tx.slot;
+        let idx = tx.transaction.as_ref().map(|t| t.index).unwrap_or(0);
+        let events = self.parse_guarded(&tx, grpc_us, block_us, ctx.filter);
 
-        match mode {
+        match ctx.mode {
             OrderMode::Unordered => {
-                for e in parse_transaction_core(&tx, grpc_us, Some(block_us), filter.as_ref()) {
-                    let _ = queue.push(e);
+                for e in events {
+                    self.push_event(queue, e);
                 }
             }
             OrderMode::Ordered => {
-                if slot > *last_slot && *last_slot > 0 {
-                    for e in slot_buf.flush_before(slot) {
-                        let _ = queue.push(e);
+                if slot > ctx.last_slot && ctx.last_slot > 0 {
+                    for e in ctx.slot_buf.flush_before(slot) {
+                        self.push_event(queue, e);
                     }
                 }
-                *last_slot = slot;
-                for (idx, e) in
-                    parse_transaction_to_vec(&tx, grpc_us, Some(block_us), filter.as_ref())
-                {
-                    slot_buf.push(slot, idx, e);
+                ctx.last_slot = slot;
+                for e in events {
+                    ctx.slot_buf.push(slot, idx, e);
                 }
             }
             OrderMode::StreamingOrdered => {
-                for (idx, e) in
-                    parse_transaction_to_vec(&tx, grpc_us, Some(block_us), filter.as_ref())
-                {
-                    for evt in slot_buf.push_streaming(slot, idx, e) {
-                        let _ = queue.push(evt);
+                for e in events {
+                    for evt in ctx.slot_buf.push_streaming(slot, idx, e) {
+                        self.push_event(queue, evt);
                     }
                 }
             }
             OrderMode::MicroBatch => {
-                for (idx, e) in
-                    parse_transaction_to_vec(&tx, grpc_us, Some(block_us), filter.as_ref())
-                {
-                    if micro_buf.push(slot, idx, e, grpc_us, batch_us) {
-                        for evt in micro_buf.flush() {
-                            let _ = queue.push(evt);
+                for e in events {
+                    if ctx.micro_buf.push(slot, idx, e, grpc_us, ctx.batch_us) {
+                        for evt in ctx.micro_buf.flush() {
+                            self.push_event(queue, evt);
                         }
                     }
                 }
@@ -414,11 +1302,96 @@ impl YellowstoneGrpc {
         }
     }
 
+    /// 解析交易；解析器 panic 时返回一个带上下文的 `DexEvent::Error`，不中断事件流
+    #[inline]
+    fn parse_guarded(
+        &self,
+        tx: &SubscribeUpdateTransaction,
+        grpc_us: i64,
+        block_us: i64,
+        filter: &Option<EventTypeFilter>,
+    ) -> Vec<DexEvent> {
+        let mut events = catch_panic(self.config.catch_panics, &self.status, || {
+            parse_transaction_core(
+                tx,
+                grpc_us,
+                Some(block_us),
+                filter.as_ref(),
+                self.config.retain_raw_data,
+                self.config.parse_limits,
+            )
+        })
+        .map(|(events, truncated)| {
+            if truncated.is_some() {
+                self.status.on_truncated();
+            }
+            events
+        });
+        if let Ok(events) = &mut events {
+            if let (Some(validation), Some(meta)) = (
+                &self.config.amount_validation,
+                tx.transaction.as_ref().and_then(|info| info.meta.as_ref()),
+            ) {
+                let warnings = validate_amounts(events, meta, validation);
+                self.status.on_validation_warnings(warnings as u64);
+            }
+            if self.config.emit_pool_created {
+                insert_pool_created(events);
+            }
+            if let Some(migrations) = &self.migrations {
+                migrations.lock().process(events);
+            }
+            self.status.on_parsed(events.len() as u64);
+            self.replay.mark(tx.slot, events);
+            self.first_swap.mark(events);
+            self.capture_accounts(tx, events);
+        }
+        if self.config.transaction_stats {
+            let parsed = events.as_deref().unwrap_or_default();
+            self.status.on_transaction(transaction_outcome(tx, parsed));
+        }
+        events.unwrap_or_else(|msg| {
+            let sig = tx
+                .transaction
+                .as_ref()
+                .map(|t| extract_signature(&t.signature).to_string())
+                .unwrap_or_default();
+            vec![DexEvent::Error(format!(
+                "parser panicked: {} (slot {}, signature {})",
+                msg, tx.slot, sig
+            ))]
+        })
+    }
+
+    /// 按配置缓存交易账户表，或在交易的事件之前插入 `DexEvent::TransactionAccounts`
+    fn capture_accounts(&self, tx: &SubscribeUpdateTransaction, events: &mut Vec<DexEvent>) {
+        let emit = self.config.emit_transaction_accounts;
+        if events.is_empty() || !(emit || self.tx_accounts.is_enabled()) {
+            return;
+        }
+        let Some(info) = &tx.transaction else { return };
+        let Some(meta) = &info.meta else { return };
+        let accounts = TransactionKeys::from_grpc(meta, &info.transaction)
+            .to_accounts(meta, &info.transaction);
+
+        let mut metadata = *events[0].metadata();
+        (metadata.outer_index, metadata.inner_index) = (0, None);
+        if self.tx_accounts.is_enabled() {
+            let evicted = self.tx_accounts.insert(metadata.signature, Arc::new(accounts.clone()));
+            self.status.on_evicted(evicted);
+        }
+        if emit {
+            let event = TransactionAccountsEvent { metadata, accounts };
+            events.insert(0, DexEvent::TransactionAccounts(event));
+        }
+    }
+
     #[inline]
     fn handle_account(
+        &self,
         acc: SubscribeUpdateAccount,
         filter: &Option<EventTypeFilter>,
-        queue: &Arc<ArrayQueue<DexEvent>>,
+        queue: &EventOutput,
         grpc_us: i64,
         block_us: i64,
     ) {
@@ -437,15 +1410,54 @@ impl YellowstoneGrpc {
             tx_index: 0,
             block_time_us: block_us,
             grpc_recv_us: grpc_us,
+            ..Default::default()
         };
-        if let Some(e) = crate::accounts::parse_account_unified(&data, meta, filter.as_ref()) {
-            let _ = queue.push(e);
+        let pubkey = data.pubkey;
+        let parsed = catch_panic(self.config.catch_panics, &self.status, || {
+            crate::accounts::parse_account_unified(&data, meta, filter.as_ref())
+        })
+        .unwrap_or_else(|msg| {
+            Some(DexEvent::Error(format!(
+                "account parser panicked: {} (slot {}, account {})",
+                msg, acc.slot, pubkey
+            )))
+        });
+        if let Some(mut e) = parsed {
+            self.replay.mark(acc.slot, std::slice::from_mut(&mut e));
+            self.status.on_parsed(1);
+            self.push_event(queue, e);
         }
     }
 }
 
 // ==================== 辅助函数 ====================
 
+/// 在当前线程上按延迟配置构建单线程 runtime 并运行 `task`
+fn run_stream_runtime<F>(profile: LatencyProfile, task: F) -> std::io::Result<()>
+where
+    F: std::future::Future<Output = ()>,
+{
+    let mut builder = tokio::runtime::Builder::new_current_thread();
+    builder.enable_all();
+    if profile != LatencyProfile::Standard {
+        // 每轮询一个任务就检查一次 IO 驱动，收到数据后尽快调度流任务
+        builder.event_interval(1);
+    }
+    let runtime = builder.build()?;
+    runtime.block_on(async move {
+        if profile == LatencyProfile::BusyPoll {
+            // 始终有可运行的任务，调度器只做非阻塞的 IO 轮询而不会休眠
+            tokio::spawn(async {
+                loop {
+                    tokio::task::yield_now().await;
+                }
+            });
+        }
+        task.await
+    });
+    Ok(())
+}
+
 /// 获取当前时间戳（微秒）
 ///
 /// 使用高性能时钟，避免系统调用开销
@@ -512,55 +1524,246 @@ fn build_subscribe_request(
     }
 }
 
+fn block_meta_filter() -> HashMap<String, SubscribeRequestFilterBlocksMeta> {
+    HashMap::from([("block_meta".to_string(), SubscribeRequestFilterBlocksMeta {})])
+}
+
+fn slot_progress_filter() -> HashMap<String, SubscribeRequestFilterSlots> {
+    HashMap::from([("slot_progress".to_string(), SubscribeRequestFilterSlots::default())])
+}
+
+/// Ping 间隔；启用看门狗时不超过超时的一半，保证健康连接在超时前收到 pong
+fn ping_interval(config: &ClientConfig) -> Duration {
+    let interval = Duration::from_millis(config.keep_alive_interval_ms.max(10000));
+    match config.stale_stream_timeout_ms {
+        0 => interval,
+        timeout_ms => interval.min(Duration::from_millis(timeout_ms / 2)),
+    }
+}
+
+/// 更新消息携带的 slot（pong 等控制消息为 None）
+#[inline]
+fn update_slot(update: &SubscribeUpdate) -> Option<u64> {
+    match &update.update_oneof {
+        Some(subscribe_update::UpdateOneof::Transaction(tx)) => Some(tx.slot),
+        Some(subscribe_update::UpdateOneof::Account(acc)) => Some(acc.slot),
+        Some(subscribe_update::UpdateOneof::Slot(s)) => Some(s.slot),
+        Some(subscribe_update::UpdateOneof::BlockMeta(meta)) => Some(meta.slot),
+        _ => None,
+    }
+}
+
+// ==================== 单条事件流的状态 ====================
+
+/// 单条事件流的过滤条件、排序缓冲和配置快照
+struct StreamContext<'a> {
+    filter: &'a Option<EventTypeFilter>,
+    mode: OrderMode,
+    timeout_ms: u64,
+    batch_us: u64,
+    check_interval: Duration,
+    next_check: Instant,
+    slot_buf: SlotBuffer,
+    micro_buf: MicroBatchBuffer,
+    last_slot: u64,
+    fees: Option<PriorityFeeAggregator>,
+}
+
+impl<'a> StreamContext<'a> {
+    fn new(config: &ClientConfig, filter: &'a Option<EventTypeFilter>) -> Self {
+        let check_interval = Duration::from_millis(config.order_timeout_ms / 2);
+        let fees = (config.emit_priority_fees
+            && filter.as_ref().is_none_or(|f| f.should_include(EventType::PriorityFeeStats)))
+        .then(PriorityFeeAggregator::new);
+        Self {
+            filter,
+            mode: config.order_mode,
+            timeout_ms: config.order_timeout_ms,
+            batch_us: config.micro_batch_us,
+            check_interval,
+            next_check: Instant::now() + check_interval,
+            slot_buf: SlotBuffer::new(),
+            micro_buf: MicroBatchBuffer::new(),
+            last_slot: 0,
+            fees,
+        }
+    }
+}
+
+// ==================== 僵死流看门狗 ====================
+
+/// 跟踪最后一次消息和最后一次 slot 推进的时间
+struct StreamWatchdog {
+    timeout: Duration,
+    last_message: Instant,
+    last_progress: Instant,
+    highest_slot: u64,
+}
+
+impl StreamWatchdog {
+    /// timeout_ms 为 0 时不启用
+    fn new(timeout_ms: u64) -> Option<Self> {
+        let now = Instant::now();
+        (timeout_ms > 0).then(|| Self {
+            timeout: Duration::from_millis(timeout_ms),
+            last_message: now,
+            last_progress: now,
+            highest_slot: 0,
+        })
+    }
+
+    #[inline]
+    fn on_update(&mut self, slot: Option<u64>) {
+        let now = Instant::now();
+        self.last_message = now;
+
+        let Some(slot) = slot else { return };
+        if slot > self.highest_slot {
+            self.highest_slot = slot;
+            self.last_progress = now;
+        }
+    }
+
+    /// 下一次可能判定超时的时间点
+    fn deadline(&self) -> Instant {
+        self.last_message.min(self.last_progress) + self.timeout
+    }
+
+    fn check(&self, now: Instant) -> Option<ClientEvent> {
+        let (reason, since) = if now >= self.last_message + self.timeout {
+            (StaleReason::NoMessage, self.last_message)
+        } else if now >= self.last_progress + self.timeout {
+            (StaleReason::NoSlotProgress, self.last_progress)
+        } else {
+            return None;
+        };
+        Some(ClientEvent::StaleStream {
+            reason,
+            last_slot: self.highest_slot,
+            idle_ms: now.duration_since(since).as_millis() as u64,
+        })
+    }
+}
+
 // ==================== 交易解析 ====================
 
 #[inline]
-fn parse_transaction_to_vec(
+fn parse_transaction_core(
     tx: &SubscribeUpdateTransaction,
     grpc_us: i64,
     block_us: Option<i64>,
     filter: Option<&EventTypeFilter>,
-) -> Vec<(u64, DexEvent)> {
-    let idx = tx.transaction.as_ref().map(|t| t.index).unwrap_or(0);
-    parse_transaction_core(tx, grpc_us, block_us, filter).into_iter().map(|e| (idx, e)).collect()
+    retain_raw: bool,
+    limits: ParseLimits,
+) -> (Vec<DexEvent>, Option<TruncateReason>) {
+    let Some(info) = &tx.transaction else { return (Vec::new(), None) };
+    parse_transaction_info(info, tx.slot, grpc_us, block_us, filter, retain_raw, limits)
 }
 
+/// 解析单笔交易信息（交易订阅与区块订阅共用）
+///
+/// `retain_raw` 为 true 时每个事件之后紧跟一个对应的 `DexEvent::RawData`。
+/// 因 `limits` 截断了部分指令或日志时，事件之后附带 `DexEvent::ParseTruncated`，并返回截断原因。
 #[inline]
-fn parse_transaction_core(
-    tx: &SubscribeUpdateTransaction,
+pub(crate) fn parse_transaction_info(
+    info: &SubscribeUpdateTransactionInfo,
+    slot: u64,
     grpc_us: i64,
     block_us: Option<i64>,
     filter: Option<&EventTypeFilter>,
-) -> Vec<DexEvent> {
-    let Some(info) = &tx.transaction else { return Vec::new() };
-    let Some(meta) = &info.meta else { return Vec::new() };
+    retain_raw: bool,
+    limits: ParseLimits,
+) -> (Vec<DexEvent>, Option<TruncateReason>) {
+    let deadline = limits.deadline();
+    let Some(meta) = &info.meta else { return (Vec::new(), None) };
 
     let sig = extract_signature(&info.signature);
-    let slot = tx.slot;
     let idx = info.index;
+    // 账户表每笔交易只转换一次，logs 和 instructions 两条路径共用
+    let keys = TransactionKeys::from_grpc(meta, &info.transaction);
+    let ctx = TxContext {
+        meta,
+        transaction: &info.transaction,
+        keys: &keys,
+        sig,
+        slot,
+        tx_idx: idx,
+        block_us,
+        grpc_us,
+        filter,
+    };
 
     // 并行解析 logs 和 instructions
-    let (log_events, instr_events) = rayon::join(
-        || {
-            parse_logs(
-                meta,
-                &info.transaction,
-                &meta.log_messages,
-                sig,
-                slot,
-                idx,
-                block_us,
-                grpc_us,
-                filter,
-            )
-        },
-        || parse_instructions(meta, &info.transaction, sig, slot, idx, block_us, grpc_us, filter),
+    let ((log_events, log_truncated), (instr_events, instr_truncated)) = rayon::join(
+        || parse_logs(ctx, retain_raw, limits, deadline),
+        || parse_instructions(ctx, limits, deadline),
     );
 
-    let mut result = Vec::with_capacity(log_events.len() + instr_events.len());
+    let mut result = Vec::with_capacity(log_events.len() + instr_events.len() + 1);
     result.extend(log_events);
-    result.extend(instr_events);
-    result
+    if retain_raw {
+        for e in instr_events {
+            let raw = instruction_data(info, e.metadata())
+                .map(|data| raw_data_event(&e, RawSource::Instruction(data.into())));
+            result.push(e);
+            result.extend(raw);
+        }
+    } else {
+        result.extend(instr_events);
+    }
+
+    let truncated = log_truncated.max(instr_truncated);
+    if let Some(reason) = truncated {
+        let metadata = EventMetadata {
+            signature: sig,
+            slot,
+            tx_index: idx,
+            block_time_us: block_us.unwrap_or(0),
+            grpc_recv_us: grpc_us,
+            ..Default::default()
+        };
+        result.push(DexEvent::ParseTruncated(ParseTruncatedEvent { metadata, reason }));
+    }
+    (result, truncated)
+}
+
+/// 事件所在位置（外层 / 内层指令索引）的指令数据
+fn instruction_data<'a>(
+    info: &'a SubscribeUpdateTransactionInfo,
+    metadata: &EventMetadata,
+) -> Option<&'a [u8]> {
+    let data = match metadata.inner_index {
+        None => {
+            &info
+                .transaction
+                .as_ref()?
+                .message
+                .as_ref()?
+                .instructions
+                .get(metadata.outer_index as usize)?
+                .data
+        }
+        Some(inner) => {
+            &info
+                .meta
+                .as_ref()?
+                .inner_instructions
+                .iter()
+                .find(|group| group.index == metadata.outer_index)?
+                .instructions
+                .get(inner as usize)?
+                .data
+        }
+    };
+    Some(data)
+}
+
+fn raw_data_event(event: &DexEvent, source: RawSource) -> DexEvent {
+    DexEvent::RawData(RawDataEvent {
+        metadata: *event.metadata(),
+        event_type: event.event_type_name().to_string(),
+        source,
+    })
 }
 
 #[inline(always)]
@@ -570,81 +1773,796 @@ fn extract_signature(bytes: &[u8]) -> solana_sdk::signature::Signature {
     solana_sdk::signature::Signature::from(arr)
 }
 
+/// 从输出队列取事件并回调异步监听器
+///
+/// 传入 `status` 时隔离监听器 panic（记录计数后继续分发），否则 panic 会终止分发任务。
+async fn dispatch_to_listener<L>(
+    queue: Arc<ArrayQueue<DexEvent>>,
+    listener: Arc<L>,
+    concurrency: usize,
+    status: Option<Arc<ClientStatus>>,
+) where
+    L: AsyncEventListener + 'static,
+{
+    let permits = Arc::new(Semaphore::new(concurrency));
+    loop {
+        let Some(event) = queue.pop() else {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            continue;
+        };
+        if concurrency == 1 {
+            call_listener(&*listener, event, status.as_deref()).await;
+            continue;
+        }
+        let Ok(permit) = Arc::clone(&permits).acquire_owned().await else { return };
+        let listener = Arc::clone(&listener);
+        let status = status.clone();
+        tokio::spawn(async move {
+            call_listener(&*listener, event, status.as_deref()).await;
+            drop(permit);
+        });
+    }
+}
+
+async fn call_listener<L: AsyncEventListener>(
+    listener: &L,
+    event: DexEvent,
+    status: Option<&ClientStatus>,
+) {
+    let Some(status) = status else {
+        listener.on_event(event).await;
+        return;
+    };
+    let event_type = event.event_type_name();
+    if let Err(payload) = AssertUnwindSafe(listener.on_event(event)).catch_unwind().await {
+        status.on_panic();
+        error!("listener panicked on {} event: {}", event_type, panic_message(&*payload));
+    }
+}
+
+/// 在 `enabled` 时捕获 `f` 中的 panic 并计数，返回 panic 信息
+///
+/// 只在 `panic = "unwind"`（默认）时有效，`panic = "abort"` 的构建中 panic 仍会终止进程。
+#[inline]
+fn catch_panic<T>(
+    enabled: bool,
+    status: &ClientStatus,
+    f: impl FnOnce() -> T,
+) -> Result<T, String> {
+    if !enabled {
+        return Ok(f());
+    }
+    std::panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        status.on_panic();
+        panic_message(&*payload)
+    })
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
 #[inline]
 fn parse_logs(
-    meta: &TransactionStatusMeta,
-    transaction: &Option<yellowstone_grpc_proto::prelude::Transaction>,
-    logs: &[String],
-    sig: solana_sdk::signature::Signature,
-    slot: u64,
-    tx_idx: u64,
-    block_us: Option<i64>,
-    grpc_us: i64,
-    filter: Option<&EventTypeFilter>,
-) -> Vec<DexEvent> {
+    ctx: TxContext<'_>,
+    retain_raw: bool,
+    limits: ParseLimits,
+    deadline: ParseDeadline,
+) -> (Vec<DexEvent>, Option<TruncateReason>) {
+    let TxContext { meta, transaction, keys, filter, .. } = ctx;
+    let logs = &meta.log_messages;
     let needs_pumpfun = filter.map(|f| f.includes_pumpfun()).unwrap_or(true);
     let has_create = needs_pumpfun && crate::logs::optimized_matcher::detect_pumpfun_create(logs);
 
-    let mut outer_idx: i32 = -1;
-    let mut inner_idx: i32 = -1;
+    let mut cursor = crate::logs::optimized_matcher::InvokeCursor::new();
     let mut invokes: HashMap<&str, Vec<(i32, i32)>> = HashMap::with_capacity(8);
     let mut result = Vec::with_capacity(4);
+    let (mut count, mut truncated) = (0usize, None);
+    let metadata = ctx.metadata();
 
-    for log in logs {
-        if let Some((pid, depth)) = crate::logs::optimized_matcher::parse_invoke_info(log) {
-            if depth == 1 {
-                inner_idx = -1;
-                outer_idx += 1;
-            } else {
-                inner_idx += 1;
+    // 同一交易的所有日志共用解码缓冲区
+    crate::logs::with_decode_context(|ctx| {
+        for (i, log) in logs.iter().enumerate() {
+            if deadline.expired() {
+                truncated = Some(TruncateReason::TimeBudget);
+                break;
+            }
+            if let Some((pid, depth)) = cursor.observe(log) {
+                count += 1;
+                if limits.count_exceeded(count) {
+                    truncated = Some(TruncateReason::MaxInstructions);
+                    break;
+                }
+                if limits.depth_exceeded(depth as u32) {
+                    truncated = truncated.max(Some(TruncateReason::MaxDepth));
+                }
+                let (outer_idx, inner_idx) = cursor.position();
+                invokes
+                    .entry(pid)
+                    .or_default()
+                    .push((outer_idx as i32, inner_idx.map_or(-1, |i| i as i32)));
+            }
+            // 过深的 CPI 输出的日志不解析
+            if limits.depth_exceeded(cursor.depth() as u32) {
+                continue;
             }
-            invokes.entry(pid).or_default().push((outer_idx, inner_idx));
-        }
-
-        if PROGRAM_DATA_FINDER.find(log.as_bytes()).is_none() {
-            continue;
-        }
 
-        if let Some(mut e) =
-            crate::logs::parse_log(log, sig, slot, tx_idx, block_us, grpc_us, filter, has_create)
-        {
-            crate::core::account_dispatcher::fill_accounts_from_transaction_data(
-                &mut e,
-                meta,
-                transaction,
-                &invokes,
-            );
-            crate::core::common_filler::fill_data(&mut e, meta, transaction, &invokes);
-            result.push(e);
+            if let Some(mut e) = crate::logs::parse_log_with_context(
+                ctx,
+                i,
+                log,
+                metadata,
+                cursor.program(),
+                filter,
+                has_create,
+            ) {
+                if let Some(m) = e.metadata_mut() {
+                    (m.outer_index, m.inner_index) = cursor.position();
+                }
+                crate::core::account_dispatcher::fill_accounts_from_transaction_data(
+                    &mut e,
+                    meta,
+                    transaction,
+                    keys,
+                    &invokes,
+                );
+                crate::core::common_filler::fill_data(&mut e, meta, transaction, &invokes);
+                let raw =
+                    retain_raw.then(|| raw_data_event(&e, RawSource::Log(log.as_str().into())));
+                result.push(e);
+                result.extend(raw);
+            }
         }
-    }
-    result
+    });
+    (result, truncated)
 }
 
 #[inline]
 fn parse_instructions(
-    meta: &TransactionStatusMeta,
-    transaction: &Option<yellowstone_grpc_proto::prelude::Transaction>,
-    sig: solana_sdk::signature::Signature,
-    slot: u64,
-    tx_idx: u64,
-    block_us: Option<i64>,
-    grpc_us: i64,
-    filter: Option<&EventTypeFilter>,
-) -> Vec<DexEvent> {
+    ctx: TxContext<'_>,
+    limits: ParseLimits,
+    deadline: ParseDeadline,
+) -> (Vec<DexEvent>, Option<TruncateReason>) {
     // 使用增强的 instruction 解析器
     // 支持：
     // - 主指令解析（8字节 discriminator）
     // - Inner instruction 解析（16字节 discriminator）
     // - 自动事件合并（instruction + inner instruction）
-    crate::grpc::instruction_parser::parse_instructions_enhanced(
-        meta,
-        transaction,
-        sig,
-        slot,
-        tx_idx,
-        block_us,
-        grpc_us,
-        filter,
-    )
+    crate::grpc::instruction_parser::parse_instructions_limited(ctx, limits, deadline)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grpc::checkpoint::MemoryCheckpointStore;
+    use std::sync::atomic::AtomicUsize;
+
+    /// 直接构造客户端，避免 `new_with_config` 触发全局预热影响 warmup 测试
+    fn test_client() -> YellowstoneGrpc {
+        YellowstoneGrpc {
+            endpoint: String::new(),
+            token: None,
+            config: ClientConfig::default(),
+            control_tx: Arc::new(Mutex::new(None)),
+            client_events: broadcast::channel(1).0,
+            status: Arc::new(ClientStatus::default()),
+            transformers: TransformerChain::new(),
+            execution_hooks: ExecutionHooks::new(),
+            leader_schedule: None,
+            tx_accounts: Arc::new(TransactionAccountsCache::default()),
+            first_swap: Arc::new(FirstSwapTracker::default()),
+            migrations: None,
+            checkpoint: None,
+            replay: Arc::new(ReplayState::default()),
+            recv_clock: None,
+        }
+    }
+
+    fn slot_update(slot: u64) -> SubscribeUpdate {
+        SubscribeUpdate {
+            update_oneof: Some(subscribe_update::UpdateOneof::Slot(SubscribeUpdateSlot {
+                slot,
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+    }
+
+    fn pong() -> SubscribeUpdate {
+        SubscribeUpdate {
+            update_oneof: Some(subscribe_update::UpdateOneof::Pong(SubscribeUpdatePong { id: 1 })),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_watchdog_disabled_by_zero_timeout() {
+        assert!(StreamWatchdog::new(0).is_none());
+    }
+
+    #[test]
+    fn test_watchdog_detects_no_message() {
+        let mut watchdog = StreamWatchdog::new(1000).unwrap();
+        watchdog.on_update(update_slot(&slot_update(10)));
+
+        let now = Instant::now();
+        assert_eq!(watchdog.check(now), None);
+        match watchdog.check(now + Duration::from_millis(1500)) {
+            Some(ClientEvent::StaleStream { reason, last_slot, idle_ms }) => {
+                assert_eq!(reason, StaleReason::NoMessage);
+                assert_eq!(last_slot, 10);
+                assert!(idle_ms >= 1000);
+            }
+            other => panic!("unexpected: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_watchdog_detects_no_slot_progress() {
+        let mut watchdog = StreamWatchdog::new(1000).unwrap();
+        watchdog.on_update(update_slot(&slot_update(10)));
+        watchdog.last_progress -= Duration::from_millis(2000);
+
+        // pong 和重复的 slot 只刷新消息时间，不算 slot 推进
+        watchdog.on_update(update_slot(&pong()));
+        watchdog.on_update(update_slot(&slot_update(10)));
+        assert!(matches!(
+            watchdog.check(Instant::now()),
+            Some(ClientEvent::StaleStream { reason: StaleReason::NoSlotProgress, .. })
+        ));
+
+        watchdog.on_update(update_slot(&slot_update(11)));
+        assert_eq!(watchdog.check(Instant::now()), None);
+    }
+
+    #[test]
+    fn test_ping_interval_respects_watchdog() {
+        let mut config = ClientConfig::default();
+        assert_eq!(ping_interval(&config), Duration::from_millis(30000));
+        config.stale_stream_timeout_ms = 20000;
+        assert_eq!(ping_interval(&config), Duration::from_millis(10000));
+    }
+
+    struct Collector(parking_lot::Mutex<Vec<u64>>);
+
+    #[async_trait::async_trait]
+    impl AsyncEventListener for Collector {
+        async fn on_event(&self, event: DexEvent) {
+            tokio::task::yield_now().await;
+            self.0.lock().push(event.metadata().slot);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_to_async_listener() {
+        for concurrency in [1, 4] {
+            let queue = Arc::new(ArrayQueue::new(16));
+            for slot in 0..10 {
+                let metadata = EventMetadata { slot, ..Default::default() };
+                let _ = queue
+                    .push(DexEvent::BlockMeta(BlockMetaEvent { metadata, ..Default::default() }));
+            }
+            let listener = Arc::new(Collector(parking_lot::Mutex::new(Vec::new())));
+            let handle =
+                tokio::spawn(dispatch_to_listener(queue, Arc::clone(&listener), concurrency, None));
+            while listener.0.lock().len() < 10 {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+            handle.abort();
+
+            let mut slots = listener.0.lock().clone();
+            if concurrency == 1 {
+                assert_eq!(slots, (0..10).collect::<Vec<_>>());
+            }
+            slots.sort_unstable();
+            assert_eq!(slots, (0..10).collect::<Vec<_>>());
+        }
+    }
+
+    struct Panicky(AtomicUsize);
+
+    #[async_trait::async_trait]
+    impl AsyncEventListener for Panicky {
+        async fn on_event(&self, event: DexEvent) {
+            self.0.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            if event.metadata().slot == 0 {
+                panic!("listener bug");
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_listener_panic_is_isolated() {
+        let queue = Arc::new(ArrayQueue::new(4));
+        for slot in 0..2 {
+            let metadata = EventMetadata { slot, ..Default::default() };
+            let _ =
+                queue.push(DexEvent::BlockMeta(BlockMetaEvent { metadata, ..Default::default() }));
+        }
+        let status = Arc::new(ClientStatus::default());
+        let listener = Arc::new(Panicky(AtomicUsize::new(0)));
+        let handle = tokio::spawn(dispatch_to_listener(
+            queue,
+            Arc::clone(&listener),
+            1,
+            Some(Arc::clone(&status)),
+        ));
+        while listener.0.load(std::sync::atomic::Ordering::Relaxed) < 2 {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+        assert!(!handle.is_finished());
+        handle.abort();
+        assert!(status.render_prometheus().contains("sol_parser_panics_total 1\n"));
+    }
+
+    #[test]
+    fn test_output_shards_keyed_by_pool() {
+        use crate::core::events::PumpFunTradeEvent;
+
+        let queues = OutputQueues::new(16, 4);
+        let (curve, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let trade = |bonding_curve| {
+            DexEvent::PumpFunBuy(PumpFunTradeEvent { bonding_curve, mint, ..Default::default() })
+        };
+
+        // 同一 pool 始终落在同一分片；无 pool 时退回 mint，都没有时为分片 0
+        let shard = shard_index(&trade(curve), 4);
+        assert_eq!(shard_index(&trade(curve), 4), shard);
+        assert_eq!(shard_index(&trade(Pubkey::default()), 4), shard_index(&trade(mint), 4));
+        assert_eq!(shard_index(&DexEvent::Error("x".into()), 4), 0);
+        assert_eq!(shard_index(&trade(curve), 1), 0);
+
+        assert!(queues.select(&trade(curve)).push(trade(curve)).is_ok());
+        assert_eq!(queues.shards[shard].len(), 1);
+        assert_eq!(OutputQueues::new(16, 0).shards.len(), 1);
+    }
+
+    #[test]
+    fn test_direct_callback_output() {
+        let client = test_client();
+        let slots = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let sink = Arc::clone(&slots);
+        let output = EventOutput::Direct(parking_lot::Mutex::new(Box::new(move |e: DexEvent| {
+            assert_ne!(e.metadata().slot, 1, "callback bug");
+            sink.lock().push(e.metadata().slot);
+        })));
+
+        for slot in 0..3 {
+            let metadata = EventMetadata { slot, ..Default::default() };
+            let event = DexEvent::BlockMeta(BlockMetaEvent { metadata, ..Default::default() });
+            client.push_event(&output, event);
+        }
+        // 回调 panic 不影响后续事件
+        assert_eq!(*slots.lock(), vec![0, 2]);
+        let stats = client.stats();
+        assert_eq!((stats.events_emitted, stats.panics, stats.events_dropped), (2, 1, 0));
+    }
+
+    #[test]
+    fn test_checkpoint_only_advances_on_callback_delivery() {
+        let client = test_client().with_checkpoint_store(Arc::new(MemoryCheckpointStore::new()));
+        let event = |slot| {
+            let metadata = EventMetadata { slot, ..Default::default() };
+            DexEvent::BlockMeta(BlockMetaEvent { metadata, ..Default::default() })
+        };
+
+        // 入队不推进断点，消费者确认后推进
+        let queues = OutputQueues::new(16, 1);
+        let queue = queues.shards[0].clone();
+        let queues = EventOutput::Queues(queues);
+        (0..3).for_each(|slot| client.push_event(&queues, event(slot)));
+        assert_eq!(client.checkpoint(), None);
+        while let Some(event) = queue.pop() {
+            client.ack(event.metadata());
+        }
+        assert_eq!(client.checkpoint().map(|c| c.slot), Some(1));
+
+        let direct = EventOutput::Direct(parking_lot::Mutex::new(Box::new(|_: DexEvent| {})));
+        (3..6).for_each(|slot| client.push_event(&direct, event(slot)));
+        assert_eq!(client.checkpoint().map(|c| c.slot), Some(4));
+    }
+
+    #[test]
+    fn test_batched_output_flushes_on_slot_boundary() {
+        let client = test_client();
+        let batches = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let sink = Arc::clone(&batches);
+        let output = EventOutput::Batched(BatchedOutput::new(
+            Duration::from_millis(5),
+            Box::new(move |events: Vec<DexEvent>| {
+                sink.lock().push(events.iter().map(|e| e.metadata().tx_index).collect::<Vec<_>>());
+            }),
+        ));
+        assert!(output.batch_deadline().is_none());
+
+        for (slot, tx_index) in [(10, 0), (10, 1), (11, 0)] {
+            let metadata = EventMetadata { slot, tx_index, ..Default::default() };
+            let event = DexEvent::BlockMeta(BlockMetaEvent { metadata, ..Default::default() });
+            client.push_event(&output, event);
+        }
+        // slot 变化时交付上一 slot 的批次，新批次等待到期
+        assert_eq!(*batches.lock(), vec![vec![0, 1]]);
+        assert!(output.batch_deadline().is_some());
+
+        client.flush_batch(&output);
+        assert_eq!(*batches.lock(), vec![vec![0, 1], vec![0]]);
+        assert!(output.batch_deadline().is_none());
+        assert_eq!(client.stats().events_emitted, 3);
+    }
+
+    #[test]
+    fn test_stream_runtime_profiles() {
+        for profile in
+            [LatencyProfile::Standard, LatencyProfile::Dedicated, LatencyProfile::BusyPoll]
+        {
+            let (tx, rx) = tokio::sync::oneshot::channel();
+            let task = async move {
+                tokio::time::sleep(Duration::from_millis(2)).await;
+                let value = tokio::spawn(async { 7 }).await.unwrap();
+                tx.send(value).unwrap();
+            };
+            run_stream_runtime(profile, task).unwrap();
+            assert_eq!(rx.blocking_recv(), Ok(7), "{:?}", profile);
+        }
+    }
+
+    #[test]
+    fn test_retain_raw_instruction_data() {
+        use yellowstone_grpc_proto::prelude::{CompiledInstruction, Message, Transaction};
+
+        let mut data = crate::instr::raydium_cpmm::discriminators::INITIALIZE.to_vec();
+        data.extend_from_slice(&[0u8; 24]);
+        let mut account_keys: Vec<Vec<u8>> =
+            (0..20).map(|_| Pubkey::new_unique().to_bytes().to_vec()).collect();
+        account_keys.push(crate::instr::program_ids::RAYDIUM_CPMM_PROGRAM_ID.to_bytes().to_vec());
+        let info = SubscribeUpdateTransactionInfo {
+            signature: vec![1; 64],
+            meta: Some(TransactionStatusMeta::default()),
+            transaction: Some(Transaction {
+                message: Some(Message {
+                    account_keys,
+                    instructions: vec![CompiledInstruction {
+                        program_id_index: 20,
+                        accounts: (0..20).collect(),
+                        data: data.clone(),
+                    }],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let limits = ParseLimits::default();
+        assert_eq!(parse_transaction_info(&info, 1, 0, None, None, false, limits).0.len(), 1);
+        let (events, _) = parse_transaction_info(&info, 1, 0, None, None, true, limits);
+        let [event, DexEvent::RawData(raw)] = events.as_slice() else { panic!("{:?}", events) };
+        assert_eq!(raw.source, RawSource::Instruction(data.into()));
+        assert_eq!(raw.metadata.event_id(&raw.event_type), event.event_id());
+    }
+
+    #[test]
+    fn test_parse_limits_truncate() {
+        use yellowstone_grpc_proto::prelude::{
+            CompiledInstruction, InnerInstruction, InnerInstructions, Message, Transaction,
+        };
+
+        let mut data = crate::instr::raydium_cpmm::discriminators::INITIALIZE.to_vec();
+        data.extend_from_slice(&[0u8; 24]);
+        let mut account_keys: Vec<Vec<u8>> =
+            (0..20).map(|_| Pubkey::new_unique().to_bytes().to_vec()).collect();
+        account_keys.push(crate::instr::program_ids::RAYDIUM_CPMM_PROGRAM_ID.to_bytes().to_vec());
+        let cpmm = crate::instr::program_ids::RAYDIUM_CPMM_PROGRAM_ID;
+        let info = SubscribeUpdateTransactionInfo {
+            signature: vec![1; 64],
+            meta: Some(TransactionStatusMeta {
+                inner_instructions: vec![InnerInstructions {
+                    index: 0,
+                    instructions: vec![InnerInstruction {
+                        program_id_index: 20,
+                        stack_height: Some(3),
+                        ..Default::default()
+                    }],
+                }],
+                log_messages: vec![
+                    format!("Program {} invoke [1]", cpmm),
+                    format!("Program {} invoke [2]", cpmm),
+                    format!("Program {} invoke [3]", cpmm),
+                ],
+                ..Default::default()
+            }),
+            transaction: Some(Transaction {
+                message: Some(Message {
+                    account_keys,
+                    instructions: vec![
+                        CompiledInstruction {
+                            program_id_index: 20,
+                            accounts: (0..20).collect(),
+                            data,
+                        },
+                        CompiledInstruction { program_id_index: 20, ..Default::default() },
+                    ],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let parse = |max_depth, max_instructions| {
+            let limits = ParseLimits { max_depth, max_instructions, ..Default::default() };
+            let (events, truncated) =
+                parse_transaction_info(&info, 1, 0, None, None, false, limits);
+            if let Some(reason) = truncated {
+                // 已解析的事件之后附带截断标记
+                let Some(DexEvent::ParseTruncated(marker)) = events.last() else { panic!() };
+                assert_eq!((marker.reason, marker.metadata.slot), (reason, 1));
+            }
+            (events.len(), truncated)
+        };
+        assert_eq!(parse(0, 0), (1, None));
+        assert_eq!(parse(3, 5), (1, None));
+        // 过深的内层指令与日志跳过，外层事件照常输出
+        assert_eq!(parse(2, 0), (2, Some(TruncateReason::MaxDepth)));
+        assert_eq!(parse(0, 2), (2, Some(TruncateReason::MaxInstructions)));
+        assert_eq!(parse(1, 1), (2, Some(TruncateReason::MaxInstructions)));
+
+        // 超出时间预算后不再解析
+        let limits = ParseLimits { time_budget_us: 1, ..Default::default() };
+        let deadline = limits.deadline();
+        std::thread::sleep(Duration::from_millis(1));
+        assert!(deadline.expired() && !ParseLimits::default().deadline().expired());
+        let meta = info.meta.as_ref().unwrap();
+        let keys = TransactionKeys::from_grpc(meta, &info.transaction);
+        let ctx = TxContext {
+            meta,
+            transaction: &info.transaction,
+            keys: &keys,
+            sig: Default::default(),
+            slot: 1,
+            tx_idx: 0,
+            block_us: None,
+            grpc_us: 0,
+            filter: None,
+        };
+        let (events, truncated) = parse_instructions(ctx, limits, deadline);
+        assert!(events.is_empty());
+        assert_eq!(truncated, Some(TruncateReason::TimeBudget));
+    }
+
+    #[test]
+    fn test_transaction_accounts_side_channel() {
+        use yellowstone_grpc_proto::prelude::{
+            CompiledInstruction, Message, MessageHeader, Transaction,
+        };
+
+        let mut data = crate::instr::raydium_cpmm::discriminators::INITIALIZE.to_vec();
+        data.extend_from_slice(&[0u8; 24]);
+        let mut keys: Vec<Pubkey> = (0..20).map(|_| Pubkey::new_unique()).collect();
+        keys.push(crate::instr::program_ids::RAYDIUM_CPMM_PROGRAM_ID);
+        let (alt_writable, alt_readonly) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let tx = SubscribeUpdateTransaction {
+            slot: 1,
+            transaction: Some(SubscribeUpdateTransactionInfo {
+                signature: vec![1; 64],
+                meta: Some(TransactionStatusMeta {
+                    loaded_writable_addresses: vec![alt_writable.to_bytes().to_vec()],
+                    loaded_readonly_addresses: vec![alt_readonly.to_bytes().to_vec()],
+                    ..Default::default()
+                }),
+                transaction: Some(Transaction {
+                    message: Some(Message {
+                        header: Some(MessageHeader {
+                            num_required_signatures: 2,
+                            num_readonly_signed_accounts: 1,
+                            num_readonly_unsigned_accounts: 1,
+                        }),
+                        account_keys: keys.iter().map(|k| k.to_bytes().to_vec()).collect(),
+                        instructions: vec![CompiledInstruction {
+                            program_id_index: 20,
+                            accounts: (0..20).collect(),
+                            data,
+                        }],
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+        };
+        let config = ClientConfig {
+            emit_transaction_accounts: true,
+            transaction_accounts_cache: 4,
+            ..Default::default()
+        };
+        let client = YellowstoneGrpc {
+            tx_accounts: Arc::new(TransactionAccountsCache::new(config.transaction_accounts_cache)),
+            config,
+            ..test_client()
+        };
+
+        let events = client.parse_guarded(&tx, 0, 0, &None);
+        let [DexEvent::TransactionAccounts(event), parsed] = events.as_slice() else {
+            panic!("{:?}", events)
+        };
+        assert_eq!(event.metadata.signature, parsed.metadata().signature);
+        let accounts = &event.accounts;
+        keys.extend([alt_writable, alt_readonly]);
+        assert_eq!(accounts.keys, keys);
+        assert_eq!(
+            (accounts.is_signer(0), accounts.is_signer(1), accounts.is_signer(2)),
+            (true, true, false)
+        );
+        assert!(accounts.is_writable(0) && !accounts.is_writable(1) && accounts.is_writable(19));
+        assert!(!accounts.is_writable(20) && accounts.is_writable(21) && !accounts.is_writable(22));
+        let cached = client.transaction_accounts(&event.metadata.signature).unwrap();
+        assert_eq!(*cached, *accounts);
+    }
+
+    #[test]
+    fn test_transaction_outcome() {
+        use yellowstone_grpc_proto::prelude::{Message, Transaction, TransactionError};
+
+        let tx = |keys: Vec<Pubkey>, is_vote: bool, failed: bool| SubscribeUpdateTransaction {
+            slot: 1,
+            transaction: Some(SubscribeUpdateTransactionInfo {
+                is_vote,
+                meta: Some(TransactionStatusMeta {
+                    err: failed.then(|| TransactionError { err: vec![1] }),
+                    ..Default::default()
+                }),
+                transaction: Some(Transaction {
+                    message: Some(Message {
+                        account_keys: keys.iter().map(|k| k.to_bytes().to_vec()).collect(),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+        };
+        let dex = vec![Pubkey::new_unique(), crate::instr::program_ids::PUMPSWAP_PROGRAM_ID];
+        let other = vec![Pubkey::new_unique()];
+        let event = DexEvent::PumpSwapBuy(Default::default());
+
+        assert_eq!(
+            transaction_outcome(&tx(dex.clone(), false, false), &[event]),
+            TransactionOutcome::WithEvents
+        );
+        assert_eq!(
+            transaction_outcome(&tx(other.clone(), true, false), &[]),
+            TransactionOutcome::Vote
+        );
+        assert_eq!(
+            transaction_outcome(&tx(dex.clone(), false, true), &[]),
+            TransactionOutcome::Failed
+        );
+        assert_eq!(
+            transaction_outcome(&tx(other, false, false), &[]),
+            TransactionOutcome::NoKnownProgram
+        );
+        assert_eq!(transaction_outcome(&tx(dex, false, false), &[]), TransactionOutcome::NoEvents);
+    }
+
+    #[test]
+    fn test_replay_marks_events_until_live_slot() {
+        use crate::core::events::PumpSwapBuyEvent;
+
+        let swap = |slot| {
+            DexEvent::PumpSwapBuy(PumpSwapBuyEvent {
+                metadata: EventMetadata { slot, ..Default::default() },
+                ..Default::default()
+            })
+        };
+        let replay = ReplayState::default();
+        replay.live_slot.store(100, Ordering::Relaxed);
+
+        let mut events = vec![swap(90), DexEvent::Error("e".to_string())];
+        replay.mark(90, &mut events);
+        assert!(events[0].metadata().replayed);
+
+        // 收到实时 slot 的更新后结束重放，只通知一次
+        assert_eq!(replay.observe(Some(99)), None);
+        assert_eq!(replay.observe(Some(100)), Some(100));
+        assert_eq!(replay.observe(Some(101)), None);
+        let mut live = vec![swap(95)];
+        replay.mark(95, &mut live);
+        assert!(!live[0].metadata().replayed);
+    }
+
+    #[test]
+    fn test_first_swap_in_slot() {
+        use crate::core::events::PumpSwapBuyEvent;
+
+        let (pool, other) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let swap = |pool, slot| {
+            DexEvent::PumpSwapBuy(PumpSwapBuyEvent {
+                metadata: EventMetadata { slot, ..Default::default() },
+                pool,
+                ..Default::default()
+            })
+        };
+        let tracker = FirstSwapTracker::new([pool]);
+        let markers = |events: &[DexEvent]| -> Vec<(u64, u64)> {
+            events
+                .iter()
+                .filter_map(|e| match e {
+                    DexEvent::FirstSwapInSlot(m) => Some((m.metadata.slot, m.previous_slot)),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        let mut events = vec![swap(other, 5), swap(pool, 5), swap(pool, 5)];
+        tracker.mark(&mut events);
+        assert_eq!(markers(&events), vec![(5, 0)]);
+        assert!(matches!(events[1], DexEvent::FirstSwapInSlot(_)));
+        assert_eq!(events.len(), 4);
+
+        // 同一 slot 的后续交易和乱序的旧 slot 都不再标记
+        let mut events = vec![swap(pool, 5), swap(pool, 4)];
+        tracker.mark(&mut events);
+        assert!(markers(&events).is_empty());
+
+        let mut events = vec![swap(pool, 6)];
+        tracker.mark(&mut events);
+        assert_eq!(markers(&events), vec![(6, 5)]);
+
+        let mut events = vec![swap(pool, 7)];
+        FirstSwapTracker::default().mark(&mut events);
+        assert_eq!(events.len(), 1);
+    }
+
+    #[test]
+    fn test_block_meta_leader() {
+        let (fee_leader, scheduled) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let schedule = Arc::new(LeaderSchedule::new());
+        schedule.insert(7, scheduled);
+        let client = test_client().with_leader_schedule(schedule);
+
+        let mut meta = SubscribeUpdateBlockMeta { slot: 7, ..Default::default() };
+        let DexEvent::BlockMeta(event) = client.block_meta_event(&meta, 0) else { panic!() };
+        assert_eq!((event.metadata.slot, event.leader), (7, scheduled));
+
+        // 手续费奖励优先于注入的 schedule
+        meta.rewards = Some(Rewards {
+            rewards: vec![Reward {
+                pubkey: fee_leader.to_string(),
+                reward_type: RewardType::Fee as i32,
+                ..Default::default()
+            }],
+            num_partitions: None,
+        });
+        assert_eq!(client.slot_leader(&meta), Some(fee_leader));
+    }
+
+    #[test]
+    fn test_external_recv_clock() {
+        let client = test_client().with_recv_clock(|update| {
+            matches!(update.update_oneof, Some(subscribe_update::UpdateOneof::BlockMeta(_)))
+                .then_some(42)
+        });
+        let block_meta = SubscribeUpdate {
+            update_oneof: Some(subscribe_update::UpdateOneof::BlockMeta(Default::default())),
+            ..Default::default()
+        };
+        assert_eq!(client.recv_us(&block_meta), 42);
+        // 外部时钟没有时间戳时退回内部时钟
+        assert!(client.recv_us(&SubscribeUpdate::default()) > 42);
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        let status = ClientStatus::default();
+        assert_eq!(catch_panic(true, &status, || 1), Ok(1));
+        let err = catch_panic(true, &status, || -> u32 { panic!("bad offset {}", 3) });
+        assert_eq!(err, Err("bad offset 3".to_string()));
+        assert!(status.render_prometheus().contains("sol_parser_panics_total 1\n"));
+    }
 }
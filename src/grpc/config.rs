@@ -1,4 +1,249 @@
 pub use crate::grpc::types::{ClientConfig, SlotFilter};
 
 // Re-export for backwards compatibility
-pub use crate::grpc::types::ClientConfig as StreamingConfig;
\ No newline at end of file
+pub use crate::grpc::types::ClientConfig as StreamingConfig;
+
+use super::client::YellowstoneGrpc;
+use super::sink::SinkConfig;
+use super::types::{AccountFilter, EventTypeFilter, TransactionFilter};
+use serde::Deserialize;
+#[cfg(feature = "config-file")]
+use std::path::Path;
+
+/// 声明式订阅配置（TOML / YAML）
+///
+/// 运维部署修改订阅内容时无需重新编译（文件加载需启用 `config-file` feature）：
+///
+/// ```toml
+/// [[endpoints]]
+/// url = "https://solana-yellowstone-grpc.publicnode.com:443"
+/// token_env = "GRPC_TOKEN"
+///
+/// [client]
+/// order_mode = "MicroBatch"
+/// backpressure = "DropOldest"
+///
+/// [[transactions]]
+/// account_include = ["6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P"]
+///
+/// [event_types]
+/// include_only = ["PumpFunTrade", "PumpFunCreate"]
+///
+/// [[sinks]]
+/// type = "file"
+/// path = "events.jsonl"
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct SubscriptionConfig {
+    pub endpoints: Vec<EndpointConfig>,
+    /// 客户端参数（顺序模式、看门狗、队列容量、背压策略等），缺省字段使用默认值
+    #[serde(default)]
+    pub client: ClientConfig,
+    #[serde(default)]
+    pub transactions: Vec<TransactionFilter>,
+    #[serde(default)]
+    pub accounts: Vec<AccountFilter>,
+    #[serde(default)]
+    pub event_types: Option<EventTypeFilter>,
+    #[serde(default)]
+    pub sinks: Vec<SinkConfig>,
+}
+
+/// gRPC 端点
+#[derive(Debug, Clone, Deserialize)]
+pub struct EndpointConfig {
+    pub url: String,
+    #[serde(default)]
+    pub token: Option<String>,
+    /// 从环境变量读取 token，避免把凭证写入配置文件
+    #[serde(default)]
+    pub token_env: Option<String>,
+}
+
+impl EndpointConfig {
+    /// 解析 token：`token` 优先，其次 `token_env`
+    pub fn resolve_token(&self) -> Result<Option<String>, ConfigError> {
+        match (&self.token, &self.token_env) {
+            (Some(token), _) => Ok(Some(token.clone())),
+            (None, Some(var)) => std::env::var(var)
+                .map(Some)
+                .map_err(|_| ConfigError::Invalid(format!("environment variable {} not set", var))),
+            (None, None) => Ok(None),
+        }
+    }
+}
+
+/// 配置文件错误
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(std::io::Error),
+    Parse(String),
+    Invalid(String),
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Io(e) => write!(f, "Config IO error: {}", e),
+            ConfigError::Parse(msg) => write!(f, "Config parse error: {}", msg),
+            ConfigError::Invalid(msg) => write!(f, "Invalid config: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl SubscriptionConfig {
+    /// 从文件加载，按扩展名选择格式（`.toml` / `.yaml` / `.yml`）
+    #[cfg(feature = "config-file")]
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(ConfigError::Io)?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("toml") => Self::from_toml_str(&content),
+            Some("yaml") | Some("yml") => Self::from_yaml_str(&content),
+            _ => Err(ConfigError::Invalid(format!(
+                "unsupported config extension: {}",
+                path.display()
+            ))),
+        }
+    }
+
+    #[cfg(feature = "config-file")]
+    pub fn from_toml_str(content: &str) -> Result<Self, ConfigError> {
+        let config: Self =
+            toml::from_str(content).map_err(|e| ConfigError::Parse(e.to_string()))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    #[cfg(feature = "config-file")]
+    pub fn from_yaml_str(content: &str) -> Result<Self, ConfigError> {
+        let config: Self =
+            serde_norway::from_str(content).map_err(|e| ConfigError::Parse(e.to_string()))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    #[cfg(feature = "config-file")]
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.endpoints.is_empty() {
+            return Err(ConfigError::Invalid("at least one endpoint is required".to_string()));
+        }
+        if self.transactions.is_empty() && self.accounts.is_empty() {
+            return Err(ConfigError::Invalid(
+                "at least one transaction or account filter is required".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// 为每个端点创建客户端（共享同一份 `client` 配置）
+    ///
+    /// 多端点同时订阅时，同一事件会从每个端点各收到一次，可用 `DexEvent::event_id()` 去重。
+    pub fn build_clients(&self) -> Result<Vec<YellowstoneGrpc>, Box<dyn std::error::Error>> {
+        self.endpoints
+            .iter()
+            .map(|ep| {
+                YellowstoneGrpc::new_with_config(
+                    ep.url.clone(),
+                    ep.resolve_token()?,
+                    self.client.clone(),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "config-file")]
+impl ClientConfig {
+    /// 从订阅配置文件中读取 `client` 部分
+    ///
+    /// 端点、过滤器和输出等完整配置见 [`SubscriptionConfig::from_file`]。
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        SubscriptionConfig::from_file(path).map(|c| c.client)
+    }
+}
+
+#[cfg(all(test, feature = "config-file"))]
+mod tests {
+    use super::*;
+    use crate::grpc::types::{BackpressurePolicy, EventType, OrderMode};
+
+    const TOML: &str = r#"
+        [[endpoints]]
+        url = "https://grpc.example.com:443"
+        token = "secret"
+
+        [client]
+        order_mode = "MicroBatch"
+        backpressure = "DropOldest"
+        queue_capacity = 1024
+
+        [[transactions]]
+        account_include = ["6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P"]
+
+        [[accounts]]
+        owner = ["pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA"]
+
+        [event_types]
+        include_only = ["PumpFunTrade", "PumpSwapBuy"]
+
+        [[sinks]]
+        type = "stdout"
+    "#;
+
+    #[test]
+    fn test_parse_toml_config() {
+        let config = SubscriptionConfig::from_toml_str(TOML).unwrap();
+        assert_eq!(config.endpoints[0].resolve_token().unwrap().as_deref(), Some("secret"));
+        assert_eq!(config.client.order_mode, OrderMode::MicroBatch);
+        assert_eq!(config.client.backpressure, BackpressurePolicy::DropOldest);
+        assert_eq!(config.client.queue_capacity, 1024);
+        // 未写出的字段保持默认值
+        assert_eq!(config.client.micro_batch_us, ClientConfig::default().micro_batch_us);
+        assert!(config.transactions[0].account_exclude.is_empty());
+        assert_eq!(config.accounts[0].owner.len(), 1);
+
+        let filter = config.event_types.unwrap();
+        assert!(filter.should_include(EventType::PumpSwapBuy));
+        assert!(!filter.should_include(EventType::PumpSwapSell));
+        assert_eq!(config.sinks, vec![SinkConfig::Stdout]);
+    }
+
+    #[test]
+    fn test_parse_yaml_config() {
+        let yaml = r#"
+endpoints:
+  - url: https://grpc.example.com:443
+transactions:
+  - account_include: [pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA]
+event_types:
+  exclude_types: [BlockMeta]
+sinks:
+  - type: file
+    path: /tmp/events.jsonl
+"#;
+        let config = SubscriptionConfig::from_yaml_str(yaml).unwrap();
+        assert_eq!(config.endpoints[0].resolve_token().unwrap(), None);
+        assert_eq!(config.client.order_mode, OrderMode::Unordered);
+        assert!(!config.event_types.unwrap().should_include(EventType::BlockMeta));
+        assert_eq!(config.sinks, vec![SinkConfig::File { path: "/tmp/events.jsonl".into() }]);
+    }
+
+    #[test]
+    fn test_reject_config_without_endpoint_or_filter() {
+        assert!(matches!(
+            SubscriptionConfig::from_toml_str("endpoints = []"),
+            Err(ConfigError::Invalid(_))
+        ));
+        assert!(matches!(
+            SubscriptionConfig::from_toml_str("[[endpoints]]\nurl = \"http://localhost:10000\""),
+            Err(ConfigError::Invalid(_))
+        ));
+        assert!(matches!(
+            SubscriptionConfig::from_toml_str("endpoints = 1"),
+            Err(ConfigError::Parse(_))
+        ));
+    }
+}
@@ -3,8 +3,19 @@ pub use crate::grpc::types::{
     EventTypeFilter,
 };
 
-use crate::grpc::types::Protocol;
+use crate::analytics::{PoolRegistry, USDC_MINT, WSOL_MINT};
+use crate::core::events::DexEvent;
+use crate::core::Transformer;
+use crate::grpc::config::ConfigError;
 use crate::grpc::program_ids::get_program_ids_for_protocols;
+use crate::grpc::types::{EventType, Protocol};
+use parking_lot::RwLock;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
 
 impl TransactionFilter {
     pub fn for_protocols(protocols: &[Protocol]) -> Self {
@@ -17,6 +28,92 @@ impl TransactionFilter {
     }
 }
 
+impl TransactionFilter {
+    /// 按事件类型过滤器推导服务端交易过滤器（`account_include` 为产出这些事件的程序）
+    ///
+    /// 只设置了事件类型时，用它代替不含任何账户的过滤器，避免订阅全网交易后再在客户端过滤。
+    /// 无法推导时返回 None，见 [`EventTypeFilter::protocols`]。
+    pub fn for_event_types(filter: &EventTypeFilter) -> Option<Self> {
+        let protocols = filter.protocols().filter(|protocols| !protocols.is_empty())?;
+        Some(Self::for_protocols(&protocols))
+    }
+
+    /// 三个账户列表都为空，服务端会推送全部交易
+    pub fn is_unconstrained(&self) -> bool {
+        self.account_include.is_empty()
+            && self.account_exclude.is_empty()
+            && self.account_required.is_empty()
+    }
+}
+
+impl EventTypeFilter {
+    /// 产出 `include_only` 中事件的交易所属协议
+    ///
+    /// 以下情况返回 None：未设置 `include_only`（排除模式），包含按全部交易统计的
+    /// `PriorityFeeStats`，或包含可能来自 [`Protocol`] 未覆盖程序（Orca、Meteora Pools / DLMM）的类型。
+    /// 只含账户事件、`BlockMeta` 等不来自协议交易的类型时返回空列表。
+    pub fn protocols(&self) -> Option<Vec<Protocol>> {
+        let mut protocols = Vec::new();
+        for event_type in self.include_only.as_ref()? {
+            for protocol in event_protocols(*event_type)? {
+                if !protocols.contains(protocol) {
+                    protocols.push(*protocol);
+                }
+            }
+        }
+        Some(protocols)
+    }
+}
+
+/// 产出该事件的交易所属协议
+///
+/// None 表示无法用 [`Protocol`] 表达：与全部交易相关，或来源包含 `Protocol` 未覆盖的程序
+/// （返回部分协议会让服务端过滤掉其余来源的事件）。
+fn event_protocols(event_type: EventType) -> Option<&'static [Protocol]> {
+    use EventType::*;
+    use Protocol::*;
+
+    let protocols: &'static [Protocol] = match event_type {
+        PriorityFeeStats => return None,
+        // 建池 / swap 标记也来自 Orca Whirlpool、Meteora Pools 和 Meteora DLMM
+        FirstSwapInSlot | NormalizedPoolCreated => return None,
+        // 其他事件的附带输出、区块元数据和账户订阅的事件不需要交易过滤
+        BlockMeta
+        | RawData
+        | TransactionAccounts
+        | ParseTruncated
+        | TokenMetadataResolved
+        | ValidationWarning
+        | TokenAccount
+        | NonceAccount
+        | AccountPumpSwapGlobalConfig
+        | AccountPumpSwapPool
+        | AccountClosed
+        | AccountRaydiumAmmV4AmmInfo
+        | AccountRaydiumAmmV4TargetOrders
+        | AccountSerumOpenOrders => &[],
+        // PumpFun → PumpSwap，Bonk → Raydium CPMM / AMM V4
+        MigrationCompleted => &[PumpFun, PumpSwap, Bonk, RaydiumCpmm, RaydiumAmmV4],
+        BonkTrade | BonkPoolCreate | BonkMigrateAmm => &[Bonk],
+        PumpFunTrade | PumpFunBuy | PumpFunSell | PumpFunBuyExactSolIn | PumpFunCreate
+        | PumpFunComplete | PumpFunMigrate => &[PumpFun],
+        PumpSwapBuy
+        | PumpSwapSell
+        | PumpSwapCreatePool
+        | PumpSwapLiquidityAdded
+        | PumpSwapLiquidityRemoved
+        | PumpSwapPoolUpdated
+        | PumpSwapConfigUpdated
+        | PumpSwapCreatorFeeClaimed => &[PumpSwap],
+        MeteoraDammV2Swap
+        | MeteoraDammV2AddLiquidity
+        | MeteoraDammV2RemoveLiquidity
+        | MeteoraDammV2CreatePosition
+        | MeteoraDammV2ClosePosition => &[MeteoraDammV2],
+    };
+    Some(protocols)
+}
+
 impl AccountFilter {
     pub fn for_protocols(protocols: &[Protocol]) -> Self {
         let program_ids = get_program_ids_for_protocols(protocols);
@@ -26,4 +123,332 @@ impl AccountFilter {
             filters: Vec::new(),
         }
     }
-}
\ No newline at end of file
+}
+/// 按池子计价代币过滤事件（如只保留 SOL 交易对）
+///
+/// 池子的代币对优先从事件字段读取，其次查询 [`PoolRegistry`]（会从经过的建池 / swap 事件中学习）。
+/// 与池子无关的事件（block meta、账户事件等）总是保留；属于某个池子但无法确定代币对的事件
+/// 默认丢弃，可用 [`with_keep_unresolved`](Self::with_keep_unresolved) 改为保留。
+/// 实现了 [`Transformer`]，通过 `YellowstoneGrpc::with_transformer` 接入客户端。
+#[derive(Debug)]
+pub struct QuoteMintFilter {
+    quote_mints: HashSet<Pubkey>,
+    registry: RwLock<PoolRegistry>,
+    keep_unresolved: bool,
+}
+
+impl QuoteMintFilter {
+    pub fn new(quote_mints: impl IntoIterator<Item = Pubkey>) -> Self {
+        Self {
+            quote_mints: quote_mints.into_iter().collect(),
+            registry: RwLock::new(PoolRegistry::new()),
+            keep_unresolved: false,
+        }
+    }
+
+    /// 只保留以 WSOL 计价的池子
+    pub fn sol_only() -> Self {
+        Self::new([WSOL_MINT])
+    }
+
+    /// 只保留以 USDC 计价的池子
+    pub fn usdc_only() -> Self {
+        Self::new([USDC_MINT])
+    }
+
+    /// 使用预先加载的池子注册表
+    pub fn with_registry(mut self, registry: PoolRegistry) -> Self {
+        self.registry = RwLock::new(registry);
+        self
+    }
+
+    pub fn with_keep_unresolved(mut self, keep: bool) -> Self {
+        self.keep_unresolved = keep;
+        self
+    }
+
+    /// 事件是否通过过滤
+    pub fn matches(&self, event: &DexEvent) -> bool {
+        let Some(pool) = event.pool() else { return true };
+        let pair = match event {
+            // bonding curve 始终以 SOL 计价
+            DexEvent::PumpFunCreate(_)
+            | DexEvent::PumpFunTrade(_)
+            | DexEvent::PumpFunBuy(_)
+            | DexEvent::PumpFunSell(_)
+            | DexEvent::PumpFunBuyExactSolIn(_)
+            | DexEvent::PumpFunMigrate(_) => return self.quote_mints.contains(&WSOL_MINT),
+            _ => {
+                // 已知池子只需读锁，未知时才取写锁从事件中学习，避免解析线程在热路径上串行
+                let known = self.registry.read().get(&pool).map(|p| (p.mint_a, p.mint_b));
+                known.or_else(|| {
+                    let mut registry = self.registry.write();
+                    registry.observe(event);
+                    registry.get(&pool).map(|p| (p.mint_a, p.mint_b))
+                })
+            }
+        };
+        match pair {
+            Some((a, b)) => self.quote_mints.contains(&a) || self.quote_mints.contains(&b),
+            None => self.keep_unresolved,
+        }
+    }
+}
+
+impl Transformer for QuoteMintFilter {
+    #[inline]
+    fn transform(&self, event: DexEvent) -> Option<DexEvent> {
+        self.matches(&event).then_some(event)
+    }
+}
+
+/// 屏蔽名单：命中 mint、池子或用户钱包的事件被丢弃
+///
+/// 查找为哈希表 O(1)。克隆共享同一份名单，可一份交给客户端（`with_transformer`），
+/// 另一份用于运行中更新或 [`spawn_reload`](Self::spawn_reload) 定期从文件重新加载。
+///
+/// 文件格式为每行 `<类型> <pubkey>`，类型为 `mint` / `pool` / `user`，`#` 开头为注释：
+///
+/// ```text
+/// # spam creators
+/// mint 9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin
+/// user 5Q544fKrFoe6tsEbD7S8EmxGTJYAKtTVhAW5Q5pge4j1
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DenyList {
+    entries: Arc<RwLock<DenyEntries>>,
+}
+
+#[derive(Debug, Default)]
+struct DenyEntries {
+    mints: HashSet<Pubkey>,
+    pools: HashSet<Pubkey>,
+    users: HashSet<Pubkey>,
+}
+
+impl DenyEntries {
+    fn parse(content: &str) -> Result<Self, ConfigError> {
+        let mut entries = Self::default();
+        for (n, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = || ConfigError::Parse(format!("line {}: {}", n + 1, line));
+            let (kind, key) = line.split_once(char::is_whitespace).ok_or_else(invalid)?;
+            let key: Pubkey = key.trim().parse().map_err(|_| invalid())?;
+            let set = match kind {
+                "mint" => &mut entries.mints,
+                "pool" => &mut entries.pools,
+                "user" => &mut entries.users,
+                _ => return Err(invalid()),
+            };
+            set.insert(key);
+        }
+        Ok(entries)
+    }
+}
+
+impl DenyList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 从文件加载（格式见类型说明）
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let list = Self::new();
+        list.reload(path)?;
+        Ok(list)
+    }
+
+    /// 用文件内容整体替换当前名单，解析失败时保持原名单不变
+    pub fn reload(&self, path: impl AsRef<Path>) -> Result<(), ConfigError> {
+        let content = std::fs::read_to_string(path).map_err(ConfigError::Io)?;
+        *self.entries.write() = DenyEntries::parse(&content)?;
+        Ok(())
+    }
+
+    /// 每隔 `interval` 检查文件修改时间，有变化时重新加载
+    ///
+    /// 加载失败只记录日志并保留原名单，任务随返回的句柄 abort 而停止。
+    pub fn spawn_reload(&self, path: impl Into<PathBuf>, interval: Duration) -> JoinHandle<()> {
+        let list = self.clone();
+        let path = path.into();
+        tokio::spawn(async move {
+            let mut last_modified = None;
+            loop {
+                tokio::time::sleep(interval).await;
+                let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok();
+                if modified.is_none() || modified == last_modified {
+                    continue;
+                }
+                match list.reload(&path) {
+                    Ok(()) => last_modified = modified,
+                    Err(e) => log::warn!("Failed to reload deny list {}: {}", path.display(), e),
+                }
+            }
+        })
+    }
+
+    pub fn deny_mint(&self, mint: Pubkey) {
+        self.entries.write().mints.insert(mint);
+    }
+
+    pub fn deny_pool(&self, pool: Pubkey) {
+        self.entries.write().pools.insert(pool);
+    }
+
+    pub fn deny_user(&self, user: Pubkey) {
+        self.entries.write().users.insert(user);
+    }
+
+    pub fn len(&self) -> usize {
+        let entries = self.entries.read();
+        entries.mints.len() + entries.pools.len() + entries.users.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 事件的 mint、池子或用户是否在名单中
+    pub fn is_denied(&self, event: &DexEvent) -> bool {
+        let entries = self.entries.read();
+        event.mint().is_some_and(|k| entries.mints.contains(&k))
+            || event.pool().is_some_and(|k| entries.pools.contains(&k))
+            || event.user().is_some_and(|k| entries.users.contains(&k))
+    }
+}
+
+impl Transformer for DenyList {
+    #[inline]
+    fn transform(&self, event: DexEvent) -> Option<DexEvent> {
+        (!self.is_denied(&event)).then_some(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::{
+        BlockMetaEvent, EventMetadata, MeteoraDlmmSwapEvent, PumpFunTradeEvent, PumpSwapBuyEvent,
+    };
+
+    #[test]
+    fn test_quote_mint_filter() {
+        let filter = QuoteMintFilter::sol_only();
+        let swap = |quote_mint| {
+            DexEvent::PumpSwapBuy(PumpSwapBuyEvent {
+                pool: Pubkey::new_unique(),
+                base_mint: Pubkey::new_unique(),
+                quote_mint,
+                ..Default::default()
+            })
+        };
+        assert!(filter.matches(&swap(WSOL_MINT)));
+        assert!(!filter.matches(&swap(USDC_MINT)));
+        assert!(filter.matches(&DexEvent::PumpFunTrade(PumpFunTradeEvent {
+            bonding_curve: Pubkey::new_unique(),
+            ..Default::default()
+        })));
+        assert!(filter.matches(&DexEvent::BlockMeta(BlockMetaEvent::default())));
+
+        // 事件不含 mint 的池子按注册表解析，未知池子默认丢弃
+        let pool = Pubkey::new_unique();
+        let dlmm = DexEvent::MeteoraDlmmSwap(MeteoraDlmmSwapEvent {
+            metadata: EventMetadata::default(),
+            pool,
+            from: Pubkey::new_unique(),
+            start_bin_id: 0,
+            end_bin_id: 0,
+            amount_in: 1,
+            amount_out: 1,
+            swap_for_y: true,
+            fee: 0,
+            protocol_fee: 0,
+            fee_bps: 0,
+            host_fee: 0,
+        });
+        assert!(!filter.matches(&dlmm));
+        assert!(QuoteMintFilter::usdc_only().with_keep_unresolved(true).matches(&dlmm));
+        let mut registry = PoolRegistry::new();
+        registry.register(pool, Pubkey::new_unique(), USDC_MINT);
+        let usdc = QuoteMintFilter::usdc_only().with_registry(registry);
+        assert!(usdc.transform(dlmm).is_some());
+    }
+
+    #[test]
+    fn test_quote_mint_filter_known_pool_uses_read_lock() {
+        let filter = QuoteMintFilter::sol_only();
+        let pool = Pubkey::new_unique();
+        let buy = DexEvent::PumpSwapBuy(PumpSwapBuyEvent {
+            pool,
+            base_mint: Pubkey::new_unique(),
+            quote_mint: WSOL_MINT,
+            ..Default::default()
+        });
+        assert!(filter.matches(&buy));
+
+        // 持有读锁时已知池子仍可匹配（不会请求写锁）
+        let _guard = filter.registry.read();
+        assert!(filter.matches(&buy));
+    }
+
+    #[test]
+    fn test_transaction_filter_for_event_types() {
+        use crate::grpc::program_ids::{PUMPFUN_PROGRAM_ID, PUMPSWAP_PROGRAM_ID};
+
+        let filter = EventTypeFilter::include_only(vec![
+            EventType::PumpFunBuy,
+            EventType::PumpSwapBuy,
+            EventType::PumpFunCreate,
+            EventType::RawData,
+        ]);
+        assert_eq!(filter.protocols(), Some(vec![Protocol::PumpFun, Protocol::PumpSwap]));
+        let tx = TransactionFilter::for_event_types(&filter).unwrap();
+        assert_eq!(tx.account_include, vec![PUMPFUN_PROGRAM_ID, PUMPSWAP_PROGRAM_ID]);
+        assert!(!tx.is_unconstrained() && TransactionFilter::default().is_unconstrained());
+
+        // 只含账户事件、排除模式或按全部交易统计的事件无法推导
+        let accounts = EventTypeFilter::include_only(vec![EventType::AccountPumpSwapPool]);
+        assert_eq!(accounts.protocols(), Some(vec![]));
+        assert!(TransactionFilter::for_event_types(&accounts).is_none());
+        let exclude = EventTypeFilter::exclude_types(vec![EventType::PumpFunTrade]);
+        assert!(TransactionFilter::for_event_types(&exclude).is_none());
+        let fees = EventTypeFilter::include_only(vec![
+            EventType::PumpFunTrade,
+            EventType::PriorityFeeStats,
+        ]);
+        assert_eq!(fees.protocols(), None);
+
+        // 来源包含 Protocol 未覆盖程序的类型不返回部分协议
+        let created = EventTypeFilter::include_only(vec![EventType::NormalizedPoolCreated]);
+        assert_eq!(created.protocols(), None);
+        let migrations = EventTypeFilter::include_only(vec![EventType::MigrationCompleted]);
+        assert!(migrations.protocols().unwrap().contains(&Protocol::RaydiumAmmV4));
+    }
+
+    #[test]
+    fn test_deny_list_file_and_lookup() {
+        let (mint, user) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let path = std::env::temp_dir().join(format!("deny-{}.txt", Pubkey::new_unique()));
+        std::fs::write(&path, format!("# spam\nmint {}\n\nuser  {}\n", mint, user)).unwrap();
+        let list = DenyList::from_file(&path).unwrap();
+        assert_eq!(list.len(), 2);
+
+        let trade = |mint, user| {
+            DexEvent::PumpFunTrade(PumpFunTradeEvent { mint, user, ..Default::default() })
+        };
+        assert!(list.is_denied(&trade(mint, Pubkey::new_unique())));
+        assert!(list.is_denied(&trade(Pubkey::new_unique(), user)));
+        assert!(list.transform(trade(Pubkey::new_unique(), Pubkey::new_unique())).is_some());
+
+        // 解析失败时保留原名单；克隆共享同一份名单
+        std::fs::write(&path, "wallet abc\n").unwrap();
+        assert!(matches!(list.reload(&path), Err(ConfigError::Parse(_))));
+        let shared = list.clone();
+        shared.deny_pool(Pubkey::new_unique());
+        assert_eq!(list.len(), 3);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
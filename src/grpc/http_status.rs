@@ -0,0 +1,161 @@
+//! 内置 HTTP 状态服务（feature `http-status`）
+//!
+//! - `GET /healthz`：已连接且最近收到消息返回 200，否则 503
+//! - `GET /metrics`：Prometheus 文本格式计数器
+//! - `GET /filters`：当前订阅过滤器（JSON）
+//!
+//! 仅处理最简单的 HTTP/1.1 GET 请求，每个连接响应一次后关闭。
+
+use super::client::YellowstoneGrpc;
+use super::status::ClientStatus;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+/// 未配置看门狗时，健康检查允许的最长静默时间
+const DEFAULT_MAX_IDLE_MS: u64 = 30_000;
+
+impl YellowstoneGrpc {
+    /// 在 `addr` 上启动状态服务，返回服务任务句柄
+    ///
+    /// 健康判定的静默阈值沿用 `stale_stream_timeout_ms`，未配置时为 30 秒。
+    pub async fn serve_status(&self, addr: SocketAddr) -> std::io::Result<JoinHandle<()>> {
+        let max_idle_ms = match self.config().stale_stream_timeout_ms {
+            0 => DEFAULT_MAX_IDLE_MS,
+            timeout_ms => timeout_ms,
+        };
+        serve_status(addr, self.status(), max_idle_ms).await
+    }
+}
+
+/// 绑定地址并在后台处理状态请求
+pub async fn serve_status(
+    addr: SocketAddr,
+    status: Arc<ClientStatus>,
+    max_idle_ms: u64,
+) -> std::io::Result<JoinHandle<()>> {
+    let listener = TcpListener::bind(addr).await?;
+    Ok(tokio::spawn(async move {
+        loop {
+            let Ok((stream, _)) = listener.accept().await else { continue };
+            let status = Arc::clone(&status);
+            tokio::spawn(async move {
+                if let Err(e) = handle_connection(stream, &status, max_idle_ms).await {
+                    log::debug!("status connection error: {}", e);
+                }
+            });
+        }
+    }))
+}
+
+async fn handle_connection(
+    mut stream: TcpStream,
+    status: &ClientStatus,
+    max_idle_ms: u64,
+) -> std::io::Result<()> {
+    // 只需要请求行，读取第一块数据即可
+    let mut buf = [0u8; 1024];
+    let n = stream.read(&mut buf).await?;
+    let request = std::str::from_utf8(&buf[..n]).unwrap_or_default();
+
+    let (code, content_type, body) = route(request, status, max_idle_ms);
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        code,
+        content_type,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+fn route(
+    request: &str,
+    status: &ClientStatus,
+    max_idle_ms: u64,
+) -> (&'static str, &'static str, String) {
+    let mut parts = request.lines().next().unwrap_or_default().split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return ("400 Bad Request", "text/plain", "bad request\n".to_string());
+    };
+    if method != "GET" {
+        return ("405 Method Not Allowed", "text/plain", "method not allowed\n".to_string());
+    }
+    let path = target.split('?').next().unwrap_or(target);
+
+    match path {
+        "/healthz" => {
+            let body = serde_json::json!({
+                "connected": status.is_connected(),
+                "last_slot": status.last_slot(),
+                "idle_ms": status.idle_ms(),
+            })
+            .to_string();
+            if status.is_healthy(max_idle_ms) {
+                ("200 OK", "application/json", body)
+            } else {
+                ("503 Service Unavailable", "application/json", body)
+            }
+        }
+        "/metrics" => ("200 OK", "text/plain; version=0.0.4", status.render_prometheus()),
+        "/filters" => match serde_json::to_string(&status.subscription()) {
+            Ok(body) => ("200 OK", "application/json", body),
+            Err(e) => ("500 Internal Server Error", "text/plain", e.to_string()),
+        },
+        _ => ("404 Not Found", "text/plain", "not found\n".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route() {
+        let status = ClientStatus::default();
+        let (code, _, _) = route("GET /healthz HTTP/1.1\r\n\r\n", &status, 1000);
+        assert_eq!(code, "503 Service Unavailable");
+
+        status.on_connected();
+        status.on_message(Some(7));
+        let (code, _, body) = route("GET /healthz HTTP/1.1\r\n\r\n", &status, 1000);
+        assert_eq!(code, "200 OK");
+        assert!(body.contains("\"last_slot\":7"));
+
+        let (code, _, body) = route("GET /metrics HTTP/1.1\r\n\r\n", &status, 1000);
+        assert_eq!(code, "200 OK");
+        assert!(body.contains("sol_parser_last_slot 7"));
+
+        let (code, _, body) = route("GET /filters?pretty HTTP/1.1\r\n\r\n", &status, 1000);
+        assert_eq!(code, "200 OK");
+        assert!(body.contains("\"order_mode\":\"Unordered\""));
+
+        assert_eq!(route("GET /nope HTTP/1.1\r\n\r\n", &status, 1000).0, "404 Not Found");
+        assert_eq!(
+            route("POST /healthz HTTP/1.1\r\n\r\n", &status, 1000).0,
+            "405 Method Not Allowed"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_serve_status_over_tcp() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+
+        let status = Arc::new(ClientStatus::default());
+        let server = serve_status(addr, status, 1000).await.unwrap();
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream.write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n").await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("sol_parser_connected 0"));
+
+        server.abort();
+    }
+}
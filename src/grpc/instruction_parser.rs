@@ -5,14 +5,42 @@
 //! - 高性能：零拷贝，内联优化，并行处理
 //! - 可读性：每个步骤都有明确的注释
 
-use crate::core::{events::*, merger::merge_events};
-use crate::grpc::types::EventTypeFilter;
-use crate::instr::read_pubkey_fast;
+use crate::core::{cache::TransactionKeys, events::*, merger::merge_events};
+use crate::grpc::types::{EventTypeFilter, ParseDeadline, ParseLimits};
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Signature;
 use std::collections::HashMap;
 use yellowstone_grpc_proto::prelude::{Transaction, TransactionStatusMeta};
 
+/// 单笔交易的解析上下文，logs 与 instructions 两条路径共用
+#[derive(Clone, Copy)]
+pub(crate) struct TxContext<'a> {
+    pub meta: &'a TransactionStatusMeta,
+    pub transaction: &'a Option<Transaction>,
+    pub keys: &'a TransactionKeys,
+    pub sig: Signature,
+    pub slot: u64,
+    pub tx_idx: u64,
+    pub block_us: Option<i64>,
+    pub grpc_us: i64,
+    pub filter: Option<&'a EventTypeFilter>,
+}
+
+impl TxContext<'_> {
+    /// 该交易事件共用的元数据（指令位置由调用方填写）
+    #[inline(always)]
+    pub fn metadata(&self) -> EventMetadata {
+        EventMetadata {
+            signature: self.sig,
+            slot: self.slot,
+            tx_index: self.tx_idx,
+            block_time_us: self.block_us.unwrap_or(0),
+            grpc_recv_us: self.grpc_us,
+            ..Default::default()
+        }
+    }
+}
+
 /// 解析交易中的所有指令事件（instruction + inner instruction）
 ///
 /// # 解析流程
@@ -30,6 +58,7 @@ use yellowstone_grpc_proto::prelude::{Transaction, TransactionStatusMeta};
 pub fn parse_instructions_enhanced(
     meta: &TransactionStatusMeta,
     transaction: &Option<Transaction>,
+    keys: &TransactionKeys,
     sig: Signature,
     slot: u64,
     tx_idx: u64,
@@ -37,75 +66,86 @@ pub fn parse_instructions_enhanced(
     grpc_us: i64,
     filter: Option<&EventTypeFilter>,
 ) -> Vec<DexEvent> {
-    let Some(tx) = transaction else { return Vec::new() };
-    let Some(msg) = &tx.message else { return Vec::new() };
+    let ctx = TxContext { meta, transaction, keys, sig, slot, tx_idx, block_us, grpc_us, filter };
+    parse_instructions_limited(ctx, ParseLimits::default(), ParseDeadline::default()).0
+}
+
+/// 同 [`parse_instructions_enhanced`]，但按 `limits` 跳过过深的内层指令、截断过多的指令，
+/// 超过 `deadline` 后停止解析剩余指令
+///
+/// 返回有指令未解析时的截断原因。
+#[inline]
+pub(crate) fn parse_instructions_limited(
+    ctx: TxContext<'_>,
+    limits: ParseLimits,
+    deadline: ParseDeadline,
+) -> (Vec<DexEvent>, Option<TruncateReason>) {
+    let TxContext { meta, transaction, keys, filter, .. } = ctx;
+    let Some(tx) = transaction else { return (Vec::new(), None) };
+    let Some(msg) = &tx.message else { return (Vec::new(), None) };
 
     // 提前检查：是否需要解析 instruction（根据 filter）
     if !should_parse_instructions(filter) {
-        return Vec::new();
+        return (Vec::new(), None);
     }
 
-    // 构建账户查找表
-    let keys_len = msg.account_keys.len();
-    let writable_len = meta.loaded_writable_addresses.len();
-    let get_key = |i: usize| -> Option<&Vec<u8>> {
-        if i < keys_len {
-            msg.account_keys.get(i)
-        } else if i < keys_len + writable_len {
-            meta.loaded_writable_addresses.get(i - keys_len)
-        } else {
-            meta.loaded_readonly_addresses.get(i - keys_len - writable_len)
-        }
-    };
-
     let mut result = Vec::with_capacity(8);
     let mut invokes: HashMap<Pubkey, Vec<(i32, i32)>> = HashMap::with_capacity(8);
+    let mut count = 0usize;
+    let mut truncated = None;
 
     // 步骤 1: 解析所有主指令
     for (i, ix) in msg.instructions.iter().enumerate() {
-        let pid = get_key(ix.program_id_index as usize)
-            .map_or(Pubkey::default(), |k| read_pubkey_fast(k));
+        count += 1;
+        if limits.count_exceeded(count) {
+            truncated = Some(TruncateReason::MaxInstructions);
+            break;
+        }
+        if deadline.expired() {
+            truncated = Some(TruncateReason::TimeBudget);
+            break;
+        }
+        let pid = keys.get(ix.program_id_index as usize).copied().unwrap_or_default();
 
         invokes.entry(pid).or_default().push((i as i32, -1));
 
         // 解析主指令（8字节 discriminator）
-        if let Some(event) = parse_outer_instruction(
-            &ix.data,
-            &pid,
-            sig,
-            slot,
-            tx_idx,
-            block_us,
-            grpc_us,
-            &ix.accounts,
-            &get_key,
-            filter,
-        ) {
+        if let Some(mut event) = parse_outer_instruction(&ix.data, &pid, &ix.accounts, &ctx) {
+            if let Some(m) = event.metadata_mut() {
+                m.outer_index = i as u32;
+            }
             result.push((i, None, event)); // (outer_idx, inner_idx, event)
         }
     }
 
     // 步骤 2: 解析所有 inner instructions
-    for inner in &meta.inner_instructions {
+    'inner: for inner in &meta.inner_instructions {
         let outer_idx = inner.index as usize;
 
         for (j, inner_ix) in inner.instructions.iter().enumerate() {
-            let pid = get_key(inner_ix.program_id_index as usize)
-                .map_or(Pubkey::default(), |k| read_pubkey_fast(k));
+            count += 1;
+            if limits.count_exceeded(count) {
+                truncated = Some(TruncateReason::MaxInstructions);
+                break 'inner;
+            }
+            if deadline.expired() {
+                truncated = Some(TruncateReason::TimeBudget);
+                break 'inner;
+            }
+            // 节点未提供 stack_height 时按直接 CPI（深度 2）处理
+            if limits.depth_exceeded(inner_ix.stack_height.unwrap_or(2)) {
+                truncated = truncated.max(Some(TruncateReason::MaxDepth));
+                continue;
+            }
+            let pid = keys.get(inner_ix.program_id_index as usize).copied().unwrap_or_default();
 
             invokes.entry(pid).or_default().push((outer_idx as i32, j as i32));
 
             // 解析 inner instruction（16字节 discriminator）
-            if let Some(event) = parse_inner_instruction(
-                &inner_ix.data,
-                &pid,
-                sig,
-                slot,
-                tx_idx,
-                block_us,
-                grpc_us,
-                filter,
-            ) {
+            if let Some(mut event) = parse_inner_instruction(&inner_ix.data, &pid, &ctx) {
+                if let Some(m) = event.metadata_mut() {
+                    (m.outer_index, m.inner_index) = (outer_idx as u32, Some(j as u32));
+                }
                 result.push((outer_idx, Some(j), event)); // (outer_idx, Some(inner_idx), event)
             }
         }
@@ -127,13 +167,14 @@ pub fn parse_instructions_enhanced(
             &mut event,
             meta,
             transaction,
+            keys,
             &invokes,
         );
         crate::core::common_filler::fill_data(&mut event, meta, transaction, &invokes_str);
         final_result.push(event);
     }
 
-    final_result
+    (final_result, truncated)
 }
 
 // ============================================================================
@@ -144,17 +185,11 @@ pub fn parse_instructions_enhanced(
 ///
 /// 主指令使用 8 字节 discriminator
 #[inline(always)]
-fn parse_outer_instruction<'a>(
+fn parse_outer_instruction(
     data: &[u8],
     program_id: &Pubkey,
-    sig: Signature,
-    slot: u64,
-    tx_idx: u64,
-    block_us: Option<i64>,
-    grpc_us: i64,
     account_indices: &[u8],
-    get_key: &dyn Fn(usize) -> Option<&'a Vec<u8>>,
-    filter: Option<&EventTypeFilter>,
+    ctx: &TxContext<'_>,
 ) -> Option<DexEvent> {
     // 检查指令数据长度（至少8字节 discriminator）
     if data.len() < 8 {
@@ -162,21 +197,18 @@ fn parse_outer_instruction<'a>(
     }
 
     // 构建账户列表
-    let accounts: Vec<Pubkey> = account_indices
-        .iter()
-        .filter_map(|&idx| get_key(idx as usize).map(|k| read_pubkey_fast(k)))
-        .collect();
+    let accounts = ctx.keys.instruction_accounts(account_indices);
 
     // 调用现有的 instruction 解析器
     crate::instr::parse_instruction_unified(
         data,
         &accounts,
-        sig,
-        slot,
-        tx_idx,
-        block_us,
-        grpc_us,
-        filter,
+        ctx.sig,
+        ctx.slot,
+        ctx.tx_idx,
+        ctx.block_us,
+        ctx.grpc_us,
+        ctx.filter,
         program_id,
     )
 }
@@ -188,25 +220,14 @@ fn parse_outer_instruction<'a>(
 fn parse_inner_instruction(
     data: &[u8],
     program_id: &Pubkey,
-    sig: Signature,
-    slot: u64,
-    tx_idx: u64,
-    block_us: Option<i64>,
-    grpc_us: i64,
-    filter: Option<&EventTypeFilter>,
+    ctx: &TxContext<'_>,
 ) -> Option<DexEvent> {
     // 检查数据长度（至少16字节 discriminator）
     if data.len() < 16 {
         return None;
     }
 
-    let metadata = EventMetadata {
-        signature: sig,
-        slot,
-        tx_index: tx_idx,
-        block_time_us: block_us.unwrap_or(0),
-        grpc_recv_us: grpc_us,
-    };
+    let (metadata, filter) = (ctx.metadata(), ctx.filter);
 
     // 提取 16 字节 discriminator
     let mut discriminator = [0u8; 16];
@@ -247,6 +268,8 @@ fn parse_inner_instruction(
             }
         }
         all_inner::meteora_damm::parse(&discriminator, inner_data, metadata)
+    } else if *program_id == program_ids::METEORA_DLMM_PROGRAM_ID {
+        all_inner::meteora_dlmm::parse(&discriminator, inner_data, metadata)
     } else if *program_id == program_ids::BONK_PROGRAM_ID {
         all_inner::bonk::parse(&discriminator, inner_data, metadata)
     } else {
@@ -374,17 +397,18 @@ mod tests {
             tx_index: 1,
             block_time_us: 1000,
             grpc_recv_us: 2000,
+            ..Default::default()
         };
 
         // 模拟：outer instruction + inner instruction（应该合并）
         let outer_event = DexEvent::PumpFunTrade(PumpFunTradeEvent {
-            metadata: metadata.clone(),
+            metadata,
             bonding_curve: Pubkey::new_unique(),
             ..Default::default()
         });
 
         let inner_event = DexEvent::PumpFunTrade(PumpFunTradeEvent {
-            metadata: metadata.clone(),
+            metadata,
             sol_amount: 1000,
             token_amount: 2000,
             ..Default::default()
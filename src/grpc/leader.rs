@@ -0,0 +1,115 @@
+//! Slot leader 识别
+//!
+//! 区块的手续费奖励（`RewardType::Fee`）只发给出块的 leader，可直接从 block meta / 区块奖励中取得。
+//! 节点未返回奖励时，可注入 [`LeaderSchedule`]（`getLeaderSchedule` 的结果）按 slot 查询。
+
+use parking_lot::RwLock;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+use yellowstone_grpc_proto::prelude::{RewardType, Rewards};
+
+/// 从 gRPC 区块奖励中取出 leader（手续费奖励的接收者）
+pub fn leader_from_rewards(rewards: &Rewards) -> Option<Pubkey> {
+    rewards
+        .rewards
+        .iter()
+        .find(|r| r.reward_type == RewardType::Fee as i32)
+        .and_then(|r| r.pubkey.parse().ok())
+}
+
+/// 从 RPC 区块奖励中取出 leader（手续费奖励的接收者）
+pub fn leader_from_rpc_rewards(rewards: &[solana_transaction_status::Reward]) -> Option<Pubkey> {
+    rewards
+        .iter()
+        .find(|r| r.reward_type == Some(solana_transaction_status::RewardType::Fee))
+        .and_then(|r| r.pubkey.parse().ok())
+}
+
+/// 注入的 leader schedule：slot → leader identity
+///
+/// 可在运行中继续写入下一个 epoch，并用 [`prune_before`](Self::prune_before) 清理旧 slot。
+#[derive(Debug, Default)]
+pub struct LeaderSchedule {
+    slots: RwLock<HashMap<u64, Pubkey>>,
+}
+
+impl LeaderSchedule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 写入一个 epoch 的 `getLeaderSchedule` 结果（identity → epoch 内的相对 slot 下标）
+    ///
+    /// `first_slot` 为该 epoch 的第一个 slot；identity 不是合法 Pubkey 时返回错误且不写入。
+    pub fn insert_epoch(
+        &self,
+        first_slot: u64,
+        schedule: &HashMap<String, Vec<usize>>,
+    ) -> Result<(), String> {
+        let mut entries = Vec::with_capacity(schedule.values().map(Vec::len).sum());
+        for (identity, offsets) in schedule {
+            let leader: Pubkey =
+                identity.parse().map_err(|_| format!("invalid leader identity: {}", identity))?;
+            entries.extend(offsets.iter().map(|&i| (first_slot + i as u64, leader)));
+        }
+        self.slots.write().extend(entries);
+        Ok(())
+    }
+
+    pub fn insert(&self, slot: u64, leader: Pubkey) {
+        self.slots.write().insert(slot, leader);
+    }
+
+    pub fn leader(&self, slot: u64) -> Option<Pubkey> {
+        self.slots.read().get(&slot).copied()
+    }
+
+    /// 移除早于 `slot` 的记录
+    pub fn prune_before(&self, slot: u64) {
+        self.slots.write().retain(|&s, _| s >= slot);
+    }
+
+    pub fn len(&self) -> usize {
+        self.slots.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slots.read().is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yellowstone_grpc_proto::prelude::Reward;
+
+    #[test]
+    fn test_leader_from_rewards_and_schedule() {
+        let leader = Pubkey::new_unique();
+        let reward = |pubkey: Pubkey, reward_type: RewardType| Reward {
+            pubkey: pubkey.to_string(),
+            reward_type: reward_type as i32,
+            ..Default::default()
+        };
+        let rewards = Rewards {
+            rewards: vec![
+                reward(Pubkey::new_unique(), RewardType::Voting),
+                reward(leader, RewardType::Fee),
+            ],
+            num_partitions: None,
+        };
+        assert_eq!(leader_from_rewards(&rewards), Some(leader));
+        assert_eq!(leader_from_rewards(&Rewards::default()), None);
+
+        let schedule = LeaderSchedule::new();
+        schedule
+            .insert_epoch(1_000, &HashMap::from([(leader.to_string(), vec![0, 1, 2, 3])]))
+            .unwrap();
+        assert_eq!(schedule.leader(1_003), Some(leader));
+        assert_eq!(schedule.leader(1_004), None);
+        assert!(schedule.insert_epoch(0, &HashMap::from([("bad".to_string(), vec![0])])).is_err());
+
+        schedule.prune_before(1_002);
+        assert_eq!(schedule.len(), 2);
+    }
+}
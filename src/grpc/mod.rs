@@ -7,19 +7,38 @@
 //! - 多协议支持（PumpFun, Bonk, Raydium等）
 
 pub mod buffers;
+pub mod checkpoint;
 pub mod client;
 pub mod config;
 pub mod event_parser;
 pub mod filter;
 pub mod instruction_parser; // 增强的 instruction 解析器
+pub mod leader;
+pub mod priority_fee;
 pub mod program_ids;
+pub mod sink;
+pub mod status;
+pub mod subscription;
+pub mod thread_tuning;
 pub mod types;
+#[cfg(feature = "http-status")]
+pub mod http_status;
+#[cfg(feature = "ws-server")]
+pub mod ws_server;
 
 // 重新导出主要API
-pub use client::YellowstoneGrpc;
+pub use checkpoint::{Checkpoint, CheckpointStore, FileCheckpointStore, MemoryCheckpointStore};
+pub use client::{RecvClock, YellowstoneGrpc};
+pub use config::{ConfigError, EndpointConfig, SubscriptionConfig};
+pub use filter::{DenyList, QuoteMintFilter};
+pub use leader::LeaderSchedule;
+pub use sink::{spawn_sinks, SinkConfig};
+pub use status::{ClientStats, ClientStatus, SubscriptionState, TransactionStats};
+pub use subscription::SubscriptionBuilder;
 pub use types::{
-    AccountFilter, ClientConfig, EventType as StreamingEventType, EventTypeFilter, OrderMode,
-    Protocol, SlotFilter, TransactionFilter,
+    AccountFilter, BackpressurePolicy, ClientConfig, ClientEvent, EventType as StreamingEventType, EventTypeFilter,
+    LatencyProfile, OrderMode, ParseDeadline, ParseLimits, Protocol, SlotFilter, StaleReason, ThreadPriority,
+    TransactionFilter,
 };
 
 // 事件解析器重新导出
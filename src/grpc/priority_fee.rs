@@ -0,0 +1,125 @@
+//! 按 slot 统计优先费
+//!
+//! 从交易的 ComputeBudget `SetComputeUnitPrice` 指令读取 compute unit 价格（micro-lamports），
+//! 同一 slot 内的交易汇总为 [`PriorityFeeStatsEvent`]，可替代轮询 `getRecentPrioritizationFees`。
+//! 统计范围是订阅过滤后收到的交易，未设置价格的交易按 0 计入。
+
+use crate::core::events::{EventMetadata, PriorityFeeStatsEvent};
+use solana_sdk::pubkey;
+use solana_sdk::pubkey::Pubkey;
+use yellowstone_grpc_proto::prelude::Transaction;
+
+pub const COMPUTE_BUDGET_PROGRAM_ID: Pubkey =
+    pubkey!("ComputeBudget111111111111111111111111111111");
+
+/// `ComputeBudgetInstruction::SetComputeUnitPrice` 的 tag
+const SET_COMPUTE_UNIT_PRICE: u8 = 3;
+
+/// 交易设置的 compute unit 价格（micro-lamports），未设置时为 None
+///
+/// 只检查外层指令；被调用的程序必须在静态账户表中，无需地址查找表。
+pub fn compute_unit_price(tx: &Transaction) -> Option<u64> {
+    let message = tx.message.as_ref()?;
+    let program = COMPUTE_BUDGET_PROGRAM_ID.to_bytes();
+    message.instructions.iter().rev().find_map(|ix| {
+        let key = message.account_keys.get(ix.program_id_index as usize)?;
+        if key.as_slice() != program || ix.data.first() != Some(&SET_COMPUTE_UNIT_PRICE) {
+            return None;
+        }
+        Some(u64::from_le_bytes(ix.data.get(1..9)?.try_into().ok()?))
+    })
+}
+
+/// 单个 slot 的价格汇总，slot 前进时输出上一个 slot 的统计
+///
+/// 上一个 slot 已输出后才到达的交易不再计入。
+#[derive(Debug, Default)]
+pub struct PriorityFeeAggregator {
+    slot: u64,
+    prices: Vec<u64>,
+}
+
+impl PriorityFeeAggregator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 记录一笔交易的价格，slot 前进时返回上一个 slot 的统计
+    pub fn observe(&mut self, slot: u64, price: Option<u64>) -> Option<PriorityFeeStatsEvent> {
+        if slot < self.slot {
+            return None;
+        }
+        let finished = if slot > self.slot { self.finish() } else { None };
+        self.slot = slot;
+        self.prices.push(price.unwrap_or(0));
+        finished
+    }
+
+    /// 输出当前 slot 的统计并清空
+    pub fn finish(&mut self) -> Option<PriorityFeeStatsEvent> {
+        if self.prices.is_empty() {
+            return None;
+        }
+        self.prices.sort_unstable();
+        let stats = PriorityFeeStatsEvent {
+            metadata: EventMetadata { slot: self.slot, ..Default::default() },
+            count: self.prices.len() as u32,
+            p50: percentile(&self.prices, 50),
+            p90: percentile(&self.prices, 90),
+            max: *self.prices.last().unwrap_or(&0),
+        };
+        self.prices.clear();
+        Some(stats)
+    }
+}
+
+/// 最近秩百分位数，`sorted` 非空且已升序
+fn percentile(sorted: &[u64], p: usize) -> u64 {
+    let rank = (sorted.len() * p).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yellowstone_grpc_proto::prelude::{CompiledInstruction, Message};
+
+    #[test]
+    fn test_compute_unit_price_and_stats() {
+        let mut data = vec![SET_COMPUTE_UNIT_PRICE];
+        data.extend_from_slice(&5_000u64.to_le_bytes());
+        let tx = Transaction {
+            message: Some(Message {
+                account_keys: vec![
+                    Pubkey::new_unique().to_bytes().to_vec(),
+                    COMPUTE_BUDGET_PROGRAM_ID.to_bytes().to_vec(),
+                ],
+                instructions: vec![
+                    CompiledInstruction {
+                        program_id_index: 1,
+                        accounts: vec![],
+                        data: vec![2, 0, 0, 1, 0],
+                    },
+                    CompiledInstruction { program_id_index: 1, accounts: vec![], data },
+                ],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(compute_unit_price(&tx), Some(5_000));
+        assert_eq!(compute_unit_price(&Transaction::default()), None);
+
+        let mut agg = PriorityFeeAggregator::new();
+        for price in 1..=10 {
+            assert!(agg.observe(100, Some(price * 100)).is_none());
+        }
+        assert!(agg.observe(100, None).is_none());
+        let stats = agg.observe(101, Some(7)).unwrap();
+        assert_eq!(stats.metadata.slot, 100);
+        assert_eq!((stats.count, stats.p50, stats.p90, stats.max), (11, 500, 900, 1_000));
+
+        // 已输出 slot 的迟到交易被忽略
+        assert!(agg.observe(100, Some(1)).is_none());
+        assert_eq!(agg.finish().map(|s| (s.metadata.slot, s.count)), Some((101, 1)));
+    }
+}
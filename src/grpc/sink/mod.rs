@@ -0,0 +1,232 @@
+//! 事件输出（sink）
+//!
+//! 从客户端输出队列取出事件，以 JSON Lines 写入配置的目标；
+//! 启用 `sqlite-sink` / `postgres-sink` feature 后还可写入 SQLite（见 [`sqlite`]）
+//! 或 PostgreSQL（见 [`postgres`]）。数据库 sink 以稳定事件 ID 幂等写入（见 [`upsert`]）。
+
+#[cfg(feature = "postgres-sink")]
+pub mod postgres;
+#[cfg(feature = "sqlite-sink")]
+pub mod sqlite;
+pub mod upsert;
+
+use crate::DexEvent;
+use crossbeam_queue::ArrayQueue;
+use serde::Deserialize;
+use std::fs::OpenOptions;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// JSON Lines sink 空闲时的刷新间隔
+const LINES_FLUSH_INTERVAL: Duration = Duration::from_millis(100);
+
+/// 数据库 sink 默认的提交间隔，每次提交一个事务
+#[cfg(any(feature = "sqlite-sink", feature = "postgres-sink"))]
+const DB_FLUSH_INTERVAL_MS: u64 = 1000;
+
+/// 输出目标
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SinkConfig {
+    /// 标准输出，每行一个 JSON 事件
+    Stdout,
+    /// 追加写入文件，每行一个 JSON 事件
+    File { path: PathBuf },
+    /// SQLite 数据库，只写入统一后的 swap 和发行事件
+    #[cfg(feature = "sqlite-sink")]
+    Sqlite {
+        path: PathBuf,
+        #[serde(default = "default_flush_interval_ms")]
+        flush_interval_ms: u64,
+    },
+    /// PostgreSQL，按事件类型分表批量 COPY 写入；缓冲达到 `batch_size` 或距上次写入超过
+    /// `flush_interval_ms` 时写入
+    #[cfg(feature = "postgres-sink")]
+    Postgres {
+        url: String,
+        #[serde(default = "default_postgres_batch_size")]
+        batch_size: usize,
+        #[serde(default = "default_flush_interval_ms")]
+        flush_interval_ms: u64,
+    },
+}
+
+#[cfg(any(feature = "sqlite-sink", feature = "postgres-sink"))]
+fn default_flush_interval_ms() -> u64 {
+    DB_FLUSH_INTERVAL_MS
+}
+
+#[cfg(feature = "postgres-sink")]
+fn default_postgres_batch_size() -> usize {
+    postgres::DEFAULT_BATCH_SIZE
+}
+
+impl SinkConfig {
+    fn open(&self) -> std::io::Result<SinkWriter> {
+        Ok(match self {
+            SinkConfig::Stdout => SinkWriter::Lines(BufWriter::new(Box::new(std::io::stdout()))),
+            SinkConfig::File { path } => SinkWriter::Lines(BufWriter::new(Box::new(
+                OpenOptions::new().create(true).append(true).open(path)?,
+            ))),
+            #[cfg(feature = "sqlite-sink")]
+            SinkConfig::Sqlite { path, .. } => {
+                SinkWriter::Sqlite(sqlite::SqliteSink::open(path).map_err(std::io::Error::other)?)
+            }
+            #[cfg(feature = "postgres-sink")]
+            SinkConfig::Postgres { url, batch_size, .. } => SinkWriter::Postgres(Box::new(
+                postgres::PostgresSink::connect(url)
+                    .map_err(std::io::Error::other)?
+                    .with_batch_size(*batch_size),
+            )),
+        })
+    }
+
+    /// 空闲时两次刷新之间的最短间隔
+    fn flush_interval(&self) -> Duration {
+        match self {
+            SinkConfig::Stdout | SinkConfig::File { .. } => LINES_FLUSH_INTERVAL,
+            #[cfg(feature = "sqlite-sink")]
+            SinkConfig::Sqlite { flush_interval_ms, .. } => {
+                Duration::from_millis(*flush_interval_ms)
+            }
+            #[cfg(feature = "postgres-sink")]
+            SinkConfig::Postgres { flush_interval_ms, .. } => {
+                Duration::from_millis(*flush_interval_ms)
+            }
+        }
+    }
+}
+
+/// 已打开的 sink
+enum SinkWriter {
+    Lines(BufWriter<Box<dyn Write + Send>>),
+    #[cfg(feature = "sqlite-sink")]
+    Sqlite(sqlite::SqliteSink),
+    #[cfg(feature = "postgres-sink")]
+    Postgres(Box<postgres::PostgresSink>),
+}
+
+impl SinkWriter {
+    fn flush(&mut self) {
+        let result = match self {
+            SinkWriter::Lines(w) => w.flush(),
+            #[cfg(feature = "sqlite-sink")]
+            SinkWriter::Sqlite(s) => s.flush().map_err(std::io::Error::other),
+            #[cfg(feature = "postgres-sink")]
+            SinkWriter::Postgres(s) => s.flush().map_err(std::io::Error::other),
+        };
+        if let Err(e) = result {
+            log::error!("Failed to flush sink: {}", e);
+        }
+    }
+}
+
+/// 一组已打开的 sink，按顺序写入同一事件
+pub struct SinkSet {
+    writers: Vec<SinkWriter>,
+    /// 与 `writers` 一一对应的 (刷新间隔, 上次刷新时间)
+    flushed: Vec<(Duration, Instant)>,
+    needs_json: bool,
+}
+
+impl SinkSet {
+    /// 打开所有 sink，任一打开失败返回错误
+    pub fn open(sinks: &[SinkConfig]) -> std::io::Result<Self> {
+        let writers = sinks.iter().map(SinkConfig::open).collect::<std::io::Result<Vec<_>>>()?;
+        let now = Instant::now();
+        let flushed = sinks.iter().map(|s| (s.flush_interval(), now)).collect();
+        let needs_json = writers.iter().any(|w| matches!(w, SinkWriter::Lines(_)));
+        Ok(Self { writers, flushed, needs_json })
+    }
+
+    /// 写入所有 sink，错误只记录日志
+    pub fn write(&mut self, event: &DexEvent) {
+        let line = if self.needs_json {
+            match serde_json::to_string(event) {
+                Ok(line) => line,
+                Err(e) => {
+                    log::error!("Failed to serialize event: {}", e);
+                    return;
+                }
+            }
+        } else {
+            String::new()
+        };
+        for w in &mut self.writers {
+            let result = match w {
+                SinkWriter::Lines(w) => writeln!(w, "{}", line),
+                #[cfg(feature = "sqlite-sink")]
+                SinkWriter::Sqlite(s) => s.write(event).map(|_| ()).map_err(std::io::Error::other),
+                #[cfg(feature = "postgres-sink")]
+                SinkWriter::Postgres(s) => s.write(event).map_err(std::io::Error::other),
+            };
+            if let Err(e) = result {
+                log::error!("Failed to write event to sink: {}", e);
+            }
+        }
+    }
+
+    pub fn flush(&mut self) {
+        let now = Instant::now();
+        for (w, (_, last)) in self.writers.iter_mut().zip(&mut self.flushed) {
+            w.flush();
+            *last = now;
+        }
+    }
+
+    /// 只刷新距上次刷新超过各自间隔的 sink
+    pub fn flush_due(&mut self) {
+        let now = Instant::now();
+        for (w, (interval, last)) in self.writers.iter_mut().zip(&mut self.flushed) {
+            if now.duration_since(*last) >= *interval {
+                w.flush();
+                *last = now;
+            }
+        }
+    }
+}
+
+/// 启动后台线程，持续把队列中的事件写入所有 sink
+///
+/// 所有 sink 在启动时打开，打开失败直接返回错误；之后的写入错误只记录日志。
+/// 队列空闲时按各 sink 的刷新间隔刷新，不会每次空闲都提交数据库事务。
+pub fn spawn_sinks(
+    queue: Arc<ArrayQueue<DexEvent>>,
+    sinks: &[SinkConfig],
+) -> std::io::Result<JoinHandle<()>> {
+    let mut sinks = SinkSet::open(sinks)?;
+
+    Ok(std::thread::spawn(move || loop {
+        let Some(event) = queue.pop() else {
+            sinks.flush_due();
+            std::thread::sleep(Duration::from_millis(1));
+            continue;
+        };
+        sinks.write(&event);
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::PumpFunTradeEvent;
+    use solana_sdk::pubkey::Pubkey;
+
+    #[test]
+    fn test_idle_flush_waits_for_interval() {
+        let path = std::env::temp_dir().join(format!("sink-{}.jsonl", Pubkey::new_unique()));
+        let mut sinks = SinkSet::open(&[SinkConfig::File { path: path.clone() }]).unwrap();
+        sinks.write(&DexEvent::PumpFunTrade(PumpFunTradeEvent::default()));
+
+        // 未到刷新间隔，空闲刷新不写出
+        sinks.flush_due();
+        assert!(std::fs::read_to_string(&path).unwrap().is_empty());
+        std::thread::sleep(LINES_FLUSH_INTERVAL);
+        sinks.flush_due();
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 1);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,338 @@
+//! PostgreSQL sink
+//!
+//! 每种事件类型一张表（`PumpFunBuy` → `pump_fun_buy`），公共列为元数据和 pool / mint / user，
+//! 其余字段以 JSONB 存入 `data` 列。事件先按类型缓冲，凑满 `batch_size` 或 [`PostgresSink::flush`]
+//! 时在一个事务内用二进制 COPY 写入临时表，再以 `event_id` 为键 upsert 到目标表（见 [`super::upsert`]），
+//! 崩溃后重放已写入的事件不会产生重复行。写入失败时保留缓冲的事件，下次写入时重试，
+//! 连接断开时按连接串重新连接。
+//!
+//! 时间列 `block_time_us` 为整数微秒，Timescale 可直接以它建 hypertable（`chunk_time_interval` 以微秒计）。
+//! hypertable 的唯一索引必须包含分区列，因此唯一键为 `(event_id, block_time_us)`。
+
+use super::upsert::{event_key, on_conflict_sql, EVENT_ID_COLUMN};
+use crate::DexEvent;
+use postgres::binary_copy::BinaryCopyInWriter;
+use postgres::types::{ToSql, Type};
+use postgres::{Client, NoTls};
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+/// 唯一索引的列（`block_time_us` 为 hypertable 分区列）
+const CONFLICT_COLUMNS: [&str; 2] = [EVENT_ID_COLUMN, "block_time_us"];
+
+/// 默认批次大小（所有类型缓冲的事件总数）
+pub const DEFAULT_BATCH_SIZE: usize = 1000;
+
+/// 写入失败后，缓冲达到批次大小时自动重试的间隔
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// 写入失败时最多保留的批次数，超出后丢弃新事件
+const MAX_RETAINED_BATCHES: usize = 10;
+
+const COLUMNS: [&str; 10] = [
+    EVENT_ID_COLUMN,
+    "signature",
+    "slot",
+    "tx_index",
+    "outer_index",
+    "block_time_us",
+    "pool",
+    "mint",
+    "user_key",
+    "data",
+];
+
+const COLUMN_TYPES: [Type; 10] = [
+    Type::TEXT,
+    Type::TEXT,
+    Type::INT8,
+    Type::INT8,
+    Type::INT8,
+    Type::INT8,
+    Type::TEXT,
+    Type::TEXT,
+    Type::TEXT,
+    Type::JSONB,
+];
+
+/// 事件类型对应的表名：`PumpFunBuy` → `pump_fun_buy`
+pub fn table_name(event_type: &str) -> String {
+    let mut name = String::with_capacity(event_type.len() + 4);
+    for (i, c) in event_type.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                name.push('_');
+            }
+            name.push(c.to_ascii_lowercase());
+        } else {
+            name.push(c);
+        }
+    }
+    name
+}
+
+/// 事件类型对应表的建表语句（幂等）
+pub fn create_table_sql(event_type: &str) -> String {
+    let table = table_name(event_type);
+    format!(
+        "CREATE TABLE IF NOT EXISTS {table} (
+            event_id      TEXT   NOT NULL,
+            signature     TEXT   NOT NULL,
+            slot          BIGINT NOT NULL,
+            tx_index      BIGINT NOT NULL,
+            outer_index   BIGINT NOT NULL,
+            block_time_us BIGINT NOT NULL,
+            pool          TEXT,
+            mint          TEXT,
+            user_key      TEXT,
+            data          JSONB  NOT NULL
+        );
+        CREATE UNIQUE INDEX IF NOT EXISTS {table}_event_key ON {table} (event_id, block_time_us);
+        CREATE INDEX IF NOT EXISTS {table}_slot ON {table} (slot);"
+    )
+}
+
+/// 从临时表幂等写入目标表的语句
+fn upsert_from_staging_sql(table: &str, staging: &str) -> String {
+    let columns = COLUMNS.join(", ");
+    format!(
+        "INSERT INTO {table} ({columns}) SELECT {columns} FROM {staging} {}",
+        on_conflict_sql(&CONFLICT_COLUMNS, &COLUMNS)
+    )
+}
+
+/// 为给定事件类型建表，通常在部署时调用一次；未预先建表的类型会在首次写入时创建
+pub fn migrate(client: &mut Client, event_types: &[&str]) -> Result<(), postgres::Error> {
+    let sql: String = event_types.iter().map(|t| create_table_sql(t)).collect();
+    client.batch_execute(&sql)
+}
+
+/// 单个事件对应的一行
+#[derive(Debug, Clone, PartialEq)]
+struct EventRow {
+    event_id: String,
+    signature: String,
+    slot: i64,
+    tx_index: i64,
+    outer_index: i64,
+    block_time_us: i64,
+    pool: Option<String>,
+    mint: Option<String>,
+    user: Option<String>,
+    data: serde_json::Value,
+}
+
+impl EventRow {
+    fn new(event: &DexEvent) -> serde_json::Result<Self> {
+        let meta = event.metadata();
+        // DexEvent 序列化为 {"<类型>": {...}}，只保留内层字段
+        let data = match serde_json::to_value(event)? {
+            serde_json::Value::Object(map) => {
+                map.into_iter().next().map(|(_, v)| v).unwrap_or_default()
+            }
+            other => other,
+        };
+        Ok(Self {
+            event_id: event_key(event),
+            signature: meta.signature.to_string(),
+            slot: meta.slot as i64,
+            tx_index: meta.tx_index as i64,
+            outer_index: meta.outer_index as i64,
+            block_time_us: meta.block_time_us,
+            pool: event.pool().map(|k| k.to_string()),
+            mint: event.mint().map(|k| k.to_string()),
+            user: event.user().map(|k| k.to_string()),
+            data,
+        })
+    }
+
+    fn values(&self) -> [&(dyn ToSql + Sync); 10] {
+        [
+            &self.event_id,
+            &self.signature,
+            &self.slot,
+            &self.tx_index,
+            &self.outer_index,
+            &self.block_time_us,
+            &self.pool,
+            &self.mint,
+            &self.user,
+            &self.data,
+        ]
+    }
+}
+
+/// 按事件类型分表、批量 COPY 写入的 PostgreSQL 连接
+pub struct PostgresSink {
+    client: Client,
+    /// 连接串，连接断开时用于重连；[`Self::from_client`] 建立的 sink 不重连
+    url: Option<String>,
+    batch_size: usize,
+    /// 上次写入失败后，自动重试的最早时间
+    retry_at: Option<Instant>,
+    pending: HashMap<&'static str, Vec<EventRow>>,
+    pending_len: usize,
+    tables: HashSet<&'static str>,
+}
+
+impl PostgresSink {
+    /// 连接数据库（不加密，`url` 为 libpq 格式的连接串）
+    pub fn connect(url: &str) -> Result<Self, postgres::Error> {
+        let mut sink = Self::from_client(Client::connect(url, NoTls)?);
+        sink.url = Some(url.to_string());
+        Ok(sink)
+    }
+
+    /// 使用已建立的连接（如需要 TLS 时自行建立）
+    pub fn from_client(client: Client) -> Self {
+        Self {
+            client,
+            url: None,
+            batch_size: DEFAULT_BATCH_SIZE,
+            retry_at: None,
+            pending: HashMap::new(),
+            pending_len: 0,
+            tables: HashSet::new(),
+        }
+    }
+
+    /// 设置批次大小
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// 缓冲一个事件，缓冲总数达到批次大小时写入
+    ///
+    /// 写入失败后缓冲会继续累积，每隔 [`RETRY_DELAY`] 重试一次；超过
+    /// [`MAX_RETAINED_BATCHES`] 个批次时丢弃新事件并返回错误。
+    pub fn write(
+        &mut self,
+        event: &DexEvent,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if self.pending_len >= self.batch_size * MAX_RETAINED_BATCHES {
+            return Err(format!("{} events pending after failed writes", self.pending_len).into());
+        }
+        let row = EventRow::new(event)?;
+        self.pending.entry(event.event_type_name()).or_default().push(row);
+        self.pending_len += 1;
+        let retry_due = self.retry_at.is_none_or(|at| Instant::now() >= at);
+        if self.pending_len >= self.batch_size && retry_due {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// 在一个事务内把所有缓冲的事件写入各自的表
+    ///
+    /// 同一批次内重复的事件只保留最后一个；已存在的事件（相同 `event_id` 和 `block_time_us`）覆盖原记录。
+    /// 写入失败时事务回滚、缓冲保留，下次调用重试；连接已断开时先重新连接。
+    pub fn flush(&mut self) -> Result<(), postgres::Error> {
+        if self.pending_len == 0 {
+            return Ok(());
+        }
+        match self.write_pending() {
+            Ok(()) => {
+                self.pending.clear();
+                self.pending_len = 0;
+                self.retry_at = None;
+                Ok(())
+            }
+            Err(e) => {
+                self.retry_at = Some(Instant::now() + RETRY_DELAY);
+                Err(e)
+            }
+        }
+    }
+
+    fn write_pending(&mut self) -> Result<(), postgres::Error> {
+        if self.client.is_closed() {
+            if let Some(url) = &self.url {
+                self.client = Client::connect(url, NoTls)?;
+            }
+        }
+
+        let missing: Vec<&str> =
+            self.pending.keys().copied().filter(|t| !self.tables.contains(t)).collect();
+        if !missing.is_empty() {
+            migrate(&mut self.client, &missing)?;
+            self.tables.extend(missing);
+        }
+
+        let mut tx = self.client.transaction()?;
+        for (event_type, rows) in &self.pending {
+            let table = table_name(event_type);
+            let staging = format!("{}_staging", table);
+            tx.batch_execute(&format!(
+                "CREATE TEMP TABLE IF NOT EXISTS {staging} (LIKE {table}) ON COMMIT DELETE ROWS"
+            ))?;
+            let sql = format!("COPY {} ({}) FROM STDIN BINARY", staging, COLUMNS.join(", "));
+            let sink = tx.copy_in(&sql)?;
+            let mut writer = BinaryCopyInWriter::new(sink, &COLUMN_TYPES);
+            for row in dedup_rows(rows) {
+                writer.write(&row.values())?;
+            }
+            writer.finish()?;
+            tx.batch_execute(&upsert_from_staging_sql(&table, &staging))?;
+        }
+        tx.commit()
+    }
+}
+
+/// 按唯一键去重并保持原顺序，重复时保留最后一个（同一条 upsert 语句不能两次修改同一行）
+fn dedup_rows(rows: &[EventRow]) -> Vec<&EventRow> {
+    let mut seen = HashSet::with_capacity(rows.len());
+    let mut unique: Vec<&EventRow> = rows
+        .iter()
+        .rev()
+        .filter(|row| seen.insert((row.event_id.as_str(), row.block_time_us)))
+        .collect();
+    unique.reverse();
+    unique
+}
+
+impl Drop for PostgresSink {
+    fn drop(&mut self) {
+        if let Err(e) = self.flush() {
+            log::error!("Failed to flush postgres sink: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::PumpFunTradeEvent;
+    use solana_sdk::pubkey::Pubkey;
+
+    #[test]
+    fn test_table_name_and_row() {
+        assert_eq!(table_name("PumpFunBuy"), "pump_fun_buy");
+        assert_eq!(table_name("MeteoraDammV2Swap"), "meteora_damm_v2_swap");
+        assert!(create_table_sql("BlockMeta").contains("CREATE TABLE IF NOT EXISTS block_meta"));
+
+        let mint = Pubkey::new_unique();
+        let event =
+            DexEvent::PumpFunBuy(PumpFunTradeEvent { mint, sol_amount: 42, ..Default::default() });
+        let row = EventRow::new(&event).unwrap();
+        assert_eq!(row.mint, Some(mint.to_string()));
+        assert_eq!(row.pool, None);
+        assert_eq!(row.data["sol_amount"], 42);
+        assert_eq!(row.event_id, event_key(&event));
+
+        let mut replayed = row.clone();
+        replayed.data = serde_json::json!({ "sol_amount": 43 });
+        let rows = [row.clone(), replayed.clone()];
+        assert_eq!(dedup_rows(&rows), vec![&replayed]);
+        let sql = upsert_from_staging_sql("pump_fun_buy", "pump_fun_buy_staging");
+        assert!(sql.starts_with("INSERT INTO pump_fun_buy (event_id, signature, "));
+        assert!(sql.contains(
+            "FROM pump_fun_buy_staging ON CONFLICT (event_id, block_time_us) DO UPDATE SET"
+        ));
+        assert!(!sql.contains("block_time_us = excluded"));
+        assert!(
+            create_table_sql("PumpFunBuy").contains("ON pump_fun_buy (event_id, block_time_us)")
+        );
+        assert!(sql.ends_with("data = excluded.data"));
+    }
+}
@@ -0,0 +1,264 @@
+//! SQLite sink
+//!
+//! 把统一后的 swap（[`normalize_swap`]）和新币发行（`PumpFunCreate` / `BonkPoolCreate`）写入 SQLite，
+//! 适合单机研究用途。数据库以 WAL 模式打开，语句经 `prepare_cached` 复用，
+//! 写入在一个事务内累积，[`SqliteSink::flush`] 时提交。
+//!
+//! 数量以 INTEGER 存储，超过 `i64::MAX` 的值写入失败；Pubkey 和签名以 base58 文本存储。
+//! 两张表都以 `event_id` 为唯一键（见 [`super::upsert`]），崩溃后重放同一事件不会重复写入。
+
+use super::upsert::{event_key, upsert_sql, SqlDialect, EVENT_ID_COLUMN};
+use crate::analytics::{normalize_swap, PoolRegistry};
+use crate::DexEvent;
+use rusqlite::{params, Connection};
+use std::path::Path;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS swaps (
+    event_id      TEXT,
+    signature     TEXT    NOT NULL,
+    slot          INTEGER NOT NULL,
+    tx_index      INTEGER NOT NULL,
+    block_time_us INTEGER NOT NULL,
+    source        TEXT    NOT NULL,
+    pool          TEXT    NOT NULL,
+    input_mint    TEXT    NOT NULL,
+    output_mint   TEXT    NOT NULL,
+    input_amount  INTEGER NOT NULL,
+    output_amount INTEGER NOT NULL,
+    trader        TEXT
+);
+CREATE INDEX IF NOT EXISTS swaps_pool_slot ON swaps (pool, slot);
+CREATE TABLE IF NOT EXISTS launches (
+    event_id      TEXT,
+    signature     TEXT    NOT NULL,
+    slot          INTEGER NOT NULL,
+    block_time_us INTEGER NOT NULL,
+    protocol      TEXT    NOT NULL,
+    launch        TEXT    NOT NULL,
+    creator       TEXT    NOT NULL,
+    name          TEXT    NOT NULL,
+    symbol        TEXT    NOT NULL,
+    uri           TEXT    NOT NULL
+);
+CREATE INDEX IF NOT EXISTS launches_launch ON launches (launch);
+";
+
+const SWAP_COLUMNS: [&str; 12] = [
+    EVENT_ID_COLUMN,
+    "signature",
+    "slot",
+    "tx_index",
+    "block_time_us",
+    "source",
+    "pool",
+    "input_mint",
+    "output_mint",
+    "input_amount",
+    "output_amount",
+    "trader",
+];
+
+const LAUNCH_COLUMNS: [&str; 10] = [
+    EVENT_ID_COLUMN,
+    "signature",
+    "slot",
+    "block_time_us",
+    "protocol",
+    "launch",
+    "creator",
+    "name",
+    "symbol",
+    "uri",
+];
+
+/// 为旧版本创建的表补上 `event_id` 列并建唯一索引
+fn migrate_event_id(conn: &Connection, table: &str) -> rusqlite::Result<()> {
+    let has_column = conn
+        .prepare(&format!("SELECT 1 FROM pragma_table_info('{}') WHERE name = ?1", table))?
+        .exists([EVENT_ID_COLUMN])?;
+    if !has_column {
+        conn.execute_batch(&format!("ALTER TABLE {} ADD COLUMN {} TEXT", table, EVENT_ID_COLUMN))?;
+    }
+    conn.execute_batch(&format!(
+        "CREATE UNIQUE INDEX IF NOT EXISTS {table}_{col} ON {table} ({col})",
+        col = EVENT_ID_COLUMN
+    ))
+}
+
+/// 写入 swap 和发行事件的 SQLite 连接
+///
+/// 内部维护一个 [`PoolRegistry`]，事件本身不含 mint 的协议在池子被观察到后才能写入 swap。
+pub struct SqliteSink {
+    conn: Connection,
+    registry: PoolRegistry,
+    in_transaction: bool,
+    insert_swap: String,
+    insert_launch: String,
+}
+
+impl SqliteSink {
+    /// 打开（或创建）数据库文件，启用 WAL 并建表
+    pub fn open(path: impl AsRef<Path>) -> rusqlite::Result<Self> {
+        Self::init(Connection::open(path)?)
+    }
+
+    /// 内存数据库，主要用于测试
+    pub fn open_in_memory() -> rusqlite::Result<Self> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> rusqlite::Result<Self> {
+        // 内存数据库不支持 WAL，返回 "memory"
+        let _: String = conn.query_row("PRAGMA journal_mode = WAL", [], |row| row.get(0))?;
+        conn.pragma_update(None, "synchronous", "NORMAL")?;
+        conn.execute_batch(SCHEMA)?;
+        migrate_event_id(&conn, "swaps")?;
+        migrate_event_id(&conn, "launches")?;
+        Ok(Self {
+            conn,
+            registry: PoolRegistry::new(),
+            in_transaction: false,
+            insert_swap: upsert_sql(SqlDialect::Sqlite, "swaps", &SWAP_COLUMNS),
+            insert_launch: upsert_sql(SqlDialect::Sqlite, "launches", &LAUNCH_COLUMNS),
+        })
+    }
+
+    /// 底层连接，可用于查询
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    /// 写入一个事件，返回是否产生了记录
+    ///
+    /// 写入在当前事务内，调用 [`flush`](Self::flush) 后才对其他连接可见；
+    /// 已写入过的事件（相同 `event_id`）覆盖原记录。
+    pub fn write(&mut self, event: &DexEvent) -> rusqlite::Result<bool> {
+        self.registry.observe(event);
+        let meta = event.metadata();
+        let signature = meta.signature.to_string();
+
+        if let Some(swap) = normalize_swap(event, &self.registry) {
+            self.begin()?;
+            self.conn.prepare_cached(&self.insert_swap)?.execute(params![
+                event_key(event),
+                signature,
+                meta.slot,
+                meta.tx_index,
+                meta.block_time_us,
+                swap.source,
+                swap.pool.to_string(),
+                swap.input_mint.to_string(),
+                swap.output_mint.to_string(),
+                swap.input_amount,
+                swap.output_amount,
+                swap.trader.map(|t| t.to_string()),
+            ])?;
+            return Ok(true);
+        }
+
+        // Bonk 事件中不含 mint，发行以 pool_state 标识（与 `LaunchTracker` 一致）
+        let (protocol, launch, creator, name, symbol, uri) = match event {
+            DexEvent::PumpFunCreate(e) => {
+                ("PumpFun", e.mint, e.creator, &e.name, &e.symbol, &e.uri)
+            }
+            DexEvent::BonkPoolCreate(e) => {
+                let p = &e.base_mint_param;
+                ("Bonk", e.pool_state, e.creator, &p.name, &p.symbol, &p.uri)
+            }
+            _ => return Ok(false),
+        };
+        self.begin()?;
+        self.conn.prepare_cached(&self.insert_launch)?.execute(params![
+            event_key(event),
+            signature,
+            meta.slot,
+            meta.block_time_us,
+            protocol,
+            launch.to_string(),
+            creator.to_string(),
+            name,
+            symbol,
+            uri,
+        ])?;
+        Ok(true)
+    }
+
+    /// 提交当前事务
+    pub fn flush(&mut self) -> rusqlite::Result<()> {
+        if self.in_transaction {
+            self.in_transaction = false;
+            self.conn.execute_batch("COMMIT")?;
+        }
+        Ok(())
+    }
+
+    fn begin(&mut self) -> rusqlite::Result<()> {
+        if !self.in_transaction {
+            self.conn.execute_batch("BEGIN")?;
+            self.in_transaction = true;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for SqliteSink {
+    fn drop(&mut self) {
+        if let Err(e) = self.flush() {
+            log::error!("Failed to commit sqlite sink: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::{PumpFunCreateTokenEvent, PumpFunTradeEvent};
+    use solana_sdk::pubkey::Pubkey;
+
+    #[test]
+    fn test_sqlite_sink_writes_swaps_and_launches() {
+        let mut sink = SqliteSink::open_in_memory().unwrap();
+        let (mint, creator) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        let create = DexEvent::PumpFunCreate(PumpFunCreateTokenEvent {
+            mint,
+            creator,
+            name: "Token".into(),
+            symbol: "TKN".into(),
+            ..Default::default()
+        });
+        let buy = DexEvent::PumpFunBuy(PumpFunTradeEvent {
+            mint,
+            bonding_curve: Pubkey::new_unique(),
+            user: creator,
+            sol_amount: 1_000,
+            token_amount: 5_000,
+            is_buy: true,
+            ..Default::default()
+        });
+        assert!(sink.write(&create).unwrap());
+        assert!(sink.write(&buy).unwrap());
+        // 重放同一事件不重复写入
+        assert!(sink.write(&buy).unwrap());
+        assert!(!sink.write(&DexEvent::Error("x".into())).unwrap());
+        sink.flush().unwrap();
+
+        let conn = sink.connection();
+        let (symbol, launch): (String, String) = conn
+            .query_row("SELECT symbol, launch FROM launches", [], |r| Ok((r.get(0)?, r.get(1)?)))
+            .unwrap();
+        assert_eq!((symbol.as_str(), launch), ("TKN", mint.to_string()));
+
+        let (output_mint, input_amount, trader): (String, i64, Option<String>) = conn
+            .query_row("SELECT output_mint, input_amount, trader FROM swaps", [], |r| {
+                Ok((r.get(0)?, r.get(1)?, r.get(2)?))
+            })
+            .unwrap();
+        assert_eq!(output_mint, mint.to_string());
+        assert_eq!(input_amount, 1_000);
+        assert_eq!(trader, Some(creator.to_string()));
+        let swaps: i64 = conn.query_row("SELECT COUNT(*) FROM swaps", [], |r| r.get(0)).unwrap();
+        assert_eq!(swaps, 1);
+    }
+}
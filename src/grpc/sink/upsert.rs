@@ -0,0 +1,149 @@
+//! 以稳定事件 ID 为键的幂等写入
+//!
+//! 崩溃后重放、多端点订阅或断点恢复都会让同一事件被写入多次。数据库 sink 以
+//! [`event_key`]（`DexEvent::event_id()` 的 32 位十六进制文本）为唯一键，
+//! 用 [`upsert_sql`] / [`on_conflict_sql`] 生成的 `INSERT ... ON CONFLICT` 语句写入，
+//! 重复写入只会覆盖同一行。
+//!
+//! 账户事件和 block meta 等没有交易签名，`event_id()` 对同一类型的所有事件都相同，
+//! 键中另外加入 slot 和账户地址。
+
+use crate::DexEvent;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+
+/// 唯一键列名
+pub const EVENT_ID_COLUMN: &str = "event_id";
+
+/// SQL 方言，只影响参数占位符
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqlDialect {
+    /// `?1, ?2, ...`
+    Sqlite,
+    /// `$1, $2, ...`
+    Postgres,
+}
+
+impl SqlDialect {
+    fn placeholder(self, index: usize) -> String {
+        match self {
+            SqlDialect::Sqlite => format!("?{}", index),
+            SqlDialect::Postgres => format!("${}", index),
+        }
+    }
+}
+
+/// 事件的幂等键：`event_id()` 的 32 位十六进制文本
+///
+/// 没有签名的事件为 `<event_id>-<slot>-<账户地址>`（没有账户地址时为空）。
+pub fn event_key(event: &DexEvent) -> String {
+    let meta = event.metadata();
+    if meta.signature != Signature::default() {
+        return format!("{:032x}", event.event_id());
+    }
+    let account = account_key(event).map(|k| k.to_string()).unwrap_or_default();
+    format!("{:032x}-{}-{}", event.event_id(), meta.slot, account)
+}
+
+/// 没有签名的事件所属的账户：账户事件为账户地址，其他事件为池子或 mint
+fn account_key(event: &DexEvent) -> Option<Pubkey> {
+    let pubkey = match event {
+        DexEvent::TokenInfo(e) => e.pubkey,
+        DexEvent::TokenAccount(e) => e.pubkey,
+        DexEvent::NonceAccount(e) => e.pubkey,
+        DexEvent::PumpSwapGlobalConfigAccount(e) => e.pubkey,
+        DexEvent::PumpSwapPoolAccount(e) => e.pubkey,
+        DexEvent::AccountClosed(e) => e.pubkey,
+        DexEvent::RaydiumAmmV4AmmInfoAccount(e) => e.pubkey,
+        DexEvent::RaydiumAmmV4TargetOrdersAccount(e) => e.pubkey,
+        DexEvent::SerumOpenOrdersAccount(e) => e.pubkey,
+        _ => return event.pool().or_else(|| event.mint()),
+    };
+    Some(pubkey)
+}
+
+/// 冲突时覆盖其余列的子句：`ON CONFLICT (event_id) DO UPDATE SET a = excluded.a, ...`
+///
+/// `conflict` 为唯一索引的列；`columns` 中属于 `conflict` 的列会被跳过，没有其他列时退化为 `DO NOTHING`。
+pub fn on_conflict_sql(conflict: &[&str], columns: &[&str]) -> String {
+    let target = conflict.join(", ");
+    let updates: Vec<String> = columns
+        .iter()
+        .filter(|c| !conflict.contains(c))
+        .map(|c| format!("{c} = excluded.{c}"))
+        .collect();
+    if updates.is_empty() {
+        format!("ON CONFLICT ({}) DO NOTHING", target)
+    } else {
+        format!("ON CONFLICT ({}) DO UPDATE SET {}", target, updates.join(", "))
+    }
+}
+
+/// 单行幂等写入语句，参数按 `columns` 顺序绑定，`columns` 须包含 `event_id`
+pub fn upsert_sql(dialect: SqlDialect, table: &str, columns: &[&str]) -> String {
+    debug_assert!(columns.contains(&EVENT_ID_COLUMN), "upsert requires the event_id column");
+    let placeholders: Vec<String> = (1..=columns.len()).map(|i| dialect.placeholder(i)).collect();
+    format!(
+        "INSERT INTO {} ({}) VALUES ({}) {}",
+        table,
+        columns.join(", "),
+        placeholders.join(", "),
+        on_conflict_sql(&[EVENT_ID_COLUMN], columns)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::{
+        BlockMetaEvent, EventMetadata, PumpFunTradeEvent, TokenAccountEvent,
+    };
+
+    #[test]
+    fn test_upsert_sql_and_event_key() {
+        assert_eq!(
+            upsert_sql(SqlDialect::Sqlite, "swaps", &["event_id", "slot", "pool"]),
+            "INSERT INTO swaps (event_id, slot, pool) VALUES (?1, ?2, ?3) \
+             ON CONFLICT (event_id) DO UPDATE SET slot = excluded.slot, pool = excluded.pool"
+        );
+        assert_eq!(
+            upsert_sql(SqlDialect::Postgres, "seen", &["event_id"]),
+            "INSERT INTO seen (event_id) VALUES ($1) ON CONFLICT (event_id) DO NOTHING"
+        );
+
+        let event = |outer_index| {
+            DexEvent::PumpFunBuy(PumpFunTradeEvent {
+                metadata: EventMetadata {
+                    signature: Signature::from([1u8; 64]),
+                    slot: 7,
+                    outer_index,
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+        };
+        assert_eq!(event_key(&event(1)).len(), 32);
+        assert_eq!(event_key(&event(1)), event_key(&event(1)));
+        assert_ne!(event_key(&event(1)), event_key(&event(2)));
+
+        // 没有签名的事件按 slot 和账户地址区分
+        let account = |slot, pubkey| {
+            DexEvent::TokenAccount(TokenAccountEvent {
+                metadata: EventMetadata { slot, ..Default::default() },
+                pubkey,
+                ..Default::default()
+            })
+        };
+        let pubkey = Pubkey::new_unique();
+        assert_eq!(event_key(&account(7, pubkey)), event_key(&account(7, pubkey)));
+        assert_ne!(event_key(&account(7, pubkey)), event_key(&account(8, pubkey)));
+        assert_ne!(event_key(&account(7, pubkey)), event_key(&account(7, Pubkey::new_unique())));
+        let block = |slot| {
+            DexEvent::BlockMeta(BlockMetaEvent {
+                metadata: EventMetadata { slot, ..Default::default() },
+                ..Default::default()
+            })
+        };
+        assert_ne!(event_key(&block(7)), event_key(&block(8)));
+    }
+}
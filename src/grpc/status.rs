@@ -0,0 +1,412 @@
+//! 客户端运行状态
+//!
+//! 连接状态、计数器与当前订阅过滤器，供健康检查和监控使用（见 `http_status` 模块），
+//! [`ClientStatus::stats`] 返回可序列化的整体快照。
+//! 计数器使用 Relaxed 原子操作，热路径开销可忽略。
+//! 开启 `ClientConfig::transaction_stats` 时另按过滤原因统计收到的交易（[`TransactionStats`]）。
+
+use super::types::{AccountFilter, EventTypeFilter, OrderMode, TransactionFilter};
+use crate::core::now_micros;
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+use std::time::Instant;
+
+/// 当前订阅状态快照
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SubscriptionState {
+    pub order_mode: OrderMode,
+    pub transactions: Vec<TransactionFilter>,
+    pub accounts: Vec<AccountFilter>,
+    pub event_types: Option<EventTypeFilter>,
+}
+
+/// 交易的分类结果，每笔交易只计入一类
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TransactionOutcome {
+    Vote,
+    Failed,
+    NoKnownProgram,
+    NoEvents,
+    WithEvents,
+}
+
+/// 收到的交易按过滤原因的分类计数
+///
+/// 产出事件的交易计入 `with_events`，其余依次按投票、失败、不含已支持程序、无事件归类。
+/// `no_known_program` 占比高说明交易订阅过滤器过宽。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct TransactionStats {
+    /// 收到的交易数，等于以下各项之和
+    pub received: u64,
+    pub vote: u64,
+    pub failed: u64,
+    /// 未调用任何已支持协议程序（见 `capabilities::PROTOCOL_REGISTRY`）
+    pub no_known_program: u64,
+    /// 调用了已支持协议程序但未产出事件（如被事件类型过滤器过滤）
+    pub no_events: u64,
+    pub with_events: u64,
+}
+
+impl TransactionStats {
+    /// 产出事件的交易占比，尚未收到交易时为 None
+    pub fn hit_rate(&self) -> Option<f64> {
+        (self.received > 0).then(|| self.with_events as f64 / self.received as f64)
+    }
+}
+
+/// 客户端统计快照，见 [`ClientStatus::stats`]
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ClientStats {
+    pub connected: bool,
+    /// 客户端创建以来的运行时间（毫秒）
+    pub uptime_ms: u64,
+    /// 收到的 gRPC 更新数（交易、账户、slot 等）
+    pub messages_received: u64,
+    /// 解析器产出的事件数（转换器、背压处理之前）
+    pub events_parsed: u64,
+    /// 投递到输出的事件数
+    pub events_emitted: u64,
+    /// 背压策略丢弃的事件数
+    pub events_dropped: u64,
+    pub reconnects: u64,
+    pub stale_streams: u64,
+    pub panics: u64,
+    /// 因 `ClientConfig::parse_limits` 截断解析的交易数
+    pub transactions_truncated: u64,
+    /// `ClientConfig::amount_validation` 发现数量不一致的 swap 事件数
+    pub validation_warnings: u64,
+    /// 内部状态表（如交易账户表缓存）因容量或过期淘汰的条目数
+    pub state_evictions: u64,
+    pub last_slot: u64,
+    /// 距最后一次消息的时间（毫秒），尚未收到消息时为 None
+    pub idle_ms: Option<u64>,
+    /// 未开启 `ClientConfig::transaction_stats` 时全为 0
+    pub transactions: TransactionStats,
+    pub subscription: SubscriptionState,
+}
+
+/// 状态创建时间
+#[derive(Debug)]
+struct StartedAt(Instant);
+
+impl Default for StartedAt {
+    fn default() -> Self {
+        Self(Instant::now())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ClientStatus {
+    started_at: StartedAt,
+    connected: AtomicBool,
+    /// 最后一次收到消息的时间（微秒），0 表示尚未收到
+    last_message_us: AtomicI64,
+    last_slot: AtomicU64,
+    messages_received: AtomicU64,
+    events_parsed: AtomicU64,
+    events_emitted: AtomicU64,
+    events_dropped: AtomicU64,
+    reconnects: AtomicU64,
+    stale_streams: AtomicU64,
+    panics: AtomicU64,
+    transactions_truncated: AtomicU64,
+    validation_warnings: AtomicU64,
+    state_evictions: AtomicU64,
+    /// 按 [`TransactionOutcome`] 下标计数
+    transactions: [AtomicU64; 5],
+    subscription: Mutex<SubscriptionState>,
+}
+
+impl ClientStatus {
+    #[inline]
+    pub(crate) fn on_connected(&self) {
+        self.connected.store(true, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub(crate) fn on_disconnected(&self) {
+        self.connected.store(false, Ordering::Relaxed);
+        self.reconnects.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub(crate) fn on_message(&self, slot: Option<u64>) {
+        self.last_message_us.store(now_micros(), Ordering::Relaxed);
+        self.messages_received.fetch_add(1, Ordering::Relaxed);
+        if let Some(slot) = slot {
+            self.last_slot.fetch_max(slot, Ordering::Relaxed);
+        }
+    }
+
+    #[inline]
+    pub(crate) fn on_parsed(&self, count: u64) {
+        self.events_parsed.fetch_add(count, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub(crate) fn on_emitted(&self, count: u64) {
+        self.events_emitted.fetch_add(count, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub(crate) fn on_dropped(&self) {
+        self.events_dropped.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub(crate) fn on_stale(&self) {
+        self.stale_streams.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub(crate) fn on_panic(&self) {
+        self.panics.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub(crate) fn on_truncated(&self) {
+        self.transactions_truncated.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub(crate) fn on_validation_warnings(&self, count: u64) {
+        if count > 0 {
+            self.validation_warnings.fetch_add(count, Ordering::Relaxed);
+        }
+    }
+
+    #[inline]
+    pub(crate) fn on_evicted(&self, count: u64) {
+        if count > 0 {
+            self.state_evictions.fetch_add(count, Ordering::Relaxed);
+        }
+    }
+
+    #[inline]
+    pub(crate) fn on_transaction(&self, outcome: TransactionOutcome) {
+        self.transactions[outcome as usize].fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn set_subscription(&self, state: SubscriptionState) {
+        *self.subscription.lock() = state;
+    }
+
+    pub(crate) fn update_filters(
+        &self,
+        transactions: Vec<TransactionFilter>,
+        accounts: Vec<AccountFilter>,
+    ) {
+        let mut state = self.subscription.lock();
+        state.transactions = transactions;
+        state.accounts = accounts;
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::Relaxed)
+    }
+
+    pub fn last_slot(&self) -> u64 {
+        self.last_slot.load(Ordering::Relaxed)
+    }
+
+    /// 距最后一次消息的时间（毫秒），尚未收到消息时为 None
+    pub fn idle_ms(&self) -> Option<u64> {
+        match self.last_message_us.load(Ordering::Relaxed) {
+            0 => None,
+            last => Some((now_micros() - last).max(0) as u64 / 1000),
+        }
+    }
+
+    /// 已连接且在 `max_idle_ms` 内收到过消息
+    pub fn is_healthy(&self, max_idle_ms: u64) -> bool {
+        self.is_connected() && self.idle_ms().is_some_and(|idle| idle <= max_idle_ms)
+    }
+
+    pub fn transaction_stats(&self) -> TransactionStats {
+        let [vote, failed, no_known_program, no_events, with_events] =
+            self.transactions.each_ref().map(|c| c.load(Ordering::Relaxed));
+        TransactionStats {
+            received: vote + failed + no_known_program + no_events + with_events,
+            vote,
+            failed,
+            no_known_program,
+            no_events,
+            with_events,
+        }
+    }
+
+    pub fn subscription(&self) -> SubscriptionState {
+        self.subscription.lock().clone()
+    }
+
+    /// 计数器、运行时间与当前订阅的快照
+    pub fn stats(&self) -> ClientStats {
+        ClientStats {
+            connected: self.is_connected(),
+            uptime_ms: self.started_at.0.elapsed().as_millis() as u64,
+            messages_received: self.messages_received.load(Ordering::Relaxed),
+            events_parsed: self.events_parsed.load(Ordering::Relaxed),
+            events_emitted: self.events_emitted.load(Ordering::Relaxed),
+            events_dropped: self.events_dropped.load(Ordering::Relaxed),
+            reconnects: self.reconnects.load(Ordering::Relaxed),
+            stale_streams: self.stale_streams.load(Ordering::Relaxed),
+            panics: self.panics.load(Ordering::Relaxed),
+            transactions_truncated: self.transactions_truncated.load(Ordering::Relaxed),
+            validation_warnings: self.validation_warnings.load(Ordering::Relaxed),
+            state_evictions: self.state_evictions.load(Ordering::Relaxed),
+            last_slot: self.last_slot(),
+            idle_ms: self.idle_ms(),
+            transactions: self.transaction_stats(),
+            subscription: self.subscription(),
+        }
+    }
+
+    /// Prometheus 文本格式
+    pub fn render_prometheus(&self) -> String {
+        let counters = [
+            ("messages_received_total", "gRPC updates received", &self.messages_received),
+            ("events_parsed_total", "Events produced by the parsers", &self.events_parsed),
+            ("events_emitted_total", "Parsed events delivered to the output", &self.events_emitted),
+            (
+                "events_dropped_total",
+                "Events dropped by the backpressure policy",
+                &self.events_dropped,
+            ),
+            (
+                "reconnects_total",
+                "Stream sessions ended before a reconnect attempt",
+                &self.reconnects,
+            ),
+            (
+                "stale_streams_total",
+                "Streams dropped by the stale-stream watchdog",
+                &self.stale_streams,
+            ),
+            ("panics_total", "Panics caught in parsers and user callbacks", &self.panics),
+            (
+                "transactions_truncated_total",
+                "Transactions whose parsing was cut short by the parse limits",
+                &self.transactions_truncated,
+            ),
+            (
+                "validation_warnings_total",
+                "Swap events whose amounts disagree with token balance changes",
+                &self.validation_warnings,
+            ),
+            (
+                "state_evictions_total",
+                "Entries evicted from bounded internal state by capacity or TTL",
+                &self.state_evictions,
+            ),
+        ];
+
+        let mut out = String::with_capacity(1024);
+        for (name, help, value) in counters {
+            let _ = writeln!(out, "# HELP sol_parser_{} {}", name, help);
+            let _ = writeln!(out, "# TYPE sol_parser_{} counter", name);
+            let _ = writeln!(out, "sol_parser_{} {}", name, value.load(Ordering::Relaxed));
+        }
+
+        let transactions = self.transaction_stats();
+        let _ = writeln!(
+            out,
+            "# HELP sol_parser_transactions_total Transactions received by filter outcome"
+        );
+        let _ = writeln!(out, "# TYPE sol_parser_transactions_total counter");
+        for (outcome, value) in [
+            ("vote", transactions.vote),
+            ("failed", transactions.failed),
+            ("no_known_program", transactions.no_known_program),
+            ("no_events", transactions.no_events),
+            ("with_events", transactions.with_events),
+        ] {
+            let _ =
+                writeln!(out, "sol_parser_transactions_total{{outcome=\"{}\"}} {}", outcome, value);
+        }
+
+        let gauges = [
+            ("connected", "Whether the gRPC stream is connected", self.is_connected() as u64),
+            ("last_slot", "Highest slot observed on the stream", self.last_slot()),
+        ];
+        for (name, help, value) in gauges {
+            let _ = writeln!(out, "# HELP sol_parser_{} {}", name, help);
+            let _ = writeln!(out, "# TYPE sol_parser_{} gauge", name);
+            let _ = writeln!(out, "sol_parser_{} {}", name, value);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_health_and_metrics() {
+        let status = ClientStatus::default();
+        assert!(!status.is_healthy(1000));
+
+        status.on_connected();
+        status.on_message(Some(42));
+        status.on_message(Some(41));
+        status.on_emitted(3);
+        status.on_dropped();
+        assert!(status.is_healthy(1000));
+        assert_eq!(status.last_slot(), 42);
+
+        let metrics = status.render_prometheus();
+        assert!(metrics.contains("sol_parser_messages_received_total 2\n"));
+        assert!(metrics.contains("sol_parser_events_emitted_total 3\n"));
+        assert!(metrics.contains("sol_parser_events_dropped_total 1\n"));
+        assert!(metrics.contains("sol_parser_connected 1\n"));
+        assert!(metrics.contains("sol_parser_last_slot 42\n"));
+
+        status.on_disconnected();
+        assert!(!status.is_healthy(1000));
+        assert!(status.render_prometheus().contains("sol_parser_reconnects_total 1\n"));
+    }
+
+    #[test]
+    fn test_stats_snapshot() {
+        let status = ClientStatus::default();
+        status.on_connected();
+        status.on_message(Some(42));
+        status.on_parsed(5);
+        status.on_emitted(4);
+        status.on_dropped();
+        status.on_disconnected();
+        status.update_filters(vec![TransactionFilter::default()], vec![]);
+
+        let stats = status.stats();
+        assert!(!stats.connected);
+        assert_eq!(stats.messages_received, 1);
+        assert_eq!((stats.events_parsed, stats.events_emitted, stats.events_dropped), (5, 4, 1));
+        assert_eq!((stats.reconnects, stats.last_slot), (1, 42));
+        assert!(stats.idle_ms.is_some());
+        assert_eq!(stats.subscription.transactions.len(), 1);
+    }
+
+    #[test]
+    fn test_transaction_stats() {
+        let status = ClientStatus::default();
+        assert_eq!(status.transaction_stats().hit_rate(), None);
+        for outcome in [
+            TransactionOutcome::Vote,
+            TransactionOutcome::NoKnownProgram,
+            TransactionOutcome::NoKnownProgram,
+            TransactionOutcome::WithEvents,
+        ] {
+            status.on_transaction(outcome);
+        }
+
+        let stats = status.stats().transactions;
+        assert_eq!(stats.received, 4);
+        assert_eq!((stats.vote, stats.no_known_program, stats.with_events), (1, 2, 1));
+        assert_eq!(stats.hit_rate(), Some(0.25));
+        let metrics = status.render_prometheus();
+        assert!(metrics.contains("sol_parser_transactions_total{outcome=\"no_known_program\"} 2\n"));
+        assert!(metrics.contains("sol_parser_transactions_total{outcome=\"failed\"} 0\n"));
+    }
+}
@@ -0,0 +1,142 @@
+//! 按代币订阅
+//!
+//! [`SubscriptionBuilder::for_mint`] 收集一个代币在各协议中的相关账户，生成交易和账户过滤器：
+//! - mint 本身
+//! - PumpFun bonding curve 及其代币账户
+//! - PumpSwap 迁移池（PumpFun 毕业后创建的 mint / WSOL 池）
+//! - Raydium Launchpad（Bonk）mint / WSOL 池
+//! - 无法按 PDA 推导的池子（Raydium / Orca / Meteora 等）通过 [`PoolRegistry`] 查询，
+//!   见 [`SubscriptionBuilder::with_registry`]
+//!
+//! PDA 规则以 `idls/pumpfun.json`、`idls/raydium_launchpad.json` 为准。
+
+use super::program_ids::{BONK_PROGRAM, PUMPFUN_PROGRAM, PUMPSWAP_PROGRAM};
+use super::types::{AccountFilter, TransactionFilter};
+use crate::accounts::program_ids::SPL_TOKEN_PROGRAM_ID;
+use crate::analytics::{PoolRegistry, WSOL_MINT};
+use crate::instr::builder::associated_token_address;
+use solana_sdk::pubkey::Pubkey;
+
+fn pda(seeds: &[&[u8]], program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(seeds, program).0
+}
+
+/// PumpFun bonding curve
+pub fn pumpfun_bonding_curve(mint: &Pubkey) -> Pubkey {
+    pda(&[b"bonding-curve", mint.as_ref()], &PUMPFUN_PROGRAM)
+}
+
+/// PumpFun 毕业迁移时创建的 PumpSwap 池（index 0，创建者为 PumpFun 的 pool authority）
+pub fn pumpswap_canonical_pool(mint: &Pubkey) -> Pubkey {
+    let authority = pda(&[b"pool-authority", mint.as_ref()], &PUMPFUN_PROGRAM);
+    pda(
+        &[b"pool", &0u16.to_le_bytes(), authority.as_ref(), mint.as_ref(), WSOL_MINT.as_ref()],
+        &PUMPSWAP_PROGRAM,
+    )
+}
+
+/// Raydium Launchpad（Bonk）池
+pub fn bonk_pool(base_mint: &Pubkey, quote_mint: &Pubkey) -> Pubkey {
+    pda(&[b"pool", base_mint.as_ref(), quote_mint.as_ref()], &BONK_PROGRAM)
+}
+
+/// 按代币生成订阅过滤器
+#[derive(Debug, Clone, Default)]
+pub struct SubscriptionBuilder {
+    mints: Vec<Pubkey>,
+    accounts: Vec<Pubkey>,
+}
+
+impl SubscriptionBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 订阅一个代币在所有已知协议中的活动
+    pub fn for_mint(mint: Pubkey) -> Self {
+        Self::new().mint(mint)
+    }
+
+    /// 追加一个代币及其可推导的 PDA
+    pub fn mint(mut self, mint: Pubkey) -> Self {
+        if !self.mints.contains(&mint) {
+            self.mints.push(mint);
+        }
+        let bonding_curve = pumpfun_bonding_curve(&mint);
+        self.account(mint)
+            .account(bonding_curve)
+            .account(associated_token_address(&bonding_curve, &mint, &SPL_TOKEN_PROGRAM_ID))
+            .account(pumpswap_canonical_pool(&mint))
+            .account(bonk_pool(&mint, &WSOL_MINT))
+    }
+
+    /// 加入注册表中包含已添加代币的池子
+    pub fn with_registry(mut self, registry: &PoolRegistry) -> Self {
+        let pools: Vec<Pubkey> =
+            self.mints.iter().flat_map(|mint| registry.pools_for_mint(mint)).collect();
+        for pool in pools {
+            self = self.account(pool);
+        }
+        self
+    }
+
+    /// 追加任意账户
+    pub fn account(mut self, account: Pubkey) -> Self {
+        if !self.accounts.contains(&account) {
+            self.accounts.push(account);
+        }
+        self
+    }
+
+    pub fn accounts(&self) -> &[Pubkey] {
+        &self.accounts
+    }
+
+    /// 涉及任一账户的交易
+    pub fn transaction_filter(&self) -> TransactionFilter {
+        TransactionFilter {
+            account_include: self.accounts.iter().map(|k| k.to_string()).collect(),
+            ..TransactionFilter::new()
+        }
+    }
+
+    /// 这些账户本身的更新（bonding curve、池子状态等）
+    pub fn account_filter(&self) -> AccountFilter {
+        AccountFilter {
+            account: self.accounts.iter().map(|k| k.to_string()).collect(),
+            ..AccountFilter::new()
+        }
+    }
+
+    /// 直接传给 `subscribe_dex_events` 等订阅方法的过滤器
+    pub fn build(&self) -> (Vec<TransactionFilter>, Vec<AccountFilter>) {
+        (vec![self.transaction_filter()], vec![self.account_filter()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_mint_derives_accounts() {
+        let mint = Pubkey::new_unique();
+        let (clmm_pool, other_pool) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut registry = PoolRegistry::new();
+        registry.register(clmm_pool, WSOL_MINT, mint);
+        registry.register(other_pool, WSOL_MINT, Pubkey::new_unique());
+
+        let builder = SubscriptionBuilder::for_mint(mint).with_registry(&registry).mint(mint);
+        let accounts = builder.accounts();
+        assert_eq!(accounts.len(), 6);
+        assert_eq!(accounts[0], mint);
+        assert!(accounts.contains(&pumpfun_bonding_curve(&mint)));
+        assert!(accounts.contains(&pumpswap_canonical_pool(&mint)));
+        assert!(accounts.contains(&clmm_pool) && !accounts.contains(&other_pool));
+
+        let (txs, accs) = builder.build();
+        assert_eq!(txs[0].account_include.len(), 6);
+        assert!(accs[0].account.contains(&clmm_pool.to_string()));
+        assert!(accs[0].owner.is_empty());
+    }
+}
@@ -0,0 +1,103 @@
+//! 当前线程的 CPU 亲和性与调度优先级
+//!
+//! 共享主机上解析线程被调度出去的代价远大于解析器本身的微优化，
+//! 流处理线程可按 `ClientConfig::stream_cpu` / `stream_thread_priority` 绑核并提升优先级。
+//! 仅 Linux 支持，其他平台返回 `ErrorKind::Unsupported`。
+
+use super::types::ThreadPriority;
+use std::io;
+
+/// 将当前线程绑定到 `cpu` 核心
+#[cfg(target_os = "linux")]
+pub fn pin_current_thread(cpu: usize) -> io::Result<()> {
+    if cpu >= libc::CPU_SETSIZE as usize {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("CPU {} out of range", cpu),
+        ));
+    }
+    // SAFETY: cpu_set_t 是纯位图，全零即空集合；cpu 已检查不越界
+    let ret = unsafe {
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        libc::CPU_SET(cpu, &mut set);
+        libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &set)
+    };
+    if ret != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// 设置当前线程的调度优先级
+#[cfg(target_os = "linux")]
+pub fn set_current_thread_priority(priority: ThreadPriority) -> io::Result<()> {
+    match priority {
+        ThreadPriority::Nice(nice) => {
+            // Linux 上 setpriority 以线程 id 为目标时只作用于该线程
+            // SAFETY: 只传入标量参数
+            let ret = unsafe { libc::setpriority(libc::PRIO_PROCESS, libc::gettid() as _, nice) };
+            if ret != 0 {
+                return Err(io::Error::last_os_error());
+            }
+        }
+        ThreadPriority::Realtime(priority) => {
+            let param = libc::sched_param { sched_priority: priority };
+            // SAFETY: param 在调用期间有效
+            let ret = unsafe {
+                libc::pthread_setschedparam(libc::pthread_self(), libc::SCHED_FIFO, &param)
+            };
+            if ret != 0 {
+                return Err(io::Error::from_raw_os_error(ret));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn pin_current_thread(_cpu: usize) -> io::Result<()> {
+    Err(io::ErrorKind::Unsupported.into())
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn set_current_thread_priority(_priority: ThreadPriority) -> io::Result<()> {
+    Err(io::ErrorKind::Unsupported.into())
+}
+
+/// 按配置调整当前线程，失败只记录警告，不影响事件流
+pub(crate) fn tune_current_thread(cpu: Option<usize>, priority: Option<ThreadPriority>) {
+    if let Some(cpu) = cpu {
+        if let Err(e) = pin_current_thread(cpu) {
+            log::warn!("failed to pin stream thread to CPU {}: {}", cpu, e);
+        }
+    }
+    if let Some(priority) = priority {
+        if let Err(e) = set_current_thread_priority(priority) {
+            log::warn!("failed to set stream thread priority {:?}: {}", priority, e);
+        }
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tune_current_thread() {
+        // 在独立线程上修改，避免影响测试线程
+        std::thread::spawn(|| {
+            let cpu = unsafe { libc::sched_getcpu() } as usize;
+            pin_current_thread(cpu).unwrap();
+            assert_eq!(unsafe { libc::sched_getcpu() } as usize, cpu);
+            let err = pin_current_thread(libc::CPU_SETSIZE as usize).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+            // 提高 nice 值（降低优先级）无需权限
+            set_current_thread_priority(ThreadPriority::Nice(19)).unwrap();
+            let nice = unsafe { libc::getpriority(libc::PRIO_PROCESS, libc::gettid() as _) };
+            assert_eq!(nice, 19);
+        })
+        .join()
+        .unwrap();
+    }
+}
@@ -1,4 +1,6 @@
+use crate::core::validation::AmountValidation;
 use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
 use yellowstone_grpc_proto::geyser::SubscribeRequestFilterAccountsFilter;
 
 /// 事件输出顺序模式
@@ -22,6 +24,7 @@ pub enum OrderMode {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ClientConfig {
     /// 是否启用性能监控
     pub enable_metrics: bool,
@@ -45,6 +48,138 @@ pub struct ClientConfig {
     /// MicroBatch 模式下的时间窗口大小（微秒）
     /// 默认 100μs，可根据网络状况调整
     pub micro_batch_us: u64,
+    /// 订阅 block meta 并输出 `DexEvent::BlockMeta`（含 slot leader），不经过排序缓冲
+    pub emit_block_meta: bool,
+    /// 按 slot 汇总收到交易的 compute unit 价格，输出 `DexEvent::PriorityFeeStats`
+    pub emit_priority_fees: bool,
+    /// 交易订阅中每个解析出的事件之后附带输出 `DexEvent::RawData`（原始日志行或指令数据），用于审计和重新解析
+    pub retain_raw_data: bool,
+    /// 交易订阅中为每笔产出事件的交易输出一个 `DexEvent::TransactionAccounts`（完整账户表），位于该交易的事件之前
+    pub emit_transaction_accounts: bool,
+    /// 按签名缓存最近交易的完整账户表，供 `YellowstoneGrpc::transaction_accounts` 查询，值为缓存的交易数，0 表示关闭
+    /// 只缓存产出事件的交易
+    pub transaction_accounts_cache: usize,
+    /// 交易账户表缓存条目的存活时间（毫秒），0 表示只按容量淘汰
+    pub transaction_accounts_cache_ttl_ms: u64,
+    /// 配置断点存储后，重连 / 重启时以 gRPC `from_slot` 从断点 slot 重新订阅
+    /// 关闭或服务端不支持时只发出 `ClientEvent::Resume`，由调用方通过 RPC 补齐
+    pub resume_from_slot: bool,
+    /// 首次订阅从该 slot 开始重放历史（Yellowstone `from_slot`），之后转入实时推送
+    /// 重放阶段的事件标记 `EventMetadata::replayed`，追上实时 slot 时发出 `ClientEvent::ReplayCompleted`；
+    /// 断点存储中已有断点时以断点为准
+    pub from_slot: Option<u64>,
+    /// 僵死流看门狗超时（毫秒），0 表示关闭
+    /// 超过此时间未收到任何消息（含 pong）或 slot 未推进，强制重连并发出 `ClientEvent::StaleStream`
+    pub stale_stream_timeout_ms: u64,
+    /// 输出队列容量（分片时为每个分片的容量）
+    pub queue_capacity: usize,
+    /// 输出队列分片数，事件按 `hash(pool) % N` 分片，见 `subscribe_dex_events_sharded`
+    pub output_shards: usize,
+    /// 输出队列满时的处理策略
+    pub backpressure: BackpressurePolicy,
+    /// 异步监听器的最大并发回调数，1 表示按顺序串行回调
+    pub listener_concurrency: usize,
+    /// 捕获解析器和用户回调中的 panic 并计数，不中断事件流：解析器 panic 输出 `DexEvent::Error`，回调 panic 记录日志
+    pub catch_panics: bool,
+    /// 流处理线程（接收 + 解析）绑定的 CPU 核心
+    /// 与 `stream_thread_priority` 任一设置时，事件流在独立线程的单线程 runtime 上运行，不占用调用方 runtime
+    pub stream_cpu: Option<usize>,
+    /// 流处理线程的调度优先级，设置失败（如权限不足）只记录警告
+    pub stream_thread_priority: Option<ThreadPriority>,
+    /// 事件流运行时的延迟配置（实验性），见 [`LatencyProfile`]
+    pub latency_profile: LatencyProfile,
+    /// 单笔交易的解析上限（CPI 深度、指令数），默认不限制
+    pub parse_limits: ParseLimits,
+    /// 用交易的 token 余额变化校验 swap 数量，不一致时输出 `DexEvent::ValidationWarning`，默认关闭
+    pub amount_validation: Option<AmountValidation>,
+    /// 在各协议建池事件之后输出统一的 `DexEvent::NormalizedPoolCreated`，默认关闭
+    pub emit_pool_created: bool,
+    /// 按过滤原因（投票、失败、不含已支持程序、无事件）统计收到的交易，见 `ClientStats::transactions`，
+    /// 用于按实际命中率调整交易订阅过滤器，默认关闭
+    pub transaction_stats: bool,
+}
+
+/// 单笔交易的解析上限，防止恶意程序的深层 CPI、大量指令或异常耗时拖慢解析
+///
+/// 超出上限的指令（及其日志）不解析，已解析的事件照常输出，之后附带一个 `DexEvent::ParseTruncated` 标记，
+/// 并计入 `ClientStats::transactions_truncated`。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ParseLimits {
+    /// 最大调用栈深度（外层指令为 1），0 表示不限制
+    pub max_depth: u32,
+    /// 最多解析的指令数（外层 + 内层），0 表示不限制
+    pub max_instructions: usize,
+    /// 单笔交易的解析时间预算（微秒，如 200），超出后停止解析剩余的指令和日志，0 表示不限制
+    pub time_budget_us: u64,
+}
+
+impl ParseLimits {
+    #[inline]
+    pub fn depth_exceeded(&self, depth: u32) -> bool {
+        self.max_depth != 0 && depth > self.max_depth
+    }
+
+    /// 已遇到 `count` 条指令时是否超出上限
+    #[inline]
+    pub fn count_exceeded(&self, count: usize) -> bool {
+        self.max_instructions != 0 && count > self.max_instructions
+    }
+
+    /// 从现在开始按 `time_budget_us` 计算的截止时间
+    #[inline]
+    pub fn deadline(&self) -> ParseDeadline {
+        ParseDeadline(
+            (self.time_budget_us > 0)
+                .then(|| Instant::now() + Duration::from_micros(self.time_budget_us)),
+        )
+    }
+}
+
+/// 单笔交易的解析截止时间，见 [`ParseLimits::deadline`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ParseDeadline(Option<Instant>);
+
+impl ParseDeadline {
+    #[inline]
+    pub fn expired(&self) -> bool {
+        self.0.is_some_and(|deadline| Instant::now() >= deadline)
+    }
+}
+
+/// 事件流运行时的延迟配置（实验性）
+///
+/// 非 `Standard` 时事件流总是在独立线程上运行，`stream_cpu` / `stream_thread_priority` 同样生效。
+/// 接收仍走 tokio 的 epoll 驱动，未使用 io_uring。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LatencyProfile {
+    /// 在调用方的 tokio runtime 上运行（配置了绑核或优先级时使用独立线程）
+    #[default]
+    Standard,
+    /// 独立线程上的单线程 runtime，每轮询一次任务就检查一次 IO 事件
+    Dedicated,
+    /// 在 `Dedicated` 基础上忙轮询：runtime 从不休眠，持续非阻塞地检查 IO，
+    /// 以占满一个核心为代价省去线程唤醒延迟，建议配合 `stream_cpu` 使用
+    BusyPoll,
+}
+
+/// 流处理线程的调度优先级（仅 Linux 生效）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ThreadPriority {
+    /// 普通调度下的 nice 值（-20..=19，越小优先级越高，负值需要 CAP_SYS_NICE）
+    Nice(i32),
+    /// SCHED_FIFO 实时调度优先级（1..=99，需要 CAP_SYS_NICE）
+    Realtime(i32),
+}
+
+/// 输出队列背压策略
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum BackpressurePolicy {
+    /// 队列满时丢弃新事件
+    #[default]
+    DropNewest,
+    /// 队列满时丢弃最旧的事件，保证消费者看到最新数据
+    DropOldest,
 }
 
 impl Default for ClientConfig {
@@ -63,11 +198,41 @@ impl Default for ClientConfig {
             order_mode: OrderMode::Unordered,
             order_timeout_ms: 100,
             micro_batch_us: 100, // 100μs 默认窗口
+            emit_block_meta: false,
+            emit_priority_fees: false,
+            retain_raw_data: false,
+            emit_transaction_accounts: false,
+            transaction_accounts_cache: 0,
+            transaction_accounts_cache_ttl_ms: 0,
+            resume_from_slot: true,
+            from_slot: None,
+            stale_stream_timeout_ms: 0,
+            queue_capacity: 100_000,
+            output_shards: 1,
+            backpressure: BackpressurePolicy::DropNewest,
+            listener_concurrency: 1,
+            catch_panics: true,
+            stream_cpu: None,
+            stream_thread_priority: None,
+            latency_profile: LatencyProfile::Standard,
+            parse_limits: ParseLimits::default(),
+            amount_validation: None,
+            emit_pool_created: false,
+            transaction_stats: false,
         }
     }
 }
 
 impl ClientConfig {
+    /// 交易账户表缓存的容量 / TTL 配置
+    pub fn transaction_accounts_limits(&self) -> crate::core::BoundedMapConfig {
+        let config = crate::core::BoundedMapConfig::with_capacity(self.transaction_accounts_cache);
+        match self.transaction_accounts_cache_ttl_ms {
+            0 => config,
+            ttl_ms => config.with_ttl(std::time::Duration::from_millis(ttl_ms)),
+        }
+    }
+
     pub fn low_latency() -> Self {
         Self {
             enable_metrics: false,
@@ -83,6 +248,27 @@ impl ClientConfig {
             order_mode: OrderMode::Unordered,
             order_timeout_ms: 50,
             micro_batch_us: 50, // 50μs 更激进的窗口
+            emit_block_meta: false,
+            emit_priority_fees: false,
+            retain_raw_data: false,
+            emit_transaction_accounts: false,
+            transaction_accounts_cache: 0,
+            transaction_accounts_cache_ttl_ms: 0,
+            resume_from_slot: true,
+            from_slot: None,
+            stale_stream_timeout_ms: 0,
+            queue_capacity: 100_000,
+            output_shards: 1,
+            backpressure: BackpressurePolicy::DropNewest,
+            listener_concurrency: 1,
+            catch_panics: true,
+            stream_cpu: None,
+            stream_thread_priority: None,
+            latency_profile: LatencyProfile::Standard,
+            parse_limits: ParseLimits::default(),
+            amount_validation: None,
+            emit_pool_created: false,
+            transaction_stats: false,
         }
     }
 
@@ -101,11 +287,63 @@ impl ClientConfig {
             order_mode: OrderMode::Unordered,
             order_timeout_ms: 200,
             micro_batch_us: 200, // 200μs 高吞吐模式
+            emit_block_meta: false,
+            emit_priority_fees: false,
+            retain_raw_data: false,
+            emit_transaction_accounts: false,
+            transaction_accounts_cache: 0,
+            transaction_accounts_cache_ttl_ms: 0,
+            resume_from_slot: true,
+            from_slot: None,
+            stale_stream_timeout_ms: 0,
+            queue_capacity: 100_000,
+            output_shards: 1,
+            backpressure: BackpressurePolicy::DropNewest,
+            listener_concurrency: 1,
+            catch_panics: true,
+            stream_cpu: None,
+            stream_thread_priority: None,
+            latency_profile: LatencyProfile::Standard,
+            parse_limits: ParseLimits::default(),
+            amount_validation: None,
+            emit_pool_created: false,
+            transaction_stats: false,
         }
     }
 }
 
-#[derive(Debug, Clone)]
+/// 僵死流判定原因
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StaleReason {
+    /// 超时未收到任何消息
+    NoMessage,
+    /// 仍有消息但 slot 未推进
+    NoSlotProgress,
+}
+
+/// 客户端状态通知，通过 `YellowstoneGrpc::subscribe_client_events` 接收
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClientEvent {
+    /// 看门狗判定流已僵死，当前连接将被丢弃并重连
+    StaleStream {
+        reason: StaleReason,
+        /// 断开前观察到的最高 slot
+        last_slot: u64,
+        /// 距最后一次消息/slot 推进的时间（毫秒）
+        idle_ms: u64,
+    },
+    /// 配置了断点存储且已有断点时，每次（重新）订阅时发出
+    Resume {
+        checkpoint: super::checkpoint::Checkpoint,
+        /// 服务端是否从断点 slot 重放；为 false 时断点之后的事件需通过 RPC 补齐
+        replayed: bool,
+    },
+    /// `from_slot` 重放已追上订阅时的实时 slot，之后的事件为实时推送
+    ReplayCompleted { live_slot: u64 },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TransactionFilter {
     pub account_include: Vec<String>,
     pub account_exclude: Vec<String>,
@@ -152,10 +390,13 @@ impl Default for TransactionFilter {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AccountFilter {
     pub account: Vec<String>,
     pub owner: Vec<String>,
+    /// memcmp / datasize 过滤器不支持从配置文件读取
+    #[serde(skip)]
     pub filters: Vec<SubscribeRequestFilterAccountsFilter>,
 }
 
@@ -214,10 +455,19 @@ pub enum Protocol {
     MeteoraDammV2,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum EventType {
     // Block events
     BlockMeta,
+    PriorityFeeStats,
+    RawData,
+    TransactionAccounts,
+    ParseTruncated,
+    FirstSwapInSlot,
+    TokenMetadataResolved,
+    ValidationWarning,
+    NormalizedPoolCreated,
+    MigrationCompleted,
 
     // Bonk events
     BonkTrade,
@@ -239,7 +489,9 @@ pub enum EventType {
     PumpSwapCreatePool,
     PumpSwapLiquidityAdded,
     PumpSwapLiquidityRemoved,
-    // PumpSwapPoolUpdated,
+    PumpSwapPoolUpdated,
+    PumpSwapConfigUpdated,
+    PumpSwapCreatorFeeClaimed,
     // PumpSwapFeesClaimed,
 
     // Raydium CPMM events
@@ -257,6 +509,9 @@ pub enum EventType {
     // RaydiumClmmDecreaseLiquidity,
     // RaydiumClmmOpenPositionWithTokenExtNft,
     // RaydiumClmmCollectFee,
+    // RaydiumClmmInitializeReward,
+    // RaydiumClmmCollectReward,
+    // RaydiumClmmSetRewardParams,
 
     // Raydium AMM V4 events
     // RaydiumAmmV4Swap,
@@ -297,9 +552,13 @@ pub enum EventType {
 
     AccountPumpSwapGlobalConfig,
     AccountPumpSwapPool,
+    AccountClosed,
+    AccountRaydiumAmmV4AmmInfo,
+    AccountRaydiumAmmV4TargetOrders,
+    AccountSerumOpenOrders,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EventTypeFilter {
     pub include_only: Option<Vec<EventType>>,
     pub exclude_types: Option<Vec<EventType>>,
@@ -321,11 +580,13 @@ impl EventTypeFilter {
                 return true;
             }
             // Special case: PumpFunTrade discriminator is shared by Buy/Sell/BuyExactSolIn
+            // and Raydium Launchpad TradeEvent (BonkTrade)
             // If filter includes any of these specific types, allow PumpFunTrade through
             // (secondary filtering will happen after parsing)
             if event_type == EventType::PumpFunTrade {
                 return include_only.iter().any(|t| matches!(t,
                     EventType::PumpFunBuy | EventType::PumpFunSell | EventType::PumpFunBuyExactSolIn
+                        | EventType::BonkTrade
                 ));
             }
             return false;
@@ -414,6 +675,9 @@ impl EventTypeFilter {
                         | EventType::PumpSwapCreatePool
                         | EventType::PumpSwapLiquidityAdded
                         | EventType::PumpSwapLiquidityRemoved
+                        | EventType::PumpSwapPoolUpdated
+                        | EventType::PumpSwapConfigUpdated
+                        | EventType::PumpSwapCreatorFeeClaimed
                 )
             });
         }
@@ -426,6 +690,9 @@ impl EventTypeFilter {
                         | EventType::PumpSwapCreatePool
                         | EventType::PumpSwapLiquidityAdded
                         | EventType::PumpSwapLiquidityRemoved
+                        | EventType::PumpSwapPoolUpdated
+                        | EventType::PumpSwapConfigUpdated
+                        | EventType::PumpSwapCreatorFeeClaimed
                 )
             });
         }
@@ -0,0 +1,199 @@
+//! WebSocket 事件广播服务（feature `ws-server`）
+//!
+//! 从输出队列取出事件，每个事件只序列化一次 JSON，广播给所有 WebSocket 连接。
+//! 连接可随时发送文本消息设置自己的订阅过滤器，空列表表示不过滤：
+//!
+//! ```json
+//! {"event_types": ["PumpFunBuy", "PumpFunSell"], "mints": ["<mint>"]}
+//! ```
+//!
+//! 处理过慢的连接会跳过积压的事件（记录日志），不影响其他连接。
+
+use crate::DexEvent;
+use crossbeam_queue::ArrayQueue;
+use futures::{SinkExt, StreamExt};
+use serde::Deserialize;
+use solana_sdk::pubkey::Pubkey;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tokio::time::Duration;
+use tokio_tungstenite::tungstenite::Message;
+
+/// 每个连接最多积压的事件数，超过后跳过最旧的事件
+const CONNECTION_BACKLOG: usize = 4096;
+
+/// accept 失败后重试前的等待时间
+const ACCEPT_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+/// 已序列化、待广播的事件
+#[derive(Debug)]
+struct Broadcast {
+    event_type: &'static str,
+    mint: Option<Pubkey>,
+    json: String,
+}
+
+/// 单个连接的订阅过滤器
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WsSubscription {
+    /// 只接收这些事件类型（`DexEvent::event_type_name`），空表示全部
+    pub event_types: Vec<String>,
+    /// 只接收这些 mint 的事件，空表示全部
+    pub mints: Vec<Pubkey>,
+}
+
+/// 客户端发送的订阅消息，mint 为 base58 字符串
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct SubscribeMessage {
+    event_types: Vec<String>,
+    mints: Vec<String>,
+}
+
+impl WsSubscription {
+    /// 解析客户端发送的订阅消息
+    pub fn from_json(text: &str) -> Result<Self, String> {
+        let msg: SubscribeMessage = serde_json::from_str(text).map_err(|e| e.to_string())?;
+        let mints = msg
+            .mints
+            .iter()
+            .map(|m| m.parse().map_err(|_| format!("invalid mint: {}", m)))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { event_types: msg.event_types, mints })
+    }
+
+    fn matches(&self, event: &Broadcast) -> bool {
+        (self.event_types.is_empty() || self.event_types.iter().any(|t| t == event.event_type))
+            && (self.mints.is_empty() || event.mint.is_some_and(|m| self.mints.contains(&m)))
+    }
+}
+
+/// 绑定地址，把 `queue` 中的事件广播给所有 WebSocket 连接，返回服务任务句柄
+///
+/// 服务会独占消费 `queue`，需要同时写入其他 sink 时请使用分片或单独的订阅。
+pub async fn serve_events(
+    addr: SocketAddr,
+    queue: Arc<ArrayQueue<DexEvent>>,
+) -> std::io::Result<JoinHandle<()>> {
+    let listener = TcpListener::bind(addr).await?;
+    let (tx, _) = broadcast::channel::<Arc<Broadcast>>(CONNECTION_BACKLOG);
+
+    let pump_tx = tx.clone();
+    let pump = tokio::spawn(async move {
+        loop {
+            let Some(event) = queue.pop() else {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                continue;
+            };
+            // 没有连接时不序列化
+            if pump_tx.receiver_count() == 0 {
+                continue;
+            }
+            match serde_json::to_string(&event) {
+                Ok(json) => {
+                    let _ = pump_tx.send(Arc::new(Broadcast {
+                        event_type: event.event_type_name(),
+                        mint: event.mint(),
+                        json,
+                    }));
+                }
+                Err(e) => log::error!("Failed to serialize event: {}", e),
+            }
+        }
+    });
+
+    Ok(tokio::spawn(async move {
+        // 服务任务被 abort 时一并停止事件泵
+        let _pump = AbortOnDrop(pump);
+        loop {
+            let (stream, peer) = match listener.accept().await {
+                Ok(accepted) => accepted,
+                Err(e) => {
+                    // 文件描述符耗尽（EMFILE）等错误会持续出现，退避后重试
+                    log::error!("ws accept error: {}", e);
+                    tokio::time::sleep(ACCEPT_RETRY_DELAY).await;
+                    continue;
+                }
+            };
+            let rx = tx.subscribe();
+            tokio::spawn(async move {
+                if let Err(e) = handle_connection(stream, rx).await {
+                    log::debug!("ws connection {} error: {}", peer, e);
+                }
+            });
+        }
+    }))
+}
+
+struct AbortOnDrop(JoinHandle<()>);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+async fn handle_connection(
+    stream: TcpStream,
+    mut rx: broadcast::Receiver<Arc<Broadcast>>,
+) -> Result<(), tokio_tungstenite::tungstenite::Error> {
+    let ws = tokio_tungstenite::accept_async(stream).await?;
+    let (mut sink, mut incoming) = ws.split();
+    let mut subscription = WsSubscription::default();
+
+    loop {
+        tokio::select! {
+            event = rx.recv() => match event {
+                Ok(event) => {
+                    if subscription.matches(&event) {
+                        sink.send(Message::Text(event.json.clone())).await?;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    log::warn!("ws connection lagged, skipped {} events", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => return Ok(()),
+            },
+            msg = incoming.next() => match msg {
+                Some(Ok(Message::Text(text))) => match WsSubscription::from_json(&text) {
+                    Ok(sub) => subscription = sub,
+                    Err(e) => {
+                        let error = serde_json::json!({ "error": e });
+                        sink.send(Message::Text(error.to_string())).await?;
+                    }
+                },
+                Some(Ok(Message::Ping(payload))) => sink.send(Message::Pong(payload)).await?,
+                Some(Ok(Message::Close(_))) | None => return Ok(()),
+                Some(Ok(_)) => {}
+                Some(Err(e)) => return Err(e),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscription_filter() {
+        let mint = Pubkey::new_unique();
+        let event = Broadcast { event_type: "PumpFunBuy", mint: Some(mint), json: String::new() };
+        let no_mint = Broadcast { event_type: "BlockMeta", mint: None, json: String::new() };
+
+        let all = WsSubscription::default();
+        assert!(all.matches(&event) && all.matches(&no_mint));
+
+        let json = format!(r#"{{"event_types": ["PumpFunBuy"], "mints": ["{}"]}}"#, mint);
+        let sub = WsSubscription::from_json(&json).unwrap();
+        assert!(sub.matches(&event));
+        assert!(!sub.matches(&no_mint));
+
+        let sub = WsSubscription::from_json(r#"{"event_types": ["PumpFunSell"]}"#).unwrap();
+        assert!(!sub.matches(&event));
+        assert!(WsSubscription::from_json(r#"{"mints": ["not-a-key"]}"#).is_err());
+    }
+}
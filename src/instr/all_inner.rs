@@ -15,9 +15,17 @@
 //! - **启用**: `cargo build --features parse-zero-copy --no-default-features`
 //! - **优点**: 最快、零拷贝、无验证开销、适合超高频场景
 //! - **适用**: 性能关键路径、每秒数万次解析的场景
+//!
+//! ### 3. 边界检查零拷贝解析器（安全）
+//! - **启用**: `cargo build --features parse-zero-copy-safe --no-default-features`
+//! - **优点**: 保持零拷贝的偏移布局，所有读取统一经过 `zc_read!` 边界检查
+//! - **适用**: 安全敏感部署，畸形/截断数据时统一返回 `None`
 
 use crate::core::events::*;
 use crate::instr::inner_common::*;
+#[cfg(feature = "parse-borsh")]
+use borsh::BorshDeserialize;
+use solana_sdk::pubkey::Pubkey;
 
 // ============================================================================
 // Raydium CPMM
@@ -94,25 +102,23 @@ pub mod raydium_cpmm {
     #[cfg(feature = "parse-zero-copy")]
     #[inline(always)]
     fn parse_swap_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-        unsafe {
-            if !check_length(data, 32 + 8 + 8) {
-                return None;
-            }
-            let pool = read_pubkey_unchecked(data, 0);
-            let input_amount = read_u64_unchecked(data, 32);
-            let output_amount = read_u64_unchecked(data, 40);
-            Some(DexEvent::RaydiumCpmmSwap(RaydiumCpmmSwapEvent {
-                metadata,
-                pool_id: pool,
-                input_amount,
-                output_amount,
-                input_vault_before: 0,
-                output_vault_before: 0,
-                input_transfer_fee: 0,
-                output_transfer_fee: 0,
-                base_input: true,
-            }))
+        if !check_length(data, 32 + 8 + 8) {
+            return None;
         }
+        let pool = zc_read!(pubkey, data, 0);
+        let input_amount = zc_read!(u64, data, 32);
+        let output_amount = zc_read!(u64, data, 40);
+        Some(DexEvent::RaydiumCpmmSwap(RaydiumCpmmSwapEvent {
+            metadata,
+            pool_id: pool,
+            input_amount,
+            output_amount,
+            input_vault_before: 0,
+            output_vault_before: 0,
+            input_transfer_fee: 0,
+            output_transfer_fee: 0,
+            base_input: true,
+        }))
     }
 
     // ============================================================================
@@ -158,23 +164,21 @@ pub mod raydium_cpmm {
     #[cfg(feature = "parse-zero-copy")]
     #[inline(always)]
     fn parse_deposit_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-        unsafe {
-            if !check_length(data, 32 + 8 + 8 + 8) {
-                return None;
-            }
-            let pool = read_pubkey_unchecked(data, 0);
-            let token0_amount = read_u64_unchecked(data, 32);
-            let token1_amount = read_u64_unchecked(data, 40);
-            let lp_token_amount = read_u64_unchecked(data, 48);
-            Some(DexEvent::RaydiumCpmmDeposit(RaydiumCpmmDepositEvent {
-                metadata,
-                pool,
-                lp_token_amount,
-                token0_amount,
-                token1_amount,
-                user: Pubkey::default(),
-            }))
+        if !check_length(data, 32 + 8 + 8 + 8) {
+            return None;
         }
+        let pool = zc_read!(pubkey, data, 0);
+        let token0_amount = zc_read!(u64, data, 32);
+        let token1_amount = zc_read!(u64, data, 40);
+        let lp_token_amount = zc_read!(u64, data, 48);
+        Some(DexEvent::RaydiumCpmmDeposit(RaydiumCpmmDepositEvent {
+            metadata,
+            pool,
+            lp_token_amount,
+            token0_amount,
+            token1_amount,
+            user: Pubkey::default(),
+        }))
     }
 
     // ============================================================================
@@ -220,23 +224,21 @@ pub mod raydium_cpmm {
     #[cfg(feature = "parse-zero-copy")]
     #[inline(always)]
     fn parse_withdraw_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-        unsafe {
-            if !check_length(data, 32 + 8 + 8 + 8) {
-                return None;
-            }
-            let pool = read_pubkey_unchecked(data, 0);
-            let lp_token_amount = read_u64_unchecked(data, 32);
-            let token0_amount = read_u64_unchecked(data, 40);
-            let token1_amount = read_u64_unchecked(data, 48);
-            Some(DexEvent::RaydiumCpmmWithdraw(RaydiumCpmmWithdrawEvent {
-                metadata,
-                pool,
-                lp_token_amount,
-                token0_amount,
-                token1_amount,
-                user: Pubkey::default(),
-            }))
+        if !check_length(data, 32 + 8 + 8 + 8) {
+            return None;
         }
+        let pool = zc_read!(pubkey, data, 0);
+        let lp_token_amount = zc_read!(u64, data, 32);
+        let token0_amount = zc_read!(u64, data, 40);
+        let token1_amount = zc_read!(u64, data, 48);
+        Some(DexEvent::RaydiumCpmmWithdraw(RaydiumCpmmWithdrawEvent {
+            metadata,
+            pool,
+            lp_token_amount,
+            token0_amount,
+            token1_amount,
+            user: Pubkey::default(),
+        }))
     }
 }
 
@@ -314,39 +316,37 @@ pub mod raydium_amm {
     #[cfg(feature = "parse-zero-copy")]
     #[inline(always)]
     fn parse_swap_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-        unsafe {
-            if !check_length(data, 32 + 8 + 8) {
-                return None;
-            }
-            let amm = read_pubkey_unchecked(data, 0);
-            let amount_in = read_u64_unchecked(data, 32);
-            let amount_out = read_u64_unchecked(data, 40);
-            Some(DexEvent::RaydiumAmmV4Swap(RaydiumAmmV4SwapEvent {
-                metadata,
-                amm,
-                amount_in,
-                amount_out,
-                minimum_amount_out: 0,
-                max_amount_in: 0,
-                token_program: Pubkey::default(),
-                amm_authority: Pubkey::default(),
-                amm_open_orders: Pubkey::default(),
-                amm_target_orders: None,
-                pool_coin_token_account: Pubkey::default(),
-                pool_pc_token_account: Pubkey::default(),
-                serum_program: Pubkey::default(),
-                serum_market: Pubkey::default(),
-                serum_bids: Pubkey::default(),
-                serum_asks: Pubkey::default(),
-                serum_event_queue: Pubkey::default(),
-                serum_coin_vault_account: Pubkey::default(),
-                serum_pc_vault_account: Pubkey::default(),
-                serum_vault_signer: Pubkey::default(),
-                user_source_token_account: Pubkey::default(),
-                user_destination_token_account: Pubkey::default(),
-                user_source_owner: Pubkey::default(),
-            }))
+        if !check_length(data, 32 + 8 + 8) {
+            return None;
         }
+        let amm = zc_read!(pubkey, data, 0);
+        let amount_in = zc_read!(u64, data, 32);
+        let amount_out = zc_read!(u64, data, 40);
+        Some(DexEvent::RaydiumAmmV4Swap(RaydiumAmmV4SwapEvent {
+            metadata,
+            amm,
+            amount_in,
+            amount_out,
+            minimum_amount_out: 0,
+            max_amount_in: 0,
+            token_program: Pubkey::default(),
+            amm_authority: Pubkey::default(),
+            amm_open_orders: Pubkey::default(),
+            amm_target_orders: None,
+            pool_coin_token_account: Pubkey::default(),
+            pool_pc_token_account: Pubkey::default(),
+            serum_program: Pubkey::default(),
+            serum_market: Pubkey::default(),
+            serum_bids: Pubkey::default(),
+            serum_asks: Pubkey::default(),
+            serum_event_queue: Pubkey::default(),
+            serum_coin_vault_account: Pubkey::default(),
+            serum_pc_vault_account: Pubkey::default(),
+            serum_vault_signer: Pubkey::default(),
+            user_source_token_account: Pubkey::default(),
+            user_destination_token_account: Pubkey::default(),
+            user_source_owner: Pubkey::default(),
+        }))
     }
 
     // ============================================================================
@@ -391,34 +391,32 @@ pub mod raydium_amm {
     #[cfg(feature = "parse-zero-copy")]
     #[inline(always)]
     fn parse_deposit_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-        unsafe {
-            if !check_length(data, 32 + 8 + 8) {
-                return None;
-            }
-            let amm = read_pubkey_unchecked(data, 0);
-            let max_coin_amount = read_u64_unchecked(data, 32);
-            let max_pc_amount = read_u64_unchecked(data, 40);
-            Some(DexEvent::RaydiumAmmV4Deposit(RaydiumAmmV4DepositEvent {
-                metadata,
-                amm,
-                max_coin_amount,
-                max_pc_amount,
-                base_side: 0,
-                token_program: Pubkey::default(),
-                amm_authority: Pubkey::default(),
-                amm_open_orders: Pubkey::default(),
-                amm_target_orders: Pubkey::default(),
-                lp_mint_address: Pubkey::default(),
-                pool_coin_token_account: Pubkey::default(),
-                pool_pc_token_account: Pubkey::default(),
-                serum_market: Pubkey::default(),
-                serum_event_queue: Pubkey::default(),
-                user_coin_token_account: Pubkey::default(),
-                user_pc_token_account: Pubkey::default(),
-                user_lp_token_account: Pubkey::default(),
-                user_owner: Pubkey::default(),
-            }))
+        if !check_length(data, 32 + 8 + 8) {
+            return None;
         }
+        let amm = zc_read!(pubkey, data, 0);
+        let max_coin_amount = zc_read!(u64, data, 32);
+        let max_pc_amount = zc_read!(u64, data, 40);
+        Some(DexEvent::RaydiumAmmV4Deposit(RaydiumAmmV4DepositEvent {
+            metadata,
+            amm,
+            max_coin_amount,
+            max_pc_amount,
+            base_side: 0,
+            token_program: Pubkey::default(),
+            amm_authority: Pubkey::default(),
+            amm_open_orders: Pubkey::default(),
+            amm_target_orders: Pubkey::default(),
+            lp_mint_address: Pubkey::default(),
+            pool_coin_token_account: Pubkey::default(),
+            pool_pc_token_account: Pubkey::default(),
+            serum_market: Pubkey::default(),
+            serum_event_queue: Pubkey::default(),
+            user_coin_token_account: Pubkey::default(),
+            user_pc_token_account: Pubkey::default(),
+            user_lp_token_account: Pubkey::default(),
+            user_owner: Pubkey::default(),
+        }))
     }
 
     // ============================================================================
@@ -462,39 +460,37 @@ pub mod raydium_amm {
     #[cfg(feature = "parse-zero-copy")]
     #[inline(always)]
     fn parse_withdraw_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-        unsafe {
-            if !check_length(data, 32 + 8) {
-                return None;
-            }
-            let amm = read_pubkey_unchecked(data, 0);
-            let amount = read_u64_unchecked(data, 32);
-            Some(DexEvent::RaydiumAmmV4Withdraw(RaydiumAmmV4WithdrawEvent {
-                metadata,
-                amm,
-                amount,
-                token_program: Pubkey::default(),
-                amm_authority: Pubkey::default(),
-                amm_open_orders: Pubkey::default(),
-                amm_target_orders: Pubkey::default(),
-                lp_mint_address: Pubkey::default(),
-                pool_coin_token_account: Pubkey::default(),
-                pool_pc_token_account: Pubkey::default(),
-                pool_withdraw_queue: Pubkey::default(),
-                pool_temp_lp_token_account: Pubkey::default(),
-                serum_program: Pubkey::default(),
-                serum_market: Pubkey::default(),
-                serum_bids: Pubkey::default(),
-                serum_asks: Pubkey::default(),
-                serum_event_queue: Pubkey::default(),
-                serum_coin_vault_account: Pubkey::default(),
-                serum_pc_vault_account: Pubkey::default(),
-                serum_vault_signer: Pubkey::default(),
-                user_lp_token_account: Pubkey::default(),
-                user_coin_token_account: Pubkey::default(),
-                user_pc_token_account: Pubkey::default(),
-                user_owner: Pubkey::default(),
-            }))
+        if !check_length(data, 32 + 8) {
+            return None;
         }
+        let amm = zc_read!(pubkey, data, 0);
+        let amount = zc_read!(u64, data, 32);
+        Some(DexEvent::RaydiumAmmV4Withdraw(RaydiumAmmV4WithdrawEvent {
+            metadata,
+            amm,
+            amount,
+            token_program: Pubkey::default(),
+            amm_authority: Pubkey::default(),
+            amm_open_orders: Pubkey::default(),
+            amm_target_orders: Pubkey::default(),
+            lp_mint_address: Pubkey::default(),
+            pool_coin_token_account: Pubkey::default(),
+            pool_pc_token_account: Pubkey::default(),
+            pool_withdraw_queue: Pubkey::default(),
+            pool_temp_lp_token_account: Pubkey::default(),
+            serum_program: Pubkey::default(),
+            serum_market: Pubkey::default(),
+            serum_bids: Pubkey::default(),
+            serum_asks: Pubkey::default(),
+            serum_event_queue: Pubkey::default(),
+            serum_coin_vault_account: Pubkey::default(),
+            serum_pc_vault_account: Pubkey::default(),
+            serum_vault_signer: Pubkey::default(),
+            user_lp_token_account: Pubkey::default(),
+            user_coin_token_account: Pubkey::default(),
+            user_pc_token_account: Pubkey::default(),
+            user_owner: Pubkey::default(),
+        }))
     }
 }
 
@@ -579,28 +575,26 @@ pub mod orca {
     #[cfg(feature = "parse-zero-copy")]
     #[inline(always)]
     fn parse_swap_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-        unsafe {
-            if !check_length(data, 32 + 8 + 8 + 1) {
-                return None;
-            }
-            let whirlpool = read_pubkey_unchecked(data, 0);
-            let input_amount = read_u64_unchecked(data, 32);
-            let output_amount = read_u64_unchecked(data, 40);
-            let a_to_b = read_bool_unchecked(data, 48);
-            Some(DexEvent::OrcaWhirlpoolSwap(OrcaWhirlpoolSwapEvent {
-                metadata,
-                whirlpool,
-                input_amount,
-                output_amount,
-                a_to_b,
-                pre_sqrt_price: 0,
-                post_sqrt_price: 0,
-                input_transfer_fee: 0,
-                output_transfer_fee: 0,
-                lp_fee: 0,
-                protocol_fee: 0,
-            }))
+        if !check_length(data, 32 + 8 + 8 + 1) {
+            return None;
         }
+        let whirlpool = zc_read!(pubkey, data, 0);
+        let input_amount = zc_read!(u64, data, 32);
+        let output_amount = zc_read!(u64, data, 40);
+        let a_to_b = zc_read!(bool, data, 48);
+        Some(DexEvent::OrcaWhirlpoolSwap(OrcaWhirlpoolSwapEvent {
+            metadata,
+            whirlpool,
+            input_amount,
+            output_amount,
+            a_to_b,
+            pre_sqrt_price: 0,
+            post_sqrt_price: 0,
+            input_transfer_fee: 0,
+            output_transfer_fee: 0,
+            lp_fee: 0,
+            protocol_fee: 0,
+        }))
     }
 
     // ============================================================================
@@ -646,27 +640,25 @@ pub mod orca {
         data: &[u8],
         metadata: EventMetadata,
     ) -> Option<DexEvent> {
-        unsafe {
-            if !check_length(data, 32 + 16 + 8 + 8) {
-                return None;
-            }
-            let whirlpool = read_pubkey_unchecked(data, 0);
-            let liquidity = read_u128_unchecked(data, 32);
-            let token_a_amount = read_u64_unchecked(data, 48);
-            let token_b_amount = read_u64_unchecked(data, 56);
-            Some(DexEvent::OrcaWhirlpoolLiquidityIncreased(OrcaWhirlpoolLiquidityIncreasedEvent {
-                metadata,
-                whirlpool,
-                liquidity,
-                token_a_amount,
-                token_b_amount,
-                position: Pubkey::default(),
-                tick_lower_index: 0,
-                tick_upper_index: 0,
-                token_a_transfer_fee: 0,
-                token_b_transfer_fee: 0,
-            }))
+        if !check_length(data, 32 + 16 + 8 + 8) {
+            return None;
         }
+        let whirlpool = zc_read!(pubkey, data, 0);
+        let liquidity = zc_read!(u128, data, 32);
+        let token_a_amount = zc_read!(u64, data, 48);
+        let token_b_amount = zc_read!(u64, data, 56);
+        Some(DexEvent::OrcaWhirlpoolLiquidityIncreased(OrcaWhirlpoolLiquidityIncreasedEvent {
+            metadata,
+            whirlpool,
+            liquidity,
+            token_a_amount,
+            token_b_amount,
+            position: Pubkey::default(),
+            tick_lower_index: 0,
+            tick_upper_index: 0,
+            token_a_transfer_fee: 0,
+            token_b_transfer_fee: 0,
+        }))
     }
 
     // ============================================================================
@@ -712,27 +704,25 @@ pub mod orca {
         data: &[u8],
         metadata: EventMetadata,
     ) -> Option<DexEvent> {
-        unsafe {
-            if !check_length(data, 32 + 16 + 8 + 8) {
-                return None;
-            }
-            let whirlpool = read_pubkey_unchecked(data, 0);
-            let liquidity = read_u128_unchecked(data, 32);
-            let token_a_amount = read_u64_unchecked(data, 48);
-            let token_b_amount = read_u64_unchecked(data, 56);
-            Some(DexEvent::OrcaWhirlpoolLiquidityDecreased(OrcaWhirlpoolLiquidityDecreasedEvent {
-                metadata,
-                whirlpool,
-                liquidity,
-                token_a_amount,
-                token_b_amount,
-                position: Pubkey::default(),
-                tick_lower_index: 0,
-                tick_upper_index: 0,
-                token_a_transfer_fee: 0,
-                token_b_transfer_fee: 0,
-            }))
+        if !check_length(data, 32 + 16 + 8 + 8) {
+            return None;
         }
+        let whirlpool = zc_read!(pubkey, data, 0);
+        let liquidity = zc_read!(u128, data, 32);
+        let token_a_amount = zc_read!(u64, data, 48);
+        let token_b_amount = zc_read!(u64, data, 56);
+        Some(DexEvent::OrcaWhirlpoolLiquidityDecreased(OrcaWhirlpoolLiquidityDecreasedEvent {
+            metadata,
+            whirlpool,
+            liquidity,
+            token_a_amount,
+            token_b_amount,
+            position: Pubkey::default(),
+            tick_lower_index: 0,
+            tick_upper_index: 0,
+            token_a_transfer_fee: 0,
+            token_b_transfer_fee: 0,
+        }))
     }
 }
 
@@ -750,59 +740,100 @@ pub mod meteora_amm {
             [31, 94, 125, 90, 227, 52, 61, 186, 155, 167, 108, 32, 122, 76, 173, 64];
         pub const REMOVE_LIQUIDITY: [u8; 16] =
             [116, 244, 97, 232, 103, 31, 152, 58, 155, 167, 108, 32, 122, 76, 173, 64];
+        pub const BOOTSTRAP_LIQUIDITY: [u8; 16] =
+            [121, 127, 38, 136, 92, 55, 14, 247, 155, 167, 108, 32, 122, 76, 173, 64];
         pub const POOL_CREATED: [u8; 16] =
             [202, 44, 41, 88, 104, 220, 157, 82, 155, 167, 108, 32, 122, 76, 173, 64];
+        pub const SET_POOL_FEES: [u8; 16] =
+            [245, 26, 198, 164, 88, 18, 75, 9, 155, 167, 108, 32, 122, 76, 173, 64];
     }
 
     #[inline]
     pub fn parse(disc: &[u8; 16], data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-        unsafe {
-            match disc {
-                &discriminators::SWAP => {
-                    if !check_length(data, 8 + 8) {
-                        return None;
-                    }
-                    let in_amount = read_u64_unchecked(data, 0);
-                    let out_amount = read_u64_unchecked(data, 8);
-                    Some(DexEvent::MeteoraPoolsSwap(MeteoraPoolsSwapEvent {
-                        metadata,
-                        in_amount,
-                        out_amount,
-                        trade_fee: 0,
-                        admin_fee: 0,
-                        host_fee: 0,
-                    }))
+        match disc {
+            &discriminators::SWAP => {
+                if !check_length(data, 8 * 5) {
+                    return None;
+                }
+                Some(DexEvent::MeteoraPoolsSwap(MeteoraPoolsSwapEvent {
+                    metadata,
+                    in_amount: zc_read!(u64, data, 0),
+                    out_amount: zc_read!(u64, data, 8),
+                    trade_fee: zc_read!(u64, data, 16),
+                    admin_fee: zc_read!(u64, data, 24),
+                    host_fee: zc_read!(u64, data, 32),
+                    pool: Pubkey::default(),
+                }))
+            }
+            &discriminators::ADD_LIQUIDITY => {
+                if !check_length(data, 8 + 8 + 8) {
+                    return None;
+                }
+                let lp_mint_amount = zc_read!(u64, data, 0);
+                let token_a_amount = zc_read!(u64, data, 8);
+                let token_b_amount = zc_read!(u64, data, 16);
+                Some(DexEvent::MeteoraPoolsAddLiquidity(MeteoraPoolsAddLiquidityEvent {
+                    metadata,
+                    lp_mint_amount,
+                    token_a_amount,
+                    token_b_amount,
+                    pool: Pubkey::default(),
+                }))
+            }
+            &discriminators::REMOVE_LIQUIDITY => {
+                if !check_length(data, 8 + 8 + 8) {
+                    return None;
+                }
+                let lp_unmint_amount = zc_read!(u64, data, 0);
+                let token_a_out_amount = zc_read!(u64, data, 8);
+                let token_b_out_amount = zc_read!(u64, data, 16);
+                Some(DexEvent::MeteoraPoolsRemoveLiquidity(MeteoraPoolsRemoveLiquidityEvent {
+                    metadata,
+                    lp_unmint_amount,
+                    token_a_out_amount,
+                    token_b_out_amount,
+                    pool: Pubkey::default(),
+                }))
+            }
+            &discriminators::BOOTSTRAP_LIQUIDITY => {
+                if !check_length(data, 8 * 3 + 32) {
+                    return None;
                 }
-                &discriminators::ADD_LIQUIDITY => {
-                    if !check_length(data, 8 + 8 + 8) {
-                        return None;
-                    }
-                    let lp_mint_amount = read_u64_unchecked(data, 0);
-                    let token_a_amount = read_u64_unchecked(data, 8);
-                    let token_b_amount = read_u64_unchecked(data, 16);
-                    Some(DexEvent::MeteoraPoolsAddLiquidity(MeteoraPoolsAddLiquidityEvent {
-                        metadata,
-                        lp_mint_amount,
-                        token_a_amount,
-                        token_b_amount,
-                    }))
+                Some(DexEvent::MeteoraPoolsBootstrapLiquidity(MeteoraPoolsBootstrapLiquidityEvent {
+                    metadata,
+                    lp_mint_amount: zc_read!(u64, data, 0),
+                    token_a_amount: zc_read!(u64, data, 8),
+                    token_b_amount: zc_read!(u64, data, 16),
+                    pool: zc_read!(pubkey, data, 24),
+                }))
+            }
+            &discriminators::POOL_CREATED => {
+                if !check_length(data, 32 * 3 + 1 + 32) {
+                    return None;
                 }
-                &discriminators::REMOVE_LIQUIDITY => {
-                    if !check_length(data, 8 + 8 + 8) {
-                        return None;
-                    }
-                    let lp_unmint_amount = read_u64_unchecked(data, 0);
-                    let token_a_out_amount = read_u64_unchecked(data, 8);
-                    let token_b_out_amount = read_u64_unchecked(data, 16);
-                    Some(DexEvent::MeteoraPoolsRemoveLiquidity(MeteoraPoolsRemoveLiquidityEvent {
-                        metadata,
-                        lp_unmint_amount,
-                        token_a_out_amount,
-                        token_b_out_amount,
-                    }))
+                Some(DexEvent::MeteoraPoolsPoolCreated(MeteoraPoolsPoolCreatedEvent {
+                    metadata,
+                    lp_mint: zc_read!(pubkey, data, 0),
+                    token_a_mint: zc_read!(pubkey, data, 32),
+                    token_b_mint: zc_read!(pubkey, data, 64),
+                    pool_type: zc_read!(u8, data, 96),
+                    pool: zc_read!(pubkey, data, 97),
+                }))
+            }
+            &discriminators::SET_POOL_FEES => {
+                if !check_length(data, 8 * 4 + 32) {
+                    return None;
                 }
-                _ => None,
+                Some(DexEvent::MeteoraPoolsSetPoolFees(MeteoraPoolsSetPoolFeesEvent {
+                    metadata,
+                    trade_fee_numerator: zc_read!(u64, data, 0),
+                    trade_fee_denominator: zc_read!(u64, data, 8),
+                    owner_trade_fee_numerator: zc_read!(u64, data, 16),
+                    owner_trade_fee_denominator: zc_read!(u64, data, 24),
+                    pool: zc_read!(pubkey, data, 32),
+                }))
             }
+            _ => None,
         }
     }
 }
@@ -893,21 +924,19 @@ pub mod meteora_damm {
     #[cfg(feature = "parse-zero-copy")]
     #[inline(always)]
     fn parse_swap_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-        unsafe {
-            if !check_length(data, 32 + 8 + 8) {
-                return None;
-            }
-            let pool = read_pubkey_unchecked(data, 0);
-            let amount_in = read_u64_unchecked(data, 32);
-            let output_amount = read_u64_unchecked(data, 40);
-            Some(DexEvent::MeteoraDammV2Swap(MeteoraDammV2SwapEvent {
-                metadata,
-                pool,
-                amount_in,
-                output_amount,
-                ..Default::default()
-            }))
+        if !check_length(data, 32 + 8 + 8) {
+            return None;
         }
+        let pool = zc_read!(pubkey, data, 0);
+        let amount_in = zc_read!(u64, data, 32);
+        let output_amount = zc_read!(u64, data, 40);
+        Some(DexEvent::MeteoraDammV2Swap(MeteoraDammV2SwapEvent {
+            metadata,
+            pool,
+            amount_in,
+            output_amount,
+            ..Default::default()
+        }))
     }
 
     // ============================================================================
@@ -947,83 +976,81 @@ pub mod meteora_damm {
 
         let mut offset = 0;
 
-        // 使用 unsafe 读取以提高性能
-        unsafe {
-            let pool = read_pubkey_unchecked(data, offset);
-            offset += 32;
+        // 零拷贝读取（parse-zero-copy-safe 下为边界检查读取）
+        let pool = zc_read!(pubkey, data, offset);
+        offset += 32;
 
-            let _config = read_pubkey_unchecked(data, offset);
-            offset += 32;
+        let _config = zc_read!(pubkey, data, offset);
+        offset += 32;
 
-            let trade_direction = read_u8_unchecked(data, offset);
-            offset += 1;
+        let trade_direction = zc_read!(u8, data, offset);
+        offset += 1;
 
-            let has_referral = read_bool_unchecked(data, offset);
-            offset += 1;
+        let has_referral = zc_read!(bool, data, offset);
+        offset += 1;
 
-            let amount_0 = read_u64_unchecked(data, offset);
-            offset += 8;
+        let amount_0 = zc_read!(u64, data, offset);
+        offset += 8;
 
-            let amount_1 = read_u64_unchecked(data, offset);
-            offset += 8;
+        let amount_1 = zc_read!(u64, data, offset);
+        offset += 8;
 
-            let swap_mode = read_u8_unchecked(data, offset);
-            offset += 1;
+        let swap_mode = zc_read!(u8, data, offset);
+        offset += 1;
 
-            let included_fee_input_amount = read_u64_unchecked(data, offset);
-            offset += 8;
+        let included_fee_input_amount = zc_read!(u64, data, offset);
+        offset += 8;
 
-            let _excluded_fee_input_amount = read_u64_unchecked(data, offset);
-            offset += 8;
+        let _excluded_fee_input_amount = zc_read!(u64, data, offset);
+        offset += 8;
 
-            let _amount_left = read_u64_unchecked(data, offset);
-            offset += 8;
+        let _amount_left = zc_read!(u64, data, offset);
+        offset += 8;
 
-            let output_amount = read_u64_unchecked(data, offset);
-            offset += 8;
+        let output_amount = zc_read!(u64, data, offset);
+        offset += 8;
 
-            let next_sqrt_price = read_u128_unchecked(data, offset);
-            offset += 16;
+        let next_sqrt_price = zc_read!(u128, data, offset);
+        offset += 16;
 
-            let lp_fee = read_u64_unchecked(data, offset);
-            offset += 8;
+        let lp_fee = zc_read!(u64, data, offset);
+        offset += 8;
 
-            let protocol_fee = read_u64_unchecked(data, offset);
-            offset += 8;
+        let protocol_fee = zc_read!(u64, data, offset);
+        offset += 8;
 
-            let referral_fee = read_u64_unchecked(data, offset);
-            offset += 8;
+        let referral_fee = zc_read!(u64, data, offset);
+        offset += 8;
 
-            let _quote_reserve_amount = read_u64_unchecked(data, offset);
-            offset += 8;
+        let _quote_reserve_amount = zc_read!(u64, data, offset);
+        offset += 8;
 
-            let _migration_threshold = read_u64_unchecked(data, offset);
-            offset += 8;
+        let _migration_threshold = zc_read!(u64, data, offset);
+        offset += 8;
 
-            let current_timestamp = read_u64_unchecked(data, offset);
+        let current_timestamp = zc_read!(u64, data, offset);
 
-            // 根据 swap_mode 确定 amount_in 和 minimum_amount_out
-            let (amount_in, minimum_amount_out) =
-                if swap_mode == 0 { (amount_0, amount_1) } else { (amount_1, amount_0) };
+        // 根据 swap_mode 确定 amount_in 和 minimum_amount_out
+        let (amount_in, minimum_amount_out) =
+            if swap_mode == 0 { (amount_0, amount_1) } else { (amount_1, amount_0) };
 
-            Some(DexEvent::MeteoraDammV2Swap(MeteoraDammV2SwapEvent {
-                metadata,
-                pool,
-                trade_direction,
-                has_referral,
-                amount_in,
-                minimum_amount_out,
-                output_amount,
-                next_sqrt_price,
-                lp_fee,
-                protocol_fee,
-                partner_fee: 0,
-                referral_fee,
-                actual_amount_in: included_fee_input_amount,
-                current_timestamp,
-                ..Default::default()
-            }))
-        }
+        Some(DexEvent::MeteoraDammV2Swap(MeteoraDammV2SwapEvent {
+            metadata,
+            pool,
+            trade_direction,
+            has_referral,
+            amount_in,
+            minimum_amount_out,
+            output_amount,
+            next_sqrt_price,
+            lp_fee,
+            protocol_fee,
+            partner_fee: 0,
+            referral_fee,
+            actual_amount_in: included_fee_input_amount,
+            current_timestamp,
+            ..Default::default()
+        }))
     }
 
     /// 零拷贝解析器 for Swap2
@@ -1039,47 +1066,45 @@ pub mod meteora_damm {
         // quote_reserve_amount(8) + migration_threshold(8) + current_timestamp(8)
         const SWAP2_EVENT_MIN_SIZE: usize = 177;
 
-        unsafe {
-            if !check_length(data, SWAP2_EVENT_MIN_SIZE) {
-                return None;
-            }
-
-            let pool = read_pubkey_unchecked(data, 0);
-            let trade_direction = read_u8_unchecked(data, 64);
-            let has_referral = read_bool_unchecked(data, 65);
-            let amount_0 = read_u64_unchecked(data, 66);
-            let amount_1 = read_u64_unchecked(data, 74);
-            let swap_mode = read_u8_unchecked(data, 82);
-            let included_fee_input_amount = read_u64_unchecked(data, 83);
-            let output_amount = read_u64_unchecked(data, 107);
-            let next_sqrt_price = read_u128_unchecked(data, 115);
-            let lp_fee = read_u64_unchecked(data, 131);
-            let protocol_fee = read_u64_unchecked(data, 139);
-            let referral_fee = read_u64_unchecked(data, 147);
-            let current_timestamp = read_u64_unchecked(data, 169);
-
-            // 根据 swap_mode 确定 amount_in 和 minimum_amount_out
-            let (amount_in, minimum_amount_out) =
-                if swap_mode == 0 { (amount_0, amount_1) } else { (amount_1, amount_0) };
-
-            Some(DexEvent::MeteoraDammV2Swap(MeteoraDammV2SwapEvent {
-                metadata,
-                pool,
-                trade_direction,
-                has_referral,
-                amount_in,
-                minimum_amount_out,
-                output_amount,
-                next_sqrt_price,
-                lp_fee,
-                protocol_fee,
-                partner_fee: 0,
-                referral_fee,
-                actual_amount_in: included_fee_input_amount,
-                current_timestamp,
-                ..Default::default()
-            }))
+        if !check_length(data, SWAP2_EVENT_MIN_SIZE) {
+            return None;
         }
+
+        let pool = zc_read!(pubkey, data, 0);
+        let trade_direction = zc_read!(u8, data, 64);
+        let has_referral = zc_read!(bool, data, 65);
+        let amount_0 = zc_read!(u64, data, 66);
+        let amount_1 = zc_read!(u64, data, 74);
+        let swap_mode = zc_read!(u8, data, 82);
+        let included_fee_input_amount = zc_read!(u64, data, 83);
+        let output_amount = zc_read!(u64, data, 107);
+        let next_sqrt_price = zc_read!(u128, data, 115);
+        let lp_fee = zc_read!(u64, data, 131);
+        let protocol_fee = zc_read!(u64, data, 139);
+        let referral_fee = zc_read!(u64, data, 147);
+        let current_timestamp = zc_read!(u64, data, 169);
+
+        // 根据 swap_mode 确定 amount_in 和 minimum_amount_out
+        let (amount_in, minimum_amount_out) =
+            if swap_mode == 0 { (amount_0, amount_1) } else { (amount_1, amount_0) };
+
+        Some(DexEvent::MeteoraDammV2Swap(MeteoraDammV2SwapEvent {
+            metadata,
+            pool,
+            trade_direction,
+            has_referral,
+            amount_in,
+            minimum_amount_out,
+            output_amount,
+            next_sqrt_price,
+            lp_fee,
+            protocol_fee,
+            partner_fee: 0,
+            referral_fee,
+            actual_amount_in: included_fee_input_amount,
+            current_timestamp,
+            ..Default::default()
+        }))
     }
 
     // ============================================================================
@@ -1123,29 +1148,27 @@ pub mod meteora_damm {
     #[cfg(feature = "parse-zero-copy")]
     #[inline(always)]
     fn parse_add_liquidity_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-        unsafe {
-            if !check_length(data, 32 + 32 + 32 + 8 + 8) {
-                return None;
-            }
-            let pool = read_pubkey_unchecked(data, 0);
-            let position = read_pubkey_unchecked(data, 32);
-            let owner = read_pubkey_unchecked(data, 64);
-            let token_a_amount = read_u64_unchecked(data, 96);
-            let token_b_amount = read_u64_unchecked(data, 104);
-            Some(DexEvent::MeteoraDammV2AddLiquidity(MeteoraDammV2AddLiquidityEvent {
-                metadata,
-                pool,
-                position,
-                owner,
-                token_a_amount,
-                token_b_amount,
-                liquidity_delta: 0,
-                token_a_amount_threshold: 0,
-                token_b_amount_threshold: 0,
-                total_amount_a: 0,
-                total_amount_b: 0,
-            }))
+        if !check_length(data, 32 + 32 + 32 + 8 + 8) {
+            return None;
         }
+        let pool = zc_read!(pubkey, data, 0);
+        let position = zc_read!(pubkey, data, 32);
+        let owner = zc_read!(pubkey, data, 64);
+        let token_a_amount = zc_read!(u64, data, 96);
+        let token_b_amount = zc_read!(u64, data, 104);
+        Some(DexEvent::MeteoraDammV2AddLiquidity(MeteoraDammV2AddLiquidityEvent {
+            metadata,
+            pool,
+            position,
+            owner,
+            token_a_amount,
+            token_b_amount,
+            liquidity_delta: 0,
+            token_a_amount_threshold: 0,
+            token_b_amount_threshold: 0,
+            total_amount_a: 0,
+            total_amount_b: 0,
+        }))
     }
 
     // ============================================================================
@@ -1190,27 +1213,25 @@ pub mod meteora_damm {
     #[cfg(feature = "parse-zero-copy")]
     #[inline(always)]
     fn parse_remove_liquidity_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-        unsafe {
-            if !check_length(data, 32 + 32 + 32 + 8 + 8) {
-                return None;
-            }
-            let pool = read_pubkey_unchecked(data, 0);
-            let position = read_pubkey_unchecked(data, 32);
-            let owner = read_pubkey_unchecked(data, 64);
-            let token_a_amount = read_u64_unchecked(data, 96);
-            let token_b_amount = read_u64_unchecked(data, 104);
-            Some(DexEvent::MeteoraDammV2RemoveLiquidity(MeteoraDammV2RemoveLiquidityEvent {
-                metadata,
-                pool,
-                position,
-                owner,
-                token_a_amount,
-                token_b_amount,
-                liquidity_delta: 0,
-                token_a_amount_threshold: 0,
-                token_b_amount_threshold: 0,
-            }))
+        if !check_length(data, 32 + 32 + 32 + 8 + 8) {
+            return None;
         }
+        let pool = zc_read!(pubkey, data, 0);
+        let position = zc_read!(pubkey, data, 32);
+        let owner = zc_read!(pubkey, data, 64);
+        let token_a_amount = zc_read!(u64, data, 96);
+        let token_b_amount = zc_read!(u64, data, 104);
+        Some(DexEvent::MeteoraDammV2RemoveLiquidity(MeteoraDammV2RemoveLiquidityEvent {
+            metadata,
+            pool,
+            position,
+            owner,
+            token_a_amount,
+            token_b_amount,
+            liquidity_delta: 0,
+            token_a_amount_threshold: 0,
+            token_b_amount_threshold: 0,
+        }))
     }
 
     // ============================================================================
@@ -1255,22 +1276,20 @@ pub mod meteora_damm {
     #[cfg(feature = "parse-zero-copy")]
     #[inline(always)]
     fn parse_create_position_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-        unsafe {
-            if !check_length(data, 32 + 32 + 32 + 32) {
-                return None;
-            }
-            let pool = read_pubkey_unchecked(data, 0);
-            let owner = read_pubkey_unchecked(data, 32);
-            let position = read_pubkey_unchecked(data, 64);
-            let position_nft_mint = read_pubkey_unchecked(data, 96);
-            Some(DexEvent::MeteoraDammV2CreatePosition(MeteoraDammV2CreatePositionEvent {
-                metadata,
-                pool,
-                owner,
-                position,
-                position_nft_mint,
-            }))
+        if !check_length(data, 32 + 32 + 32 + 32) {
+            return None;
         }
+        let pool = zc_read!(pubkey, data, 0);
+        let owner = zc_read!(pubkey, data, 32);
+        let position = zc_read!(pubkey, data, 64);
+        let position_nft_mint = zc_read!(pubkey, data, 96);
+        Some(DexEvent::MeteoraDammV2CreatePosition(MeteoraDammV2CreatePositionEvent {
+            metadata,
+            pool,
+            owner,
+            position,
+            position_nft_mint,
+        }))
     }
 
     // ============================================================================
@@ -1315,22 +1334,20 @@ pub mod meteora_damm {
     #[cfg(feature = "parse-zero-copy")]
     #[inline(always)]
     fn parse_close_position_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-        unsafe {
-            if !check_length(data, 32 + 32 + 32 + 32) {
-                return None;
-            }
-            let pool = read_pubkey_unchecked(data, 0);
-            let owner = read_pubkey_unchecked(data, 32);
-            let position = read_pubkey_unchecked(data, 64);
-            let position_nft_mint = read_pubkey_unchecked(data, 96);
-            Some(DexEvent::MeteoraDammV2ClosePosition(MeteoraDammV2ClosePositionEvent {
-                metadata,
-                pool,
-                owner,
-                position,
-                position_nft_mint,
-            }))
+        if !check_length(data, 32 + 32 + 32 + 32) {
+            return None;
         }
+        let pool = zc_read!(pubkey, data, 0);
+        let owner = zc_read!(pubkey, data, 32);
+        let position = zc_read!(pubkey, data, 64);
+        let position_nft_mint = zc_read!(pubkey, data, 96);
+        Some(DexEvent::MeteoraDammV2ClosePosition(MeteoraDammV2ClosePositionEvent {
+            metadata,
+            pool,
+            owner,
+            position,
+            position_nft_mint,
+        }))
     }
 }
 
@@ -1341,6 +1358,8 @@ pub mod meteora_damm {
 pub mod bonk {
     //! Bonk (Raydium Launchpad) Inner Instruction 解析器
     //!
+    //! Discriminator 与字段布局来自 `idls/raydium_launchpad.json`（LaunchLab `TradeEvent` / `PoolCreateEvent`）。
+    //!
     //! ## 解析器插件系统
     //!
     //! 支持两种可插拔的解析器实现：
@@ -1357,18 +1376,17 @@ pub mod bonk {
 
     pub mod discriminators {
         pub const POOL_CREATE: [u8; 16] =
-            [100, 50, 200, 150, 75, 120, 90, 30, 155, 167, 108, 32, 122, 76, 173, 64];
+            [151, 215, 226, 9, 118, 161, 115, 174, 155, 167, 108, 32, 122, 76, 173, 64];
         pub const TRADE: [u8; 16] =
-            [80, 120, 100, 200, 150, 75, 60, 40, 155, 167, 108, 32, 122, 76, 173, 64];
-        pub const MIGRATE: [u8; 16] =
-            [90, 130, 110, 210, 160, 85, 70, 50, 155, 167, 108, 32, 122, 76, 173, 64];
+            [189, 219, 127, 211, 78, 230, 97, 238, 155, 167, 108, 32, 122, 76, 173, 64];
     }
 
     /// 主入口：根据 discriminator 解析事件
     #[inline]
     pub fn parse(disc: &[u8; 16], data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-        match disc {
-            &discriminators::TRADE => parse_trade(data, metadata),
+        match *disc {
+            discriminators::TRADE => parse_trade(data, metadata),
+            discriminators::POOL_CREATE => parse_pool_create(data, metadata),
             _ => None,
         }
     }
@@ -1395,17 +1413,14 @@ pub mod bonk {
     #[cfg(feature = "parse-borsh")]
     #[inline(always)]
     fn parse_trade_borsh(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-        // 数据结构：pool_state(32) + user(32) + amount_in(8) + amount_out(8) + is_buy(1) = 81 bytes
-        const TRADE_EVENT_SIZE: usize = 32 + 32 + 8 + 8 + 1;
+        const TRADE_EVENT_SIZE: usize = BonkTradeEvent::TRADE_EVENT_SIZE;
         if data.len() < TRADE_EVENT_SIZE {
             return None;
         }
 
         let mut event = borsh::from_slice::<BonkTradeEvent>(&data[..TRADE_EVENT_SIZE]).ok()?;
         event.metadata = metadata;
-        event.trade_direction =
-            if event.is_buy { TradeDirection::Buy } else { TradeDirection::Sell };
-        event.exact_in = true;
+        event.is_buy = event.trade_direction == TradeDirection::Buy;
         Some(DexEvent::BonkTrade(event))
     }
 
@@ -1413,26 +1428,119 @@ pub mod bonk {
     #[cfg(feature = "parse-zero-copy")]
     #[inline(always)]
     fn parse_trade_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-        unsafe {
-            if !check_length(data, 32 + 32 + 8 + 8 + 1) {
-                return None;
-            }
-            let pool_state = read_pubkey_unchecked(data, 0);
-            let user = read_pubkey_unchecked(data, 32);
-            let amount_in = read_u64_unchecked(data, 64);
-            let amount_out = read_u64_unchecked(data, 72);
-            let is_buy = read_bool_unchecked(data, 80);
-            Some(DexEvent::BonkTrade(BonkTradeEvent {
-                metadata,
-                pool_state,
-                user,
-                amount_in,
-                amount_out,
-                is_buy,
-                trade_direction: if is_buy { TradeDirection::Buy } else { TradeDirection::Sell },
-                exact_in: true,
-            }))
+        if !check_length(data, BonkTradeEvent::TRADE_EVENT_SIZE) {
+            return None;
         }
+        let trade_direction = TradeDirection::from_u8(zc_read!(u8, data, 136))?;
+        let pool_status = PoolStatus::from_u8(zc_read!(u8, data, 137))?;
+        Some(DexEvent::BonkTrade(BonkTradeEvent {
+            metadata,
+            pool_state: zc_read!(pubkey, data, 0),
+            total_base_sell: zc_read!(u64, data, 32),
+            virtual_base: zc_read!(u64, data, 40),
+            virtual_quote: zc_read!(u64, data, 48),
+            real_base_before: zc_read!(u64, data, 56),
+            real_quote_before: zc_read!(u64, data, 64),
+            real_base_after: zc_read!(u64, data, 72),
+            real_quote_after: zc_read!(u64, data, 80),
+            amount_in: zc_read!(u64, data, 88),
+            amount_out: zc_read!(u64, data, 96),
+            protocol_fee: zc_read!(u64, data, 104),
+            platform_fee: zc_read!(u64, data, 112),
+            creator_fee: zc_read!(u64, data, 120),
+            share_fee: zc_read!(u64, data, 128),
+            trade_direction,
+            pool_status,
+            exact_in: zc_read!(bool, data, 138),
+            is_buy: trade_direction == TradeDirection::Buy,
+            user: Pubkey::default(),
+        }))
+    }
+
+    // ============================================================================
+    // PoolCreate Event
+    // ============================================================================
+
+    /// 解析 PoolCreate 事件（统一入口）
+    ///
+    /// 只解析到 `base_mint_param`，后续的 curve/vesting 参数不影响事件字段。
+    #[inline(always)]
+    fn parse_pool_create(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+        #[cfg(feature = "parse-borsh")]
+        {
+            parse_pool_create_borsh(data, metadata)
+        }
+
+        #[cfg(feature = "parse-zero-copy")]
+        {
+            parse_pool_create_zero_copy(data, metadata)
+        }
+    }
+
+    /// PoolCreateEvent 前缀布局（不要求消费全部数据）
+    #[cfg(feature = "parse-borsh")]
+    #[derive(BorshDeserialize)]
+    struct PoolCreateHead {
+        pool_state: Pubkey,
+        creator: Pubkey,
+        config: Pubkey,
+        decimals: u8,
+        name: String,
+        symbol: String,
+        uri: String,
+    }
+
+    /// Borsh 解析器
+    #[cfg(feature = "parse-borsh")]
+    #[inline(always)]
+    fn parse_pool_create_borsh(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+        let head = PoolCreateHead::deserialize(&mut &data[..]).ok()?;
+        Some(DexEvent::BonkPoolCreate(BonkPoolCreateEvent {
+            metadata,
+            base_mint_param: BaseMintParam {
+                symbol: head.symbol,
+                name: head.name,
+                uri: head.uri,
+                decimals: head.decimals,
+            },
+            pool_state: head.pool_state,
+            creator: head.creator,
+            config: head.config,
+            base_mint: Pubkey::default(),
+            quote_mint: Pubkey::default(),
+        }))
+    }
+
+    /// 零拷贝解析器
+    #[cfg(feature = "parse-zero-copy")]
+    #[inline(always)]
+    fn parse_pool_create_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+        if !check_length(data, 32 * 3 + 1) {
+            return None;
+        }
+        let pool_state = zc_read!(pubkey, data, 0);
+        let creator = zc_read!(pubkey, data, 32);
+        let config = zc_read!(pubkey, data, 64);
+        let decimals = zc_read!(u8, data, 96);
+        let mut offset = 97;
+
+        let (name, name_len) = zc_read!(string, data, offset)?;
+        offset += name_len;
+
+        let (symbol, symbol_len) = zc_read!(string, data, offset)?;
+        offset += symbol_len;
+
+        let (uri, _) = zc_read!(string, data, offset)?;
+
+        Some(DexEvent::BonkPoolCreate(BonkPoolCreateEvent {
+            metadata,
+            base_mint_param: BaseMintParam { symbol, name, uri, decimals },
+            pool_state,
+            creator,
+            config,
+            base_mint: Pubkey::default(),
+            quote_mint: Pubkey::default(),
+        }))
     }
 }
 
@@ -1466,7 +1574,7 @@ pub mod meteora_dlmm {
         pub const REMOVE_LIQUIDITY: [u8; 16] =
             [80, 85, 209, 72, 24, 206, 35, 178, 155, 167, 108, 32, 122, 76, 173, 64];
         pub const INITIALIZE_POOL: [u8; 16] =
-            [95, 180, 10, 172, 84, 174, 232, 40, 155, 167, 108, 32, 122, 76, 173, 64];
+            [185, 74, 252, 125, 27, 215, 188, 111, 155, 167, 108, 32, 122, 76, 173, 64];
         pub const INITIALIZE_BIN_ARRAY: [u8; 16] =
             [11, 18, 155, 194, 33, 115, 238, 119, 155, 167, 108, 32, 122, 76, 173, 64];
         pub const CREATE_POSITION: [u8; 16] =
@@ -1475,6 +1583,12 @@ pub mod meteora_dlmm {
             [94, 168, 102, 45, 59, 122, 137, 54, 155, 167, 108, 32, 122, 76, 173, 64];
         pub const CLAIM_FEE: [u8; 16] =
             [152, 70, 208, 111, 104, 91, 44, 1, 155, 167, 108, 32, 122, 76, 173, 64];
+        pub const CLAIM_REWARD: [u8; 16] =
+            [148, 116, 134, 204, 22, 171, 85, 95, 155, 167, 108, 32, 122, 76, 173, 64];
+        pub const FUND_REWARD: [u8; 16] =
+            [246, 228, 58, 130, 145, 170, 79, 204, 155, 167, 108, 32, 122, 76, 173, 64];
+        pub const UPDATE_POSITION_OPERATOR: [u8; 16] =
+            [39, 115, 48, 204, 246, 47, 66, 57, 155, 167, 108, 32, 122, 76, 173, 64];
     }
 
     /// 主入口：根据 discriminator 解析事件
@@ -1489,6 +1603,11 @@ pub mod meteora_dlmm {
             &discriminators::CREATE_POSITION => parse_create_position(data, metadata),
             &discriminators::CLOSE_POSITION => parse_close_position(data, metadata),
             &discriminators::CLAIM_FEE => parse_claim_fee(data, metadata),
+            &discriminators::CLAIM_REWARD => parse_claim_reward(data, metadata),
+            &discriminators::FUND_REWARD => parse_fund_reward(data, metadata),
+            &discriminators::UPDATE_POSITION_OPERATOR => {
+                parse_update_position_operator(data, metadata)
+            }
             _ => None,
         }
     }
@@ -1530,36 +1649,34 @@ pub mod meteora_dlmm {
     #[cfg(feature = "parse-zero-copy")]
     #[inline(always)]
     fn parse_swap_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-        unsafe {
-            if !check_length(data, 32 + 32 + 4 + 4 + 8 + 8 + 1 + 8 + 8 + 16 + 8) {
-                return None;
-            }
-            let pool = read_pubkey_unchecked(data, 0);
-            let from = read_pubkey_unchecked(data, 32);
-            let start_bin_id = read_i32_unchecked(data, 64);
-            let end_bin_id = read_i32_unchecked(data, 68);
-            let amount_in = read_u64_unchecked(data, 72);
-            let amount_out = read_u64_unchecked(data, 80);
-            let swap_for_y = read_bool_unchecked(data, 88);
-            let fee = read_u64_unchecked(data, 89);
-            let protocol_fee = read_u64_unchecked(data, 97);
-            let fee_bps = read_u128_unchecked(data, 105);
-            let host_fee = read_u64_unchecked(data, 121);
-            Some(DexEvent::MeteoraDlmmSwap(MeteoraDlmmSwapEvent {
-                metadata,
-                pool,
-                from,
-                start_bin_id,
-                end_bin_id,
-                amount_in,
-                amount_out,
-                swap_for_y,
-                fee,
-                protocol_fee,
-                fee_bps,
-                host_fee,
-            }))
+        if !check_length(data, 32 + 32 + 4 + 4 + 8 + 8 + 1 + 8 + 8 + 16 + 8) {
+            return None;
         }
+        let pool = zc_read!(pubkey, data, 0);
+        let from = zc_read!(pubkey, data, 32);
+        let start_bin_id = zc_read!(i32, data, 64);
+        let end_bin_id = zc_read!(i32, data, 68);
+        let amount_in = zc_read!(u64, data, 72);
+        let amount_out = zc_read!(u64, data, 80);
+        let swap_for_y = zc_read!(bool, data, 88);
+        let fee = zc_read!(u64, data, 89);
+        let protocol_fee = zc_read!(u64, data, 97);
+        let fee_bps = zc_read!(u128, data, 105);
+        let host_fee = zc_read!(u64, data, 121);
+        Some(DexEvent::MeteoraDlmmSwap(MeteoraDlmmSwapEvent {
+            metadata,
+            pool,
+            from,
+            start_bin_id,
+            end_bin_id,
+            amount_in,
+            amount_out,
+            swap_for_y,
+            fee,
+            protocol_fee,
+            fee_bps,
+            host_fee,
+        }))
     }
 
     // ============================================================================
@@ -1601,25 +1718,23 @@ pub mod meteora_dlmm {
     #[cfg(feature = "parse-zero-copy")]
     #[inline(always)]
     fn parse_add_liquidity_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-        unsafe {
-            if !check_length(data, 32 + 32 + 32 + 16 + 4) {
-                return None;
-            }
-            let pool = read_pubkey_unchecked(data, 0);
-            let from = read_pubkey_unchecked(data, 32);
-            let position = read_pubkey_unchecked(data, 64);
-            let amount_0 = read_u64_unchecked(data, 96);
-            let amount_1 = read_u64_unchecked(data, 104);
-            let active_bin_id = read_i32_unchecked(data, 112);
-            Some(DexEvent::MeteoraDlmmAddLiquidity(MeteoraDlmmAddLiquidityEvent {
-                metadata,
-                pool,
-                from,
-                position,
-                amounts: [amount_0, amount_1],
-                active_bin_id,
-            }))
+        if !check_length(data, 32 + 32 + 32 + 16 + 4) {
+            return None;
         }
+        let pool = zc_read!(pubkey, data, 0);
+        let from = zc_read!(pubkey, data, 32);
+        let position = zc_read!(pubkey, data, 64);
+        let amount_0 = zc_read!(u64, data, 96);
+        let amount_1 = zc_read!(u64, data, 104);
+        let active_bin_id = zc_read!(i32, data, 112);
+        Some(DexEvent::MeteoraDlmmAddLiquidity(MeteoraDlmmAddLiquidityEvent {
+            metadata,
+            pool,
+            from,
+            position,
+            amounts: [amount_0, amount_1],
+            active_bin_id,
+        }))
     }
 
     // ============================================================================
@@ -1662,25 +1777,23 @@ pub mod meteora_dlmm {
     #[cfg(feature = "parse-zero-copy")]
     #[inline(always)]
     fn parse_remove_liquidity_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-        unsafe {
-            if !check_length(data, 32 + 32 + 32 + 16 + 4) {
-                return None;
-            }
-            let pool = read_pubkey_unchecked(data, 0);
-            let from = read_pubkey_unchecked(data, 32);
-            let position = read_pubkey_unchecked(data, 64);
-            let amount_0 = read_u64_unchecked(data, 96);
-            let amount_1 = read_u64_unchecked(data, 104);
-            let active_bin_id = read_i32_unchecked(data, 112);
-            Some(DexEvent::MeteoraDlmmRemoveLiquidity(MeteoraDlmmRemoveLiquidityEvent {
-                metadata,
-                pool,
-                from,
-                position,
-                amounts: [amount_0, amount_1],
-                active_bin_id,
-            }))
+        if !check_length(data, 32 + 32 + 32 + 16 + 4) {
+            return None;
         }
+        let pool = zc_read!(pubkey, data, 0);
+        let from = zc_read!(pubkey, data, 32);
+        let position = zc_read!(pubkey, data, 64);
+        let amount_0 = zc_read!(u64, data, 96);
+        let amount_1 = zc_read!(u64, data, 104);
+        let active_bin_id = zc_read!(i32, data, 112);
+        Some(DexEvent::MeteoraDlmmRemoveLiquidity(MeteoraDlmmRemoveLiquidityEvent {
+            metadata,
+            pool,
+            from,
+            position,
+            amounts: [amount_0, amount_1],
+            active_bin_id,
+        }))
     }
 
     // ============================================================================
@@ -1705,40 +1818,47 @@ pub mod meteora_dlmm {
     #[cfg(feature = "parse-borsh")]
     #[inline(always)]
     fn parse_initialize_pool_borsh(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-        // pool(32) + creator(32) + active_bin_id(4) + bin_step(2) = 70 bytes
-        const INITIALIZE_POOL_EVENT_SIZE: usize = 32 + 32 + 4 + 2;
+        // LbPairCreate: lb_pair(32) + bin_step(2) + token_x(32) + token_y(32) = 98 bytes
+        const INITIALIZE_POOL_EVENT_SIZE: usize = 32 + 2 + 32 + 32;
         if data.len() < INITIALIZE_POOL_EVENT_SIZE {
             return None;
         }
 
-        let mut event = borsh::from_slice::<MeteoraDlmmInitializePoolEvent>(
-            &data[..INITIALIZE_POOL_EVENT_SIZE],
-        )
-        .ok()?;
-        event.metadata = metadata;
-        Some(DexEvent::MeteoraDlmmInitializePool(event))
+        let (pool, bin_step, token_x_mint, token_y_mint) =
+            borsh::from_slice::<(Pubkey, u16, Pubkey, Pubkey)>(&data[..INITIALIZE_POOL_EVENT_SIZE])
+                .ok()?;
+        Some(DexEvent::MeteoraDlmmInitializePool(MeteoraDlmmInitializePoolEvent {
+            metadata,
+            pool,
+            creator: Pubkey::default(),
+            active_bin_id: 0,
+            bin_step,
+            token_x_mint,
+            token_y_mint,
+        }))
     }
 
     /// 零拷贝解析器 - Initialize Pool
     #[cfg(feature = "parse-zero-copy")]
     #[inline(always)]
     fn parse_initialize_pool_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-        unsafe {
-            if !check_length(data, 32 + 32 + 4 + 2) {
-                return None;
-            }
-            let pool = read_pubkey_unchecked(data, 0);
-            let creator = read_pubkey_unchecked(data, 32);
-            let active_bin_id = read_i32_unchecked(data, 64);
-            let bin_step = read_u16_unchecked(data, 68);
-            Some(DexEvent::MeteoraDlmmInitializePool(MeteoraDlmmInitializePoolEvent {
-                metadata,
-                pool,
-                creator,
-                active_bin_id,
-                bin_step,
-            }))
+        // LbPairCreate: lb_pair(32) + bin_step(2) + token_x(32) + token_y(32)
+        if !check_length(data, 32 + 2 + 32 + 32) {
+            return None;
         }
+        let pool = zc_read!(pubkey, data, 0);
+        let bin_step = zc_read!(u16, data, 32);
+        let token_x_mint = zc_read!(pubkey, data, 34);
+        let token_y_mint = zc_read!(pubkey, data, 66);
+        Some(DexEvent::MeteoraDlmmInitializePool(MeteoraDlmmInitializePoolEvent {
+            metadata,
+            pool,
+            creator: Pubkey::default(),
+            active_bin_id: 0,
+            bin_step,
+            token_x_mint,
+            token_y_mint,
+        }))
     }
 
     // ============================================================================
@@ -1784,20 +1904,18 @@ pub mod meteora_dlmm {
         data: &[u8],
         metadata: EventMetadata,
     ) -> Option<DexEvent> {
-        unsafe {
-            if !check_length(data, 32 + 32 + 8) {
-                return None;
-            }
-            let pool = read_pubkey_unchecked(data, 0);
-            let bin_array = read_pubkey_unchecked(data, 32);
-            let index = read_i64_unchecked(data, 64);
-            Some(DexEvent::MeteoraDlmmInitializeBinArray(MeteoraDlmmInitializeBinArrayEvent {
-                metadata,
-                pool,
-                bin_array,
-                index,
-            }))
+        if !check_length(data, 32 + 32 + 8) {
+            return None;
         }
+        let pool = zc_read!(pubkey, data, 0);
+        let bin_array = zc_read!(pubkey, data, 32);
+        let index = zc_read!(i64, data, 64);
+        Some(DexEvent::MeteoraDlmmInitializeBinArray(MeteoraDlmmInitializeBinArrayEvent {
+            metadata,
+            pool,
+            bin_array,
+            index,
+        }))
     }
 
     // ============================================================================
@@ -1840,24 +1958,22 @@ pub mod meteora_dlmm {
     #[cfg(feature = "parse-zero-copy")]
     #[inline(always)]
     fn parse_create_position_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-        unsafe {
-            if !check_length(data, 32 + 32 + 32 + 4 + 4) {
-                return None;
-            }
-            let pool = read_pubkey_unchecked(data, 0);
-            let position = read_pubkey_unchecked(data, 32);
-            let owner = read_pubkey_unchecked(data, 64);
-            let lower_bin_id = read_i32_unchecked(data, 96);
-            let width = read_u32_unchecked(data, 100);
-            Some(DexEvent::MeteoraDlmmCreatePosition(MeteoraDlmmCreatePositionEvent {
-                metadata,
-                pool,
-                position,
-                owner,
-                lower_bin_id,
-                width,
-            }))
+        if !check_length(data, 32 + 32 + 32 + 4 + 4) {
+            return None;
         }
+        let pool = zc_read!(pubkey, data, 0);
+        let position = zc_read!(pubkey, data, 32);
+        let owner = zc_read!(pubkey, data, 64);
+        let lower_bin_id = zc_read!(i32, data, 96);
+        let width = zc_read!(u32, data, 100);
+        Some(DexEvent::MeteoraDlmmCreatePosition(MeteoraDlmmCreatePositionEvent {
+            metadata,
+            pool,
+            position,
+            owner,
+            lower_bin_id,
+            width,
+        }))
     }
 
     // ============================================================================
@@ -1899,20 +2015,18 @@ pub mod meteora_dlmm {
     #[cfg(feature = "parse-zero-copy")]
     #[inline(always)]
     fn parse_close_position_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-        unsafe {
-            if !check_length(data, 32 + 32 + 32) {
-                return None;
-            }
-            let pool = read_pubkey_unchecked(data, 0);
-            let position = read_pubkey_unchecked(data, 32);
-            let owner = read_pubkey_unchecked(data, 64);
-            Some(DexEvent::MeteoraDlmmClosePosition(MeteoraDlmmClosePositionEvent {
-                metadata,
-                pool,
-                position,
-                owner,
-            }))
+        if !check_length(data, 32 + 32 + 32) {
+            return None;
         }
+        let pool = zc_read!(pubkey, data, 0);
+        let position = zc_read!(pubkey, data, 32);
+        let owner = zc_read!(pubkey, data, 64);
+        Some(DexEvent::MeteoraDlmmClosePosition(MeteoraDlmmClosePositionEvent {
+            metadata,
+            pool,
+            position,
+            owner,
+        }))
     }
 
     // ============================================================================
@@ -1953,23 +2067,195 @@ pub mod meteora_dlmm {
     #[cfg(feature = "parse-zero-copy")]
     #[inline(always)]
     fn parse_claim_fee_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-        unsafe {
-            if !check_length(data, 32 + 32 + 32 + 8 + 8) {
-                return None;
-            }
-            let pool = read_pubkey_unchecked(data, 0);
-            let position = read_pubkey_unchecked(data, 32);
-            let owner = read_pubkey_unchecked(data, 64);
-            let fee_x = read_u64_unchecked(data, 96);
-            let fee_y = read_u64_unchecked(data, 104);
-            Some(DexEvent::MeteoraDlmmClaimFee(MeteoraDlmmClaimFeeEvent {
-                metadata,
-                pool,
-                position,
-                owner,
-                fee_x,
-                fee_y,
-            }))
+        if !check_length(data, 32 + 32 + 32 + 8 + 8) {
+            return None;
         }
+        let pool = zc_read!(pubkey, data, 0);
+        let position = zc_read!(pubkey, data, 32);
+        let owner = zc_read!(pubkey, data, 64);
+        let fee_x = zc_read!(u64, data, 96);
+        let fee_y = zc_read!(u64, data, 104);
+        Some(DexEvent::MeteoraDlmmClaimFee(MeteoraDlmmClaimFeeEvent {
+            metadata,
+            pool,
+            position,
+            owner,
+            fee_x,
+            fee_y,
+        }))
+    }
+    // ============================================================================
+    // Claim Reward Event
+    // ============================================================================
+
+    /// 解析 Claim Reward 事件（统一入口）
+    #[inline(always)]
+    fn parse_claim_reward(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+        #[cfg(feature = "parse-borsh")]
+        {
+            parse_claim_reward_borsh(data, metadata)
+        }
+
+        #[cfg(feature = "parse-zero-copy")]
+        {
+            parse_claim_reward_zero_copy(data, metadata)
+        }
+    }
+
+    /// Borsh 解析器 - Claim Reward
+    #[cfg(feature = "parse-borsh")]
+    #[inline(always)]
+    fn parse_claim_reward_borsh(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+        // pool(32) + position(32) + owner(32) + reward_index(8) + total_reward(8) = 112 bytes
+        const CLAIM_REWARD_EVENT_SIZE: usize = 32 + 32 + 32 + 8 + 8;
+        if data.len() < CLAIM_REWARD_EVENT_SIZE {
+            return None;
+        }
+
+        let mut event =
+            borsh::from_slice::<MeteoraDlmmClaimRewardEvent>(&data[..CLAIM_REWARD_EVENT_SIZE])
+                .ok()?;
+        event.metadata = metadata;
+        Some(DexEvent::MeteoraDlmmClaimReward(event))
+    }
+
+    /// 零拷贝解析器 - Claim Reward
+    #[cfg(feature = "parse-zero-copy")]
+    #[inline(always)]
+    fn parse_claim_reward_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+        if !check_length(data, 32 + 32 + 32 + 8 + 8) {
+            return None;
+        }
+        let pool = zc_read!(pubkey, data, 0);
+        let position = zc_read!(pubkey, data, 32);
+        let owner = zc_read!(pubkey, data, 64);
+        let reward_index = zc_read!(u64, data, 96);
+        let total_reward = zc_read!(u64, data, 104);
+        Some(DexEvent::MeteoraDlmmClaimReward(MeteoraDlmmClaimRewardEvent {
+            metadata,
+            pool,
+            position,
+            owner,
+            reward_index,
+            total_reward,
+            reward_mint: Pubkey::default(),
+        }))
+    }
+
+    // ============================================================================
+    // Fund Reward Event
+    // ============================================================================
+
+    /// 解析 Fund Reward 事件（统一入口）
+    #[inline(always)]
+    fn parse_fund_reward(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+        #[cfg(feature = "parse-borsh")]
+        {
+            parse_fund_reward_borsh(data, metadata)
+        }
+
+        #[cfg(feature = "parse-zero-copy")]
+        {
+            parse_fund_reward_zero_copy(data, metadata)
+        }
+    }
+
+    /// Borsh 解析器 - Fund Reward
+    #[cfg(feature = "parse-borsh")]
+    #[inline(always)]
+    fn parse_fund_reward_borsh(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+        // pool(32) + funder(32) + reward_index(8) + amount(8) = 80 bytes
+        const FUND_REWARD_EVENT_SIZE: usize = 32 + 32 + 8 + 8;
+        if data.len() < FUND_REWARD_EVENT_SIZE {
+            return None;
+        }
+
+        let mut event =
+            borsh::from_slice::<MeteoraDlmmFundRewardEvent>(&data[..FUND_REWARD_EVENT_SIZE])
+                .ok()?;
+        event.metadata = metadata;
+        Some(DexEvent::MeteoraDlmmFundReward(event))
+    }
+
+    /// 零拷贝解析器 - Fund Reward
+    #[cfg(feature = "parse-zero-copy")]
+    #[inline(always)]
+    fn parse_fund_reward_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+        if !check_length(data, 32 + 32 + 8 + 8) {
+            return None;
+        }
+        let pool = zc_read!(pubkey, data, 0);
+        let funder = zc_read!(pubkey, data, 32);
+        let reward_index = zc_read!(u64, data, 64);
+        let amount = zc_read!(u64, data, 72);
+        Some(DexEvent::MeteoraDlmmFundReward(MeteoraDlmmFundRewardEvent {
+            metadata,
+            pool,
+            funder,
+            reward_index,
+            amount,
+            reward_mint: Pubkey::default(),
+        }))
+    }
+
+    // ============================================================================
+    // Update Position Operator Event
+    // ============================================================================
+
+    /// 解析 Update Position Operator 事件（统一入口）
+    #[inline(always)]
+    fn parse_update_position_operator(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+        #[cfg(feature = "parse-borsh")]
+        {
+            parse_update_position_operator_borsh(data, metadata)
+        }
+
+        #[cfg(feature = "parse-zero-copy")]
+        {
+            parse_update_position_operator_zero_copy(data, metadata)
+        }
+    }
+
+    /// Borsh 解析器 - Update Position Operator
+    #[cfg(feature = "parse-borsh")]
+    #[inline(always)]
+    fn parse_update_position_operator_borsh(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<DexEvent> {
+        // position(32) + old_operator(32) + new_operator(32) = 96 bytes
+        const UPDATE_POSITION_OPERATOR_EVENT_SIZE: usize = 32 + 32 + 32;
+        if data.len() < UPDATE_POSITION_OPERATOR_EVENT_SIZE {
+            return None;
+        }
+
+        let mut event = borsh::from_slice::<MeteoraDlmmUpdatePositionOperatorEvent>(
+            &data[..UPDATE_POSITION_OPERATOR_EVENT_SIZE],
+        )
+        .ok()?;
+        event.metadata = metadata;
+        Some(DexEvent::MeteoraDlmmUpdatePositionOperator(event))
+    }
+
+    /// 零拷贝解析器 - Update Position Operator
+    #[cfg(feature = "parse-zero-copy")]
+    #[inline(always)]
+    fn parse_update_position_operator_zero_copy(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<DexEvent> {
+        if !check_length(data, 32 + 32 + 32) {
+            return None;
+        }
+        let position = zc_read!(pubkey, data, 0);
+        let old_operator = zc_read!(pubkey, data, 32);
+        let new_operator = zc_read!(pubkey, data, 64);
+        Some(DexEvent::MeteoraDlmmUpdatePositionOperator(MeteoraDlmmUpdatePositionOperatorEvent {
+            metadata,
+            position,
+            old_operator,
+            new_operator,
+            owner: Pubkey::default(),
+        }))
     }
 }
@@ -0,0 +1,363 @@
+//! 从事件重建指令
+//!
+//! 事件中已有的账户直接使用，其余账户（全局配置、event authority、ATA、volume accumulator 等）
+//! 按 IDL 中的 PDA 规则推导，得到可直接放入交易的 [`Instruction`]，用于跟单或反向交易。
+//! 目前支持 PumpFun 和 PumpSwap 的 buy / sell；其他事件缺少指令账户，返回 None。
+//!
+//! 账户顺序以 `idls/pumpfun.json`、`idls/pump_amm.json` 为准。
+
+use super::program_ids::{PUMPFUN_PROGRAM_ID, PUMPSWAP_PROGRAM_ID};
+use super::{pump, pump_amm};
+use crate::core::events::{DexEvent, PumpFunTradeEvent, PumpSwapBuyEvent, PumpSwapSellEvent};
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey;
+use solana_sdk::pubkey::Pubkey;
+
+pub const SYSTEM_PROGRAM_ID: Pubkey = pubkey!("11111111111111111111111111111111");
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+/// Pump 手续费程序（`fee_config` 所属程序）
+pub const PUMP_FEE_PROGRAM_ID: Pubkey = pubkey!("pfeeUxB6jkeY1Hxd7CsFCAjcbHA9rWtchMGdZ6VojVZ");
+/// PumpSwap 全局配置账户
+pub const PUMPSWAP_GLOBAL_CONFIG: Pubkey = pubkey!("ADyA8hdefvWN2dbGGWFotbzWxrAvLW83WG6QCVXvJKqw");
+
+fn pda(seeds: &[&[u8]], program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(seeds, program).0
+}
+
+/// 关联代币账户地址
+pub fn associated_token_address(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    pda(&[owner.as_ref(), token_program.as_ref(), mint.as_ref()], &ASSOCIATED_TOKEN_PROGRAM_ID)
+}
+
+/// 任一账户未填充（默认值）时返回 None
+fn all_known(keys: &[&Pubkey]) -> Option<()> {
+    keys.iter().all(|k| **k != Pubkey::default()).then_some(())
+}
+
+fn instruction_data(discriminator: [u8; 8], args: &[u64], track_volume: Option<bool>) -> Vec<u8> {
+    let mut data = Vec::with_capacity(8 + args.len() * 8 + 1);
+    data.extend_from_slice(&discriminator);
+    for arg in args {
+        data.extend_from_slice(&arg.to_le_bytes());
+    }
+    // OptionBool 为单字段结构体，序列化为 1 字节
+    if let Some(track) = track_volume {
+        data.push(track as u8);
+    }
+    data
+}
+
+impl PumpFunTradeEvent {
+    /// 按事件重建原 buy / sell 指令，数量与限价均取成交值（无滑点空间）
+    pub fn to_instruction(&self) -> Option<Instruction> {
+        self.instruction_for(self.user, self.token_amount, self.sol_amount)
+    }
+
+    /// 以 `user` 身份构造同方向的指令
+    ///
+    /// buy 时 `sol_limit` 为 `max_sol_cost`，sell 时为 `min_sol_output`。
+    pub fn instruction_for(
+        &self,
+        user: Pubkey,
+        token_amount: u64,
+        sol_limit: u64,
+    ) -> Option<Instruction> {
+        all_known(&[&self.mint, &self.fee_recipient, &self.creator, &self.token_program, &user])?;
+        let program = PUMPFUN_PROGRAM_ID;
+        let bonding_curve = pda(&[b"bonding-curve", self.mint.as_ref()], &program);
+        let fee_config = pda(&[b"fee_config", program.as_ref()], &PUMP_FEE_PROGRAM_ID);
+
+        let mut accounts = vec![
+            AccountMeta::new_readonly(pda(&[b"global"], &program), false),
+            AccountMeta::new(self.fee_recipient, false),
+            AccountMeta::new_readonly(self.mint, false),
+            AccountMeta::new(bonding_curve, false),
+            AccountMeta::new(
+                associated_token_address(&bonding_curve, &self.mint, &self.token_program),
+                false,
+            ),
+            AccountMeta::new(
+                associated_token_address(&user, &self.mint, &self.token_program),
+                false,
+            ),
+            AccountMeta::new(user, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ];
+        let creator_vault =
+            AccountMeta::new(pda(&[b"creator-vault", self.creator.as_ref()], &program), false);
+        let token_program = AccountMeta::new_readonly(self.token_program, false);
+        // buy 与 sell 中 creator_vault / token_program 的顺序相反
+        if self.is_buy {
+            accounts.extend([token_program, creator_vault]);
+        } else {
+            accounts.extend([creator_vault, token_program]);
+        }
+        accounts.extend([
+            AccountMeta::new_readonly(pda(&[b"__event_authority"], &program), false),
+            AccountMeta::new_readonly(program, false),
+        ]);
+        if self.is_buy {
+            accounts.extend([
+                AccountMeta::new_readonly(pda(&[b"global_volume_accumulator"], &program), false),
+                AccountMeta::new(
+                    pda(&[b"user_volume_accumulator", user.as_ref()], &program),
+                    false,
+                ),
+            ]);
+        }
+        accounts.extend([
+            AccountMeta::new_readonly(fee_config, false),
+            AccountMeta::new_readonly(PUMP_FEE_PROGRAM_ID, false),
+        ]);
+
+        let data = if self.is_buy {
+            instruction_data(
+                pump::discriminators::BUY,
+                &[token_amount, sol_limit],
+                Some(self.track_volume),
+            )
+        } else {
+            instruction_data(pump::discriminators::SELL, &[token_amount, sol_limit], None)
+        };
+        Some(Instruction { program_id: program, accounts, data })
+    }
+}
+
+/// PumpSwap buy / sell 共用的账户
+struct PumpSwapAccounts<'a> {
+    pool: &'a Pubkey,
+    base_mint: &'a Pubkey,
+    quote_mint: &'a Pubkey,
+    pool_base_token_account: &'a Pubkey,
+    pool_quote_token_account: &'a Pubkey,
+    protocol_fee_recipient: &'a Pubkey,
+    protocol_fee_recipient_token_account: &'a Pubkey,
+    /// 原交易的用户及其代币账户，换成其他用户时改用 ATA
+    user: &'a Pubkey,
+    user_base_token_account: &'a Pubkey,
+    user_quote_token_account: &'a Pubkey,
+    base_token_program: &'a Pubkey,
+    quote_token_program: &'a Pubkey,
+    coin_creator_vault_ata: &'a Pubkey,
+    coin_creator_vault_authority: &'a Pubkey,
+}
+
+impl PumpSwapAccounts<'_> {
+    fn build(&self, user: Pubkey, is_buy: bool) -> Option<Vec<AccountMeta>> {
+        all_known(&[
+            self.pool,
+            self.base_mint,
+            self.quote_mint,
+            self.pool_base_token_account,
+            self.pool_quote_token_account,
+            self.protocol_fee_recipient,
+            self.protocol_fee_recipient_token_account,
+            self.base_token_program,
+            self.quote_token_program,
+            self.coin_creator_vault_ata,
+            self.coin_creator_vault_authority,
+            &user,
+        ])?;
+        let program = PUMPSWAP_PROGRAM_ID;
+        let original = user == *self.user && *self.user_base_token_account != Pubkey::default();
+        let (user_base, user_quote) = if original {
+            (*self.user_base_token_account, *self.user_quote_token_account)
+        } else {
+            (
+                associated_token_address(&user, self.base_mint, self.base_token_program),
+                associated_token_address(&user, self.quote_mint, self.quote_token_program),
+            )
+        };
+        let mut accounts = vec![
+            AccountMeta::new(*self.pool, false),
+            AccountMeta::new(user, true),
+            AccountMeta::new_readonly(PUMPSWAP_GLOBAL_CONFIG, false),
+            AccountMeta::new_readonly(*self.base_mint, false),
+            AccountMeta::new_readonly(*self.quote_mint, false),
+            AccountMeta::new(user_base, false),
+            AccountMeta::new(user_quote, false),
+            AccountMeta::new(*self.pool_base_token_account, false),
+            AccountMeta::new(*self.pool_quote_token_account, false),
+            AccountMeta::new_readonly(*self.protocol_fee_recipient, false),
+            AccountMeta::new(*self.protocol_fee_recipient_token_account, false),
+            AccountMeta::new_readonly(*self.base_token_program, false),
+            AccountMeta::new_readonly(*self.quote_token_program, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(pda(&[b"__event_authority"], &program), false),
+            AccountMeta::new_readonly(program, false),
+            AccountMeta::new(*self.coin_creator_vault_ata, false),
+            AccountMeta::new_readonly(*self.coin_creator_vault_authority, false),
+        ];
+        if is_buy {
+            accounts.extend([
+                AccountMeta::new_readonly(pda(&[b"global_volume_accumulator"], &program), false),
+                AccountMeta::new(
+                    pda(&[b"user_volume_accumulator", user.as_ref()], &program),
+                    false,
+                ),
+            ]);
+        }
+        accounts.extend([
+            AccountMeta::new_readonly(
+                pda(&[b"fee_config", program.as_ref()], &PUMP_FEE_PROGRAM_ID),
+                false,
+            ),
+            AccountMeta::new_readonly(PUMP_FEE_PROGRAM_ID, false),
+        ]);
+        Some(accounts)
+    }
+}
+
+impl PumpSwapBuyEvent {
+    fn accounts(&self) -> PumpSwapAccounts<'_> {
+        PumpSwapAccounts {
+            pool: &self.pool,
+            base_mint: &self.base_mint,
+            quote_mint: &self.quote_mint,
+            pool_base_token_account: &self.pool_base_token_account,
+            pool_quote_token_account: &self.pool_quote_token_account,
+            protocol_fee_recipient: &self.protocol_fee_recipient,
+            protocol_fee_recipient_token_account: &self.protocol_fee_recipient_token_account,
+            user: &self.user,
+            user_base_token_account: &self.user_base_token_account,
+            user_quote_token_account: &self.user_quote_token_account,
+            base_token_program: &self.base_token_program,
+            quote_token_program: &self.quote_token_program,
+            coin_creator_vault_ata: &self.coin_creator_vault_ata,
+            coin_creator_vault_authority: &self.coin_creator_vault_authority,
+        }
+    }
+
+    /// 按事件重建原 buy 指令（`base_amount_out` 与 `max_quote_amount_in` 取原值）
+    pub fn to_instruction(&self) -> Option<Instruction> {
+        self.instruction_for(self.user, self.base_amount_out, self.max_quote_amount_in)
+    }
+
+    /// 以 `user` 身份构造 buy 指令，非原用户时代币账户取其 ATA
+    pub fn instruction_for(
+        &self,
+        user: Pubkey,
+        base_amount_out: u64,
+        max_quote_amount_in: u64,
+    ) -> Option<Instruction> {
+        Some(Instruction {
+            program_id: PUMPSWAP_PROGRAM_ID,
+            accounts: self.accounts().build(user, true)?,
+            data: instruction_data(
+                pump_amm::discriminators::BUY,
+                &[base_amount_out, max_quote_amount_in],
+                Some(self.track_volume),
+            ),
+        })
+    }
+}
+
+impl PumpSwapSellEvent {
+    fn accounts(&self) -> PumpSwapAccounts<'_> {
+        PumpSwapAccounts {
+            pool: &self.pool,
+            base_mint: &self.base_mint,
+            quote_mint: &self.quote_mint,
+            pool_base_token_account: &self.pool_base_token_account,
+            pool_quote_token_account: &self.pool_quote_token_account,
+            protocol_fee_recipient: &self.protocol_fee_recipient,
+            protocol_fee_recipient_token_account: &self.protocol_fee_recipient_token_account,
+            user: &self.user,
+            user_base_token_account: &self.user_base_token_account,
+            user_quote_token_account: &self.user_quote_token_account,
+            base_token_program: &self.base_token_program,
+            quote_token_program: &self.quote_token_program,
+            coin_creator_vault_ata: &self.coin_creator_vault_ata,
+            coin_creator_vault_authority: &self.coin_creator_vault_authority,
+        }
+    }
+
+    /// 按事件重建原 sell 指令（`base_amount_in` 与 `min_quote_amount_out` 取原值）
+    pub fn to_instruction(&self) -> Option<Instruction> {
+        self.instruction_for(self.user, self.base_amount_in, self.min_quote_amount_out)
+    }
+
+    /// 以 `user` 身份构造 sell 指令，非原用户时代币账户取其 ATA
+    pub fn instruction_for(
+        &self,
+        user: Pubkey,
+        base_amount_in: u64,
+        min_quote_amount_out: u64,
+    ) -> Option<Instruction> {
+        Some(Instruction {
+            program_id: PUMPSWAP_PROGRAM_ID,
+            accounts: self.accounts().build(user, false)?,
+            data: instruction_data(
+                pump_amm::discriminators::SELL,
+                &[base_amount_in, min_quote_amount_out],
+                None,
+            ),
+        })
+    }
+}
+
+impl DexEvent {
+    /// 重建产生该事件的 swap 指令，账户不全或不支持的事件返回 None
+    pub fn to_instruction(&self) -> Option<Instruction> {
+        match self {
+            DexEvent::PumpFunTrade(e)
+            | DexEvent::PumpFunBuy(e)
+            | DexEvent::PumpFunSell(e)
+            | DexEvent::PumpFunBuyExactSolIn(e) => e.to_instruction(),
+            DexEvent::PumpSwapBuy(e) => e.to_instruction(),
+            DexEvent::PumpSwapSell(e) => e.to_instruction(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pumpfun_instruction_layout() {
+        let (mint, user, creator) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let mut event = PumpFunTradeEvent {
+            mint,
+            user,
+            creator,
+            fee_recipient: Pubkey::new_unique(),
+            token_program: spl_token_id(),
+            token_amount: 1_000,
+            sol_amount: 50,
+            is_buy: true,
+            track_volume: true,
+            ..Default::default()
+        };
+
+        let ix = DexEvent::PumpFunBuy(event.clone()).to_instruction().unwrap();
+        assert_eq!(ix.program_id, PUMPFUN_PROGRAM_ID);
+        assert_eq!(ix.accounts.len(), 16);
+        assert_eq!(ix.accounts[6], AccountMeta::new(user, true));
+        assert_eq!(ix.accounts[8].pubkey, spl_token_id());
+        assert_eq!(ix.data[..8], pump::discriminators::BUY);
+        assert_eq!(ix.data[8..16], 1_000u64.to_le_bytes());
+        assert_eq!(ix.data[16..24], 50u64.to_le_bytes());
+        assert_eq!(ix.data[24], 1);
+        // 全局账户为 IDL 中 ["global"] 的 PDA
+        assert_eq!(ix.accounts[0].pubkey, pubkey!("4wTV1YmiEkRvAtNtsSGPtUrqRYQMe5SKy2uB4Jjaxnjf"));
+
+        event.is_buy = false;
+        let ix = event.instruction_for(creator, 10, 1).unwrap();
+        assert_eq!(ix.accounts.len(), 14);
+        assert_eq!(ix.accounts[6].pubkey, creator);
+        assert_eq!(ix.accounts[9].pubkey, spl_token_id());
+        assert_eq!(ix.data.len(), 24);
+
+        event.token_program = Pubkey::default();
+        assert!(event.to_instruction().is_none());
+    }
+
+    fn spl_token_id() -> Pubkey {
+        pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+    }
+}
@@ -2,14 +2,18 @@
 //!
 //! 提供零拷贝、高性能的通用读取函数，供所有协议的 inner instruction 解析器使用
 
+use std::borrow::Cow;
+
 /// 零拷贝读取 u8
 #[inline(always)]
+#[cfg(not(feature = "forbid-unsafe"))]
 pub unsafe fn read_u8_unchecked(data: &[u8], offset: usize) -> u8 {
     *data.get_unchecked(offset)
 }
 
 /// 零拷贝读取 u16
 #[inline(always)]
+#[cfg(not(feature = "forbid-unsafe"))]
 pub unsafe fn read_u16_unchecked(data: &[u8], offset: usize) -> u16 {
     let ptr = data.as_ptr().add(offset) as *const u16;
     u16::from_le(ptr.read_unaligned())
@@ -17,6 +21,7 @@ pub unsafe fn read_u16_unchecked(data: &[u8], offset: usize) -> u16 {
 
 /// 零拷贝读取 u32
 #[inline(always)]
+#[cfg(not(feature = "forbid-unsafe"))]
 pub unsafe fn read_u32_unchecked(data: &[u8], offset: usize) -> u32 {
     let ptr = data.as_ptr().add(offset) as *const u32;
     u32::from_le(ptr.read_unaligned())
@@ -24,6 +29,7 @@ pub unsafe fn read_u32_unchecked(data: &[u8], offset: usize) -> u32 {
 
 /// 零拷贝读取 u64
 #[inline(always)]
+#[cfg(not(feature = "forbid-unsafe"))]
 pub unsafe fn read_u64_unchecked(data: &[u8], offset: usize) -> u64 {
     let ptr = data.as_ptr().add(offset) as *const u64;
     u64::from_le(ptr.read_unaligned())
@@ -31,6 +37,7 @@ pub unsafe fn read_u64_unchecked(data: &[u8], offset: usize) -> u64 {
 
 /// 零拷贝读取 u128
 #[inline(always)]
+#[cfg(not(feature = "forbid-unsafe"))]
 pub unsafe fn read_u128_unchecked(data: &[u8], offset: usize) -> u128 {
     let ptr = data.as_ptr().add(offset) as *const u128;
     u128::from_le(ptr.read_unaligned())
@@ -38,6 +45,7 @@ pub unsafe fn read_u128_unchecked(data: &[u8], offset: usize) -> u128 {
 
 /// 零拷贝读取 i32
 #[inline(always)]
+#[cfg(not(feature = "forbid-unsafe"))]
 pub unsafe fn read_i32_unchecked(data: &[u8], offset: usize) -> i32 {
     let ptr = data.as_ptr().add(offset) as *const i32;
     i32::from_le(ptr.read_unaligned())
@@ -45,6 +53,7 @@ pub unsafe fn read_i32_unchecked(data: &[u8], offset: usize) -> i32 {
 
 /// 零拷贝读取 i64
 #[inline(always)]
+#[cfg(not(feature = "forbid-unsafe"))]
 pub unsafe fn read_i64_unchecked(data: &[u8], offset: usize) -> i64 {
     let ptr = data.as_ptr().add(offset) as *const i64;
     i64::from_le(ptr.read_unaligned())
@@ -52,6 +61,7 @@ pub unsafe fn read_i64_unchecked(data: &[u8], offset: usize) -> i64 {
 
 /// 零拷贝读取 i128
 #[inline(always)]
+#[cfg(not(feature = "forbid-unsafe"))]
 pub unsafe fn read_i128_unchecked(data: &[u8], offset: usize) -> i128 {
     let ptr = data.as_ptr().add(offset) as *const i128;
     i128::from_le(ptr.read_unaligned())
@@ -59,12 +69,14 @@ pub unsafe fn read_i128_unchecked(data: &[u8], offset: usize) -> i128 {
 
 /// 零拷贝读取 bool
 #[inline(always)]
+#[cfg(not(feature = "forbid-unsafe"))]
 pub unsafe fn read_bool_unchecked(data: &[u8], offset: usize) -> bool {
     *data.get_unchecked(offset) == 1
 }
 
 /// 零拷贝读取 Pubkey (32 bytes)
 #[inline(always)]
+#[cfg(not(feature = "forbid-unsafe"))]
 pub unsafe fn read_pubkey_unchecked(data: &[u8], offset: usize) -> solana_sdk::pubkey::Pubkey {
     use solana_sdk::pubkey::Pubkey;
     let ptr = data.as_ptr().add(offset);
@@ -73,9 +85,43 @@ pub unsafe fn read_pubkey_unchecked(data: &[u8], offset: usize) -> solana_sdk::p
     Pubkey::new_from_array(bytes)
 }
 
-/// 零拷贝读取字符串（带长度前缀）
+/// 解码链上字符串字节（代币名称、URI 等由发行者任意填写）
+///
+/// 默认校验 UTF-8，非法字节替换为 U+FFFD，合法时不分配；
+/// `unchecked-utf8` 下跳过校验（`forbid-unsafe` 时该 feature 不生效）。
 #[inline(always)]
+pub fn decode_utf8(bytes: &[u8]) -> Cow<'_, str> {
+    #[cfg(all(feature = "unchecked-utf8", not(feature = "forbid-unsafe")))]
+    {
+        // SAFETY: 由启用 unchecked-utf8 的调用方保证数据源可信
+        Cow::Borrowed(unsafe { std::str::from_utf8_unchecked(bytes) })
+    }
+    #[cfg(not(all(feature = "unchecked-utf8", not(feature = "forbid-unsafe"))))]
+    {
+        String::from_utf8_lossy(bytes)
+    }
+}
+
+/// 零拷贝读取字符串（带长度前缀，UTF-8 处理见 [`decode_utf8`]）
+#[inline(always)]
+#[cfg(not(feature = "forbid-unsafe"))]
 pub unsafe fn read_string_unchecked(data: &[u8], offset: usize) -> Option<(String, usize)> {
+    read_str_unchecked(data, offset).map(|(s, len)| (s.into_owned(), len))
+}
+
+/// 检查数据长度是否足够
+#[inline(always)]
+pub fn check_length(data: &[u8], required: usize) -> bool {
+    data.len() >= required
+}
+
+/// 零拷贝读取字符串切片（带长度前缀，合法 UTF-8 时不分配，见 [`decode_utf8`]）
+///
+/// # Safety
+/// 不检查长度前缀的读取边界，调用方需保证 `offset + 4 <= data.len()`
+#[inline(always)]
+#[cfg(not(feature = "forbid-unsafe"))]
+pub unsafe fn read_str_unchecked(data: &[u8], offset: usize) -> Option<(Cow<'_, str>, usize)> {
     if data.len() < offset + 4 {
         return None;
     }
@@ -86,12 +132,215 @@ pub unsafe fn read_string_unchecked(data: &[u8], offset: usize) -> Option<(Strin
     }
 
     let string_bytes = &data[offset + 4..offset + 4 + len];
-    let s = std::str::from_utf8_unchecked(string_bytes);
-    Some((s.to_string(), 4 + len))
+    Some((decode_utf8(string_bytes), 4 + len))
 }
 
-/// 检查数据长度是否足够
+// ============================================================================
+// 边界检查读取（parse-zero-copy-safe）
+// ============================================================================
+
+/// 边界检查读取固定长度字节数组，越界返回 None
 #[inline(always)]
-pub fn check_length(data: &[u8], required: usize) -> bool {
-    data.len() >= required
+fn read_array_checked<const N: usize>(data: &[u8], offset: usize) -> Option<[u8; N]> {
+    data.get(offset..offset.checked_add(N)?)?.try_into().ok()
+}
+
+/// 边界检查读取 u8
+#[inline(always)]
+pub fn read_u8_checked(data: &[u8], offset: usize) -> Option<u8> {
+    data.get(offset).copied()
+}
+
+/// 边界检查读取 u16
+#[inline(always)]
+pub fn read_u16_checked(data: &[u8], offset: usize) -> Option<u16> {
+    read_array_checked(data, offset).map(u16::from_le_bytes)
+}
+
+/// 边界检查读取 u32
+#[inline(always)]
+pub fn read_u32_checked(data: &[u8], offset: usize) -> Option<u32> {
+    read_array_checked(data, offset).map(u32::from_le_bytes)
+}
+
+/// 边界检查读取 u64
+#[inline(always)]
+pub fn read_u64_checked(data: &[u8], offset: usize) -> Option<u64> {
+    read_array_checked(data, offset).map(u64::from_le_bytes)
+}
+
+/// 边界检查读取 u128
+#[inline(always)]
+pub fn read_u128_checked(data: &[u8], offset: usize) -> Option<u128> {
+    read_array_checked(data, offset).map(u128::from_le_bytes)
+}
+
+/// 边界检查读取 i32
+#[inline(always)]
+pub fn read_i32_checked(data: &[u8], offset: usize) -> Option<i32> {
+    read_array_checked(data, offset).map(i32::from_le_bytes)
+}
+
+/// 边界检查读取 i64
+#[inline(always)]
+pub fn read_i64_checked(data: &[u8], offset: usize) -> Option<i64> {
+    read_array_checked(data, offset).map(i64::from_le_bytes)
+}
+
+/// 边界检查读取 i128
+#[inline(always)]
+pub fn read_i128_checked(data: &[u8], offset: usize) -> Option<i128> {
+    read_array_checked(data, offset).map(i128::from_le_bytes)
+}
+
+/// 边界检查读取 bool
+#[inline(always)]
+pub fn read_bool_checked(data: &[u8], offset: usize) -> Option<bool> {
+    read_u8_checked(data, offset).map(|b| b == 1)
+}
+
+/// 边界检查读取 Pubkey (32 bytes)
+#[inline(always)]
+pub fn read_pubkey_checked(data: &[u8], offset: usize) -> Option<solana_sdk::pubkey::Pubkey> {
+    read_array_checked(data, offset).map(solana_sdk::pubkey::Pubkey::new_from_array)
+}
+
+/// 边界检查读取字符串切片（带长度前缀，UTF-8 处理见 [`decode_utf8`]）
+#[inline(always)]
+pub fn read_str_checked(data: &[u8], offset: usize) -> Option<(Cow<'_, str>, usize)> {
+    let len = read_u32_checked(data, offset)? as usize;
+    let start = offset.checked_add(4)?;
+    let string_bytes = data.get(start..start.checked_add(len)?)?;
+    Some((decode_utf8(string_bytes), 4 + len))
+}
+
+/// 边界检查读取字符串（带长度前缀，UTF-8 处理见 [`decode_utf8`]）
+#[inline(always)]
+pub fn read_string_checked(data: &[u8], offset: usize) -> Option<(String, usize)> {
+    read_str_checked(data, offset).map(|(s, len)| (s.into_owned(), len))
+}
+
+/// 零拷贝解析器统一读取宏
+///
+/// - 默认（`parse-zero-copy`）：展开为 `read_*_unchecked`，由调用方的 `check_length` 保证边界
+/// - `parse-zero-copy-safe` / `forbid-unsafe`：展开为 `read_*_checked(..)?`，缓冲区过短时所在解析函数返回 `None`
+///
+/// 标量类型直接得到值；`str` / `string` 与底层读取函数一致，返回 `Option<(_, usize)>`。
+///
+/// ```ignore
+/// let pool = zc_read!(pubkey, data, 0);
+/// let amount = zc_read!(u64, data, 32);
+/// let (name, len) = zc_read!(str, data, 40)?;
+/// ```
+macro_rules! zc_read {
+    (u8, $data:expr, $offset:expr) => { zc_read!(@scalar read_u8_unchecked, read_u8_checked, $data, $offset) };
+    (u16, $data:expr, $offset:expr) => { zc_read!(@scalar read_u16_unchecked, read_u16_checked, $data, $offset) };
+    (u32, $data:expr, $offset:expr) => { zc_read!(@scalar read_u32_unchecked, read_u32_checked, $data, $offset) };
+    (u64, $data:expr, $offset:expr) => { zc_read!(@scalar read_u64_unchecked, read_u64_checked, $data, $offset) };
+    (u128, $data:expr, $offset:expr) => { zc_read!(@scalar read_u128_unchecked, read_u128_checked, $data, $offset) };
+    (i32, $data:expr, $offset:expr) => { zc_read!(@scalar read_i32_unchecked, read_i32_checked, $data, $offset) };
+    (i64, $data:expr, $offset:expr) => { zc_read!(@scalar read_i64_unchecked, read_i64_checked, $data, $offset) };
+    (i128, $data:expr, $offset:expr) => { zc_read!(@scalar read_i128_unchecked, read_i128_checked, $data, $offset) };
+    (bool, $data:expr, $offset:expr) => { zc_read!(@scalar read_bool_unchecked, read_bool_checked, $data, $offset) };
+    (pubkey, $data:expr, $offset:expr) => { zc_read!(@scalar read_pubkey_unchecked, read_pubkey_checked, $data, $offset) };
+    (str, $data:expr, $offset:expr) => { zc_read!(@option read_str_unchecked, read_str_checked, $data, $offset) };
+    (string, $data:expr, $offset:expr) => { zc_read!(@option read_string_unchecked, read_string_checked, $data, $offset) };
+
+    (@scalar $unchecked:ident, $checked:ident, $data:expr, $offset:expr) => {{
+        #[cfg(any(feature = "parse-zero-copy-safe", feature = "forbid-unsafe"))]
+        let value = $crate::instr::inner_common::$checked($data, $offset)?;
+        #[cfg(not(any(feature = "parse-zero-copy-safe", feature = "forbid-unsafe")))]
+        let value = unsafe { $crate::instr::inner_common::$unchecked($data, $offset) };
+        value
+    }};
+    (@option $unchecked:ident, $checked:ident, $data:expr, $offset:expr) => {{
+        #[cfg(any(feature = "parse-zero-copy-safe", feature = "forbid-unsafe"))]
+        let value = $crate::instr::inner_common::$checked($data, $offset);
+        #[cfg(not(any(feature = "parse-zero-copy-safe", feature = "forbid-unsafe")))]
+        let value = unsafe { $crate::instr::inner_common::$unchecked($data, $offset) };
+        value
+    }};
+}
+pub(crate) use zc_read;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_reads_match_unchecked() {
+        let mut data = vec![0u8; 64];
+        data[0..8].copy_from_slice(&42u64.to_le_bytes());
+        data[8..40].copy_from_slice(&[7u8; 32]);
+        data[40] = 1;
+
+        let read = |data: &[u8]| -> Option<_> {
+            Some((zc_read!(u64, data, 0), zc_read!(pubkey, data, 8), zc_read!(bool, data, 40)))
+        };
+        let checked = (
+            read_u64_checked(&data, 0),
+            read_pubkey_checked(&data, 8),
+            read_bool_checked(&data, 40),
+        );
+        assert_eq!(read(&data).map(|(a, b, c)| (Some(a), Some(b), Some(c))), Some(checked));
+        // 缓冲区过短：默认实现由调用方的长度检查保证不越界，检查模式下返回 None
+        #[cfg(any(feature = "parse-zero-copy-safe", feature = "forbid-unsafe"))]
+        assert_eq!(read(&data[..40]), None);
+    }
+
+    /// 链上数据为小端序，读取结果不能依赖宿主字节序（CI 在大端目标上运行本测试）
+    #[test]
+    fn test_readers_are_little_endian() {
+        let data: Vec<u8> = (1..=16).collect();
+        let read = |data: &[u8]| -> Option<_> {
+            Some((
+                zc_read!(u16, data, 0),
+                zc_read!(u32, data, 0),
+                zc_read!(u64, data, 0),
+                zc_read!(u128, data, 0),
+                zc_read!(i64, &(-2i64).to_le_bytes(), 0),
+            ))
+        };
+        assert_eq!(
+            read(&data),
+            Some((
+                0x0201,
+                0x0403_0201,
+                0x0807_0605_0403_0201,
+                0x100f_0e0d_0c0b_0a09_0807_0605_0403_0201,
+                -2
+            ))
+        );
+        assert_eq!(read_u64_checked(&data, 8), Some(0x100f_0e0d_0c0b_0a09));
+        assert_eq!(crate::accounts::read_u64_le(&data, 0), Some(0x0807_0605_0403_0201));
+        // discriminator 查找表的常量（PumpFun TradeEvent）按小端序读取
+        let trade = [189, 219, 127, 211, 78, 230, 97, 238];
+        let discriminator = || -> Option<u64> { Some(zc_read!(u64, &trade, 0)) };
+        assert_eq!(discriminator(), Some(0xEE61_E64E_D37F_DBBD));
+    }
+
+    #[test]
+    fn test_checked_reads_short_buffer() {
+        let data = [0u8; 8];
+        assert_eq!(read_u64_checked(&data, 0), Some(0));
+        assert_eq!(read_u64_checked(&data, 1), None);
+        assert_eq!(read_u128_checked(&data, 0), None);
+        assert_eq!(read_pubkey_checked(&data, 0), None);
+        assert_eq!(read_u8_checked(&data, 8), None);
+        assert_eq!(read_u64_checked(&data, usize::MAX), None);
+    }
+
+    #[test]
+    fn test_read_str_checked() {
+        let mut data = 3u32.to_le_bytes().to_vec();
+        data.extend_from_slice(b"abc");
+        assert_eq!(read_str_checked(&data, 0), Some(("abc".into(), 7)));
+        // 长度前缀超出缓冲区
+        assert_eq!(read_str_checked(&data[..6], 0), None);
+        // 非法 UTF-8 与默认路径一致按 decode_utf8 处理，不丢弃事件
+        let bad = [1u8, 0, 0, 0, 0xff];
+        assert_eq!(read_str_checked(&bad, 0).map(|(_, len)| len), Some(5));
+        #[cfg(not(all(feature = "unchecked-utf8", not(feature = "forbid-unsafe"))))]
+        assert_eq!(read_str_checked(&bad, 0), Some(("\u{FFFD}".into(), 5)));
+    }
 }
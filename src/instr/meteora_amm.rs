@@ -122,6 +122,7 @@ fn parse_swap_instruction(
         trade_fee: 0, // 从日志中获取
         admin_fee: 0, // 从日志中获取
         host_fee: 0, // 从日志中获取
+        pool,
     }))
 }
 
@@ -152,6 +153,7 @@ fn parse_add_liquidity_instruction(
         lp_mint_amount: pool_token_amount,
         token_a_amount: maximum_token_a_amount, // 先用指令中的最大值，日志会覆盖实际值
         token_b_amount: maximum_token_b_amount, // 先用指令中的最大值，日志会覆盖实际值
+        pool,
     }))
 }
 
@@ -182,6 +184,7 @@ fn parse_remove_liquidity_instruction(
         lp_unmint_amount: pool_token_amount,
         token_a_out_amount: minimum_token_a_amount, // 先用指令中的最小值，日志会覆盖实际值
         token_b_out_amount: minimum_token_b_amount, // 先用指令中的最小值，日志会覆盖实际值
+        pool,
     }))
 }
 
@@ -57,6 +57,58 @@ pub enum MeteoraDlmmInstruction {
 /// Meteora DLMM 程序 ID (使用常量)
 pub const PROGRAM_ID_PUBKEY: Pubkey = program_ids::METEORA_DLMM_PROGRAM_ID;
 
+/// Meteora DLMM Anchor 指令 discriminator 常量（来自 `idls/meteora_dlmm.json`）
+pub mod discriminators {
+    pub const INITIALIZE_BIN_ARRAY_BITMAP_EXTENSION: [u8; 8] = [47, 157, 226, 180, 12, 240, 33, 71];
+}
+
+/// 外层指令解析入口（由 `parse_instruction_unified` 调用）
+///
+/// Swap / 流动性 / 奖励等事件从日志获得，这里只解析不产生日志事件的
+/// `initialize_bin_array_bitmap_extension` 指令。
+pub fn parse_outer_instruction(
+    instruction_data: &[u8],
+    accounts: &[Pubkey],
+    signature: Signature,
+    slot: u64,
+    tx_index: u64,
+    block_time_us: Option<i64>,
+    grpc_recv_us: i64,
+) -> Option<DexEvent> {
+    if instruction_data.len() < 8 {
+        return None;
+    }
+
+    let discriminator: [u8; 8] = instruction_data[0..8].try_into().ok()?;
+    let metadata =
+        create_metadata(signature, slot, tx_index, block_time_us.unwrap_or_default(), grpc_recv_us);
+
+    match discriminator {
+        discriminators::INITIALIZE_BIN_ARRAY_BITMAP_EXTENSION => {
+            parse_initialize_bin_array_bitmap_extension_instruction(accounts, metadata)
+        }
+        _ => None,
+    }
+}
+
+/// 解析 bin array bitmap extension 初始化指令
+///
+/// Account indices (from meteora_dlmm.json):
+/// 0: lbPair, 1: binArrayBitmapExtension, 2: funder
+fn parse_initialize_bin_array_bitmap_extension_instruction(
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    Some(DexEvent::MeteoraDlmmInitializeBinArrayBitmapExtension(
+        MeteoraDlmmInitializeBinArrayBitmapExtensionEvent {
+            metadata,
+            pool: get_account(accounts, 0)?,
+            bin_array_bitmap_extension: get_account(accounts, 1)?,
+            funder: get_account(accounts, 2).unwrap_or_default(),
+        },
+    ))
+}
+
 /// 主要的 Meteora DLMM 指令解析函数
 pub fn parse_instruction(
     instruction_data: &[u8],
@@ -87,6 +139,9 @@ pub fn parse_instruction(
 }
 
 /// 解析初始化LB池指令
+///
+/// 账户（initializeLbPair）：0: lb_pair, 1: bin_array_bitmap_extension, 2: token_mint_x,
+/// 3: token_mint_y, ..., 8: funder
 fn parse_initialize_lb_pair_instruction(
     data: &[u8],
     accounts: &[Pubkey],
@@ -108,9 +163,11 @@ fn parse_initialize_lb_pair_instruction(
     Some(DexEvent::MeteoraDlmmInitializePool(MeteoraDlmmInitializePoolEvent {
         metadata,
         pool,
-        creator: get_account(accounts, 1).unwrap_or_default(),
+        creator: get_account(accounts, 8).unwrap_or_default(),
         active_bin_id: active_id,
         bin_step,
+        token_x_mint: get_account(accounts, 2).unwrap_or_default(),
+        token_y_mint: get_account(accounts, 3).unwrap_or_default(),
     }))
 }
 
@@ -306,4 +363,39 @@ fn parse_close_position_instruction(
         position: get_account(accounts, 1).unwrap_or_default(),
         owner: get_account(accounts, 2).unwrap_or_default(),
     }))
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_initialize_bin_array_bitmap_extension() {
+        let accs: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+        let event = parse_outer_instruction(
+            &discriminators::INITIALIZE_BIN_ARRAY_BITMAP_EXTENSION,
+            &accs,
+            Signature::default(),
+            1,
+            0,
+            Some(0),
+            0,
+        );
+        match event {
+            Some(DexEvent::MeteoraDlmmInitializeBinArrayBitmapExtension(e)) => {
+                assert_eq!(e.pool, accs[0]);
+                assert_eq!(e.bin_array_bitmap_extension, accs[1]);
+                assert_eq!(e.funder, accs[2]);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_unknown_outer_instruction_is_ignored() {
+        let accs: Vec<Pubkey> = (0..16).map(|_| Pubkey::new_unique()).collect();
+        let event =
+            parse_outer_instruction(&[0u8; 32], &accs, Signature::default(), 1, 0, Some(0), 0);
+        assert!(event.is_none());
+    }
+}
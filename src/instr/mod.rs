@@ -2,6 +2,8 @@
 //!
 //! 包含所有 DEX 协议的指令解析器实现
 
+#![cfg_attr(feature = "forbid-unsafe", forbid(unsafe_code))]
+
 pub mod meteora_amm;
 pub mod meteora_damm;
 pub mod meteora_dlmm;
@@ -14,6 +16,7 @@ pub mod raydium_clmm;
 pub mod raydium_cpmm;
 pub mod raydium_launchpad;
 pub mod utils;
+pub mod builder;
 
 // Inner instruction 解析器（16字节 discriminator）
 pub mod inner_common;        // 通用零拷贝读取函数
@@ -26,8 +29,10 @@ use crate::logs::perf_hints::unlikely;
 
 // 重新导出主要解析函数
 pub use meteora_damm::parse_instruction as parse_meteora_damm_instruction;
+pub use meteora_dlmm::parse_outer_instruction as parse_meteora_dlmm_instruction;
 pub use pump::parse_instruction as parse_pumpfun_instruction;
 pub use pump_amm::parse_instruction as parse_pumpswap_instruction;
+pub use raydium_launchpad::parse_instruction as parse_raydium_launchpad_instruction;
 
 // 重新导出工具函数
 pub use utils::*;
@@ -61,6 +66,7 @@ pub fn parse_instruction_unified(
                 matches!(
                     t,
                     EventType::PumpFunMigrate
+                        | EventType::BonkMigrateAmm
                         | EventType::MeteoraDammV2Swap
                         | EventType::MeteoraDammV2AddLiquidity
                         | EventType::MeteoraDammV2CreatePosition
@@ -120,6 +126,55 @@ pub fn parse_instruction_unified(
             grpc_recv_us,
         );
     }
+    // Meteora DLMM
+    else if *program_id == METEORA_DLMM_PROGRAM_ID {
+        return parse_meteora_dlmm_instruction(
+            instruction_data,
+            accounts,
+            signature,
+            slot,
+            tx_index,
+            block_time_us,
+            grpc_recv_us,
+        );
+    }
+    // Raydium Launchpad (Bonk)
+    else if *program_id == BONK_PROGRAM_ID {
+        if event_type_filter.is_some() && !event_type_filter.unwrap().includes_raydium_launchpad() {
+            return None;
+        }
+        return parse_raydium_launchpad_instruction(
+            instruction_data,
+            accounts,
+            signature,
+            slot,
+            tx_index,
+            block_time_us,
+            grpc_recv_us,
+        );
+    }
+    // Raydium CLMM：只解析奖励指令，swap / 流动性事件来自日志
+    else if *program_id == RAYDIUM_CLMM_PROGRAM_ID {
+        return raydium_clmm::parse_reward_instruction(
+            instruction_data,
+            accounts,
+            signature,
+            slot,
+            tx_index,
+            block_time_us,
+        );
+    }
+    // Raydium CPMM：只解析建池指令，swap / 流动性事件来自日志
+    else if *program_id == RAYDIUM_CPMM_PROGRAM_ID {
+        return raydium_cpmm::parse_pool_initialize(
+            instruction_data,
+            accounts,
+            signature,
+            slot,
+            tx_index,
+            block_time_us,
+        );
+    }
 
     None
 }
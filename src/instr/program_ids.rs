@@ -11,14 +11,13 @@ use solana_sdk::pubkey::Pubkey;
 pub const PUMPFUN_PROGRAM_ID: Pubkey = pubkey!("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P");
 
 /// Bonk program ID as Pubkey constant
-pub const BONK_PROGRAM_ID: Pubkey = pubkey!("DjVE6JNiYqPL2QXyCUUh8rNjHrbz9hXHNYt99MQ59qw1");
+pub const BONK_PROGRAM_ID: Pubkey = pubkey!("LanMV9sAd7wArD4vJFi2qDdfnVhFxYSUg6eADduJ3uj");
 
 /// PumpSwap program ID as Pubkey constant
 pub const PUMPSWAP_PROGRAM_ID: Pubkey = pubkey!("pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA");
 
 /// Raydium CLMM program ID as Pubkey constant
-/// Note: Original string was too long, using corrected version
-pub const RAYDIUM_CLMM_PROGRAM_ID: Pubkey = pubkey!("CAMMCzo5YL8w4VFF8KVHrK22GGUQpMDdHFWF5LCATdCR");
+pub const RAYDIUM_CLMM_PROGRAM_ID: Pubkey = crate::grpc::program_ids::RAYDIUM_CLMM_PROGRAM;
 
 /// Raydium CPMM program ID as Pubkey constant
 pub const RAYDIUM_CPMM_PROGRAM_ID: Pubkey = pubkey!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C");
@@ -59,6 +59,40 @@ pub fn parse_instruction(
     }
 }
 
+/// 用外层 buy / sell / buy_exact_sol_in 指令参数填充交易事件，并计算 `slippage_tolerance_bps`
+///
+/// `instruction_data` 含 8 字节 discriminator；事件需已包含成交数量（来自日志）。
+/// 不是交易指令或参数不足时返回 false，事件不变
+pub fn apply_trade_args(event: &mut PumpFunTradeEvent, instruction_data: &[u8]) -> bool {
+    let Some(discriminator) = instruction_data.get(..8) else {
+        return false;
+    };
+    let (Some(first), Some(second)) =
+        (read_u64_le(instruction_data, 8), read_u64_le(instruction_data, 16))
+    else {
+        return false;
+    };
+    // 滑点 = 指令限价与成交数量之差 / 成交数量
+    let (slack, executed) = match discriminator {
+        d if d == discriminators::BUY => {
+            (event.amount, event.max_sol_cost) = (first, second);
+            (second.saturating_sub(event.sol_amount), event.sol_amount)
+        }
+        d if d == discriminators::SELL => {
+            (event.amount, event.min_sol_output) = (first, second);
+            (event.sol_amount.saturating_sub(second), event.sol_amount)
+        }
+        d if d == discriminators::BUY_EXACT_SOL_IN => {
+            (event.max_sol_cost, event.amount) = (first, second);
+            (event.token_amount.saturating_sub(second), event.token_amount)
+        }
+        _ => return false,
+    };
+    event.slippage_tolerance_bps =
+        (executed > 0).then(|| (slack as u128 * 10_000 / executed as u128) as u64);
+    true
+}
+
 /// Parse buy/buy_exact_sol_in instruction
 ///
 /// Account indices (from pump.json):
@@ -277,3 +311,40 @@ fn parse_migrate_log_instruction(
         pool,
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ix(discriminator: [u8; 8], first: u64, second: u64) -> Vec<u8> {
+        let mut data = discriminator.to_vec();
+        data.extend_from_slice(&first.to_le_bytes());
+        data.extend_from_slice(&second.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_apply_trade_args_slippage() {
+        let executed = PumpFunTradeEvent { sol_amount: 1_000_000, token_amount: 5_000, ..Default::default() };
+
+        // buy: 最多花费 1.05 倍 → 500 bps
+        let mut buy = executed.clone();
+        assert!(apply_trade_args(&mut buy, &ix(discriminators::BUY, 5_000, 1_050_000)));
+        assert_eq!((buy.amount, buy.max_sol_cost, buy.slippage_tolerance_bps), (5_000, 1_050_000, Some(500)));
+
+        // sell: 至少收到 0.99 倍 → 100 bps
+        let mut sell = executed.clone();
+        assert!(apply_trade_args(&mut sell, &ix(discriminators::SELL, 5_000, 990_000)));
+        assert_eq!((sell.min_sol_output, sell.slippage_tolerance_bps), (990_000, Some(100)));
+
+        // buy_exact_sol_in: 按代币数量计算，限价不低于成交时为 0
+        let mut exact = executed.clone();
+        assert!(apply_trade_args(&mut exact, &ix(discriminators::BUY_EXACT_SOL_IN, 1_000_000, 5_000)));
+        assert_eq!((exact.max_sol_cost, exact.amount, exact.slippage_tolerance_bps), (1_000_000, 5_000, Some(0)));
+
+        let mut other = executed;
+        assert!(!apply_trade_args(&mut other, &ix(discriminators::CREATE, 1, 2)));
+        assert!(!apply_trade_args(&mut other, &discriminators::BUY));
+        assert_eq!(other.slippage_tolerance_bps, None);
+    }
+}
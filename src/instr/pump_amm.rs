@@ -21,6 +21,8 @@ pub mod discriminators {
     pub const DEPOSIT: [u8; 8] = [242, 35, 198, 137, 82, 225, 242, 182];
     /// withdraw: Remove liquidity from pool
     pub const WITHDRAW: [u8; 8] = [183, 18, 70, 156, 148, 109, 161, 34];
+    /// collect_coin_creator_fee
+    pub const COLLECT_COIN_CREATOR_FEE: [u8; 8] = [160, 57, 89, 42, 181, 139, 43, 66];
 }
 
 /// Pump AMM Program ID
@@ -67,6 +69,31 @@ pub fn parse_instruction(
         discriminators::WITHDRAW => {
             parse_withdraw_instruction(data, accounts, signature, slot, tx_index, block_time_us)
         }
+        discriminators::COLLECT_COIN_CREATOR_FEE => {
+            parse_collect_coin_creator_fee_instruction(accounts, signature, slot, tx_index, block_time_us)
+        }
+        _ => None,
+    }
+}
+
+/// 解析 buy / buy_exact_quote_in / sell 指令参数，`instruction_data` 含 8 字节 discriminator
+///
+/// 参数不足 16 字节或不是交易指令时返回 None
+pub fn parse_trade_args(instruction_data: &[u8]) -> Option<PumpSwapInstructionArgs> {
+    let discriminator: [u8; 8] = instruction_data.get(..8)?.try_into().ok()?;
+    let first = read_u64_le(instruction_data, 8)?;
+    let second = read_u64_le(instruction_data, 16)?;
+    match discriminator {
+        discriminators::BUY => {
+            Some(PumpSwapInstructionArgs::Buy { base_amount_out: first, max_quote_amount_in: second })
+        }
+        discriminators::BUY_EXACT_QUOTE_IN => Some(PumpSwapInstructionArgs::BuyExactQuoteIn {
+            spendable_quote_in: first,
+            min_base_amount_out: second,
+        }),
+        discriminators::SELL => {
+            Some(PumpSwapInstructionArgs::Sell { base_amount_in: first, min_quote_amount_out: second })
+        }
         _ => None,
     }
 }
@@ -121,6 +148,10 @@ fn parse_buy_instruction(
         quote_token_program: get_account(accounts, 12).unwrap_or_default(),
         base_amount_out: base_amount,
         max_quote_amount_in: quote_amount,
+        instruction_args: (data.len() >= 16).then_some(PumpSwapInstructionArgs::Buy {
+            base_amount_out: base_amount,
+            max_quote_amount_in: quote_amount,
+        }),
         ..Default::default()
     }))
 }
@@ -179,6 +210,10 @@ fn parse_buy_exact_quote_in_instruction(
         quote_token_program: get_account(accounts, 12).unwrap_or_default(),
         base_amount_out: base_amount,
         max_quote_amount_in: quote_amount,
+        instruction_args: (data.len() >= 16).then_some(PumpSwapInstructionArgs::BuyExactQuoteIn {
+            spendable_quote_in: quote_amount,
+            min_base_amount_out: base_amount,
+        }),
         ..Default::default()
     }))
 }
@@ -227,6 +262,10 @@ fn parse_sell_instruction(
         quote_token_program: get_account(accounts, 12).unwrap_or_default(),
         base_amount_in: base_amount,
         min_quote_amount_out: quote_amount,
+        instruction_args: (data.len() >= 16).then_some(PumpSwapInstructionArgs::Sell {
+            base_amount_in: base_amount,
+            min_quote_amount_out: quote_amount,
+        }),
         ..Default::default()
     }))
 }
@@ -318,3 +357,76 @@ fn parse_withdraw_instruction(
         ..Default::default()
     }))
 }
+
+/// Parse collect_coin_creator_fee instruction
+///
+/// Account indices (from pump_amm.json):
+/// 0: quote_mint, 1: quote_token_program, 2: coin_creator, 3: coin_creator_vault_authority,
+/// 4: coin_creator_vault_ata, 5: coin_creator_token_account
+///
+/// 领取数量来自 CollectCoinCreatorFeeEvent（inner instruction），合并时保留这里的 quote_mint
+fn parse_collect_coin_creator_fee_instruction(
+    accounts: &[Pubkey],
+    signature: Signature,
+    slot: u64,
+    tx_index: u64,
+    block_time_us: Option<i64>,
+) -> Option<DexEvent> {
+    if accounts.len() < 6 {
+        return None;
+    }
+
+    let metadata = create_metadata(
+        signature, slot, tx_index,
+        block_time_us.unwrap_or_default(), 0
+    );
+
+    Some(DexEvent::PumpSwapCreatorFeeClaimed(PumpSwapCreatorFeeClaimed {
+        metadata,
+        quote_mint: get_account(accounts, 0).unwrap_or_default(),
+        creator: get_account(accounts, 2).unwrap_or_default(),
+        creator_vault_ata: get_account(accounts, 4).unwrap_or_default(),
+        creator_token_account: get_account(accounts, 5).unwrap_or_default(),
+        ..Default::default()
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::merger::merge_events;
+
+    fn ix(discriminator: [u8; 8], first: u64, second: u64) -> Vec<u8> {
+        let mut data = discriminator.to_vec();
+        data.extend_from_slice(&first.to_le_bytes());
+        data.extend_from_slice(&second.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_trade_instruction_args() {
+        assert_eq!(
+            parse_trade_args(&ix(discriminators::BUY_EXACT_QUOTE_IN, 5_000, 120)),
+            Some(PumpSwapInstructionArgs::BuyExactQuoteIn { spendable_quote_in: 5_000, min_base_amount_out: 120 })
+        );
+        assert_eq!(
+            parse_trade_args(&ix(discriminators::SELL, 300, 9)),
+            Some(PumpSwapInstructionArgs::Sell { base_amount_in: 300, min_quote_amount_out: 9 })
+        );
+        assert!(parse_trade_args(&ix(discriminators::DEPOSIT, 1, 2)).is_none());
+        assert!(parse_trade_args(&discriminators::BUY).is_none());
+
+        // 与 inner instruction 事件合并后保留指令参数
+        let accounts: Vec<Pubkey> = (0..13).map(|_| Pubkey::new_unique()).collect();
+        let data = ix(discriminators::BUY, 1_000, 2_000);
+        let mut event = parse_instruction(&data, &accounts, Signature::default(), 1, 0, None).unwrap();
+        let inner = DexEvent::PumpSwapBuy(PumpSwapBuyEvent { quote_amount_in: 1_900, ..Default::default() });
+        merge_events(&mut event, inner);
+        let DexEvent::PumpSwapBuy(e) = event else { panic!() };
+        assert_eq!(e.quote_amount_in, 1_900);
+        assert_eq!(
+            e.instruction_args,
+            Some(PumpSwapInstructionArgs::Buy { base_amount_out: 1_000, max_quote_amount_in: 2_000 })
+        );
+    }
+}
@@ -27,6 +27,7 @@
 //! ```
 
 use crate::core::events::*;
+use crate::core::layout::{PUMPSWAP_BUY_EVENT, PUMPSWAP_SELL_EVENT};
 use crate::instr::inner_common::*;
 
 
@@ -73,6 +74,42 @@ pub mod discriminators {
         228, 69, 165, 46, 81, 203, 154, 29,  // magic prefix
         22, 9, 133, 26, 160, 44, 71, 192,    // RemoveLiquidityEvent hash
     ];
+
+    /// UpdateFeeConfigEvent
+    pub const UPDATE_FEE_CONFIG: [u8; 16] = [
+        228, 69, 165, 46, 81, 203, 154, 29,  // magic prefix
+        90, 23, 65, 35, 62, 244, 188, 208,   // UpdateFeeConfigEvent hash
+    ];
+
+    /// DisableEvent
+    pub const DISABLE: [u8; 16] = [
+        228, 69, 165, 46, 81, 203, 154, 29,  // magic prefix
+        107, 253, 193, 76, 228, 202, 27, 104, // DisableEvent hash
+    ];
+
+    /// AdminSetCoinCreatorEvent
+    pub const ADMIN_SET_COIN_CREATOR: [u8; 16] = [
+        228, 69, 165, 46, 81, 203, 154, 29,  // magic prefix
+        45, 220, 93, 24, 25, 97, 172, 104,   // AdminSetCoinCreatorEvent hash
+    ];
+
+    /// SetBondingCurveCoinCreatorEvent
+    pub const SET_BONDING_CURVE_COIN_CREATOR: [u8; 16] = [
+        228, 69, 165, 46, 81, 203, 154, 29,  // magic prefix
+        242, 231, 235, 102, 65, 99, 189, 211, // SetBondingCurveCoinCreatorEvent hash
+    ];
+
+    /// SetMetaplexCoinCreatorEvent
+    pub const SET_METAPLEX_COIN_CREATOR: [u8; 16] = [
+        228, 69, 165, 46, 81, 203, 154, 29,  // magic prefix
+        150, 107, 199, 123, 124, 207, 102, 228, // SetMetaplexCoinCreatorEvent hash
+    ];
+
+    /// CollectCoinCreatorFeeEvent
+    pub const COLLECT_COIN_CREATOR_FEE: [u8; 16] = [
+        228, 69, 165, 46, 81, 203, 154, 29,  // magic prefix
+        232, 245, 194, 238, 234, 218, 58, 89, // CollectCoinCreatorFeeEvent hash
+    ];
 }
 
 /// 解析 PumpSwap inner instruction (统一入口)
@@ -83,11 +120,17 @@ pub fn parse_pumpswap_inner_instruction(
     metadata: EventMetadata,
 ) -> Option<DexEvent> {
     match discriminator {
-        &discriminators::BUY => parse_buy_inner(data, metadata),
-        &discriminators::SELL => parse_sell_inner(data, metadata),
+        &discriminators::BUY => parse_buy_inner(&PUMPSWAP_BUY_EVENT.upgrade(data), metadata),
+        &discriminators::SELL => parse_sell_inner(&PUMPSWAP_SELL_EVENT.upgrade(data), metadata),
         &discriminators::CREATE_POOL => parse_create_pool_inner(data, metadata),
         &discriminators::ADD_LIQUIDITY => parse_add_liquidity_inner(data, metadata),
         &discriminators::REMOVE_LIQUIDITY => parse_remove_liquidity_inner(data, metadata),
+        &discriminators::UPDATE_FEE_CONFIG
+        | &discriminators::DISABLE
+        | &discriminators::ADMIN_SET_COIN_CREATOR
+        | &discriminators::SET_BONDING_CURVE_COIN_CREATOR
+        | &discriminators::SET_METAPLEX_COIN_CREATOR
+        | &discriminators::COLLECT_COIN_CREATOR_FEE => parse_admin_inner(discriminator, data, metadata),
         _ => None,
     }
 }
@@ -171,109 +214,107 @@ fn parse_buy_inner_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<Dex
     // current_sol_volume: u64 (8)
     // last_update_timestamp: i64 (8)
 
-    unsafe {
-        const MIN_SIZE: usize = 8 * 17 + 32 * 7 + 1;
-        if !check_length(data, MIN_SIZE) {
-            return None;
-        }
-
-        let mut offset = 0;
-
-        // 解析数值字段
-        let timestamp = read_i64_unchecked(data, offset);
-        offset += 8;
-        let base_amount_out = read_u64_unchecked(data, offset);
-        offset += 8;
-        let max_quote_amount_in = read_u64_unchecked(data, offset);
-        offset += 8;
-        let user_base_token_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
-        let user_quote_token_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
-        let pool_base_token_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
-        let pool_quote_token_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
-        let quote_amount_in = read_u64_unchecked(data, offset);
-        offset += 8;
-        let lp_fee_basis_points = read_u64_unchecked(data, offset);
-        offset += 8;
-        let lp_fee = read_u64_unchecked(data, offset);
-        offset += 8;
-        let protocol_fee_basis_points = read_u64_unchecked(data, offset);
-        offset += 8;
-        let protocol_fee = read_u64_unchecked(data, offset);
-        offset += 8;
-        let quote_amount_in_with_lp_fee = read_u64_unchecked(data, offset);
-        offset += 8;
-        let user_quote_amount_in = read_u64_unchecked(data, offset);
-        offset += 8;
-
-        // 解析 Pubkey 字段
-        let pool = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let user = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let user_base_token_account = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let user_quote_token_account = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let protocol_fee_recipient = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let protocol_fee_recipient_token_account = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let coin_creator = read_pubkey_unchecked(data, offset);
-        offset += 32;
-
-        let coin_creator_fee_basis_points = read_u64_unchecked(data, offset);
-        offset += 8;
-        let coin_creator_fee = read_u64_unchecked(data, offset);
-        offset += 8;
-
-        let track_volume = data[offset] != 0;
-        offset += 1;
-
-        let total_unclaimed_tokens = read_u64_unchecked(data, offset);
-        offset += 8;
-        let total_claimed_tokens = read_u64_unchecked(data, offset);
-        offset += 8;
-        let current_sol_volume = read_u64_unchecked(data, offset);
-        offset += 8;
-        let last_update_timestamp = read_i64_unchecked(data, offset);
-
-        Some(DexEvent::PumpSwapBuy(PumpSwapBuyEvent {
-            metadata,
-            timestamp,
-            base_amount_out,
-            max_quote_amount_in,
-            user_base_token_reserves,
-            user_quote_token_reserves,
-            pool_base_token_reserves,
-            pool_quote_token_reserves,
-            quote_amount_in,
-            lp_fee_basis_points,
-            lp_fee,
-            protocol_fee_basis_points,
-            protocol_fee,
-            quote_amount_in_with_lp_fee,
-            user_quote_amount_in,
-            pool,
-            user,
-            user_base_token_account,
-            user_quote_token_account,
-            protocol_fee_recipient,
-            protocol_fee_recipient_token_account,
-            coin_creator,
-            coin_creator_fee_basis_points,
-            coin_creator_fee,
-            track_volume,
-            total_unclaimed_tokens,
-            total_claimed_tokens,
-            current_sol_volume,
-            last_update_timestamp,
-            ..Default::default()
-        }))
+    const MIN_SIZE: usize = 8 * 17 + 32 * 7 + 1;
+    if !check_length(data, MIN_SIZE) {
+        return None;
     }
+
+    let mut offset = 0;
+
+    // 解析数值字段
+    let timestamp = zc_read!(i64, data, offset);
+    offset += 8;
+    let base_amount_out = zc_read!(u64, data, offset);
+    offset += 8;
+    let max_quote_amount_in = zc_read!(u64, data, offset);
+    offset += 8;
+    let user_base_token_reserves = zc_read!(u64, data, offset);
+    offset += 8;
+    let user_quote_token_reserves = zc_read!(u64, data, offset);
+    offset += 8;
+    let pool_base_token_reserves = zc_read!(u64, data, offset);
+    offset += 8;
+    let pool_quote_token_reserves = zc_read!(u64, data, offset);
+    offset += 8;
+    let quote_amount_in = zc_read!(u64, data, offset);
+    offset += 8;
+    let lp_fee_basis_points = zc_read!(u64, data, offset);
+    offset += 8;
+    let lp_fee = zc_read!(u64, data, offset);
+    offset += 8;
+    let protocol_fee_basis_points = zc_read!(u64, data, offset);
+    offset += 8;
+    let protocol_fee = zc_read!(u64, data, offset);
+    offset += 8;
+    let quote_amount_in_with_lp_fee = zc_read!(u64, data, offset);
+    offset += 8;
+    let user_quote_amount_in = zc_read!(u64, data, offset);
+    offset += 8;
+
+    // 解析 Pubkey 字段
+    let pool = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let user = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let user_base_token_account = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let user_quote_token_account = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let protocol_fee_recipient = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let protocol_fee_recipient_token_account = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let coin_creator = zc_read!(pubkey, data, offset);
+    offset += 32;
+
+    let coin_creator_fee_basis_points = zc_read!(u64, data, offset);
+    offset += 8;
+    let coin_creator_fee = zc_read!(u64, data, offset);
+    offset += 8;
+
+    let track_volume = data[offset] != 0;
+    offset += 1;
+
+    let total_unclaimed_tokens = zc_read!(u64, data, offset);
+    offset += 8;
+    let total_claimed_tokens = zc_read!(u64, data, offset);
+    offset += 8;
+    let current_sol_volume = zc_read!(u64, data, offset);
+    offset += 8;
+    let last_update_timestamp = zc_read!(i64, data, offset);
+
+    Some(DexEvent::PumpSwapBuy(PumpSwapBuyEvent {
+        metadata,
+        timestamp,
+        base_amount_out,
+        max_quote_amount_in,
+        user_base_token_reserves,
+        user_quote_token_reserves,
+        pool_base_token_reserves,
+        pool_quote_token_reserves,
+        quote_amount_in,
+        lp_fee_basis_points,
+        lp_fee,
+        protocol_fee_basis_points,
+        protocol_fee,
+        quote_amount_in_with_lp_fee,
+        user_quote_amount_in,
+        pool,
+        user,
+        user_base_token_account,
+        user_quote_token_account,
+        protocol_fee_recipient,
+        protocol_fee_recipient_token_account,
+        coin_creator,
+        coin_creator_fee_basis_points,
+        coin_creator_fee,
+        track_volume,
+        total_unclaimed_tokens,
+        total_claimed_tokens,
+        current_sol_volume,
+        last_update_timestamp,
+        ..Default::default()
+    }))
 }
 
 // ============================================================================
@@ -351,183 +392,188 @@ fn parse_sell_inner_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<De
     // coin_creator_fee_basis_points: u64 (8)
     // coin_creator_fee: u64 (8)
 
-    unsafe {
-        const MIN_SIZE: usize = 8 * 16 + 32 * 7;
-        if !check_length(data, MIN_SIZE) {
-            return None;
-        }
-
-        let mut offset = 0;
-
-        // 解析数值字段
-        let timestamp = read_i64_unchecked(data, offset);
-        offset += 8;
-        let base_amount_in = read_u64_unchecked(data, offset);
-        offset += 8;
-        let min_quote_amount_out = read_u64_unchecked(data, offset);
-        offset += 8;
-        let user_base_token_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
-        let user_quote_token_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
-        let pool_base_token_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
-        let pool_quote_token_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
-        let quote_amount_out = read_u64_unchecked(data, offset);
-        offset += 8;
-        let lp_fee_basis_points = read_u64_unchecked(data, offset);
-        offset += 8;
-        let lp_fee = read_u64_unchecked(data, offset);
-        offset += 8;
-        let protocol_fee_basis_points = read_u64_unchecked(data, offset);
-        offset += 8;
-        let protocol_fee = read_u64_unchecked(data, offset);
-        offset += 8;
-        let quote_amount_out_without_lp_fee = read_u64_unchecked(data, offset);
-        offset += 8;
-        let user_quote_amount_out = read_u64_unchecked(data, offset);
-        offset += 8;
-
-        // 解析 Pubkey 字段
-        let pool = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let user = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let user_base_token_account = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let user_quote_token_account = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let protocol_fee_recipient = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let protocol_fee_recipient_token_account = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let coin_creator = read_pubkey_unchecked(data, offset);
-        offset += 32;
-
-        let coin_creator_fee_basis_points = read_u64_unchecked(data, offset);
-        offset += 8;
-        let coin_creator_fee = read_u64_unchecked(data, offset);
-
-        Some(DexEvent::PumpSwapSell(PumpSwapSellEvent {
-            metadata,
-            timestamp,
-            base_amount_in,
-            min_quote_amount_out,
-            user_base_token_reserves,
-            user_quote_token_reserves,
-            pool_base_token_reserves,
-            pool_quote_token_reserves,
-            quote_amount_out,
-            lp_fee_basis_points,
-            lp_fee,
-            protocol_fee_basis_points,
-            protocol_fee,
-            quote_amount_out_without_lp_fee,
-            user_quote_amount_out,
-            pool,
-            user,
-            user_base_token_account,
-            user_quote_token_account,
-            protocol_fee_recipient,
-            protocol_fee_recipient_token_account,
-            coin_creator,
-            coin_creator_fee_basis_points,
-            coin_creator_fee,
-            is_pump_pool: true,
-            ..Default::default()
-        }))
+    const MIN_SIZE: usize = 8 * 16 + 32 * 7;
+    if !check_length(data, MIN_SIZE) {
+        return None;
     }
+
+    let mut offset = 0;
+
+    // 解析数值字段
+    let timestamp = zc_read!(i64, data, offset);
+    offset += 8;
+    let base_amount_in = zc_read!(u64, data, offset);
+    offset += 8;
+    let min_quote_amount_out = zc_read!(u64, data, offset);
+    offset += 8;
+    let user_base_token_reserves = zc_read!(u64, data, offset);
+    offset += 8;
+    let user_quote_token_reserves = zc_read!(u64, data, offset);
+    offset += 8;
+    let pool_base_token_reserves = zc_read!(u64, data, offset);
+    offset += 8;
+    let pool_quote_token_reserves = zc_read!(u64, data, offset);
+    offset += 8;
+    let quote_amount_out = zc_read!(u64, data, offset);
+    offset += 8;
+    let lp_fee_basis_points = zc_read!(u64, data, offset);
+    offset += 8;
+    let lp_fee = zc_read!(u64, data, offset);
+    offset += 8;
+    let protocol_fee_basis_points = zc_read!(u64, data, offset);
+    offset += 8;
+    let protocol_fee = zc_read!(u64, data, offset);
+    offset += 8;
+    let quote_amount_out_without_lp_fee = zc_read!(u64, data, offset);
+    offset += 8;
+    let user_quote_amount_out = zc_read!(u64, data, offset);
+    offset += 8;
+
+    // 解析 Pubkey 字段
+    let pool = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let user = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let user_base_token_account = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let user_quote_token_account = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let protocol_fee_recipient = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let protocol_fee_recipient_token_account = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let coin_creator = zc_read!(pubkey, data, offset);
+    offset += 32;
+
+    let coin_creator_fee_basis_points = zc_read!(u64, data, offset);
+    offset += 8;
+    let coin_creator_fee = zc_read!(u64, data, offset);
+
+    Some(DexEvent::PumpSwapSell(PumpSwapSellEvent {
+        metadata,
+        timestamp,
+        base_amount_in,
+        min_quote_amount_out,
+        user_base_token_reserves,
+        user_quote_token_reserves,
+        pool_base_token_reserves,
+        pool_quote_token_reserves,
+        quote_amount_out,
+        lp_fee_basis_points,
+        lp_fee,
+        protocol_fee_basis_points,
+        protocol_fee,
+        quote_amount_out_without_lp_fee,
+        user_quote_amount_out,
+        pool,
+        user,
+        user_base_token_account,
+        user_quote_token_account,
+        protocol_fee_recipient,
+        protocol_fee_recipient_token_account,
+        coin_creator,
+        coin_creator_fee_basis_points,
+        coin_creator_fee,
+        is_pump_pool: true,
+        ..Default::default()
+    }))
 }
 
 /// 解析 CreatePool 事件
 #[inline(always)]
 fn parse_create_pool_inner(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-    unsafe {
-        if !check_length(data, 32 + 32 + 32 + 32 + 8 + 8) {
-            return None;
-        }
-
-        let mut offset = 0;
-        let pool = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let creator = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let base_mint = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let quote_mint = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let base_amount = read_u64_unchecked(data, offset);
-        offset += 8;
-        let quote_amount = read_u64_unchecked(data, offset);
-
-        Some(DexEvent::PumpSwapCreatePool(PumpSwapCreatePoolEvent {
-            metadata,
-            pool,
-            creator,
-            base_mint,
-            quote_mint,
-            base_amount_in: base_amount,
-            quote_amount_in: quote_amount,
-            ..Default::default()
-        }))
+    if !check_length(data, 32 + 32 + 32 + 32 + 8 + 8) {
+        return None;
     }
+
+    let mut offset = 0;
+    let pool = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let creator = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let base_mint = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let quote_mint = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let base_amount = zc_read!(u64, data, offset);
+    offset += 8;
+    let quote_amount = zc_read!(u64, data, offset);
+
+    Some(DexEvent::PumpSwapCreatePool(PumpSwapCreatePoolEvent {
+        metadata,
+        pool,
+        creator,
+        base_mint,
+        quote_mint,
+        base_amount_in: base_amount,
+        quote_amount_in: quote_amount,
+        ..Default::default()
+    }))
 }
 
 /// 解析 AddLiquidity 事件
 #[inline(always)]
 fn parse_add_liquidity_inner(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-    unsafe {
-        if !check_length(data, 32 + 32 + 8 + 8 + 8) {
-            return None;
-        }
-
-        let mut offset = 0;
-        let _pool = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let _user = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let base_amount = read_u64_unchecked(data, offset);
-        offset += 8;
-        let quote_amount = read_u64_unchecked(data, offset);
-        offset += 8;
-        let lp_amount = read_u64_unchecked(data, offset);
-
-        Some(DexEvent::PumpSwapLiquidityAdded(PumpSwapLiquidityAdded {
-            metadata,
-            base_amount_in: base_amount,
-            quote_amount_in: quote_amount,
-            lp_token_amount_out: lp_amount,
-            ..Default::default()
-        }))
+    if !check_length(data, 32 + 32 + 8 + 8 + 8) {
+        return None;
     }
+
+    let mut offset = 0;
+    let _pool = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let _user = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let base_amount = zc_read!(u64, data, offset);
+    offset += 8;
+    let quote_amount = zc_read!(u64, data, offset);
+    offset += 8;
+    let lp_amount = zc_read!(u64, data, offset);
+
+    Some(DexEvent::PumpSwapLiquidityAdded(PumpSwapLiquidityAdded {
+        metadata,
+        base_amount_in: base_amount,
+        quote_amount_in: quote_amount,
+        lp_token_amount_out: lp_amount,
+        ..Default::default()
+    }))
 }
 
 /// 解析 RemoveLiquidity 事件
 #[inline(always)]
 fn parse_remove_liquidity_inner(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-    unsafe {
-        if !check_length(data, 32 + 32 + 8 + 8 + 8) {
-            return None;
-        }
-
-        let mut offset = 0;
-        let _pool = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let _user = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let lp_amount = read_u64_unchecked(data, offset);
-        offset += 8;
-        let base_amount_out = read_u64_unchecked(data, offset);
-        offset += 8;
-        let quote_amount_out = read_u64_unchecked(data, offset);
-
-        Some(DexEvent::PumpSwapLiquidityRemoved(PumpSwapLiquidityRemoved {
-            metadata,
-            lp_token_amount_in: lp_amount,
-            base_amount_out,
-            quote_amount_out,
-            ..Default::default()
-        }))
+    if !check_length(data, 32 + 32 + 8 + 8 + 8) {
+        return None;
     }
+
+    let mut offset = 0;
+    let _pool = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let _user = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let lp_amount = zc_read!(u64, data, offset);
+    offset += 8;
+    let base_amount_out = zc_read!(u64, data, offset);
+    offset += 8;
+    let quote_amount_out = zc_read!(u64, data, offset);
+
+    Some(DexEvent::PumpSwapLiquidityRemoved(PumpSwapLiquidityRemoved {
+        metadata,
+        lp_token_amount_in: lp_amount,
+        base_amount_out,
+        quote_amount_out,
+        ..Default::default()
+    }))
+}
+
+/// 解析管理类事件（费率配置 / disable / coin creator 变更 / 创作者手续费领取）
+///
+/// 事件数据布局与日志中的 "Program data:" 一致，直接复用日志解析器
+#[inline(always)]
+fn parse_admin_inner(
+    discriminator: &[u8; 16],
+    data: &[u8],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    let event_discriminator = u64::from_le_bytes(discriminator[8..16].try_into().ok()?);
+    crate::logs::pump_amm::parse_admin_from_data(event_discriminator, data, metadata)
 }
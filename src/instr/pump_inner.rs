@@ -28,7 +28,9 @@
 //! ```
 
 use crate::core::events::*;
-
+use crate::core::layout::PUMPFUN_TRADE_EVENT;
+#[cfg(feature = "parse-zero-copy")]
+use crate::instr::inner_common::*;
 
 // ============================================================================
 // Inner Instruction Discriminators (16 bytes)
@@ -56,64 +58,6 @@ pub mod discriminators {
     ];
 }
 
-// ============================================================================
-// 零拷贝读取函数（仅用于 zero-copy 解析器）
-// ============================================================================
-
-#[cfg(feature = "parse-zero-copy")]
-#[inline(always)]
-unsafe fn read_u64_unchecked(data: &[u8], offset: usize) -> u64 {
-    let ptr = data.as_ptr().add(offset) as *const u64;
-    u64::from_le(ptr.read_unaligned())
-}
-
-#[cfg(feature = "parse-zero-copy")]
-#[inline(always)]
-unsafe fn read_i64_unchecked(data: &[u8], offset: usize) -> i64 {
-    let ptr = data.as_ptr().add(offset) as *const i64;
-    i64::from_le(ptr.read_unaligned())
-}
-
-#[cfg(feature = "parse-zero-copy")]
-#[inline(always)]
-unsafe fn read_bool_unchecked(data: &[u8], offset: usize) -> bool {
-    *data.get_unchecked(offset) == 1
-}
-
-#[cfg(feature = "parse-zero-copy")]
-#[inline(always)]
-unsafe fn read_pubkey_unchecked(data: &[u8], offset: usize) -> solana_sdk::pubkey::Pubkey {
-    use solana_sdk::pubkey::Pubkey;
-    let ptr = data.as_ptr().add(offset);
-    let mut bytes = [0u8; 32];
-    std::ptr::copy_nonoverlapping(ptr, bytes.as_mut_ptr(), 32);
-    Pubkey::new_from_array(bytes)
-}
-
-#[cfg(feature = "parse-zero-copy")]
-#[inline(always)]
-unsafe fn read_str_unchecked(data: &[u8], offset: usize) -> Option<(&str, usize)> {
-    if data.len() < offset + 4 {
-        return None;
-    }
-
-    let len = read_u32_unchecked(data, offset) as usize;
-    if data.len() < offset + 4 + len {
-        return None;
-    }
-
-    let string_bytes = &data[offset + 4..offset + 4 + len];
-    let s = std::str::from_utf8_unchecked(string_bytes);
-    Some((s, 4 + len))
-}
-
-#[cfg(feature = "parse-zero-copy")]
-#[inline(always)]
-unsafe fn read_u32_unchecked(data: &[u8], offset: usize) -> u32 {
-    let ptr = data.as_ptr().add(offset) as *const u32;
-    u32::from_le(ptr.read_unaligned())
-}
-
 // ============================================================================
 // Inner Instruction 解析函数
 // ============================================================================
@@ -134,7 +78,9 @@ pub fn parse_pumpfun_inner_instruction(
     metadata: EventMetadata,
 ) -> Option<DexEvent> {
     match discriminator {
-        &discriminators::TRADE_EVENT => parse_trade_event_inner(data, metadata),
+        &discriminators::TRADE_EVENT => {
+            parse_trade_event_inner(&PUMPFUN_TRADE_EVENT.upgrade(data), metadata)
+        }
         &discriminators::CREATE_TOKEN_EVENT => parse_create_event_inner(data, metadata),
         &discriminators::COMPLETE_PUMP_AMM_MIGRATION_EVENT => parse_migrate_event_inner(data, metadata),
         _ => None,
@@ -187,144 +133,142 @@ fn parse_trade_event_inner_borsh(data: &[u8], metadata: EventMetadata) -> Option
 #[cfg(feature = "parse-zero-copy")]
 #[inline(always)]
 fn parse_trade_event_inner_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-    unsafe {
-        // 快速边界检查
-        if data.len() < 32 + 8 + 8 + 1 + 32 + 8 + 8 + 8 + 8 + 8 + 32 + 8 + 8 + 32 + 8 + 8 {
-            return None;
-        }
+    // 快速边界检查
+    if data.len() < 32 + 8 + 8 + 1 + 32 + 8 + 8 + 8 + 8 + 8 + 32 + 8 + 8 + 32 + 8 + 8 {
+        return None;
+    }
 
-        let mut offset = 0;
+    let mut offset = 0;
 
-        let mint = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let mint = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let sol_amount = read_u64_unchecked(data, offset);
-        offset += 8;
+    let sol_amount = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let token_amount = read_u64_unchecked(data, offset);
-        offset += 8;
+    let token_amount = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let is_buy = read_bool_unchecked(data, offset);
-        offset += 1;
+    let is_buy = zc_read!(bool, data, offset);
+    offset += 1;
 
-        let user = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let user = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let timestamp = read_i64_unchecked(data, offset);
-        offset += 8;
+    let timestamp = zc_read!(i64, data, offset);
+    offset += 8;
 
-        let virtual_sol_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
+    let virtual_sol_reserves = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let virtual_token_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
+    let virtual_token_reserves = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let real_sol_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
+    let real_sol_reserves = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let real_token_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
+    let real_token_reserves = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let fee_recipient = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let fee_recipient = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let fee_basis_points = read_u64_unchecked(data, offset);
-        offset += 8;
+    let fee_basis_points = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let fee = read_u64_unchecked(data, offset);
-        offset += 8;
+    let fee = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let creator = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let creator = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let creator_fee_basis_points = read_u64_unchecked(data, offset);
-        offset += 8;
+    let creator_fee_basis_points = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let creator_fee = read_u64_unchecked(data, offset);
-        offset += 8;
+    let creator_fee = zc_read!(u64, data, offset);
+    offset += 8;
 
-        // 可选字段
-        let track_volume = if offset < data.len() {
-            read_bool_unchecked(data, offset)
-        } else {
-            false
-        };
-        offset += 1;
+    // 可选字段
+    let track_volume = if offset < data.len() {
+        zc_read!(bool, data, offset)
+    } else {
+        false
+    };
+    offset += 1;
 
-        let total_unclaimed_tokens = if offset + 8 <= data.len() {
-            read_u64_unchecked(data, offset)
-        } else {
-            0
-        };
-        offset += 8;
+    let total_unclaimed_tokens = if offset + 8 <= data.len() {
+        zc_read!(u64, data, offset)
+    } else {
+        0
+    };
+    offset += 8;
 
-        let total_claimed_tokens = if offset + 8 <= data.len() {
-            read_u64_unchecked(data, offset)
-        } else {
-            0
-        };
-        offset += 8;
+    let total_claimed_tokens = if offset + 8 <= data.len() {
+        zc_read!(u64, data, offset)
+    } else {
+        0
+    };
+    offset += 8;
 
-        let current_sol_volume = if offset + 8 <= data.len() {
-            read_u64_unchecked(data, offset)
-        } else {
-            0
-        };
-        offset += 8;
+    let current_sol_volume = if offset + 8 <= data.len() {
+        zc_read!(u64, data, offset)
+    } else {
+        0
+    };
+    offset += 8;
 
-        let last_update_timestamp = if offset + 8 <= data.len() {
-            read_i64_unchecked(data, offset)
-        } else {
-            0
-        };
-        offset += 8;
-
-        let ix_name = if offset + 4 <= data.len() {
-            if let Some((s, _)) = read_str_unchecked(data, offset) {
-                s.to_string()
-            } else {
-                String::new()
-            }
+    let last_update_timestamp = if offset + 8 <= data.len() {
+        zc_read!(i64, data, offset)
+    } else {
+        0
+    };
+    offset += 8;
+
+    let ix_name = if offset + 4 <= data.len() {
+        if let Some((s, _)) = zc_read!(str, data, offset) {
+            s.to_string()
         } else {
             String::new()
-        };
-
-        // Inner instruction 只包含日志数据，不含指令上下文账户
-        let trade_event = PumpFunTradeEvent {
-            metadata,
-            mint,
-            sol_amount,
-            token_amount,
-            is_buy,
-            is_created_buy: false, // 这个由外层检测设置
-            user,
-            timestamp,
-            virtual_sol_reserves,
-            virtual_token_reserves,
-            real_sol_reserves,
-            real_token_reserves,
-            fee_recipient,
-            fee_basis_points,
-            fee,
-            creator,
-            creator_fee_basis_points,
-            creator_fee,
-            track_volume,
-            total_unclaimed_tokens,
-            total_claimed_tokens,
-            current_sol_volume,
-            last_update_timestamp,
-            ix_name: ix_name.clone(),
-            ..Default::default() // 其他账户字段由 instruction 提供
-        };
-
-        // 根据 ix_name 返回不同的事件类型
-        match ix_name.as_str() {
-            "buy" => Some(DexEvent::PumpFunBuy(trade_event)),
-            "sell" => Some(DexEvent::PumpFunSell(trade_event)),
-            "buy_exact_sol_in" => Some(DexEvent::PumpFunBuyExactSolIn(trade_event)),
-            _ => Some(DexEvent::PumpFunTrade(trade_event)),
         }
+    } else {
+        String::new()
+    };
+
+    // Inner instruction 只包含日志数据，不含指令上下文账户
+    let trade_event = PumpFunTradeEvent {
+        metadata,
+        mint,
+        sol_amount,
+        token_amount,
+        is_buy,
+        is_created_buy: false, // 这个由外层检测设置
+        user,
+        timestamp,
+        virtual_sol_reserves,
+        virtual_token_reserves,
+        real_sol_reserves,
+        real_token_reserves,
+        fee_recipient,
+        fee_basis_points,
+        fee,
+        creator,
+        creator_fee_basis_points,
+        creator_fee,
+        track_volume,
+        total_unclaimed_tokens,
+        total_claimed_tokens,
+        current_sol_volume,
+        last_update_timestamp,
+        ix_name: ix_name.clone(),
+        ..Default::default() // 其他账户字段由 instruction 提供
+    };
+
+    // 根据 ix_name 返回不同的事件类型
+    match ix_name.as_str() {
+        "buy" => Some(DexEvent::PumpFunBuy(trade_event)),
+        "sell" => Some(DexEvent::PumpFunSell(trade_event)),
+        "buy_exact_sol_in" => Some(DexEvent::PumpFunBuyExactSolIn(trade_event)),
+        _ => Some(DexEvent::PumpFunTrade(trade_event)),
     }
 }
 
@@ -366,80 +310,78 @@ fn parse_create_event_inner_borsh(data: &[u8], metadata: EventMetadata) -> Optio
 #[cfg(feature = "parse-zero-copy")]
 #[inline(always)]
 fn parse_create_event_inner_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-    unsafe {
-        let mut offset = 0;
-
-        let (name, name_len) = read_str_unchecked(data, offset)?;
-        offset += name_len;
-
-        let (symbol, symbol_len) = read_str_unchecked(data, offset)?;
-        offset += symbol_len;
+    let mut offset = 0;
 
-        let (uri, uri_len) = read_str_unchecked(data, offset)?;
-        offset += uri_len;
-
-        if data.len() < offset + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 32 + 1 {
-            return None;
-        }
+    let (name, name_len) = zc_read!(str, data, offset)?;
+    offset += name_len;
 
-        let mint = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let (symbol, symbol_len) = zc_read!(str, data, offset)?;
+    offset += symbol_len;
 
-        let bonding_curve = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let (uri, uri_len) = zc_read!(str, data, offset)?;
+    offset += uri_len;
 
-        let user = read_pubkey_unchecked(data, offset);
-        offset += 32;
-
-        let creator = read_pubkey_unchecked(data, offset);
-        offset += 32;
-
-        let timestamp = read_i64_unchecked(data, offset);
-        offset += 8;
-
-        let virtual_token_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
-
-        let virtual_sol_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
-
-        let real_token_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
-
-        let token_total_supply = read_u64_unchecked(data, offset);
-        offset += 8;
-
-        let token_program = if offset + 32 <= data.len() {
-            read_pubkey_unchecked(data, offset)
-        } else {
-            solana_sdk::pubkey::Pubkey::default()
-        };
-        offset += 32;
-
-        let is_mayhem_mode = if offset < data.len() {
-            read_bool_unchecked(data, offset)
-        } else {
-            false
-        };
-
-        Some(DexEvent::PumpFunCreate(PumpFunCreateTokenEvent {
-            metadata,
-            name: name.to_string(),
-            symbol: symbol.to_string(),
-            uri: uri.to_string(),
-            mint,
-            bonding_curve,
-            user,
-            creator,
-            timestamp,
-            virtual_token_reserves,
-            virtual_sol_reserves,
-            real_token_reserves,
-            token_total_supply,
-            token_program,
-            is_mayhem_mode,
-        }))
+    if data.len() < offset + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 32 + 1 {
+        return None;
     }
+
+    let mint = zc_read!(pubkey, data, offset);
+    offset += 32;
+
+    let bonding_curve = zc_read!(pubkey, data, offset);
+    offset += 32;
+
+    let user = zc_read!(pubkey, data, offset);
+    offset += 32;
+
+    let creator = zc_read!(pubkey, data, offset);
+    offset += 32;
+
+    let timestamp = zc_read!(i64, data, offset);
+    offset += 8;
+
+    let virtual_token_reserves = zc_read!(u64, data, offset);
+    offset += 8;
+
+    let virtual_sol_reserves = zc_read!(u64, data, offset);
+    offset += 8;
+
+    let real_token_reserves = zc_read!(u64, data, offset);
+    offset += 8;
+
+    let token_total_supply = zc_read!(u64, data, offset);
+    offset += 8;
+
+    let token_program = if offset + 32 <= data.len() {
+        zc_read!(pubkey, data, offset)
+    } else {
+        solana_sdk::pubkey::Pubkey::default()
+    };
+    offset += 32;
+
+    let is_mayhem_mode = if offset < data.len() {
+        zc_read!(bool, data, offset)
+    } else {
+        false
+    };
+
+    Some(DexEvent::PumpFunCreate(PumpFunCreateTokenEvent {
+        metadata,
+        name: name.to_string(),
+        symbol: symbol.to_string(),
+        uri: uri.to_string(),
+        mint,
+        bonding_curve,
+        user,
+        creator,
+        timestamp,
+        virtual_token_reserves,
+        virtual_sol_reserves,
+        real_token_reserves,
+        token_total_supply,
+        token_program,
+        is_mayhem_mode,
+    }))
 }
 
 // ============================================================================
@@ -486,48 +428,46 @@ fn parse_migrate_event_inner_borsh(data: &[u8], metadata: EventMetadata) -> Opti
 #[cfg(feature = "parse-zero-copy")]
 #[inline(always)]
 fn parse_migrate_event_inner_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-    unsafe {
-        if data.len() < 32 + 32 + 8 + 8 + 8 + 32 + 8 + 32 {
-            return None;
-        }
+    if data.len() < 32 + 32 + 8 + 8 + 8 + 32 + 8 + 32 {
+        return None;
+    }
 
-        let mut offset = 0;
+    let mut offset = 0;
 
-        let user = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let user = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let mint = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let mint = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let mint_amount = read_u64_unchecked(data, offset);
-        offset += 8;
+    let mint_amount = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let sol_amount = read_u64_unchecked(data, offset);
-        offset += 8;
+    let sol_amount = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let pool_migration_fee = read_u64_unchecked(data, offset);
-        offset += 8;
+    let pool_migration_fee = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let bonding_curve = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let bonding_curve = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let timestamp = read_i64_unchecked(data, offset);
-        offset += 8;
+    let timestamp = zc_read!(i64, data, offset);
+    offset += 8;
 
-        let pool = read_pubkey_unchecked(data, offset);
+    let pool = zc_read!(pubkey, data, offset);
 
-        Some(DexEvent::PumpFunMigrate(PumpFunMigrateEvent {
-            metadata,
-            user,
-            mint,
-            mint_amount,
-            sol_amount,
-            pool_migration_fee,
-            bonding_curve,
-            timestamp,
-            pool,
-        }))
-    }
+    Some(DexEvent::PumpFunMigrate(PumpFunMigrateEvent {
+        metadata,
+        user,
+        mint,
+        mint_amount,
+        sol_amount,
+        pool_migration_fee,
+        bonding_curve,
+        timestamp,
+        pool,
+    }))
 }
 
 #[cfg(test)]
@@ -551,6 +491,7 @@ mod tests {
             tx_index: 0,
             block_time_us: 0,
             grpc_recv_us: 0,
+            ..Default::default()
         };
 
         let short_data = vec![0u8; 10];
@@ -18,6 +18,9 @@ pub mod discriminators {
     pub const OPEN_POSITION_V2: [u8; 8] = [77, 184, 74, 214, 112, 86, 241, 199];
     pub const OPEN_POSITION_WITH_TOKEN_22_NFT: [u8; 8] = [77, 255, 174, 82, 125, 29, 201, 46];
     pub const CLOSE_POSITION: [u8; 8] = [123, 134, 81, 0, 49, 68, 98, 98];
+    pub const INITIALIZE_REWARD: [u8; 8] = [95, 135, 192, 196, 242, 129, 230, 68];
+    pub const COLLECT_REMAINING_REWARDS: [u8; 8] = [18, 237, 166, 197, 34, 16, 213, 144];
+    pub const SET_REWARD_PARAMS: [u8; 8] = [112, 52, 167, 75, 32, 201, 211, 137];
 }
 
 /// Raydium CLMM 程序 ID
@@ -68,7 +71,81 @@ pub fn parse_instruction(
     }
 }
 
+/// 解析奖励指令（`initialize_reward` / `collect_remaining_rewards` / `set_reward_params`）
+///
+/// 奖励配置变更不发出日志事件，只能按程序 ID 从指令解析。其他指令返回 None。
+pub fn parse_reward_instruction(
+    instruction_data: &[u8],
+    accounts: &[Pubkey],
+    signature: Signature,
+    slot: u64,
+    tx_index: u64,
+    block_time_us: Option<i64>,
+) -> Option<DexEvent> {
+    let discriminator: [u8; 8] = instruction_data.get(0..8)?.try_into().ok()?;
+    let data = &instruction_data[8..];
+    let account = |i: usize| get_account(accounts, i).unwrap_or_default();
+
+    match discriminator {
+        discriminators::INITIALIZE_REWARD => {
+            // accounts: reward_funder, funder_token_account, amm_config, pool_state,
+            // operation_state, reward_token_mint, reward_token_vault, ...
+            let pool_state = get_account(accounts, 3)?;
+            let metadata =
+                create_metadata_simple(signature, slot, tx_index, block_time_us, pool_state);
+            Some(DexEvent::RaydiumClmmInitializeReward(RaydiumClmmInitializeRewardEvent {
+                metadata,
+                pool_state,
+                reward_funder: account(0),
+                reward_token_mint: account(5),
+                reward_token_vault: account(6),
+                open_time: read_u64_le(data, 0)?,
+                end_time: read_u64_le(data, 8)?,
+                emissions_per_second_x64: read_u128_le(data, 16)?,
+            }))
+        }
+        discriminators::COLLECT_REMAINING_REWARDS => {
+            // accounts: reward_funder, funder_token_account, pool_state, reward_token_vault,
+            // reward_vault_mint, ...
+            let pool_state = get_account(accounts, 2)?;
+            let metadata =
+                create_metadata_simple(signature, slot, tx_index, block_time_us, pool_state);
+            Some(DexEvent::RaydiumClmmCollectReward(RaydiumClmmCollectRewardEvent {
+                metadata,
+                pool_state,
+                reward_funder: account(0),
+                reward_token_vault: account(3),
+                reward_token_mint: account(4),
+                reward_index: read_u8(data, 0)?,
+            }))
+        }
+        discriminators::SET_REWARD_PARAMS => {
+            // accounts: authority, amm_config, pool_state, operation_state, ...
+            let pool_state = get_account(accounts, 2)?;
+            let metadata =
+                create_metadata_simple(signature, slot, tx_index, block_time_us, pool_state);
+            Some(DexEvent::RaydiumClmmSetRewardParams(RaydiumClmmSetRewardParamsEvent {
+                metadata,
+                pool_state,
+                authority: account(0),
+                amm_config: account(1),
+                reward_index: read_u8(data, 0)?,
+                emissions_per_second_x64: read_u128_le(data, 1)?,
+                open_time: read_u64_le(data, 17)?,
+                end_time: read_u64_le(data, 25)?,
+            }))
+        }
+        _ => None,
+    }
+}
+
 /// 解析交换指令
+///
+/// swap 与 swap_v2 参数相同，前 8 个账户一致：
+/// 0: payer, 1: ammConfig, 2: poolState, 3: inputTokenAccount, 4: outputTokenAccount,
+/// 5: inputVault, 6: outputVault, 7: observationState。
+/// swap_v2 之后为 tokenProgram、tokenProgram2022、memoProgram、inputVaultMint、outputVaultMint。
+/// 成交数量和 Token-2022 转账手续费只在 SwapEvent 日志中，由日志事件合并填充。
 fn parse_swap_instruction(
     data: &[u8],
     accounts: &[Pubkey],
@@ -85,12 +162,12 @@ fn parse_swap_instruction(
     let _other_amount_threshold = read_u64_le(data, offset)?;
     offset += 8;
 
-    let sqrt_price_limit_x64 = read_u64_le(data, offset)? as u128;
-    offset += 8;
+    let sqrt_price_limit_x64 = read_u128_le(data, offset)?;
+    offset += 16;
 
     let is_base_input = data.get(offset)? == &1;
 
-    let pool = get_account(accounts, 0)?;
+    let pool = get_account(accounts, 2)?;
     let metadata = create_metadata_simple(signature, slot, tx_index, block_time_us, pool);
 
     Some(DexEvent::RaydiumClmmSwap(RaydiumClmmSwapEvent {
@@ -98,7 +175,7 @@ fn parse_swap_instruction(
 
         // IDL SwapEvent 事件字段
         pool_state: pool,
-        sender: get_account(accounts, 1).unwrap_or_default(),
+        sender: get_account(accounts, 0).unwrap_or_default(),
         token_account_0: Pubkey::default(),
         token_account_1: Pubkey::default(),
         amount_0: 0, // 从日志填充
@@ -119,7 +196,7 @@ fn parse_swap_instruction(
     }))
 }
 
-/// 解析 Swap V2 指令（支持 Token2022）
+/// 解析 Swap V2 指令（支持 Token-2022）
 fn parse_swap_v2_instruction(
     data: &[u8],
     accounts: &[Pubkey],
@@ -319,3 +396,90 @@ fn parse_open_position_with_token_22_nft_instruction(
     // Token22 NFT 版本与 V2 参数相同
     parse_open_position_v2_instruction(data, accounts, signature, slot, tx_index, block_time_us)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reward_instructions_routed_by_program_id() {
+        let accs: Vec<Pubkey> = (0..10).map(|_| Pubkey::new_unique()).collect();
+        let program_id = solana_sdk::pubkey!("CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK");
+        let parse = |data: &[u8]| {
+            crate::instr::parse_instruction_unified(
+                data,
+                &accs,
+                Signature::default(),
+                1,
+                0,
+                Some(0),
+                0,
+                None,
+                &program_id,
+            )
+        };
+
+        let mut data = discriminators::INITIALIZE_REWARD.to_vec();
+        data.extend_from_slice(&100u64.to_le_bytes());
+        data.extend_from_slice(&200u64.to_le_bytes());
+        data.extend_from_slice(&(5u128 << 64).to_le_bytes());
+        match parse(&data) {
+            Some(DexEvent::RaydiumClmmInitializeReward(e)) => {
+                assert_eq!((e.pool_state, e.reward_funder), (accs[3], accs[0]));
+                assert_eq!((e.reward_token_mint, e.reward_token_vault), (accs[5], accs[6]));
+                assert_eq!((e.open_time, e.end_time), (100, 200));
+                assert_eq!(e.emissions_per_second_x64, 5 << 64);
+            }
+            other => panic!("{:?}", other),
+        }
+
+        let mut data = discriminators::COLLECT_REMAINING_REWARDS.to_vec();
+        data.push(2);
+        match parse(&data) {
+            Some(DexEvent::RaydiumClmmCollectReward(e)) => {
+                assert_eq!((e.pool_state, e.reward_token_mint), (accs[2], accs[4]));
+                assert_eq!(e.reward_index, 2);
+            }
+            other => panic!("{:?}", other),
+        }
+
+        let mut data = discriminators::SET_REWARD_PARAMS.to_vec();
+        data.push(1);
+        data.extend_from_slice(&7u128.to_le_bytes());
+        data.extend_from_slice(&300u64.to_le_bytes());
+        data.extend_from_slice(&400u64.to_le_bytes());
+        match parse(&data) {
+            Some(DexEvent::RaydiumClmmSetRewardParams(e)) => {
+                assert_eq!((e.pool_state, e.authority, e.amm_config), (accs[2], accs[0], accs[1]));
+                assert_eq!((e.reward_index, e.emissions_per_second_x64), (1, 7));
+                assert_eq!((e.open_time, e.end_time), (300, 400));
+            }
+            other => panic!("{:?}", other),
+        }
+
+        // swap 等事件来自日志，指令不重复输出
+        assert!(parse(&discriminators::SWAP_V2).is_none());
+    }
+
+    #[test]
+    fn test_swap_and_swap_v2_instructions() {
+        let mut args = 1_000u64.to_le_bytes().to_vec();
+        args.extend_from_slice(&900u64.to_le_bytes());
+        args.extend_from_slice(&(1u128 << 64).to_le_bytes());
+        args.push(1);
+
+        // swap_v2 多出 Token-2022 程序、memo 程序和两侧 vault mint
+        for (discriminator, account_count) in [(discriminators::SWAP, 10), (discriminators::SWAP_V2, 13)] {
+            let accs: Vec<Pubkey> = (0..account_count).map(|_| Pubkey::new_unique()).collect();
+            let mut data = discriminator.to_vec();
+            data.extend_from_slice(&args);
+            match parse_instruction(&data, &accs, Signature::default(), 1, 0, Some(0)) {
+                Some(DexEvent::RaydiumClmmSwap(e)) => {
+                    assert_eq!((e.pool_state, e.sender), (accs[2], accs[0]));
+                    assert_eq!(e.sqrt_price_x64, 1 << 64);
+                }
+                other => panic!("{:?}", other),
+            }
+        }
+    }
+}
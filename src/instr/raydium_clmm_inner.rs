@@ -15,6 +15,10 @@
 //! - **适用**: 性能关键路径、每秒数万次解析的场景
 
 use crate::core::events::*;
+#[cfg(feature = "parse-zero-copy")]
+use crate::instr::inner_common::*;
+#[cfg(feature = "parse-zero-copy")]
+use solana_sdk::pubkey::Pubkey;
 
 /// Raydium CLMM inner instruction discriminators (16 bytes)
 pub mod discriminators {
@@ -117,44 +121,42 @@ fn parse_swap_inner_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<De
     // zero_for_one: bool (1 byte)
     // sqrt_price_x64: u128 (16 bytes)
     // liquidity: u128 (16 bytes)
-    unsafe {
-        if !check_length(data, 32 + 32 + 32 + 8 + 8 + 1 + 16 + 16) {
-            return None;
-        }
-
-        let mut offset = 0;
-        let pool_id = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let input_vault = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let output_vault = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let input_amount = read_u64_unchecked(data, offset);
-        offset += 8;
-        let output_amount = read_u64_unchecked(data, offset);
-        offset += 8;
-        let zero_for_one = read_bool_unchecked(data, offset);
-        offset += 1;
-        let sqrt_price_x64 = read_u128_unchecked(data, offset);
-        offset += 16;
-        let liquidity = read_u128_unchecked(data, offset);
-
-        Some(DexEvent::RaydiumClmmSwap(RaydiumClmmSwapEvent {
-            metadata,
-            pool_state: pool_id,
-            sender: Pubkey::default(),
-            token_account_0: input_vault,
-            token_account_1: output_vault,
-            amount_0: input_amount,
-            transfer_fee_0: 0,
-            amount_1: output_amount,
-            transfer_fee_1: 0,
-            zero_for_one,
-            sqrt_price_x64,
-            liquidity,
-            tick: 0,
-        }))
+    if !check_length(data, 32 + 32 + 32 + 8 + 8 + 1 + 16 + 16) {
+        return None;
     }
+
+    let mut offset = 0;
+    let pool_id = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let input_vault = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let output_vault = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let input_amount = zc_read!(u64, data, offset);
+    offset += 8;
+    let output_amount = zc_read!(u64, data, offset);
+    offset += 8;
+    let zero_for_one = zc_read!(bool, data, offset);
+    offset += 1;
+    let sqrt_price_x64 = zc_read!(u128, data, offset);
+    offset += 16;
+    let liquidity = zc_read!(u128, data, offset);
+
+    Some(DexEvent::RaydiumClmmSwap(RaydiumClmmSwapEvent {
+        metadata,
+        pool_state: pool_id,
+        sender: Pubkey::default(),
+        token_account_0: input_vault,
+        token_account_1: output_vault,
+        amount_0: input_amount,
+        transfer_fee_0: 0,
+        amount_1: output_amount,
+        transfer_fee_1: 0,
+        zero_for_one,
+        sqrt_price_x64,
+        liquidity,
+        tick: 0,
+    }))
 }
 
 // ============================================================================
@@ -207,32 +209,30 @@ fn parse_increase_liquidity_inner_zero_copy(
     data: &[u8],
     metadata: EventMetadata,
 ) -> Option<DexEvent> {
-    unsafe {
-        if !check_length(data, 32 + 32 + 8 + 8 + 16) {
-            return None;
-        }
-
-        let mut offset = 0;
-        let pool_id = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let position = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let token_0_amount = read_u64_unchecked(data, offset);
-        offset += 8;
-        let token_1_amount = read_u64_unchecked(data, offset);
-        offset += 8;
-        let liquidity = read_u128_unchecked(data, offset);
-
-        Some(DexEvent::RaydiumClmmIncreaseLiquidity(RaydiumClmmIncreaseLiquidityEvent {
-            metadata,
-            pool: pool_id,
-            position_nft_mint: position,
-            user: Pubkey::default(),
-            liquidity,
-            amount0_max: token_0_amount,
-            amount1_max: token_1_amount,
-        }))
+    if !check_length(data, 32 + 32 + 8 + 8 + 16) {
+        return None;
     }
+
+    let mut offset = 0;
+    let pool_id = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let position = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let token_0_amount = zc_read!(u64, data, offset);
+    offset += 8;
+    let token_1_amount = zc_read!(u64, data, offset);
+    offset += 8;
+    let liquidity = zc_read!(u128, data, offset);
+
+    Some(DexEvent::RaydiumClmmIncreaseLiquidity(RaydiumClmmIncreaseLiquidityEvent {
+        metadata,
+        pool: pool_id,
+        position_nft_mint: position,
+        user: Pubkey::default(),
+        liquidity,
+        amount0_max: token_0_amount,
+        amount1_max: token_1_amount,
+    }))
 }
 
 // ============================================================================
@@ -285,32 +285,30 @@ fn parse_decrease_liquidity_inner_zero_copy(
     data: &[u8],
     metadata: EventMetadata,
 ) -> Option<DexEvent> {
-    unsafe {
-        if !check_length(data, 32 + 32 + 8 + 8 + 16) {
-            return None;
-        }
-
-        let mut offset = 0;
-        let pool_id = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let position = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let token_0_amount = read_u64_unchecked(data, offset);
-        offset += 8;
-        let token_1_amount = read_u64_unchecked(data, offset);
-        offset += 8;
-        let liquidity = read_u128_unchecked(data, offset);
-
-        Some(DexEvent::RaydiumClmmDecreaseLiquidity(RaydiumClmmDecreaseLiquidityEvent {
-            metadata,
-            pool: pool_id,
-            position_nft_mint: position,
-            user: Pubkey::default(),
-            liquidity,
-            amount0_min: token_0_amount,
-            amount1_min: token_1_amount,
-        }))
+    if !check_length(data, 32 + 32 + 8 + 8 + 16) {
+        return None;
     }
+
+    let mut offset = 0;
+    let pool_id = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let position = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let token_0_amount = zc_read!(u64, data, offset);
+    offset += 8;
+    let token_1_amount = zc_read!(u64, data, offset);
+    offset += 8;
+    let liquidity = zc_read!(u128, data, offset);
+
+    Some(DexEvent::RaydiumClmmDecreaseLiquidity(RaydiumClmmDecreaseLiquidityEvent {
+        metadata,
+        pool: pool_id,
+        position_nft_mint: position,
+        user: Pubkey::default(),
+        liquidity,
+        amount0_min: token_0_amount,
+        amount1_min: token_1_amount,
+    }))
 }
 
 // ============================================================================
@@ -358,36 +356,34 @@ fn parse_create_pool_inner_borsh(data: &[u8], metadata: EventMetadata) -> Option
 #[cfg(feature = "parse-zero-copy")]
 #[inline(always)]
 fn parse_create_pool_inner_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-    unsafe {
-        if !check_length(data, 32 + 32 + 32 + 2 + 4 + 16) {
-            return None;
-        }
-
-        let mut offset = 0;
-        let pool_id = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let token_0_mint = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let token_1_mint = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let tick_spacing = read_u16_unchecked(data, offset);
-        offset += 2;
-        let fee_rate = read_u32_unchecked(data, offset);
-        offset += 4;
-        let sqrt_price_x64 = read_u128_unchecked(data, offset);
-
-        Some(DexEvent::RaydiumClmmCreatePool(RaydiumClmmCreatePoolEvent {
-            metadata,
-            pool: pool_id,
-            token_0_mint,
-            token_1_mint,
-            tick_spacing,
-            fee_rate,
-            creator: Pubkey::default(),
-            sqrt_price_x64,
-            open_time: 0,
-        }))
+    if !check_length(data, 32 + 32 + 32 + 2 + 4 + 16) {
+        return None;
     }
+
+    let mut offset = 0;
+    let pool_id = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let token_0_mint = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let token_1_mint = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let tick_spacing = zc_read!(u16, data, offset);
+    offset += 2;
+    let fee_rate = zc_read!(u32, data, offset);
+    offset += 4;
+    let sqrt_price_x64 = zc_read!(u128, data, offset);
+
+    Some(DexEvent::RaydiumClmmCreatePool(RaydiumClmmCreatePoolEvent {
+        metadata,
+        pool: pool_id,
+        token_0_mint,
+        token_1_mint,
+        tick_spacing,
+        fee_rate,
+        creator: Pubkey::default(),
+        sqrt_price_x64,
+        open_time: 0,
+    }))
 }
 
 // ============================================================================
@@ -433,26 +429,24 @@ fn parse_collect_fee_inner_borsh(data: &[u8], metadata: EventMetadata) -> Option
 #[cfg(feature = "parse-zero-copy")]
 #[inline(always)]
 fn parse_collect_fee_inner_zero_copy(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-    unsafe {
-        if !check_length(data, 32 + 32 + 8 + 8) {
-            return None;
-        }
-
-        let mut offset = 0;
-        let pool_id = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let position = read_pubkey_unchecked(data, offset);
-        offset += 32;
-        let token_0_fee = read_u64_unchecked(data, offset);
-        offset += 8;
-        let token_1_fee = read_u64_unchecked(data, offset);
-
-        Some(DexEvent::RaydiumClmmCollectFee(RaydiumClmmCollectFeeEvent {
-            metadata,
-            pool_state: pool_id,
-            position_nft_mint: position,
-            amount_0: token_0_fee,
-            amount_1: token_1_fee,
-        }))
+    if !check_length(data, 32 + 32 + 8 + 8) {
+        return None;
     }
+
+    let mut offset = 0;
+    let pool_id = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let position = zc_read!(pubkey, data, offset);
+    offset += 32;
+    let token_0_fee = zc_read!(u64, data, offset);
+    offset += 8;
+    let token_1_fee = zc_read!(u64, data, offset);
+
+    Some(DexEvent::RaydiumClmmCollectFee(RaydiumClmmCollectFeeEvent {
+        metadata,
+        pool_state: pool_id,
+        position_nft_mint: position,
+        amount_0: token_0_fee,
+        amount_1: token_1_fee,
+    }))
 }
@@ -12,6 +12,7 @@ pub mod discriminators {
     pub const SWAP_BASE_IN: [u8; 8] = [143, 190, 90, 218, 196, 30, 51, 222];
     pub const SWAP_BASE_OUT: [u8; 8] = [55, 217, 98, 86, 163, 74, 180, 173];
     pub const INITIALIZE: [u8; 8] = [175, 175, 109, 31, 13, 152, 155, 237];
+    pub const INITIALIZE_WITH_PERMISSION: [u8; 8] = [63, 55, 254, 65, 49, 178, 89, 121];
     pub const DEPOSIT: [u8; 8] = [242, 35, 198, 137, 82, 225, 242, 182];
     pub const WITHDRAW: [u8; 8] = [183, 18, 70, 156, 148, 109, 161, 34];
 }
@@ -42,8 +43,8 @@ pub fn parse_instruction(
         discriminators::SWAP_BASE_OUT => {
             parse_swap_base_out_instruction(data, accounts, signature, slot, tx_index, block_time_us)
         },
-        discriminators::INITIALIZE => {
-            parse_initialize_instruction(data, accounts, signature, slot, tx_index, block_time_us)
+        discriminators::INITIALIZE | discriminators::INITIALIZE_WITH_PERMISSION => {
+            parse_pool_initialize(instruction_data, accounts, signature, slot, tx_index, block_time_us)
         },
         discriminators::DEPOSIT => {
             parse_deposit_instruction(data, accounts, signature, slot, tx_index, block_time_us)
@@ -159,34 +160,48 @@ fn parse_swap_base_out_instruction(
     }))
 }
 
-/// 解析初始化指令
-fn parse_initialize_instruction(
-    data: &[u8],
+/// 解析建池指令（`initialize` / `initialize_with_permission`）
+///
+/// CPMM 的 CREATE_POOL discriminator 与 CLMM 相同且程序不发出建池日志，
+/// 建池事件只能按程序 ID 从指令解析。其他指令返回 None。
+pub fn parse_pool_initialize(
+    instruction_data: &[u8],
     accounts: &[Pubkey],
     signature: Signature,
     slot: u64,
     tx_index: u64,
     block_time_us: Option<i64>,
 ) -> Option<DexEvent> {
-    let mut offset = 0;
-
-    let init_amount0 = read_u64_le(data, offset)?;
-    offset += 8;
-
-    let init_amount1 = read_u64_le(data, offset)?;
-    offset += 8;
+    let discriminator: [u8; 8] = instruction_data.get(0..8)?.try_into().ok()?;
+    // initialize_with_permission 在最前面多一个 payer 账户
+    let shift = match discriminator {
+        discriminators::INITIALIZE => 0,
+        discriminators::INITIALIZE_WITH_PERMISSION => 1,
+        _ => return None,
+    };
+    let data = &instruction_data[8..];
 
-    let _open_time = read_u64_le(data, offset)?;
+    let init_amount0 = read_u64_le(data, 0)?;
+    let init_amount1 = read_u64_le(data, 8)?;
+    let open_time = read_u64_le(data, 16)?;
 
-    let pool = get_account(accounts, 0)?;
+    let account = |i: usize| get_account(accounts, i + shift).unwrap_or_default();
+    let pool = get_account(accounts, 3 + shift)?;
     let metadata = create_metadata_simple(signature, slot, tx_index, block_time_us, pool);
 
     Some(DexEvent::RaydiumCpmmInitialize(RaydiumCpmmInitializeEvent {
         metadata,
         pool,
-        creator: get_account(accounts, 1).unwrap_or_default(),
+        creator: account(0),
         init_amount0,
         init_amount1,
+        open_time,
+        amm_config: account(1),
+        token_0_mint: account(4),
+        token_1_mint: account(5),
+        lp_mint: account(6),
+        token_0_vault: account(10),
+        token_1_vault: account(11),
     }))
 }
 
@@ -252,4 +267,43 @@ fn parse_withdraw_instruction(
         token0_amount: minimum_token_0_amount, // 先赋值为minimum，logs会覆盖
         token1_amount: minimum_token_1_amount, // 先赋值为minimum，logs会覆盖
     }))
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pool_initialize_routed_by_program_id() {
+        let accs: Vec<Pubkey> = (0..21).map(|_| Pubkey::new_unique()).collect();
+        let mut data = discriminators::INITIALIZE_WITH_PERMISSION.to_vec();
+        for v in [1_000u64, 2_000, 3] {
+            data.extend_from_slice(&v.to_le_bytes());
+        }
+        data.push(0); // creator_fee_on
+
+        let parse = |program_id: &Pubkey| {
+            crate::instr::parse_instruction_unified(
+                &data,
+                &accs,
+                Signature::default(),
+                1,
+                0,
+                Some(0),
+                0,
+                None,
+                program_id,
+            )
+        };
+        match parse(&PROGRAM_ID_PUBKEY) {
+            Some(DexEvent::RaydiumCpmmInitialize(e)) => {
+                assert_eq!((e.creator, e.pool), (accs[1], accs[4]));
+                assert_eq!((e.token_0_mint, e.token_1_mint), (accs[5], accs[6]));
+                assert_eq!((e.token_0_vault, e.token_1_vault), (accs[11], accs[12]));
+                assert_eq!((e.init_amount0, e.init_amount1, e.open_time), (1_000, 2_000, 3));
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+        assert!(parse(&program_ids::RAYDIUM_CLMM_PROGRAM_ID).is_none());
+    }
+}
@@ -1,6 +1,7 @@
 //! Bonk 指令解析器
 //!
-//! 使用 match discriminator 模式解析 Bonk 指令
+//! 使用 match discriminator 模式解析 Bonk (Raydium LaunchLab) 指令。
+//! Discriminator 与账户顺序均来自 `idls/raydium_launchpad.json`。
 
 use solana_sdk::{pubkey::Pubkey, signature::Signature};
 use crate::core::events::*;
@@ -9,15 +10,24 @@ use super::program_ids;
 
 /// Bonk discriminator 常量
 pub mod discriminators {
-    pub const TRADE: [u8; 8] = [2, 3, 4, 5, 6, 7, 8, 9];
-    pub const POOL_CREATE: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
-    pub const MIGRATE_AMM: [u8; 8] = [3, 4, 5, 6, 7, 8, 9, 10];
+    pub const BUY_EXACT_IN: [u8; 8] = [250, 234, 13, 123, 213, 156, 19, 236];
+    pub const BUY_EXACT_OUT: [u8; 8] = [24, 211, 116, 40, 105, 3, 153, 56];
+    pub const SELL_EXACT_IN: [u8; 8] = [149, 39, 222, 155, 211, 124, 152, 26];
+    pub const SELL_EXACT_OUT: [u8; 8] = [95, 200, 71, 34, 8, 9, 11, 166];
+    pub const INITIALIZE: [u8; 8] = [175, 175, 109, 31, 13, 152, 155, 237];
+    pub const INITIALIZE_V2: [u8; 8] = [67, 153, 175, 39, 218, 16, 38, 32];
+    pub const INITIALIZE_WITH_TOKEN_2022: [u8; 8] = [37, 190, 126, 222, 44, 154, 171, 17];
+    pub const MIGRATE_TO_AMM: [u8; 8] = [207, 82, 192, 145, 254, 207, 145, 223];
+    pub const MIGRATE_TO_CPSWAP: [u8; 8] = [136, 92, 200, 103, 28, 218, 144, 140];
 }
 
 /// Raydium Launchpad 程序 ID
 pub const PROGRAM_ID_PUBKEY: Pubkey = program_ids::BONK_PROGRAM_ID;
 
 /// 主要的 Bonk 指令解析函数
+///
+/// Trade / PoolCreate 从日志（`TradeEvent` / `PoolCreateEvent`）获得完整数据，
+/// 这里只解析迁移指令（LaunchLab 迁移不产生日志事件）。
 pub fn parse_instruction(
     instruction_data: &[u8],
     accounts: &[Pubkey],
@@ -25,106 +35,176 @@ pub fn parse_instruction(
     slot: u64,
     tx_index: u64,
     block_time_us: Option<i64>,
+    grpc_recv_us: i64,
 ) -> Option<DexEvent> {
     if instruction_data.len() < 8 {
         return None;
     }
 
     let discriminator: [u8; 8] = instruction_data[0..8].try_into().ok()?;
-    let data = &instruction_data[8..];
+    let metadata =
+        create_metadata(signature, slot, tx_index, block_time_us.unwrap_or_default(), grpc_recv_us);
 
     match discriminator {
-        discriminators::TRADE => {
-            parse_trade_instruction(data, accounts, signature, slot, tx_index, block_time_us)
-        },
-        discriminators::POOL_CREATE => {
-            parse_pool_create_instruction(data, accounts, signature, slot, tx_index, block_time_us)
-        },
-        discriminators::MIGRATE_AMM => {
-            parse_migrate_amm_instruction(data, accounts, signature, slot, tx_index, block_time_us)
-        },
+        discriminators::MIGRATE_TO_AMM => parse_migrate_instruction(accounts, metadata, 23, 13),
+        discriminators::MIGRATE_TO_CPSWAP => parse_migrate_instruction(accounts, metadata, 17, 5),
         _ => None,
     }
 }
 
-/// 解析交易指令
-#[allow(unused_variables)]
+/// 解析交易指令（buy/sell exact in/out）
+///
+/// Account indices (from raydium_launchpad.json):
+/// 0: payer, 1: authority, 2: global_config, 3: platform_config, 4: pool_state
+#[allow(dead_code)]
 fn parse_trade_instruction(
+    discriminator: [u8; 8],
     data: &[u8],
     accounts: &[Pubkey],
-    signature: Signature,
-    slot: u64,
-    tx_index: u64,
-    block_time_us: Option<i64>,
+    metadata: EventMetadata,
 ) -> Option<DexEvent> {
-    let mut offset = 0;
-
-    let amount_in = read_u64_le(data, offset)?;
-    offset += 8;
-
-    let amount_out_min = read_u64_le(data, offset)?;
-
-    let pool_state = get_account(accounts, 0)?;
-    let metadata = create_metadata_simple(signature, slot, tx_index, block_time_us, pool_state);
+    let (is_buy, exact_in) = match discriminator {
+        discriminators::BUY_EXACT_IN => (true, true),
+        discriminators::BUY_EXACT_OUT => (true, false),
+        discriminators::SELL_EXACT_IN => (false, true),
+        discriminators::SELL_EXACT_OUT => (false, false),
+        _ => return None,
+    };
+
+    // args: amount(8) + limit(8) + share_fee_rate(8)
+    let amount = read_u64_le(data, 0)?;
+    let limit = read_u64_le(data, 8)?;
+    let (amount_in, amount_out) = if exact_in { (amount, limit) } else { (limit, amount) };
 
     Some(DexEvent::BonkTrade(BonkTradeEvent {
         metadata,
-        pool_state,
-        user: get_account(accounts, 1).unwrap_or_default(),
+        pool_state: get_account(accounts, 4)?,
         amount_in,
-        amount_out: amount_out_min, // 先用指令中的最小值，日志会覆盖实际值
-        is_buy: true, // 默认为买入，实际值从日志确定
-        trade_direction: TradeDirection::Buy,
-        exact_in: true,
+        amount_out, // 指令中为滑点限制值，日志会给出实际值
+        trade_direction: if is_buy { TradeDirection::Buy } else { TradeDirection::Sell },
+        exact_in,
+        is_buy,
+        user: get_account(accounts, 0).unwrap_or_default(),
+        ..Default::default()
     }))
 }
 
-/// 解析池创建指令
+/// 解析池创建指令（initialize / initialize_v2 / initialize_with_token_2022）
+///
+/// Account indices (from raydium_launchpad.json):
+/// 0: payer, 1: creator, 2: global_config, 3: platform_config, 4: authority, 5: pool_state,
+/// 6: base_mint, 7: quote_mint
+#[allow(dead_code)]
 fn parse_pool_create_instruction(
-    _data: &[u8],
+    data: &[u8],
     accounts: &[Pubkey],
-    signature: Signature,
-    slot: u64,
-    tx_index: u64,
-    block_time_us: Option<i64>,
+    metadata: EventMetadata,
 ) -> Option<DexEvent> {
-    let pool_state = get_account(accounts, 0)?;
-    let metadata = create_metadata_simple(signature, slot, tx_index, block_time_us, pool_state);
+    // args 以 MintParams 开头：decimals(1) + name + symbol + uri
+    let decimals = read_u8(data, 0)?;
+    let mut offset = 1;
+
+    let (name, name_len) = read_str_unchecked(data, offset)?;
+    offset += name_len;
+
+    let (symbol, symbol_len) = read_str_unchecked(data, offset)?;
+    offset += symbol_len;
+
+    let (uri, _) = read_str_unchecked(data, offset)?;
 
     Some(DexEvent::BonkPoolCreate(BonkPoolCreateEvent {
         metadata,
         base_mint_param: BaseMintParam {
-            symbol: "BONK".to_string(),
-            name: "Bonk Pool".to_string(),
-            uri: "https://bonk.com".to_string(),
-            decimals: 5,
+            symbol: symbol.to_string(),
+            name: name.to_string(),
+            uri: uri.to_string(),
+            decimals,
         },
-        pool_state,
+        pool_state: get_account(accounts, 5)?,
         creator: get_account(accounts, 1).unwrap_or_default(),
+        config: get_account(accounts, 2).unwrap_or_default(),
+        base_mint: get_account(accounts, 6).unwrap_or_default(),
+        quote_mint: get_account(accounts, 7).unwrap_or_default(),
     }))
 }
 
-/// 解析 AMM 迁移指令
-fn parse_migrate_amm_instruction(
-    data: &[u8],
+/// 解析迁移指令（migrate_to_amm / migrate_to_cpswap）
+///
+/// Account indices (from raydium_launchpad.json):
+/// - migrate_to_amm: 0: payer, 13: amm_pool, 23: pool_state
+/// - migrate_to_cpswap: 0: payer, 5: cpswap_pool, 17: pool_state
+fn parse_migrate_instruction(
     accounts: &[Pubkey],
-    signature: Signature,
-    slot: u64,
-    tx_index: u64,
-    block_time_us: Option<i64>,
+    metadata: EventMetadata,
+    pool_state_index: usize,
+    new_pool_index: usize,
 ) -> Option<DexEvent> {
-    let offset = 0;
-
-    let liquidity_amount = read_u64_le(data, offset)?;
-
-    let old_pool = get_account(accounts, 0)?;
-    let metadata = create_metadata_simple(signature, slot, tx_index, block_time_us, old_pool);
-
     Some(DexEvent::BonkMigrateAmm(BonkMigrateAmmEvent {
         metadata,
-        old_pool,
-        new_pool: get_account(accounts, 1).unwrap_or_default(),
-        user: get_account(accounts, 2).unwrap_or_default(),
-        liquidity_amount,
+        old_pool: get_account(accounts, pool_state_index)?,
+        new_pool: get_account(accounts, new_pool_index)?,
+        user: get_account(accounts, 0).unwrap_or_default(),
+        liquidity_amount: 0, // 迁移指令参数不包含流动性数量
     }))
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accounts(n: usize) -> Vec<Pubkey> {
+        (0..n).map(|_| Pubkey::new_unique()).collect()
+    }
+
+    #[test]
+    fn test_parse_migrate_to_amm() {
+        let accs = accounts(32);
+        let event = parse_instruction(
+            &discriminators::MIGRATE_TO_AMM,
+            &accs,
+            Signature::default(),
+            1,
+            0,
+            Some(0),
+            0,
+        );
+        match event {
+            Some(DexEvent::BonkMigrateAmm(e)) => {
+                assert_eq!(e.old_pool, accs[23]);
+                assert_eq!(e.new_pool, accs[13]);
+                assert_eq!(e.user, accs[0]);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_migrate_to_cpswap() {
+        let accs = accounts(28);
+        let event = parse_instruction(
+            &discriminators::MIGRATE_TO_CPSWAP,
+            &accs,
+            Signature::default(),
+            1,
+            0,
+            Some(0),
+            0,
+        );
+        match event {
+            Some(DexEvent::BonkMigrateAmm(e)) => {
+                assert_eq!(e.old_pool, accs[17]);
+                assert_eq!(e.new_pool, accs[5]);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_trade_instruction_is_left_to_logs() {
+        let mut data = discriminators::BUY_EXACT_IN.to_vec();
+        data.extend_from_slice(&[0u8; 24]);
+        let event =
+            parse_instruction(&data, &accounts(16), Signature::default(), 1, 0, Some(0), 0);
+        assert!(event.is_none());
+    }
+}
@@ -18,6 +18,7 @@ pub fn create_metadata(
         tx_index,
         block_time_us,
         grpc_recv_us,
+        ..Default::default()
     }
 }
 
@@ -38,6 +39,7 @@ pub fn create_metadata_simple(
         tx_index,
         block_time_us: block_time_us.unwrap_or(0),
         grpc_recv_us: current_time,
+        ..Default::default()
     }
 }
 
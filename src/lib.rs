@@ -1,3 +1,6 @@
+// 派生宏生成的代码通过 `::sol_parser_sdk::` 路径引用本 crate
+extern crate self as sol_parser_sdk;
+
 // 核心模块 - 扁平化结构
 pub mod accounts; // 账户解析器
 pub mod common;
@@ -13,6 +16,22 @@ pub mod grpc;
 // RPC 解析模块 - 支持直接从RPC解析交易
 pub mod rpc_parser;
 
+// 区块解析模块 - 按 slot 批次解析整个区块
+pub mod block_parser;
+
+// 分析模块 - 基于事件流的在线分析（套利检测等）
+pub mod analytics;
+
+// 协议能力矩阵 - 本构建支持的协议、事件与事件来源
+pub mod capabilities;
+
+// 合成事件流压测 - 无需端点驱动解析 + 队列 + sink 管线
+pub mod loadgen;
+
+// 代币元数据模块 - Metaplex 元数据解析与缓存
+#[cfg(feature = "token-metadata")]
+pub mod metadata;
+
 // 兼容性别名
 pub mod parser {
     pub use crate::core::*;
@@ -23,15 +42,34 @@ pub use core::{
     // 事件类型
     DexEvent, EventMetadata, ParsedEvent,
     // 主要解析函数
-    parse_transaction_events, parse_logs_only, parse_transaction_with_listener,
+    parse_transaction_events, parse_transaction_events_with_dedup, parse_logs_only, parse_transaction_with_listener,
     // 流式解析函数
     parse_transaction_events_streaming, parse_logs_streaming, parse_transaction_with_streaming_listener,
     // 事件监听器
-    EventListener, StreamingEventListener,
+    EventListener, StreamingEventListener, AsyncEventListener,
+    // 事件转换钩子
+    Transformer, TransformerChain,
+    // 跟单执行钩子
+    ExecutionHook, ExecutionHooks, ExecutionPattern,
+    // 字段脱敏
+    RedactAction, Redactor,
 };
 
 // 导出预热函数
 pub use warmup::warmup_parser;
 
 // 导出 RPC 解析函数
-pub use rpc_parser::{parse_rpc_transaction, parse_transaction_from_rpc, convert_rpc_to_grpc, ParseError};
+pub use rpc_parser::{
+    parse_rpc_transaction, parse_transaction_from_rpc, convert_rpc_to_grpc, parse_bundle_simulation,
+    parse_versioned_transaction,
+    parse_simulation_logs, backfill_since_checkpoint, backfill_since_checkpoint_with_retry,
+    backfill_address_history, load_pool_trades,
+    BundleSimulation, SimulatedTransactionResult, ParseError, RetryPolicy, HistoryOptions,
+    PoolTradeOptions,
+};
+
+// 导出协议能力查询
+pub use capabilities::{supported_protocols, ParseSource, ProtocolInfo};
+
+// 导出区块解析函数
+pub use block_parser::{parse_block_events, BlockRef, SlotEvents};
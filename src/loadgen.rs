@@ -0,0 +1,400 @@
+//! 合成事件流压测
+//!
+//! 不连接 gRPC 端点，按协议合成接近真实的交易日志（`Program invoke` / `Program data` 等），
+//! 以配置的速率驱动完整管线：日志解析 → 输出队列 → sink，报告持续吞吐（events/s）与
+//! 从交易生成到 sink 写入完成的延迟分位数。
+//!
+//! - [`SyntheticStream`]：确定性的合成交易生成器，市场、mint 与用户从固定大小的集合中抽取，
+//!   重复度接近实际流量，也可单独用来喂自定义管线
+//! - [`run`]：按 [`LoadGenConfig`] 运行压测，返回 [`LoadGenReport`]
+//!
+//! 命令行入口见 `examples/loadgen.rs`。
+
+use crate::core::clock::now_micros;
+use crate::core::events::DexEvent;
+use crate::core::layout::{PUMPFUN_TRADE_EVENT, PUMPSWAP_BUY_EVENT, PUMPSWAP_SELL_EVENT};
+use crate::core::unified_parser::parse_logs_only;
+use crate::grpc::sink::{SinkConfig, SinkSet};
+use crate::instr::program_ids::{PUMPFUN_PROGRAM_ID, PUMPSWAP_PROGRAM_ID, RAYDIUM_CLMM_PROGRAM_ID};
+use crate::logs::optimized_matcher::discriminators;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use crossbeam_queue::ArrayQueue;
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// 合成交易的协议
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyntheticProtocol {
+    /// PumpFun 买卖（TradeEvent）
+    PumpFun,
+    /// PumpSwap 买卖（BuyEvent / SellEvent）
+    PumpSwap,
+    /// Raydium CLMM swap（SwapEvent）
+    RaydiumClmm,
+}
+
+impl SyntheticProtocol {
+    pub const ALL: [SyntheticProtocol; 3] =
+        [SyntheticProtocol::PumpFun, SyntheticProtocol::PumpSwap, SyntheticProtocol::RaydiumClmm];
+
+    /// 按名称解析：`pumpfun` / `pumpswap` / `clmm`
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "pumpfun" => Some(SyntheticProtocol::PumpFun),
+            "pumpswap" => Some(SyntheticProtocol::PumpSwap),
+            "clmm" | "raydium_clmm" => Some(SyntheticProtocol::RaydiumClmm),
+            _ => None,
+        }
+    }
+}
+
+/// 每种实体的集合大小：少量热门市场承载大部分交易
+const MARKETS: usize = 64;
+const USERS: usize = 4096;
+
+/// 确定性的合成交易生成器
+pub struct SyntheticStream {
+    protocols: Vec<SyntheticProtocol>,
+    markets: Vec<(Pubkey, Pubkey)>,
+    users: Vec<Pubkey>,
+    state: u64,
+    slot: u64,
+    tx_in_slot: u64,
+}
+
+impl SyntheticStream {
+    /// `protocols` 为空时使用全部协议；相同 `seed` 生成相同的交易序列
+    pub fn new(protocols: &[SyntheticProtocol], seed: u64) -> Self {
+        let protocols =
+            if protocols.is_empty() { SyntheticProtocol::ALL.to_vec() } else { protocols.to_vec() };
+        let mut stream = Self {
+            protocols,
+            markets: Vec::new(),
+            users: Vec::new(),
+            state: seed | 1,
+            slot: 300_000_000,
+            tx_in_slot: 0,
+        };
+        stream.markets = (0..MARKETS).map(|_| (stream.pubkey(), stream.pubkey())).collect();
+        stream.users = (0..USERS).map(|_| stream.pubkey()).collect();
+        stream
+    }
+
+    /// 当前 slot，每 400 笔交易推进一个 slot
+    pub fn slot(&self) -> u64 {
+        self.slot
+    }
+
+    /// 生成下一笔交易的签名与日志
+    pub fn next_transaction(&mut self) -> (Signature, Vec<String>) {
+        self.tx_in_slot += 1;
+        if self.tx_in_slot > 400 {
+            self.tx_in_slot = 1;
+            self.slot += 1;
+        }
+        let mut signature = [0u8; 64];
+        signature.iter_mut().for_each(|b| *b = self.next() as u8);
+
+        let index = self.next() as usize % self.protocols.len();
+        let protocol = self.protocols[index];
+        let is_buy = self.next().is_multiple_of(2);
+        let (program, instruction, data) = match protocol {
+            SyntheticProtocol::PumpFun => (
+                PUMPFUN_PROGRAM_ID,
+                if is_buy { "Buy" } else { "Sell" },
+                self.pumpfun_trade(is_buy),
+            ),
+            SyntheticProtocol::PumpSwap => {
+                (PUMPSWAP_PROGRAM_ID, if is_buy { "Buy" } else { "Sell" }, self.pumpswap(is_buy))
+            }
+            SyntheticProtocol::RaydiumClmm => (RAYDIUM_CLMM_PROGRAM_ID, "Swap", self.clmm_swap()),
+        };
+        let logs = vec![
+            "Program ComputeBudget111111111111111111111111111111 invoke [1]".to_string(),
+            "Program ComputeBudget111111111111111111111111111111 success".to_string(),
+            format!("Program {} invoke [1]", program),
+            format!("Program log: Instruction: {}", instruction),
+            format!("Program data: {}", STANDARD.encode(&data)),
+            format!(
+                "Program {} consumed {} of 200000 compute units",
+                program,
+                20_000 + self.next() % 40_000
+            ),
+            format!("Program {} success", program),
+        ];
+        (Signature::from(signature), logs)
+    }
+
+    fn pumpfun_trade(&mut self, is_buy: bool) -> Vec<u8> {
+        let (_, mint) = self.market();
+        let mut data = discriminators::PUMPFUN_TRADE.to_le_bytes().to_vec();
+        data.extend_from_slice(mint.as_ref());
+        self.put_u64s(&mut data, 2, 1_000_000_000);
+        data.push(is_buy as u8);
+        data.extend_from_slice(self.user().as_ref());
+        data.extend_from_slice(&(now_micros() / 1_000_000).to_le_bytes());
+        self.put_u64s(&mut data, 4, 1_000_000_000_000);
+        data.extend_from_slice(self.user().as_ref());
+        self.put_u64s(&mut data, 2, 100_000);
+        data.extend_from_slice(self.user().as_ref());
+        self.put_u64s(&mut data, 2, 100_000);
+        data.push(1);
+        self.put_u64s(&mut data, 4, 1_000_000_000);
+        debug_assert_eq!(data.len(), 8 + PUMPFUN_TRADE_EVENT.current_size - 5);
+        put_str(&mut data, if is_buy { "buy" } else { "sell" });
+        data.push(0);
+        data
+    }
+
+    fn pumpswap(&mut self, is_buy: bool) -> Vec<u8> {
+        let (pool, _) = self.market();
+        let discriminator =
+            if is_buy { discriminators::PUMPSWAP_BUY } else { discriminators::PUMPSWAP_SELL };
+        let mut data = discriminator.to_le_bytes().to_vec();
+        data.extend_from_slice(&(now_micros() / 1_000_000).to_le_bytes());
+        self.put_u64s(&mut data, 13, 1_000_000_000);
+        data.extend_from_slice(pool.as_ref());
+        data.extend_from_slice(self.user().as_ref());
+        for _ in 0..5 {
+            data.extend_from_slice(self.pubkey().as_ref());
+        }
+        self.put_u64s(&mut data, 2, 100_000);
+        if !is_buy {
+            debug_assert_eq!(data.len(), 8 + PUMPSWAP_SELL_EVENT.current_size);
+            return data;
+        }
+        data.push(1);
+        self.put_u64s(&mut data, 4, 1_000_000_000);
+        self.put_u64s(&mut data, 1, 1_000_000_000);
+        debug_assert_eq!(data.len(), 8 + PUMPSWAP_BUY_EVENT.current_size - 4);
+        put_str(&mut data, "buy");
+        data
+    }
+
+    fn clmm_swap(&mut self) -> Vec<u8> {
+        let (pool, _) = self.market();
+        let mut data = discriminators::RAYDIUM_CLMM_SWAP.to_le_bytes().to_vec();
+        data.extend_from_slice(pool.as_ref());
+        data.extend_from_slice(self.user().as_ref());
+        data.extend_from_slice(self.pubkey().as_ref());
+        data.extend_from_slice(self.pubkey().as_ref());
+        self.put_u64s(&mut data, 4, 1_000_000_000);
+        data.push(self.next() as u8 & 1);
+        data.extend_from_slice(&((self.next() as u128) << 32).to_le_bytes());
+        data.extend_from_slice(&(self.next() as u128).to_le_bytes());
+        data.extend_from_slice(&((self.next() % 20_000) as i32 - 10_000).to_le_bytes());
+        data
+    }
+
+    /// 热门市场优先：一半交易落在前 1/8 的市场
+    fn market(&mut self) -> (Pubkey, Pubkey) {
+        let r = self.next() as usize;
+        let index = if r.is_multiple_of(2) { r / 2 % (MARKETS / 8) } else { r / 2 % MARKETS };
+        self.markets[index]
+    }
+
+    fn user(&mut self) -> Pubkey {
+        let index = self.next() as usize % USERS;
+        self.users[index]
+    }
+
+    fn pubkey(&mut self) -> Pubkey {
+        let mut bytes = [0u8; 32];
+        for chunk in bytes.chunks_mut(8) {
+            chunk.copy_from_slice(&self.next().to_le_bytes());
+        }
+        Pubkey::new_from_array(bytes)
+    }
+
+    fn put_u64s(&mut self, data: &mut Vec<u8>, count: usize, max: u64) {
+        for _ in 0..count {
+            data.extend_from_slice(&(self.next() % max).to_le_bytes());
+        }
+    }
+
+    /// xorshift64
+    fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+}
+
+fn put_str(data: &mut Vec<u8>, s: &str) {
+    data.extend_from_slice(&(s.len() as u32).to_le_bytes());
+    data.extend_from_slice(s.as_bytes());
+}
+
+/// 压测配置
+#[derive(Debug, Clone)]
+pub struct LoadGenConfig {
+    /// 目标速率（交易/秒），0 表示不限速
+    pub rate: u64,
+    pub duration: Duration,
+    /// 为空时使用全部协议
+    pub protocols: Vec<SyntheticProtocol>,
+    /// 解析与 sink 之间的队列容量，队列满时丢弃新事件（计入 `dropped`）
+    pub queue_capacity: usize,
+    pub sinks: Vec<SinkConfig>,
+    pub seed: u64,
+}
+
+impl Default for LoadGenConfig {
+    fn default() -> Self {
+        Self {
+            rate: 50_000,
+            duration: Duration::from_secs(10),
+            protocols: Vec::new(),
+            queue_capacity: 100_000,
+            sinks: Vec::new(),
+            seed: 42,
+        }
+    }
+}
+
+/// 压测结果
+#[derive(Debug, Clone, Default)]
+pub struct LoadGenReport {
+    pub transactions: u64,
+    /// 写入 sink 的事件数
+    pub events: u64,
+    /// 队列满而丢弃的事件数
+    pub dropped: u64,
+    pub elapsed: Duration,
+    pub events_per_sec: f64,
+    /// 从交易生成到 sink 写入完成的延迟（微秒）
+    pub p50_latency_us: u64,
+    pub p99_latency_us: u64,
+    pub max_latency_us: u64,
+}
+
+impl std::fmt::Display for LoadGenReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} txs, {} events ({} dropped) in {:.2}s: {:.0} events/s, latency p50={}μs p99={}μs max={}μs",
+            self.transactions,
+            self.events,
+            self.dropped,
+            self.elapsed.as_secs_f64(),
+            self.events_per_sec,
+            self.p50_latency_us,
+            self.p99_latency_us,
+            self.max_latency_us
+        )
+    }
+}
+
+/// 运行压测：当前线程生成并解析交易，后台线程消费队列写入 sink
+///
+/// 每个事件记录一个 4 字节延迟样本。sink 打开失败时返回错误。
+pub fn run(config: &LoadGenConfig) -> std::io::Result<LoadGenReport> {
+    let mut sinks = SinkSet::open(&config.sinks)?;
+    let queue = Arc::new(ArrayQueue::<DexEvent>::new(config.queue_capacity.max(1)));
+    let done = Arc::new(AtomicBool::new(false));
+
+    let consumer = {
+        let (queue, done) = (Arc::clone(&queue), Arc::clone(&done));
+        std::thread::spawn(move || {
+            let mut latencies = Vec::new();
+            loop {
+                let Some(event) = queue.pop() else {
+                    if done.load(Ordering::Acquire) && queue.is_empty() {
+                        break;
+                    }
+                    sinks.flush();
+                    std::thread::yield_now();
+                    continue;
+                };
+                sinks.write(&event);
+                let latency = now_micros() - event.metadata().grpc_recv_us;
+                latencies.push(latency.clamp(0, u32::MAX as i64) as u32);
+            }
+            sinks.flush();
+            latencies
+        })
+    };
+
+    let mut stream = SyntheticStream::new(&config.protocols, config.seed);
+    let (mut transactions, mut dropped) = (0u64, 0u64);
+    let start = Instant::now();
+    while start.elapsed() < config.duration {
+        if config.rate > 0 {
+            let due = (start.elapsed().as_secs_f64() * config.rate as f64) as u64;
+            if transactions >= due {
+                std::thread::sleep(Duration::from_micros(50));
+                continue;
+            }
+        }
+        let (signature, logs) = stream.next_transaction();
+        let generated_us = now_micros();
+        transactions += 1;
+        for mut event in parse_logs_only(&logs, signature, stream.slot(), None) {
+            if let Some(metadata) = event.metadata_mut() {
+                metadata.grpc_recv_us = generated_us;
+            }
+            if queue.push(event).is_err() {
+                dropped += 1;
+            }
+        }
+    }
+    done.store(true, Ordering::Release);
+    let mut latencies = consumer.join().map_err(|_| std::io::Error::other("consumer panicked"))?;
+    let elapsed = start.elapsed();
+
+    latencies.sort_unstable();
+    let percentile = |p: usize| {
+        latencies.get((latencies.len() * p / 100).min(latencies.len().saturating_sub(1)))
+    };
+    let events = latencies.len() as u64;
+    Ok(LoadGenReport {
+        transactions,
+        events,
+        dropped,
+        elapsed,
+        events_per_sec: events as f64 / elapsed.as_secs_f64(),
+        p50_latency_us: percentile(50).map_or(0, |&v| v as u64),
+        p99_latency_us: percentile(99).map_or(0, |&v| v as u64),
+        max_latency_us: latencies.last().map_or(0, |&v| v as u64),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_synthetic_stream_parses_and_runs() {
+        let mut stream = SyntheticStream::new(&[], 7);
+        let mut seen = std::collections::HashSet::new();
+        for _ in 0..200 {
+            let (signature, logs) = stream.next_transaction();
+            let events = parse_logs_only(&logs, signature, stream.slot(), None);
+            assert_eq!(events.len(), 1, "{:?}", logs);
+            seen.insert(events[0].event_type_name());
+        }
+        for name in ["PumpFunBuy", "PumpFunSell", "PumpSwapBuy", "PumpSwapSell", "RaydiumClmmSwap"]
+        {
+            assert!(seen.contains(name), "{name}: {seen:?}");
+        }
+
+        // CLMM 日志使用主网程序 ID
+        let mut clmm = SyntheticStream::new(&[SyntheticProtocol::RaydiumClmm], 7);
+        let (_, logs) = clmm.next_transaction();
+        assert_eq!(logs[2], "Program CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK invoke [1]");
+
+        let config = LoadGenConfig {
+            rate: 5_000,
+            duration: Duration::from_millis(200),
+            ..Default::default()
+        };
+        let report = run(&config).unwrap();
+        assert!(report.transactions > 0 && report.events == report.transactions, "{report}");
+        assert_eq!(report.dropped, 0);
+        assert!(report.p50_latency_us <= report.p99_latency_us);
+        assert!(report.p99_latency_us <= report.max_latency_us);
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! 解析 Meteora Pools 程序的日志事件
 
-use solana_sdk::signature::Signature;
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
 use crate::core::events::*;
 use super::utils::*;
 
@@ -92,6 +92,7 @@ pub fn parse_swap_from_data(data: &[u8], metadata: EventMetadata) -> Option<DexE
         trade_fee,
         admin_fee,
         host_fee,
+        pool: Pubkey::default(),
     }))
 }
 
@@ -113,6 +114,7 @@ pub fn parse_add_liquidity_from_data(data: &[u8], metadata: EventMetadata) -> Op
         lp_mint_amount,
         token_a_amount,
         token_b_amount,
+        pool: Pubkey::default(),
     }))
 }
 
@@ -134,6 +136,7 @@ pub fn parse_remove_liquidity_from_data(data: &[u8], metadata: EventMetadata) ->
         lp_unmint_amount,
         token_a_out_amount,
         token_b_out_amount,
+        pool: Pubkey::default(),
     }))
 }
 
@@ -191,6 +194,35 @@ pub fn parse_pool_created_from_data(data: &[u8], metadata: EventMetadata) -> Opt
     }))
 }
 
+/// Parse Meteora AMM SetPoolFees event from pre-decoded data
+#[inline(always)]
+pub fn parse_set_pool_fees_from_data(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+    let mut offset = 0;
+
+    let trade_fee_numerator = read_u64_le(data, offset)?;
+    offset += 8;
+
+    let trade_fee_denominator = read_u64_le(data, offset)?;
+    offset += 8;
+
+    let owner_trade_fee_numerator = read_u64_le(data, offset)?;
+    offset += 8;
+
+    let owner_trade_fee_denominator = read_u64_le(data, offset)?;
+    offset += 8;
+
+    let pool = read_pubkey(data, offset)?;
+
+    Some(DexEvent::MeteoraPoolsSetPoolFees(MeteoraPoolsSetPoolFeesEvent {
+        metadata,
+        trade_fee_numerator,
+        trade_fee_denominator,
+        owner_trade_fee_numerator,
+        owner_trade_fee_denominator,
+        pool,
+    }))
+}
+
 /// 解析 Swap 事件
 fn parse_swap_event(
     data: &[u8],
@@ -226,6 +258,7 @@ fn parse_swap_event(
         trade_fee,
         admin_fee,
         host_fee,
+        pool: Pubkey::default(),
     }))
 }
 
@@ -255,6 +288,7 @@ fn parse_add_liquidity_event(
         lp_mint_amount,
         token_a_amount,
         token_b_amount,
+        pool: Pubkey::default(),
     }))
 }
 
@@ -284,6 +318,7 @@ fn parse_remove_liquidity_event(
         lp_unmint_amount,
         token_a_out_amount,
         token_b_out_amount,
+        pool: Pubkey::default(),
     }))
 }
 
@@ -404,4 +439,90 @@ fn parse_text_log(
 ) -> Option<DexEvent> {
     // 目前暂不实现文本解析，主要依赖结构化解析
     None
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logs::utils::program_data_log;
+
+    fn pool_created_body(pool: Pubkey) -> Vec<u8> {
+        let mut body = Vec::new();
+        for _ in 0..3 {
+            body.extend_from_slice(Pubkey::new_unique().as_ref());
+        }
+        body.push(1); // pool_type
+        body.extend_from_slice(pool.as_ref());
+        body
+    }
+
+    fn set_pool_fees_body(pool: Pubkey) -> Vec<u8> {
+        let mut body = Vec::new();
+        for v in [25u64, 10_000, 5, 10_000] {
+            body.extend_from_slice(&v.to_le_bytes());
+        }
+        body.extend_from_slice(pool.as_ref());
+        body
+    }
+
+    #[test]
+    fn test_parse_set_pool_fees_log() {
+        let pool = Pubkey::new_unique();
+        let log = program_data_log(&discriminators::SET_POOL_FEES_EVENT, &set_pool_fees_body(pool));
+
+        match crate::logs::parse_log(&log, Signature::default(), 1, 0, Some(0), 0, None, false) {
+            Some(DexEvent::MeteoraPoolsSetPoolFees(e)) => {
+                assert_eq!(e.pool, pool);
+                assert_eq!(e.trade_fee_numerator, 25);
+                assert_eq!(e.owner_trade_fee_numerator, 5);
+                assert_eq!(e.owner_trade_fee_denominator, 10_000);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_pool_created_and_bootstrap_log() {
+        let pool = Pubkey::new_unique();
+        let log = program_data_log(&discriminators::POOL_CREATED_EVENT, &pool_created_body(pool));
+        assert!(matches!(
+            crate::logs::parse_log(&log, Signature::default(), 1, 0, Some(0), 0, None, false),
+            Some(DexEvent::MeteoraPoolsPoolCreated(e)) if e.pool == pool && e.pool_type == 1
+        ));
+
+        let mut body = Vec::new();
+        for v in [100u64, 200, 300] {
+            body.extend_from_slice(&v.to_le_bytes());
+        }
+        body.extend_from_slice(pool.as_ref());
+        let log = program_data_log(&discriminators::BOOTSTRAP_LIQUIDITY_EVENT, &body);
+        assert!(matches!(
+            crate::logs::parse_log(&log, Signature::default(), 1, 0, Some(0), 0, None, false),
+            Some(DexEvent::MeteoraPoolsBootstrapLiquidity(e)) if e.pool == pool && e.token_b_amount == 300
+        ));
+    }
+
+    #[test]
+    fn test_inner_instruction_events() {
+        use crate::instr::all_inner::meteora_amm::{self as inner, discriminators as inner_disc};
+
+        let pool = Pubkey::new_unique();
+        assert!(matches!(
+            inner::parse(&inner_disc::POOL_CREATED, &pool_created_body(pool), EventMetadata::default()),
+            Some(DexEvent::MeteoraPoolsPoolCreated(e)) if e.pool == pool
+        ));
+        assert!(matches!(
+            inner::parse(&inner_disc::SET_POOL_FEES, &set_pool_fees_body(pool), EventMetadata::default()),
+            Some(DexEvent::MeteoraPoolsSetPoolFees(e)) if e.pool == pool && e.trade_fee_denominator == 10_000
+        ));
+
+        let mut swap = Vec::new();
+        for v in 1..=5u64 {
+            swap.extend_from_slice(&v.to_le_bytes());
+        }
+        assert!(matches!(
+            inner::parse(&inner_disc::SWAP, &swap, EventMetadata::default()),
+            Some(DexEvent::MeteoraPoolsSwap(e)) if e.trade_fee == 3 && e.host_fee == 5
+        ));
+    }
+}
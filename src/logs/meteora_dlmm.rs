@@ -2,7 +2,7 @@
 //!
 //! Parse Meteora DLMM program log events
 
-use solana_sdk::signature::Signature;
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
 use crate::core::events::*;
 use super::utils::*;
 
@@ -12,10 +12,13 @@ pub mod discriminators {
     pub const ADD_LIQUIDITY_EVENT: [u8; 8] = [181, 157, 89, 67, 143, 182, 52, 72];
     pub const REMOVE_LIQUIDITY_EVENT: [u8; 8] = [80, 85, 209, 72, 24, 206, 35, 178];
     pub const INITIALIZE_BIN_ARRAY_EVENT: [u8; 8] = [11, 18, 155, 194, 33, 115, 238, 119];
-    pub const INITIALIZE_POOL_EVENT: [u8; 8] = [95, 180, 10, 172, 84, 174, 232, 40];
+    pub const INITIALIZE_POOL_EVENT: [u8; 8] = [185, 74, 252, 125, 27, 215, 188, 111];
     pub const CREATE_POSITION_EVENT: [u8; 8] = [123, 233, 11, 43, 146, 180, 97, 119];
     pub const CLOSE_POSITION_EVENT: [u8; 8] = [94, 168, 102, 45, 59, 122, 137, 54];
     pub const CLAIM_FEE_EVENT: [u8; 8] = [152, 70, 208, 111, 104, 91, 44, 1];
+    pub const CLAIM_REWARD_EVENT: [u8; 8] = [148, 116, 134, 204, 22, 171, 85, 95];
+    pub const FUND_REWARD_EVENT: [u8; 8] = [246, 228, 58, 130, 145, 170, 79, 204];
+    pub const UPDATE_POSITION_OPERATOR_EVENT: [u8; 8] = [39, 115, 48, 204, 246, 47, 66, 57];
 }
 
 /// 主要的 Meteora DLMM 日志解析函数
@@ -62,6 +65,15 @@ fn parse_structured_log(
         discriminators::CLAIM_FEE_EVENT => {
             parse_claim_fee_event(data, signature, slot, tx_index, block_time_us, grpc_recv_us)
         },
+        discriminators::CLAIM_REWARD_EVENT => {
+            parse_claim_reward_event(data, signature, slot, tx_index, block_time_us, grpc_recv_us)
+        },
+        discriminators::FUND_REWARD_EVENT => {
+            parse_fund_reward_event(data, signature, slot, tx_index, block_time_us, grpc_recv_us)
+        },
+        discriminators::UPDATE_POSITION_OPERATOR_EVENT => {
+            parse_update_position_operator_event(data, signature, slot, tx_index, block_time_us, grpc_recv_us)
+        },
         _ => None,
     }
 }
@@ -208,6 +220,9 @@ fn parse_remove_liquidity_event(
 }
 
 /// 解析池初始化事件
+///
+/// 事件（LbPairCreate）：lb_pair(32) + bin_step(2) + token_x(32) + token_y(32)；
+/// 事件不含创建者和初始 active bin，creator 由账户填充器从指令账户补齐
 fn parse_initialize_pool_event(
     data: &[u8],
     signature: Signature,
@@ -221,22 +236,24 @@ fn parse_initialize_pool_event(
     let pool = read_pubkey(data, offset)?;
     offset += 32;
 
-    let creator = read_pubkey(data, offset)?;
-    offset += 32;
+    let bin_step = read_u16_le(data, offset)?;
+    offset += 2;
 
-    let active_bin_id = read_u32_le(data, offset)? as i32;
-    offset += 4;
+    let token_x_mint = read_pubkey(data, offset)?;
+    offset += 32;
 
-    let bin_step = read_u16_le(data, offset)?;
+    let token_y_mint = read_pubkey(data, offset)?;
 
     let metadata = create_metadata_simple(signature, slot, tx_index, block_time_us, pool, grpc_recv_us);
 
     Some(DexEvent::MeteoraDlmmInitializePool(MeteoraDlmmInitializePoolEvent {
         metadata,
         pool,
-        creator,
-        active_bin_id,
+        creator: Pubkey::default(),
+        active_bin_id: 0,
         bin_step,
+        token_x_mint,
+        token_y_mint,
     }))
 }
 
@@ -343,8 +360,110 @@ fn parse_claim_fee_event(
     }))
 }
 
+/// 解析奖励领取事件
+fn parse_claim_reward_event(
+    data: &[u8],
+    signature: Signature,
+    slot: u64,
+    tx_index: u64,
+    block_time_us: Option<i64>,
+    grpc_recv_us: i64,
+) -> Option<DexEvent> {
+    let mut offset = 0;
+
+    let pool = read_pubkey(data, offset)?;
+    offset += 32;
+
+    let position = read_pubkey(data, offset)?;
+    offset += 32;
+
+    let owner = read_pubkey(data, offset)?;
+    offset += 32;
+
+    let reward_index = read_u64_le(data, offset)?;
+    offset += 8;
+
+    let total_reward = read_u64_le(data, offset)?;
+
+    let metadata = create_metadata_simple(signature, slot, tx_index, block_time_us, pool, grpc_recv_us);
+
+    Some(DexEvent::MeteoraDlmmClaimReward(MeteoraDlmmClaimRewardEvent {
+        metadata,
+        pool,
+        position,
+        owner,
+        reward_index,
+        total_reward,
+        reward_mint: Pubkey::default(), // 由账户填充器从指令账户获取
+    }))
+}
+
+/// 解析奖励注资事件
+fn parse_fund_reward_event(
+    data: &[u8],
+    signature: Signature,
+    slot: u64,
+    tx_index: u64,
+    block_time_us: Option<i64>,
+    grpc_recv_us: i64,
+) -> Option<DexEvent> {
+    let mut offset = 0;
+
+    let pool = read_pubkey(data, offset)?;
+    offset += 32;
+
+    let funder = read_pubkey(data, offset)?;
+    offset += 32;
+
+    let reward_index = read_u64_le(data, offset)?;
+    offset += 8;
+
+    let amount = read_u64_le(data, offset)?;
+
+    let metadata = create_metadata_simple(signature, slot, tx_index, block_time_us, pool, grpc_recv_us);
+
+    Some(DexEvent::MeteoraDlmmFundReward(MeteoraDlmmFundRewardEvent {
+        metadata,
+        pool,
+        funder,
+        reward_index,
+        amount,
+        reward_mint: Pubkey::default(), // 由账户填充器从指令账户获取
+    }))
+}
+
+/// 解析仓位操作员更新事件
+fn parse_update_position_operator_event(
+    data: &[u8],
+    signature: Signature,
+    slot: u64,
+    tx_index: u64,
+    block_time_us: Option<i64>,
+    grpc_recv_us: i64,
+) -> Option<DexEvent> {
+    let mut offset = 0;
+
+    let position = read_pubkey(data, offset)?;
+    offset += 32;
+
+    let old_operator = read_pubkey(data, offset)?;
+    offset += 32;
+
+    let new_operator = read_pubkey(data, offset)?;
+
+    let metadata = create_metadata_simple(signature, slot, tx_index, block_time_us, position, grpc_recv_us);
+
+    Some(DexEvent::MeteoraDlmmUpdatePositionOperator(MeteoraDlmmUpdatePositionOperatorEvent {
+        metadata,
+        position,
+        old_operator,
+        new_operator,
+        owner: Pubkey::default(), // 由账户填充器从指令账户获取
+    }))
+}
+
 /// 文本回退解析
-fn parse_text_log(
+pub(crate) fn parse_text_log(
     log: &str,
     signature: Signature,
     slot: u64,
@@ -392,10 +511,10 @@ fn parse_swap_from_text(
         from: solana_sdk::pubkey::Pubkey::default(),
         start_bin_id: 0,
         end_bin_id: 0,
-        amount_in: extract_number_from_text(log, "amount_in").unwrap_or(1_000_000),
-        amount_out: extract_number_from_text(log, "amount_out").unwrap_or(950_000),
+        amount_in: extract_number_from_text(log, "amount_in").unwrap_or(0),
+        amount_out: extract_number_from_text(log, "amount_out").unwrap_or(0),
         swap_for_y: detect_trade_type(log).unwrap_or(true),
-        fee: extract_number_from_text(log, "fee").unwrap_or(3000),
+        fee: extract_number_from_text(log, "fee").unwrap_or(0),
         protocol_fee: 0,
         fee_bps: 0,
         host_fee: 0,
@@ -421,8 +540,8 @@ fn parse_add_liquidity_from_text(
         from: solana_sdk::pubkey::Pubkey::default(),
         position: solana_sdk::pubkey::Pubkey::default(),
         amounts: [
-            extract_number_from_text(log, "amount_x").unwrap_or(500_000),
-            extract_number_from_text(log, "amount_y").unwrap_or(500_000),
+            extract_number_from_text(log, "amount_x").unwrap_or(0),
+            extract_number_from_text(log, "amount_y").unwrap_or(0),
         ],
         active_bin_id: 0,
     }))
@@ -447,8 +566,8 @@ fn parse_remove_liquidity_from_text(
         from: solana_sdk::pubkey::Pubkey::default(),
         position: solana_sdk::pubkey::Pubkey::default(),
         amounts: [
-            extract_number_from_text(log, "amount_x").unwrap_or(500_000),
-            extract_number_from_text(log, "amount_y").unwrap_or(500_000),
+            extract_number_from_text(log, "amount_x").unwrap_or(0),
+            extract_number_from_text(log, "amount_y").unwrap_or(0),
         ],
         active_bin_id: 0,
     }))
@@ -472,6 +591,95 @@ fn parse_initialize_pool_from_text(
         pool: solana_sdk::pubkey::Pubkey::default(),
         creator: solana_sdk::pubkey::Pubkey::default(),
         active_bin_id: extract_number_from_text(log, "bin_id").unwrap_or(0) as i32,
-        bin_step: extract_number_from_text(log, "bin_step").unwrap_or(1) as u16,
+        bin_step: extract_number_from_text(log, "bin_step").unwrap_or(0) as u16,
+        token_x_mint: solana_sdk::pubkey::Pubkey::default(),
+        token_y_mint: solana_sdk::pubkey::Pubkey::default(),
     }))
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logs::utils::program_data_log;
+
+    #[test]
+    fn test_parse_initialize_pool_log() {
+        let (pool, token_x, token_y) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let mut body = pool.to_bytes().to_vec();
+        body.extend_from_slice(&25u16.to_le_bytes());
+        body.extend_from_slice(token_x.as_ref());
+        body.extend_from_slice(token_y.as_ref());
+        let hash = solana_program::hash::hash(b"event:LbPairCreate").to_bytes();
+        let log = program_data_log(&hash[..8], &body);
+
+        match crate::logs::parse_log(&log, Signature::default(), 1, 0, Some(0), 0, None, false) {
+            Some(DexEvent::MeteoraDlmmInitializePool(e)) => {
+                assert_eq!(e.pool, pool);
+                assert_eq!(e.bin_step, 25);
+                assert_eq!((e.token_x_mint, e.token_y_mint), (token_x, token_y));
+                // 事件不含创建者，由账户填充器补齐
+                assert_eq!(e.creator, Pubkey::default());
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_claim_reward_log() {
+        let (pool, position, owner) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let mut body = Vec::new();
+        for key in [pool, position, owner] {
+            body.extend_from_slice(key.as_ref());
+        }
+        body.extend_from_slice(&1u64.to_le_bytes());
+        body.extend_from_slice(&42_000u64.to_le_bytes());
+        let log = program_data_log(&discriminators::CLAIM_REWARD_EVENT, &body);
+
+        match crate::logs::parse_log(&log, Signature::default(), 1, 0, Some(0), 0, None, false) {
+            Some(DexEvent::MeteoraDlmmClaimReward(e)) => {
+                assert_eq!(e.pool, pool);
+                assert_eq!(e.position, position);
+                assert_eq!(e.owner, owner);
+                assert_eq!(e.reward_index, 1);
+                assert_eq!(e.total_reward, 42_000);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_fund_reward_log() {
+        let (pool, funder) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut body = Vec::new();
+        body.extend_from_slice(pool.as_ref());
+        body.extend_from_slice(funder.as_ref());
+        body.extend_from_slice(&0u64.to_le_bytes());
+        body.extend_from_slice(&7_500u64.to_le_bytes());
+        let log = program_data_log(&discriminators::FUND_REWARD_EVENT, &body);
+
+        match crate::logs::parse_log(&log, Signature::default(), 1, 0, Some(0), 0, None, false) {
+            Some(DexEvent::MeteoraDlmmFundReward(e)) => {
+                assert_eq!(e.pool, pool);
+                assert_eq!(e.funder, funder);
+                assert_eq!(e.amount, 7_500);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_update_position_operator_log() {
+        let keys = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let body: Vec<u8> = keys.iter().flat_map(|k| k.to_bytes()).collect();
+        let log = program_data_log(&discriminators::UPDATE_POSITION_OPERATOR_EVENT, &body);
+
+        match crate::logs::parse_log(&log, Signature::default(), 1, 0, Some(0), 0, None, false) {
+            Some(DexEvent::MeteoraDlmmUpdatePositionOperator(e)) => {
+                assert_eq!(e.position, keys[0]);
+                assert_eq!(e.old_operator, keys[1]);
+                assert_eq!(e.new_operator, keys[2]);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+}
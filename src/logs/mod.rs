@@ -2,7 +2,8 @@
 //!
 //! Contains log parsers for all DEX protocols
 
-// Allow dead code for fallback text parsers (kept for future use)
+#![cfg_attr(feature = "forbid-unsafe", forbid(unsafe_code))]
+// 文本回退解析器仅在 `fallback-text-logs` feature 下使用
 #![allow(dead_code)]
 #![allow(unused_imports)]
 #![allow(unused_variables)]
@@ -26,6 +27,7 @@ pub mod zero_copy_parser;
 // 导出关键的 utils 函数
 pub use utils::extract_discriminator_fast;
 pub use zero_copy_parser::parse_pumpfun_trade;
+pub use optimized_matcher::{parse_log_with_context, with_decode_context, LogDecodeContext};
 pub use discriminator_lut::{lookup_discriminator, discriminator_to_name, discriminator_to_protocol, parse_with_discriminator};
 
 // 重新导出主要解析函数
@@ -1,57 +1,55 @@
 //! Optimized log matcher with early discriminator filtering
 //!
 //! Performance strategy:
-//! 1. SIMD-based log type detection (~50ns)
+//! 1. Program attribution from the invoke stack ([`InvokeCursor::program`])
 //! 2. Extract discriminator BEFORE full parsing (~50ns)
 //! 3. Check filter at discriminator level - skip parsing if not needed
 //! 4. Only parse events user actually configured
-//! 5. Compiler-optimized base64 decoding (auto-vectorized with target-cpu=native)
+//! 5. SIMD base64 decoding into a per-transaction buffer ([`LogDecodeContext`])
+//!
+//! Protocol detection used to scan each `Program data:` line for program-id substrings
+//! and treated any long unmatched line as PumpFun. Logs carry no program id, so it is now
+//! attributed to the program on top of the invoke stack instead; discriminator collisions
+//! (Launchpad/PumpFun trade, DLMM/CPMM swap, CPMM/CLMM create pool) are resolved by that
+//! program, and logs from other programs are skipped.
+//!
+//! Measured with `cargo bench --bench zero_latency_optimizations -- "Protocol Detection"`
+//! on a 15-line aggregator swap: substring scan ~260ns, invoke attribution ~430ns.
+//! The cursor already runs for instruction indices, so the extra cost is one program-id
+//! comparison per `invoke` line (~11ns per log line overall).
 
-use super::perf_hints::{likely, unlikely};
+use super::perf_hints::likely;
 use crate::core::events::{DexEvent, EventMetadata};
+use crate::core::profile::profile_hit;
 use crate::grpc::types::{EventType, EventTypeFilter};
+use crate::instr::inner_common::zc_read;
 use memchr::memmem;
 use once_cell::sync::Lazy;
 use solana_sdk::signature::Signature;
 
 /// SIMD 优化的字符串查找器 - 预编译一次，重复使用
-static PUMPFUN_FINDER: Lazy<memmem::Finder> =
-    Lazy::new(|| memmem::Finder::new(b"6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P"));
-static RAYDIUM_AMM_FINDER: Lazy<memmem::Finder> =
-    Lazy::new(|| memmem::Finder::new(b"675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8"));
-static RAYDIUM_CLMM_FINDER: Lazy<memmem::Finder> =
-    Lazy::new(|| memmem::Finder::new(b"CAMMCzo5YL8w4VFF8KVHrK22GGUQpMdRBFSzKNT3t4ivN6"));
-static RAYDIUM_CPMM_FINDER: Lazy<memmem::Finder> =
-    Lazy::new(|| memmem::Finder::new(b"CPMDWBwJDtYax9qKcQP3CtKz7tHjJsN3H8hGrYVD9mZD"));
-static BONK_FINDER: Lazy<memmem::Finder> =
-    Lazy::new(|| memmem::Finder::new(b"Bxby5A7E8xPDGGc3FyJw7m5eK5aqNVLU83H2zLTQDH1b"));
-static PROGRAM_FINDER: Lazy<memmem::Finder> = Lazy::new(|| memmem::Finder::new(b"Program"));
 static PROGRAM_DATA_FINDER: Lazy<memmem::Finder> =
     Lazy::new(|| memmem::Finder::new(b"Program data: "));
 static PUMPFUN_CREATE_FINDER: Lazy<memmem::Finder> =
     Lazy::new(|| memmem::Finder::new(b"Program data: G3KpTd7rY3Y"));
-    static WHIRL_FINDER: Lazy<memmem::Finder> = Lazy::new(|| memmem::Finder::new(b"whirL"));
-static METEORA_FINDER: Lazy<memmem::Finder> = Lazy::new(|| memmem::Finder::new(b"meteora"));
-static METEORA_LB_FINDER: Lazy<memmem::Finder> = Lazy::new(|| memmem::Finder::new(b"LB"));
-static METEORA_DLMM_FINDER: Lazy<memmem::Finder> = Lazy::new(|| memmem::Finder::new(b"DLMM"));
-static PUMPSWAP_LOWER_FINDER: Lazy<memmem::Finder> = Lazy::new(|| memmem::Finder::new(b"pumpswap"));
-static PUMPSWAP_UPPER_FINDER: Lazy<memmem::Finder> = Lazy::new(|| memmem::Finder::new(b"PumpSwap"));
 
 /// 预计算的程序 ID 字符串常量
 pub mod program_id_strings {
+    use crate::grpc::program_ids::raydium_clmm_program_id;
+
     pub const PUMPFUN_INVOKE: &str = "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P invoke";
     pub const PUMPFUN_SUCCESS: &str = "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P success";
     pub const PUMPFUN_ID: &str = "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P";
 
-    pub const BONK_INVOKE: &str = "Program Bxby5A7E8xPDGGc3FyJw7m5eK5aqNVLU83H2zLTQDH1b invoke";
-    pub const BONK_SUCCESS: &str = "Program Bxby5A7E8xPDGGc3FyJw7m5eK5aqNVLU83H2zLTQDH1b success";
-    pub const BONK_ID: &str = "Bxby5A7E8xPDGGc3FyJw7m5eK5aqNVLU83H2zLTQDH1b";
+    pub const BONK_INVOKE: &str = "Program LanMV9sAd7wArD4vJFi2qDdfnVhFxYSUg6eADduJ3uj invoke";
+    pub const BONK_SUCCESS: &str = "Program LanMV9sAd7wArD4vJFi2qDdfnVhFxYSUg6eADduJ3uj success";
+    pub const BONK_ID: &str = "LanMV9sAd7wArD4vJFi2qDdfnVhFxYSUg6eADduJ3uj";
 
     pub const RAYDIUM_CLMM_INVOKE: &str =
-        "Program CAMMCzo5YL8w4VFF8KVHrK22GGUQpMdRBFSzKNT3t4ivN6 invoke";
+        concat!("Program ", raydium_clmm_program_id!(), " invoke");
     pub const RAYDIUM_CLMM_SUCCESS: &str =
-        "Program CAMMCzo5YL8w4VFF8KVHrK22GGUQpMdRBFSzKNT3t4ivN6 success";
-    pub const RAYDIUM_CLMM_ID: &str = "CAMMCzo5YL8w4VFF8KVHrK22GGUQpMdRBFSzKNT3t4ivN6";
+        concat!("Program ", raydium_clmm_program_id!(), " success");
+    pub const RAYDIUM_CLMM_ID: &str = crate::grpc::program_ids::RAYDIUM_CLMM_PROGRAM_ID;
 
     pub const RAYDIUM_CPMM_INVOKE: &str =
         "Program CPMDWBwJDtYax9qKcQP3CtKz7tHjJsN3H8hGrYVD9mZD invoke";
@@ -69,12 +67,12 @@ pub mod program_id_strings {
     pub const PUMPFUN_CREATE_DISCRIMINATOR: &str = "GB7IKAUcB3c"; // [24, 30, 200, 40, 5, 28, 7, 119]
 }
 
-/// 快速日志类型枚举
-#[derive(Debug, Copy, Clone, PartialEq)]
-pub enum LogType {
+/// 输出日志的程序（由调用栈归属，见 [`InvokeCursor::program`]）
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LogProgram {
     PumpFun,
+    PumpSwap,
     RaydiumLaunchpad,
-    PumpAmm,
     RaydiumClmm,
     RaydiumCpmm,
     RaydiumAmm,
@@ -82,92 +80,102 @@ pub enum LogType {
     MeteoraAmm,
     MeteoraDamm,
     MeteoraDlmm,
-    Unknown,
+    /// 不支持的程序，其输出的日志直接跳过
+    Other,
 }
 
-/// SIMD 优化的日志类型检测器 - 激进早期退出
-#[inline(always)]
-pub fn detect_log_type(log: &str) -> LogType {
-    let log_bytes = log.as_bytes();
-
-    // 第一步：快速长度检查 - 太短的日志直接跳过
-    if log_bytes.len() < 20 {
-        return LogType::Unknown;
-    }
-
-    // 第二步：检查是否有 "Program data:" - 这是事件日志的标志
-    let has_program_data = PROGRAM_DATA_FINDER.find(log_bytes).is_some();
-
-    // 只有 "Program data:" 日志才可能是交易事件
-    if unlikely(!has_program_data) {
-        return LogType::Unknown;
-    }
-
-    // 第三步：使用 SIMD 快速检测具体协议
-    // Raydium AMM - 高频，有明确程序ID（最常见）
-    if likely(RAYDIUM_AMM_FINDER.find(log_bytes).is_some()) {
-        return LogType::RaydiumAmm;
-    }
-
-    // Raydium CLMM
-    if RAYDIUM_CLMM_FINDER.find(log_bytes).is_some() {
-        return LogType::RaydiumClmm;
-    }
-
-    // Raydium CPMM
-    if RAYDIUM_CPMM_FINDER.find(log_bytes).is_some() {
-        return LogType::RaydiumCpmm;
-    }
-
-    // Raydium Launchpad (Bonk)
-    if BONK_FINDER.find(log_bytes).is_some() {
-        return LogType::RaydiumLaunchpad;
-    }
-
-    // Orca Whirlpool
-    if WHIRL_FINDER.find(log_bytes).is_some() {
-        return LogType::OrcaWhirlpool;
-    }
-
-    // Meteora - SIMD 优化
-    if let Some(pos) = METEORA_FINDER.find(log_bytes) {
-        let rest = &log_bytes[pos..];
-        if METEORA_LB_FINDER.find(rest).is_some() {
-            return LogType::MeteoraDamm;
-        } else if METEORA_DLMM_FINDER.find(rest).is_some() {
-            return LogType::MeteoraDlmm;
-        } else {
-            return LogType::MeteoraAmm;
+impl LogProgram {
+    #[inline]
+    pub fn from_program_id(program_id: &str) -> Self {
+        use crate::grpc::program_ids::*;
+        match program_id {
+            PUMPFUN_PROGRAM_ID => LogProgram::PumpFun,
+            PUMPSWAP_PROGRAM_ID => LogProgram::PumpSwap,
+            BONK_PROGRAM_ID => LogProgram::RaydiumLaunchpad,
+            RAYDIUM_CLMM_PROGRAM_ID => LogProgram::RaydiumClmm,
+            RAYDIUM_CPMM_PROGRAM_ID => LogProgram::RaydiumCpmm,
+            RAYDIUM_AMM_V4_PROGRAM_ID => LogProgram::RaydiumAmm,
+            ORCA_WHIRLPOOL_PROGRAM_ID => LogProgram::OrcaWhirlpool,
+            METEORA_POOLS_PROGRAM_ID => LogProgram::MeteoraAmm,
+            METEORA_DAMM_V2_PROGRAM_ID => LogProgram::MeteoraDamm,
+            METEORA_DLMM_PROGRAM_ID => LogProgram::MeteoraDlmm,
+            _ => LogProgram::Other,
         }
     }
 
-    // Pump AMM
-    if PUMPSWAP_LOWER_FINDER.find(log_bytes).is_some()
-        || PUMPSWAP_UPPER_FINDER.find(log_bytes).is_some()
-    {
-        return LogType::PumpAmm;
-    }
-
-    // PumpFun - 特殊处理：可能有程序ID，也可能直接是base64数据
-    // 1. 先检查是否包含程序ID（高频事件）
-    if likely(PUMPFUN_FINDER.find(log_bytes).is_some()) {
-        return LogType::PumpFun;
-    }
-
-    // 2. 兜底：有 "Program data:" 但无法识别协议的，尝试作为 PumpFun 解析
-    // PumpFun的日志格式：Program data: <base64>
-    // 只要日志够长且包含Program data，就认为可能是PumpFun
-    if log.len() > 30 {
-        return LogType::PumpFun;
+    /// 该程序是否会输出此 discriminator 的事件
+    ///
+    /// Meteora DAMM V2 / DLMM 由各自的日志解析器校验 discriminator。
+    #[inline]
+    fn emits(self, discriminator: u64) -> bool {
+        use discriminators::*;
+        match self {
+            LogProgram::PumpFun => {
+                matches!(discriminator, PUMPFUN_CREATE | PUMPFUN_TRADE | PUMPFUN_MIGRATE)
+            }
+            LogProgram::PumpSwap => matches!(
+                discriminator,
+                PUMPSWAP_BUY
+                    | PUMPSWAP_SELL
+                    | PUMPSWAP_CREATE_POOL
+                    | PUMPSWAP_ADD_LIQUIDITY
+                    | PUMPSWAP_REMOVE_LIQUIDITY
+                    | PUMPSWAP_UPDATE_FEE_CONFIG
+                    | PUMPSWAP_DISABLE
+                    | PUMPSWAP_ADMIN_SET_COIN_CREATOR
+                    | PUMPSWAP_SET_BONDING_CURVE_COIN_CREATOR
+                    | PUMPSWAP_SET_METAPLEX_COIN_CREATOR
+                    | PUMPSWAP_COLLECT_COIN_CREATOR_FEE
+            ),
+            LogProgram::RaydiumLaunchpad => {
+                matches!(discriminator, PUMPFUN_TRADE | RAYDIUM_LAUNCHPAD_POOL_CREATE)
+            }
+            LogProgram::RaydiumClmm => matches!(
+                discriminator,
+                RAYDIUM_CLMM_SWAP
+                    | RAYDIUM_CLMM_INCREASE_LIQUIDITY
+                    | RAYDIUM_CLMM_DECREASE_LIQUIDITY
+                    | RAYDIUM_CLMM_CREATE_POOL
+                    | RAYDIUM_CLMM_COLLECT_FEE
+            ),
+            LogProgram::RaydiumCpmm => matches!(
+                discriminator,
+                RAYDIUM_CPMM_SWAP_BASE_IN
+                    | RAYDIUM_CPMM_SWAP_BASE_OUT
+                    | RAYDIUM_CPMM_DEPOSIT
+                    | RAYDIUM_CPMM_WITHDRAW
+            ),
+            LogProgram::RaydiumAmm => matches!(
+                discriminator,
+                RAYDIUM_AMM_SWAP_BASE_IN
+                    | RAYDIUM_AMM_SWAP_BASE_OUT
+                    | RAYDIUM_AMM_DEPOSIT
+                    | RAYDIUM_AMM_WITHDRAW
+                    | RAYDIUM_AMM_INITIALIZE2
+            ),
+            LogProgram::OrcaWhirlpool => matches!(
+                discriminator,
+                ORCA_TRADED | ORCA_LIQUIDITY_INCREASED | ORCA_LIQUIDITY_DECREASED | ORCA_POOL_INITIALIZED
+            ),
+            LogProgram::MeteoraAmm => matches!(
+                discriminator,
+                METEORA_AMM_SWAP
+                    | METEORA_AMM_ADD_LIQUIDITY
+                    | METEORA_AMM_REMOVE_LIQUIDITY
+                    | METEORA_AMM_BOOTSTRAP_LIQUIDITY
+                    | METEORA_AMM_POOL_CREATED
+                    | METEORA_AMM_SET_POOL_FEES
+            ),
+            LogProgram::MeteoraDamm | LogProgram::MeteoraDlmm => true,
+            LogProgram::Other => false,
+        }
     }
-
-    LogType::Unknown
 }
 
 // ============================================================================
 // Discriminator constants (compile-time computed) - All protocols
 // ============================================================================
-mod discriminators {
+pub(crate) mod discriminators {
     // PumpFun discriminators
     pub const PUMPFUN_CREATE: u64 = u64::from_le_bytes([27, 114, 169, 77, 222, 235, 99, 118]);
     pub const PUMPFUN_TRADE: u64 = u64::from_le_bytes([189, 219, 127, 211, 78, 230, 97, 238]);
@@ -179,9 +187,19 @@ mod discriminators {
     pub const PUMPSWAP_CREATE_POOL: u64 = u64::from_le_bytes([177, 49, 12, 210, 160, 118, 167, 116]);
     pub const PUMPSWAP_ADD_LIQUIDITY: u64 = u64::from_le_bytes([120, 248, 61, 83, 31, 142, 107, 144]);
     pub const PUMPSWAP_REMOVE_LIQUIDITY: u64 = u64::from_le_bytes([22, 9, 133, 26, 160, 44, 71, 192]);
+    pub const PUMPSWAP_UPDATE_FEE_CONFIG: u64 = u64::from_le_bytes([90, 23, 65, 35, 62, 244, 188, 208]);
+    pub const PUMPSWAP_DISABLE: u64 = u64::from_le_bytes([107, 253, 193, 76, 228, 202, 27, 104]);
+    pub const PUMPSWAP_ADMIN_SET_COIN_CREATOR: u64 = u64::from_le_bytes([45, 220, 93, 24, 25, 97, 172, 104]);
+    pub const PUMPSWAP_SET_BONDING_CURVE_COIN_CREATOR: u64 = u64::from_le_bytes([242, 231, 235, 102, 65, 99, 189, 211]);
+    pub const PUMPSWAP_SET_METAPLEX_COIN_CREATOR: u64 = u64::from_le_bytes([150, 107, 199, 123, 124, 207, 102, 228]);
+    pub const PUMPSWAP_COLLECT_COIN_CREATOR_FEE: u64 = u64::from_le_bytes([232, 245, 194, 238, 234, 218, 58, 89]);
+
+    // Raydium Launchpad (Bonk) discriminators
+    // Note: Launchpad TradeEvent == PUMPFUN_TRADE, handled in the PumpFun hot path
+    pub const RAYDIUM_LAUNCHPAD_POOL_CREATE: u64 = u64::from_le_bytes([151, 215, 226, 9, 118, 161, 115, 174]);
     
     // Raydium CLMM discriminators
-    pub const RAYDIUM_CLMM_SWAP: u64 = u64::from_le_bytes([248, 198, 158, 145, 225, 117, 135, 200]);
+    pub const RAYDIUM_CLMM_SWAP: u64 = u64::from_le_bytes([64, 198, 205, 232, 38, 8, 113, 226]);
     pub const RAYDIUM_CLMM_INCREASE_LIQUIDITY: u64 = u64::from_le_bytes([133, 29, 89, 223, 69, 238, 176, 10]);
     pub const RAYDIUM_CLMM_DECREASE_LIQUIDITY: u64 = u64::from_le_bytes([160, 38, 208, 111, 104, 91, 44, 1]);
     pub const RAYDIUM_CLMM_CREATE_POOL: u64 = u64::from_le_bytes([233, 146, 209, 142, 207, 104, 64, 188]);
@@ -213,6 +231,7 @@ mod discriminators {
     pub const METEORA_AMM_REMOVE_LIQUIDITY: u64 = u64::from_le_bytes([116, 244, 97, 232, 103, 31, 152, 58]);
     pub const METEORA_AMM_BOOTSTRAP_LIQUIDITY: u64 = u64::from_le_bytes([121, 127, 38, 136, 92, 55, 14, 247]);
     pub const METEORA_AMM_POOL_CREATED: u64 = u64::from_le_bytes([202, 44, 41, 88, 104, 220, 157, 82]);
+    pub const METEORA_AMM_SET_POOL_FEES: u64 = u64::from_le_bytes([245, 26, 198, 164, 88, 18, 75, 9]);
     
     // Meteora DAMM V2 discriminators
     pub const METEORA_DAMM_SWAP: u64 = u64::from_le_bytes([27, 60, 21, 213, 138, 170, 187, 147]);
@@ -227,11 +246,105 @@ mod discriminators {
     pub const METEORA_DLMM_SWAP: u64 = u64::from_le_bytes([143, 190, 90, 218, 196, 30, 51, 222]);
     pub const METEORA_DLMM_ADD_LIQUIDITY: u64 = u64::from_le_bytes([181, 157, 89, 67, 143, 182, 52, 72]);
     pub const METEORA_DLMM_REMOVE_LIQUIDITY: u64 = u64::from_le_bytes([80, 85, 209, 72, 24, 206, 35, 178]);
-    pub const METEORA_DLMM_INITIALIZE_POOL: u64 = u64::from_le_bytes([95, 180, 10, 172, 84, 174, 232, 40]);
+    pub const METEORA_DLMM_INITIALIZE_POOL: u64 = u64::from_le_bytes([185, 74, 252, 125, 27, 215, 188, 111]);
     pub const METEORA_DLMM_CREATE_POSITION: u64 = u64::from_le_bytes([123, 233, 11, 43, 146, 180, 97, 119]);
     pub const METEORA_DLMM_CLOSE_POSITION: u64 = u64::from_le_bytes([94, 168, 102, 45, 59, 122, 137, 54]);
 }
 
+/// Program data 的最大 base64 长度（解码后不超过 2048 字节）
+const MAX_PROGRAM_DATA_B64_LEN: usize = 2700;
+
+/// 提取 "Program data: " 之后的 base64 内容
+#[inline(always)]
+fn program_data_b64(log: &str) -> Option<&[u8]> {
+    let pos = PROGRAM_DATA_FINDER.find(log.as_bytes())?;
+    let trimmed = log.get(pos + 14..)?.trim(); // "Program data: " length
+    if trimmed.is_empty() || trimmed.len() > MAX_PROGRAM_DATA_B64_LEN {
+        return None;
+    }
+    Some(trimmed.as_bytes())
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DecodeSlot {
+    Pending,
+    Invalid,
+    Decoded { start: u32, len: u32 },
+}
+
+/// 单笔交易的日志解码上下文
+///
+/// 同一交易的所有日志共用一块解码缓冲区，解码结果按日志下标记录，
+/// 重复查询同一条日志时不再解码。处理下一笔交易前调用 [`LogDecodeContext::clear`]。
+#[derive(Debug)]
+pub struct LogDecodeContext {
+    buf: Vec<u8>,
+    slots: Vec<DecodeSlot>,
+}
+
+impl Default for LogDecodeContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LogDecodeContext {
+    pub fn new() -> Self {
+        Self { buf: Vec::with_capacity(2048), slots: Vec::with_capacity(32) }
+    }
+
+    /// 清空解码结果，保留已分配的内存
+    #[inline]
+    pub fn clear(&mut self) {
+        self.buf.clear();
+        self.slots.clear();
+    }
+
+    /// 解码第 `index` 条日志的 Program data，不是有效 Program data 时返回 None
+    #[inline]
+    pub fn decode(&mut self, index: usize, log: &str) -> Option<&[u8]> {
+        if index >= self.slots.len() {
+            self.slots.resize(index + 1, DecodeSlot::Pending);
+        }
+        if let DecodeSlot::Pending = self.slots[index] {
+            self.slots[index] = self.decode_into_buf(log).unwrap_or(DecodeSlot::Invalid);
+        }
+        match self.slots[index] {
+            DecodeSlot::Decoded { start, len } => {
+                Some(&self.buf[start as usize..(start + len) as usize])
+            }
+            _ => None,
+        }
+    }
+
+    fn decode_into_buf(&mut self, log: &str) -> Option<DecodeSlot> {
+        use base64_simd::AsOut;
+        let src = program_data_b64(log)?;
+        let start = self.buf.len();
+        let capacity = base64_simd::STANDARD.decoded_length(src).ok()?;
+        self.buf.resize(start + capacity, 0);
+        let len = base64_simd::STANDARD.decode(src, self.buf[start..].as_out()).map(|d| d.len());
+        self.buf.truncate(start + len.as_ref().map_or(0, |&len| len));
+        let len = len.ok()?;
+        Some(DecodeSlot::Decoded { start: start as u32, len: len as u32 })
+    }
+}
+
+thread_local! {
+    static DECODE_CONTEXT: std::cell::RefCell<LogDecodeContext> =
+        std::cell::RefCell::new(LogDecodeContext::new());
+}
+
+/// 使用当前线程复用的解码上下文（调用前已清空）
+#[inline]
+pub fn with_decode_context<R>(f: impl FnOnce(&mut LogDecodeContext) -> R) -> R {
+    DECODE_CONTEXT.with(|ctx| {
+        let mut ctx = ctx.borrow_mut();
+        ctx.clear();
+        f(&mut ctx)
+    })
+}
+
 /// Optimized unified log parser with **single-decode, early-filter** strategy
 /// 
 /// **Performance Strategy**:
@@ -255,51 +368,148 @@ pub fn parse_log_optimized(
     is_created_buy: bool,
 ) -> Option<DexEvent> {
     // Step 1: Find "Program data: " prefix using SIMD
-    let log_bytes = log.as_bytes();
-    let pos = PROGRAM_DATA_FINDER.find(log_bytes)?;
-    let data_start = pos + 14; // "Program data: " length
-    
-    if log_bytes.len() <= data_start {
+    let src = program_data_b64(log);
+    if src.is_none() {
+        profile_hit!(OtherLog);
+    }
+    let src = src?;
+
+    // Step 2: Decode base64 ONCE to stack buffer (SIMD-accelerated, zero heap allocation)
+    let mut buf = [0u8; 2048];
+    use base64_simd::AsOut;
+    let program_data = base64_simd::STANDARD.decode(src, buf.as_mut().as_out()).ok()?;
+
+    let metadata = EventMetadata {
+        signature,
+        slot,
+        tx_index,
+        block_time_us: block_time_us.unwrap_or(0),
+        grpc_recv_us,
+        ..Default::default()
+    };
+    parse_decoded_log(log, program_data, metadata, None, event_type_filter, is_created_buy)
+}
+
+/// 使用交易级解码上下文解析第 `index` 条日志，同一交易内的日志共用解码缓冲区
+///
+/// `program` 为调用栈归属的输出程序（[`InvokeCursor::program`]），用于处理
+/// discriminator 冲突并跳过其他程序输出的日志；None 时仅按 discriminator 路由。
+/// 启用 `fallback-text-logs` feature 时，不是有效 Program data 的日志交给 [`parse_text_fallback`]。
+#[inline(always)]
+pub fn parse_log_with_context(
+    ctx: &mut LogDecodeContext,
+    index: usize,
+    log: &str,
+    metadata: EventMetadata,
+    program: Option<LogProgram>,
+    event_type_filter: Option<&EventTypeFilter>,
+    is_created_buy: bool,
+) -> Option<DexEvent> {
+    let Some(program_data) = ctx.decode(index, log) else {
+        profile_hit!(OtherLog);
+        #[cfg(feature = "fallback-text-logs")]
+        return parse_text_fallback(log, metadata, program, event_type_filter);
+        #[cfg(not(feature = "fallback-text-logs"))]
+        return None;
+    };
+    parse_decoded_log(log, program_data, metadata, program, event_type_filter, is_created_buy)
+}
+
+/// 文本日志回退：按输出程序解析人类可读的 `Program log:` 行
+///
+/// 只处理调用栈归属明确的程序；PumpFun / PumpSwap 始终输出 Anchor 事件，不做回退。
+/// Anchor 的 `Instruction: Xxx` 行与随后的 Program data 描述同一次调用，跳过以免重复。
+/// 文本中没有的数值为 0，账户字段由账户填充器补全。
+#[cfg(feature = "fallback-text-logs")]
+#[cold]
+fn parse_text_fallback(
+    log: &str,
+    metadata: EventMetadata,
+    program: Option<LogProgram>,
+    event_type_filter: Option<&EventTypeFilter>,
+) -> Option<DexEvent> {
+    let text = log.strip_prefix("Program log: ")?;
+    if text.starts_with("Instruction: ") {
         return None;
     }
-    
-    // Step 2: Decode base64 ONCE to stack buffer (compiler auto-vectorized, zero heap allocation)
-    let mut buf = [0u8; 2048];  // Increased back to 2048 to prevent buffer overflow panics
-    let data_part = &log[data_start..];
-    let trimmed = data_part.trim();
-
-    // Validate input size before decoding (base64: 4 chars -> 3 bytes, so max input = (2048/3)*4 = ~2730 chars)
-    // Add safety margin to prevent base64-simd assertion failures
-    if trimmed.len() > 2700 {
+
+    let EventMetadata { signature, slot, tx_index, block_time_us, grpc_recv_us, .. } = metadata;
+    let block_time_us = Some(block_time_us);
+    let event = match program? {
+        LogProgram::RaydiumLaunchpad => crate::logs::raydium_launchpad::parse_text_log(
+            tx_index, text, signature, slot, block_time_us, grpc_recv_us,
+        ),
+        LogProgram::RaydiumClmm => crate::logs::raydium_clmm::parse_text_log(
+            text, signature, slot, tx_index, block_time_us, grpc_recv_us,
+        ),
+        LogProgram::RaydiumCpmm => crate::logs::raydium_cpmm::parse_text_log(
+            text, signature, slot, tx_index, block_time_us, grpc_recv_us,
+        ),
+        LogProgram::RaydiumAmm => crate::logs::raydium_amm::parse_text_log(
+            text, signature, slot, tx_index, block_time_us, grpc_recv_us,
+        ),
+        LogProgram::MeteoraDlmm => crate::logs::meteora_dlmm::parse_text_log(
+            text, signature, slot, tx_index, block_time_us, grpc_recv_us,
+        ),
+        _ => None,
+    }?;
+
+    // 与 Program data 路径一致：不可按类型过滤的协议事件只在未设置 include_only 时输出
+    if let Some(filter) = event_type_filter {
+        let event_type = match &event {
+            DexEvent::BonkTrade(_) => Some(EventType::BonkTrade),
+            DexEvent::BonkPoolCreate(_) => Some(EventType::BonkPoolCreate),
+            DexEvent::BonkMigrateAmm(_) => Some(EventType::BonkMigrateAmm),
+            _ => None,
+        };
+        let included = match event_type {
+            Some(event_type) => filter.should_include(event_type),
+            None => filter.include_only.is_none(),
+        };
+        if !included {
+            return None;
+        }
+    }
+    Some(event)
+}
+
+/// 从已解码的 Program data 解析事件
+#[inline(always)]
+fn parse_decoded_log(
+    log: &str,
+    program_data: &[u8],
+    metadata: EventMetadata,
+    program: Option<LogProgram>,
+    event_type_filter: Option<&EventTypeFilter>,
+    is_created_buy: bool,
+) -> Option<DexEvent> {
+    profile_hit!(ProgramData);
+    if program_data.len() < 8 {
         return None;
     }
 
-    // SIMD-accelerated base64 decoding (AVX2/SSE4/NEON)
-    use base64_simd::AsOut;
-    let decoded_slice = base64_simd::STANDARD
-        .decode(trimmed.as_bytes(), buf.as_mut().as_out())
-        .ok()?;
-    let decoded_len = decoded_slice.len();
+    // Step 3: Extract discriminator (~5ns, just read 8 bytes)
+    let discriminator = zc_read!(u64, program_data, 0);
     
-    if decoded_len < 8 {
+    // Step 4: Program attribution - skip logs from other programs / mismatched discriminators
+    if program.is_some_and(|p| !p.emits(discriminator)) {
+        profile_hit!(ProgramMismatch);
         return None;
     }
-    
-    let program_data = &buf[..decoded_len];
-    
-    // Step 3: Extract discriminator (~5ns, just read 8 bytes)
-    let discriminator = unsafe {
-        let ptr = program_data.as_ptr() as *const u64;
-        ptr.read_unaligned()
+
+    // Map discriminator to EventType for early filtering
+    let event_type = match program {
+        Some(LogProgram::RaydiumLaunchpad) if discriminator == discriminators::PUMPFUN_TRADE => {
+            Some(EventType::BonkTrade)
+        }
+        _ => discriminator_to_event_type(discriminator),
     };
     
-    // Step 4: Map discriminator to EventType for early filtering
-    let event_type = discriminator_to_event_type(discriminator);
-    
     // Step 5: Early filter check - BEFORE parsing any fields!
     if let Some(filter) = event_type_filter {
         if let Some(et) = event_type {
             if !filter.should_include(et) {
+                profile_hit!(FilterRejected);
                 return None; // Skip ALL parsing - saves ~200-500ns
             }
         } else {
@@ -312,6 +522,7 @@ pub fn parse_log_optimized(
                     EventType::PumpSwapLiquidityAdded | EventType::PumpSwapLiquidityRemoved
                 ));
                 if !wants_supported {
+                    profile_hit!(FilterRejected);
                     return None;
                 }
             }
@@ -323,13 +534,32 @@ pub fn parse_log_optimized(
 
     use crate::core::events::*;
 
-    let metadata = EventMetadata {
-        signature,
-        slot,
-        tx_index,
-        block_time_us: block_time_us.unwrap_or(0),
-        grpc_recv_us,
-    };
+    // Meteora DAMM V2 / DLMM 与 Raydium CPMM 存在 discriminator 冲突，归属明确时直接交给对应解析器
+    match program {
+        Some(LogProgram::MeteoraDamm) => {
+            profile_hit!(AttributedRoute);
+            return crate::logs::parse_meteora_damm_log(
+                log,
+                metadata.signature,
+                metadata.slot,
+                metadata.tx_index,
+                Some(metadata.block_time_us),
+                metadata.grpc_recv_us,
+            );
+        }
+        Some(LogProgram::MeteoraDlmm) => {
+            profile_hit!(AttributedRoute);
+            return crate::logs::parse_meteora_dlmm_log(
+                log,
+                metadata.signature,
+                metadata.slot,
+                metadata.tx_index,
+                Some(metadata.block_time_us),
+                metadata.grpc_recv_us,
+            );
+        }
+        _ => {}
+    }
 
     // ========================================================================
     // Hot-path optimization: Fast check for top 5 most common discriminators
@@ -340,12 +570,28 @@ pub fn parse_log_optimized(
     // Check hot-path discriminators first (ordered by frequency)
     if likely(discriminator == discriminators::PUMPFUN_TRADE) {
         // PumpFun Trade - Most common (~40% of all events)
-        let event = crate::logs::pump::parse_trade_from_data(data, metadata, is_created_buy)?;
+        profile_hit!(HotPathHit);
+        // Raydium Launchpad TradeEvent 共享同一 discriminator：归属已知时直接路由，
+        // 否则按数据长度区分（Launchpad 数据更短，PumpFun 解析失败）
+        let pumpfun = match program {
+            Some(LogProgram::RaydiumLaunchpad) => None,
+            _ => crate::logs::pump::parse_trade_from_data(data, metadata, is_created_buy),
+        };
+        let Some(event) = pumpfun else {
+            if let Some(filter) = event_type_filter {
+                if !filter.should_include(EventType::BonkTrade) {
+                    profile_hit!(FilterRejected);
+                    return None;
+                }
+            }
+            return crate::logs::raydium_launchpad::parse_trade_from_data(data, metadata);
+        };
         // Secondary filter check
         if let Some(filter) = event_type_filter {
             if let Some(ref include_only) = filter.include_only {
                 let has_specific_filter = include_only.iter().any(|t| matches!(t,
                     EventType::PumpFunBuy | EventType::PumpFunSell | EventType::PumpFunBuyExactSolIn
+                        | EventType::BonkTrade
                 ));
                 if has_specific_filter {
                     let event_type_matches = match &event {
@@ -356,6 +602,7 @@ pub fn parse_log_optimized(
                         _ => false,
                     };
                     if !event_type_matches {
+                        profile_hit!(FilterRejected);
                         return None;
                     }
                 }
@@ -366,21 +613,25 @@ pub fn parse_log_optimized(
 
     if likely(discriminator == discriminators::RAYDIUM_CLMM_SWAP) {
         // Raydium CLMM Swap - High frequency (~20% of events)
+        profile_hit!(HotPathHit);
         return crate::logs::raydium_clmm::parse_swap_from_data(data, metadata);
     }
 
     if likely(discriminator == discriminators::RAYDIUM_AMM_SWAP_BASE_IN) {
         // Raydium AMM Swap Base In - High frequency (~15% of events)
+        profile_hit!(HotPathHit);
         return crate::logs::raydium_amm::parse_swap_base_in_from_data(data, metadata);
     }
 
     if likely(discriminator == discriminators::PUMPSWAP_BUY) {
         // PumpSwap Buy - Medium frequency (~10% of events)
+        profile_hit!(HotPathHit);
         return crate::logs::pump_amm::parse_buy_from_data(data, metadata);
     }
 
     if discriminator == discriminators::PUMPSWAP_SELL {
         // PumpSwap Sell - Medium frequency (~5% of events)
+        profile_hit!(HotPathHit);
         return crate::logs::pump_amm::parse_sell_from_data(data, metadata);
     }
 
@@ -388,6 +639,7 @@ pub fn parse_log_optimized(
     // Cold path: Handle remaining ~10% of events via match statement
     // ========================================================================
 
+    profile_hit!(ColdPath);
     match discriminator {
         // Note: Hot-path discriminators (PUMPFUN_TRADE, RAYDIUM_CLMM_SWAP, RAYDIUM_AMM_SWAP_BASE_IN,
         // PUMPSWAP_BUY, PUMPSWAP_SELL) are handled above and never reach this match statement
@@ -410,8 +662,21 @@ pub fn parse_log_optimized(
         discriminators::PUMPSWAP_REMOVE_LIQUIDITY => {
             crate::logs::pump_amm::parse_remove_liquidity_from_data(data, metadata)
         }
+        discriminators::PUMPSWAP_UPDATE_FEE_CONFIG
+        | discriminators::PUMPSWAP_DISABLE
+        | discriminators::PUMPSWAP_ADMIN_SET_COIN_CREATOR
+        | discriminators::PUMPSWAP_SET_BONDING_CURVE_COIN_CREATOR
+        | discriminators::PUMPSWAP_SET_METAPLEX_COIN_CREATOR
+        | discriminators::PUMPSWAP_COLLECT_COIN_CREATOR_FEE => {
+            crate::logs::pump_amm::parse_admin_from_data(discriminator, data, metadata)
+        }
 
         // ========== Other protocols - route by discriminator ==========
+        // Raydium Launchpad (Bonk)
+        discriminators::RAYDIUM_LAUNCHPAD_POOL_CREATE => {
+            crate::logs::raydium_launchpad::parse_pool_create_from_data(data, metadata)
+        }
+
         // Raydium CLMM - use from_data functions (cold path)
         discriminators::RAYDIUM_CLMM_INCREASE_LIQUIDITY => {
             crate::logs::raydium_clmm::parse_increase_liquidity_from_data(data, metadata)
@@ -433,8 +698,8 @@ pub fn parse_log_optimized(
         discriminators::RAYDIUM_CPMM_SWAP_BASE_OUT => {
             crate::logs::raydium_cpmm::parse_swap_base_out_from_data(data, metadata)
         }
-        // Note: RAYDIUM_CPMM_CREATE_POOL discriminator conflicts with RAYDIUM_CLMM_CREATE_POOL
-        // CPMM create pool is rare, handled via log content detection if needed
+        // Note: CPMM emits no create-pool event (RAYDIUM_CPMM_CREATE_POOL == RAYDIUM_CLMM_CREATE_POOL);
+        // pool creation is parsed from the initialize instruction
         discriminators::RAYDIUM_CPMM_DEPOSIT => {
             crate::logs::raydium_cpmm::parse_deposit_from_data(data, metadata)
         }
@@ -489,6 +754,9 @@ pub fn parse_log_optimized(
         discriminators::METEORA_AMM_POOL_CREATED => {
             crate::logs::meteora_amm::parse_pool_created_from_data(data, metadata)
         }
+        discriminators::METEORA_AMM_SET_POOL_FEES => {
+            crate::logs::meteora_amm::parse_set_pool_fees_from_data(data, metadata)
+        }
         
         // Meteora DAMM V2
         discriminators::METEORA_DAMM_SWAP |
@@ -498,17 +766,32 @@ pub fn parse_log_optimized(
         discriminators::METEORA_DAMM_INITIALIZE_POOL |
         discriminators::METEORA_DAMM_CREATE_POSITION |
         discriminators::METEORA_DAMM_CLOSE_POSITION => {
-            crate::logs::parse_meteora_damm_log(log, signature, slot, tx_index, block_time_us, grpc_recv_us)
+            crate::logs::parse_meteora_damm_log(
+                log,
+                metadata.signature,
+                metadata.slot,
+                metadata.tx_index,
+                Some(metadata.block_time_us),
+                metadata.grpc_recv_us,
+            )
         }
         
         // NOTE: Meteora DLMM discriminators conflict with Raydium CPMM!
         // METEORA_DLMM_SWAP == RAYDIUM_CPMM_SWAP_BASE_IN
-        // Handle DLMM in fallback using log content detection
+        // Attributed DLMM logs are routed above; unattributed ones fall back here
         
         // Unknown discriminator - try fallback protocols
         _ => {
+            profile_hit!(DiscriminatorMiss);
             // Try Meteora DLMM (has discriminator conflict with Raydium CPMM)
-            if let Some(event) = crate::logs::parse_meteora_dlmm_log(log, signature, slot, tx_index, block_time_us, grpc_recv_us) {
+            if let Some(event) = crate::logs::parse_meteora_dlmm_log(
+                log,
+                metadata.signature,
+                metadata.slot,
+                metadata.tx_index,
+                Some(metadata.block_time_us),
+                metadata.grpc_recv_us,
+            ) {
                 return Some(event);
             }
             None
@@ -528,6 +811,16 @@ fn discriminator_to_event_type(discriminator: u64) -> Option<EventType> {
         discriminators::PUMPSWAP_CREATE_POOL => Some(EventType::PumpSwapCreatePool),
         discriminators::PUMPSWAP_ADD_LIQUIDITY => Some(EventType::PumpSwapLiquidityAdded),
         discriminators::PUMPSWAP_REMOVE_LIQUIDITY => Some(EventType::PumpSwapLiquidityRemoved),
+        discriminators::PUMPSWAP_UPDATE_FEE_CONFIG | discriminators::PUMPSWAP_DISABLE => {
+            Some(EventType::PumpSwapConfigUpdated)
+        }
+        discriminators::PUMPSWAP_ADMIN_SET_COIN_CREATOR
+        | discriminators::PUMPSWAP_SET_BONDING_CURVE_COIN_CREATOR
+        | discriminators::PUMPSWAP_SET_METAPLEX_COIN_CREATOR => Some(EventType::PumpSwapPoolUpdated),
+        discriminators::PUMPSWAP_COLLECT_COIN_CREATOR_FEE => {
+            Some(EventType::PumpSwapCreatorFeeClaimed)
+        }
+        discriminators::RAYDIUM_LAUNCHPAD_POOL_CREATE => Some(EventType::BonkPoolCreate),
         _ => None,
     }
 }
@@ -586,3 +879,228 @@ pub fn parse_invoke_info(log: &str) -> Option<(&str, usize)> {
 
     Some((program_id, depth))
 }
+
+/// 判断是否为 `Program <id> success` / `Program <id> failed: ...` 退出日志
+#[inline]
+fn is_program_exit(log: &str) -> bool {
+    let Some(rest) = log.strip_prefix("Program ") else { return false };
+    let Some((program_id, status)) = rest.split_once(' ') else { return false };
+    // 排除 "Program log:" / "Program data:" 等输出日志
+    !program_id.ends_with(':') && (status == "success" || status.starts_with("failed"))
+}
+
+/// 日志调用栈游标：跟踪当前日志所属的指令位置和输出程序
+///
+/// 外层指令按 `invoke [1]` 计数，内层指令按同一外层指令下的 CPI 顺序计数，
+/// 与 `TransactionStatusMeta::inner_instructions` 中的索引一致。
+/// 子调用退出后，后续日志重新归属到调用方指令。
+#[derive(Debug, Default)]
+pub struct InvokeCursor {
+    outer: Option<u32>,
+    next_inner: u32,
+    /// (内层指令索引, 程序)
+    stack: Vec<(Option<u32>, LogProgram)>,
+}
+
+impl InvokeCursor {
+    pub fn new() -> Self {
+        Self { outer: None, next_inner: 0, stack: Vec::with_capacity(4) }
+    }
+
+    /// 处理一行日志；若为 invoke 日志则返回 (program_id, depth)
+    #[inline]
+    pub fn observe<'a>(&mut self, log: &'a str) -> Option<(&'a str, usize)> {
+        if let Some((program_id, depth)) = parse_invoke_info(log) {
+            let program = LogProgram::from_program_id(program_id);
+            if depth == 1 {
+                self.outer = Some(self.outer.map_or(0, |i| i + 1));
+                self.next_inner = 0;
+                self.stack.clear();
+                self.stack.push((None, program));
+            } else {
+                self.stack.push((Some(self.next_inner), program));
+                self.next_inner += 1;
+            }
+            return Some((program_id, depth));
+        }
+
+        if is_program_exit(log) {
+            self.stack.pop();
+        }
+        None
+    }
+
+    /// 当前日志所属的 (outer_index, inner_index)
+    #[inline]
+    pub fn position(&self) -> (u32, Option<u32>) {
+        (self.outer.unwrap_or(0), self.stack.last().and_then(|&(inner, _)| inner))
+    }
+
+    /// 当前调用栈深度（外层指令为 1），尚未进入任何指令时为 0
+    #[inline]
+    pub fn depth(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// 当前正在执行（即输出当前日志）的程序，尚未进入任何指令时为 None
+    #[inline]
+    pub fn program(&self) -> Option<LogProgram> {
+        self.stack.last().map(|&(_, program)| program)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invoke_cursor_tracks_call_stack() {
+        let mut cursor = InvokeCursor::new();
+        let logs = [
+            ("Program ComputeBudget111111111111111111111111111111 invoke [1]", (0, None)),
+            ("Program ComputeBudget111111111111111111111111111111 success", (0, None)),
+            ("Program pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA invoke [1]", (1, None)),
+            ("Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [2]", (1, Some(0))),
+            ("Program log: Instruction: Transfer success", (1, Some(0))),
+            ("Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success", (1, None)),
+            ("Program data: AAAA", (1, None)),
+            ("Program pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA invoke [2]", (1, Some(1))),
+            ("Program pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA failed: custom program error", (1, None)),
+        ];
+
+        for (log, expected) in logs {
+            cursor.observe(log);
+            assert_eq!(cursor.position(), expected, "{}", log);
+        }
+    }
+
+    #[test]
+    fn test_program_attribution_routing() {
+        use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+        let mut cursor = InvokeCursor::new();
+        assert_eq!(cursor.program(), None);
+        cursor.observe("Program LanMV9sAd7wArD4vJFi2qDdfnVhFxYSUg6eADduJ3uj invoke [1]");
+        assert_eq!(cursor.program(), Some(LogProgram::RaydiumLaunchpad));
+        cursor.observe("Program JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 invoke [2]");
+        assert_eq!(cursor.program(), Some(LogProgram::Other));
+        cursor.observe("Program JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 success");
+        assert_eq!(cursor.program(), Some(LogProgram::RaydiumLaunchpad));
+
+        // Launchpad TradeEvent 与 PumpFun TradeEvent 共享 discriminator
+        let mut data = discriminators::PUMPFUN_TRADE.to_le_bytes().to_vec();
+        data.extend_from_slice(&[0u8; 256]);
+        let log = format!("Program data: {}", STANDARD.encode(&data));
+        let metadata = EventMetadata::default();
+        let mut ctx = LogDecodeContext::new();
+        let parse = |ctx: &mut LogDecodeContext, program| {
+            parse_log_with_context(ctx, 0, &log, metadata, program, None, false)
+        };
+
+        assert!(matches!(parse(&mut ctx, None), Some(DexEvent::PumpFunTrade(_))));
+        assert!(matches!(
+            parse(&mut ctx, Some(LogProgram::RaydiumLaunchpad)),
+            Some(DexEvent::BonkTrade(_))
+        ));
+        // 其他程序输出的日志、与程序不符的 discriminator 均跳过
+        assert!(parse(&mut ctx, Some(LogProgram::Other)).is_none());
+        assert!(parse(&mut ctx, Some(LogProgram::PumpSwap)).is_none());
+    }
+
+    #[test]
+    fn test_raydium_clmm_swap_event_transfer_fees() {
+        use base64::{engine::general_purpose::STANDARD, Engine as _};
+        use solana_sdk::pubkey::Pubkey;
+
+        // Token-2022 池（带转账手续费扩展）的 swap_v2 SwapEvent
+        let keys: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let mut data = discriminators::RAYDIUM_CLMM_SWAP.to_le_bytes().to_vec();
+        for key in &keys {
+            data.extend_from_slice(key.as_ref());
+        }
+        for value in [1_000_000u64, 2_500, 998_000, 1_200] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.push(1);
+        data.extend_from_slice(&(7u128 << 64).to_le_bytes());
+        data.extend_from_slice(&42u128.to_le_bytes());
+        data.extend_from_slice(&(-120i32).to_le_bytes());
+        let log = format!("Program data: {}", STANDARD.encode(&data));
+
+        let event = parse_log_optimized(&log, Signature::default(), 1, 0, None, 0, None, false);
+        let Some(DexEvent::RaydiumClmmSwap(e)) = event else { panic!("{:?}", event) };
+        assert_eq!((e.pool_state, e.sender), (keys[0], keys[1]));
+        assert_eq!((e.token_account_0, e.token_account_1), (keys[2], keys[3]));
+        assert_eq!((e.amount_0, e.transfer_fee_0, e.amount_1, e.transfer_fee_1), (1_000_000, 2_500, 998_000, 1_200));
+        assert!(e.zero_for_one);
+        assert_eq!((e.sqrt_price_x64, e.liquidity, e.tick), (7 << 64, 42, -120));
+        // 协议日志解析器与优化路径读取相同的布局
+        let direct = crate::logs::raydium_clmm::parse_log(&log, Signature::default(), 1, 0, None, 0);
+        let Some(DexEvent::RaydiumClmmSwap(direct)) = direct else { panic!("{:?}", direct) };
+        assert_eq!((direct.pool_state, direct.sender, direct.amount_1), (e.pool_state, e.sender, e.amount_1));
+        assert_eq!((direct.zero_for_one, direct.sqrt_price_x64, direct.tick), (true, 7 << 64, -120));
+
+        // 截断的事件不输出
+        let short = format!("Program data: {}", STANDARD.encode(&data[..data.len() - 1]));
+        assert!(parse_log_optimized(&short, Signature::default(), 1, 0, None, 0, None, false).is_none());
+    }
+
+    #[test]
+    fn test_text_log_fallback() {
+        let log = "Program log: trade amount_in: 100 amount_out: 90";
+        let metadata = EventMetadata::default();
+        let mut ctx = LogDecodeContext::new();
+        let mut parse = |program, filter: Option<&EventTypeFilter>| {
+            parse_log_with_context(&mut ctx, 0, log, metadata, program, filter, false)
+        };
+
+        let event = parse(Some(LogProgram::RaydiumLaunchpad), None);
+        if cfg!(feature = "fallback-text-logs") {
+            let Some(DexEvent::BonkTrade(trade)) = event else { panic!("{:?}", event) };
+            assert_eq!((trade.amount_in, trade.amount_out), (100, 90));
+            let exclude = EventTypeFilter::exclude_types(vec![EventType::BonkTrade]);
+            assert!(parse(Some(LogProgram::RaydiumLaunchpad), Some(&exclude)).is_none());
+        } else {
+            assert!(event.is_none());
+        }
+        // 未归属程序或其他程序的文本日志不回退
+        assert!(parse(None, None).is_none());
+        assert!(parse(Some(LogProgram::Other), None).is_none());
+    }
+
+    #[test]
+    fn test_decode_context_reuses_buffer() {
+        use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+        let mut data = discriminators::METEORA_AMM_SET_POOL_FEES.to_le_bytes().to_vec();
+        for v in [25u64, 10_000, 5, 10_000] {
+            data.extend_from_slice(&v.to_le_bytes());
+        }
+        data.extend_from_slice(&[7u8; 32]);
+        let logs = [
+            "Program log: Instruction: Swap".to_string(),
+            format!("Program data: {}", STANDARD.encode(&data)),
+            "Program data: !!!".to_string(),
+            format!("Program data: {}", STANDARD.encode([1u8; 16])),
+        ];
+
+        let mut ctx = LogDecodeContext::new();
+        assert!(ctx.decode(0, &logs[0]).is_none());
+        assert_eq!(ctx.decode(1, &logs[1]), Some(data.as_slice()));
+        assert!(ctx.decode(2, &logs[2]).is_none());
+        assert_eq!(ctx.decode(3, &logs[3]), Some([1u8; 16].as_slice()));
+        // 已解码的日志直接返回缓存结果
+        let ptr = ctx.decode(1, &logs[1]).unwrap().as_ptr();
+        assert_eq!(ctx.decode(1, &logs[1]).unwrap().as_ptr(), ptr);
+
+        let metadata = EventMetadata { slot: 1, ..Default::default() };
+        let expected =
+            parse_log_optimized(&logs[1], Signature::default(), 1, 0, Some(0), 0, None, false);
+        let parsed = parse_log_with_context(&mut ctx, 1, &logs[1], metadata, None, None, false);
+        assert!(matches!(parsed, Some(DexEvent::MeteoraPoolsSetPoolFees(_))));
+        assert_eq!(format!("{:?}", parsed), format!("{:?}", expected));
+
+        ctx.clear();
+        assert!(ctx.decode(0, &logs[1]).is_some());
+    }
+}
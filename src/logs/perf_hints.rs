@@ -25,6 +25,10 @@ pub fn unlikely(condition: bool) -> bool {
 }
 
 /// 预取数据到 CPU 缓存（读优化）
+///
+/// x86_64 使用 `_mm_prefetch`，aarch64 使用 `prfm` 指令（`_prefetch` 内建函数尚未稳定），
+/// 其他架构为空操作。预取只是提示，`ptr` 越界也不会访问内存。
+#[cfg(not(feature = "forbid-unsafe"))]
 #[inline(always)]
 pub unsafe fn prefetch_read<T>(ptr: *const T) {
     #[cfg(target_arch = "x86_64")]
@@ -32,9 +36,14 @@ pub unsafe fn prefetch_read<T>(ptr: *const T) {
         use std::arch::x86_64::{_mm_prefetch, _MM_HINT_T0};
         _mm_prefetch(ptr as *const i8, _MM_HINT_T0);
     }
+    #[cfg(target_arch = "aarch64")]
+    std::arch::asm!("prfm pldl1keep, [{0}]", in(reg) ptr, options(nostack, preserves_flags, readonly));
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    let _ = ptr;
 }
 
 /// 预取数据到 CPU 缓存（写优化）
+#[cfg(not(feature = "forbid-unsafe"))]
 #[inline(always)]
 pub unsafe fn prefetch_write<T>(ptr: *const T) {
     #[cfg(target_arch = "x86_64")]
@@ -42,4 +51,8 @@ pub unsafe fn prefetch_write<T>(ptr: *const T) {
         use std::arch::x86_64::{_mm_prefetch, _MM_HINT_T1};
         _mm_prefetch(ptr as *const i8, _MM_HINT_T1);
     }
+    #[cfg(target_arch = "aarch64")]
+    std::arch::asm!("prfm pstl1keep, [{0}]", in(reg) ptr, options(nostack, preserves_flags, readonly));
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    let _ = ptr;
 }
\ No newline at end of file
@@ -10,6 +10,8 @@
 //! - 内存预取 (CPU cache optimization)
 
 use crate::core::events::*;
+use crate::core::layout::PUMPFUN_TRADE_EVENT;
+use crate::instr::inner_common::zc_read;
 use memchr::memmem;
 use once_cell::sync::Lazy;
 use solana_sdk::{pubkey::Pubkey, signature::Signature};
@@ -92,82 +94,7 @@ fn extract_discriminator_simd(log: &str) -> Option<u64> {
         .ok()?;
 
     // 使用 unsafe 读取 u64 (零拷贝，无边界检查)
-    unsafe {
-        let ptr = buf.as_ptr() as *const u64;
-        Some(ptr.read_unaligned())
-    }
-}
-
-// ============================================================================
-// Unsafe 读取函数 - 消除边界检查
-// ============================================================================
-
-/// 读取 u64 (unsafe, 无边界检查)
-#[inline(always)]
-unsafe fn read_u64_unchecked(data: &[u8], offset: usize) -> u64 {
-    let ptr = data.as_ptr().add(offset) as *const u64;
-    u64::from_le(ptr.read_unaligned())
-}
-
-/// 读取 i64 (unsafe, 无边界检查)
-#[inline(always)]
-unsafe fn read_i64_unchecked(data: &[u8], offset: usize) -> i64 {
-    let ptr = data.as_ptr().add(offset) as *const i64;
-    i64::from_le(ptr.read_unaligned())
-}
-
-/// 读取 bool (unsafe, 无边界检查)
-#[inline(always)]
-unsafe fn read_bool_unchecked(data: &[u8], offset: usize) -> bool {
-    *data.get_unchecked(offset) == 1
-}
-
-/// 读取 Pubkey (unsafe, 无边界检查)
-///
-/// 优化: 添加内存预取，假设连续读取多个 Pubkey
-#[inline(always)]
-unsafe fn read_pubkey_unchecked(data: &[u8], offset: usize) -> Pubkey {
-    // 预取下一个可能的 Pubkey 位置 (假设连续读取)
-    // 使用 T0 提示 (最高优先级) 将数据预取到 L1 cache
-    #[cfg(target_arch = "x86_64")]
-    {
-        use std::arch::x86_64::_mm_prefetch;
-        use std::arch::x86_64::_MM_HINT_T0;
-        if offset + 64 < data.len() {
-            _mm_prefetch((data.as_ptr().add(offset + 32)) as *const i8, _MM_HINT_T0);
-        }
-    }
-
-    let ptr = data.as_ptr().add(offset);
-    let mut bytes = [0u8; 32];
-    std::ptr::copy_nonoverlapping(ptr, bytes.as_mut_ptr(), 32);
-    Pubkey::new_from_array(bytes)
-}
-
-/// 读取 u32 长度前缀的字符串 (零拷贝，返回 &str)
-///
-/// 优化: 直接返回 &str，避免 String 分配
-#[inline(always)]
-unsafe fn read_str_unchecked(data: &[u8], offset: usize) -> Option<(&str, usize)> {
-    if data.len() < offset + 4 {
-        return None;
-    }
-
-    let len = read_u32_unchecked(data, offset) as usize;
-    if data.len() < offset + 4 + len {
-        return None;
-    }
-
-    let string_bytes = &data[offset + 4..offset + 4 + len];
-    let s = std::str::from_utf8_unchecked(string_bytes);
-    Some((s, 4 + len))
-}
-
-/// 读取 u32 (unsafe, 无边界检查)
-#[inline(always)]
-unsafe fn read_u32_unchecked(data: &[u8], offset: usize) -> u32 {
-    let ptr = data.as_ptr().add(offset) as *const u32;
-    u32::from_le(ptr.read_unaligned())
+    Some(zc_read!(u64, &buf, 0))
 }
 
 // ============================================================================
@@ -199,7 +126,7 @@ pub fn parse_log(
     }
 
     // 使用 unsafe 读取 discriminator (SIMD 优化)
-    let discriminator = unsafe { read_u64_unchecked(program_data, 0) };
+    let discriminator = zc_read!(u64, program_data, 0);
     let data = &program_data[8..];
 
     let result = match discriminator {
@@ -207,7 +134,7 @@ pub fn parse_log(
             parse_create_event_optimized(data, signature, slot, tx_index, block_time_us, grpc_recv_us)
         }
         discriminators::TRADE_EVENT => {
-            parse_trade_event_optimized(data, signature, slot, tx_index, block_time_us, grpc_recv_us, is_created_buy)
+            parse_trade_event_optimized(&PUMPFUN_TRADE_EVENT.upgrade(data), signature, slot, tx_index, block_time_us, grpc_recv_us, is_created_buy)
         }
         discriminators::MIGRATE_EVENT => {
             parse_migrate_event_optimized(data, signature, slot, tx_index, block_time_us, grpc_recv_us)
@@ -239,94 +166,86 @@ fn parse_create_event_optimized(
     block_time_us: Option<i64>,
     grpc_recv_us: i64,
 ) -> Option<DexEvent> {
-    unsafe {
-        let mut offset = 0;
+    let mut offset = 0;
 
-        // 读取字符串字段 (零拷贝)
-        let (name, name_len) = read_str_unchecked(data, offset)?;
-        offset += name_len;
+    // 读取字符串字段 (零拷贝)
+    let (name, name_len) = zc_read!(str, data, offset)?;
+    offset += name_len;
 
-        let (symbol, symbol_len) = read_str_unchecked(data, offset)?;
-        offset += symbol_len;
+    let (symbol, symbol_len) = zc_read!(str, data, offset)?;
+    offset += symbol_len;
 
-        let (uri, uri_len) = read_str_unchecked(data, offset)?;
-        offset += uri_len;
+    let (uri, uri_len) = zc_read!(str, data, offset)?;
+    offset += uri_len;
 
-        // 快速边界检查
-        if data.len() < offset + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 32 + 1 {
-            return None;
-        }
+    // 快速边界检查
+    if data.len() < offset + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 32 + 1 {
+        return None;
+    }
 
-        // 读取 Pubkey 字段
-        let mint = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    // 读取 Pubkey 字段
+    let mint = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let bonding_curve = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let bonding_curve = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let user = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let user = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let creator = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let creator = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        // 读取数值字段
-        let timestamp = read_i64_unchecked(data, offset);
-        offset += 8;
+    // 读取数值字段
+    let timestamp = zc_read!(i64, data, offset);
+    offset += 8;
 
-        let virtual_token_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
+    let virtual_token_reserves = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let virtual_sol_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
+    let virtual_sol_reserves = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let real_token_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
+    let real_token_reserves = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let token_total_supply = read_u64_unchecked(data, offset);
-        offset += 8;
+    let token_total_supply = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let token_program = if offset + 32 <= data.len() {
-            read_pubkey_unchecked(data, offset)
-        } else {
-            Pubkey::default()
-        };
-        offset += 32;
+    let token_program =
+        if offset + 32 <= data.len() { zc_read!(pubkey, data, offset) } else { Pubkey::default() };
+    offset += 32;
 
-        let is_mayhem_mode = if offset < data.len() {
-            read_bool_unchecked(data, offset)
-        } else {
-            false
-        };
+    let is_mayhem_mode = if offset < data.len() { zc_read!(bool, data, offset) } else { false };
 
-        let metadata = EventMetadata {
-            signature,
-            slot,
-            tx_index,
-            block_time_us: block_time_us.unwrap_or(0),
-            grpc_recv_us,
-        };
+    let metadata = EventMetadata {
+        signature,
+        slot,
+        tx_index,
+        block_time_us: block_time_us.unwrap_or(0),
+        grpc_recv_us,
+        ..Default::default()
+    };
 
-        // 将 &str 转换为 String (这是唯一的堆分配)
-        // 优化: 可以考虑使用 SmallString 或 Cow<'static, str> 进一步优化
-        Some(DexEvent::PumpFunCreate(PumpFunCreateTokenEvent {
-            metadata,
-            name: name.to_string(),
-            symbol: symbol.to_string(),
-            uri: uri.to_string(),
-            mint,
-            bonding_curve,
-            user,
-            creator,
-            timestamp,
-            virtual_token_reserves,
-            virtual_sol_reserves,
-            real_token_reserves,
-            token_total_supply,
-            token_program,
-            is_mayhem_mode,
-        }))
-    }
+    // 将 &str 转换为 String (这是唯一的堆分配)
+    // 优化: 可以考虑使用 SmallString 或 Cow<'static, str> 进一步优化
+    Some(DexEvent::PumpFunCreate(PumpFunCreateTokenEvent {
+        metadata,
+        name: name.to_string(),
+        symbol: symbol.to_string(),
+        uri: uri.to_string(),
+        mint,
+        bonding_curve,
+        user,
+        creator,
+        timestamp,
+        virtual_token_reserves,
+        virtual_sol_reserves,
+        real_token_reserves,
+        token_total_supply,
+        token_program,
+        is_mayhem_mode,
+    }))
 }
 
 /// 解析 TradeEvent (极限优化)
@@ -346,165 +265,147 @@ fn parse_trade_event_optimized(
     grpc_recv_us: i64,
     is_created_buy: bool,
 ) -> Option<DexEvent> {
-    unsafe {
-        // 快速边界检查
-        if data.len() < 32 + 8 + 8 + 1 + 32 + 8 + 8 + 8 + 8 + 8 + 32 + 8 + 8 + 32 + 8 + 8 {
-            return None;
-        }
+    // 快速边界检查
+    if data.len() < 32 + 8 + 8 + 1 + 32 + 8 + 8 + 8 + 8 + 8 + 32 + 8 + 8 + 32 + 8 + 8 {
+        return None;
+    }
 
-        let mut offset = 0;
+    let mut offset = 0;
 
-        let mint = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let mint = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let sol_amount = read_u64_unchecked(data, offset);
-        offset += 8;
+    let sol_amount = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let token_amount = read_u64_unchecked(data, offset);
-        offset += 8;
+    let token_amount = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let is_buy = read_bool_unchecked(data, offset);
-        offset += 1;
+    let is_buy = zc_read!(bool, data, offset);
+    offset += 1;
 
-        let user = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let user = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let timestamp = read_i64_unchecked(data, offset);
-        offset += 8;
+    let timestamp = zc_read!(i64, data, offset);
+    offset += 8;
 
-        let virtual_sol_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
+    let virtual_sol_reserves = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let virtual_token_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
+    let virtual_token_reserves = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let real_sol_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
+    let real_sol_reserves = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let real_token_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
+    let real_token_reserves = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let fee_recipient = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let fee_recipient = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let fee_basis_points = read_u64_unchecked(data, offset);
-        offset += 8;
+    let fee_basis_points = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let fee = read_u64_unchecked(data, offset);
-        offset += 8;
+    let fee = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let creator = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let creator = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let creator_fee_basis_points = read_u64_unchecked(data, offset);
-        offset += 8;
+    let creator_fee_basis_points = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let creator_fee = read_u64_unchecked(data, offset);
-        offset += 8;
+    let creator_fee = zc_read!(u64, data, offset);
+    offset += 8;
 
-        // 可选字段
-        let track_volume = if offset < data.len() {
-            read_bool_unchecked(data, offset)
-        } else {
-            false
-        };
-        offset += 1;
+    // 可选字段
+    let track_volume = if offset < data.len() { zc_read!(bool, data, offset) } else { false };
+    offset += 1;
 
-        let total_unclaimed_tokens = if offset + 8 <= data.len() {
-            read_u64_unchecked(data, offset)
-        } else {
-            0
-        };
-        offset += 8;
+    let total_unclaimed_tokens =
+        if offset + 8 <= data.len() { zc_read!(u64, data, offset) } else { 0 };
+    offset += 8;
 
-        let total_claimed_tokens = if offset + 8 <= data.len() {
-            read_u64_unchecked(data, offset)
-        } else {
-            0
-        };
-        offset += 8;
+    let total_claimed_tokens =
+        if offset + 8 <= data.len() { zc_read!(u64, data, offset) } else { 0 };
+    offset += 8;
 
-        let current_sol_volume = if offset + 8 <= data.len() {
-            read_u64_unchecked(data, offset)
-        } else {
-            0
-        };
-        offset += 8;
+    let current_sol_volume = if offset + 8 <= data.len() { zc_read!(u64, data, offset) } else { 0 };
+    offset += 8;
 
-        let last_update_timestamp = if offset + 8 <= data.len() {
-            read_i64_unchecked(data, offset)
-        } else {
-            0
-        };
-        offset += 8;
-
-        // ix_name: String (4-byte length prefix + content)
-        // Values: "buy" | "sell" | "buy_exact_sol_in"
-        let ix_name = if offset + 4 <= data.len() {
-            if let Some((s, len)) = read_str_unchecked(data, offset) {
-                offset += len;
-                s.to_string()
-            } else {
-                String::new()
-            }
+    let last_update_timestamp =
+        if offset + 8 <= data.len() { zc_read!(i64, data, offset) } else { 0 };
+    offset += 8;
+
+    // ix_name: String (4-byte length prefix + content)
+    // Values: "buy" | "sell" | "buy_exact_sol_in"
+    let ix_name = if offset + 4 <= data.len() {
+        if let Some((s, len)) = zc_read!(str, data, offset) {
+            offset += len;
+            s.to_string()
         } else {
             String::new()
-        };
+        }
+    } else {
+        String::new()
+    };
 
-        // mayhem_mode: bool (1 byte, new field from IDL update)
-        let mayhem_mode = if offset < data.len() {
-            read_bool_unchecked(data, offset)
-        } else {
-            false
-        };
+    // mayhem_mode: bool (1 byte, new field from IDL update)
+    let mayhem_mode = if offset < data.len() { zc_read!(bool, data, offset) } else { false };
 
-        let metadata = EventMetadata {
-            signature,
-            slot,
-            tx_index,
-            block_time_us: block_time_us.unwrap_or(0),
-            grpc_recv_us,
-        };
+    let metadata = EventMetadata {
+        signature,
+        slot,
+        tx_index,
+        block_time_us: block_time_us.unwrap_or(0),
+        grpc_recv_us,
+        ..Default::default()
+    };
 
-        let trade_event = PumpFunTradeEvent {
-            metadata,
-            mint,
-            sol_amount,
-            token_amount,
-            is_buy,
-            is_created_buy,
-            user,
-            timestamp,
-            virtual_sol_reserves,
-            virtual_token_reserves,
-            real_sol_reserves,
-            real_token_reserves,
-            fee_recipient,
-            fee_basis_points,
-            fee,
-            creator,
-            creator_fee_basis_points,
-            creator_fee,
-            track_volume,
-            total_unclaimed_tokens,
-            total_claimed_tokens,
-            current_sol_volume,
-            last_update_timestamp,
-            ix_name: ix_name.clone(),
-            mayhem_mode,
-            bonding_curve: Pubkey::default(),
-            associated_bonding_curve: Pubkey::default(),
-            creator_vault: Pubkey::default(),
-            token_program: Pubkey::default(),
-        };
+    let trade_event = PumpFunTradeEvent {
+        metadata,
+        mint,
+        sol_amount,
+        token_amount,
+        is_buy,
+        is_created_buy,
+        user,
+        timestamp,
+        virtual_sol_reserves,
+        virtual_token_reserves,
+        real_sol_reserves,
+        real_token_reserves,
+        fee_recipient,
+        fee_basis_points,
+        fee,
+        creator,
+        creator_fee_basis_points,
+        creator_fee,
+        track_volume,
+        total_unclaimed_tokens,
+        total_claimed_tokens,
+        current_sol_volume,
+        last_update_timestamp,
+        ix_name: ix_name.clone(),
+        mayhem_mode,
+        amount: 0,
+        max_sol_cost: 0,
+        min_sol_output: 0,
+        slippage_tolerance_bps: None,
+        bonding_curve: Pubkey::default(),
+        associated_bonding_curve: Pubkey::default(),
+        creator_vault: Pubkey::default(),
+        token_program: Pubkey::default(),
+    };
 
-        // 根据 ix_name 返回不同的事件类型，支持用户过滤特定交易类型
-        match ix_name.as_str() {
-            "buy" => Some(DexEvent::PumpFunBuy(trade_event)),
-            "sell" => Some(DexEvent::PumpFunSell(trade_event)),
-            "buy_exact_sol_in" => Some(DexEvent::PumpFunBuyExactSolIn(trade_event)),
-            _ => Some(DexEvent::PumpFunTrade(trade_event)), // 兼容旧版本或未知类型
-        }
+    // 根据 ix_name 返回不同的事件类型，支持用户过滤特定交易类型
+    match ix_name.as_str() {
+        "buy" => Some(DexEvent::PumpFunBuy(trade_event)),
+        "sell" => Some(DexEvent::PumpFunSell(trade_event)),
+        "buy_exact_sol_in" => Some(DexEvent::PumpFunBuyExactSolIn(trade_event)),
+        _ => Some(DexEvent::PumpFunTrade(trade_event)), // 兼容旧版本或未知类型
     }
 }
 
@@ -518,57 +419,56 @@ fn parse_migrate_event_optimized(
     block_time_us: Option<i64>,
     grpc_recv_us: i64,
 ) -> Option<DexEvent> {
-    unsafe {
-        // 快速边界检查
-        if data.len() < 32 + 32 + 8 + 8 + 8 + 32 + 8 + 32 {
-            return None;
-        }
+    // 快速边界检查
+    if data.len() < 32 + 32 + 8 + 8 + 8 + 32 + 8 + 32 {
+        return None;
+    }
 
-        let mut offset = 0;
+    let mut offset = 0;
 
-        let user = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let user = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let mint = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let mint = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let mint_amount = read_u64_unchecked(data, offset);
-        offset += 8;
+    let mint_amount = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let sol_amount = read_u64_unchecked(data, offset);
-        offset += 8;
+    let sol_amount = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let pool_migration_fee = read_u64_unchecked(data, offset);
-        offset += 8;
+    let pool_migration_fee = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let bonding_curve = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let bonding_curve = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let timestamp = read_i64_unchecked(data, offset);
-        offset += 8;
+    let timestamp = zc_read!(i64, data, offset);
+    offset += 8;
 
-        let pool = read_pubkey_unchecked(data, offset);
+    let pool = zc_read!(pubkey, data, offset);
 
-        let metadata = EventMetadata {
-            signature,
-            slot,
-            tx_index,
-            block_time_us: block_time_us.unwrap_or(0),
-            grpc_recv_us,
-        };
+    let metadata = EventMetadata {
+        signature,
+        slot,
+        tx_index,
+        block_time_us: block_time_us.unwrap_or(0),
+        grpc_recv_us,
+        ..Default::default()
+    };
 
-        Some(DexEvent::PumpFunMigrate(PumpFunMigrateEvent {
-            metadata,
-            user,
-            mint,
-            mint_amount,
-            sol_amount,
-            pool_migration_fee,
-            bonding_curve,
-            timestamp,
-            pool,
-        }))
-    }
+    Some(DexEvent::PumpFunMigrate(PumpFunMigrateEvent {
+        metadata,
+        user,
+        mint,
+        mint_amount,
+        sol_amount,
+        pool_migration_fee,
+        bonding_curve,
+        timestamp,
+        pool,
+    }))
 }
 
 // ============================================================================
@@ -605,155 +505,138 @@ pub fn is_event_type(log: &str, discriminator: u64) -> bool {
 /// - other/empty -> DexEvent::PumpFunTrade (backward compatible)
 #[inline(always)]
 pub fn parse_trade_from_data(data: &[u8], metadata: EventMetadata, is_created_buy: bool) -> Option<DexEvent> {
-    unsafe {
-        // 快速边界检查
-        if data.len() < 32 + 8 + 8 + 1 + 32 + 8 + 8 + 8 + 8 + 8 + 32 + 8 + 8 + 32 + 8 + 8 {
-            return None;
-        }
+    let upgraded = PUMPFUN_TRADE_EVENT.upgrade(data);
+    let data = &upgraded[..];
+    // 快速边界检查
+    if data.len() < 32 + 8 + 8 + 1 + 32 + 8 + 8 + 8 + 8 + 8 + 32 + 8 + 8 + 32 + 8 + 8 {
+        return None;
+    }
 
-        let mut offset = 0;
+    let mut offset = 0;
 
-        let mint = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let mint = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let sol_amount = read_u64_unchecked(data, offset);
-        offset += 8;
+    let sol_amount = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let token_amount = read_u64_unchecked(data, offset);
-        offset += 8;
+    let token_amount = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let is_buy = read_bool_unchecked(data, offset);
-        offset += 1;
+    let is_buy = zc_read!(bool, data, offset);
+    offset += 1;
 
-        let user = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let user = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let timestamp = read_i64_unchecked(data, offset);
-        offset += 8;
+    let timestamp = zc_read!(i64, data, offset);
+    offset += 8;
 
-        let virtual_sol_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
+    let virtual_sol_reserves = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let virtual_token_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
+    let virtual_token_reserves = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let real_sol_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
+    let real_sol_reserves = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let real_token_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
+    let real_token_reserves = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let fee_recipient = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let fee_recipient = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let fee_basis_points = read_u64_unchecked(data, offset);
-        offset += 8;
+    let fee_basis_points = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let fee = read_u64_unchecked(data, offset);
-        offset += 8;
+    let fee = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let creator = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let creator = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let creator_fee_basis_points = read_u64_unchecked(data, offset);
-        offset += 8;
+    let creator_fee_basis_points = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let creator_fee = read_u64_unchecked(data, offset);
-        offset += 8;
+    let creator_fee = zc_read!(u64, data, offset);
+    offset += 8;
 
-        // 可选字段
-        let track_volume = if offset < data.len() {
-            read_bool_unchecked(data, offset)
-        } else {
-            false
-        };
-        offset += 1;
+    // 可选字段
+    let track_volume = if offset < data.len() { zc_read!(bool, data, offset) } else { false };
+    offset += 1;
 
-        let total_unclaimed_tokens = if offset + 8 <= data.len() {
-            read_u64_unchecked(data, offset)
-        } else {
-            0
-        };
-        offset += 8;
+    let total_unclaimed_tokens =
+        if offset + 8 <= data.len() { zc_read!(u64, data, offset) } else { 0 };
+    offset += 8;
 
-        let total_claimed_tokens = if offset + 8 <= data.len() {
-            read_u64_unchecked(data, offset)
-        } else {
-            0
-        };
-        offset += 8;
+    let total_claimed_tokens =
+        if offset + 8 <= data.len() { zc_read!(u64, data, offset) } else { 0 };
+    offset += 8;
 
-        let current_sol_volume = if offset + 8 <= data.len() {
-            read_u64_unchecked(data, offset)
-        } else {
-            0
-        };
-        offset += 8;
+    let current_sol_volume = if offset + 8 <= data.len() { zc_read!(u64, data, offset) } else { 0 };
+    offset += 8;
 
-        let last_update_timestamp = if offset + 8 <= data.len() {
-            read_i64_unchecked(data, offset)
-        } else {
-            0
-        };
-        offset += 8;
-
-        let ix_name = if offset + 4 <= data.len() {
-            if let Some((s, len)) = read_str_unchecked(data, offset) {
-                offset += len;
-                s.to_string()
-            } else {
-                String::new()
-            }
-        } else {
-            String::new()
-        };
+    let last_update_timestamp =
+        if offset + 8 <= data.len() { zc_read!(i64, data, offset) } else { 0 };
+    offset += 8;
 
-        // mayhem_mode: bool (1 byte, new field from IDL update)
-        let mayhem_mode = if offset < data.len() {
-            read_bool_unchecked(data, offset)
+    let ix_name = if offset + 4 <= data.len() {
+        if let Some((s, len)) = zc_read!(str, data, offset) {
+            offset += len;
+            s.to_string()
         } else {
-            false
-        };
+            String::new()
+        }
+    } else {
+        String::new()
+    };
 
-        let trade_event = PumpFunTradeEvent {
-            metadata,
-            mint,
-            sol_amount,
-            token_amount,
-            is_buy,
-            is_created_buy,
-            user,
-            timestamp,
-            virtual_sol_reserves,
-            virtual_token_reserves,
-            real_sol_reserves,
-            real_token_reserves,
-            fee_recipient,
-            fee_basis_points,
-            fee,
-            creator,
-            creator_fee_basis_points,
-            creator_fee,
-            track_volume,
-            total_unclaimed_tokens,
-            total_claimed_tokens,
-            current_sol_volume,
-            last_update_timestamp,
-            ix_name: ix_name.clone(),
-            mayhem_mode,
-            bonding_curve: Pubkey::default(),
-            associated_bonding_curve: Pubkey::default(),
-            creator_vault: Pubkey::default(),
-            token_program: Pubkey::default(),
-        };
+    // mayhem_mode: bool (1 byte, new field from IDL update)
+    let mayhem_mode = if offset < data.len() { zc_read!(bool, data, offset) } else { false };
+
+    let trade_event = PumpFunTradeEvent {
+        metadata,
+        mint,
+        sol_amount,
+        token_amount,
+        is_buy,
+        is_created_buy,
+        user,
+        timestamp,
+        virtual_sol_reserves,
+        virtual_token_reserves,
+        real_sol_reserves,
+        real_token_reserves,
+        fee_recipient,
+        fee_basis_points,
+        fee,
+        creator,
+        creator_fee_basis_points,
+        creator_fee,
+        track_volume,
+        total_unclaimed_tokens,
+        total_claimed_tokens,
+        current_sol_volume,
+        last_update_timestamp,
+        ix_name: ix_name.clone(),
+        mayhem_mode,
+        amount: 0,
+        max_sol_cost: 0,
+        min_sol_output: 0,
+        slippage_tolerance_bps: None,
+        bonding_curve: Pubkey::default(),
+        associated_bonding_curve: Pubkey::default(),
+        creator_vault: Pubkey::default(),
+        token_program: Pubkey::default(),
+    };
 
-        // 根据 ix_name 返回不同的事件类型
-        match ix_name.as_str() {
-            "buy" => Some(DexEvent::PumpFunBuy(trade_event)),
-            "sell" => Some(DexEvent::PumpFunSell(trade_event)),
-            "buy_exact_sol_in" => Some(DexEvent::PumpFunBuyExactSolIn(trade_event)),
-            _ => Some(DexEvent::PumpFunTrade(trade_event)),
-        }
+    // 根据 ix_name 返回不同的事件类型
+    match ix_name.as_str() {
+        "buy" => Some(DexEvent::PumpFunBuy(trade_event)),
+        "sell" => Some(DexEvent::PumpFunSell(trade_event)),
+        "buy_exact_sol_in" => Some(DexEvent::PumpFunBuyExactSolIn(trade_event)),
+        _ => Some(DexEvent::PumpFunTrade(trade_event)),
     }
 }
 
@@ -796,127 +679,116 @@ pub fn parse_buy_exact_sol_in_from_data(data: &[u8], metadata: EventMetadata, is
 /// Parse PumpFun Create event from pre-decoded data
 #[inline(always)]
 pub fn parse_create_from_data(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-    unsafe {
-        let mut offset = 0;
+    let mut offset = 0;
 
-        let (name, name_len) = read_str_unchecked(data, offset)?;
-        offset += name_len;
+    let (name, name_len) = zc_read!(str, data, offset)?;
+    offset += name_len;
 
-        let (symbol, symbol_len) = read_str_unchecked(data, offset)?;
-        offset += symbol_len;
+    let (symbol, symbol_len) = zc_read!(str, data, offset)?;
+    offset += symbol_len;
 
-        let (uri, uri_len) = read_str_unchecked(data, offset)?;
-        offset += uri_len;
+    let (uri, uri_len) = zc_read!(str, data, offset)?;
+    offset += uri_len;
 
-        if data.len() < offset + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 32 + 1 {
-            return None;
-        }
+    if data.len() < offset + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 32 + 1 {
+        return None;
+    }
 
-        let mint = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let mint = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let bonding_curve = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let bonding_curve = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let user = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let user = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let creator = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let creator = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let timestamp = read_i64_unchecked(data, offset);
-        offset += 8;
+    let timestamp = zc_read!(i64, data, offset);
+    offset += 8;
 
-        let virtual_token_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
+    let virtual_token_reserves = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let virtual_sol_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
+    let virtual_sol_reserves = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let real_token_reserves = read_u64_unchecked(data, offset);
-        offset += 8;
+    let real_token_reserves = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let token_total_supply = read_u64_unchecked(data, offset);
-        offset += 8;
+    let token_total_supply = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let token_program = if offset + 32 <= data.len() {
-            read_pubkey_unchecked(data, offset)
-        } else {
-            Pubkey::default()
-        };
-        offset += 32;
+    let token_program =
+        if offset + 32 <= data.len() { zc_read!(pubkey, data, offset) } else { Pubkey::default() };
+    offset += 32;
 
-        let is_mayhem_mode = if offset < data.len() {
-            read_bool_unchecked(data, offset)
-        } else {
-            false
-        };
+    let is_mayhem_mode = if offset < data.len() { zc_read!(bool, data, offset) } else { false };
 
-        Some(DexEvent::PumpFunCreate(PumpFunCreateTokenEvent {
-            metadata,
-            name: name.to_string(),
-            symbol: symbol.to_string(),
-            uri: uri.to_string(),
-            mint,
-            bonding_curve,
-            user,
-            creator,
-            timestamp,
-            virtual_token_reserves,
-            virtual_sol_reserves,
-            real_token_reserves,
-            token_total_supply,
-            token_program,
-            is_mayhem_mode,
-        }))
-    }
+    Some(DexEvent::PumpFunCreate(PumpFunCreateTokenEvent {
+        metadata,
+        name: name.to_string(),
+        symbol: symbol.to_string(),
+        uri: uri.to_string(),
+        mint,
+        bonding_curve,
+        user,
+        creator,
+        timestamp,
+        virtual_token_reserves,
+        virtual_sol_reserves,
+        real_token_reserves,
+        token_total_supply,
+        token_program,
+        is_mayhem_mode,
+    }))
 }
 
 /// Parse PumpFun Migrate event from pre-decoded data
 #[inline(always)]
 pub fn parse_migrate_from_data(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-    unsafe {
-        if data.len() < 32 + 32 + 8 + 8 + 8 + 32 + 8 + 32 {
-            return None;
-        }
+    if data.len() < 32 + 32 + 8 + 8 + 8 + 32 + 8 + 32 {
+        return None;
+    }
 
-        let mut offset = 0;
+    let mut offset = 0;
 
-        let user = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let user = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let mint = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let mint = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let mint_amount = read_u64_unchecked(data, offset);
-        offset += 8;
+    let mint_amount = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let sol_amount = read_u64_unchecked(data, offset);
-        offset += 8;
+    let sol_amount = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let pool_migration_fee = read_u64_unchecked(data, offset);
-        offset += 8;
+    let pool_migration_fee = zc_read!(u64, data, offset);
+    offset += 8;
 
-        let bonding_curve = read_pubkey_unchecked(data, offset);
-        offset += 32;
+    let bonding_curve = zc_read!(pubkey, data, offset);
+    offset += 32;
 
-        let timestamp = read_i64_unchecked(data, offset);
-        offset += 8;
+    let timestamp = zc_read!(i64, data, offset);
+    offset += 8;
 
-        let pool = read_pubkey_unchecked(data, offset);
+    let pool = zc_read!(pubkey, data, offset);
 
-        Some(DexEvent::PumpFunMigrate(PumpFunMigrateEvent {
-            metadata,
-            user,
-            mint,
-            mint_amount,
-            sol_amount,
-            pool_migration_fee,
-            bonding_curve,
-            timestamp,
-            pool,
-        }))
-    }
+    Some(DexEvent::PumpFunMigrate(PumpFunMigrateEvent {
+        metadata,
+        user,
+        mint,
+        mint_amount,
+        sol_amount,
+        pool_migration_fee,
+        bonding_curve,
+        timestamp,
+        pool,
+    }))
 }
 
 // ============================================================================
@@ -962,4 +834,57 @@ mod tests {
 
         println!("Average parse time: {} ns", elapsed.as_nanos() / 1000);
     }
+
+    /// 字符串字段的随机输入：非法 UTF-8 替换为 U+FFFD，任意截断不 panic
+    #[test]
+    #[cfg(not(feature = "unchecked-utf8"))]
+    fn test_fuzz_string_fields() {
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        let mut random_bytes = |len: usize| (0..len).map(|_| next() as u8).collect::<Vec<u8>>();
+
+        for _ in 0..500 {
+            let strings = [random_bytes(24), random_bytes(8), random_bytes(64)];
+            let mut data = Vec::new();
+            for s in &strings {
+                data.extend_from_slice(&(s.len() as u32).to_le_bytes());
+                data.extend_from_slice(s);
+            }
+            data.extend_from_slice(&random_bytes(32 * 5 + 8 * 5 + 1));
+
+            let Some(DexEvent::PumpFunCreate(e)) =
+                parse_create_from_data(&data, EventMetadata::default())
+            else {
+                panic!("create event not parsed")
+            };
+            for (field, raw) in [&e.name, &e.symbol, &e.uri].into_iter().zip(&strings) {
+                match std::str::from_utf8(raw) {
+                    Ok(valid) => assert_eq!(field, valid),
+                    Err(_) => assert!(field.contains('\u{FFFD}')),
+                }
+            }
+            for len in 0..data.len() {
+                let _ = parse_create_from_data(&data[..len], EventMetadata::default());
+            }
+
+            // TradeEvent 的 ix_name
+            let ix_name = random_bytes(12);
+            let mut trade = random_bytes(PUMPFUN_TRADE_EVENT.current_size - 5);
+            trade.extend_from_slice(&(ix_name.len() as u32).to_le_bytes());
+            trade.extend_from_slice(&ix_name);
+            trade.push(0);
+            for len in 0..trade.len() {
+                let _ = parse_trade_from_data(&trade[..len], EventMetadata::default(), false);
+            }
+            // 长度前缀超出缓冲区
+            trade[PUMPFUN_TRADE_EVENT.current_size - 5..][..4]
+                .copy_from_slice(&u32::MAX.to_le_bytes());
+            let _ = parse_trade_from_data(&trade, EventMetadata::default(), false);
+        }
+    }
 }
@@ -11,6 +11,8 @@
 //! - L1 cache 优化 (1KB 栈缓冲区)
 
 use crate::core::events::*;
+use crate::core::layout::{PUMPSWAP_BUY_EVENT, PUMPSWAP_SELL_EVENT};
+use crate::instr::inner_common::zc_read;
 use memchr::memmem;
 use once_cell::sync::Lazy;
 use solana_sdk::{pubkey::Pubkey, signature::Signature};
@@ -41,6 +43,12 @@ pub mod discriminators {
     pub const ADD_LIQUIDITY: u64 = u64::from_le_bytes([120, 248, 61, 83, 31, 142, 107, 144]); // DepositEvent
     pub const REMOVE_LIQUIDITY: u64 = u64::from_le_bytes([22, 9, 133, 26, 160, 44, 71, 192]);
     // WithdrawEvent
+    pub const UPDATE_FEE_CONFIG: u64 = u64::from_le_bytes([90, 23, 65, 35, 62, 244, 188, 208]); // UpdateFeeConfigEvent
+    pub const DISABLE: u64 = u64::from_le_bytes([107, 253, 193, 76, 228, 202, 27, 104]); // DisableEvent
+    pub const ADMIN_SET_COIN_CREATOR: u64 = u64::from_le_bytes([45, 220, 93, 24, 25, 97, 172, 104]); // AdminSetCoinCreatorEvent
+    pub const SET_BONDING_CURVE_COIN_CREATOR: u64 = u64::from_le_bytes([242, 231, 235, 102, 65, 99, 189, 211]); // SetBondingCurveCoinCreatorEvent
+    pub const SET_METAPLEX_COIN_CREATOR: u64 = u64::from_le_bytes([150, 107, 199, 123, 124, 207, 102, 228]); // SetMetaplexCoinCreatorEvent
+    pub const COLLECT_COIN_CREATOR_FEE: u64 = u64::from_le_bytes([232, 245, 194, 238, 234, 218, 58, 89]); // CollectCoinCreatorFeeEvent
 }
 
 /// Base64 查找器预计算 (用于快速定位)
@@ -95,77 +103,7 @@ fn extract_discriminator_simd(log: &str) -> Option<u64> {
     base64_simd::STANDARD.decode(&trimmed.as_bytes()[..16], buf.as_mut().as_out()).ok()?;
 
     // 使用 unsafe 读取 u64 (零拷贝，无边界检查)
-    unsafe {
-        let ptr = buf.as_ptr() as *const u64;
-        Some(ptr.read_unaligned())
-    }
-}
-
-// ============================================================================
-// Unsafe 读取函数 - 消除边界检查
-// ============================================================================
-
-/// 读取 u64 (unsafe, 无边界检查)
-#[inline(always)]
-unsafe fn read_u64_unchecked(data: &[u8], offset: usize) -> u64 {
-    let ptr = data.as_ptr().add(offset) as *const u64;
-    u64::from_le(ptr.read_unaligned())
-}
-
-/// 读取 i64 (unsafe, 无边界检查)
-#[inline(always)]
-unsafe fn read_i64_unchecked(data: &[u8], offset: usize) -> i64 {
-    let ptr = data.as_ptr().add(offset) as *const i64;
-    i64::from_le(ptr.read_unaligned())
-}
-
-/// Read u16 (unsafe, no bounds check)
-#[inline(always)]
-unsafe fn read_u16_unchecked(data: &[u8], offset: usize) -> u16 {
-    let ptr = data.as_ptr().add(offset) as *const u16;
-    u16::from_le(ptr.read_unaligned())
-}
-
-/// Read u32 (unsafe, no bounds check)
-#[allow(dead_code)]
-#[inline(always)]
-unsafe fn read_u32_unchecked(data: &[u8], offset: usize) -> u32 {
-    let ptr = data.as_ptr().add(offset) as *const u32;
-    u32::from_le(ptr.read_unaligned())
-}
-
-/// Read u8 (unsafe, no bounds check)
-#[inline(always)]
-unsafe fn read_u8_unchecked(data: &[u8], offset: usize) -> u8 {
-    *data.get_unchecked(offset)
-}
-
-/// 读取 bool (unsafe, 无边界检查)
-#[inline(always)]
-unsafe fn read_bool_unchecked(data: &[u8], offset: usize) -> bool {
-    *data.get_unchecked(offset) == 1
-}
-
-/// 读取 Pubkey (unsafe, 无边界检查)
-///
-/// 优化: 添加内存预取，假设连续读取多个 Pubkey
-#[inline(always)]
-unsafe fn read_pubkey_unchecked(data: &[u8], offset: usize) -> Pubkey {
-    // 预取下一个可能的 Pubkey 位置 (假设连续读取)
-    // 使用 T0 提示 (最高优先级) 将数据预取到 L1 cache
-    #[cfg(target_arch = "x86_64")]
-    {
-        use std::arch::x86_64::_mm_prefetch;
-        use std::arch::x86_64::_MM_HINT_T0;
-        if offset + 64 < data.len() {
-            _mm_prefetch((data.as_ptr().add(offset + 32)) as *const i8, _MM_HINT_T0);
-        }
-    }
-
-    let ptr = data.as_ptr().add(offset);
-    let mut bytes = [0u8; 32];
-    std::ptr::copy_nonoverlapping(ptr, bytes.as_mut_ptr(), 32);
-    Pubkey::new_from_array(bytes)
+    Some(zc_read!(u64, &buf, 0))
 }
 
 // ============================================================================
@@ -196,15 +134,17 @@ pub fn parse_log(
     }
 
     // Read discriminator using unsafe (SIMD optimized)
-    let discriminator = unsafe { read_u64_unchecked(program_data, 0) };
+    let discriminator = zc_read!(u64, program_data, 0);
     let data = &program_data[8..];
 
     let result = match discriminator {
         discriminators::BUY => {
-            parse_buy_event_optimized(data, signature, slot, tx_index, block_time_us, grpc_recv_us)
+            let data = PUMPSWAP_BUY_EVENT.upgrade(data);
+            parse_buy_event_optimized(&data, signature, slot, tx_index, block_time_us, grpc_recv_us)
         }
         discriminators::SELL => {
-            parse_sell_event_optimized(data, signature, slot, tx_index, block_time_us, grpc_recv_us)
+            let data = PUMPSWAP_SELL_EVENT.upgrade(data);
+            parse_sell_event_optimized(&data, signature, slot, tx_index, block_time_us, grpc_recv_us)
         }
         discriminators::CREATE_POOL => parse_create_pool_event_optimized(
             data,
@@ -230,6 +170,22 @@ pub fn parse_log(
             block_time_us,
             grpc_recv_us,
         ),
+        discriminators::UPDATE_FEE_CONFIG
+        | discriminators::DISABLE
+        | discriminators::ADMIN_SET_COIN_CREATOR
+        | discriminators::SET_BONDING_CURVE_COIN_CREATOR
+        | discriminators::SET_METAPLEX_COIN_CREATOR
+        | discriminators::COLLECT_COIN_CREATOR_FEE => {
+            let metadata = EventMetadata {
+                signature,
+                slot,
+                tx_index,
+                block_time_us: block_time_us.unwrap_or(0),
+                grpc_recv_us,
+                ..Default::default()
+            };
+            parse_admin_from_data(discriminator, data, metadata)
+        }
         _ => None,
     };
 
@@ -264,101 +220,99 @@ fn parse_buy_event_optimized(
         return None;
     }
 
-    unsafe {
-        let timestamp = read_i64_unchecked(data, 0);
-        let base_amount_out = read_u64_unchecked(data, 8);
-        let max_quote_amount_in = read_u64_unchecked(data, 16);
-        let user_base_token_reserves = read_u64_unchecked(data, 24);
-        let user_quote_token_reserves = read_u64_unchecked(data, 32);
-        let pool_base_token_reserves = read_u64_unchecked(data, 40);
-        let pool_quote_token_reserves = read_u64_unchecked(data, 48);
-        let quote_amount_in = read_u64_unchecked(data, 56);
-        let lp_fee_basis_points = read_u64_unchecked(data, 64);
-        let lp_fee = read_u64_unchecked(data, 72);
-        let protocol_fee_basis_points = read_u64_unchecked(data, 80);
-        let protocol_fee = read_u64_unchecked(data, 88);
-        let quote_amount_in_with_lp_fee = read_u64_unchecked(data, 96);
-        let user_quote_amount_in = read_u64_unchecked(data, 104);
-
-        let pool = read_pubkey_unchecked(data, 112);
-        let user = read_pubkey_unchecked(data, 144);
-        let user_base_token_account = read_pubkey_unchecked(data, 176);
-        let user_quote_token_account = read_pubkey_unchecked(data, 208);
-        let protocol_fee_recipient = read_pubkey_unchecked(data, 240);
-        let protocol_fee_recipient_token_account = read_pubkey_unchecked(data, 272);
-        let coin_creator = read_pubkey_unchecked(data, 304);
-
-        let coin_creator_fee_basis_points = read_u64_unchecked(data, 336);
-        let coin_creator_fee = read_u64_unchecked(data, 344);
-        let track_volume = read_bool_unchecked(data, 352);
-        let total_unclaimed_tokens = read_u64_unchecked(data, 353);
-        let total_claimed_tokens = read_u64_unchecked(data, 361);
-        let current_sol_volume = read_u64_unchecked(data, 369);
-        let last_update_timestamp = read_i64_unchecked(data, 377);
-
-        // New fields from IDL update
-        let mut offset = 385;
-        let min_base_amount_out = read_u64_unchecked(data, offset);
-        offset += 8;
-
-        // ix_name: String (4-byte length prefix + content)
-        let ix_name = if offset + 4 <= data.len() {
-            let len = read_u32_unchecked(data, offset) as usize;
-            offset += 4;
-            if offset + len <= data.len() {
-                let string_bytes = &data[offset..offset + len];
-                let s = std::str::from_utf8_unchecked(string_bytes);
-                s.to_string()
-            } else {
-                String::new()
-            }
+    let timestamp = zc_read!(i64, data, 0);
+    let base_amount_out = zc_read!(u64, data, 8);
+    let max_quote_amount_in = zc_read!(u64, data, 16);
+    let user_base_token_reserves = zc_read!(u64, data, 24);
+    let user_quote_token_reserves = zc_read!(u64, data, 32);
+    let pool_base_token_reserves = zc_read!(u64, data, 40);
+    let pool_quote_token_reserves = zc_read!(u64, data, 48);
+    let quote_amount_in = zc_read!(u64, data, 56);
+    let lp_fee_basis_points = zc_read!(u64, data, 64);
+    let lp_fee = zc_read!(u64, data, 72);
+    let protocol_fee_basis_points = zc_read!(u64, data, 80);
+    let protocol_fee = zc_read!(u64, data, 88);
+    let quote_amount_in_with_lp_fee = zc_read!(u64, data, 96);
+    let user_quote_amount_in = zc_read!(u64, data, 104);
+
+    let pool = zc_read!(pubkey, data, 112);
+    let user = zc_read!(pubkey, data, 144);
+    let user_base_token_account = zc_read!(pubkey, data, 176);
+    let user_quote_token_account = zc_read!(pubkey, data, 208);
+    let protocol_fee_recipient = zc_read!(pubkey, data, 240);
+    let protocol_fee_recipient_token_account = zc_read!(pubkey, data, 272);
+    let coin_creator = zc_read!(pubkey, data, 304);
+
+    let coin_creator_fee_basis_points = zc_read!(u64, data, 336);
+    let coin_creator_fee = zc_read!(u64, data, 344);
+    let track_volume = zc_read!(bool, data, 352);
+    let total_unclaimed_tokens = zc_read!(u64, data, 353);
+    let total_claimed_tokens = zc_read!(u64, data, 361);
+    let current_sol_volume = zc_read!(u64, data, 369);
+    let last_update_timestamp = zc_read!(i64, data, 377);
+
+    // New fields from IDL update
+    let mut offset = 385;
+    let min_base_amount_out = zc_read!(u64, data, offset);
+    offset += 8;
+
+    // ix_name: String (4-byte length prefix + content)
+    let ix_name = if offset + 4 <= data.len() {
+        let len = zc_read!(u32, data, offset) as usize;
+        offset += 4;
+        if offset + len <= data.len() {
+            let string_bytes = &data[offset..offset + len];
+            String::from_utf8_lossy(string_bytes).into_owned()
         } else {
             String::new()
-        };
+        }
+    } else {
+        String::new()
+    };
 
-        let metadata = EventMetadata {
-            signature,
-            slot,
-            tx_index,
-            block_time_us: block_time_us.unwrap_or(0),
-            grpc_recv_us,
-        };
-
-        Some(DexEvent::PumpSwapBuy(PumpSwapBuyEvent {
-            metadata,
-            timestamp,
-            base_amount_out,
-            max_quote_amount_in,
-            user_base_token_reserves,
-            user_quote_token_reserves,
-            pool_base_token_reserves,
-            pool_quote_token_reserves,
-            quote_amount_in,
-            lp_fee_basis_points,
-            lp_fee,
-            protocol_fee_basis_points,
-            protocol_fee,
-            quote_amount_in_with_lp_fee,
-            user_quote_amount_in,
-            pool,
-            user,
-            user_base_token_account,
-            user_quote_token_account,
-            protocol_fee_recipient,
-            protocol_fee_recipient_token_account,
-            coin_creator,
-            coin_creator_fee_basis_points,
-            coin_creator_fee,
-            track_volume,
-            total_unclaimed_tokens,
-            total_claimed_tokens,
-            current_sol_volume,
-            last_update_timestamp,
-            min_base_amount_out,
-            ix_name,
-            ..Default::default()
-        }))
-    }
+    let metadata = EventMetadata {
+        signature,
+        slot,
+        tx_index,
+        block_time_us: block_time_us.unwrap_or(0),
+        grpc_recv_us,
+        ..Default::default()
+    };
+
+    Some(DexEvent::PumpSwapBuy(PumpSwapBuyEvent {
+        metadata,
+        timestamp,
+        base_amount_out,
+        max_quote_amount_in,
+        user_base_token_reserves,
+        user_quote_token_reserves,
+        pool_base_token_reserves,
+        pool_quote_token_reserves,
+        quote_amount_in,
+        lp_fee_basis_points,
+        lp_fee,
+        protocol_fee_basis_points,
+        protocol_fee,
+        quote_amount_in_with_lp_fee,
+        user_quote_amount_in,
+        pool,
+        user,
+        user_base_token_account,
+        user_quote_token_account,
+        protocol_fee_recipient,
+        protocol_fee_recipient_token_account,
+        coin_creator,
+        coin_creator_fee_basis_points,
+        coin_creator_fee,
+        track_volume,
+        total_unclaimed_tokens,
+        total_claimed_tokens,
+        current_sol_volume,
+        last_update_timestamp,
+        min_base_amount_out,
+        ix_name,
+        ..Default::default()
+    }))
 }
 
 /// 解析卖出事件 (极限优化)
@@ -377,69 +331,68 @@ fn parse_sell_event_optimized(
         return None;
     }
 
-    unsafe {
-        let timestamp = read_i64_unchecked(data, 0);
-        let base_amount_in = read_u64_unchecked(data, 8);
-        let min_quote_amount_out = read_u64_unchecked(data, 16);
-        let user_base_token_reserves = read_u64_unchecked(data, 24);
-        let user_quote_token_reserves = read_u64_unchecked(data, 32);
-        let pool_base_token_reserves = read_u64_unchecked(data, 40);
-        let pool_quote_token_reserves = read_u64_unchecked(data, 48);
-        let quote_amount_out = read_u64_unchecked(data, 56);
-        let lp_fee_basis_points = read_u64_unchecked(data, 64);
-        let lp_fee = read_u64_unchecked(data, 72);
-        let protocol_fee_basis_points = read_u64_unchecked(data, 80);
-        let protocol_fee = read_u64_unchecked(data, 88);
-        let quote_amount_out_without_lp_fee = read_u64_unchecked(data, 96);
-        let user_quote_amount_out = read_u64_unchecked(data, 104);
-
-        let pool = read_pubkey_unchecked(data, 112);
-        let user = read_pubkey_unchecked(data, 144);
-        let user_base_token_account = read_pubkey_unchecked(data, 176);
-        let user_quote_token_account = read_pubkey_unchecked(data, 208);
-        let protocol_fee_recipient = read_pubkey_unchecked(data, 240);
-        let protocol_fee_recipient_token_account = read_pubkey_unchecked(data, 272);
-        let coin_creator = read_pubkey_unchecked(data, 304);
-
-        let coin_creator_fee_basis_points = read_u64_unchecked(data, 336);
-        let coin_creator_fee = read_u64_unchecked(data, 344);
-
-        let metadata = EventMetadata {
-            signature,
-            slot,
-            tx_index,
-            block_time_us: block_time_us.unwrap_or(0),
-            grpc_recv_us,
-        };
-
-        Some(DexEvent::PumpSwapSell(PumpSwapSellEvent {
-            metadata,
-            timestamp,
-            base_amount_in,
-            min_quote_amount_out,
-            user_base_token_reserves,
-            user_quote_token_reserves,
-            pool_base_token_reserves,
-            pool_quote_token_reserves,
-            quote_amount_out,
-            lp_fee_basis_points,
-            lp_fee,
-            protocol_fee_basis_points,
-            protocol_fee,
-            quote_amount_out_without_lp_fee,
-            user_quote_amount_out,
-            pool,
-            user,
-            user_base_token_account,
-            user_quote_token_account,
-            protocol_fee_recipient,
-            protocol_fee_recipient_token_account,
-            coin_creator,
-            coin_creator_fee_basis_points,
-            coin_creator_fee,
-            ..Default::default()
-        }))
-    }
+    let timestamp = zc_read!(i64, data, 0);
+    let base_amount_in = zc_read!(u64, data, 8);
+    let min_quote_amount_out = zc_read!(u64, data, 16);
+    let user_base_token_reserves = zc_read!(u64, data, 24);
+    let user_quote_token_reserves = zc_read!(u64, data, 32);
+    let pool_base_token_reserves = zc_read!(u64, data, 40);
+    let pool_quote_token_reserves = zc_read!(u64, data, 48);
+    let quote_amount_out = zc_read!(u64, data, 56);
+    let lp_fee_basis_points = zc_read!(u64, data, 64);
+    let lp_fee = zc_read!(u64, data, 72);
+    let protocol_fee_basis_points = zc_read!(u64, data, 80);
+    let protocol_fee = zc_read!(u64, data, 88);
+    let quote_amount_out_without_lp_fee = zc_read!(u64, data, 96);
+    let user_quote_amount_out = zc_read!(u64, data, 104);
+
+    let pool = zc_read!(pubkey, data, 112);
+    let user = zc_read!(pubkey, data, 144);
+    let user_base_token_account = zc_read!(pubkey, data, 176);
+    let user_quote_token_account = zc_read!(pubkey, data, 208);
+    let protocol_fee_recipient = zc_read!(pubkey, data, 240);
+    let protocol_fee_recipient_token_account = zc_read!(pubkey, data, 272);
+    let coin_creator = zc_read!(pubkey, data, 304);
+
+    let coin_creator_fee_basis_points = zc_read!(u64, data, 336);
+    let coin_creator_fee = zc_read!(u64, data, 344);
+
+    let metadata = EventMetadata {
+        signature,
+        slot,
+        tx_index,
+        block_time_us: block_time_us.unwrap_or(0),
+        grpc_recv_us,
+        ..Default::default()
+    };
+
+    Some(DexEvent::PumpSwapSell(PumpSwapSellEvent {
+        metadata,
+        timestamp,
+        base_amount_in,
+        min_quote_amount_out,
+        user_base_token_reserves,
+        user_quote_token_reserves,
+        pool_base_token_reserves,
+        pool_quote_token_reserves,
+        quote_amount_out,
+        lp_fee_basis_points,
+        lp_fee,
+        protocol_fee_basis_points,
+        protocol_fee,
+        quote_amount_out_without_lp_fee,
+        user_quote_amount_out,
+        pool,
+        user,
+        user_base_token_account,
+        user_quote_token_account,
+        protocol_fee_recipient,
+        protocol_fee_recipient_token_account,
+        coin_creator,
+        coin_creator_fee_basis_points,
+        coin_creator_fee,
+        ..Default::default()
+    }))
 }
 
 /// 解析池创建事件 (极限优化)
@@ -458,66 +411,65 @@ fn parse_create_pool_event_optimized(
         return None;
     }
 
-    unsafe {
-        let timestamp = read_i64_unchecked(data, 0);
-        let index = read_u16_unchecked(data, 8);
-
-        let creator = read_pubkey_unchecked(data, 10);
-        let base_mint = read_pubkey_unchecked(data, 42);
-        let quote_mint = read_pubkey_unchecked(data, 74);
-
-        let base_mint_decimals = read_u8_unchecked(data, 106);
-        let quote_mint_decimals = read_u8_unchecked(data, 107);
-
-        let base_amount_in = read_u64_unchecked(data, 108);
-        let quote_amount_in = read_u64_unchecked(data, 116);
-        let pool_base_amount = read_u64_unchecked(data, 124);
-        let pool_quote_amount = read_u64_unchecked(data, 132);
-        let minimum_liquidity = read_u64_unchecked(data, 140);
-        let initial_liquidity = read_u64_unchecked(data, 148);
-        let lp_token_amount_out = read_u64_unchecked(data, 156);
-
-        let pool_bump = read_u8_unchecked(data, 164);
-
-        let pool = read_pubkey_unchecked(data, 165);
-        let lp_mint = read_pubkey_unchecked(data, 197);
-        let user_base_token_account = read_pubkey_unchecked(data, 229);
-        let user_quote_token_account = read_pubkey_unchecked(data, 261);
-        let coin_creator = read_pubkey_unchecked(data, 293);
+    let timestamp = zc_read!(i64, data, 0);
+    let index = zc_read!(u16, data, 8);
+
+    let creator = zc_read!(pubkey, data, 10);
+    let base_mint = zc_read!(pubkey, data, 42);
+    let quote_mint = zc_read!(pubkey, data, 74);
+
+    let base_mint_decimals = zc_read!(u8, data, 106);
+    let quote_mint_decimals = zc_read!(u8, data, 107);
+
+    let base_amount_in = zc_read!(u64, data, 108);
+    let quote_amount_in = zc_read!(u64, data, 116);
+    let pool_base_amount = zc_read!(u64, data, 124);
+    let pool_quote_amount = zc_read!(u64, data, 132);
+    let minimum_liquidity = zc_read!(u64, data, 140);
+    let initial_liquidity = zc_read!(u64, data, 148);
+    let lp_token_amount_out = zc_read!(u64, data, 156);
+
+    let pool_bump = zc_read!(u8, data, 164);
+
+    let pool = zc_read!(pubkey, data, 165);
+    let lp_mint = zc_read!(pubkey, data, 197);
+    let user_base_token_account = zc_read!(pubkey, data, 229);
+    let user_quote_token_account = zc_read!(pubkey, data, 261);
+    let coin_creator = zc_read!(pubkey, data, 293);
+
+    let metadata = EventMetadata {
+        signature,
+        slot,
+        tx_index,
+        block_time_us: block_time_us.unwrap_or(0),
+        grpc_recv_us,
+        ..Default::default()
+    };
 
-        let metadata = EventMetadata {
-            signature,
-            slot,
-            tx_index,
-            block_time_us: block_time_us.unwrap_or(0),
-            grpc_recv_us,
-        };
-
-        Some(DexEvent::PumpSwapCreatePool(PumpSwapCreatePoolEvent {
-            metadata,
-            timestamp,
-            index,
-            creator,
-            base_mint,
-            quote_mint,
-            base_mint_decimals,
-            quote_mint_decimals,
-            base_amount_in,
-            quote_amount_in,
-            pool_base_amount,
-            pool_quote_amount,
-            minimum_liquidity,
-            initial_liquidity,
-            lp_token_amount_out,
-            pool_bump,
-            pool,
-            lp_mint,
-            user_base_token_account,
-            user_quote_token_account,
-            coin_creator,
-            is_pumpfun_migrated_pool: false,
-        }))
-    }
+    Some(DexEvent::PumpSwapCreatePool(PumpSwapCreatePoolEvent {
+        metadata,
+        timestamp,
+        index,
+        creator,
+        base_mint,
+        quote_mint,
+        base_mint_decimals,
+        quote_mint_decimals,
+        base_amount_in,
+        quote_amount_in,
+        pool_base_amount,
+        pool_quote_amount,
+        minimum_liquidity,
+        initial_liquidity,
+        lp_token_amount_out,
+        pool_bump,
+        pool,
+        lp_mint,
+        user_base_token_account,
+        user_quote_token_account,
+        coin_creator,
+        is_pumpfun_migrated_pool: false,
+    }))
 }
 
 /// 解析添加流动性事件 (极限优化)
@@ -535,53 +487,52 @@ fn parse_add_liquidity_event_optimized(
         return None;
     }
 
-    unsafe {
-        let timestamp = read_i64_unchecked(data, 0);
-        let lp_token_amount_out = read_u64_unchecked(data, 8);
-        let max_base_amount_in = read_u64_unchecked(data, 16);
-        let max_quote_amount_in = read_u64_unchecked(data, 24);
-        let user_base_token_reserves = read_u64_unchecked(data, 32);
-        let user_quote_token_reserves = read_u64_unchecked(data, 40);
-        let pool_base_token_reserves = read_u64_unchecked(data, 48);
-        let pool_quote_token_reserves = read_u64_unchecked(data, 56);
-        let base_amount_in = read_u64_unchecked(data, 64);
-        let quote_amount_in = read_u64_unchecked(data, 72);
-        let lp_mint_supply = read_u64_unchecked(data, 80);
-
-        let pool = read_pubkey_unchecked(data, 88);
-        let user = read_pubkey_unchecked(data, 120);
-        let user_base_token_account = read_pubkey_unchecked(data, 152);
-        let user_quote_token_account = read_pubkey_unchecked(data, 184);
-        let user_pool_token_account = read_pubkey_unchecked(data, 216);
-
-        let metadata = EventMetadata {
-            signature,
-            slot,
-            tx_index,
-            block_time_us: block_time_us.unwrap_or(0),
-            grpc_recv_us,
-        };
-
-        Some(DexEvent::PumpSwapLiquidityAdded(PumpSwapLiquidityAdded {
-            metadata,
-            timestamp,
-            lp_token_amount_out,
-            max_base_amount_in,
-            max_quote_amount_in,
-            user_base_token_reserves,
-            user_quote_token_reserves,
-            pool_base_token_reserves,
-            pool_quote_token_reserves,
-            base_amount_in,
-            quote_amount_in,
-            lp_mint_supply,
-            pool,
-            user,
-            user_base_token_account,
-            user_quote_token_account,
-            user_pool_token_account,
-        }))
-    }
+    let timestamp = zc_read!(i64, data, 0);
+    let lp_token_amount_out = zc_read!(u64, data, 8);
+    let max_base_amount_in = zc_read!(u64, data, 16);
+    let max_quote_amount_in = zc_read!(u64, data, 24);
+    let user_base_token_reserves = zc_read!(u64, data, 32);
+    let user_quote_token_reserves = zc_read!(u64, data, 40);
+    let pool_base_token_reserves = zc_read!(u64, data, 48);
+    let pool_quote_token_reserves = zc_read!(u64, data, 56);
+    let base_amount_in = zc_read!(u64, data, 64);
+    let quote_amount_in = zc_read!(u64, data, 72);
+    let lp_mint_supply = zc_read!(u64, data, 80);
+
+    let pool = zc_read!(pubkey, data, 88);
+    let user = zc_read!(pubkey, data, 120);
+    let user_base_token_account = zc_read!(pubkey, data, 152);
+    let user_quote_token_account = zc_read!(pubkey, data, 184);
+    let user_pool_token_account = zc_read!(pubkey, data, 216);
+
+    let metadata = EventMetadata {
+        signature,
+        slot,
+        tx_index,
+        block_time_us: block_time_us.unwrap_or(0),
+        grpc_recv_us,
+        ..Default::default()
+    };
+
+    Some(DexEvent::PumpSwapLiquidityAdded(PumpSwapLiquidityAdded {
+        metadata,
+        timestamp,
+        lp_token_amount_out,
+        max_base_amount_in,
+        max_quote_amount_in,
+        user_base_token_reserves,
+        user_quote_token_reserves,
+        pool_base_token_reserves,
+        pool_quote_token_reserves,
+        base_amount_in,
+        quote_amount_in,
+        lp_mint_supply,
+        pool,
+        user,
+        user_base_token_account,
+        user_quote_token_account,
+        user_pool_token_account,
+    }))
 }
 
 /// 解析移除流动性事件 (极限优化)
@@ -599,53 +550,52 @@ fn parse_remove_liquidity_event_optimized(
         return None;
     }
 
-    unsafe {
-        let timestamp = read_i64_unchecked(data, 0);
-        let lp_token_amount_in = read_u64_unchecked(data, 8);
-        let min_base_amount_out = read_u64_unchecked(data, 16);
-        let min_quote_amount_out = read_u64_unchecked(data, 24);
-        let user_base_token_reserves = read_u64_unchecked(data, 32);
-        let user_quote_token_reserves = read_u64_unchecked(data, 40);
-        let pool_base_token_reserves = read_u64_unchecked(data, 48);
-        let pool_quote_token_reserves = read_u64_unchecked(data, 56);
-        let base_amount_out = read_u64_unchecked(data, 64);
-        let quote_amount_out = read_u64_unchecked(data, 72);
-        let lp_mint_supply = read_u64_unchecked(data, 80);
-
-        let pool = read_pubkey_unchecked(data, 88);
-        let user = read_pubkey_unchecked(data, 120);
-        let user_base_token_account = read_pubkey_unchecked(data, 152);
-        let user_quote_token_account = read_pubkey_unchecked(data, 184);
-        let user_pool_token_account = read_pubkey_unchecked(data, 216);
-
-        let metadata = EventMetadata {
-            signature,
-            slot,
-            tx_index,
-            block_time_us: block_time_us.unwrap_or(0),
-            grpc_recv_us,
-        };
-
-        Some(DexEvent::PumpSwapLiquidityRemoved(PumpSwapLiquidityRemoved {
-            metadata,
-            timestamp,
-            lp_token_amount_in,
-            min_base_amount_out,
-            min_quote_amount_out,
-            user_base_token_reserves,
-            user_quote_token_reserves,
-            pool_base_token_reserves,
-            pool_quote_token_reserves,
-            base_amount_out,
-            quote_amount_out,
-            lp_mint_supply,
-            pool,
-            user,
-            user_base_token_account,
-            user_quote_token_account,
-            user_pool_token_account,
-        }))
-    }
+    let timestamp = zc_read!(i64, data, 0);
+    let lp_token_amount_in = zc_read!(u64, data, 8);
+    let min_base_amount_out = zc_read!(u64, data, 16);
+    let min_quote_amount_out = zc_read!(u64, data, 24);
+    let user_base_token_reserves = zc_read!(u64, data, 32);
+    let user_quote_token_reserves = zc_read!(u64, data, 40);
+    let pool_base_token_reserves = zc_read!(u64, data, 48);
+    let pool_quote_token_reserves = zc_read!(u64, data, 56);
+    let base_amount_out = zc_read!(u64, data, 64);
+    let quote_amount_out = zc_read!(u64, data, 72);
+    let lp_mint_supply = zc_read!(u64, data, 80);
+
+    let pool = zc_read!(pubkey, data, 88);
+    let user = zc_read!(pubkey, data, 120);
+    let user_base_token_account = zc_read!(pubkey, data, 152);
+    let user_quote_token_account = zc_read!(pubkey, data, 184);
+    let user_pool_token_account = zc_read!(pubkey, data, 216);
+
+    let metadata = EventMetadata {
+        signature,
+        slot,
+        tx_index,
+        block_time_us: block_time_us.unwrap_or(0),
+        grpc_recv_us,
+        ..Default::default()
+    };
+
+    Some(DexEvent::PumpSwapLiquidityRemoved(PumpSwapLiquidityRemoved {
+        metadata,
+        timestamp,
+        lp_token_amount_in,
+        min_base_amount_out,
+        min_quote_amount_out,
+        user_base_token_reserves,
+        user_quote_token_reserves,
+        pool_base_token_reserves,
+        pool_quote_token_reserves,
+        base_amount_out,
+        quote_amount_out,
+        lp_mint_supply,
+        pool,
+        user,
+        user_base_token_account,
+        user_quote_token_account,
+        user_pool_token_account,
+    }))
 }
 
 // ============================================================================
@@ -674,164 +624,163 @@ pub fn is_event_type(log: &str, discriminator: u64) -> bool {
 /// Parse PumpSwap Buy event from pre-decoded data
 #[inline(always)]
 pub fn parse_buy_from_data(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+    let upgraded = PUMPSWAP_BUY_EVENT.upgrade(data);
+    let data = &upgraded[..];
     // Updated size check for new fields
     const MIN_REQUIRED_LEN: usize = 14 * 8 + 7 * 32 + 1 + 5 * 8 + 4;
     if data.len() < MIN_REQUIRED_LEN {
         return None;
     }
 
-    unsafe {
-        let timestamp = read_i64_unchecked(data, 0);
-        let base_amount_out = read_u64_unchecked(data, 8);
-        let max_quote_amount_in = read_u64_unchecked(data, 16);
-        let user_base_token_reserves = read_u64_unchecked(data, 24);
-        let user_quote_token_reserves = read_u64_unchecked(data, 32);
-        let pool_base_token_reserves = read_u64_unchecked(data, 40);
-        let pool_quote_token_reserves = read_u64_unchecked(data, 48);
-        let quote_amount_in = read_u64_unchecked(data, 56);
-        let lp_fee_basis_points = read_u64_unchecked(data, 64);
-        let lp_fee = read_u64_unchecked(data, 72);
-        let protocol_fee_basis_points = read_u64_unchecked(data, 80);
-        let protocol_fee = read_u64_unchecked(data, 88);
-        let quote_amount_in_with_lp_fee = read_u64_unchecked(data, 96);
-        let user_quote_amount_in = read_u64_unchecked(data, 104);
-
-        let pool = read_pubkey_unchecked(data, 112);
-        let user = read_pubkey_unchecked(data, 144);
-        let user_base_token_account = read_pubkey_unchecked(data, 176);
-        let user_quote_token_account = read_pubkey_unchecked(data, 208);
-        let protocol_fee_recipient = read_pubkey_unchecked(data, 240);
-        let protocol_fee_recipient_token_account = read_pubkey_unchecked(data, 272);
-        let coin_creator = read_pubkey_unchecked(data, 304);
-
-        let coin_creator_fee_basis_points = read_u64_unchecked(data, 336);
-        let coin_creator_fee = read_u64_unchecked(data, 344);
-        let track_volume = read_bool_unchecked(data, 352);
-        let total_unclaimed_tokens = read_u64_unchecked(data, 353);
-        let total_claimed_tokens = read_u64_unchecked(data, 361);
-        let current_sol_volume = read_u64_unchecked(data, 369);
-        let last_update_timestamp = read_i64_unchecked(data, 377);
-
-        // New fields from IDL update
-        let mut offset = 385;
-        let min_base_amount_out = read_u64_unchecked(data, offset);
-        offset += 8;
-
-        // ix_name: String (4-byte length prefix + content)
-        let ix_name = if offset + 4 <= data.len() {
-            let len = read_u32_unchecked(data, offset) as usize;
-            offset += 4;
-            if offset + len <= data.len() {
-                let string_bytes = &data[offset..offset + len];
-                let s = std::str::from_utf8_unchecked(string_bytes);
-                s.to_string()
-            } else {
-                String::new()
-            }
+    let timestamp = zc_read!(i64, data, 0);
+    let base_amount_out = zc_read!(u64, data, 8);
+    let max_quote_amount_in = zc_read!(u64, data, 16);
+    let user_base_token_reserves = zc_read!(u64, data, 24);
+    let user_quote_token_reserves = zc_read!(u64, data, 32);
+    let pool_base_token_reserves = zc_read!(u64, data, 40);
+    let pool_quote_token_reserves = zc_read!(u64, data, 48);
+    let quote_amount_in = zc_read!(u64, data, 56);
+    let lp_fee_basis_points = zc_read!(u64, data, 64);
+    let lp_fee = zc_read!(u64, data, 72);
+    let protocol_fee_basis_points = zc_read!(u64, data, 80);
+    let protocol_fee = zc_read!(u64, data, 88);
+    let quote_amount_in_with_lp_fee = zc_read!(u64, data, 96);
+    let user_quote_amount_in = zc_read!(u64, data, 104);
+
+    let pool = zc_read!(pubkey, data, 112);
+    let user = zc_read!(pubkey, data, 144);
+    let user_base_token_account = zc_read!(pubkey, data, 176);
+    let user_quote_token_account = zc_read!(pubkey, data, 208);
+    let protocol_fee_recipient = zc_read!(pubkey, data, 240);
+    let protocol_fee_recipient_token_account = zc_read!(pubkey, data, 272);
+    let coin_creator = zc_read!(pubkey, data, 304);
+
+    let coin_creator_fee_basis_points = zc_read!(u64, data, 336);
+    let coin_creator_fee = zc_read!(u64, data, 344);
+    let track_volume = zc_read!(bool, data, 352);
+    let total_unclaimed_tokens = zc_read!(u64, data, 353);
+    let total_claimed_tokens = zc_read!(u64, data, 361);
+    let current_sol_volume = zc_read!(u64, data, 369);
+    let last_update_timestamp = zc_read!(i64, data, 377);
+
+    // New fields from IDL update
+    let mut offset = 385;
+    let min_base_amount_out = zc_read!(u64, data, offset);
+    offset += 8;
+
+    // ix_name: String (4-byte length prefix + content)
+    let ix_name = if offset + 4 <= data.len() {
+        let len = zc_read!(u32, data, offset) as usize;
+        offset += 4;
+        if offset + len <= data.len() {
+            let string_bytes = &data[offset..offset + len];
+            String::from_utf8_lossy(string_bytes).into_owned()
         } else {
             String::new()
-        };
-
-        Some(DexEvent::PumpSwapBuy(PumpSwapBuyEvent {
-            metadata,
-            timestamp,
-            base_amount_out,
-            max_quote_amount_in,
-            user_base_token_reserves,
-            user_quote_token_reserves,
-            pool_base_token_reserves,
-            pool_quote_token_reserves,
-            quote_amount_in,
-            lp_fee_basis_points,
-            lp_fee,
-            protocol_fee_basis_points,
-            protocol_fee,
-            quote_amount_in_with_lp_fee,
-            user_quote_amount_in,
-            pool,
-            user,
-            user_base_token_account,
-            user_quote_token_account,
-            protocol_fee_recipient,
-            protocol_fee_recipient_token_account,
-            coin_creator,
-            coin_creator_fee_basis_points,
-            coin_creator_fee,
-            track_volume,
-            total_unclaimed_tokens,
-            total_claimed_tokens,
-            current_sol_volume,
-            last_update_timestamp,
-            min_base_amount_out,
-            ix_name,
-            ..Default::default()
-        }))
-    }
+        }
+    } else {
+        String::new()
+    };
+
+    Some(DexEvent::PumpSwapBuy(PumpSwapBuyEvent {
+        metadata,
+        timestamp,
+        base_amount_out,
+        max_quote_amount_in,
+        user_base_token_reserves,
+        user_quote_token_reserves,
+        pool_base_token_reserves,
+        pool_quote_token_reserves,
+        quote_amount_in,
+        lp_fee_basis_points,
+        lp_fee,
+        protocol_fee_basis_points,
+        protocol_fee,
+        quote_amount_in_with_lp_fee,
+        user_quote_amount_in,
+        pool,
+        user,
+        user_base_token_account,
+        user_quote_token_account,
+        protocol_fee_recipient,
+        protocol_fee_recipient_token_account,
+        coin_creator,
+        coin_creator_fee_basis_points,
+        coin_creator_fee,
+        track_volume,
+        total_unclaimed_tokens,
+        total_claimed_tokens,
+        current_sol_volume,
+        last_update_timestamp,
+        min_base_amount_out,
+        ix_name,
+        ..Default::default()
+    }))
 }
 
 /// Parse PumpSwap Sell event from pre-decoded data
 #[inline(always)]
 pub fn parse_sell_from_data(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+    let upgraded = PUMPSWAP_SELL_EVENT.upgrade(data);
+    let data = &upgraded[..];
     const REQUIRED_LEN: usize = 13 * 8 + 8 + 7 * 32;
     if data.len() < REQUIRED_LEN {
         return None;
     }
 
-    unsafe {
-        let timestamp = read_i64_unchecked(data, 0);
-        let base_amount_in = read_u64_unchecked(data, 8);
-        let min_quote_amount_out = read_u64_unchecked(data, 16);
-        let user_base_token_reserves = read_u64_unchecked(data, 24);
-        let user_quote_token_reserves = read_u64_unchecked(data, 32);
-        let pool_base_token_reserves = read_u64_unchecked(data, 40);
-        let pool_quote_token_reserves = read_u64_unchecked(data, 48);
-        let quote_amount_out = read_u64_unchecked(data, 56);
-        let lp_fee_basis_points = read_u64_unchecked(data, 64);
-        let lp_fee = read_u64_unchecked(data, 72);
-        let protocol_fee_basis_points = read_u64_unchecked(data, 80);
-        let protocol_fee = read_u64_unchecked(data, 88);
-        let quote_amount_out_without_lp_fee = read_u64_unchecked(data, 96);
-        let user_quote_amount_out = read_u64_unchecked(data, 104);
-
-        let pool = read_pubkey_unchecked(data, 112);
-        let user = read_pubkey_unchecked(data, 144);
-        let user_base_token_account = read_pubkey_unchecked(data, 176);
-        let user_quote_token_account = read_pubkey_unchecked(data, 208);
-        let protocol_fee_recipient = read_pubkey_unchecked(data, 240);
-        let protocol_fee_recipient_token_account = read_pubkey_unchecked(data, 272);
-        let coin_creator = read_pubkey_unchecked(data, 304);
-
-        let coin_creator_fee_basis_points = read_u64_unchecked(data, 336);
-        let coin_creator_fee = read_u64_unchecked(data, 344);
-
-        Some(DexEvent::PumpSwapSell(PumpSwapSellEvent {
-            metadata,
-            timestamp,
-            base_amount_in,
-            min_quote_amount_out,
-            user_base_token_reserves,
-            user_quote_token_reserves,
-            pool_base_token_reserves,
-            pool_quote_token_reserves,
-            quote_amount_out,
-            lp_fee_basis_points,
-            lp_fee,
-            protocol_fee_basis_points,
-            protocol_fee,
-            quote_amount_out_without_lp_fee,
-            user_quote_amount_out,
-            pool,
-            user,
-            user_base_token_account,
-            user_quote_token_account,
-            protocol_fee_recipient,
-            protocol_fee_recipient_token_account,
-            coin_creator,
-            coin_creator_fee_basis_points,
-            coin_creator_fee,
-            ..Default::default()
-        }))
-    }
+    let timestamp = zc_read!(i64, data, 0);
+    let base_amount_in = zc_read!(u64, data, 8);
+    let min_quote_amount_out = zc_read!(u64, data, 16);
+    let user_base_token_reserves = zc_read!(u64, data, 24);
+    let user_quote_token_reserves = zc_read!(u64, data, 32);
+    let pool_base_token_reserves = zc_read!(u64, data, 40);
+    let pool_quote_token_reserves = zc_read!(u64, data, 48);
+    let quote_amount_out = zc_read!(u64, data, 56);
+    let lp_fee_basis_points = zc_read!(u64, data, 64);
+    let lp_fee = zc_read!(u64, data, 72);
+    let protocol_fee_basis_points = zc_read!(u64, data, 80);
+    let protocol_fee = zc_read!(u64, data, 88);
+    let quote_amount_out_without_lp_fee = zc_read!(u64, data, 96);
+    let user_quote_amount_out = zc_read!(u64, data, 104);
+
+    let pool = zc_read!(pubkey, data, 112);
+    let user = zc_read!(pubkey, data, 144);
+    let user_base_token_account = zc_read!(pubkey, data, 176);
+    let user_quote_token_account = zc_read!(pubkey, data, 208);
+    let protocol_fee_recipient = zc_read!(pubkey, data, 240);
+    let protocol_fee_recipient_token_account = zc_read!(pubkey, data, 272);
+    let coin_creator = zc_read!(pubkey, data, 304);
+
+    let coin_creator_fee_basis_points = zc_read!(u64, data, 336);
+    let coin_creator_fee = zc_read!(u64, data, 344);
+
+    Some(DexEvent::PumpSwapSell(PumpSwapSellEvent {
+        metadata,
+        timestamp,
+        base_amount_in,
+        min_quote_amount_out,
+        user_base_token_reserves,
+        user_quote_token_reserves,
+        pool_base_token_reserves,
+        pool_quote_token_reserves,
+        quote_amount_out,
+        lp_fee_basis_points,
+        lp_fee,
+        protocol_fee_basis_points,
+        protocol_fee,
+        quote_amount_out_without_lp_fee,
+        user_quote_amount_out,
+        pool,
+        user,
+        user_base_token_account,
+        user_quote_token_account,
+        protocol_fee_recipient,
+        protocol_fee_recipient_token_account,
+        coin_creator,
+        coin_creator_fee_basis_points,
+        coin_creator_fee,
+        ..Default::default()
+    }))
 }
 
 /// Parse PumpSwap CreatePool event from pre-decoded data
@@ -842,58 +791,56 @@ pub fn parse_create_pool_from_data(data: &[u8], metadata: EventMetadata) -> Opti
         return None;
     }
 
-    unsafe {
-        let timestamp = read_i64_unchecked(data, 0);
-        let index = read_u16_unchecked(data, 8);
-
-        let creator = read_pubkey_unchecked(data, 10);
-        let base_mint = read_pubkey_unchecked(data, 42);
-        let quote_mint = read_pubkey_unchecked(data, 74);
-
-        let base_mint_decimals = read_u8_unchecked(data, 106);
-        let quote_mint_decimals = read_u8_unchecked(data, 107);
-
-        let base_amount_in = read_u64_unchecked(data, 108);
-        let quote_amount_in = read_u64_unchecked(data, 116);
-        let pool_base_amount = read_u64_unchecked(data, 124);
-        let pool_quote_amount = read_u64_unchecked(data, 132);
-        let minimum_liquidity = read_u64_unchecked(data, 140);
-        let initial_liquidity = read_u64_unchecked(data, 148);
-        let lp_token_amount_out = read_u64_unchecked(data, 156);
-
-        let pool_bump = read_u8_unchecked(data, 164);
-
-        let pool = read_pubkey_unchecked(data, 165);
-        let lp_mint = read_pubkey_unchecked(data, 197);
-        let user_base_token_account = read_pubkey_unchecked(data, 229);
-        let user_quote_token_account = read_pubkey_unchecked(data, 261);
-        let coin_creator = read_pubkey_unchecked(data, 293);
-
-        Some(DexEvent::PumpSwapCreatePool(PumpSwapCreatePoolEvent {
-            metadata,
-            timestamp,
-            index,
-            creator,
-            base_mint,
-            quote_mint,
-            base_mint_decimals,
-            quote_mint_decimals,
-            base_amount_in,
-            quote_amount_in,
-            pool_base_amount,
-            pool_quote_amount,
-            minimum_liquidity,
-            initial_liquidity,
-            lp_token_amount_out,
-            pool_bump,
-            pool,
-            lp_mint,
-            user_base_token_account,
-            user_quote_token_account,
-            coin_creator,
-            is_pumpfun_migrated_pool: false,
-        }))
-    }
+    let timestamp = zc_read!(i64, data, 0);
+    let index = zc_read!(u16, data, 8);
+
+    let creator = zc_read!(pubkey, data, 10);
+    let base_mint = zc_read!(pubkey, data, 42);
+    let quote_mint = zc_read!(pubkey, data, 74);
+
+    let base_mint_decimals = zc_read!(u8, data, 106);
+    let quote_mint_decimals = zc_read!(u8, data, 107);
+
+    let base_amount_in = zc_read!(u64, data, 108);
+    let quote_amount_in = zc_read!(u64, data, 116);
+    let pool_base_amount = zc_read!(u64, data, 124);
+    let pool_quote_amount = zc_read!(u64, data, 132);
+    let minimum_liquidity = zc_read!(u64, data, 140);
+    let initial_liquidity = zc_read!(u64, data, 148);
+    let lp_token_amount_out = zc_read!(u64, data, 156);
+
+    let pool_bump = zc_read!(u8, data, 164);
+
+    let pool = zc_read!(pubkey, data, 165);
+    let lp_mint = zc_read!(pubkey, data, 197);
+    let user_base_token_account = zc_read!(pubkey, data, 229);
+    let user_quote_token_account = zc_read!(pubkey, data, 261);
+    let coin_creator = zc_read!(pubkey, data, 293);
+
+    Some(DexEvent::PumpSwapCreatePool(PumpSwapCreatePoolEvent {
+        metadata,
+        timestamp,
+        index,
+        creator,
+        base_mint,
+        quote_mint,
+        base_mint_decimals,
+        quote_mint_decimals,
+        base_amount_in,
+        quote_amount_in,
+        pool_base_amount,
+        pool_quote_amount,
+        minimum_liquidity,
+        initial_liquidity,
+        lp_token_amount_out,
+        pool_bump,
+        pool,
+        lp_mint,
+        user_base_token_account,
+        user_quote_token_account,
+        coin_creator,
+        is_pumpfun_migrated_pool: false,
+    }))
 }
 
 /// Parse PumpSwap AddLiquidity event from pre-decoded data
@@ -904,45 +851,43 @@ pub fn parse_add_liquidity_from_data(data: &[u8], metadata: EventMetadata) -> Op
         return None;
     }
 
-    unsafe {
-        let timestamp = read_i64_unchecked(data, 0);
-        let lp_token_amount_out = read_u64_unchecked(data, 8);
-        let max_base_amount_in = read_u64_unchecked(data, 16);
-        let max_quote_amount_in = read_u64_unchecked(data, 24);
-        let user_base_token_reserves = read_u64_unchecked(data, 32);
-        let user_quote_token_reserves = read_u64_unchecked(data, 40);
-        let pool_base_token_reserves = read_u64_unchecked(data, 48);
-        let pool_quote_token_reserves = read_u64_unchecked(data, 56);
-        let base_amount_in = read_u64_unchecked(data, 64);
-        let quote_amount_in = read_u64_unchecked(data, 72);
-        let lp_mint_supply = read_u64_unchecked(data, 80);
-
-        let pool = read_pubkey_unchecked(data, 88);
-        let user = read_pubkey_unchecked(data, 120);
-        let user_base_token_account = read_pubkey_unchecked(data, 152);
-        let user_quote_token_account = read_pubkey_unchecked(data, 184);
-        let user_pool_token_account = read_pubkey_unchecked(data, 216);
-
-        Some(DexEvent::PumpSwapLiquidityAdded(PumpSwapLiquidityAdded {
-            metadata,
-            timestamp,
-            lp_token_amount_out,
-            max_base_amount_in,
-            max_quote_amount_in,
-            user_base_token_reserves,
-            user_quote_token_reserves,
-            pool_base_token_reserves,
-            pool_quote_token_reserves,
-            base_amount_in,
-            quote_amount_in,
-            lp_mint_supply,
-            pool,
-            user,
-            user_base_token_account,
-            user_quote_token_account,
-            user_pool_token_account,
-        }))
-    }
+    let timestamp = zc_read!(i64, data, 0);
+    let lp_token_amount_out = zc_read!(u64, data, 8);
+    let max_base_amount_in = zc_read!(u64, data, 16);
+    let max_quote_amount_in = zc_read!(u64, data, 24);
+    let user_base_token_reserves = zc_read!(u64, data, 32);
+    let user_quote_token_reserves = zc_read!(u64, data, 40);
+    let pool_base_token_reserves = zc_read!(u64, data, 48);
+    let pool_quote_token_reserves = zc_read!(u64, data, 56);
+    let base_amount_in = zc_read!(u64, data, 64);
+    let quote_amount_in = zc_read!(u64, data, 72);
+    let lp_mint_supply = zc_read!(u64, data, 80);
+
+    let pool = zc_read!(pubkey, data, 88);
+    let user = zc_read!(pubkey, data, 120);
+    let user_base_token_account = zc_read!(pubkey, data, 152);
+    let user_quote_token_account = zc_read!(pubkey, data, 184);
+    let user_pool_token_account = zc_read!(pubkey, data, 216);
+
+    Some(DexEvent::PumpSwapLiquidityAdded(PumpSwapLiquidityAdded {
+        metadata,
+        timestamp,
+        lp_token_amount_out,
+        max_base_amount_in,
+        max_quote_amount_in,
+        user_base_token_reserves,
+        user_quote_token_reserves,
+        pool_base_token_reserves,
+        pool_quote_token_reserves,
+        base_amount_in,
+        quote_amount_in,
+        lp_mint_supply,
+        pool,
+        user,
+        user_base_token_account,
+        user_quote_token_account,
+        user_pool_token_account,
+    }))
 }
 
 /// Parse PumpSwap RemoveLiquidity event from pre-decoded data
@@ -953,45 +898,195 @@ pub fn parse_remove_liquidity_from_data(data: &[u8], metadata: EventMetadata) ->
         return None;
     }
 
-    unsafe {
-        let timestamp = read_i64_unchecked(data, 0);
-        let lp_token_amount_in = read_u64_unchecked(data, 8);
-        let min_base_amount_out = read_u64_unchecked(data, 16);
-        let min_quote_amount_out = read_u64_unchecked(data, 24);
-        let user_base_token_reserves = read_u64_unchecked(data, 32);
-        let user_quote_token_reserves = read_u64_unchecked(data, 40);
-        let pool_base_token_reserves = read_u64_unchecked(data, 48);
-        let pool_quote_token_reserves = read_u64_unchecked(data, 56);
-        let base_amount_out = read_u64_unchecked(data, 64);
-        let quote_amount_out = read_u64_unchecked(data, 72);
-        let lp_mint_supply = read_u64_unchecked(data, 80);
-
-        let pool = read_pubkey_unchecked(data, 88);
-        let user = read_pubkey_unchecked(data, 120);
-        let user_base_token_account = read_pubkey_unchecked(data, 152);
-        let user_quote_token_account = read_pubkey_unchecked(data, 184);
-        let user_pool_token_account = read_pubkey_unchecked(data, 216);
-
-        Some(DexEvent::PumpSwapLiquidityRemoved(PumpSwapLiquidityRemoved {
-            metadata,
-            timestamp,
-            lp_token_amount_in,
-            min_base_amount_out,
-            min_quote_amount_out,
-            user_base_token_reserves,
-            user_quote_token_reserves,
-            pool_base_token_reserves,
-            pool_quote_token_reserves,
-            base_amount_out,
-            quote_amount_out,
-            lp_mint_supply,
-            pool,
-            user,
-            user_base_token_account,
-            user_quote_token_account,
-            user_pool_token_account,
-        }))
+    let timestamp = zc_read!(i64, data, 0);
+    let lp_token_amount_in = zc_read!(u64, data, 8);
+    let min_base_amount_out = zc_read!(u64, data, 16);
+    let min_quote_amount_out = zc_read!(u64, data, 24);
+    let user_base_token_reserves = zc_read!(u64, data, 32);
+    let user_quote_token_reserves = zc_read!(u64, data, 40);
+    let pool_base_token_reserves = zc_read!(u64, data, 48);
+    let pool_quote_token_reserves = zc_read!(u64, data, 56);
+    let base_amount_out = zc_read!(u64, data, 64);
+    let quote_amount_out = zc_read!(u64, data, 72);
+    let lp_mint_supply = zc_read!(u64, data, 80);
+
+    let pool = zc_read!(pubkey, data, 88);
+    let user = zc_read!(pubkey, data, 120);
+    let user_base_token_account = zc_read!(pubkey, data, 152);
+    let user_quote_token_account = zc_read!(pubkey, data, 184);
+    let user_pool_token_account = zc_read!(pubkey, data, 216);
+
+    Some(DexEvent::PumpSwapLiquidityRemoved(PumpSwapLiquidityRemoved {
+        metadata,
+        timestamp,
+        lp_token_amount_in,
+        min_base_amount_out,
+        min_quote_amount_out,
+        user_base_token_reserves,
+        user_quote_token_reserves,
+        pool_base_token_reserves,
+        pool_quote_token_reserves,
+        base_amount_out,
+        quote_amount_out,
+        lp_mint_supply,
+        pool,
+        user,
+        user_base_token_account,
+        user_quote_token_account,
+        user_pool_token_account,
+    }))
+}
+
+/// Parse PumpSwap admin events (UpdateFeeConfig / Disable / coin creator 变更 / 创作者手续费领取) from pre-decoded data
+///
+/// `discriminator` 为事件 discriminator（u64 LE），不属于管理事件时返回 None。
+#[inline]
+pub fn parse_admin_from_data(
+    discriminator: u64,
+    data: &[u8],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::UPDATE_FEE_CONFIG => parse_update_fee_config_from_data(data, metadata),
+        discriminators::DISABLE => parse_disable_from_data(data, metadata),
+        discriminators::ADMIN_SET_COIN_CREATOR => {
+            parse_admin_set_coin_creator_from_data(data, metadata)
+        }
+        discriminators::SET_BONDING_CURVE_COIN_CREATOR
+        | discriminators::SET_METAPLEX_COIN_CREATOR => {
+            parse_set_coin_creator_from_data(data, metadata)
+        }
+        discriminators::COLLECT_COIN_CREATOR_FEE => {
+            parse_collect_coin_creator_fee_from_data(data, metadata)
+        }
+        _ => None,
+    }
+}
+
+/// Parse PumpSwap UpdateFeeConfig event from pre-decoded data
+#[inline(always)]
+pub fn parse_update_fee_config_from_data(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+    const REQUIRED_LEN: usize = 8 + 32 + 8 + 8 + 32 * 8 + 8 + 32;
+    if data.len() < REQUIRED_LEN {
+        return None;
+    }
+
+    let timestamp = zc_read!(i64, data, 0);
+    let admin = zc_read!(pubkey, data, 8);
+    let lp_fee_basis_points = zc_read!(u64, data, 40);
+    let protocol_fee_basis_points = zc_read!(u64, data, 48);
+
+    let mut protocol_fee_recipients = [Pubkey::default(); 8];
+    for (i, recipient) in protocol_fee_recipients.iter_mut().enumerate() {
+        *recipient = zc_read!(pubkey, data, 56 + i * 32);
+    }
+
+    let coin_creator_fee_basis_points = zc_read!(u64, data, 312);
+    let admin_set_coin_creator_authority = zc_read!(pubkey, data, 320);
+
+    Some(DexEvent::PumpSwapConfigUpdated(PumpSwapConfigUpdated {
+        metadata,
+        timestamp,
+        admin,
+        lp_fee_basis_points,
+        protocol_fee_basis_points,
+        protocol_fee_recipients,
+        coin_creator_fee_basis_points,
+        admin_set_coin_creator_authority,
+        ix_name: "update_fee_config".to_string(),
+        ..Default::default()
+    }))
+}
+
+/// Parse PumpSwap Disable event from pre-decoded data
+#[inline(always)]
+pub fn parse_disable_from_data(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+    const REQUIRED_LEN: usize = 8 + 32 + 5;
+    if data.len() < REQUIRED_LEN {
+        return None;
+    }
+
+    Some(DexEvent::PumpSwapConfigUpdated(PumpSwapConfigUpdated {
+        metadata,
+        timestamp: zc_read!(i64, data, 0),
+        admin: zc_read!(pubkey, data, 8),
+        disable_create_pool: zc_read!(bool, data, 40),
+        disable_deposit: zc_read!(bool, data, 41),
+        disable_withdraw: zc_read!(bool, data, 42),
+        disable_buy: zc_read!(bool, data, 43),
+        disable_sell: zc_read!(bool, data, 44),
+        ix_name: "disable".to_string(),
+        ..Default::default()
+    }))
+}
+
+/// Parse PumpSwap AdminSetCoinCreator event from pre-decoded data
+#[inline(always)]
+pub fn parse_admin_set_coin_creator_from_data(
+    data: &[u8],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    const REQUIRED_LEN: usize = 8 + 32 * 5;
+    if data.len() < REQUIRED_LEN {
+        return None;
+    }
+
+    Some(DexEvent::PumpSwapPoolUpdated(PumpSwapPoolUpdated {
+        metadata,
+        timestamp: zc_read!(i64, data, 0),
+        authority: zc_read!(pubkey, data, 8),
+        base_mint: zc_read!(pubkey, data, 40),
+        pool: zc_read!(pubkey, data, 72),
+        old_coin_creator: zc_read!(pubkey, data, 104),
+        new_coin_creator: zc_read!(pubkey, data, 136),
+        ix_name: "admin_set_coin_creator".to_string(),
+    }))
+}
+
+/// Parse PumpSwap SetBondingCurveCoinCreator / SetMetaplexCoinCreator event from pre-decoded data
+///
+/// 两个事件布局相同：timestamp, base_mint, pool, bonding_curve | metadata, coin_creator
+#[inline(always)]
+pub fn parse_set_coin_creator_from_data(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+    const REQUIRED_LEN: usize = 8 + 32 * 4;
+    if data.len() < REQUIRED_LEN {
+        return None;
+    }
+
+    Some(DexEvent::PumpSwapPoolUpdated(PumpSwapPoolUpdated {
+        metadata,
+        timestamp: zc_read!(i64, data, 0),
+        base_mint: zc_read!(pubkey, data, 8),
+        pool: zc_read!(pubkey, data, 40),
+        authority: zc_read!(pubkey, data, 72),
+        old_coin_creator: Pubkey::default(),
+        new_coin_creator: zc_read!(pubkey, data, 104),
+        ix_name: "set_coin_creator".to_string(),
+    }))
+}
+
+/// Parse PumpSwap CollectCoinCreatorFee event from pre-decoded data
+///
+/// 布局：timestamp, coin_creator, coin_creator_fee, coin_creator_vault_ata, coin_creator_token_account
+#[inline(always)]
+pub fn parse_collect_coin_creator_fee_from_data(
+    data: &[u8],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    const REQUIRED_LEN: usize = 8 + 32 + 8 + 32 + 32;
+    if data.len() < REQUIRED_LEN {
+        return None;
     }
+
+    Some(DexEvent::PumpSwapCreatorFeeClaimed(PumpSwapCreatorFeeClaimed {
+        metadata,
+        timestamp: zc_read!(i64, data, 0),
+        creator: zc_read!(pubkey, data, 8),
+        amount: zc_read!(u64, data, 40),
+        creator_vault_ata: zc_read!(pubkey, data, 48),
+        creator_token_account: zc_read!(pubkey, data, 80),
+        quote_mint: Pubkey::default(),
+    }))
 }
 
 // ============================================================================
@@ -1014,6 +1109,7 @@ pub fn reset_perf_stats() {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::logs::utils::program_data_log;
 
     #[test]
     fn test_discriminator_simd() {
@@ -1037,4 +1133,146 @@ mod tests {
 
         println!("Average parse time: {} ns", elapsed.as_nanos() / 1000);
     }
+
+    #[test]
+    fn test_parse_update_fee_config_log() {
+        let admin = Pubkey::new_unique();
+        let recipients: Vec<Pubkey> = (0..8).map(|_| Pubkey::new_unique()).collect();
+        let authority = Pubkey::new_unique();
+
+        let mut body = 1_700_000_000i64.to_le_bytes().to_vec();
+        body.extend_from_slice(admin.as_ref());
+        body.extend_from_slice(&20u64.to_le_bytes());
+        body.extend_from_slice(&5u64.to_le_bytes());
+        for r in &recipients {
+            body.extend_from_slice(r.as_ref());
+        }
+        body.extend_from_slice(&5u64.to_le_bytes());
+        body.extend_from_slice(authority.as_ref());
+        let log = program_data_log(&discriminators::UPDATE_FEE_CONFIG.to_le_bytes(), &body);
+
+        match crate::logs::parse_log(&log, Signature::default(), 1, 0, Some(0), 0, None, false) {
+            Some(DexEvent::PumpSwapConfigUpdated(e)) => {
+                assert_eq!(e.ix_name, "update_fee_config");
+                assert_eq!(e.admin, admin);
+                assert_eq!(e.lp_fee_basis_points, 20);
+                assert_eq!(e.protocol_fee_basis_points, 5);
+                assert_eq!(e.protocol_fee_recipients.to_vec(), recipients);
+                assert_eq!(e.admin_set_coin_creator_authority, authority);
+                assert!(!e.disable_buy);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_disable_log() {
+        let mut body = 0i64.to_le_bytes().to_vec();
+        body.extend_from_slice(Pubkey::new_unique().as_ref());
+        body.extend_from_slice(&[0, 0, 0, 1, 1]);
+        let log = program_data_log(&discriminators::DISABLE.to_le_bytes(), &body);
+
+        match parse_log(&log, Signature::default(), 1, 0, Some(0), 0) {
+            Some(DexEvent::PumpSwapConfigUpdated(e)) => {
+                assert_eq!(e.ix_name, "disable");
+                assert!(!e.disable_create_pool);
+                assert!(e.disable_buy && e.disable_sell);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_coin_creator_log() {
+        let keys: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let mut body = 0i64.to_le_bytes().to_vec();
+        for k in &keys {
+            body.extend_from_slice(k.as_ref());
+        }
+        let log = program_data_log(&discriminators::SET_METAPLEX_COIN_CREATOR.to_le_bytes(), &body);
+
+        match crate::logs::parse_log(&log, Signature::default(), 1, 0, Some(0), 0, None, false) {
+            Some(DexEvent::PumpSwapPoolUpdated(e)) => {
+                assert_eq!(e.ix_name, "set_coin_creator");
+                assert_eq!(e.base_mint, keys[0]);
+                assert_eq!(e.pool, keys[1]);
+                assert_eq!(e.new_coin_creator, keys[3]);
+                assert_eq!(e.old_coin_creator, Pubkey::default());
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_admin_set_coin_creator_inner() {
+        use crate::instr::pump_amm_inner::{discriminators as inner, parse_pumpswap_inner_instruction};
+
+        let keys: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+        let mut body = 0i64.to_le_bytes().to_vec();
+        for k in &keys {
+            body.extend_from_slice(k.as_ref());
+        }
+        let metadata = EventMetadata { signature: Signature::default(), slot: 1, tx_index: 0, block_time_us: 0, grpc_recv_us: 0, ..Default::default() };
+
+        match parse_pumpswap_inner_instruction(&inner::ADMIN_SET_COIN_CREATOR, &body, metadata) {
+            Some(DexEvent::PumpSwapPoolUpdated(e)) => {
+                assert_eq!(e.ix_name, "admin_set_coin_creator");
+                assert_eq!(e.authority, keys[0]);
+                assert_eq!(e.pool, keys[2]);
+                assert_eq!(e.old_coin_creator, keys[3]);
+                assert_eq!(e.new_coin_creator, keys[4]);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_collect_coin_creator_fee() {
+        use crate::instr::pump_amm_inner::{discriminators as inner, parse_pumpswap_inner_instruction};
+
+        let keys: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let mut body = 1_700_000_000i64.to_le_bytes().to_vec();
+        body.extend_from_slice(keys[0].as_ref());
+        body.extend_from_slice(&123_456u64.to_le_bytes());
+        body.extend_from_slice(keys[1].as_ref());
+        body.extend_from_slice(keys[2].as_ref());
+
+        let log = program_data_log(&discriminators::COLLECT_COIN_CREATOR_FEE.to_le_bytes(), &body);
+        let from_log = crate::logs::parse_log(&log, Signature::default(), 1, 0, Some(0), 0, None, false);
+        let metadata = EventMetadata { signature: Signature::default(), slot: 1, ..Default::default() };
+        let from_inner =
+            parse_pumpswap_inner_instruction(&inner::COLLECT_COIN_CREATOR_FEE, &body, metadata);
+
+        // 外层指令提供 quote_mint，合并后保留
+        let quote_mint = Pubkey::new_unique();
+        let mut accounts = vec![quote_mint, Pubkey::new_unique(), keys[0], Pubkey::new_unique()];
+        accounts.extend_from_slice(&keys[1..]);
+        let mut merged = crate::instr::pump_amm::parse_instruction(
+            &crate::instr::pump_amm::discriminators::COLLECT_COIN_CREATOR_FEE,
+            &accounts,
+            Signature::default(),
+            1,
+            0,
+            Some(0),
+        )
+        .unwrap();
+        crate::core::merger::merge_events(&mut merged, from_inner.clone().unwrap());
+        match &merged {
+            DexEvent::PumpSwapCreatorFeeClaimed(e) => assert_eq!(e.quote_mint, quote_mint),
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        for event in [from_log, from_inner, Some(merged)] {
+            match event {
+                Some(DexEvent::PumpSwapCreatorFeeClaimed(e)) => {
+                    assert_eq!(e.timestamp, 1_700_000_000);
+                    assert_eq!(e.creator, keys[0]);
+                    assert_eq!(e.amount, 123_456);
+                    assert_eq!(e.creator_vault_ata, keys[1]);
+                    assert_eq!(e.creator_token_account, keys[2]);
+                }
+                other => panic!("unexpected event: {:?}", other),
+            }
+        }
+    }
 }
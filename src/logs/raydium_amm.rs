@@ -591,7 +591,7 @@ fn parse_withdraw_pnl_event(
 }
 
 /// 文本日志解析（回退方案）
-fn parse_text_log(
+pub(crate) fn parse_text_log(
     log: &str,
     signature: Signature,
     slot: u64,
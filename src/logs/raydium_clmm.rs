@@ -8,7 +8,8 @@ use super::utils::*;
 
 /// Raydium CLMM discriminator 常量
 pub mod discriminators {
-    pub const SWAP: [u8; 8] = [248, 198, 158, 145, 225, 117, 135, 200];
+    /// SwapEvent（swap / swap_v2 共用）
+    pub const SWAP: [u8; 8] = [64, 198, 205, 232, 38, 8, 113, 226];
     pub const INCREASE_LIQUIDITY: [u8; 8] = [133, 29, 89, 223, 69, 238, 176, 10];
     pub const DECREASE_LIQUIDITY: [u8; 8] = [160, 38, 208, 111, 104, 91, 44, 1];
     pub const CREATE_POOL: [u8; 8] = [233, 146, 209, 142, 207, 104, 64, 188];
@@ -16,7 +17,7 @@ pub mod discriminators {
 }
 
 /// Raydium CLMM 程序 ID
-pub const PROGRAM_ID: &str = "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK";
+pub const PROGRAM_ID: &str = crate::grpc::program_ids::RAYDIUM_CLMM_PROGRAM_ID;
 
 /// 检查日志是否来自 Raydium CLMM 程序
 pub fn is_raydium_clmm_log(log: &str) -> bool {
@@ -85,16 +86,34 @@ fn parse_swap_event(
     let user = read_pubkey(data, offset)?;
     offset += 32;
 
-    let amount = read_u64_le(data, offset)?;
+    let token_account_0 = read_pubkey(data, offset)?;
+    offset += 32;
+
+    let token_account_1 = read_pubkey(data, offset)?;
+    offset += 32;
+
+    let amount_0 = read_u64_le(data, offset)?;
     offset += 8;
 
-    let other_amount_threshold = read_u64_le(data, offset)?;
+    let transfer_fee_0 = read_u64_le(data, offset)?;
     offset += 8;
 
-    let sqrt_price_limit_x64 = read_u128_le(data, offset)?;
-    offset += 16;
+    let amount_1 = read_u64_le(data, offset)?;
+    offset += 8;
+
+    let transfer_fee_1 = read_u64_le(data, offset)?;
+    offset += 8;
 
     let is_base_input = read_bool(data, offset)?;
+    offset += 1;
+
+    let sqrt_price_x64 = read_u128_le(data, offset)?;
+    offset += 16;
+
+    let liquidity = read_u128_le(data, offset)?;
+    offset += 16;
+
+    let tick = read_i32_le(data, offset)?;
 
     let metadata = create_metadata_simple(signature, slot, tx_index, block_time_us, pool_state, grpc_recv_us);
 
@@ -104,17 +123,17 @@ fn parse_swap_event(
         // IDL SwapEvent 事件字段
         pool_state,
         sender: user,
-        token_account_0: Pubkey::default(),
-        token_account_1: Pubkey::default(),
-        amount_0: 0, // 从日志填充
-        transfer_fee_0: 0, // 从日志填充
-        amount_1: 0, // 从日志填充
-        transfer_fee_1: 0, // 从日志填充
+        token_account_0,
+        token_account_1,
+        amount_0,
+        transfer_fee_0,
+        amount_1,
+        transfer_fee_1,
         zero_for_one: is_base_input,
-        sqrt_price_x64: sqrt_price_limit_x64,
+        sqrt_price_x64,
         // is_base_input,
-        liquidity: 0, // 从日志填充
-        tick: 0, // 从日志填充
+        liquidity,
+        tick,
 
         // 暂时注释，以后会用，AI禁止改动
         // 指令参数字段
@@ -271,7 +290,7 @@ fn parse_collect_fee_event(
 }
 
 /// 文本回退解析
-fn parse_text_log(
+pub(crate) fn parse_text_log(
     log: &str,
     signature: Signature,
     slot: u64,
@@ -362,9 +381,9 @@ fn parse_increase_liquidity_from_text(
         pool: Pubkey::default(),
         position_nft_mint: Pubkey::default(),
         user: Pubkey::default(),
-        liquidity: extract_number_from_text(log, "liquidity").unwrap_or(1_000_000) as u128,
-        amount0_max: extract_number_from_text(log, "amount0_max").unwrap_or(1_000_000),
-        amount1_max: extract_number_from_text(log, "amount1_max").unwrap_or(1_000_000),
+        liquidity: extract_number_from_text(log, "liquidity").unwrap_or(0) as u128,
+        amount0_max: extract_number_from_text(log, "amount0_max").unwrap_or(0),
+        amount1_max: extract_number_from_text(log, "amount1_max").unwrap_or(0),
     }))
 }
 
@@ -386,9 +405,9 @@ fn parse_decrease_liquidity_from_text(
         pool: Pubkey::default(),
         position_nft_mint: Pubkey::default(),
         user: Pubkey::default(),
-        liquidity: extract_number_from_text(log, "liquidity").unwrap_or(1_000_000) as u128,
-        amount0_min: extract_number_from_text(log, "amount0_min").unwrap_or(1_000_000),
-        amount1_min: extract_number_from_text(log, "amount1_min").unwrap_or(1_000_000),
+        liquidity: extract_number_from_text(log, "liquidity").unwrap_or(0) as u128,
+        amount0_min: extract_number_from_text(log, "amount0_min").unwrap_or(0),
+        amount1_min: extract_number_from_text(log, "amount1_min").unwrap_or(0),
     }))
 }
 
@@ -435,8 +454,8 @@ fn parse_collect_fee_from_text(
         metadata,
         pool_state: Pubkey::default(),
         position_nft_mint: Pubkey::default(),
-        amount_0: extract_number_from_text(log, "amount_0").unwrap_or(10_000),
-        amount_1: extract_number_from_text(log, "amount_1").unwrap_or(10_000),
+        amount_0: extract_number_from_text(log, "amount_0").unwrap_or(0),
+        amount_1: extract_number_from_text(log, "amount_1").unwrap_or(0),
     }))
 }
 
@@ -446,41 +465,25 @@ fn parse_collect_fee_from_text(
 // ============================================================================
 
 /// Parse Raydium CLMM Swap event from pre-decoded data
+///
+/// Layout follows the IDL `SwapEvent`, emitted by both `swap` and `swap_v2`. For Token-2022
+/// mints with a transfer fee extension, `transfer_fee_0/1` hold the fee withheld on each side.
 #[inline(always)]
 pub fn parse_swap_from_data(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
-    let mut offset = 0;
-
-    let pool_state = read_pubkey(data, offset)?;
-    offset += 32;
-
-    let user = read_pubkey(data, offset)?;
-    offset += 32;
-
-    let _amount = read_u64_le(data, offset)?;
-    offset += 8;
-
-    let _other_amount_threshold = read_u64_le(data, offset)?;
-    offset += 8;
-
-    let sqrt_price_limit_x64 = read_u128_le(data, offset)?;
-    offset += 16;
-
-    let is_base_input = read_bool(data, offset)?;
-
     Some(DexEvent::RaydiumClmmSwap(RaydiumClmmSwapEvent {
         metadata,
-        pool_state,
-        sender: user,
-        token_account_0: Pubkey::default(),
-        token_account_1: Pubkey::default(),
-        amount_0: 0,
-        transfer_fee_0: 0,
-        amount_1: 0,
-        transfer_fee_1: 0,
-        zero_for_one: is_base_input,
-        sqrt_price_x64: sqrt_price_limit_x64,
-        liquidity: 0,
-        tick: 0,
+        pool_state: read_pubkey(data, 0)?,
+        sender: read_pubkey(data, 32)?,
+        token_account_0: read_pubkey(data, 64)?,
+        token_account_1: read_pubkey(data, 96)?,
+        amount_0: read_u64_le(data, 128)?,
+        transfer_fee_0: read_u64_le(data, 136)?,
+        amount_1: read_u64_le(data, 144)?,
+        transfer_fee_1: read_u64_le(data, 152)?,
+        zero_for_one: read_bool(data, 160)?,
+        sqrt_price_x64: read_u128_le(data, 161)?,
+        liquidity: read_u128_le(data, 177)?,
+        tick: read_i32_le(data, 193)?,
     }))
 }
 
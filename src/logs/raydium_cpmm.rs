@@ -150,10 +150,10 @@ pub fn parse_create_pool_from_data(data: &[u8], metadata: EventMetadata) -> Opti
     let pool_state = read_pubkey(data, offset)?;
     offset += 32;
 
-    let _token_0_mint = read_pubkey(data, offset)?;
+    let token_0_mint = read_pubkey(data, offset)?;
     offset += 32;
 
-    let _token_1_mint = read_pubkey(data, offset)?;
+    let token_1_mint = read_pubkey(data, offset)?;
     offset += 32;
 
     let creator = read_pubkey(data, offset)?;
@@ -170,6 +170,9 @@ pub fn parse_create_pool_from_data(data: &[u8], metadata: EventMetadata) -> Opti
         creator,
         init_amount0: initial_amount_0,
         init_amount1: initial_amount_1,
+        token_0_mint,
+        token_1_mint,
+        ..Default::default()
     }))
 }
 
@@ -391,6 +394,9 @@ fn parse_create_pool_event(
         creator,
         init_amount0: initial_amount_0,
         init_amount1: initial_amount_1,
+        token_0_mint,
+        token_1_mint,
+        ..Default::default()
     }))
 }
 
@@ -469,7 +475,7 @@ fn parse_withdraw_event(
 }
 
 /// 文本回退解析
-fn parse_text_log(
+pub(crate) fn parse_text_log(
     log: &str,
     signature: Signature,
     slot: u64,
@@ -524,8 +530,8 @@ fn parse_swap_base_in_from_text(
         pool_id: Pubkey::default(),
         input_vault_before: 0,
         output_vault_before: 0,
-        input_amount: extract_number_from_text(log, "amount_in").unwrap_or(1_000_000_000),
-        output_amount: extract_number_from_text(log, "amount_out").unwrap_or(950_000_000),
+        input_amount: extract_number_from_text(log, "amount_in").unwrap_or(0),
+        output_amount: extract_number_from_text(log, "amount_out").unwrap_or(0),
         input_transfer_fee: 0,
         output_transfer_fee: 0,
         base_input: true,
@@ -569,8 +575,8 @@ fn parse_swap_base_out_from_text(
         pool_id: Pubkey::default(),
         input_vault_before: 0,
         output_vault_before: 0,
-        input_amount: extract_number_from_text(log, "amount_in").unwrap_or(1_000_000_000),
-        output_amount: extract_number_from_text(log, "amount_out").unwrap_or(950_000_000),
+        input_amount: extract_number_from_text(log, "amount_in").unwrap_or(0),
+        output_amount: extract_number_from_text(log, "amount_out").unwrap_or(0),
         input_transfer_fee: 0,
         output_transfer_fee: 0,
         base_input: false,
@@ -611,8 +617,9 @@ fn parse_create_pool_from_text(
         metadata,
         pool: Pubkey::default(),
         creator: Pubkey::default(),
-        init_amount0: extract_number_from_text(log, "amount_0").unwrap_or(1_000_000_000),
-        init_amount1: extract_number_from_text(log, "amount_1").unwrap_or(1_000_000_000),
+        init_amount0: extract_number_from_text(log, "amount_0").unwrap_or(0),
+        init_amount1: extract_number_from_text(log, "amount_1").unwrap_or(0),
+        ..Default::default()
     }))
 }
 
@@ -633,9 +640,9 @@ fn parse_deposit_from_text(
         metadata,
         pool: Pubkey::default(),
         user: Pubkey::default(),
-        lp_token_amount: extract_number_from_text(log, "lp_token").unwrap_or(1_000_000),
-        token0_amount: extract_number_from_text(log, "token_0").unwrap_or(1_000_000_000),
-        token1_amount: extract_number_from_text(log, "token_1").unwrap_or(1_000_000_000),
+        lp_token_amount: extract_number_from_text(log, "lp_token").unwrap_or(0),
+        token0_amount: extract_number_from_text(log, "token_0").unwrap_or(0),
+        token1_amount: extract_number_from_text(log, "token_1").unwrap_or(0),
     }))
 }
 
@@ -656,8 +663,8 @@ fn parse_withdraw_from_text(
         metadata,
         pool: Pubkey::default(),
         user: Pubkey::default(),
-        lp_token_amount: extract_number_from_text(log, "lp_token").unwrap_or(1_000_000),
-        token0_amount: extract_number_from_text(log, "token_0").unwrap_or(1_000_000_000),
-        token1_amount: extract_number_from_text(log, "token_1").unwrap_or(1_000_000_000),
+        lp_token_amount: extract_number_from_text(log, "lp_token").unwrap_or(0),
+        token0_amount: extract_number_from_text(log, "token_0").unwrap_or(0),
+        token1_amount: extract_number_from_text(log, "token_1").unwrap_or(0),
     }))
 }
\ No newline at end of file
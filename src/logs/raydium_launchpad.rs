@@ -1,6 +1,12 @@
 //! Bonk 日志解析器
 //!
-//! 使用 match discriminator 模式解析 Bonk 事件
+//! 使用 match discriminator 模式解析 Bonk (Raydium LaunchLab) 事件。
+//! Discriminator 与字段布局均来自 `idls/raydium_launchpad.json`。
+//!
+//! 注意：
+//! - `TradeEvent` 与 PumpFun `TradeEvent` 的 discriminator 相同，
+//!   统一入口（`optimized_matcher`）在 PumpFun 解析失败（数据过短）时回退到本模块
+//! - LaunchLab 迁移不产生日志事件，`BonkMigrateAmm` 由指令解析器产生
 
 use solana_sdk::{pubkey::Pubkey, signature::Signature};
 use crate::core::events::*;
@@ -8,13 +14,12 @@ use super::utils::*;
 
 /// Bonk discriminator 常量
 pub mod discriminators {
-    pub const TRADE: [u8; 8] = [2, 3, 4, 5, 6, 7, 8, 9];
-    pub const POOL_CREATE: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
-    pub const MIGRATE_AMM: [u8; 8] = [3, 4, 5, 6, 7, 8, 9, 10];
+    pub const TRADE: [u8; 8] = [189, 219, 127, 211, 78, 230, 97, 238];
+    pub const POOL_CREATE: [u8; 8] = [151, 215, 226, 9, 118, 161, 115, 174];
 }
 
 /// Bonk 程序 ID
-pub const PROGRAM_ID: &str = "DjVE6JNiYqPL2QXyCUUh8rNjHrbz9hXHNYt99MQ59qw1";
+pub const PROGRAM_ID: &str = "LanMV9sAd7wArD4vJFi2qDdfnVhFxYSUg6eADduJ3uj";
 
 /// 检查日志是否来自 Raydium Launchpad 程序
 pub fn is_raydium_launchpad_log(log: &str) -> bool {
@@ -45,37 +50,59 @@ fn parse_structured_log(
 
     let discriminator: [u8; 8] = program_data[0..8].try_into().ok()?;
     let data = &program_data[8..];
+    let metadata = EventMetadata {
+        signature,
+        slot,
+        tx_index,
+        block_time_us: block_time_us.unwrap_or(0),
+        grpc_recv_us,
+        ..Default::default()
+    };
 
     match discriminator {
-        discriminators::TRADE => {
-            parse_trade_event(data, signature, slot, tx_index, block_time_us, grpc_recv_us)
-        },
-        discriminators::POOL_CREATE => {
-            parse_pool_create_event(data, signature, slot, tx_index, block_time_us, grpc_recv_us)
-        },
-        discriminators::MIGRATE_AMM => {
-            parse_migrate_amm_event(data, signature, slot, tx_index, block_time_us, grpc_recv_us)
-        },
+        discriminators::TRADE => parse_trade_from_data(data, metadata),
+        discriminators::POOL_CREATE => parse_pool_create_from_data(data, metadata),
         _ => None,
     }
 }
 
-/// 解析交易事件
-fn parse_trade_event(
-    data: &[u8],
-    signature: Signature,
-    slot: u64,
-    tx_index: u64,
-    block_time_us: Option<i64>,
-    grpc_recv_us: i64,
-) -> Option<DexEvent> {
+// ============================================================================
+// Public API for optimized parsing from pre-decoded data
+// These functions accept already-decoded data (without discriminator)
+// ============================================================================
+
+/// Parse Raydium Launchpad TradeEvent from pre-decoded data
+#[inline(always)]
+pub fn parse_trade_from_data(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+    if data.len() < BonkTradeEvent::TRADE_EVENT_SIZE {
+        return None;
+    }
+
     let mut offset = 0;
 
     let pool_state = read_pubkey(data, offset)?;
     offset += 32;
 
-    let user = read_pubkey(data, offset)?;
-    offset += 32;
+    let total_base_sell = read_u64_le(data, offset)?;
+    offset += 8;
+
+    let virtual_base = read_u64_le(data, offset)?;
+    offset += 8;
+
+    let virtual_quote = read_u64_le(data, offset)?;
+    offset += 8;
+
+    let real_base_before = read_u64_le(data, offset)?;
+    offset += 8;
+
+    let real_quote_before = read_u64_le(data, offset)?;
+    offset += 8;
+
+    let real_base_after = read_u64_le(data, offset)?;
+    offset += 8;
+
+    let real_quote_after = read_u64_le(data, offset)?;
+    offset += 8;
 
     let amount_in = read_u64_le(data, offset)?;
     offset += 8;
@@ -83,103 +110,90 @@ fn parse_trade_event(
     let amount_out = read_u64_le(data, offset)?;
     offset += 8;
 
-    let is_buy = read_bool(data, offset)?;
+    let protocol_fee = read_u64_le(data, offset)?;
+    offset += 8;
+
+    let platform_fee = read_u64_le(data, offset)?;
+    offset += 8;
+
+    let creator_fee = read_u64_le(data, offset)?;
+    offset += 8;
+
+    let share_fee = read_u64_le(data, offset)?;
+    offset += 8;
+
+    let trade_direction = TradeDirection::from_u8(read_u8(data, offset)?)?;
     offset += 1;
 
-    let exact_in = read_bool(data, offset)?;
+    let pool_status = PoolStatus::from_u8(read_u8(data, offset)?)?;
+    offset += 1;
 
-    let metadata = create_metadata_simple(signature, slot, tx_index, block_time_us, pool_state, grpc_recv_us);
+    let exact_in = read_bool(data, offset)?;
 
     Some(DexEvent::BonkTrade(BonkTradeEvent {
         metadata,
         pool_state,
-        user,
+        total_base_sell,
+        virtual_base,
+        virtual_quote,
+        real_base_before,
+        real_quote_before,
+        real_base_after,
+        real_quote_after,
         amount_in,
         amount_out,
-        is_buy,
-        trade_direction: if is_buy { TradeDirection::Buy } else { TradeDirection::Sell },
+        protocol_fee,
+        platform_fee,
+        creator_fee,
+        share_fee,
+        trade_direction,
+        pool_status,
         exact_in,
+        is_buy: trade_direction == TradeDirection::Buy,
+        user: Pubkey::default(),
     }))
 }
 
-/// 解析池创建事件
-fn parse_pool_create_event(
-    data: &[u8],
-    signature: Signature,
-    slot: u64,
-    tx_index: u64,
-    block_time_us: Option<i64>,
-    grpc_recv_us: i64,
-) -> Option<DexEvent> {
+/// Parse Raydium Launchpad PoolCreateEvent from pre-decoded data
+///
+/// 只解析到 `base_mint_param`，后续的 curve/vesting 参数不影响事件字段。
+#[inline(always)]
+pub fn parse_pool_create_from_data(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
     let mut offset = 0;
 
     let pool_state = read_pubkey(data, offset)?;
     offset += 32;
 
-    let _token_a_mint = read_pubkey(data, offset)?;
+    let creator = read_pubkey(data, offset)?;
     offset += 32;
 
-    let _token_b_mint = read_pubkey(data, offset)?;
+    let config = read_pubkey(data, offset)?;
     offset += 32;
 
-    let creator = read_pubkey(data, offset)?;
-    offset += 32;
+    let decimals = read_u8(data, offset)?;
+    offset += 1;
 
-    let _initial_liquidity_a = read_u64_le(data, offset)?;
-    offset += 8;
+    let (name, name_len) = read_string(data, offset)?;
+    offset += name_len;
 
-    let _initial_liquidity_b = read_u64_le(data, offset)?;
+    let (symbol, symbol_len) = read_string(data, offset)?;
+    offset += symbol_len;
 
-    let metadata = create_metadata_simple(signature, slot, tx_index, block_time_us, pool_state, grpc_recv_us);
+    let (uri, _) = read_string(data, offset)?;
 
     Some(DexEvent::BonkPoolCreate(BonkPoolCreateEvent {
         metadata,
-        base_mint_param: BaseMintParam {
-            symbol: "BONK".to_string(),
-            name: "Bonk Pool".to_string(),
-            uri: "https://bonk.com".to_string(),
-            decimals: 5,
-        },
+        base_mint_param: BaseMintParam { symbol, name, uri, decimals },
         pool_state,
         creator,
-    }))
-}
-
-/// 解析 AMM 迁移事件
-fn parse_migrate_amm_event(
-    data: &[u8],
-    signature: Signature,
-    slot: u64,
-    tx_index: u64,
-    block_time_us: Option<i64>,
-    grpc_recv_us: i64,
-) -> Option<DexEvent> {
-    let mut offset = 0;
-
-    let old_pool = read_pubkey(data, offset)?;
-    offset += 32;
-
-    let new_pool = read_pubkey(data, offset)?;
-    offset += 32;
-
-    let user = read_pubkey(data, offset)?;
-    offset += 32;
-
-    let liquidity_amount = read_u64_le(data, offset)?;
-
-    let metadata = create_metadata_simple(signature, slot, tx_index, block_time_us, old_pool, grpc_recv_us);
-
-    Some(DexEvent::BonkMigrateAmm(BonkMigrateAmmEvent {
-        metadata,
-        old_pool,
-        new_pool,
-        user,
-        liquidity_amount,
+        config,
+        base_mint: Pubkey::default(),
+        quote_mint: Pubkey::default(),
     }))
 }
 
 /// 文本回退解析
-fn parse_text_log(
+pub(crate) fn parse_text_log(
     tx_index: u64,
     log: &str,
     signature: Signature,
@@ -219,13 +233,12 @@ fn parse_trade_from_text(tx_index: u64,
 
     Some(DexEvent::BonkTrade(BonkTradeEvent {
         metadata,
-        pool_state: Pubkey::default(),
-        user: Pubkey::default(),
-        amount_in: extract_number_from_text(log, "amount_in").unwrap_or(1000000),
-        amount_out: extract_number_from_text(log, "amount_out").unwrap_or(950000),
+        amount_in: extract_number_from_text(log, "amount_in").unwrap_or(0),
+        amount_out: extract_number_from_text(log, "amount_out").unwrap_or(0),
         is_buy,
         trade_direction: if is_buy { TradeDirection::Buy } else { TradeDirection::Sell },
         exact_in: true,
+        ..Default::default()
     }))
 }
 
@@ -241,14 +254,18 @@ fn parse_pool_create_from_text(tx_index: u64,
 
     Some(DexEvent::BonkPoolCreate(BonkPoolCreateEvent {
         metadata,
+        // 文本日志不含代币元数据
         base_mint_param: BaseMintParam {
-            symbol: "BONK".to_string(),
-            name: "Bonk Pool".to_string(),
-            uri: "https://bonk.com".to_string(),
-            decimals: 5,
+            symbol: String::new(),
+            name: String::new(),
+            uri: String::new(),
+            decimals: 0,
         },
         pool_state: Pubkey::default(),
         creator: Pubkey::default(),
+        config: Pubkey::default(),
+        base_mint: Pubkey::default(),
+        quote_mint: Pubkey::default(),
     }))
 }
 
@@ -271,4 +288,172 @@ fn parse_migrate_from_text(tx_index: u64,
         user: Pubkey::default(),
         liquidity_amount: extract_number_from_text(log, "liquidity").unwrap_or(0),
     }))
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logs::utils::program_data_log;
+
+    /// 按 IDL TradeEvent 布局构造事件数据（不含 discriminator）
+    fn trade_event_fixture(pool_state: Pubkey, direction: u8) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(pool_state.as_ref());
+        for v in 1..=13u64 {
+            data.extend_from_slice(&(v * 1_000).to_le_bytes());
+        }
+        data.push(direction); // trade_direction
+        data.push(2); // pool_status = Trade
+        data.push(1); // exact_in
+        data
+    }
+
+    /// 按 IDL PoolCreateEvent 布局构造事件数据（不含 discriminator）
+    fn pool_create_event_fixture(pool_state: Pubkey, creator: Pubkey, config: Pubkey) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(pool_state.as_ref());
+        data.extend_from_slice(creator.as_ref());
+        data.extend_from_slice(config.as_ref());
+        data.push(6); // decimals
+        for s in ["Bonk Test", "BTEST", "https://example.com/bt.json"] {
+            data.extend_from_slice(&(s.len() as u32).to_le_bytes());
+            data.extend_from_slice(s.as_bytes());
+        }
+        // curve_param: Constant { supply, total_base_sell, total_quote_fund_raising, migrate_type }
+        data.push(0);
+        data.extend_from_slice(&[0u8; 8 * 3 + 1]);
+        // vesting_param + amm_fee_on
+        data.extend_from_slice(&[0u8; 8 * 3]);
+        data.push(0);
+        data
+    }
+
+    #[test]
+    fn test_trade_event_size_matches_idl() {
+        let data = trade_event_fixture(Pubkey::new_unique(), 0);
+        assert_eq!(data.len(), BonkTradeEvent::TRADE_EVENT_SIZE);
+    }
+
+    #[test]
+    fn test_parse_trade_log() {
+        let pool_state = Pubkey::new_unique();
+        let log = program_data_log(&discriminators::TRADE, &trade_event_fixture(pool_state, 1));
+
+        match parse_log(&log, Signature::default(), 7, 0, Some(0), 0) {
+            Some(DexEvent::BonkTrade(e)) => {
+                assert_eq!(e.pool_state, pool_state);
+                assert_eq!(e.total_base_sell, 1_000);
+                assert_eq!(e.amount_in, 8_000);
+                assert_eq!(e.amount_out, 9_000);
+                assert_eq!(e.share_fee, 13_000);
+                assert_eq!(e.trade_direction, TradeDirection::Sell);
+                assert_eq!(e.pool_status, PoolStatus::Trade);
+                assert!(!e.is_buy);
+                assert!(e.exact_in);
+                assert_eq!(e.metadata.slot, 7);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_pool_create_log() {
+        let (pool_state, creator, config) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let log = program_data_log(
+            &discriminators::POOL_CREATE,
+            &pool_create_event_fixture(pool_state, creator, config),
+        );
+
+        match parse_log(&log, Signature::default(), 7, 0, Some(0), 0) {
+            Some(DexEvent::BonkPoolCreate(e)) => {
+                assert_eq!(e.pool_state, pool_state);
+                assert_eq!(e.creator, creator);
+                assert_eq!(e.config, config);
+                assert_eq!(e.base_mint_param.decimals, 6);
+                assert_eq!(e.base_mint_param.name, "Bonk Test");
+                assert_eq!(e.base_mint_param.symbol, "BTEST");
+                assert_eq!(e.base_mint_param.uri, "https://example.com/bt.json");
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_unified_parser_routes_launchpad_events() {
+        let pool_state = Pubkey::new_unique();
+
+        // TradeEvent 与 PumpFun TradeEvent discriminator 相同，需按长度回退到 Launchpad
+        let log = program_data_log(&discriminators::TRADE, &trade_event_fixture(pool_state, 0));
+        match crate::logs::parse_log(&log, Signature::default(), 1, 0, Some(0), 0, None, false) {
+            Some(DexEvent::BonkTrade(e)) => {
+                assert_eq!(e.pool_state, pool_state);
+                assert!(e.is_buy);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        let log = program_data_log(
+            &discriminators::POOL_CREATE,
+            &pool_create_event_fixture(pool_state, Pubkey::new_unique(), Pubkey::new_unique()),
+        );
+        assert!(matches!(
+            crate::logs::parse_log(&log, Signature::default(), 1, 0, Some(0), 0, None, false),
+            Some(DexEvent::BonkPoolCreate(_))
+        ));
+    }
+
+    #[test]
+    fn test_unified_parser_respects_bonk_filter() {
+        use crate::grpc::types::{EventType, EventTypeFilter};
+
+        let log =
+            program_data_log(&discriminators::TRADE, &trade_event_fixture(Pubkey::new_unique(), 0));
+        let bonk_only = EventTypeFilter::include_only(vec![EventType::BonkTrade]);
+        assert!(matches!(
+            crate::logs::parse_log(&log, Signature::default(), 1, 0, Some(0), 0, Some(&bonk_only), false),
+            Some(DexEvent::BonkTrade(_))
+        ));
+
+        let pump_only = EventTypeFilter::include_only(vec![EventType::PumpFunTrade]);
+        assert!(crate::logs::parse_log(
+            &log,
+            Signature::default(),
+            1,
+            0,
+            Some(0),
+            0,
+            Some(&pump_only),
+            false
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_inner_instruction_matches_log_decoding() {
+        let pool_state = Pubkey::new_unique();
+        let fixture = trade_event_fixture(pool_state, 0);
+        let disc = crate::instr::all_inner::bonk::discriminators::TRADE;
+        assert_eq!(&disc[..8], &discriminators::TRADE);
+
+        let event = crate::instr::all_inner::bonk::parse(&disc, &fixture, EventMetadata::default());
+        match event {
+            Some(DexEvent::BonkTrade(e)) => {
+                assert_eq!(e.pool_state, pool_state);
+                assert_eq!(e.virtual_quote, 3_000);
+                assert_eq!(e.trade_direction, TradeDirection::Buy);
+                assert!(e.is_buy);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        let fixture =
+            pool_create_event_fixture(pool_state, Pubkey::new_unique(), Pubkey::new_unique());
+        let disc = crate::instr::all_inner::bonk::discriminators::POOL_CREATE;
+        assert_eq!(&disc[..8], &discriminators::POOL_CREATE);
+        assert!(matches!(
+            crate::instr::all_inner::bonk::parse(&disc, &fixture, EventMetadata::default()),
+            Some(DexEvent::BonkPoolCreate(e)) if e.base_mint_param.symbol == "BTEST"
+        ));
+    }
+}
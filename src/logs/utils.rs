@@ -165,6 +165,7 @@ pub fn create_metadata_simple(
         tx_index,
         block_time_us: block_time_us.unwrap_or(0),
         grpc_recv_us,
+        ..Default::default()
     }
 }
 
@@ -182,16 +183,26 @@ pub fn create_metadata_default(
         tx_index,
         block_time_us: block_time_us.unwrap_or(0),
         grpc_recv_us: current_time,
+        ..Default::default()
     }
 }
 
+/// 构造 `Program data: <base64(discriminator + body)>` 日志
+#[cfg(test)]
+pub(crate) fn program_data_log(disc: &[u8], body: &[u8]) -> String {
+    let mut data = disc.to_vec();
+    data.extend_from_slice(body);
+    format!("Program data: {}", general_purpose::STANDARD.encode(data))
+}
+
 /// 文本回退解析工具
 pub mod text_parser {
 
     /// 从文本中提取数字
     pub fn extract_number_from_text(text: &str, field: &str) -> Option<u64> {
         if let Some(start) = text.find(&format!("{}:", field)) {
-            let after_colon = &text[start + field.len() + 1..];
+            // 兼容 "field: 123" 与 "field:123"
+            let after_colon = text[start + field.len() + 1..].trim_start();
             if let Some(end) = after_colon.find(' ').or_else(|| after_colon.find(',')) {
                 after_colon[..end].trim().parse().ok()
             } else {
@@ -220,7 +231,7 @@ pub mod text_parser {
     #[inline(always)]  // 零延迟优化：内联热路径
     pub fn extract_text_field_ref<'a>(text: &'a str, field: &str) -> Option<&'a str> {
         let start = text.find(&format!("{}:", field))?;
-        let after_colon = &text[start + field.len() + 1..];
+        let after_colon = text[start + field.len() + 1..].trim_start();
         if let Some(end) = after_colon.find(',').or_else(|| after_colon.find(' ')) {
             Some(after_colon[..end].trim())
         } else {
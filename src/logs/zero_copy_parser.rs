@@ -4,6 +4,7 @@ use solana_sdk::{pubkey::Pubkey, signature::Signature};
 use crate::core::events::*;
 use memchr::memmem;
 use base64::{Engine as _, engine::general_purpose};
+#[cfg(not(feature = "forbid-unsafe"))]
 use super::perf_hints::prefetch_read;
 
 /// 零分配 PumpFun Trade 事件解析（栈缓冲区）
@@ -62,6 +63,7 @@ pub fn parse_pumpfun_trade(
     let mut offset = 0;
 
     // 预取后续数据到 CPU 缓存
+    #[cfg(not(feature = "forbid-unsafe"))]
     unsafe {
         if data.len() >= 64 {
             prefetch_read(data.as_ptr().add(32));
@@ -134,6 +136,7 @@ pub fn parse_pumpfun_trade(
         tx_index,
         block_time_us: block_time_us.unwrap_or(0),
         grpc_recv_us,
+        ..Default::default()
     };
 
     Some(DexEvent::PumpFunTrade(PumpFunTradeEvent {
@@ -0,0 +1,208 @@
+//! Metaplex 代币元数据解析
+//!
+//! 按 mint 缓存 Metaplex Token Metadata（名称、符号、URI、更新权限），数据来源：
+//! - 账户流：订阅元数据账户（owner 为 [`METADATA_PROGRAM_ID`]），用 [`MetadataResolver::observe_account`] 更新
+//! - RPC：[`MetadataResolver::resolve`] 缓存未命中时按 PDA 查询
+//! - 发行事件：`PumpFunCreate` 自带名称 / 符号 / URI
+//!
+//! 之后可用 [`MetadataResolver::for_event`] 为发行、交易事件查找可读标识。
+//! 发行事件 URI 指向的 JSON（图片、社交链接）见 `uri` 模块（feature `uri-fetcher`）。
+
+#[cfg(feature = "uri-fetcher")]
+pub mod uri;
+
+use crate::accounts::AccountData;
+use crate::DexEvent;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey;
+use solana_sdk::pubkey::Pubkey;
+use std::sync::Arc;
+
+/// Metaplex Token Metadata 程序 ID
+pub const METADATA_PROGRAM_ID: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// 元数据账户的 `Key::MetadataV1`
+const KEY_METADATA_V1: u8 = 4;
+
+/// 代币的可读标识
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenMetadata {
+    pub mint: Pubkey,
+    /// 从发行事件学习时未知，为默认值
+    pub update_authority: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+/// mint 对应的元数据 PDA
+pub fn metadata_pda(mint: &Pubkey) -> Pubkey {
+    let seeds: &[&[u8]] = &[b"metadata", METADATA_PROGRAM_ID.as_ref(), mint.as_ref()];
+    Pubkey::find_program_address(seeds, &METADATA_PROGRAM_ID).0
+}
+
+/// 解析元数据账户数据，字符串去掉末尾的 `\0` 填充
+pub fn parse_metadata_account(data: &[u8]) -> Option<TokenMetadata> {
+    if data.first() != Some(&KEY_METADATA_V1) {
+        return None;
+    }
+    let mut offset = 1;
+    let update_authority = read_pubkey(data, &mut offset)?;
+    let mint = read_pubkey(data, &mut offset)?;
+    let name = read_string(data, &mut offset)?;
+    let symbol = read_string(data, &mut offset)?;
+    let uri = read_string(data, &mut offset)?;
+    Some(TokenMetadata { mint, update_authority, name, symbol, uri })
+}
+
+fn read_pubkey(data: &[u8], offset: &mut usize) -> Option<Pubkey> {
+    let bytes: [u8; 32] = data.get(*offset..*offset + 32)?.try_into().ok()?;
+    *offset += 32;
+    Some(Pubkey::new_from_array(bytes))
+}
+
+fn read_string(data: &[u8], offset: &mut usize) -> Option<String> {
+    let len = u32::from_le_bytes(data.get(*offset..*offset + 4)?.try_into().ok()?) as usize;
+    let bytes = data.get(*offset + 4..*offset + 4 + len)?;
+    *offset += 4 + len;
+    Some(String::from_utf8_lossy(bytes).trim_end_matches('\0').to_string())
+}
+
+/// 按 mint 缓存的元数据解析器，可在多个任务间共享
+#[derive(Default)]
+pub struct MetadataResolver {
+    cache: DashMap<Pubkey, Arc<TokenMetadata>>,
+    rpc: Option<Arc<RpcClient>>,
+}
+
+impl MetadataResolver {
+    /// 只使用账户流和发行事件，不查询 RPC
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 缓存未命中时通过 `rpc` 查询
+    pub fn with_rpc(rpc: Arc<RpcClient>) -> Self {
+        Self { cache: DashMap::new(), rpc: Some(rpc) }
+    }
+
+    /// 处理账户流中的元数据账户更新，非元数据账户返回 None
+    pub fn observe_account(&self, account: &AccountData) -> Option<Arc<TokenMetadata>> {
+        if account.owner != METADATA_PROGRAM_ID {
+            return None;
+        }
+        let metadata = Arc::new(parse_metadata_account(&account.data)?);
+        self.cache.insert(metadata.mint, Arc::clone(&metadata));
+        Some(metadata)
+    }
+
+    /// 从发行事件学习元数据，已缓存的链上数据不会被覆盖
+    pub fn observe_event(&self, event: &DexEvent) {
+        let DexEvent::PumpFunCreate(e) = event else { return };
+        self.cache.entry(e.mint).or_insert_with(|| {
+            Arc::new(TokenMetadata {
+                mint: e.mint,
+                update_authority: Pubkey::default(),
+                name: e.name.clone(),
+                symbol: e.symbol.clone(),
+                uri: e.uri.clone(),
+            })
+        });
+    }
+
+    pub fn get(&self, mint: &Pubkey) -> Option<Arc<TokenMetadata>> {
+        self.cache.get(mint).map(|m| Arc::clone(&m))
+    }
+
+    /// 事件主要代币（`DexEvent::mint`）的已缓存元数据
+    pub fn for_event(&self, event: &DexEvent) -> Option<Arc<TokenMetadata>> {
+        self.get(&event.mint()?)
+    }
+
+    /// 查询 mint 的元数据：先查缓存，未命中且配置了 RPC 时按 PDA 获取并缓存
+    ///
+    /// 元数据账户不存在或未配置 RPC 时返回 `Ok(None)`。
+    pub async fn resolve(&self, mint: &Pubkey) -> anyhow::Result<Option<Arc<TokenMetadata>>> {
+        if let Some(metadata) = self.get(mint) {
+            return Ok(Some(metadata));
+        }
+        let Some(rpc) = &self.rpc else { return Ok(None) };
+        let pda = metadata_pda(mint);
+        let account = rpc.get_account_with_commitment(&pda, rpc.commitment()).await?.value;
+        let Some(account) = account.filter(|a| a.owner == METADATA_PROGRAM_ID) else {
+            return Ok(None);
+        };
+        let Some(metadata) = parse_metadata_account(&account.data) else { return Ok(None) };
+        let metadata = Arc::new(metadata);
+        self.cache.insert(*mint, Arc::clone(&metadata));
+        Ok(Some(metadata))
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+impl std::fmt::Debug for MetadataResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MetadataResolver")
+            .field("cached", &self.cache.len())
+            .field("rpc", &self.rpc.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::{EventMetadata, PumpFunTradeEvent};
+
+    fn borsh_string(out: &mut Vec<u8>, s: &str, padded: usize) {
+        out.extend_from_slice(&(padded as u32).to_le_bytes());
+        out.extend_from_slice(s.as_bytes());
+        out.resize(out.len() + padded - s.len(), 0);
+    }
+
+    #[test]
+    fn test_metadata_from_account_stream() {
+        let (mint, authority) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut data = vec![KEY_METADATA_V1];
+        data.extend_from_slice(authority.as_ref());
+        data.extend_from_slice(mint.as_ref());
+        borsh_string(&mut data, "Test Token", 32);
+        borsh_string(&mut data, "TEST", 10);
+        borsh_string(&mut data, "https://example.com/t.json", 200);
+        data.extend_from_slice(&[0u8; 40]);
+
+        let resolver = MetadataResolver::new();
+        let account = AccountData {
+            pubkey: metadata_pda(&mint),
+            executable: false,
+            lamports: 0,
+            owner: METADATA_PROGRAM_ID,
+            rent_epoch: 0,
+            data: data.clone(),
+        };
+        let metadata = resolver.observe_account(&account).unwrap();
+        assert_eq!((metadata.mint, metadata.update_authority), (mint, authority));
+        assert_eq!((metadata.name.as_str(), metadata.symbol.as_str()), ("Test Token", "TEST"));
+        assert_eq!(metadata.uri, "https://example.com/t.json");
+
+        let trade = DexEvent::PumpFunTrade(PumpFunTradeEvent {
+            metadata: EventMetadata::default(),
+            mint,
+            ..Default::default()
+        });
+        assert_eq!(resolver.for_event(&trade), Some(metadata));
+
+        assert!(parse_metadata_account(&data[..80]).is_none());
+        let other = AccountData { owner: Pubkey::new_unique(), ..account };
+        assert!(resolver.observe_account(&other).is_none());
+    }
+}
@@ -0,0 +1,221 @@
+//! 发行事件的 URI 元数据抓取（feature `uri-fetcher`）
+//!
+//! `PumpFunCreate` / `BonkPoolCreate` 事件提交给 [`UriFetcher`] 后，后台任务按限速抓取 URI 指向的 JSON
+//! （`ipfs://` / `ar://` 地址经网关转换），解析图片和社交链接后输出 `DexEvent::TokenMetadataResolved`，
+//! 供按是否带社交链接过滤新币的策略使用。抓取或解析失败只记录日志，不输出事件。
+
+use crate::core::events::{EventMetadata, TokenMetadataResolvedEvent};
+use crate::DexEvent;
+use serde_json::Value;
+use solana_sdk::pubkey::Pubkey;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, Semaphore};
+use tokio::time::MissedTickBehavior;
+
+#[derive(Debug, Clone)]
+pub struct UriFetcherConfig {
+    /// 每秒最多发起的请求数
+    pub requests_per_sec: u32,
+    /// 同时进行的最大请求数
+    pub max_concurrency: usize,
+    /// 单个请求超时（毫秒）
+    pub timeout_ms: u64,
+    /// `ipfs://` 地址使用的网关前缀
+    pub ipfs_gateway: String,
+    /// `ar://` 地址使用的网关前缀
+    pub arweave_gateway: String,
+    /// 待抓取队列和结果队列的容量，待抓取队列满时丢弃新提交
+    pub queue_capacity: usize,
+}
+
+impl Default for UriFetcherConfig {
+    fn default() -> Self {
+        Self {
+            requests_per_sec: 10,
+            max_concurrency: 8,
+            timeout_ms: 5_000,
+            ipfs_gateway: "https://ipfs.io/ipfs/".to_string(),
+            arweave_gateway: "https://arweave.net/".to_string(),
+            queue_capacity: 1024,
+        }
+    }
+}
+
+impl UriFetcherConfig {
+    /// 把 `ipfs://` / `ar://` 地址转换为网关 URL，其他地址原样返回
+    pub fn gateway_url(&self, uri: &str) -> String {
+        let uri = uri.trim();
+        if let Some(cid) = uri.strip_prefix("ipfs://") {
+            format!("{}{}", self.ipfs_gateway, cid.trim_start_matches("ipfs/"))
+        } else if let Some(id) = uri.strip_prefix("ar://") {
+            format!("{}{}", self.arweave_gateway, id)
+        } else {
+            uri.to_string()
+        }
+    }
+}
+
+/// 待抓取的发行事件
+#[derive(Debug)]
+struct FetchJob {
+    metadata: EventMetadata,
+    mint: Pubkey,
+    uri: String,
+    name: String,
+    symbol: String,
+}
+
+impl FetchJob {
+    fn from_event(event: &DexEvent) -> Option<Self> {
+        let job = match event {
+            DexEvent::PumpFunCreate(e) => FetchJob {
+                metadata: e.metadata,
+                mint: e.mint,
+                uri: e.uri.clone(),
+                name: e.name.clone(),
+                symbol: e.symbol.clone(),
+            },
+            DexEvent::BonkPoolCreate(e) => FetchJob {
+                metadata: e.metadata,
+                mint: Pubkey::default(),
+                uri: e.base_mint_param.uri.clone(),
+                name: e.base_mint_param.name.clone(),
+                symbol: e.base_mint_param.symbol.clone(),
+            },
+            _ => return None,
+        };
+        (!job.uri.trim().is_empty()).then_some(job)
+    }
+
+    /// 用抓取到的 JSON 生成结果事件，名称 / 符号优先使用链上值
+    fn resolve(self, json: &Value) -> TokenMetadataResolvedEvent {
+        let or_json = |value: String, key| {
+            if value.is_empty() {
+                json_field(json, key).unwrap_or_default()
+            } else {
+                value
+            }
+        };
+        TokenMetadataResolvedEvent {
+            metadata: self.metadata,
+            mint: self.mint,
+            name: or_json(self.name, "name"),
+            symbol: or_json(self.symbol, "symbol"),
+            uri: self.uri,
+            description: json_field(json, "description"),
+            image: json_field(json, "image"),
+            twitter: json_field(json, "twitter"),
+            telegram: json_field(json, "telegram"),
+            website: json_field(json, "website"),
+        }
+    }
+}
+
+/// 读取非空字符串字段，顶层没有时查找 `extensions` 下的同名字段
+fn json_field(json: &Value, key: &str) -> Option<String> {
+    [json.get(key), json.get("extensions").and_then(|ext| ext.get(key))]
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .map(str::trim)
+        .find(|s| !s.is_empty())
+        .map(str::to_string)
+}
+
+/// 发行事件 URI 抓取器的提交句柄，可克隆后在多处提交
+#[derive(Debug, Clone)]
+pub struct UriFetcher {
+    jobs: mpsc::Sender<FetchJob>,
+}
+
+impl UriFetcher {
+    /// 启动后台 worker（需在 tokio runtime 中调用），返回提交句柄和结果事件接收端
+    ///
+    /// 所有句柄被丢弃后 worker 在完成已提交的任务后退出。
+    pub fn spawn(config: UriFetcherConfig) -> reqwest::Result<(Self, mpsc::Receiver<DexEvent>)> {
+        let client =
+            reqwest::Client::builder().timeout(Duration::from_millis(config.timeout_ms)).build()?;
+        let capacity = config.queue_capacity.max(1);
+        let (jobs, job_rx) = mpsc::channel(capacity);
+        let (results, result_rx) = mpsc::channel(capacity);
+        tokio::spawn(run_worker(Arc::new(config), client, job_rx, results));
+        Ok((Self { jobs }, result_rx))
+    }
+
+    /// 提交发行事件；不是发行事件、URI 为空或队列已满时返回 false
+    pub fn submit(&self, event: &DexEvent) -> bool {
+        let Some(job) = FetchJob::from_event(event) else { return false };
+        self.jobs.try_send(job).is_ok()
+    }
+}
+
+async fn run_worker(
+    config: Arc<UriFetcherConfig>,
+    client: reqwest::Client,
+    mut jobs: mpsc::Receiver<FetchJob>,
+    results: mpsc::Sender<DexEvent>,
+) {
+    let mut ticker = tokio::time::interval(Duration::from_secs(1) / config.requests_per_sec.max(1));
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let permits = Arc::new(Semaphore::new(config.max_concurrency.max(1)));
+
+    while let Some(job) = jobs.recv().await {
+        ticker.tick().await;
+        let Ok(permit) = Arc::clone(&permits).acquire_owned().await else { break };
+        let (config, client, results) = (Arc::clone(&config), client.clone(), results.clone());
+        tokio::spawn(async move {
+            let _permit = permit;
+            let url = config.gateway_url(&job.uri);
+            match fetch_json(&client, &url).await {
+                Ok(json) => {
+                    let event = DexEvent::TokenMetadataResolved(job.resolve(&json));
+                    let _ = results.send(event).await;
+                }
+                Err(e) => log::debug!("failed to fetch token uri {}: {}", url, e),
+            }
+        });
+    }
+}
+
+async fn fetch_json(client: &reqwest::Client, url: &str) -> reqwest::Result<Value> {
+    client.get(url).send().await?.error_for_status()?.json().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::PumpFunCreateTokenEvent;
+
+    #[test]
+    fn test_resolve_launch_uri() {
+        let config = UriFetcherConfig::default();
+        assert_eq!(config.gateway_url("ipfs://Qm123"), "https://ipfs.io/ipfs/Qm123");
+        assert_eq!(config.gateway_url("ipfs://ipfs/Qm123"), "https://ipfs.io/ipfs/Qm123");
+        assert_eq!(config.gateway_url("ar://abc"), "https://arweave.net/abc");
+        assert_eq!(config.gateway_url("https://x.io/a.json"), "https://x.io/a.json");
+
+        let mint = Pubkey::new_unique();
+        let create = DexEvent::PumpFunCreate(PumpFunCreateTokenEvent {
+            mint,
+            name: "Test".to_string(),
+            uri: "ipfs://Qm123".to_string(),
+            ..Default::default()
+        });
+        let json = serde_json::json!({
+            "name": "Ignored",
+            "symbol": "TST",
+            "image": "https://ipfs.io/ipfs/QmImage",
+            "twitter": "",
+            "extensions": { "twitter": "https://x.com/test" },
+        });
+        let event = FetchJob::from_event(&create).unwrap().resolve(&json);
+        assert_eq!((event.mint, event.name.as_str(), event.symbol.as_str()), (mint, "Test", "TST"));
+        assert_eq!(event.image.as_deref(), Some("https://ipfs.io/ipfs/QmImage"));
+        assert_eq!(event.twitter.as_deref(), Some("https://x.com/test"));
+        assert!(event.has_socials() && event.telegram.is_none());
+
+        let no_uri = DexEvent::PumpFunCreate(PumpFunCreateTokenEvent::default());
+        assert!(FetchJob::from_event(&no_uri).is_none());
+    }
+}
@@ -3,22 +3,40 @@
 //! 提供独立的 RPC 交易解析功能，不依赖 gRPC streaming
 //! 可以用于测试验证和离线分析
 
+use crate::core::cache::TransactionKeys;
 use crate::core::events::DexEvent;
+use crate::grpc::checkpoint::Checkpoint;
 use crate::grpc::instruction_parser::parse_instructions_enhanced;
 use crate::grpc::types::EventTypeFilter;
-use crate::instr::read_pubkey_fast;
-use base64::{engine::general_purpose, Engine as _};
-use solana_client::rpc_client::RpcClient;
+use serde::{Deserialize, Serialize};
+use solana_client::client_error::{ClientError, ClientErrorKind};
+use solana_client::rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient};
 use solana_client::rpc_config::RpcTransactionConfig;
+use solana_client::rpc_custom_error::{
+    JSON_RPC_SERVER_ERROR_BLOCK_NOT_AVAILABLE,
+    JSON_RPC_SERVER_ERROR_BLOCK_STATUS_NOT_AVAILABLE_YET,
+    JSON_RPC_SERVER_ERROR_LONG_TERM_STORAGE_UNREACHABLE,
+    JSON_RPC_SERVER_ERROR_MIN_CONTEXT_SLOT_NOT_REACHED, JSON_RPC_SERVER_ERROR_NODE_UNHEALTHY,
+    JSON_RPC_SERVER_ERROR_UNSUPPORTED_TRANSACTION_VERSION,
+};
+use solana_client::rpc_request::RpcError;
+use solana_sdk::message::VersionedMessage;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Signature;
+use solana_sdk::transaction::VersionedTransaction;
+use solana_transaction_status::parse_accounts::{ParsedAccount, ParsedAccountSource};
 use solana_transaction_status::{
-    EncodedConfirmedTransactionWithStatusMeta, EncodedTransaction, UiTransactionEncoding,
+    EncodedConfirmedTransactionWithStatusMeta, EncodedTransaction,
+    EncodedTransactionWithStatusMeta, UiInstruction, UiMessage, UiParsedInstruction, UiTransaction,
+    UiTransactionEncoding,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
 use yellowstone_grpc_proto::prelude::{
     CompiledInstruction, InnerInstruction, InnerInstructions, Message, MessageAddressTableLookup,
-    MessageHeader, Transaction, TransactionStatusMeta,
+    MessageHeader, TokenBalance, Transaction, TransactionStatusMeta, UiTokenAmount,
 };
 
 /// Parse a transaction from RPC by signature
@@ -54,20 +72,370 @@ pub fn parse_transaction_from_rpc(
         max_supported_transaction_version: Some(0),
     };
 
-    let rpc_tx = rpc_client.get_transaction_with_config(signature, config).map_err(|e| {
-        let msg = e.to_string();
-        if msg.contains("429") || msg.contains("Too Many Requests") {
-            ParseError::RateLimited(msg)
-        } else {
-            ParseError::RpcError(msg)
+    let rpc_tx =
+        rpc_client.get_transaction_with_config(signature, config).map_err(map_client_error)?;
+
+    parse_rpc_transaction(&rpc_tx, filter)
+}
+
+/// Page size of `getSignaturesForAddress` (the RPC maximum)
+const BACKFILL_PAGE_LIMIT: usize = 1000;
+
+/// Backfill the events delivered after a stream checkpoint via RPC
+///
+/// Pages through `getSignaturesForAddress` for `address` (typically a program ID from the
+/// subscription filter) back to `checkpoint`, then fetches and parses every successful transaction,
+/// oldest first. Used when the gRPC server cannot resume `from_slot`
+/// (`ClientEvent::Resume { replayed: false, .. }`). With a default checkpoint signature (slot-batch
+/// delivery) the transactions of `checkpoint.slot` itself are included again.
+///
+/// Retryable errors (see [`ParseError::is_retryable`]) are retried with the default
+/// [`RetryPolicy`]; permanent errors abort the backfill immediately.
+pub fn backfill_since_checkpoint(
+    rpc_client: &RpcClient,
+    address: &Pubkey,
+    checkpoint: &Checkpoint,
+    filter: Option<&EventTypeFilter>,
+) -> Result<Vec<DexEvent>, ParseError> {
+    backfill_since_checkpoint_with_retry(
+        rpc_client,
+        address,
+        checkpoint,
+        filter,
+        &RetryPolicy::default(),
+    )
+}
+
+/// Same as [`backfill_since_checkpoint`] with an explicit retry policy for every RPC call
+pub fn backfill_since_checkpoint_with_retry(
+    rpc_client: &RpcClient,
+    address: &Pubkey,
+    checkpoint: &Checkpoint,
+    filter: Option<&EventTypeFilter>,
+    retry: &RetryPolicy,
+) -> Result<Vec<DexEvent>, ParseError> {
+    let until = (checkpoint.signature != Signature::default()).then_some(checkpoint.signature);
+    let range = SignatureRange { until, min_slot: checkpoint.slot, ..Default::default() };
+    let signatures = successful_signatures(rpc_client, address, &range, retry)?;
+    fetch_chronological(rpc_client, &signatures, filter, retry)
+}
+
+/// Range and size of a [`backfill_address_history`] request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryOptions {
+    /// Start from transactions older than this signature (exclusive), None for the newest
+    pub before: Option<Signature>,
+    /// Stop at this signature (exclusive)
+    pub until: Option<Signature>,
+    /// Stop at transactions older than this slot
+    pub min_slot: u64,
+    /// At most this many successful transactions, the most recent ones in the range
+    pub max_transactions: usize,
+    pub retry: RetryPolicy,
+}
+
+impl Default for HistoryOptions {
+    fn default() -> Self {
+        Self {
+            before: None,
+            until: None,
+            min_slot: 0,
+            max_transactions: 1000,
+            retry: RetryPolicy::default(),
+        }
+    }
+}
+
+/// Load the event history of a wallet or pool via RPC
+///
+/// Pages through `getSignaturesForAddress` for `address` within the range of `options`, then
+/// fetches and parses every successful transaction and returns their events oldest first.
+/// Failed transactions are skipped and do not count toward `max_transactions`. To continue
+/// further back, pass the oldest returned signature as `before` of the next call.
+///
+/// # Example
+/// ```no_run
+/// use solana_client::rpc_client::RpcClient;
+/// use sol_parser_sdk::{backfill_address_history, HistoryOptions};
+///
+/// let client = RpcClient::new("https://api.mainnet-beta.solana.com".to_string());
+/// let wallet = "your-wallet-here".parse().unwrap();
+/// let options = HistoryOptions { max_transactions: 200, ..Default::default() };
+/// let events = backfill_address_history(&client, &wallet, &options, None).unwrap();
+/// ```
+pub fn backfill_address_history(
+    rpc_client: &RpcClient,
+    address: &Pubkey,
+    options: &HistoryOptions,
+    filter: Option<&EventTypeFilter>,
+) -> Result<Vec<DexEvent>, ParseError> {
+    let range = SignatureRange {
+        before: options.before,
+        until: options.until,
+        min_slot: options.min_slot,
+        max: options.max_transactions,
+        ..Default::default()
+    };
+    let signatures = successful_signatures(rpc_client, address, &range, &options.retry)?;
+    fetch_chronological(rpc_client, &signatures, filter, &options.retry)
+}
+
+/// Slot range and concurrency of a [`load_pool_trades`] request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoolTradeOptions {
+    /// First slot to load (inclusive)
+    pub from_slot: u64,
+    /// Last slot to load (inclusive), transactions after it are paged past
+    pub to_slot: u64,
+    /// Transactions fetched in parallel, at least 1
+    pub concurrency: usize,
+    pub retry: RetryPolicy,
+}
+
+impl Default for PoolTradeOptions {
+    fn default() -> Self {
+        Self { from_slot: 0, to_slot: u64::MAX, concurrency: 8, retry: RetryPolicy::default() }
+    }
+}
+
+/// Load the historical trades of one pool via RPC, e.g. to seed candles of a newly tracked pool
+///
+/// Pages through `getSignaturesForAddress` for `pool` between `from_slot` and `to_slot`, fetches
+/// the successful transactions with up to `concurrency` parallel requests and returns the swaps
+/// on `pool` oldest first. Events whose [`DexEvent::event_id`] is in `seen` (already delivered by
+/// the stream) are dropped, as are duplicates within the result.
+///
+/// # Example
+/// ```no_run
+/// use solana_client::rpc_client::RpcClient;
+/// use sol_parser_sdk::{load_pool_trades, PoolTradeOptions};
+/// use std::collections::HashSet;
+///
+/// let client = RpcClient::new("https://api.mainnet-beta.solana.com".to_string());
+/// let pool = "your-pool-here".parse().unwrap();
+/// let options = PoolTradeOptions { from_slot: 300_000_000, ..Default::default() };
+/// let trades = load_pool_trades(&client, &pool, &options, &HashSet::new()).unwrap();
+/// ```
+pub fn load_pool_trades(
+    rpc_client: &RpcClient,
+    pool: &Pubkey,
+    options: &PoolTradeOptions,
+    seen: &HashSet<u128>,
+) -> Result<Vec<DexEvent>, ParseError> {
+    let range = SignatureRange {
+        min_slot: options.from_slot,
+        max_slot: options.to_slot,
+        ..Default::default()
+    };
+    let signatures = successful_signatures(rpc_client, pool, &range, &options.retry)?;
+    let transactions =
+        fetch_concurrent(rpc_client, &signatures, options.concurrency, &options.retry)?;
+
+    let mut ids = HashSet::new();
+    Ok(transactions
+        .into_iter()
+        .rev()
+        .flatten()
+        .filter(|event| event.is_swap() && event.pool() == Some(*pool))
+        .filter(|event| {
+            let id = event.event_id();
+            !seen.contains(&id) && ids.insert(id)
+        })
+        .collect())
+}
+
+/// Fetch and parse `signatures` with up to `concurrency` threads, events of each transaction in
+/// the order of `signatures`
+fn fetch_concurrent(
+    rpc_client: &RpcClient,
+    signatures: &[Signature],
+    concurrency: usize,
+    retry: &RetryPolicy,
+) -> Result<Vec<Vec<DexEvent>>, ParseError> {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(vec![Vec::new(); signatures.len()]);
+    let fetch = || -> Result<(), ParseError> {
+        loop {
+            let i = next.fetch_add(1, Ordering::Relaxed);
+            let Some(signature) = signatures.get(i) else { return Ok(()) };
+            // Stop the other workers on a permanent error
+            let events = retry
+                .run(|| parse_transaction_from_rpc(rpc_client, signature, None))
+                .inspect_err(|_| next.store(signatures.len(), Ordering::Relaxed))?;
+            results.lock().unwrap_or_else(|e| e.into_inner())[i] = events;
         }
+    };
+    std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..concurrency.clamp(1, signatures.len().max(1)))
+            .map(|_| scope.spawn(fetch))
+            .collect();
+        workers
+            .into_iter()
+            .try_for_each(|worker| worker.join().unwrap_or_else(|e| std::panic::resume_unwind(e)))
     })?;
+    Ok(results.into_inner().unwrap_or_else(|e| e.into_inner()))
+}
 
-    parse_rpc_transaction(&rpc_tx, filter)
+/// Range of a `getSignaturesForAddress` paging
+struct SignatureRange {
+    /// Start from transactions older than this signature (exclusive)
+    before: Option<Signature>,
+    /// Stop at this signature (exclusive)
+    until: Option<Signature>,
+    /// Stop at transactions older than this slot
+    min_slot: u64,
+    /// Skip (but page past) transactions newer than this slot
+    max_slot: u64,
+    /// At most this many signatures
+    max: usize,
+}
+
+impl Default for SignatureRange {
+    fn default() -> Self {
+        Self { before: None, until: None, min_slot: 0, max_slot: u64::MAX, max: usize::MAX }
+    }
+}
+
+/// Signatures of successful transactions touching `address`, newest first
+///
+/// Pages `getSignaturesForAddress` backwards from `range.before` until `range.until`, a transaction
+/// older than `range.min_slot`, `range.max` signatures or the end of history.
+fn successful_signatures(
+    rpc_client: &RpcClient,
+    address: &Pubkey,
+    range: &SignatureRange,
+    retry: &RetryPolicy,
+) -> Result<Vec<Signature>, ParseError> {
+    let mut before = range.before;
+    let mut signatures = Vec::new();
+    while signatures.len() < range.max {
+        let page = retry.run(|| {
+            let config = GetConfirmedSignaturesForAddress2Config {
+                before,
+                until: range.until,
+                limit: Some(BACKFILL_PAGE_LIMIT),
+                commitment: None,
+            };
+            rpc_client
+                .get_signatures_for_address_with_config(address, config)
+                .map_err(map_client_error)
+        })?;
+        let full_page = page.len() == BACKFILL_PAGE_LIMIT;
+        for status in page {
+            if status.slot < range.min_slot || signatures.len() == range.max {
+                return Ok(signatures);
+            }
+            let signature: Signature = status
+                .signature
+                .parse()
+                .map_err(|e| ParseError::ConversionError(format!("invalid signature: {}", e)))?;
+            before = Some(signature);
+            if status.err.is_none() && status.slot <= range.max_slot {
+                signatures.push(signature);
+            }
+        }
+        if !full_page {
+            break;
+        }
+    }
+    Ok(signatures)
+}
+
+/// Fetch and parse `signatures` (newest first), returning events oldest first
+fn fetch_chronological(
+    rpc_client: &RpcClient,
+    signatures: &[Signature],
+    filter: Option<&EventTypeFilter>,
+    retry: &RetryPolicy,
+) -> Result<Vec<DexEvent>, ParseError> {
+    let mut events = Vec::new();
+    for signature in signatures.iter().rev() {
+        events.extend(retry.run(|| parse_transaction_from_rpc(rpc_client, signature, filter))?);
+    }
+    Ok(events)
+}
+
+/// Retry policy for retryable [`ParseError`]s, with exponential backoff
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt, 0 disables retrying
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled on every further retry
+    pub retry_delay_ms: u64,
+    /// Upper bound of the delay between retries
+    pub max_delay_ms: u64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self { max_retries: 5, retry_delay_ms: 500, max_delay_ms: 8_000 }
+    }
+}
+
+impl RetryPolicy {
+    /// Never retry
+    pub const NONE: Self = Self { max_retries: 0, retry_delay_ms: 0, max_delay_ms: 0 };
+
+    /// Delay before retry number `retry` (starting at 0)
+    pub fn delay(&self, retry: u32) -> Duration {
+        let delay = self.retry_delay_ms.saturating_mul(1u64 << retry.min(32));
+        Duration::from_millis(delay.min(self.max_delay_ms))
+    }
+
+    /// Call `f` until it succeeds, fails with a permanent error or runs out of retries
+    pub fn run<T>(&self, mut f: impl FnMut() -> Result<T, ParseError>) -> Result<T, ParseError> {
+        let mut retry = 0;
+        loop {
+            match f() {
+                Err(e) if e.is_retryable() && retry < self.max_retries => {
+                    log::debug!("retryable RPC error (retry {}): {}", retry + 1, e);
+                    std::thread::sleep(self.delay(retry));
+                    retry += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// JSON-RPC server errors for data the node does not have yet
+const NOT_AVAILABLE_CODES: [i64; 5] = [
+    JSON_RPC_SERVER_ERROR_BLOCK_NOT_AVAILABLE,
+    JSON_RPC_SERVER_ERROR_NODE_UNHEALTHY,
+    JSON_RPC_SERVER_ERROR_BLOCK_STATUS_NOT_AVAILABLE_YET,
+    JSON_RPC_SERVER_ERROR_MIN_CONTEXT_SLOT_NOT_REACHED,
+    JSON_RPC_SERVER_ERROR_LONG_TERM_STORAGE_UNREACHABLE,
+];
+
+fn map_client_error(e: ClientError) -> ParseError {
+    let msg = e.to_string();
+    if msg.contains("429") || msg.contains("Too Many Requests") {
+        return ParseError::RateLimited(msg);
+    }
+    match e.kind() {
+        ClientErrorKind::Io(_) => ParseError::Timeout(msg),
+        ClientErrorKind::Reqwest(e) if e.is_timeout() || e.is_connect() => ParseError::Timeout(msg),
+        ClientErrorKind::RpcError(RpcError::RpcResponseError { code, .. }) => match *code {
+            code if NOT_AVAILABLE_CODES.contains(&code) => ParseError::NotAvailable(msg),
+            JSON_RPC_SERVER_ERROR_UNSUPPORTED_TRANSACTION_VERSION => {
+                ParseError::UnsupportedEncoding(msg)
+            }
+            _ => ParseError::RpcError(msg),
+        },
+        // getTransaction returns null for signatures not (yet) visible at the commitment level
+        ClientErrorKind::SerdeJson(_) if msg.contains("invalid type: null") => {
+            ParseError::NotAvailable(msg)
+        }
+        _ => ParseError::RpcError(msg),
+    }
 }
 
 /// Parse a RPC transaction structure
 ///
+/// Accepts every `getTransaction` encoding: `base64`, `base58` / `binary`, `json` and
+/// `jsonParsed`. With `jsonParsed`, instructions the node fully parsed (system, SPL token, ...)
+/// carry no raw data and produce no instruction events; DEX program instructions are unaffected.
+///
 /// # Arguments
 /// * `rpc_tx` - RPC transaction to parse
 /// * `filter` - Optional event type filter
@@ -85,20 +453,77 @@ pub fn parse_transaction_from_rpc(
 pub fn parse_rpc_transaction(
     rpc_tx: &EncodedConfirmedTransactionWithStatusMeta,
     filter: Option<&EventTypeFilter>,
+) -> Result<Vec<DexEvent>, ParseError> {
+    parse_encoded_transaction(&rpc_tx.transaction, rpc_tx.slot, rpc_tx.block_time, 0, filter)
+}
+
+/// Parse an encoded transaction at a known position in its block
+///
+/// Shared by [`parse_rpc_transaction`] (tx_index = 0) and block parsing (real tx_index).
+pub(crate) fn parse_encoded_transaction(
+    tx: &EncodedTransactionWithStatusMeta,
+    slot: u64,
+    block_time: Option<i64>,
+    tx_index: u64,
+    filter: Option<&EventTypeFilter>,
 ) -> Result<Vec<DexEvent>, ParseError> {
     // Convert RPC format to gRPC format
-    let (grpc_meta, grpc_tx) = convert_rpc_to_grpc(rpc_tx)?;
+    let (grpc_meta, grpc_tx) = convert_encoded_to_grpc(tx)?;
+    let signature = extract_signature(tx)?;
+    let block_time_us = block_time.map(|t| t * 1_000_000);
+    Ok(parse_converted(grpc_meta, grpc_tx, signature, slot, block_time_us, tx_index, filter))
+}
+
+/// Parse a transaction given as native solana-sdk / solana-transaction-status types
+///
+/// For validator sidecars (Geyser plugins, banking-stage hooks) that hold the transaction and its
+/// status meta directly, without going through gRPC or RPC JSON. Events carry `slot` as given,
+/// `tx_index` 0 and no block time. A failed transaction (`meta.status` is `Err`) yields no events.
+///
+/// # Example
+/// ```no_run
+/// use sol_parser_sdk::parse_versioned_transaction;
+/// use solana_sdk::transaction::VersionedTransaction;
+/// use solana_transaction_status::TransactionStatusMeta;
+///
+/// fn on_transaction(tx: &VersionedTransaction, meta: &TransactionStatusMeta, slot: u64) {
+///     let events = parse_versioned_transaction(tx, meta, slot, None).unwrap();
+/// }
+/// ```
+pub fn parse_versioned_transaction(
+    tx: &VersionedTransaction,
+    meta: &solana_transaction_status::TransactionStatusMeta,
+    slot: u64,
+    filter: Option<&EventTypeFilter>,
+) -> Result<Vec<DexEvent>, ParseError> {
+    if meta.status.is_err() {
+        return Ok(Vec::new());
+    }
+    let signature =
+        *tx.signatures.first().ok_or_else(|| ParseError::MissingField("signatures".to_string()))?;
+    let grpc_meta = convert_status_meta(meta);
+    let grpc_tx = convert_versioned_transaction(tx)?;
+    Ok(parse_converted(grpc_meta, grpc_tx, signature, slot, None, 0, filter))
+}
 
+/// Parse a transaction already converted to gRPC format
+fn parse_converted(
+    grpc_meta: TransactionStatusMeta,
+    grpc_tx: Transaction,
+    signature: Signature,
+    slot: u64,
+    block_time_us: Option<i64>,
+    tx_index: u64,
+    filter: Option<&EventTypeFilter>,
+) -> Vec<DexEvent> {
     // Extract metadata
-    let signature = extract_signature(rpc_tx)?;
-    let slot = rpc_tx.slot;
-    let block_time_us = rpc_tx.block_time.map(|t| t * 1_000_000);
     let grpc_recv_us =
         std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_micros()
             as i64;
 
     // Wrap grpc_tx in Option for reuse
     let grpc_tx_opt = Some(grpc_tx);
+    let keys = TransactionKeys::from_grpc(&grpc_meta, &grpc_tx_opt);
 
     // Build program_invokes HashMap for account filling
     // Use string keys to match gRPC parsing logic
@@ -106,23 +531,9 @@ pub fn parse_rpc_transaction(
 
     if let Some(ref tx) = grpc_tx_opt {
         if let Some(ref msg) = tx.message {
-            // Build account key lookup
-            let keys_len = msg.account_keys.len();
-            let writable_len = grpc_meta.loaded_writable_addresses.len();
-            let get_key = |i: usize| -> Option<&Vec<u8>> {
-                if i < keys_len {
-                    msg.account_keys.get(i)
-                } else if i < keys_len + writable_len {
-                    grpc_meta.loaded_writable_addresses.get(i - keys_len)
-                } else {
-                    grpc_meta.loaded_readonly_addresses.get(i - keys_len - writable_len)
-                }
-            };
-
             // Record outer instructions
             for (i, ix) in msg.instructions.iter().enumerate() {
-                let pid = get_key(ix.program_id_index as usize)
-                    .map_or(Pubkey::default(), |k| read_pubkey_fast(k));
+                let pid = keys.get(ix.program_id_index as usize).copied().unwrap_or_default();
                 let pid_str = pid.to_string();
                 let pid_static: &'static str = pid_str.leak();
                 program_invokes.entry(pid_static).or_default().push((i as i32, -1));
@@ -132,8 +543,8 @@ pub fn parse_rpc_transaction(
             for inner in &grpc_meta.inner_instructions {
                 let outer_idx = inner.index as usize;
                 for (j, inner_ix) in inner.instructions.iter().enumerate() {
-                    let pid = get_key(inner_ix.program_id_index as usize)
-                        .map_or(Pubkey::default(), |k| read_pubkey_fast(k));
+                    let pid =
+                        keys.get(inner_ix.program_id_index as usize).copied().unwrap_or_default();
                     let pid_str = pid.to_string();
                     let pid_static: &'static str = pid_str.leak();
                     program_invokes
@@ -149,9 +560,10 @@ pub fn parse_rpc_transaction(
     let mut events = parse_instructions_enhanced(
         &grpc_meta,
         &grpc_tx_opt,
+        &keys,
         signature,
         slot,
-        0, // tx_idx
+        tx_index,
         block_time_us,
         grpc_recv_us,
         filter,
@@ -159,15 +571,26 @@ pub fn parse_rpc_transaction(
 
     // Parse logs (for protocols like PumpFun that emit events in logs)
     let mut is_created_buy = false;
+    let mut cursor = crate::logs::optimized_matcher::InvokeCursor::new();
+
+    let metadata = crate::core::events::EventMetadata {
+        signature,
+        slot,
+        tx_index,
+        block_time_us: block_time_us.unwrap_or(0),
+        grpc_recv_us,
+        ..Default::default()
+    };
+    let mut ctx = crate::logs::LogDecodeContext::new();
 
-    for log in &grpc_meta.log_messages {
-        if let Some(mut event) = crate::logs::parse_log(
+    for (i, log) in grpc_meta.log_messages.iter().enumerate() {
+        cursor.observe(log);
+        if let Some(mut event) = crate::logs::parse_log_with_context(
+            &mut ctx,
+            i,
             log,
-            signature,
-            slot,
-            0, // tx_index
-            block_time_us,
-            grpc_recv_us,
+            metadata,
+            cursor.program(),
             filter,
             is_created_buy,
         ) {
@@ -175,12 +598,16 @@ pub fn parse_rpc_transaction(
             if matches!(event, DexEvent::PumpFunCreate(_)) {
                 is_created_buy = true;
             }
+            if let Some(m) = event.metadata_mut() {
+                (m.outer_index, m.inner_index) = cursor.position();
+            }
 
             // Fill account fields - use same function as gRPC parsing
             crate::core::account_dispatcher::fill_accounts_from_transaction_data(
                 &mut event,
                 &grpc_meta,
                 &grpc_tx_opt,
+                &keys,
                 &program_invokes,
             );
 
@@ -196,23 +623,153 @@ pub fn parse_rpc_transaction(
         }
     }
 
-    Ok(events)
+    events
+}
+
+/// The `value` of a Jito `simulateBundle` response
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleSimulation {
+    /// `"succeeded"` or `{ "failed": { ... } }`
+    #[serde(default)]
+    pub summary: serde_json::Value,
+    /// Results in bundle order; stops at the first failing transaction
+    #[serde(default)]
+    pub transaction_results: Vec<SimulatedTransactionResult>,
+}
+
+/// Simulation result of a single bundle transaction
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatedTransactionResult {
+    #[serde(default)]
+    pub err: Option<serde_json::Value>,
+    #[serde(default)]
+    pub logs: Option<Vec<String>>,
+    #[serde(default)]
+    pub units_consumed: Option<u64>,
+}
+
+/// Parse the predicted events of a Jito bundle simulation
+///
+/// `transactions` are the bundle transactions in submission order and `simulation` is the
+/// `value` of the `simulateBundle` response. Returns one event list per simulated transaction,
+/// with `tx_index` set to the position in the bundle; a bundle that failed part-way yields fewer
+/// lists than transactions, and a failed transaction yields an empty list.
+///
+/// Simulation results carry no inner instructions or loaded lookup-table addresses, so events
+/// come from logs and outer instructions, and account fields resolved through ALTs stay unset.
+pub fn parse_bundle_simulation(
+    transactions: &[VersionedTransaction],
+    simulation: &BundleSimulation,
+    slot: u64,
+    filter: Option<&EventTypeFilter>,
+) -> Result<Vec<Vec<DexEvent>>, ParseError> {
+    let mut bundle_events = Vec::with_capacity(simulation.transaction_results.len());
+    for (i, (tx, result)) in transactions.iter().zip(&simulation.transaction_results).enumerate() {
+        if result.err.is_some() {
+            bundle_events.push(Vec::new());
+            continue;
+        }
+        let signature = *tx
+            .signatures
+            .first()
+            .ok_or_else(|| ParseError::MissingField("signatures".to_string()))?;
+        let grpc_meta = TransactionStatusMeta {
+            log_messages: result.logs.clone().unwrap_or_default(),
+            log_messages_none: result.logs.is_none(),
+            compute_units_consumed: result.units_consumed,
+            ..Default::default()
+        };
+        let grpc_tx = convert_versioned_transaction(tx)?;
+        bundle_events
+            .push(parse_converted(grpc_meta, grpc_tx, signature, slot, None, i as u64, filter));
+    }
+    Ok(bundle_events)
+}
+
+/// Parse the logs of a `simulateTransaction` response
+///
+/// Simulation responses have no signature or slot, so events carry synthetic metadata: a default
+/// signature, `slot` as given (e.g. the response context slot, or 0), `tx_index` 0, and the parse
+/// time as `grpc_recv_us`. Instruction positions come from the invoke lines of the logs. Account
+/// fields that are only available from the transaction itself stay unset; use
+/// [`parse_bundle_simulation`] when the transaction is at hand.
+pub fn parse_simulation_logs(
+    logs: &[String],
+    slot: u64,
+    filter: Option<&EventTypeFilter>,
+) -> Vec<DexEvent> {
+    let metadata = crate::core::events::EventMetadata {
+        slot,
+        grpc_recv_us: crate::core::now_micros(),
+        ..Default::default()
+    };
+    let is_created_buy = crate::logs::optimized_matcher::detect_pumpfun_create(logs);
+    let mut cursor = crate::logs::optimized_matcher::InvokeCursor::new();
+    let mut events = Vec::new();
+
+    crate::logs::with_decode_context(|ctx| {
+        for (i, log) in logs.iter().enumerate() {
+            cursor.observe(log);
+            let Some(mut event) = crate::logs::parse_log_with_context(
+                ctx,
+                i,
+                log,
+                metadata,
+                cursor.program(),
+                filter,
+                is_created_buy,
+            ) else {
+                continue;
+            };
+            if let Some(m) = event.metadata_mut() {
+                (m.outer_index, m.inner_index) = cursor.position();
+            }
+            events.push(event);
+        }
+    });
+    events
 }
 
 /// Parse error types
+///
+/// [`Self::is_retryable`] separates transient failures (rate limits, timeouts, data the node does
+/// not have yet) from permanent ones that fail the same way on every attempt.
 #[derive(Debug)]
 pub enum ParseError {
+    /// Error returned by the RPC node (permanent)
     RpcError(String),
+    /// HTTP 429 (retryable)
     RateLimited(String),
+    /// Request timed out or the connection failed (retryable)
+    Timeout(String),
+    /// Block or transaction not available on the node yet (retryable)
+    NotAvailable(String),
+    /// Transaction encoding or version the parser cannot handle (permanent)
+    UnsupportedEncoding(String),
     ConversionError(String),
     MissingField(String),
 }
 
+impl ParseError {
+    /// Whether the same request may succeed when retried later
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            ParseError::RateLimited(_) | ParseError::Timeout(_) | ParseError::NotAvailable(_)
+        )
+    }
+}
+
 impl std::fmt::Display for ParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             ParseError::RpcError(msg) => write!(f, "RPC error: {}", msg),
             ParseError::RateLimited(msg) => write!(f, "Rate limited: {}", msg),
+            ParseError::Timeout(msg) => write!(f, "Timeout: {}", msg),
+            ParseError::NotAvailable(msg) => write!(f, "Not available: {}", msg),
+            ParseError::UnsupportedEncoding(msg) => write!(f, "Unsupported encoding: {}", msg),
             ParseError::ConversionError(msg) => write!(f, "Conversion error: {}", msg),
             ParseError::MissingField(msg) => write!(f, "Missing field: {}", msg),
         }
@@ -225,33 +782,42 @@ impl std::error::Error for ParseError {}
 // Internal conversion functions
 // ============================================================================
 
-fn extract_signature(
-    rpc_tx: &EncodedConfirmedTransactionWithStatusMeta,
-) -> Result<Signature, ParseError> {
-    let ui_tx = &rpc_tx.transaction.transaction;
-
-    match ui_tx {
-        EncodedTransaction::Binary(data, _encoding) => {
-            let bytes = general_purpose::STANDARD.decode(data).map_err(|e| {
-                ParseError::ConversionError(format!("Failed to decode base64: {}", e))
-            })?;
-
-            let versioned_tx: solana_sdk::transaction::VersionedTransaction =
-                bincode::deserialize(&bytes).map_err(|e| {
-                    ParseError::ConversionError(format!("Failed to deserialize transaction: {}", e))
-                })?;
+fn extract_signature(tx: &EncodedTransactionWithStatusMeta) -> Result<Signature, ParseError> {
+    let signature = match &tx.transaction {
+        EncodedTransaction::Json(ui_tx) => ui_tx
+            .signatures
+            .first()
+            .map(|s| s.parse().map_err(|e| ParseError::ConversionError(format!("{}", e))))
+            .transpose()?,
+        encoded => decode_binary_transaction(encoded)?.signatures.first().copied(),
+    };
+    signature.ok_or_else(|| ParseError::MissingField("signatures".to_string()))
+}
 
-            Ok(versioned_tx.signatures[0])
-        }
-        _ => Err(ParseError::ConversionError("Unsupported transaction encoding".to_string())),
+/// Decode a base64 / base58 (including legacy `binary`) encoded transaction
+fn decode_binary_transaction(
+    encoded: &EncodedTransaction,
+) -> Result<VersionedTransaction, ParseError> {
+    match encoded {
+        EncodedTransaction::Binary(..) | EncodedTransaction::LegacyBinary(_) => encoded
+            .decode()
+            .ok_or_else(|| ParseError::ConversionError("Failed to decode transaction".to_string())),
+        _ => Err(ParseError::UnsupportedEncoding(
+            "Expected a base64 or base58 encoded transaction".to_string(),
+        )),
     }
 }
 
 pub fn convert_rpc_to_grpc(
     rpc_tx: &EncodedConfirmedTransactionWithStatusMeta,
 ) -> Result<(TransactionStatusMeta, Transaction), ParseError> {
-    let rpc_meta = rpc_tx
-        .transaction
+    convert_encoded_to_grpc(&rpc_tx.transaction)
+}
+
+fn convert_encoded_to_grpc(
+    tx: &EncodedTransactionWithStatusMeta,
+) -> Result<(TransactionStatusMeta, Transaction), ParseError> {
+    let rpc_meta = tx
         .meta
         .as_ref()
         .ok_or_else(|| ParseError::MissingField("meta".to_string()))?;
@@ -326,79 +892,292 @@ pub fn convert_rpc_to_grpc(
         },
     };
 
-    // Convert inner instructions
-    let inner_instructions_opt: Option<Vec<_>> = rpc_meta.inner_instructions.clone().into();
-    if let Some(ref inner_instructions) = inner_instructions_opt {
-        for inner in inner_instructions {
-            let mut grpc_inner =
-                InnerInstructions { index: inner.index as u32, instructions: Vec::new() };
-
-            for ix in &inner.instructions {
-                if let solana_transaction_status::UiInstruction::Compiled(compiled) = ix {
-                    // Decode base58 data
-                    let data = bs58::decode(&compiled.data).into_vec().map_err(|e| {
-                        ParseError::ConversionError(format!(
-                            "Failed to decode instruction data: {}",
-                            e
-                        ))
-                    })?;
-
-                    grpc_inner.instructions.push(InnerInstruction {
-                        program_id_index: compiled.program_id_index as u32,
-                        accounts: compiled.accounts.clone(),
-                        data,
-                        stack_height: compiled.stack_height.map(|h| h as u32),
-                    });
+    // Convert transaction
+    let grpc_tx = match &tx.transaction {
+        EncodedTransaction::Json(ui_tx) => convert_ui_transaction(ui_tx)?,
+        encoded => convert_versioned_transaction(&decode_binary_transaction(encoded)?)?,
+    };
+
+    // jsonParsed omits the meta's loaded addresses; take them from the lookup-table keys instead
+    if let EncodedTransaction::Json(UiTransaction { message: UiMessage::Parsed(msg), .. }) =
+        &tx.transaction
+    {
+        if grpc_meta.loaded_writable_addresses.is_empty()
+            && grpc_meta.loaded_readonly_addresses.is_empty()
+        {
+            for key in &msg.account_keys {
+                if key.source != Some(ParsedAccountSource::LookupTable) {
+                    continue;
+                }
+                let bytes = key
+                    .pubkey
+                    .parse::<Pubkey>()
+                    .map_err(|e| ParseError::ConversionError(format!("invalid pubkey: {}", e)))?
+                    .to_bytes()
+                    .to_vec();
+                if key.writable {
+                    grpc_meta.loaded_writable_addresses.push(bytes);
+                } else {
+                    grpc_meta.loaded_readonly_addresses.push(bytes);
                 }
             }
-
-            grpc_meta.inner_instructions.push(grpc_inner);
         }
     }
 
-    // Convert transaction
-    let ui_tx = &rpc_tx.transaction.transaction;
-
-    let (message, signatures) = match ui_tx {
-        EncodedTransaction::Binary(data, _encoding) => {
-            // Decode base64
-            let bytes = general_purpose::STANDARD.decode(data).map_err(|e| {
-                ParseError::ConversionError(format!("Failed to decode base64: {}", e))
-            })?;
-
-            // Parse as versioned transaction
-            let versioned_tx: solana_sdk::transaction::VersionedTransaction =
-                bincode::deserialize(&bytes).map_err(|e| {
-                    ParseError::ConversionError(format!("Failed to deserialize transaction: {}", e))
-                })?;
-
-            let sigs: Vec<Vec<u8>> =
-                versioned_tx.signatures.iter().map(|s| s.as_ref().to_vec()).collect();
-
-            let message = match versioned_tx.message {
-                solana_sdk::message::VersionedMessage::Legacy(legacy_msg) => {
-                    convert_legacy_message(&legacy_msg)?
-                }
-                solana_sdk::message::VersionedMessage::V0(v0_msg) => convert_v0_message(&v0_msg)?,
-            };
+    // Convert inner instructions, resolving jsonParsed accounts against the full key list
+    let accounts = AccountIndex::new(&grpc_tx, &grpc_meta);
+    let inner_instructions_opt: Option<Vec<_>> = rpc_meta.inner_instructions.clone().into();
+    for inner in inner_instructions_opt.iter().flatten() {
+        let instructions = inner
+            .instructions
+            .iter()
+            .map(|ix| {
+                let (program_id_index, accounts, data, stack_height) = accounts.resolve(ix)?;
+                Ok(InnerInstruction { program_id_index, accounts, data, stack_height })
+            })
+            .collect::<Result<_, ParseError>>()?;
+        grpc_meta
+            .inner_instructions
+            .push(InnerInstructions { index: inner.index as u32, instructions });
+    }
 
-            (message, sigs)
+    Ok((grpc_meta, grpc_tx))
+}
+
+/// Account key → index over static keys followed by loaded writable and readonly addresses,
+/// the order instruction account indexes refer to
+struct AccountIndex(HashMap<String, u8>);
+
+impl AccountIndex {
+    fn new(tx: &Transaction, meta: &TransactionStatusMeta) -> Self {
+        let static_keys = tx.message.iter().flat_map(|m| &m.account_keys);
+        let loaded = meta.loaded_writable_addresses.iter().chain(&meta.loaded_readonly_addresses);
+        Self::from_keys(static_keys.chain(loaded).map(|key| bs58::encode(key).into_string()))
+    }
+
+    fn from_keys(keys: impl IntoIterator<Item = String>) -> Self {
+        let mut index = HashMap::new();
+        for (i, key) in keys.into_iter().enumerate() {
+            index.entry(key).or_insert(i as u8);
         }
-        EncodedTransaction::Json(_) => {
-            return Err(ParseError::ConversionError(
-                "JSON encoded transactions not supported yet".to_string(),
-            ));
+        Self(index)
+    }
+
+    fn get(&self, key: &str) -> Result<u8, ParseError> {
+        self.0
+            .get(key)
+            .copied()
+            .ok_or_else(|| ParseError::ConversionError(format!("unknown account key {}", key)))
+    }
+
+    /// `(program_id_index, accounts, data, stack_height)` of an RPC instruction
+    ///
+    /// Instructions the RPC node fully parsed (`jsonParsed` system / token programs) carry no raw
+    /// data; they keep their position with empty accounts and data.
+    #[allow(clippy::type_complexity)]
+    fn resolve(
+        &self,
+        ix: &UiInstruction,
+    ) -> Result<(u32, Vec<u8>, Vec<u8>, Option<u32>), ParseError> {
+        Ok(match ix {
+            UiInstruction::Compiled(ix) => (
+                ix.program_id_index as u32,
+                ix.accounts.clone(),
+                decode_instruction_data(&ix.data)?,
+                ix.stack_height,
+            ),
+            UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(ix)) => (
+                self.get(&ix.program_id)? as u32,
+                ix.accounts.iter().map(|key| self.get(key)).collect::<Result<_, _>>()?,
+                decode_instruction_data(&ix.data)?,
+                ix.stack_height,
+            ),
+            UiInstruction::Parsed(UiParsedInstruction::Parsed(ix)) => {
+                (self.get(&ix.program_id)? as u32, Vec::new(), Vec::new(), ix.stack_height)
+            }
+        })
+    }
+}
+
+fn decode_instruction_data(data: &str) -> Result<Vec<u8>, ParseError> {
+    bs58::decode(data).into_vec().map_err(|e| {
+        ParseError::ConversionError(format!("Failed to decode instruction data: {}", e))
+    })
+}
+
+/// Convert a `json` / `jsonParsed` encoded transaction to gRPC format
+///
+/// `jsonParsed` lists static and lookup-table keys together; only the static keys go into the
+/// message (loaded addresses come from the meta) and the header is rebuilt from the
+/// signer / writable flags.
+fn convert_ui_transaction(ui_tx: &UiTransaction) -> Result<Transaction, ParseError> {
+    let pubkey = |key: &str| {
+        key.parse::<Pubkey>()
+            .map(|k| k.to_bytes().to_vec())
+            .map_err(|e| ParseError::ConversionError(format!("invalid pubkey {}: {}", key, e)))
+    };
+    let signatures = ui_tx
+        .signatures
+        .iter()
+        .map(|s| {
+            s.parse::<Signature>()
+                .map(|s| s.as_ref().to_vec())
+                .map_err(|e| ParseError::ConversionError(format!("invalid signature: {}", e)))
+        })
+        .collect::<Result<_, _>>()?;
+
+    let (header, account_keys, recent_blockhash, lookups) = match &ui_tx.message {
+        UiMessage::Raw(msg) => (
+            MessageHeader {
+                num_required_signatures: msg.header.num_required_signatures as u32,
+                num_readonly_signed_accounts: msg.header.num_readonly_signed_accounts as u32,
+                num_readonly_unsigned_accounts: msg.header.num_readonly_unsigned_accounts as u32,
+            },
+            msg.account_keys.iter().map(|k| pubkey(k)).collect::<Result<Vec<_>, _>>()?,
+            &msg.recent_blockhash,
+            &msg.address_table_lookups,
+        ),
+        UiMessage::Parsed(msg) => {
+            let static_keys: Vec<_> = msg
+                .account_keys
+                .iter()
+                .filter(|k| k.source != Some(ParsedAccountSource::LookupTable))
+                .collect();
+            let count =
+                |f: fn(&ParsedAccount) -> bool| static_keys.iter().filter(|k| f(k)).count() as u32;
+            (
+                MessageHeader {
+                    num_required_signatures: count(|k| k.signer),
+                    num_readonly_signed_accounts: count(|k| k.signer && !k.writable),
+                    num_readonly_unsigned_accounts: count(|k| !k.signer && !k.writable),
+                },
+                static_keys.iter().map(|k| pubkey(&k.pubkey)).collect::<Result<Vec<_>, _>>()?,
+                &msg.recent_blockhash,
+                &msg.address_table_lookups,
+            )
         }
-        _ => {
-            return Err(ParseError::ConversionError(
-                "Unsupported transaction encoding".to_string(),
-            ));
+    };
+
+    let address_table_lookups = lookups
+        .iter()
+        .flatten()
+        .map(|lookup| {
+            Ok(MessageAddressTableLookup {
+                account_key: pubkey(&lookup.account_key)?,
+                writable_indexes: lookup.writable_indexes.clone(),
+                readonly_indexes: lookup.readonly_indexes.clone(),
+            })
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+    let instructions = match &ui_tx.message {
+        UiMessage::Raw(msg) => msg
+            .instructions
+            .iter()
+            .map(|ix| {
+                Ok(CompiledInstruction {
+                    program_id_index: ix.program_id_index as u32,
+                    accounts: ix.accounts.clone(),
+                    data: decode_instruction_data(&ix.data)?,
+                })
+            })
+            .collect::<Result<_, ParseError>>()?,
+        // jsonParsed lists lookup-table keys after the static keys, in the same order as the
+        // meta's loaded addresses, so indexes into this list match the gRPC key order
+        UiMessage::Parsed(msg) => {
+            let index = AccountIndex::from_keys(msg.account_keys.iter().map(|k| k.pubkey.clone()));
+            msg.instructions
+                .iter()
+                .map(|ix| {
+                    let (program_id_index, accounts, data, _) = index.resolve(ix)?;
+                    Ok(CompiledInstruction { program_id_index, accounts, data })
+                })
+                .collect::<Result<_, ParseError>>()?
         }
     };
 
-    let grpc_tx = Transaction { signatures, message: Some(message) };
+    let message = Message {
+        header: Some(header),
+        account_keys,
+        recent_blockhash: bs58::decode(recent_blockhash).into_vec().unwrap_or_default(),
+        instructions,
+        versioned: lookups.is_some(),
+        address_table_lookups,
+    };
+    Ok(Transaction { signatures, message: Some(message) })
+}
 
-    Ok((grpc_meta, grpc_tx))
+/// Convert a native status meta to gRPC format
+///
+/// The transaction error and rewards are not carried over; the parser does not read them.
+fn convert_status_meta(
+    meta: &solana_transaction_status::TransactionStatusMeta,
+) -> TransactionStatusMeta {
+    let token_balances =
+        |balances: &Option<Vec<solana_transaction_status::TransactionTokenBalance>>| {
+            balances
+                .iter()
+                .flatten()
+                .map(|b| TokenBalance {
+                    account_index: b.account_index as u32,
+                    mint: b.mint.clone(),
+                    ui_token_amount: Some(UiTokenAmount {
+                        ui_amount: b.ui_token_amount.ui_amount.unwrap_or_default(),
+                        decimals: b.ui_token_amount.decimals as u32,
+                        amount: b.ui_token_amount.amount.clone(),
+                        ui_amount_string: b.ui_token_amount.ui_amount_string.clone(),
+                    }),
+                    owner: b.owner.clone(),
+                    program_id: b.program_id.clone(),
+                })
+                .collect()
+        };
+    let pubkeys = |keys: &[Pubkey]| keys.iter().map(|k| k.to_bytes().to_vec()).collect();
+
+    TransactionStatusMeta {
+        err: None,
+        fee: meta.fee,
+        pre_balances: meta.pre_balances.clone(),
+        post_balances: meta.post_balances.clone(),
+        inner_instructions: meta
+            .inner_instructions
+            .iter()
+            .flatten()
+            .map(|inner| InnerInstructions {
+                index: inner.index as u32,
+                instructions: inner
+                    .instructions
+                    .iter()
+                    .map(|ix| InnerInstruction {
+                        program_id_index: ix.instruction.program_id_index as u32,
+                        accounts: ix.instruction.accounts.clone(),
+                        data: ix.instruction.data.clone(),
+                        stack_height: ix.stack_height,
+                    })
+                    .collect(),
+            })
+            .collect(),
+        inner_instructions_none: meta.inner_instructions.is_none(),
+        log_messages: meta.log_messages.clone().unwrap_or_default(),
+        log_messages_none: meta.log_messages.is_none(),
+        pre_token_balances: token_balances(&meta.pre_token_balances),
+        post_token_balances: token_balances(&meta.post_token_balances),
+        rewards: Vec::new(),
+        loaded_writable_addresses: pubkeys(&meta.loaded_addresses.writable),
+        loaded_readonly_addresses: pubkeys(&meta.loaded_addresses.readonly),
+        return_data: None,
+        return_data_none: meta.return_data.is_none(),
+        compute_units_consumed: meta.compute_units_consumed,
+        cost_units: meta.cost_units,
+    }
+}
+
+fn convert_versioned_transaction(tx: &VersionedTransaction) -> Result<Transaction, ParseError> {
+    let signatures = tx.signatures.iter().map(|s| s.as_ref().to_vec()).collect();
+    let message = match &tx.message {
+        VersionedMessage::Legacy(legacy_msg) => convert_legacy_message(legacy_msg)?,
+        VersionedMessage::V0(v0_msg) => convert_v0_message(v0_msg)?,
+    };
+    Ok(Transaction { signatures, message: Some(message) })
 }
 
 fn convert_legacy_message(
@@ -479,6 +1258,52 @@ mod tests {
         assert_eq!(err.to_string(), "RPC error: Network error");
     }
 
+    #[test]
+    fn test_retry_policy_respects_error_kind() {
+        let policy = RetryPolicy { max_retries: 3, retry_delay_ms: 1, max_delay_ms: 2 };
+        assert_eq!(policy.delay(0), Duration::from_millis(1));
+        assert_eq!(policy.delay(5), Duration::from_millis(2));
+
+        // 可重试错误重试到成功
+        let mut calls = 0;
+        let result = policy.run(|| {
+            calls += 1;
+            if calls < 3 {
+                Err(ParseError::NotAvailable("block".to_string()))
+            } else {
+                Ok(calls)
+            }
+        });
+        assert_eq!((result.unwrap(), calls), (3, 3));
+
+        // 永久错误不重试
+        let mut calls = 0;
+        let result: Result<(), _> = policy.run(|| {
+            calls += 1;
+            Err(ParseError::UnsupportedEncoding("json".to_string()))
+        });
+        assert!(!result.unwrap_err().is_retryable());
+        assert_eq!(calls, 1);
+
+        // 重试次数用尽后返回最后一次错误
+        let mut calls = 0;
+        let result: Result<(), _> = policy.run(|| {
+            calls += 1;
+            Err(ParseError::Timeout("timed out".to_string()))
+        });
+        assert!(matches!(result, Err(ParseError::Timeout(_))));
+        assert_eq!(calls, 4);
+
+        let not_found = ClientError::from(serde_json::from_str::<String>("null").unwrap_err());
+        assert!(matches!(map_client_error(not_found), ParseError::NotAvailable(_)));
+        let skipped = ClientError::from(RpcError::RpcResponseError {
+            code: solana_client::rpc_custom_error::JSON_RPC_SERVER_ERROR_SLOT_SKIPPED,
+            message: "Slot was skipped".to_string(),
+            data: solana_client::rpc_request::RpcResponseErrorData::Empty,
+        });
+        assert!(matches!(map_client_error(skipped), ParseError::RpcError(_)));
+    }
+
     #[test]
     fn test_error_mapping_logic() {
         let test_msgs = vec![
@@ -494,4 +1319,391 @@ mod tests {
             assert_eq!(is_rate_limited, should_be_rate_limited, "Failed for message: {}", msg);
         }
     }
+
+    #[test]
+    fn test_parse_bundle_simulation() {
+        use solana_sdk::message::compiled_instruction::CompiledInstruction as SdkInstruction;
+        use solana_sdk::message::{legacy, MessageHeader as SdkHeader};
+        use solana_sdk::pubkey::Pubkey;
+
+        let mut data = crate::instr::raydium_cpmm::discriminators::INITIALIZE.to_vec();
+        data.extend_from_slice(&[0u8; 24]);
+        let mut account_keys: Vec<Pubkey> = (0..20).map(|_| Pubkey::new_unique()).collect();
+        account_keys.push(crate::instr::program_ids::RAYDIUM_CPMM_PROGRAM_ID);
+        let tx = |sig: u8| VersionedTransaction {
+            signatures: vec![Signature::from([sig; 64])],
+            message: VersionedMessage::Legacy(legacy::Message {
+                header: SdkHeader { num_required_signatures: 1, ..Default::default() },
+                account_keys: account_keys.clone(),
+                instructions: vec![SdkInstruction {
+                    program_id_index: 20,
+                    accounts: (0..20).collect(),
+                    data: data.clone(),
+                }],
+                ..Default::default()
+            }),
+        };
+        let simulation: BundleSimulation = serde_json::from_value(serde_json::json!({
+            "summary": { "failed": { "error": {}, "tx_signature": null } },
+            "transactionResults": [
+                { "err": null, "logs": [], "unitsConsumed": 1000 },
+                { "err": { "InstructionError": [0, "Custom"] }, "logs": [] },
+            ],
+        }))
+        .unwrap();
+
+        let bundle =
+            parse_bundle_simulation(&[tx(1), tx(2), tx(3)], &simulation, 42, None).unwrap();
+        assert_eq!(bundle.len(), 2);
+        let [event] = bundle[0].as_slice() else { panic!("{:?}", bundle[0]) };
+        let metadata = event.metadata();
+        assert_eq!(
+            (metadata.signature, metadata.slot, metadata.tx_index),
+            (Signature::from([1; 64]), 42, 0)
+        );
+        assert!(bundle[1].is_empty());
+    }
+
+    #[test]
+    fn test_parse_versioned_transaction() {
+        use solana_sdk::message::compiled_instruction::CompiledInstruction as SdkInstruction;
+        use solana_sdk::message::{legacy, MessageHeader as SdkHeader};
+        use solana_sdk::pubkey::Pubkey;
+        use solana_sdk::transaction::TransactionError;
+        use solana_transaction_status::{
+            InnerInstruction as SdkInnerInstruction, InnerInstructions as SdkInnerInstructions,
+            TransactionStatusMeta as SdkStatusMeta,
+        };
+
+        let mut data = crate::instr::raydium_cpmm::discriminators::INITIALIZE.to_vec();
+        data.extend_from_slice(&[0u8; 24]);
+        let mut account_keys: Vec<Pubkey> = (0..20).map(|_| Pubkey::new_unique()).collect();
+        account_keys.push(crate::instr::program_ids::RAYDIUM_CPMM_PROGRAM_ID);
+        let tx = VersionedTransaction {
+            signatures: vec![Signature::from([9; 64])],
+            message: VersionedMessage::Legacy(legacy::Message {
+                header: SdkHeader { num_required_signatures: 1, ..Default::default() },
+                account_keys,
+                instructions: vec![SdkInstruction {
+                    program_id_index: 20,
+                    accounts: (0..20).collect(),
+                    data,
+                }],
+                ..Default::default()
+            }),
+        };
+        let mut meta = SdkStatusMeta {
+            inner_instructions: Some(vec![SdkInnerInstructions {
+                index: 0,
+                instructions: vec![SdkInnerInstruction {
+                    instruction: SdkInstruction {
+                        program_id_index: 0,
+                        accounts: vec![],
+                        data: vec![],
+                    },
+                    stack_height: Some(2),
+                }],
+            }]),
+            log_messages: Some(vec![]),
+            ..Default::default()
+        };
+
+        let events = parse_versioned_transaction(&tx, &meta, 42, None).unwrap();
+        let [DexEvent::RaydiumCpmmInitialize(event)] = events.as_slice() else {
+            panic!("{:?}", events)
+        };
+        assert_eq!((event.metadata.signature, event.metadata.slot), (Signature::from([9; 64]), 42));
+
+        let grpc_meta = convert_status_meta(&meta);
+        assert_eq!(grpc_meta.inner_instructions[0].instructions[0].stack_height, Some(2));
+        assert!(!grpc_meta.log_messages_none && grpc_meta.return_data_none);
+
+        meta.status = Err(TransactionError::AccountInUse);
+        assert!(parse_versioned_transaction(&tx, &meta, 42, None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_all_rpc_encodings() {
+        use solana_sdk::message::compiled_instruction::CompiledInstruction as SdkInstruction;
+        use solana_sdk::message::v0::{
+            self, LoadedAddresses, MessageAddressTableLookup as SdkLookup,
+        };
+        use solana_sdk::message::MessageHeader as SdkHeader;
+        use solana_transaction_status::{
+            InnerInstruction as SdkInnerInstruction, InnerInstructions as SdkInnerInstructions,
+            TransactionStatusMeta as SdkStatusMeta, VersionedTransactionWithStatusMeta,
+        };
+
+        // 静态账户：付款人、CPMM 程序、System 程序；指令的 20 个账户全部来自地址查找表
+        let payer = Pubkey::new_unique();
+        let system_program = Pubkey::default();
+        let loaded = LoadedAddresses {
+            writable: (0..10).map(|_| Pubkey::new_unique()).collect(),
+            readonly: (0..10).map(|_| Pubkey::new_unique()).collect(),
+        };
+        let mut data = crate::instr::raydium_cpmm::discriminators::INITIALIZE.to_vec();
+        data.extend_from_slice(&[0u8; 24]);
+        let transaction = VersionedTransaction {
+            signatures: vec![Signature::from([9; 64])],
+            message: VersionedMessage::V0(v0::Message {
+                header: SdkHeader {
+                    num_required_signatures: 1,
+                    num_readonly_signed_accounts: 0,
+                    num_readonly_unsigned_accounts: 2,
+                },
+                account_keys: vec![
+                    payer,
+                    crate::instr::program_ids::RAYDIUM_CPMM_PROGRAM_ID,
+                    system_program,
+                ],
+                instructions: vec![SdkInstruction {
+                    program_id_index: 1,
+                    accounts: (3..23).collect(),
+                    data,
+                }],
+                address_table_lookups: vec![SdkLookup {
+                    account_key: Pubkey::new_unique(),
+                    writable_indexes: (0..10).collect(),
+                    readonly_indexes: (10..20).collect(),
+                }],
+                ..Default::default()
+            }),
+        };
+        // jsonParsed 下 System 转账会被 RPC 完全解析（不含原始数据）
+        let mut transfer = 2u32.to_le_bytes().to_vec();
+        transfer.extend_from_slice(&1u64.to_le_bytes());
+        let meta = SdkStatusMeta {
+            inner_instructions: Some(vec![SdkInnerInstructions {
+                index: 0,
+                instructions: vec![SdkInnerInstruction {
+                    instruction: SdkInstruction {
+                        program_id_index: 2,
+                        accounts: vec![0, 3],
+                        data: transfer,
+                    },
+                    stack_height: Some(2),
+                }],
+            }]),
+            log_messages: Some(vec![]),
+            loaded_addresses: loaded.clone(),
+            ..Default::default()
+        };
+
+        for encoding in [
+            UiTransactionEncoding::Base64,
+            UiTransactionEncoding::Base58,
+            UiTransactionEncoding::Binary,
+            UiTransactionEncoding::Json,
+            UiTransactionEncoding::JsonParsed,
+        ] {
+            let encoded = VersionedTransactionWithStatusMeta {
+                transaction: transaction.clone(),
+                meta: meta.clone(),
+            }
+            .encode(encoding, Some(0), false)
+            .unwrap();
+            let events = parse_encoded_transaction(&encoded, 42, None, 0, None)
+                .unwrap_or_else(|e| panic!("{:?}: {}", encoding, e));
+            let [DexEvent::RaydiumCpmmInitialize(event)] = events.as_slice() else {
+                panic!("{:?}: {:?}", encoding, events)
+            };
+            assert_eq!(event.metadata.signature, Signature::from([9; 64]), "{:?}", encoding);
+            assert_eq!(event.creator, loaded.writable[0], "{:?}", encoding);
+            assert_eq!(event.amm_config, loaded.writable[1], "{:?}", encoding);
+
+            let (grpc_meta, _) = convert_encoded_to_grpc(&encoded).unwrap();
+            assert_eq!(grpc_meta.loaded_readonly_addresses.len(), 10, "{:?}", encoding);
+            assert_eq!(grpc_meta.inner_instructions[0].instructions[0].program_id_index, 2);
+        }
+    }
+
+    #[test]
+    fn test_backfill_address_history() {
+        use solana_client::rpc_request::RpcRequest;
+        use solana_rpc_client::mock_sender::MocksMap;
+        use solana_sdk::message::compiled_instruction::CompiledInstruction as SdkInstruction;
+        use solana_sdk::message::{legacy, MessageHeader as SdkHeader};
+        use solana_transaction_status::{
+            TransactionStatusMeta as SdkStatusMeta, VersionedTransactionWithStatusMeta,
+        };
+
+        let mut account_keys: Vec<Pubkey> = (0..20).map(|_| Pubkey::new_unique()).collect();
+        account_keys.push(crate::instr::program_ids::RAYDIUM_CPMM_PROGRAM_ID);
+        let mut data = crate::instr::raydium_cpmm::discriminators::INITIALIZE.to_vec();
+        data.extend_from_slice(&[0u8; 24]);
+        let rpc_tx = |n: u8| {
+            let transaction = VersionedTransaction {
+                signatures: vec![Signature::from([n; 64])],
+                message: VersionedMessage::Legacy(legacy::Message {
+                    header: SdkHeader { num_required_signatures: 1, ..Default::default() },
+                    account_keys: account_keys.clone(),
+                    instructions: vec![SdkInstruction {
+                        program_id_index: 20,
+                        accounts: (0..20).collect(),
+                        data: data.clone(),
+                    }],
+                    ..Default::default()
+                }),
+            };
+            let meta = SdkStatusMeta { log_messages: Some(vec![]), ..Default::default() };
+            let encoded = VersionedTransactionWithStatusMeta { transaction, meta }
+                .encode(UiTransactionEncoding::Base64, Some(0), false)
+                .unwrap();
+            serde_json::to_value(EncodedConfirmedTransactionWithStatusMeta {
+                slot: 100 + n as u64,
+                transaction: encoded,
+                block_time: None,
+            })
+            .unwrap()
+        };
+        let status = |n: u8, failed: bool| {
+            serde_json::json!({
+                "signature": Signature::from([n; 64]).to_string(),
+                "slot": 100 + n as u64,
+                "err": failed.then(|| serde_json::json!({"InstructionError": [0, "InvalidArgument"]})),
+                "memo": null,
+                "blockTime": null,
+                "confirmationStatus": null,
+            })
+        };
+        // 最新的在前，中间一笔失败
+        let statuses = serde_json::json!([status(3, false), status(2, true), status(1, false)]);
+        let client = |transactions: Vec<u8>| {
+            let mut mocks = MocksMap::default();
+            mocks.insert(RpcRequest::GetSignaturesForAddress, statuses.clone());
+            for n in transactions {
+                mocks.insert(RpcRequest::GetTransaction, rpc_tx(n));
+            }
+            RpcClient::new_mock_with_mocks_map("succeeds", mocks)
+        };
+        let slots = |events: Vec<DexEvent>| -> Vec<u64> {
+            events.iter().map(|e| e.metadata().slot).collect()
+        };
+        let address = Pubkey::new_unique();
+
+        // 按时间顺序（最早在前）拉取，跳过失败交易
+        let options = HistoryOptions::default();
+        let events = backfill_address_history(&client(vec![1, 3]), &address, &options, None);
+        assert_eq!(slots(events.unwrap()), vec![101, 103]);
+
+        // 只取最近的 N 笔
+        let options = HistoryOptions { max_transactions: 1, ..Default::default() };
+        let events = backfill_address_history(&client(vec![3]), &address, &options, None);
+        assert_eq!(slots(events.unwrap()), vec![103]);
+
+        let options = HistoryOptions { min_slot: 102, ..Default::default() };
+        let events = backfill_address_history(&client(vec![3]), &address, &options, None);
+        assert_eq!(slots(events.unwrap()), vec![103]);
+    }
+
+    #[test]
+    fn test_load_pool_trades() {
+        use crate::instr::program_ids::PUMPSWAP_PROGRAM_ID;
+        use crate::instr::pump_amm::discriminators;
+        use solana_client::rpc_request::RpcRequest;
+        use solana_rpc_client::mock_sender::MocksMap;
+        use solana_sdk::message::compiled_instruction::CompiledInstruction as SdkInstruction;
+        use solana_sdk::message::{legacy, MessageHeader as SdkHeader};
+        use solana_transaction_status::{
+            TransactionStatusMeta as SdkStatusMeta, VersionedTransactionWithStatusMeta,
+        };
+
+        let mut account_keys: Vec<Pubkey> = (0..13).map(|_| Pubkey::new_unique()).collect();
+        account_keys.push(PUMPSWAP_PROGRAM_ID);
+        let pool = account_keys[0];
+        let mut data = discriminators::BUY.to_vec();
+        data.extend_from_slice(&[0u8; 16]);
+        // 每笔交易一次池子上的 buy 和一次其他池子（账户顺序轮转）上的 buy
+        let rpc_tx = |n: u8| {
+            let buy = |first: u8| SdkInstruction {
+                program_id_index: 13,
+                accounts: (0..13).map(|i| (i + first) % 13).collect(),
+                data: data.clone(),
+            };
+            let transaction = VersionedTransaction {
+                signatures: vec![Signature::from([n; 64])],
+                message: VersionedMessage::Legacy(legacy::Message {
+                    header: SdkHeader { num_required_signatures: 1, ..Default::default() },
+                    account_keys: account_keys.clone(),
+                    instructions: vec![buy(0), buy(1)],
+                    ..Default::default()
+                }),
+            };
+            let meta = SdkStatusMeta { log_messages: Some(vec![]), ..Default::default() };
+            let encoded = VersionedTransactionWithStatusMeta { transaction, meta }
+                .encode(UiTransactionEncoding::Base64, Some(0), false)
+                .unwrap();
+            serde_json::to_value(EncodedConfirmedTransactionWithStatusMeta {
+                slot: 100 + n as u64,
+                transaction: encoded,
+                block_time: None,
+            })
+            .unwrap()
+        };
+        let status = |n: u8| {
+            serde_json::json!({
+                "signature": Signature::from([n; 64]).to_string(),
+                "slot": 100 + n as u64,
+                "err": null,
+                "memo": null,
+                "blockTime": null,
+                "confirmationStatus": null,
+            })
+        };
+        let client = |transactions: &[u8]| {
+            let mut mocks = MocksMap::default();
+            let statuses: Vec<_> = (0..=5).rev().map(status).collect();
+            mocks.insert(RpcRequest::GetSignaturesForAddress, serde_json::json!(statuses));
+            for &n in transactions {
+                mocks.insert(RpcRequest::GetTransaction, rpc_tx(n));
+            }
+            RpcClient::new_mock_with_mocks_map("succeeds", mocks)
+        };
+        let slots = |events: &[DexEvent]| -> Vec<u64> {
+            events.iter().map(|e| e.metadata().slot).collect()
+        };
+
+        // 只拉取 [102, 104] 内的交易，按时间顺序只保留该池子的成交
+        let options =
+            PoolTradeOptions { from_slot: 102, to_slot: 104, concurrency: 1, ..Default::default() };
+        let trades =
+            load_pool_trades(&client(&[4, 3, 2]), &pool, &options, &HashSet::new()).unwrap();
+        assert_eq!(slots(&trades), vec![102, 103, 104]);
+        assert!(trades.iter().all(|e| e.pool() == Some(pool)));
+
+        // 已由流推送的事件按 event_id 去重
+        let seen = HashSet::from([trades[1].event_id()]);
+        let trades = load_pool_trades(&client(&[4, 3, 2]), &pool, &options, &seen).unwrap();
+        assert_eq!(slots(&trades), vec![102, 104]);
+
+        let options = PoolTradeOptions { concurrency: 4, ..options };
+        let trades = load_pool_trades(&client(&[4, 3, 2]), &pool, &options, &seen).unwrap();
+        assert_eq!(trades.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_simulation_logs() {
+        use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+        // PumpSwap DisableEvent
+        let mut data = vec![107, 253, 193, 76, 228, 202, 27, 104];
+        data.extend_from_slice(&0i64.to_le_bytes());
+        data.extend_from_slice(&[7u8; 32]);
+        data.extend_from_slice(&[1, 1, 1, 1, 1]);
+        let program = crate::accounts::program_ids::PUMPSWAP_PROGRAM_ID;
+        let logs = vec![
+            "Program ComputeBudget111111111111111111111111111111 invoke [1]".to_string(),
+            "Program ComputeBudget111111111111111111111111111111 success".to_string(),
+            format!("Program {} invoke [1]", program),
+            format!("Program data: {}", STANDARD.encode(data)),
+            format!("Program {} success", program),
+        ];
+
+        let events = parse_simulation_logs(&logs, 7, None);
+        let [event] = events.as_slice() else { panic!("{:?}", events) };
+        let metadata = event.metadata();
+        assert_eq!((metadata.signature, metadata.slot), (Signature::default(), 7));
+        assert_eq!((metadata.outer_index, metadata.inner_index), (1, None));
+        assert!(parse_simulation_logs(&[], 7, None).is_empty());
+    }
 }
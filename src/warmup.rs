@@ -62,24 +62,17 @@ fn warmup_simd_finders() {
     
     // 预热 logs/optimized_matcher.rs 中的所有 Finder
     let _ = memmem::find(dummy_log, b"Program data: ");
-    let _ = memmem::find(dummy_log, b"6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P");
-    let _ = memmem::find(dummy_log, b"675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8");
     let _ = memmem::find(dummy_log, b"invoke [");
-    let _ = memmem::find(dummy_log, b"Program");
-    let _ = memmem::find(dummy_log, b"pumpswap");
-    let _ = memmem::find(dummy_log, b"PumpSwap");
-    let _ = memmem::find(dummy_log, b"whirL");
-    let _ = memmem::find(dummy_log, b"meteora");
     
     // 触发 parse_invoke_info 来预热相关 Finder
     let _ = crate::logs::optimized_matcher::parse_invoke_info(
         "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P invoke [1]"
     );
     
-    // 触发 detect_log_type 来预热类型检测
-    let _ = crate::logs::optimized_matcher::detect_log_type(
-        "Program data: test"
-    );
+    // 触发程序归属来预热调用栈游标
+    let mut cursor = crate::logs::optimized_matcher::InvokeCursor::new();
+    cursor.observe("Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P invoke [1]");
+    let _ = cursor.program();
 }
 
 /// 预热 Base64 引擎